@@ -59,19 +59,29 @@ pub struct Index {
     /// For PEP 503 endpoints, this excludes `/simple`.
     pub root_url: DisplaySafeUrl,
     pub auth_policy: AuthPolicy,
+    /// Additional URL prefixes that share the credentials of the index.
+    pub auth_scope: Vec<DisplaySafeUrl>,
 }
 
 impl Index {
+    /// Returns `true` if the URL is under the root of the index, or one of its declared
+    /// authentication scopes.
     pub fn is_prefix_for(&self, url: &Url) -> bool {
-        if self.root_url.scheme() != url.scheme()
-            || self.root_url.host_str() != url.host_str()
-            || self.root_url.port_or_known_default() != url.port_or_known_default()
-        {
-            return false;
-        }
+        is_prefix(&self.root_url, url) || self.auth_scope.iter().any(|scope| is_prefix(scope, url))
+    }
+}
 
-        url.path().starts_with(self.root_url.path())
+/// Returns `true` if `prefix` has the same scheme, host, and port as `url`, and its path is a
+/// prefix of the path of `url`.
+fn is_prefix(prefix: &Url, url: &Url) -> bool {
+    if prefix.scheme() != url.scheme()
+        || prefix.host_str() != url.host_str()
+        || prefix.port_or_known_default() != url.port_or_known_default()
+    {
+        return false;
     }
+
+    url.path().starts_with(prefix.path())
 }
 
 // TODO(john): Multiple methods in this struct need to iterate over
@@ -1758,11 +1758,13 @@ mod tests {
                 url: DisplaySafeUrl::from(base_url_1.clone()),
                 root_url: DisplaySafeUrl::from(base_url_1.clone()),
                 auth_policy: AuthPolicy::Auto,
+                auth_scope: Vec::new(),
             },
             Index {
                 url: DisplaySafeUrl::from(base_url_2.clone()),
                 root_url: DisplaySafeUrl::from(base_url_2.clone()),
                 auth_policy: AuthPolicy::Auto,
+                auth_scope: Vec::new(),
             },
         ]);
 
@@ -1866,6 +1868,7 @@ mod tests {
             url: DisplaySafeUrl::from(index_url.clone()),
             root_url: DisplaySafeUrl::from(index_url.clone()),
             auth_policy: AuthPolicy::Auto,
+            auth_scope: Vec::new(),
         }]);
 
         let client = test_client_builder()
@@ -1925,6 +1928,7 @@ mod tests {
             url: url.clone(),
             root_url: url.clone(),
             auth_policy: policy,
+            auth_scope: Vec::new(),
         }])
     }
 
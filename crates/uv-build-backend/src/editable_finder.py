@@ -0,0 +1,66 @@
+"""Editable finder for a namespace package, generated by the uv build backend.
+
+Instead of adding the module root to `sys.path`, the top-level modules are provided
+through a dedicated `sys.path` entry. For namespace packages, the finder only
+contributes a namespace portion, which Python's path finder merges with the portions of
+the same namespace from all other `sys.path` entries, including other editable installs.
+"""
+
+import os
+import sys
+from importlib.machinery import ModuleSpec
+from importlib.util import spec_from_file_location
+
+# The top-level module names, mapped to their location in the source tree.
+MAPPING = __MAPPING__
+
+# The `sys.path` entry handled by the finder.
+PATH_ENTRY = __PATH_ENTRY__
+
+
+class _EditableFinder:
+    """A path entry finder for the top-level modules of an editable project."""
+
+    @classmethod
+    def path_hook(cls, path):
+        if path == PATH_ENTRY:
+            return cls
+        raise ImportError
+
+    @classmethod
+    def invalidate_caches(cls):
+        pass
+
+    @classmethod
+    def find_spec(cls, fullname, target=None):
+        path = MAPPING.get(fullname)
+        if path is None:
+            return None
+
+        # A regular package.
+        init_py = os.path.join(path, "__init__.py")
+        if os.path.isfile(init_py):
+            return spec_from_file_location(
+                fullname, init_py, submodule_search_locations=[path]
+            )
+
+        # A namespace portion, without a loader, such that the path finder continues to
+        # collect portions from the remaining `sys.path` entries.
+        if os.path.isdir(path):
+            spec = ModuleSpec(fullname, None, is_package=True)
+            spec.submodule_search_locations = [path]
+            return spec
+
+        # A single-file module.
+        module_py = path + ".py"
+        if os.path.isfile(module_py):
+            return spec_from_file_location(fullname, module_py)
+
+        return None
+
+
+def install():
+    if _EditableFinder.path_hook not in sys.path_hooks:
+        sys.path_hooks.append(_EditableFinder.path_hook)
+    if PATH_ENTRY not in sys.path:
+        sys.path.append(PATH_ENTRY)
@@ -23,6 +23,7 @@ pub fn build_source_dist(
     source_tree: &Path,
     source_dist_directory: &Path,
     uv_version: &str,
+    source_date_epoch: Option<u64>,
 ) -> Result<SourceDistFilename, Error> {
     let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
     let pyproject_toml = PyProjectToml::parse(&contents)?;
@@ -32,7 +33,7 @@ pub fn build_source_dist(
         extension: SourceDistExtension::TarGz,
     };
     let source_dist_path = source_dist_directory.join(filename.to_string());
-    let writer = TarGzWriter::new(&source_dist_path)?;
+    let writer = TarGzWriter::new(&source_dist_path, source_date_epoch)?;
     write_source_dist(source_tree, writer, uv_version)?;
     Ok(filename)
 }
@@ -273,15 +274,25 @@ fn write_source_dist(
 struct TarGzWriter {
     path: PathBuf,
     tar: tar::Builder<GzEncoder<File>>,
+    /// The modification time of all archive members.
+    mtime: u64,
 }
 
 impl TarGzWriter {
-    fn new(path: impl Into<PathBuf>) -> Result<Self, Error> {
+    /// A source distribution writer.
+    ///
+    /// If `source_date_epoch` is set, it's used as the modification time of all archive members,
+    /// otherwise, the Unix epoch is used.
+    fn new(path: impl Into<PathBuf>, source_date_epoch: Option<u64>) -> Result<Self, Error> {
         let path = path.into();
         let file = File::create(&path)?;
         let enc = GzEncoder::new(file, Compression::default());
         let tar = tar::Builder::new(enc);
-        Ok(Self { path, tar })
+        Ok(Self {
+            path,
+            tar,
+            mtime: source_date_epoch.unwrap_or_default(),
+        })
     }
 }
 
@@ -289,6 +300,7 @@ impl DirectoryWriter for TarGzWriter {
     fn write_bytes(&mut self, path: &str, bytes: &[u8]) -> Result<(), Error> {
         let mut header = Header::new_gnu();
         header.set_size(bytes.len() as u64);
+        header.set_mtime(self.mtime);
         // Reasonable default to avoid 0o000 permissions, the user's umask will be applied on
         // unpacking.
         header.set_mode(0o644);
@@ -320,6 +332,7 @@ impl DirectoryWriter for TarGzWriter {
             header.set_mode(0o644);
         }
         header.set_size(metadata.len());
+        header.set_mtime(self.mtime);
         let reader = BufReader::new(File::open(file)?);
         self.tar
             .append_data(&mut header, path, reader)
@@ -333,6 +346,7 @@ impl DirectoryWriter for TarGzWriter {
         header.set_mode(0o755);
         header.set_entry_type(EntryType::Directory);
         header.set_size(0);
+        header.set_mtime(self.mtime);
         self.tar
             .append_data(&mut header, directory, io::empty())
             .map_err(|err| Error::TarWrite(self.path.clone(), err))?;
@@ -3,12 +3,13 @@ use globset::{GlobSet, GlobSetBuilder};
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::io::{BufReader, Read, Write};
 use std::path::{Path, PathBuf};
 use std::{io, mem};
 use tracing::{debug, trace};
 use walkdir::WalkDir;
-use zip::{CompressionMethod, ZipWriter};
+use zip::{CompressionMethod, DateTime, ZipWriter};
 
 use uv_distribution_filename::WheelFilename;
 use uv_fs::Simplified;
@@ -27,6 +28,7 @@ pub fn build_wheel(
     wheel_dir: &Path,
     metadata_directory: Option<&Path>,
     uv_version: &str,
+    source_date_epoch: Option<u64>,
 ) -> Result<WheelFilename, Error> {
     let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
     let pyproject_toml = PyProjectToml::parse(&contents)?;
@@ -48,7 +50,7 @@ pub fn build_wheel(
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
-    let wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
+    let wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?, source_date_epoch);
 
     write_wheel(
         source_tree,
@@ -242,6 +244,7 @@ pub fn build_editable(
     wheel_dir: &Path,
     metadata_directory: Option<&Path>,
     uv_version: &str,
+    source_date_epoch: Option<u64>,
 ) -> Result<WheelFilename, Error> {
     let contents = fs_err::read_to_string(source_tree.join("pyproject.toml"))?;
     let pyproject_toml = PyProjectToml::parse(&contents)?;
@@ -268,11 +271,12 @@ pub fn build_editable(
 
     let wheel_path = wheel_dir.join(filename.to_string());
     debug!("Writing wheel at {}", wheel_path.user_display());
-    let mut wheel_writer = ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?);
+    let mut wheel_writer =
+        ZipDirectoryWriter::new_wheel(File::create(&wheel_path)?, source_date_epoch);
 
     debug!("Adding pth file to {}", wheel_path.user_display());
     // Check that a module root exists in the directory we're linking from the `.pth` file
-    let (src_root, _module_relative) = find_roots(
+    let (src_root, modules_relative) = find_roots(
         source_tree,
         &pyproject_toml,
         &settings.module_root,
@@ -280,10 +284,34 @@ pub fn build_editable(
         settings.namespace,
     )?;
 
-    wheel_writer.write_bytes(
-        &format!("{}.pth", pyproject_toml.name().as_dist_info_name()),
-        src_root.as_os_str().as_encoded_bytes(),
-    )?;
+    let dist_info_name = pyproject_toml.name().as_dist_info_name();
+    let is_namespace = settings.namespace
+        || modules_relative
+            .iter()
+            .any(|module_relative| module_relative.components().count() > 1);
+    let finder = if is_namespace {
+        editable_finder(&dist_info_name, &src_root, &modules_relative)
+    } else {
+        None
+    };
+    if let Some(finder) = finder {
+        // For namespace packages, adding the module root to `sys.path` would expose everything
+        // in the module root, which can shadow the portions of the namespace provided by other
+        // distributions. Instead, install a finder that only provides the project's own modules,
+        // as namespace portions that are merged with those of other `sys.path` entries.
+        debug!("Adding editable finder for namespace package");
+        let finder_module = format!("_uv_editable_{dist_info_name}");
+        wheel_writer.write_bytes(&format!("{finder_module}.py"), finder.as_bytes())?;
+        wheel_writer.write_bytes(
+            &format!("{dist_info_name}.pth"),
+            format!("import {finder_module}; {finder_module}.install()\n").as_bytes(),
+        )?;
+    } else {
+        wheel_writer.write_bytes(
+            &format!("{dist_info_name}.pth"),
+            src_root.as_os_str().as_encoded_bytes(),
+        )?;
+    }
 
     debug!("Adding metadata files to: `{}`", wheel_path.user_display());
     let dist_info_dir = write_dist_info(
@@ -298,6 +326,69 @@ pub fn build_editable(
     Ok(filename)
 }
 
+/// The editable finder module for namespace packages, with placeholders for the module mapping and
+/// the `sys.path` entry.
+const EDITABLE_FINDER: &str = include_str!("editable_finder.py");
+
+/// Render the editable finder for the top-level modules of a namespace package.
+///
+/// Returns `None` for stub packages, which type checkers can only discover through a path entry in
+/// the `.pth` file, and if a module path is not valid UTF-8. In both cases, we fall back to a plain
+/// `.pth` file.
+fn editable_finder(
+    dist_info_name: &str,
+    src_root: &Path,
+    modules_relative: &[PathBuf],
+) -> Option<String> {
+    let mut mapping = BTreeMap::new();
+    for module_relative in modules_relative {
+        let top_level = module_relative.components().next()?.as_os_str().to_str()?;
+        if top_level.ends_with("-stubs") {
+            return None;
+        }
+        let path = src_root.join(top_level);
+        mapping.insert(top_level, path.to_str()?.to_string());
+    }
+
+    let mapping = mapping
+        .iter()
+        .map(|(name, path)| {
+            format!(
+                "    {}: {},\n",
+                python_string_literal(name),
+                python_string_literal(path)
+            )
+        })
+        .collect::<String>();
+
+    Some(
+        EDITABLE_FINDER
+            .replace("__MAPPING__", &format!("{{\n{mapping}}}"))
+            .replace(
+                "__PATH_ENTRY__",
+                &python_string_literal(&format!("__editable__.{dist_info_name}.finder")),
+            ),
+    )
+}
+
+/// Format a string as a (double-quoted) Python string literal.
+fn python_string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
 /// Write the dist-info directory to the output directory without building the wheel.
 pub fn metadata(
     source_tree: &Path,
@@ -589,16 +680,22 @@ fn wheel_info(filename: &WheelFilename, uv_version: &str) -> String {
 struct ZipDirectoryWriter {
     writer: ZipWriter<File>,
     compression: CompressionMethod,
+    /// The modification time of all archive members.
+    last_modified: DateTime,
     /// The entries in the `RECORD` file.
     record: Vec<RecordEntry>,
 }
 
 impl ZipDirectoryWriter {
     /// A wheel writer with deflate compression.
-    fn new_wheel(file: File) -> Self {
+    ///
+    /// If `source_date_epoch` is set, it's used as the modification time of all archive members,
+    /// otherwise, the earliest timestamp that zip archives support is used.
+    fn new_wheel(file: File, source_date_epoch: Option<u64>) -> Self {
         Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Deflated,
+            last_modified: source_date_epoch.map(zip_date_time).unwrap_or_default(),
             record: Vec::new(),
         }
     }
@@ -607,10 +704,11 @@ impl ZipDirectoryWriter {
     ///
     /// Since editables are temporary, we save time be skipping compression and decompression.
     #[expect(dead_code)]
-    fn new_editable(file: File) -> Self {
+    fn new_editable(file: File, source_date_epoch: Option<u64>) -> Self {
         Self {
             writer: ZipWriter::new(file),
             compression: CompressionMethod::Stored,
+            last_modified: source_date_epoch.map(zip_date_time).unwrap_or_default(),
             record: Vec::new(),
         }
     }
@@ -625,7 +723,8 @@ impl ZipDirectoryWriter {
         let permissions = if executable_bit { 0o755 } else { 0o644 };
         let options = zip::write::SimpleFileOptions::default()
             .unix_permissions(permissions)
-            .compression_method(self.compression);
+            .compression_method(self.compression)
+            .last_modified_time(self.last_modified);
         self.writer.start_file(path, options)?;
         Ok(Box::new(&mut self.writer))
     }
@@ -637,7 +736,8 @@ impl DirectoryWriter for ZipDirectoryWriter {
         // Set appropriate permissions for metadata files (644 = rw-r--r--)
         let options = zip::write::SimpleFileOptions::default()
             .unix_permissions(0o644)
-            .compression_method(self.compression);
+            .compression_method(self.compression)
+            .last_modified_time(self.last_modified);
         self.writer.start_file(path, options)?;
         self.writer.write_all(bytes)?;
 
@@ -672,7 +772,9 @@ impl DirectoryWriter for ZipDirectoryWriter {
 
     fn write_directory(&mut self, directory: &str) -> Result<(), Error> {
         trace!("Adding directory {}", directory);
-        let options = zip::write::SimpleFileOptions::default().compression_method(self.compression);
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(self.compression)
+            .last_modified_time(self.last_modified);
         Ok(self.writer.add_directory(directory, options)?)
     }
 
@@ -693,6 +795,49 @@ impl DirectoryWriter for ZipDirectoryWriter {
     }
 }
 
+/// Convert a Unix timestamp to a zip timestamp, clamped to the range that zip archives support
+/// (1980 to 2107).
+fn zip_date_time(timestamp: u64) -> DateTime {
+    // Convert the days since the Unix epoch to a civil date, see
+    // <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+    let era = (days + 719_468) / 146_097;
+    let day_of_era = days + 719_468 - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    if year < 1980 {
+        return DateTime::default();
+    }
+    u16::try_from(year)
+        .ok()
+        .and_then(|year| {
+            DateTime::from_date_and_time(
+                year,
+                u8::try_from(month).ok()?,
+                u8::try_from(day).ok()?,
+                u8::try_from(seconds / 3600).ok()?,
+                u8::try_from(seconds % 3600 / 60).ok()?,
+                u8::try_from(seconds % 60).ok()?,
+            )
+            .ok()
+        })
+        .unwrap_or_else(|| {
+            DateTime::from_date_and_time(2107, 12, 31, 23, 59, 58)
+                .expect("The latest zip timestamp is valid")
+        })
+}
+
 struct FilesystemWriter {
     /// The virtualenv or metadata directory that add file paths are relative to.
     root: PathBuf,
@@ -5,7 +5,7 @@ use std::path::PathBuf;
 use std::process::ExitStatus;
 use std::sync::LazyLock;
 
-use crate::PythonRunnerOutput;
+use crate::{LimitExceeded, PythonRunnerOutput};
 use owo_colors::OwoColorize;
 use regex::Regex;
 use thiserror::Error;
@@ -81,6 +81,8 @@ pub enum Error {
     BuildBackend(#[from] BuildBackendError),
     #[error("The build backend returned an error")]
     MissingHeader(#[from] MissingHeaderError),
+    #[error("The build backend exceeded the build limits")]
+    BuildLimit(#[from] BuildLimitError),
     #[error("Failed to build PATH for build script")]
     BuildScriptPath(#[source] env::JoinPathsError),
     // For the convenience of typing `setup_build` properly.
@@ -109,6 +111,7 @@ impl IsBuildBackendError for Error {
             Self::CommandFailed(_, _)
             | Self::BuildBackend(_)
             | Self::MissingHeader(_)
+            | Self::BuildLimit(_)
             | Self::BuildScriptPath(_) => true,
         }
     }
@@ -322,6 +325,80 @@ impl Display for MissingHeaderError {
     }
 }
 
+/// The number of trailing lines of build output to include when a build exceeds its limits.
+const BUILD_LIMIT_TAIL: usize = 20;
+
+#[derive(Debug, Error)]
+pub struct BuildLimitError {
+    message: String,
+    limit: LimitExceeded,
+    package_name: Option<PackageName>,
+    package: Option<String>,
+    stdout: Vec<String>,
+    stderr: Vec<String>,
+}
+
+impl Display for BuildLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.limit {
+            LimitExceeded::Timeout(timeout) => {
+                write!(f, "{} (timed out after {timeout})", self.message)?;
+            }
+            LimitExceeded::OutputSize(size) => {
+                write!(
+                    f,
+                    "{} (exceeded the maximum output size of {size})",
+                    self.message
+                )?;
+            }
+        }
+
+        if self.stdout.iter().any(|line| !line.trim().is_empty()) {
+            write!(
+                f,
+                "\n\n{} (last {BUILD_LIMIT_TAIL} lines)\n{}",
+                "[stdout]".red(),
+                self.stdout.join("\n")
+            )?;
+        }
+
+        if self.stderr.iter().any(|line| !line.trim().is_empty()) {
+            write!(
+                f,
+                "\n\n{} (last {BUILD_LIMIT_TAIL} lines)\n{}",
+                "[stderr]".red(),
+                self.stderr.join("\n")
+            )?;
+        }
+
+        let package = self
+            .package
+            .as_deref()
+            .map(|package| format!(" of `{}`", package.cyan()))
+            .unwrap_or_default();
+        let (setting, value) = match self.limit {
+            LimitExceeded::Timeout(timeout) => ("build-timeout", timeout.to_string()),
+            LimitExceeded::OutputSize(size) => ("build-max-output-size", size.to_string()),
+        };
+        let setting = if let Some(package_name) = &self.package_name {
+            format!("{setting}-package = {{ {package_name} = \"...\" }}")
+        } else {
+            format!("{setting} = \"...\"")
+        };
+
+        write!(
+            f,
+            "\n\n{}{} The build{package} was terminated after exceeding the configured limit ({value}). To raise the limit, set `{}`, or disable build limits with `{}`.",
+            "hint".bold().cyan(),
+            ":".bold(),
+            setting.green(),
+            "--no-build-timeout".green(),
+        )?;
+
+        Ok(())
+    }
+}
+
 impl Error {
     /// Construct an [`Error`] from the output of a failed command.
     pub(crate) fn from_command_output(
@@ -332,6 +409,27 @@ impl Error {
         version: Option<&Version>,
         version_id: Option<&str>,
     ) -> Self {
+        // If the build was terminated for exceeding its limits, surface the tail of its output.
+        if let Some(limit) = output.limit {
+            let tail =
+                |lines: &[String]| lines[lines.len().saturating_sub(BUILD_LIMIT_TAIL)..].to_vec();
+            let package = if let (Some(name), Some(version)) = (name, version) {
+                Some(format!("{name}@{version}"))
+            } else if let Some(version_id) = version_id {
+                Some(version_id.to_string())
+            } else {
+                name.map(ToString::to_string)
+            };
+            return Self::BuildLimit(BuildLimitError {
+                message,
+                limit,
+                package_name: name.cloned(),
+                package,
+                stdout: tail(&output.stdout),
+                stderr: tail(&output.stderr),
+            });
+        }
+
         // In the cases I've seen it was the 5th and 3rd last line (see test case), 10 seems like a reasonable cutoff.
         let missing_library = output.stderr.iter().rev().take(10).find_map(|line| {
             if let Some((_, [header])) = MISSING_HEADER_RE_GCC
@@ -409,11 +507,11 @@ impl Error {
 
 #[cfg(test)]
 mod test {
-    use crate::{Error, PythonRunnerOutput};
+    use crate::{Error, LimitExceeded, PythonRunnerOutput};
     use indoc::indoc;
     use std::process::ExitStatus;
     use std::str::FromStr;
-    use uv_configuration::BuildOutput;
+    use uv_configuration::{BuildOutput, BuildTimeout};
     use uv_pep440::Version;
     use uv_pep508::PackageName;
 
@@ -421,6 +519,7 @@ mod test {
     fn missing_header() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            limit: None,
             stdout: indoc!(r"
                 running bdist_wheel
                 running build
@@ -488,6 +587,7 @@ mod test {
     fn missing_linker_library() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            limit: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -535,6 +635,7 @@ mod test {
     fn missing_wheel_package() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            limit: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -584,6 +685,7 @@ mod test {
     fn missing_distutils() {
         let output = PythonRunnerOutput {
             status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            limit: None,
             stdout: Vec::new(),
             stderr: indoc!(
                 r"
@@ -621,4 +723,57 @@ mod test {
         hint: `distutils` was removed from the standard library in Python 3.12. Consider adding a constraint (like `pygraphviz >1.11`) to avoid building a version of `pygraphviz` that depends on `distutils`.
         "###);
     }
+
+    #[test]
+    fn build_timeout() {
+        let output = PythonRunnerOutput {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            limit: Some(LimitExceeded::Timeout(
+                BuildTimeout::from_str("10m").unwrap(),
+            )),
+            stdout: (1..=25)
+                .map(|line| format!("compiling module {line}"))
+                .collect(),
+            stderr: Vec::new(),
+        };
+
+        let err = Error::from_command_output(
+            "Call to `setuptools.build_meta.build_wheel` failed".to_string(),
+            &output,
+            BuildOutput::Stderr,
+            Some(&PackageName::from_str("numpy").unwrap()),
+            Some(&Version::from_str("1.26.0").unwrap()),
+            None,
+        );
+        assert!(matches!(err, Error::BuildLimit { .. }));
+        let formatted = std::error::Error::source(&err).unwrap().to_string();
+        let formatted = anstream::adapter::strip_str(&formatted);
+        insta::assert_snapshot!(formatted, @r#"
+        Call to `setuptools.build_meta.build_wheel` failed (timed out after 10m)
+
+        [stdout] (last 20 lines)
+        compiling module 6
+        compiling module 7
+        compiling module 8
+        compiling module 9
+        compiling module 10
+        compiling module 11
+        compiling module 12
+        compiling module 13
+        compiling module 14
+        compiling module 15
+        compiling module 16
+        compiling module 17
+        compiling module 18
+        compiling module 19
+        compiling module 20
+        compiling module 21
+        compiling module 22
+        compiling module 23
+        compiling module 24
+        compiling module 25
+
+        hint: The build of `numpy@1.26.0` was terminated after exceeding the configured limit (10m). To raise the limit, set `build-timeout-package = { numpy = "..." }`, or disable build limits with `--no-build-timeout`.
+        "#);
+    }
 }
@@ -3,6 +3,7 @@
 //! <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
 
 mod error;
+mod process_group;
 
 use std::borrow::Cow;
 use std::ffi::OsString;
@@ -14,6 +15,7 @@ use std::process::ExitStatus;
 use std::rc::Rc;
 use std::str::FromStr;
 use std::sync::LazyLock;
+use std::time::Duration;
 use std::{env, iter};
 
 use fs_err as fs;
@@ -30,7 +32,10 @@ use tracing::{Instrument, debug, info_span, instrument, warn};
 
 use uv_cache_key::cache_digest;
 use uv_configuration::Preview;
-use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy};
+use uv_configuration::{
+    BuildKind, BuildLimits, BuildOutput, BuildOutputSize, BuildTimeout, ConfigSettings,
+    SourceStrategy,
+};
 use uv_distribution::BuildRequires;
 use uv_distribution_types::{ExtraBuildRequires, IndexLocations, Requirement, Resolution};
 use uv_fs::LockedFile;
@@ -44,7 +49,9 @@ use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, SourceBu
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
-pub use crate::error::{Error, MissingHeaderCause};
+use crate::process_group::ProcessGroup;
+
+pub use crate::error::{BuildLimitError, Error, MissingHeaderCause};
 
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend {
@@ -233,6 +240,8 @@ pub struct SourceBuild {
     project: Option<Project>,
     /// The virtual environment in which to build the source distribution.
     venv: PythonEnvironment,
+    /// The resolved requirements of the build environment, if isolated.
+    build_requirements: Option<Resolution>,
     /// Populated if `prepare_metadata_for_build_wheel` was called.
     ///
     /// > If the build frontend has previously called `prepare_metadata_for_build_wheel` and depends
@@ -287,6 +296,7 @@ impl SourceBuild {
         build_kind: BuildKind,
         mut environment_variables: FxHashMap<OsString, OsString>,
         level: BuildOutput,
+        limits: &BuildLimits,
         concurrent_builds: usize,
         preview: Preview,
     ) -> Result<Self, Error> {
@@ -350,7 +360,7 @@ impl SourceBuild {
 
         // Set up the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        if build_isolation.is_isolated(package_name.as_ref()) {
+        let mut build_requirements = if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Resolving build requirements");
 
             let dependency_sources = if extra_build_dependencies.is_empty() {
@@ -373,9 +383,12 @@ impl SourceBuild {
                 .install(&resolved_requirements, &venv, build_stack)
                 .await
                 .map_err(|err| Error::RequirementsInstall(dependency_sources, err.into()))?;
+
+            Some(resolved_requirements)
         } else {
             debug!("Proceeding without build isolation");
-        }
+            None
+        };
 
         // Figure out what the modified path should be, and remove the PATH variable from the
         // environment variables if it's there.
@@ -410,11 +423,27 @@ impl SourceBuild {
 
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        let runner = PythonRunner::new(concurrent_builds, level);
+        let timeout = limits.timeout(package_name.as_ref());
+        let max_output_size = limits.max_output_size(package_name.as_ref());
+
+        // If the build is subject to a size limit, redirect the backend's temporary files into a
+        // dedicated directory, such that its size can be measured (and removed on a breach).
+        let max_output_size = if let Some(max_output_size) = max_output_size {
+            let scratch = temp_dir.path().join("tmp");
+            fs::create_dir_all(&scratch)?;
+            for var in [EnvVars::TMPDIR, EnvVars::TEMP, EnvVars::TMP] {
+                environment_variables.insert(var.into(), scratch.clone().into_os_string());
+            }
+            Some((max_output_size, scratch))
+        } else {
+            None
+        };
+
+        let runner = PythonRunner::new(concurrent_builds, level, timeout, max_output_size);
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
-            create_pep517_build_environment(
+            let extra_requirements = create_pep517_build_environment(
                 &runner,
                 &source_tree,
                 install_path,
@@ -436,6 +465,12 @@ impl SourceBuild {
                 &temp_dir,
             )
             .await?;
+
+            // If the backend requested additional requirements, the build environment was
+            // re-resolved to include them.
+            if extra_requirements.is_some() {
+                build_requirements = extra_requirements;
+            }
         }
 
         Ok(Self {
@@ -444,6 +479,7 @@ impl SourceBuild {
             pep517_backend,
             project,
             venv,
+            build_requirements,
             build_kind,
             level,
             config_settings,
@@ -911,6 +947,10 @@ impl SourceBuildTrait for SourceBuild {
     async fn wheel<'a>(&'a self, wheel_dir: &'a Path) -> Result<String, AnyErrorBuild> {
         Ok(self.build(wheel_dir).await?)
     }
+
+    fn build_requirements(&self) -> Option<&Resolution> {
+        self.build_requirements.as_ref()
+    }
 }
 
 fn escape_path_for_python(path: &Path) -> String {
@@ -940,7 +980,7 @@ async fn create_pep517_build_environment(
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
     temp_dir: &TempDir,
-) -> Result<(), Error> {
+) -> Result<Option<Resolution>, Error> {
     // Write the hook output to a file so that we can read it back reliably.
     let outfile = temp_dir
         .path()
@@ -1070,9 +1110,11 @@ async fn create_pep517_build_environment(
             .map_err(|err| {
                 Error::RequirementsInstall("`build-system.requires`", AnyErrorBuild::from(err))
             })?;
+
+        return Ok(Some(resolution));
     }
 
-    Ok(())
+    Ok(None)
 }
 
 /// A runner that manages the execution of external python processes with a
@@ -1081,6 +1123,10 @@ async fn create_pep517_build_environment(
 struct PythonRunner {
     control: Semaphore,
     level: BuildOutput,
+    /// The maximum duration of each script, if any.
+    timeout: Option<BuildTimeout>,
+    /// The maximum size of the build's temporary directory, if any, along with the directory.
+    max_output_size: Option<(BuildOutputSize, PathBuf)>,
 }
 
 #[derive(Debug)]
@@ -1088,14 +1134,36 @@ struct PythonRunnerOutput {
     stdout: Vec<String>,
     stderr: Vec<String>,
     status: ExitStatus,
+    /// The build limit that caused the script to be terminated, if any.
+    limit: Option<LimitExceeded>,
+}
+
+/// A build limit that was exceeded by a build backend hook.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum LimitExceeded {
+    /// The hook ran for longer than the build timeout.
+    Timeout(BuildTimeout),
+    /// The build's temporary files exceeded the maximum output size.
+    OutputSize(BuildOutputSize),
 }
 
+/// The interval at which to measure the size of the build's temporary directory.
+const OUTPUT_SIZE_INTERVAL: Duration = Duration::from_secs(1);
+
 impl PythonRunner {
-    /// Create a `PythonRunner` with the provided concurrency limit and output level.
-    fn new(concurrency: usize, level: BuildOutput) -> Self {
+    /// Create a `PythonRunner` with the provided concurrency limit, output level, and build
+    /// limits.
+    fn new(
+        concurrency: usize,
+        level: BuildOutput,
+        timeout: Option<BuildTimeout>,
+        max_output_size: Option<(BuildOutputSize, PathBuf)>,
+    ) -> Self {
         Self {
             control: Semaphore::new(concurrency),
             level,
+            timeout,
+            max_output_size,
         }
     }
 
@@ -1134,7 +1202,8 @@ impl PythonRunner {
 
         let _permit = self.control.acquire().await.unwrap();
 
-        let mut child = Command::new(venv.python_executable())
+        let mut command = Command::new(venv.python_executable());
+        command
             .args(["-c", script])
             .current_dir(source_tree.simplified())
             .envs(environment_variables)
@@ -1143,10 +1212,23 @@ impl PythonRunner {
             .env(EnvVars::CLICOLOR_FORCE, "1")
             .env(EnvVars::PYTHONIOENCODING, "utf-8:backslashreplace")
             .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+
+        // If any build limits are configured, run the script in its own process group, such that
+        // any processes spawned by the build backend can be terminated along with it.
+        let limited = self.timeout.is_some() || self.max_output_size.is_some();
+        #[cfg(unix)]
+        if limited {
+            command.process_group(0);
+        }
+
+        let mut child = command
             .spawn()
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
 
+        // Kill the process group if the build is cancelled or uv is interrupted.
+        let group = limited.then(|| ProcessGroup::new(&child));
+
         // Create buffers to capture `stdout` and `stderr`.
         let mut stdout_buf = Vec::with_capacity(1024);
         let mut stderr_buf = Vec::with_capacity(1024);
@@ -1155,20 +1237,37 @@ impl PythonRunner {
         let stdout_reader = tokio::io::BufReader::new(child.stdout.take().unwrap()).split(b'\n');
         let stderr_reader = tokio::io::BufReader::new(child.stderr.take().unwrap()).split(b'\n');
 
-        // Asynchronously read from the in-memory pipes.
+        // Asynchronously read from the in-memory pipes, unless the script exceeds the build
+        // limits first.
         let printer = Printer::from(self.level);
-        let result = tokio::join!(
-            read_from(stdout_reader, printer, &mut stdout_buf),
-            read_from(stderr_reader, printer, &mut stderr_buf),
-        );
-        match result {
-            (Ok(()), Ok(())) => {}
-            (Err(err), _) | (_, Err(err)) => {
-                return Err(Error::CommandFailed(
-                    venv.python_executable().to_path_buf(),
-                    err,
-                ));
+        let limit = {
+            let output = async {
+                let result = tokio::join!(
+                    read_from(stdout_reader, printer, &mut stdout_buf),
+                    read_from(stderr_reader, printer, &mut stderr_buf),
+                );
+                match result {
+                    (Ok(()), Ok(())) => Ok(()),
+                    (Err(err), _) | (_, Err(err)) => Err(err),
+                }
+            };
+            tokio::select! {
+                result = output => {
+                    result.map_err(|err| {
+                        Error::CommandFailed(venv.python_executable().to_path_buf(), err)
+                    })?;
+                    None
+                }
+                limit = self.exceeded_limit() => Some(limit),
+            }
+        };
+
+        if let Some(limit) = limit {
+            debug!("Terminating build backend after exceeding the build limits: {limit:?}");
+            if let Some(group) = &group {
+                group.kill();
             }
+            let _ = child.start_kill();
         }
 
         // Wait for the child process to finish.
@@ -1176,13 +1275,78 @@ impl PythonRunner {
             .wait()
             .await
             .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))?;
+        if limit.is_none() {
+            if let Some(group) = group {
+                group.release();
+            }
+        }
+
+        // If the build exceeded the maximum output size, remove its temporary files.
+        if let Some(LimitExceeded::OutputSize(_)) = limit {
+            if let Some((_, scratch)) = &self.max_output_size {
+                if let Err(err) = fs::remove_dir_all(scratch) {
+                    warn!(
+                        "Failed to remove build directory `{}`: {err}",
+                        scratch.user_display()
+                    );
+                }
+            }
+        }
 
         Ok(PythonRunnerOutput {
             stdout: stdout_buf,
             stderr: stderr_buf,
             status,
+            limit,
         })
     }
+
+    /// Wait until a script exceeds the build limits, returning the exceeded limit.
+    ///
+    /// If no limits are configured, the returned future never completes.
+    async fn exceeded_limit(&self) -> LimitExceeded {
+        let timeout = async {
+            match self.timeout {
+                Some(timeout) => {
+                    tokio::time::sleep(timeout.as_duration()).await;
+                    LimitExceeded::Timeout(timeout)
+                }
+                None => std::future::pending().await,
+            }
+        };
+
+        let output_size = async {
+            match &self.max_output_size {
+                Some((max_output_size, scratch)) => loop {
+                    tokio::time::sleep(OUTPUT_SIZE_INTERVAL).await;
+                    let scratch = scratch.clone();
+                    let size = tokio::task::spawn_blocking(move || directory_size(&scratch))
+                        .await
+                        .unwrap_or_default();
+                    if size > max_output_size.as_bytes() {
+                        break LimitExceeded::OutputSize(*max_output_size);
+                    }
+                },
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            limit = timeout => limit,
+            limit = output_size => limit,
+        }
+    }
+}
+
+/// Return the total size of the files in a directory, in bytes.
+fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(std::fs::Metadata::is_file)
+        .map(|metadata| metadata.len())
+        .sum()
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -0,0 +1,206 @@
+//! Tracking for build backends that run in their own process group.
+//!
+//! When a build limit is configured, the build backend is spawned in a separate process group, such
+//! that any processes it spawns can be terminated along with it. As a consequence, the backend no
+//! longer receives the `SIGINT` that the terminal sends to uv's process group on `Ctrl-C`. To avoid
+//! leaking those processes, the group is killed when the [`ProcessGroup`] is dropped (e.g., if the
+//! build future is cancelled), and a `SIGINT` handler kills all tracked groups before forwarding
+//! the interrupt to whichever handler was installed before it (e.g., the handler that reverts the
+//! changes made by `uv add`). The handler is only installed while at least one group is tracked.
+
+#[cfg(unix)]
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use tokio::process::Child;
+
+/// A process group led by a build backend, which is killed on drop unless released.
+#[derive(Debug)]
+pub(crate) struct ProcessGroup {
+    #[cfg(unix)]
+    pgid: Option<i32>,
+    #[cfg(unix)]
+    slot: Option<usize>,
+}
+
+impl ProcessGroup {
+    /// Track the process group led by the given child, which must have been spawned with
+    /// `process_group(0)`.
+    pub(crate) fn new(child: &Child) -> Self {
+        #[cfg(unix)]
+        {
+            let pgid = child.id().and_then(|pid| i32::try_from(pid).ok());
+            let slot = pgid.and_then(unix::register);
+            Self { pgid, slot }
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = child;
+            Self {}
+        }
+    }
+
+    /// Kill every process in the group.
+    pub(crate) fn kill(&self) {
+        #[cfg(unix)]
+        if let Some(pgid) = self.pgid {
+            let _ = nix::sys::signal::killpg(
+                nix::unistd::Pid::from_raw(pgid),
+                nix::sys::signal::Signal::SIGKILL,
+            );
+        }
+    }
+
+    /// Stop tracking the group without killing it, e.g., once the backend exited on its own.
+    pub(crate) fn release(self) {
+        #[cfg(unix)]
+        {
+            let mut group = self;
+            group.pgid = None;
+        }
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        self.kill();
+        #[cfg(unix)]
+        if let Some(slot) = self.slot.take() {
+            unix::unregister(slot);
+        }
+    }
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicU8, AtomicUsize};
+
+    use nix::libc::{self, c_int, c_void, siginfo_t};
+    use nix::sys::signal::{SaFlags, SigAction, SigHandler, SigSet, Signal};
+    use nix::unistd::Pid;
+
+    use super::{AtomicI32, Ordering};
+
+    /// The maximum number of process groups that are killed on interrupt.
+    const MAX_GROUPS: usize = 256;
+
+    /// The tracked process groups, read from the signal handler; unused slots are zero.
+    static GROUPS: [AtomicI32; MAX_GROUPS] = [const { AtomicI32::new(0) }; MAX_GROUPS];
+
+    /// The number of tracked groups, along with the `SIGINT` disposition to restore once there are
+    /// none left.
+    static ACTIVE: Mutex<(usize, Option<libc::sigaction>)> = Mutex::new((0, None));
+
+    /// Whether the previous `SIGINT` handler expects `siginfo_t`, read from the signal handler.
+    static PREVIOUS_SIGINFO: AtomicU8 = AtomicU8::new(0);
+
+    /// The previous `SIGINT` handler (or `SIG_DFL` / `SIG_IGN`), read from the signal handler.
+    static PREVIOUS_HANDLER: AtomicUsize = AtomicUsize::new(libc::SIG_DFL);
+
+    /// Track a process group, returning its slot.
+    pub(super) fn register(pgid: i32) -> Option<usize> {
+        let mut active = ACTIVE.lock().unwrap();
+        let slot = GROUPS.iter().position(|group| {
+            group
+                .compare_exchange(0, pgid, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+        })?;
+        if active.0 == 0 {
+            // Record the existing handler before installing ours, such that the interrupt can be
+            // forwarded to it (e.g., to revert the changes made by `uv add`).
+            let previous = current_action()?;
+            PREVIOUS_HANDLER.store(previous.sa_sigaction, Ordering::SeqCst);
+            PREVIOUS_SIGINFO.store(
+                u8::from(previous.sa_flags & libc::SA_SIGINFO != 0),
+                Ordering::SeqCst,
+            );
+
+            let action = SigAction::new(
+                SigHandler::SigAction(handle_interrupt),
+                SaFlags::SA_RESTART | SaFlags::SA_SIGINFO,
+                SigSet::empty(),
+            );
+            // SAFETY: The handler only performs async-signal-safe operations: atomic loads,
+            // `killpg`, `signal`, and `raise`, before forwarding to the previous handler.
+            #[allow(unsafe_code)]
+            let _ = unsafe { nix::sys::signal::sigaction(Signal::SIGINT, &action) };
+            active.1 = Some(previous);
+        }
+        active.0 += 1;
+        Some(slot)
+    }
+
+    /// Stop tracking the process group in the given slot.
+    pub(super) fn unregister(slot: usize) {
+        let mut active = ACTIVE.lock().unwrap();
+        GROUPS[slot].store(0, Ordering::SeqCst);
+        active.0 -= 1;
+        if active.0 == 0 {
+            if let Some(previous) = active.1.take() {
+                // If another handler replaced ours in the meantime, keep it in place.
+                let ours = handle_interrupt as usize;
+                if current_action().is_some_and(|current| current.sa_sigaction == ours) {
+                    // SAFETY: Restores the disposition that was in place before the first group
+                    // was registered.
+                    #[allow(unsafe_code)]
+                    unsafe {
+                        libc::sigaction(libc::SIGINT, &raw const previous, std::ptr::null_mut());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Return the current `SIGINT` disposition, without modifying it.
+    fn current_action() -> Option<libc::sigaction> {
+        let mut action = std::mem::MaybeUninit::<libc::sigaction>::uninit();
+        // SAFETY: A null `act` only queries the disposition, which is written to `action`.
+        #[allow(unsafe_code)]
+        unsafe {
+            if libc::sigaction(libc::SIGINT, std::ptr::null(), action.as_mut_ptr()) != 0 {
+                return None;
+            }
+            Some(action.assume_init())
+        }
+    }
+
+    /// Kill all tracked process groups, then forward the interrupt to the previous handler, or
+    /// terminate uv if there was none.
+    extern "C" fn handle_interrupt(signal: c_int, info: *mut siginfo_t, context: *mut c_void) {
+        for group in &GROUPS {
+            let pgid = group.load(Ordering::SeqCst);
+            if pgid != 0 {
+                let _ = nix::sys::signal::killpg(Pid::from_raw(pgid), Signal::SIGKILL);
+            }
+        }
+
+        let handler = PREVIOUS_HANDLER.load(Ordering::SeqCst);
+        if handler == libc::SIG_IGN {
+            return;
+        }
+        if handler == libc::SIG_DFL {
+            // SAFETY: Resetting to the default disposition is async-signal-safe.
+            #[allow(unsafe_code)]
+            let _ = unsafe { nix::sys::signal::signal(Signal::SIGINT, SigHandler::SigDfl) };
+            let _ = nix::sys::signal::raise(Signal::SIGINT);
+            return;
+        }
+        if PREVIOUS_SIGINFO.load(Ordering::SeqCst) != 0 {
+            // SAFETY: The previous handler was installed with `SA_SIGINFO`, so it takes the
+            // signal information and context.
+            #[allow(unsafe_code)]
+            let handler = unsafe {
+                std::mem::transmute::<usize, extern "C" fn(c_int, *mut siginfo_t, *mut c_void)>(
+                    handler,
+                )
+            };
+            handler(signal, info, context);
+        } else {
+            // SAFETY: The previous handler was installed without `SA_SIGINFO`, so it only takes
+            // the signal number.
+            #[allow(unsafe_code)]
+            let handler = unsafe { std::mem::transmute::<usize, extern "C" fn(c_int)>(handler) };
+            handler(signal);
+        }
+    }
+}
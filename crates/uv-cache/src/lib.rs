@@ -11,8 +11,11 @@ use tracing::debug;
 
 pub use archive::ArchiveId;
 use uv_cache_info::Timestamp;
+use uv_cache_key::cache_digest;
+use uv_distribution_types::IndexUrl;
 use uv_fs::{LockedFile, cachedir, directories};
 use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pypi_types::ResolutionMetadata;
 
 pub use crate::by_timestamp::CachedByTimestamp;
@@ -20,6 +23,7 @@ pub use crate::by_timestamp::CachedByTimestamp;
 pub use crate::cli::CacheArgs;
 use crate::removal::Remover;
 pub use crate::removal::{Removal, rm_rf};
+pub use crate::size::CacheSize;
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -28,6 +32,7 @@ mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
 mod removal;
+mod size;
 mod wheel;
 
 /// The version of the archive bucket.
@@ -370,30 +375,108 @@ impl Cache {
         Remover::new(reporter).rm_rf(&self.root)
     }
 
+    /// Compute the disk usage of the cache.
+    pub fn size(&self) -> Result<CacheSize, io::Error> {
+        if !self.root.exists() {
+            return Ok(CacheSize::default());
+        }
+        CacheSize::from_path(&self.root)
+    }
+
     /// Remove a package from the cache.
     ///
+    /// If a [`CacheBucket`] is provided, only entries in that bucket are removed.
+    ///
     /// Returns the number of entries removed from the cache.
-    pub fn remove(&self, name: &PackageName) -> Result<Removal, io::Error> {
+    pub fn remove(
+        &self,
+        name: &PackageName,
+        bucket: Option<CacheBucket>,
+    ) -> Result<Removal, io::Error> {
         // Collect the set of referenced archives.
         let references = self.find_archive_references()?;
 
         // Remove any entries for the package from the cache.
         let mut summary = Removal::default();
-        for bucket in CacheBucket::iter() {
+        for bucket in CacheBucket::iter().filter(|candidate| bucket.is_none_or(|b| b == *candidate))
+        {
             summary += bucket.remove(self, name)?;
         }
 
         // Remove any archives that are no longer referenced.
+        summary += Self::remove_dangling_archives(references)?;
+
+        Ok(summary)
+    }
+
+    /// Remove all entries fetched from the given index from the cache.
+    ///
+    /// Registry metadata, wheels, and source distributions are namespaced by index URL, so
+    /// entries fetched from any other index are retained.
+    pub fn remove_index(&self, index: &IndexUrl) -> Result<Removal, io::Error> {
+        // Collect the set of referenced archives.
+        let references = self.find_archive_references()?;
+
+        let mut summary = Removal::default();
+        let namespace = WheelCache::Index(index).root();
+        for bucket in [
+            CacheBucket::Simple,
+            CacheBucket::Wheels,
+            CacheBucket::SourceDistributions,
+        ] {
+            summary += rm_rf(self.bucket(bucket).join(&namespace))?;
+        }
+
+        // If the index was also used as a `--find-links` source, remove its flat index entry.
+        summary += rm_rf(
+            self.bucket(CacheBucket::FlatIndex)
+                .join("html")
+                .join(format!(
+                    "{}.msgpack",
+                    cache_digest(&index.url().to_string())
+                )),
+        )?;
+
+        // Remove any archives that are no longer referenced.
+        summary += Self::remove_dangling_archives(references)?;
+
+        Ok(summary)
+    }
+
+    /// Remove any archives for which all references have been removed.
+    fn remove_dangling_archives(
+        references: FxHashMap<PathBuf, Vec<PathBuf>>,
+    ) -> Result<Removal, io::Error> {
+        let mut summary = Removal::default();
         for (target, references) in references {
             if references.iter().all(|path| !path.exists()) {
                 debug!("Removing dangling cache entry: {}", target.display());
                 summary += rm_rf(target)?;
             }
         }
-
         Ok(summary)
     }
 
+    /// Find the entries for a package in the cache.
+    ///
+    /// If a [`Version`] is provided, only entries for that version are returned. If a
+    /// [`CacheBucket`] is provided, only that bucket is searched.
+    ///
+    /// Returns the paths of all matching entries, which may be files or directories.
+    pub fn find(
+        &self,
+        name: &PackageName,
+        version: Option<&Version>,
+        bucket: Option<CacheBucket>,
+    ) -> Result<Vec<PathBuf>, io::Error> {
+        let mut paths = Vec::new();
+        for bucket in CacheBucket::iter().filter(|candidate| bucket.is_none_or(|b| b == *candidate))
+        {
+            paths.extend(bucket.find(self, name, version)?);
+        }
+        Ok(paths)
+    }
+
     /// Run the garbage collector on the cache, removing any dangling entries.
     pub fn prune(&self, ci: bool) -> Result<Removal, io::Error> {
         let mut summary = Removal::default();
@@ -498,7 +581,8 @@ impl Cache {
             }
         }
 
-        // Fourth, remove any unused archives (by searching for archives that are not symlinked).
+        // Fourth, remove any unused archives (by searching for archives that are not symlinked),
+        // unless they're hardlinked into an environment.
         let references = self.find_archive_references()?;
 
         match fs_err::read_dir(self.bucket(CacheBucket::Archive)) {
@@ -507,6 +591,12 @@ impl Cache {
                     let entry = entry?;
                     let path = fs_err::canonicalize(entry.path())?;
                     if !references.contains_key(&path) {
+                        // Retain any archives that are hardlinked into an environment, such that
+                        // subsequent installs can continue to share the same files.
+                        if size::is_hardlinked(&path)? {
+                            debug!("Retaining hardlinked cache archive: {}", path.display());
+                            continue;
+                        }
                         debug!("Removing dangling cache archive: {}", path.display());
                         summary += rm_rf(path)?;
                     }
@@ -1017,17 +1107,6 @@ impl CacheBucket {
     ///
     /// Returns the number of entries removed from the cache.
     fn remove(self, cache: &Cache, name: &PackageName) -> Result<Removal, io::Error> {
-        /// Returns `true` if the [`Path`] represents a built wheel for the given package.
-        fn is_match(path: &Path, name: &PackageName) -> bool {
-            let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
-                return false;
-            };
-            let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
-                return false;
-            };
-            metadata.name == *name
-        }
-
         let mut summary = Removal::default();
         match self {
             Self::Wheels => {
@@ -1066,7 +1145,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Url);
                 for url in directories(root)? {
-                    if directories(&url)?.any(|version| is_match(&version, name)) {
+                    if directories(&url)?.any(|version| is_built_wheel(&version, name, None)) {
                         summary += rm_rf(url)?;
                     }
                 }
@@ -1076,7 +1155,7 @@ impl CacheBucket {
                 // search for a wheel matching the package name.
                 let root = cache.bucket(self).join(WheelCacheKind::Path);
                 for path in directories(root)? {
-                    if directories(&path)?.any(|version| is_match(&version, name)) {
+                    if directories(&path)?.any(|version| is_built_wheel(&version, name, None)) {
                         summary += rm_rf(path)?;
                     }
                 }
@@ -1087,7 +1166,7 @@ impl CacheBucket {
                 let root = cache.bucket(self).join(WheelCacheKind::Git);
                 for repository in directories(root)? {
                     for sha in directories(repository)? {
-                        if is_match(&sha, name) {
+                        if is_built_wheel(&sha, name, None) {
                             summary += rm_rf(sha)?;
                         }
                     }
@@ -1123,6 +1202,130 @@ impl CacheBucket {
         Ok(summary)
     }
 
+    /// Find the entries for a package in the cache bucket.
+    ///
+    /// Mirrors the layout traversed by [`CacheBucket::remove`].
+    fn find(
+        self,
+        cache: &Cache,
+        name: &PackageName,
+        version: Option<&Version>,
+    ) -> Result<Vec<PathBuf>, io::Error> {
+        /// Returns the entries within a package directory that match the given version.
+        ///
+        /// Registry entries are keyed by version (source distributions) or by a cache key that
+        /// starts with the version (wheels).
+        fn versions(
+            directory: PathBuf,
+            version: Option<&Version>,
+        ) -> Result<Vec<PathBuf>, io::Error> {
+            if !directory.exists() {
+                return Ok(Vec::new());
+            }
+            let Some(version) = version else {
+                return Ok(vec![directory]);
+            };
+            let version = version.to_string();
+            let mut paths = Vec::new();
+            for entry in fs_err::read_dir(&directory)? {
+                let entry = entry?;
+                let file_name = entry.file_name();
+                let Some(file_name) = file_name.to_str() else {
+                    continue;
+                };
+                if file_name == version
+                    || file_name
+                        .strip_prefix(version.as_str())
+                        .is_some_and(|rest| rest.starts_with(['-', '.']))
+                {
+                    paths.push(entry.path());
+                }
+            }
+            paths.sort();
+            Ok(paths)
+        }
+
+        let mut paths = Vec::new();
+        match self {
+            Self::Wheels => {
+                // For `pypi` wheels, we expect a directory per package (indexed by name).
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                paths.extend(versions(root.join(name.to_string()), version)?);
+
+                // For alternate indices and direct URLs, we expect a directory for every index or
+                // URL, followed by a directory per package (indexed by name).
+                for kind in [WheelCacheKind::Index, WheelCacheKind::Url] {
+                    let root = cache.bucket(self).join(kind);
+                    for directory in directories(root)? {
+                        paths.extend(versions(directory.join(name.to_string()), version)?);
+                    }
+                }
+            }
+            Self::SourceDistributions => {
+                // For `pypi` and alternate indices, we expect a directory per package (indexed by
+                // name), followed by a directory per version.
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                paths.extend(versions(root.join(name.to_string()), version)?);
+
+                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                for directory in directories(root)? {
+                    paths.extend(versions(directory.join(name.to_string()), version)?);
+                }
+
+                // For direct URLs and local dependencies, we expect a directory for every URL or
+                // path, followed by a directory per version, which we need to inspect to determine
+                // the package name.
+                for kind in [WheelCacheKind::Url, WheelCacheKind::Path] {
+                    let root = cache.bucket(self).join(kind);
+                    for directory in directories(root)? {
+                        for revision in directories(&directory)? {
+                            if is_built_wheel(&revision, name, version) {
+                                paths.push(revision);
+                            }
+                        }
+                    }
+                }
+
+                // For Git dependencies, we expect a directory for every repository, followed by a
+                // directory for every SHA.
+                let root = cache.bucket(self).join(WheelCacheKind::Git);
+                for repository in directories(root)? {
+                    for sha in directories(repository)? {
+                        if is_built_wheel(&sha, name, version) {
+                            paths.push(sha);
+                        }
+                    }
+                }
+            }
+            Self::Simple => {
+                // The registry metadata is stored per package, covering all versions.
+                let root = cache.bucket(self).join(WheelCacheKind::Pypi);
+                let path = root.join(format!("{name}.rkyv"));
+                if path.exists() {
+                    paths.push(path);
+                }
+
+                let root = cache.bucket(self).join(WheelCacheKind::Index);
+                for directory in directories(root)? {
+                    let path = directory.join(format!("{name}.rkyv"));
+                    if path.exists() {
+                        paths.push(path);
+                    }
+                }
+            }
+            Self::FlatIndex
+            | Self::Git
+            | Self::Interpreter
+            | Self::Archive
+            | Self::Builds
+            | Self::Environments
+            | Self::Python => {
+                // Entries aren't indexed by package name.
+            }
+        }
+        Ok(paths)
+    }
+
     /// Return an iterator over all cache buckets.
     pub fn iter() -> impl Iterator<Item = Self> {
         [
@@ -1141,6 +1344,18 @@ impl CacheBucket {
     }
 }
 
+/// Returns `true` if the [`Path`] represents a built wheel for the given package (and version, if
+/// provided).
+fn is_built_wheel(path: &Path, name: &PackageName, version: Option<&Version>) -> bool {
+    let Ok(metadata) = fs_err::read(path.join("metadata.msgpack")) else {
+        return false;
+    };
+    let Ok(metadata) = rmp_serde::from_slice::<ResolutionMetadata>(&metadata) else {
+        return false;
+    };
+    metadata.name == *name && version.is_none_or(|version| metadata.version == *version)
+}
+
 impl Display for CacheBucket {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.to_str())
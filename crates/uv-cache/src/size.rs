@@ -0,0 +1,115 @@
+use std::fs::Metadata;
+use std::io;
+use std::path::Path;
+
+use rustc_hash::FxHashSet;
+
+/// The disk usage of the cache, accounting for files that are hardlinked into virtual
+/// environments.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CacheSize {
+    /// The number of bytes that would be used if every hardlinked file were a separate copy.
+    pub logical_bytes: u64,
+    /// The number of bytes actually used by the cache, counting each file once, regardless of
+    /// how many times it's linked.
+    pub physical_bytes: u64,
+}
+
+impl CacheSize {
+    /// Compute the [`CacheSize`] of the directory at the given path.
+    pub fn from_path(path: &Path) -> io::Result<Self> {
+        let mut size = Self::default();
+        let mut seen = FxHashSet::default();
+        for entry in walkdir::WalkDir::new(path) {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let metadata = entry.metadata()?;
+
+            // Count each inode once, even if it's linked multiple times within the cache.
+            if inode(&metadata).is_none_or(|inode| seen.insert(inode)) {
+                size.logical_bytes += metadata.len() * link_count(entry.path(), &metadata)?;
+                size.physical_bytes += metadata.len();
+            }
+        }
+        Ok(size)
+    }
+
+    /// The number of bytes saved by hardlinking files from the cache.
+    pub fn shared_bytes(&self) -> u64 {
+        self.logical_bytes.saturating_sub(self.physical_bytes)
+    }
+}
+
+/// Returns `true` if any file in the given directory is hardlinked elsewhere (e.g., into a
+/// virtual environment installed with `--link-mode hardlink`).
+pub(crate) fn is_hardlinked(path: &Path) -> io::Result<bool> {
+    for entry in walkdir::WalkDir::new(path) {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if link_count(entry.path(), &entry.metadata()?)? > 1 {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Return the number of hardlinks to the file.
+#[cfg(unix)]
+#[allow(clippy::unnecessary_wraps)]
+fn link_count(_path: &Path, metadata: &Metadata) -> io::Result<u64> {
+    use std::os::unix::fs::MetadataExt;
+    Ok(metadata.nlink())
+}
+
+/// Return the number of hardlinks to the file.
+///
+/// The link count isn't exposed by [`Metadata`] on stable Rust on Windows, so it's read from the
+/// file's handle instead.
+#[cfg(windows)]
+#[allow(unsafe_code)] // We need to do an FFI call through the windows-* crates.
+fn link_count(path: &Path, _metadata: &Metadata) -> io::Result<u64> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::{
+        BY_HANDLE_FILE_INFORMATION, GetFileInformationByHandle,
+    };
+
+    let file = fs_err::File::open(path)?;
+    // SAFETY: `BY_HANDLE_FILE_INFORMATION` is a plain struct of integers, for which all-zeroes is
+    // a valid value.
+    let mut information: BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
+    // SAFETY: The handle is valid for the lifetime of `file`, and `information` is a valid,
+    // writable pointer.
+    let success =
+        unsafe { GetFileInformationByHandle(file.file().as_raw_handle(), &raw mut information) };
+    if success == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(u64::from(information.nNumberOfLinks))
+}
+
+/// Return the number of hardlinks to the file.
+///
+/// The link count isn't available on this platform, so every file is assumed to have a single
+/// link.
+#[cfg(not(any(unix, windows)))]
+#[allow(clippy::unnecessary_wraps)]
+fn link_count(_path: &Path, _metadata: &Metadata) -> io::Result<u64> {
+    Ok(1)
+}
+
+/// Return a unique identifier for the file's underlying inode, if available.
+#[cfg(unix)]
+fn inode(metadata: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+/// Return a unique identifier for the file's underlying inode, if available.
+#[cfg(not(unix))]
+fn inode(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
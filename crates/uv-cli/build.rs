@@ -17,18 +17,20 @@ fn main() {
         .expect("CARGO_MANIFEST_DIR should be doubly nested in workspace")
         .to_path_buf();
 
-    commit_info(&workspace_root);
+    let commit_date = commit_info(&workspace_root);
+    build_date(commit_date.as_deref());
 
     #[allow(clippy::disallowed_methods)]
     let target = std::env::var(EnvVars::TARGET).unwrap();
     println!("cargo:rustc-env=RUST_HOST_TARGET={target}");
 }
 
-fn commit_info(workspace_root: &Path) {
+/// Emit the commit information as environment variables, returning the commit date, if any.
+fn commit_info(workspace_root: &Path) -> Option<String> {
     // If not in a git repository, do not attempt to retrieve commit information
     let git_dir = workspace_root.join(".git");
     if !git_dir.exists() {
-        return;
+        return None;
     }
 
     if let Some(git_head_path) = git_head(&git_dir) {
@@ -59,7 +61,7 @@ fn commit_info(workspace_root: &Path) {
         .output()
     {
         Ok(output) if output.status.success() => output,
-        _ => return,
+        _ => return None,
     };
     let stdout = String::from_utf8(output.stdout).unwrap();
     let mut parts = stdout.split_whitespace();
@@ -70,7 +72,8 @@ fn commit_info(workspace_root: &Path) {
         EnvVars::UV_COMMIT_SHORT_HASH,
         next()
     );
-    println!("cargo:rustc-env={}={}", EnvVars::UV_COMMIT_DATE, next());
+    let commit_date = next().to_string();
+    println!("cargo:rustc-env={}={commit_date}", EnvVars::UV_COMMIT_DATE);
 
     // Describe can fail for some commits
     // https://git-scm.com/docs/pretty-formats#Documentation/pretty-formats.txt-emdescribeoptionsem
@@ -88,6 +91,47 @@ fn commit_info(workspace_root: &Path) {
             describe_parts.next().unwrap_or("0")
         );
     }
+
+    Some(commit_date)
+}
+
+/// Emit the build date, if it can be determined reproducibly.
+///
+/// Uses `SOURCE_DATE_EPOCH` if set, falling back to the commit date. The current time is never
+/// used, since it would make the build output depend on when it was built.
+fn build_date(commit_date: Option<&str>) {
+    // Respect `SOURCE_DATE_EPOCH` for reproducible builds.
+    // See: <https://reproducible-builds.org/specs/source-date-epoch/>
+    println!("cargo:rerun-if-env-changed={}", EnvVars::SOURCE_DATE_EPOCH);
+    if let Some(seconds) = std::env::var(EnvVars::SOURCE_DATE_EPOCH)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+    {
+        let (year, month, day) = civil_from_days(seconds / 86_400);
+        println!(
+            "cargo:rustc-env={}={year:04}-{month:02}-{day:02}",
+            EnvVars::UV_BUILD_DATE
+        );
+    } else if let Some(commit_date) = commit_date {
+        println!("cargo:rustc-env={}={commit_date}", EnvVars::UV_BUILD_DATE);
+    }
+}
+
+/// Convert a number of days since the Unix epoch to a `(year, month, day)` date.
+///
+/// See: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
 }
 
 fn git_head(git_dir: &Path) -> Option<PathBuf> {
@@ -8,15 +8,16 @@ use clap::builder::Styles;
 use clap::builder::styling::{AnsiColor, Effects, Style};
 use clap::{Args, Parser, Subcommand};
 
-use uv_cache::CacheArgs;
+use uv_cache::{CacheArgs, CacheBucket};
 use uv_configuration::{
     ConfigSettingEntry, ConfigSettingPackageEntry, ExportFormat, IndexStrategy,
-    KeyringProviderType, PackageNameSpecifier, PreviewFeatures, ProjectBuildBackend, TargetTriple,
-    TrustedHost, TrustedPublishing, VersionControlSystem,
+    KeyringProviderType, PackageNameSpecifier, PipCompileFormat, PreviewFeatures,
+    ProjectBuildBackend, ScriptTemplate, TargetTriple, TrustedHost, TrustedPublishing,
+    UpgradeStrategy, VersionControlSystem,
 };
 use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName, PipGroupName};
-use uv_pep508::{MarkerTree, Requirement};
+use uv_pep508::{MarkerTree, Requirement, uv_pep440::Version};
 use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
@@ -59,6 +60,26 @@ pub enum SyncFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum LockFormat {
+    /// Display the changes to the lockfile in a human-readable format.
+    #[default]
+    Text,
+    /// Display a report of the changes to the lockfile in JSON format.
+    Json,
+}
+
+/// A release segment of a version, per PEP 440.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReleaseSegment {
+    /// A change to the first release segment (e.g., `1.2.3` to `2.0.0`).
+    Major,
+    /// A change to the first or second release segment (e.g., `1.2.3` to `1.3.0`).
+    Minor,
+    /// A change to the first, second, or third release segment (e.g., `1.2.3` to `1.2.4`).
+    Patch,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -134,6 +155,23 @@ pub struct TopLevelArgs {
     #[arg(global = true, long, env = EnvVars::UV_NO_CONFIG, value_parser = clap::builder::BoolishValueParser::new(), help_heading = "Global options")]
     pub no_config: bool,
 
+    /// Continue discovering configuration files above the root of the workspace or repository.
+    ///
+    /// By default, the search for `pyproject.toml` and `uv.toml` files stops at the workspace root
+    /// or at the root of the enclosing Git repository (i.e., the first parent directory that
+    /// contains `.git`), whichever comes first, such that configuration files outside the workspace
+    /// or repository are not applied.
+    #[arg(global = true, long, env = EnvVars::UV_NO_CONFIG_BOUNDARY, value_parser = clap::builder::BoolishValueParser::new(), help_heading = "Global options")]
+    pub no_config_boundary: bool,
+
+    /// Ignore constraints and overrides declared in the user-level configuration file.
+    ///
+    /// By default, `constraint-dependencies` and `override-dependencies` in the user-level
+    /// `uv.toml` are applied in addition to those declared by the project, with the project's
+    /// declarations taking precedence for any given package.
+    #[arg(global = true, long, env = EnvVars::UV_NO_USER_CONSTRAINTS, value_parser = clap::builder::BoolishValueParser::new(), help_heading = "Global options")]
+    pub no_user_constraints: bool,
+
     /// Display the concise help for this command.
     #[arg(global = true, short, long, action = clap::ArgAction::HelpShort, help_heading = "Global options")]
     help: Option<bool>,
@@ -276,6 +314,26 @@ pub struct GlobalArgs {
     )]
     pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
 
+    /// The number of times to retry a network request that failed with a transient error.
+    ///
+    /// Connection failures, HTTP 5xx server errors, and HTTP 429 rate limits are retried with
+    /// exponential backoff, waiting at least as long as requested by a `Retry-After` header (up
+    /// to 60 seconds). Errors that won't resolve on retry, such as HTTP 404 or authentication
+    /// failures, are reported immediately.
+    ///
+    /// Defaults to 3.
+    #[arg(global = true, long, env = EnvVars::UV_HTTP_RETRIES)]
+    pub retries: Option<u32>,
+
+    /// The base of the exponential backoff between retries of failed network requests.
+    ///
+    /// The delay before the n-th retry grows as `base^n`, up to a maximum interval. Increase this
+    /// value to back off more aggressively from an overloaded index.
+    ///
+    /// Defaults to 2.
+    #[arg(global = true, long, env = EnvVars::UV_HTTP_RETRY_BACKOFF)]
+    pub retry_backoff: Option<u32>,
+
     /// Whether to enable all experimental preview features.
     ///
     /// Preview features may change without warning.
@@ -319,12 +377,33 @@ pub struct GlobalArgs {
     #[arg(global = true, long, hide = true)]
     pub show_settings: bool,
 
+    /// Write a structured log of the resolver's decisions to the given file.
+    ///
+    /// Each line of the file is a JSON object describing a single event: a candidate version
+    /// that was considered, a version that was rejected (along with the constraint and the chain
+    /// of requirements that imposed it), a fork, a backtrack, or a version that was selected. The
+    /// log is written even if resolution fails.
+    ///
+    /// This option is used for debugging purposes.
+    #[arg(global = true, long, hide = true, env = EnvVars::UV_RESOLUTION_LOG)]
+    pub resolution_log: Option<PathBuf>,
+
     /// Hide all progress outputs.
     ///
     /// For example, spinners or progress bars.
     #[arg(global = true, long, env = EnvVars::UV_NO_PROGRESS, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_progress: bool,
 
+    /// The format in which to display progress.
+    ///
+    /// By default, progress is displayed as human-readable spinners and progress bars. With
+    /// `json`, the progress bars are replaced by newline-delimited JSON events on stderr, with
+    /// one event per line for the start, progress, and completion of each resolve, download,
+    /// build, and install. Other output, such as warnings, may still be written to stderr
+    /// alongside the JSON events.
+    #[arg(global = true, long, value_enum, env = EnvVars::UV_PROGRESS)]
+    pub progress: Option<ProgressFormat>,
+
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and `direct_url.json`) to site-packages `.dist-info` directories.
     #[arg(global = true, long, hide = true, env = EnvVars::UV_NO_INSTALLER_METADATA, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_installer_metadata: bool,
@@ -383,6 +462,16 @@ impl ColorChoice {
     }
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProgressFormat {
+    /// Display human-readable spinners and progress bars.
+    #[default]
+    Auto,
+
+    /// Display machine-readable progress events as newline-delimited JSON.
+    Json,
+}
+
 impl From<ColorChoice> for anstream::ColorChoice {
     fn from(value: ColorChoice) -> Self {
         match value {
@@ -503,6 +592,9 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage workspaces.
+    #[command(hide = true)]
+    Workspace(WorkspaceNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -717,7 +809,18 @@ pub enum SelfCommand {
 #[derive(Args, Debug)]
 pub struct SelfUpdateArgs {
     /// Update to the specified version. If not provided, uv will update to the latest version.
-    pub target_version: Option<String>,
+    pub target_version: Option<Version>,
+
+    /// The release channel from which to update, when no version is provided.
+    ///
+    /// The `stable` channel only considers stable releases, while the `preview` channel also
+    /// considers pre-releases.
+    #[arg(long, value_enum, conflicts_with = "target_version")]
+    pub channel: Option<SelfUpdateChannel>,
+
+    /// Allow updating to a version that's older than the current version.
+    #[arg(long)]
+    pub allow_downgrade: bool,
 
     /// A GitHub token for authentication.
     /// A token is not required but can be used to reduce the chance of encountering rate limits.
@@ -729,6 +832,30 @@ pub struct SelfUpdateArgs {
     pub dry_run: bool,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum SelfUpdateChannel {
+    /// Update to the latest stable release.
+    #[default]
+    Stable,
+    /// Update to the latest release, including pre-releases.
+    Preview,
+}
+
+#[derive(Args)]
+pub struct WorkspaceNamespace {
+    #[command(subcommand)]
+    pub command: WorkspaceCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommand {
+    /// List the members of the workspace.
+    ///
+    /// Shows each directory matched by the `members` entries of the workspace, along with the
+    /// `members` or `exclude` entry that determined whether the directory is a workspace member.
+    List,
+}
+
 #[derive(Args)]
 pub struct CacheNamespace {
     #[command(subcommand)]
@@ -755,13 +882,122 @@ pub enum CacheCommand {
     ///
     /// Note that it is important for performance for the cache directory to be located on the same
     /// file system as the Python environment uv is operating on.
-    Dir,
+    ///
+    /// When `--package` is provided, the paths of the cache entries for that package are shown
+    /// instead, one per line.
+    Dir(DirArgs),
+    /// Show the size of the cache.
+    ///
+    /// Displays the total size of the cache directory, in bytes.
+    Size(SizeArgs),
+    /// Show the cache entries for a package.
+    ///
+    /// Lists the entries for the package in each cache bucket, along with their size. For
+    /// registry metadata, the time at which the metadata was fetched and its `ETag` are shown;
+    /// for wheels and source distributions, the time at which the entry was last modified is
+    /// shown.
+    Info(InfoArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct DirArgs {
+    /// Show the cache entries for the given package, rather than the cache directory.
+    ///
+    /// Exits with a non-zero status if the package is not present in the cache.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    /// Only show the cache entries for the given version of the package.
+    ///
+    /// Registry metadata is shared across all versions of a package, and so is always shown.
+    #[arg(long, requires = "package")]
+    pub version: Option<Version>,
+
+    /// Only show the cache entries in the given bucket.
+    #[arg(long, value_enum, requires = "package")]
+    pub bucket: Option<CacheBucketName>,
+}
+
+#[derive(Args, Debug)]
+pub struct InfoArgs {
+    /// The package to show the cache entries for.
+    pub package: PackageName,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = CacheInfoFormat::default())]
+    pub output_format: CacheInfoFormat,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CacheInfoFormat {
+    /// Display the cache entries in a human-readable format.
+    #[default]
+    Text,
+    /// Display the cache entries in JSON format.
+    Json,
+}
+
+#[derive(Args, Debug)]
+pub struct SizeArgs {
+    /// Display the size in human-readable units (e.g., `1.2GiB`).
+    #[arg(long)]
+    pub human: bool,
+
+    /// Report the logical and physical size of the cache separately.
+    ///
+    /// When packages are installed with `--link-mode hardlink`, files in the cache are shared with
+    /// the virtual environments they're installed into. The logical size counts every linked copy
+    /// of each file, while the physical size counts each file once; the difference is the disk
+    /// space saved by sharing.
+    #[arg(long)]
+    pub shared: bool,
+}
+
+/// The buckets that can be selected via `uv cache dir --bucket` and `uv cache clean --bucket`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum CacheBucketName {
+    /// Built and downloaded wheels.
+    Wheels,
+    /// Source distributions, along with the wheels built from them.
+    Sdists,
+    /// Package metadata fetched from the registry.
+    Metadata,
+}
+
+impl From<CacheBucketName> for CacheBucket {
+    fn from(name: CacheBucketName) -> Self {
+        match name {
+            CacheBucketName::Wheels => Self::Wheels,
+            CacheBucketName::Sdists => Self::SourceDistributions,
+            CacheBucketName::Metadata => Self::Simple,
+        }
+    }
 }
 
 #[derive(Args, Debug)]
 pub struct CleanArgs {
     /// The packages to remove from the cache.
+    ///
+    /// Removes all cached wheels, source distributions, built wheels, and metadata for the given
+    /// packages, reporting the space reclaimed for each.
     pub package: Vec<PackageName>,
+
+    /// Only remove the cache entries in the given bucket.
+    ///
+    /// For example, `--bucket metadata` evicts the registry metadata for the given packages,
+    /// while retaining any downloaded or built wheels.
+    #[arg(long, value_enum, requires = "package")]
+    pub bucket: Option<CacheBucketName>,
+
+    /// Only remove the cache entries fetched from the given index URL.
+    ///
+    /// Removes the registry metadata, wheels, and source distributions that were fetched from the
+    /// index, while retaining the entries for all other indexes.
+    ///
+    /// The URL must match the index URL used during resolution (e.g., the value passed to
+    /// `--index-url` or `--index`).
+    #[arg(long, conflicts_with = "package")]
+    pub index: Option<IndexUrl>,
 }
 
 #[derive(Args, Debug)]
@@ -1004,6 +1240,16 @@ pub enum ProjectCommand {
     Export(ExportArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
+    /// Show why a package is required by the project.
+    ///
+    /// Prints every path from a root of the project (i.e., a workspace member, or one of its extras
+    /// or dependency groups) to the given package, along with the requirement, and any marker, on
+    /// each step of the path.
+    ///
+    /// By default, the paths are read from the `uv.lock` file, which is not updated. Use
+    /// `--environment` to read the metadata of the packages installed in the Python environment
+    /// instead.
+    Why(WhyArgs),
 }
 
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
@@ -1163,6 +1409,22 @@ fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
     }
 }
 
+/// Parse a string into a [`Requirement`] for `--upgrade-package`, rejecting extras.
+///
+/// The requirement may include a version specifier (e.g., `requests<2.32`), which is applied as a
+/// constraint for the current resolution.
+fn parse_upgrade_package(input: &str) -> Result<Requirement<VerbatimParsedUrl>, String> {
+    let requirement =
+        Requirement::<VerbatimParsedUrl>::from_str(input).map_err(|err| err.to_string())?;
+    if !requirement.extras.is_empty() {
+        return Err(format!(
+            "Extras are not supported in `--upgrade-package` (use `{}` instead)",
+            requirement.name
+        ));
+    }
+    Ok(requirement)
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 pub struct PipCompileArgs {
@@ -1232,6 +1494,18 @@ pub struct PipCompileArgs {
     #[arg(long, group = "sources")]
     pub group: Vec<PipGroupName>,
 
+    /// Only include the specified dependency group from a `pyproject.toml`.
+    ///
+    /// Requirements from all other sources (e.g., the dependencies of a `pyproject.toml` or the
+    /// contents of a `requirements.txt` file) are excluded. Constraints and overrides are still
+    /// respected.
+    ///
+    /// If no path is provided, the `pyproject.toml` in the working directory is used.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, group = "sources")]
+    pub only_group: Vec<PipGroupName>,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -1260,7 +1534,7 @@ pub struct PipCompileArgs {
     /// uv will infer the output format from the file extension of the output file, if
     /// provided. Otherwise, defaults to `requirements.txt`.
     #[arg(long, value_enum)]
-    pub format: Option<ExportFormat>,
+    pub format: Option<PipCompileFormat>,
 
     /// Include extras in the output file.
     ///
@@ -1400,6 +1674,13 @@ pub struct PipCompileArgs {
     #[arg(long, conflicts_with = "no_build")]
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
 
+    /// Allow building the given packages from source if no compatible wheel is available, even
+    /// when building from source is disabled (e.g., with `--only-binary :all:`).
+    ///
+    /// Pre-built wheels are still preferred for these packages. Multiple packages may be provided.
+    #[arg(long, value_name = "PACKAGE")]
+    pub allow_binary_fallback: Option<Vec<PackageName>>,
+
     /// The Python version to use for resolution.
     ///
     /// For example, `3.8` or `3.8.17`.
@@ -1445,6 +1726,27 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("universal"), hide = true)]
     pub no_universal: bool,
 
+    /// Alongside the universal output file, write a flat requirements file for each of the given
+    /// platforms.
+    ///
+    /// Each file is derived from the universal resolution by evaluating its markers for the
+    /// target platform, and is written next to the output file with the platform as a suffix
+    /// (e.g., `requirements-linux.txt`).
+    ///
+    /// Accepts a comma-separated list of target triples, like
+    /// `--split-by-platform=x86_64-unknown-linux-gnu,aarch64-apple-darwin`. Defaults to `linux`,
+    /// `macos`, and `windows`.
+    ///
+    /// Requires `--universal` and `--output-file`.
+    #[arg(
+        long,
+        value_name = "PLATFORMS",
+        num_args = 0..,
+        value_delimiter = ',',
+        require_equals = true
+    )]
+    pub split_by_platform: Option<Vec<TargetTriple>>,
+
     /// Specify a package to omit from the output resolution. Its dependencies will still be
     /// included in the resolution. Equivalent to pip-compile's `--unsafe-package` option.
     #[arg(long, alias = "unsafe-package")]
@@ -1464,7 +1766,12 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("emit_find_links"), hide = true)]
     pub no_emit_find_links: bool,
 
-    /// Include `--no-binary` and `--only-binary` entries in the generated output file.
+    /// Include `--no-binary`, `--only-binary`, and `--config-settings` entries in the generated
+    /// output file.
+    ///
+    /// Any `--config-settings` and `--config-settings-package` entries in the output file will
+    /// be applied when building source distributions during `uv pip sync` and `uv pip install`,
+    /// unless overridden on the command line.
     #[arg(long, overrides_with("no_emit_build_options"))]
     pub emit_build_options: bool,
 
@@ -1491,6 +1798,30 @@ pub struct PipCompileArgs {
     #[arg(long, overrides_with("emit_index_annotation"), hide = true)]
     pub no_emit_index_annotation: bool,
 
+    /// Resolve and include the build requirements for each source distribution in the output.
+    ///
+    /// For each source distribution in the resolution, uv will resolve the requirements from
+    /// `build-system.requires`, along with any requirements returned by the build backend's
+    /// `get_requires_for_build_wheel` hook. Packages that are resolved to wheels do not contribute
+    /// build requirements.
+    ///
+    /// The build requirements are emitted as a commented section at the end of the output, unless
+    /// `--emit-build-requirements-output` is provided.
+    #[arg(long, overrides_with("no_emit_build_requirements"))]
+    pub emit_build_requirements: bool,
+
+    #[arg(long, overrides_with("emit_build_requirements"), hide = true)]
+    pub no_emit_build_requirements: bool,
+
+    /// Write the resolved build requirements to the given file, rather than the main output.
+    ///
+    /// The resulting file can be passed to `--build-constraints` in subsequent invocations to pin
+    /// the build environments of any source distributions.
+    ///
+    /// Implies `--emit-build-requirements`.
+    #[arg(long, value_name = "FILE")]
+    pub emit_build_requirements_output: Option<PathBuf>,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -1581,10 +1912,11 @@ pub struct PipSyncArgs {
     ///
     /// Hash-checking mode introduces a number of additional constraints:
     ///
-    /// - Git dependencies are not supported.
-    /// - Editable installations are not supported.
+    /// - Git dependencies are not supported, unless `--allow-unhashable-sources` is provided.
+    /// - Editable installations are not supported, unless `--allow-unhashable-sources` is provided.
     /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
-    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory (or
+    ///   `--allow-unhashable-sources` is provided).
     #[arg(
         long,
         env = EnvVars::UV_REQUIRE_HASHES,
@@ -1596,6 +1928,28 @@ pub struct PipSyncArgs {
     #[arg(long, overrides_with("require_hashes"), hide = true)]
     pub no_require_hashes: bool,
 
+    /// Require a matching hash for each requirement, reporting any that are missing.
+    ///
+    /// Like `--require-hashes`, but rather than failing on the first requirement without a hash,
+    /// uv will resolve the requirements (including any transitive dependencies) and fail with a
+    /// list of every distribution that lacks a hash, along with its computed hashes, such that
+    /// they can be added to the requirements file.
+    #[arg(long, overrides_with("no_derive_hashes"))]
+    pub derive_hashes: bool,
+
+    #[arg(long, overrides_with("derive_hashes"), hide = true)]
+    pub no_derive_hashes: bool,
+
+    /// Allow Git dependencies and local directories when hashes are required.
+    ///
+    /// These requirements can't be hashed. With `--allow-unhashable-sources`, they're exempt from
+    /// `--require-hashes` (with a warning), though their dependencies must still be hashed.
+    #[arg(long, overrides_with("no_allow_unhashable_sources"))]
+    pub allow_unhashable_sources: bool,
+
+    #[arg(long, overrides_with("allow_unhashable_sources"), hide = true)]
+    pub no_allow_unhashable_sources: bool,
+
     #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
     pub verify_hashes: bool,
 
@@ -1725,6 +2079,13 @@ pub struct PipSyncArgs {
     #[arg(long, conflicts_with = "no_build")]
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
 
+    /// Allow building the given packages from source if no compatible wheel is available, even
+    /// when building from source is disabled (e.g., with `--only-binary :all:`).
+    ///
+    /// Pre-built wheels are still preferred for these packages. Multiple packages may be provided.
+    #[arg(long, value_name = "PACKAGE")]
+    pub allow_binary_fallback: Option<Vec<PackageName>>,
+
     /// Allow sync of empty requirements, which will clear the environment of all packages.
     #[arg(long, overrides_with("no_allow_empty_requirements"))]
     pub allow_empty_requirements: bool,
@@ -1770,6 +2131,14 @@ pub struct PipSyncArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Select the format in which to report the plan of a dry run.
+    ///
+    /// With `json`, the planned change for each package (one of `install`, `upgrade`,
+    /// `downgrade`, `reinstall`, `uninstall`, or `satisfied`) is written to stdout, along with
+    /// the currently installed version (`from`) and the version that would be installed (`to`).
+    #[arg(long, value_enum, default_value_t = SyncFormat::default(), requires = "dry_run")]
+    pub output_format: SyncFormat,
+
     /// The backend to use when fetching packages in the PyTorch ecosystem (e.g., `cpu`, `cu126`, or `auto`).
     ///
     /// When set, uv will ignore the configured index URLs for packages in the PyTorch ecosystem,
@@ -1866,9 +2235,30 @@ pub struct PipInstallArgs {
     #[arg(long, group = "sources")]
     pub group: Vec<PipGroupName>,
 
+    /// Only install the specified dependency group from a `pyproject.toml`.
+    ///
+    /// Requirements from all other sources (e.g., the dependencies of a `pyproject.toml` or the
+    /// contents of a `requirements.txt` file) are excluded. Constraints and overrides are still
+    /// respected.
+    ///
+    /// If no path is provided, the `pyproject.toml` in the working directory is used.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, group = "sources")]
+    pub only_group: Vec<PipGroupName>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
+    /// The strategy to use when upgrading packages with `--upgrade`.
+    ///
+    /// By default, uv will upgrade all packages, including the dependencies of the requested
+    /// packages (`eager`). Use `--upgrade-strategy only-if-needed` to upgrade the requested
+    /// packages, and upgrade their dependencies only if the installed versions no longer satisfy
+    /// the requested packages.
+    #[arg(long, value_enum, help_heading = "Resolver options")]
+    pub upgrade_strategy: Option<UpgradeStrategy>,
+
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
@@ -1891,10 +2281,11 @@ pub struct PipInstallArgs {
     ///
     /// Hash-checking mode introduces a number of additional constraints:
     ///
-    /// - Git dependencies are not supported.
-    /// - Editable installations are not supported.
+    /// - Git dependencies are not supported, unless `--allow-unhashable-sources` is provided.
+    /// - Editable installations are not supported, unless `--allow-unhashable-sources` is provided.
     /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
-    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory (or
+    ///   `--allow-unhashable-sources` is provided).
     #[arg(
         long,
         env = EnvVars::UV_REQUIRE_HASHES,
@@ -1906,6 +2297,28 @@ pub struct PipInstallArgs {
     #[arg(long, overrides_with("require_hashes"), hide = true)]
     pub no_require_hashes: bool,
 
+    /// Require a matching hash for each requirement, reporting any that are missing.
+    ///
+    /// Like `--require-hashes`, but rather than failing on the first requirement without a hash,
+    /// uv will resolve the requirements (including any transitive dependencies) and fail with a
+    /// list of every distribution that lacks a hash, along with its computed hashes, such that
+    /// they can be added to the requirements file.
+    #[arg(long, overrides_with("no_derive_hashes"))]
+    pub derive_hashes: bool,
+
+    #[arg(long, overrides_with("derive_hashes"), hide = true)]
+    pub no_derive_hashes: bool,
+
+    /// Allow Git dependencies and local directories when hashes are required.
+    ///
+    /// These requirements can't be hashed. With `--allow-unhashable-sources`, they're exempt from
+    /// `--require-hashes` (with a warning), though their dependencies must still be hashed.
+    #[arg(long, overrides_with("no_allow_unhashable_sources"))]
+    pub allow_unhashable_sources: bool,
+
+    #[arg(long, overrides_with("allow_unhashable_sources"), hide = true)]
+    pub no_allow_unhashable_sources: bool,
+
     #[arg(long, overrides_with("no_verify_hashes"), hide = true)]
     pub verify_hashes: bool,
 
@@ -2035,6 +2448,13 @@ pub struct PipInstallArgs {
     #[arg(long, conflicts_with = "no_build")]
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
 
+    /// Allow building the given packages from source if no compatible wheel is available, even
+    /// when building from source is disabled (e.g., with `--only-binary :all:`).
+    ///
+    /// Pre-built wheels are still preferred for these packages. Multiple packages may be provided.
+    #[arg(long, value_name = "PACKAGE")]
+    pub allow_binary_fallback: Option<Vec<PackageName>>,
+
     /// The minimum Python version that should be supported by the requirements (e.g., `3.7` or
     /// `3.7.9`).
     ///
@@ -2481,8 +2901,10 @@ pub struct BuildArgs {
     /// directory if no source directory is provided.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
+    ///
+    /// May be provided multiple times to build multiple packages.
     #[arg(long, conflicts_with("all_packages"))]
-    pub package: Option<PackageName>,
+    pub package: Vec<PackageName>,
 
     /// Builds all packages in the workspace.
     ///
@@ -2493,6 +2915,16 @@ pub struct BuildArgs {
     #[arg(long, alias = "all", conflicts_with("package"))]
     pub all_packages: bool,
 
+    /// Only build workspace members with changes since the given Git reference.
+    ///
+    /// A member is considered changed if any file under its root differs from the given reference
+    /// (e.g., a branch, tag, or commit), including uncommitted and untracked files. Files in nested
+    /// members are attributed to the nested member only.
+    ///
+    /// Applies to all workspace members, unless `--package` is provided.
+    #[arg(long, value_name = "REF")]
+    pub since: Option<String>,
+
     /// The output directory to which distributions should be written.
     ///
     /// Defaults to the `dist` subdirectory within the source directory, or the
@@ -2500,6 +2932,11 @@ pub struct BuildArgs {
     #[arg(long, short, value_parser = parse_file_path)]
     pub out_dir: Option<PathBuf>,
 
+    /// A template for the output directory of each package, in which `{name}` is replaced with
+    /// the normalized package name, e.g., `dist/{name}`.
+    #[arg(long, value_name = "TEMPLATE", conflicts_with("out_dir"))]
+    pub out_dir_template: Option<String>,
+
     /// Build a source distribution ("sdist") from the given directory.
     #[arg(long)]
     pub sdist: bool,
@@ -2535,6 +2972,15 @@ pub struct BuildArgs {
     #[arg(long, conflicts_with = "list")]
     pub force_pep517: bool,
 
+    /// Use the given Unix timestamp as the modification time of the files in the built
+    /// distributions, for reproducible builds.
+    ///
+    /// When using the uv build backend, all archive members are given this timestamp, such that
+    /// building the same source tree twice produces identical distributions. The timestamp is also
+    /// passed to other build backends through the `SOURCE_DATE_EPOCH` environment variable.
+    #[arg(long, env = EnvVars::SOURCE_DATE_EPOCH, value_name = "TIMESTAMP")]
+    pub source_date_epoch: Option<u64>,
+
     /// Constrain build dependencies using the given requirements files when building distributions.
     ///
     /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
@@ -2909,6 +3355,26 @@ pub struct InitArgs {
     #[arg(long, conflicts_with_all=["app", "lib", "package", "build_backend", "description"])]
     pub r#script: bool,
 
+    /// Add a dependency to the script's inline metadata.
+    ///
+    /// Accepts PEP 508 requirements, e.g., `rich` or `requests>=2`.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, requires = "script")]
+    pub with: Vec<Requirement<VerbatimParsedUrl>>,
+
+    /// The template to use for the body of the script.
+    ///
+    /// Only used when creating a new script; the contents of an existing file are preserved.
+    #[arg(long, value_enum, requires = "script")]
+    pub template: Option<ScriptTemplate>,
+
+    /// Replace the inline metadata of an existing PEP 723 script.
+    ///
+    /// By default, uv will refuse to initialize a script that already contains inline metadata.
+    #[arg(long, requires = "script")]
+    pub force: bool,
+
     /// Set the project description.
     #[arg(long, conflicts_with = "script", overrides_with = "no_description")]
     pub description: Option<String>,
@@ -3142,6 +3608,16 @@ pub struct RunArgs {
     #[arg(long, value_delimiter = ',', value_parser = parse_maybe_file_path)]
     pub with_requirements: Vec<Maybe<PathBuf>>,
 
+    /// Run with the dependencies from the given dependency group of the project installed.
+    ///
+    /// The group is read from the project's `pyproject.toml` and layered atop the project
+    /// environment, in which the project itself is installed as usual. The same environment
+    /// semantics as `--with` apply.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with = "no_project")]
+    pub with_group: Vec<GroupName>,
+
     /// Run the command in an isolated virtual environment.
     ///
     /// Usually, the project environment is reused for performance. This option forces a fresh
@@ -3261,6 +3737,17 @@ pub struct RunArgs {
     #[arg(long, env = EnvVars::UV_SHOW_RESOLUTION, value_parser = clap::builder::BoolishValueParser::new(), hide = true)]
     pub show_resolution: bool,
 
+    /// Display the environment that would be used to run the command, without running it.
+    ///
+    /// Prints the path to the Python interpreter, the kind of environment (e.g., the project
+    /// environment, a script environment, or an ephemeral environment layered atop another due to
+    /// `--with`), the project or script from which it was derived, the enabled extras and
+    /// dependency groups, and whether the lockfile was up-to-date.
+    ///
+    /// The environment is still created and synchronized as it would be for the command.
+    #[arg(long)]
+    pub show_environment: bool,
+
     /// Number of times that `uv run` will allow recursive invocations.
     ///
     /// The current recursion depth is tracked by environment variable. If environment variables are
@@ -3398,6 +3885,18 @@ pub struct SyncArgs {
     #[arg(long, overrides_with = "active", hide = true)]
     pub no_active: bool,
 
+    /// Make the project's virtual environment relocatable.
+    ///
+    /// If the project environment does not exist, it will be created as a relocatable environment,
+    /// as with `uv venv --relocatable`. If it exists but is not relocatable, it will be recreated.
+    ///
+    /// Entrypoints are written with relative shebangs, such that the environment can be moved
+    /// (e.g., built at one path in a container image and run at another). Editable installs
+    /// reference their source tree by absolute path and cannot be relocated; a warning is
+    /// emitted if any are installed.
+    #[arg(long, conflicts_with_all = ["script", "active"])]
+    pub relocatable: bool,
+
     /// Do not install the current project.
     ///
     /// By default, the current project is installed into the environment with all of its
@@ -3501,6 +4000,16 @@ pub struct SyncArgs {
     )]
     pub script: Option<PathBuf>,
 
+    /// Sync a separate environment for each Python version in `tool.uv.test-pythons`.
+    ///
+    /// Each environment is created at `.venv-<version>` in the workspace root and installed from
+    /// the same lockfile. Managed Python versions are downloaded as needed.
+    ///
+    /// If any of the requested versions is incompatible with the project, uv will exit with an
+    /// error before syncing any environments.
+    #[arg(long, conflicts_with_all = ["script", "active"])]
+    pub all_pythons: bool,
+
     /// The Python interpreter to use for the project environment.
     ///
     /// By default, the first interpreter that meets the project's `requires-python` constraint is
@@ -3546,6 +4055,22 @@ pub struct SyncArgs {
 
     #[arg(long, overrides_with("check"), hide = true)]
     pub no_check: bool,
+
+    /// Fail if the environment contains packages that were installed by a tool other than uv.
+    ///
+    /// Packages are identified by their `INSTALLER` metadata (e.g., packages installed with `pip
+    /// install`). By default, uv will warn about such packages, but proceed with the sync.
+    #[arg(long, conflicts_with = "adopt_foreign")]
+    pub check_foreign: bool,
+
+    /// Take ownership of packages in the environment that were installed by a tool other than uv.
+    ///
+    /// Adopted packages have their `INSTALLER` metadata rewritten to `uv`, after which they're
+    /// treated like any other package in the environment; for example, an exact sync will remove
+    /// them if they aren't required by the project. Editable installs retain their
+    /// `direct_url.json` and `.pth` files.
+    #[arg(long)]
+    pub adopt_foreign: bool,
 }
 
 #[derive(Args)]
@@ -3572,6 +4097,21 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "check_exists", conflicts_with = "check")]
     pub dry_run: bool,
 
+    /// Repair a lockfile that contains entries whose names collide after normalization.
+    ///
+    /// Package names, extras, and dependency groups are normalized when reading the lockfile, such
+    /// that (e.g.) `Foo_Bar` and `foo-bar` refer to the same package. If the lockfile contains
+    /// multiple identical entries that differ only in the spelling of their names, uv will merge
+    /// them and rewrite the lockfile with normalized names prior to locking. If the colliding
+    /// entries are otherwise distinct, uv will exit with an error.
+    #[arg(
+        long,
+        conflicts_with = "check_exists",
+        conflicts_with = "check",
+        conflicts_with = "dry_run"
+    )]
+    pub repair: bool,
+
     /// Lock the specified Python script, rather than the current project.
     ///
     /// If provided, uv will lock the script (based on its inline metadata table, in adherence with
@@ -3579,6 +4119,43 @@ pub struct LockArgs {
     #[arg(long)]
     pub script: Option<PathBuf>,
 
+    /// Record the inputs consumed by the resolver into a reproducibility bundle in the given
+    /// directory.
+    ///
+    /// The bundle includes the HTTP responses received from package indexes (with credentials and
+    /// sensitive headers removed), the project manifests and lockfile, and the marker environment
+    /// of the interpreter. Responses are always fetched from the network, bypassing the cache.
+    ///
+    /// The bundle can be replayed with `--replay-bundle`.
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["check_exists", "replay_bundle"])]
+    pub record_bundle: Option<PathBuf>,
+
+    /// Resolve using the responses recorded in a reproducibility bundle, without network access.
+    ///
+    /// Any request that was not recorded in the bundle will fail. To reproduce a recorded
+    /// resolution, run this command against the manifests in the bundle's `workspace` directory.
+    #[arg(long, value_name = "DIR", conflicts_with = "check_exists")]
+    pub replay_bundle: Option<PathBuf>,
+
+    /// Select the output format.
+    ///
+    /// With `json`, a report of the changes to the lockfile is written to stdout, including the
+    /// previous and updated versions of each package, the release segment that changed (e.g.,
+    /// `major`), whether the package is a direct dependency, and the workspace members, extras,
+    /// and dependency groups that require it.
+    #[arg(long, value_enum, default_value_t = LockFormat::default())]
+    pub output_format: LockFormat,
+
+    /// Exit with an error if any direct dependency was updated across the given release segment.
+    ///
+    /// For example, `--fail-on major` will exit with a non-zero status if any direct dependency
+    /// was updated from `1.2.3` to `2.0.0`, while `--fail-on minor` will additionally exit with a
+    /// non-zero status for an update from `1.2.3` to `1.3.0`.
+    ///
+    /// The lockfile is still written; to avoid writing the lockfile, combine with `--dry-run`.
+    #[arg(long, value_enum, value_name = "SEGMENT", conflicts_with_all = ["check", "check_exists"])]
+    pub fail_on: Option<ReleaseSegment>,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -3606,6 +4183,18 @@ pub struct LockArgs {
         value_parser = parse_maybe_string,
     )]
     pub python: Option<Maybe<String>>,
+
+    /// Require that the resolution is installable on the given Python version.
+    ///
+    /// Can be provided multiple times to resolve against multiple Python versions at once, in
+    /// which case uv will only select package versions that can be installed on every requested
+    /// version (e.g., by requiring compatible wheels for packages without a source
+    /// distribution), and error if no such resolution exists.
+    ///
+    /// Each version must be compatible with the project's `requires-python`. The versions are
+    /// only enforced during resolution, and are not persisted to the lockfile.
+    #[arg(long = "python-version", help_heading = "Python options")]
+    pub python_versions: Vec<PythonVersion>,
 }
 
 #[derive(Args)]
@@ -3678,6 +4267,10 @@ pub struct AddArgs {
     ///
     /// Additionally, by default, uv will add bounds to your dependency, e.g., `foo>=1.0.0`. When
     /// `--raw` is provided, uv will add the dependency without bounds.
+    ///
+    /// When `--raw` is provided, named requirements are written to the `pyproject.toml` exactly as
+    /// provided on the command line (e.g., preserving the form of a direct URL or the spelling of
+    /// markers), rather than in a normalized form, unless modified by `--extra` or `--marker`.
     #[arg(
         long,
         conflicts_with = "editable",
@@ -4043,14 +4636,44 @@ pub struct TreeArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct WhyArgs {
+    /// The package to explain.
+    pub package: PackageName,
+
+    /// Read the installed packages from the Python environment, rather than from `uv.lock`.
+    ///
+    /// The paths are derived from the metadata of the installed packages, so only requirements
+    /// that apply to the environment are included.
+    #[arg(long)]
+    pub environment: bool,
+
+    /// The Python interpreter whose environment should be inspected, with `--environment`.
+    ///
+    /// By default, uv inspects the project's virtual environment, or the first environment found
+    /// in the usual discovery order.
+    ///
+    /// See `uv help python` for details on Python discovery and supported request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 pub struct ExportArgs {
     /// The format to which `uv.lock` should be exported.
     ///
-    /// Supports both `requirements.txt` and `pylock.toml` (PEP 751) output formats.
+    /// Supports `requirements.txt`, `pylock.toml` (PEP 751), and `cyclonedx-json` (a CycloneDX
+    /// v1.5 SBOM) output formats.
     ///
     /// uv will infer the output format from the file extension of the output file, if
-    /// provided. Otherwise, defaults to `requirements.txt`.
+    /// provided (e.g., `bom.cdx.json` for CycloneDX). Otherwise, defaults to `requirements.txt`.
     #[arg(long, value_enum)]
     pub format: Option<ExportFormat>,
 
@@ -4180,6 +4803,39 @@ pub struct ExportArgs {
     #[arg(long, overrides_with("hashes"))]
     pub no_hashes: bool,
 
+    /// Export the requirements for a single environment, omitting environment markers.
+    ///
+    /// By default, the exported requirements are universal, with environment markers on any
+    /// requirements that are only needed in some environments. With `--no-markers`, the markers
+    /// are instead evaluated against the target environment (as determined by the interpreter,
+    /// `--python-platform`, and `--python-version`), and only the requirements that apply to that
+    /// environment are included.
+    ///
+    /// Hashes are limited to the artifacts that are compatible with the target environment. If a
+    /// required package has no compatible wheel or source distribution, the export fails.
+    ///
+    /// Only supported for `requirements.txt` exports.
+    #[arg(long)]
+    pub no_markers: bool,
+
+    /// The platform to export requirements for, when using `--no-markers`.
+    ///
+    /// Represented as a "target triple", a string that describes the target platform in terms of
+    /// its CPU, vendor, and operating system name, like `x86_64-unknown-linux-gnu` or
+    /// `aarch64-apple-darwin`.
+    ///
+    /// Defaults to the platform of the discovered Python interpreter.
+    #[arg(long, requires = "no_markers")]
+    pub python_platform: Option<TargetTriple>,
+
+    /// The Python version to export requirements for, when using `--no-markers`.
+    ///
+    /// For example, `3.10` or `3.10.12`.
+    ///
+    /// Defaults to the version of the discovered Python interpreter.
+    #[arg(long, requires = "no_markers", help_heading = "Python options")]
+    pub python_version: Option<PythonVersion>,
+
     /// Write the exported requirements to the given file.
     #[arg(long, short)]
     pub output_file: Option<PathBuf>,
@@ -4624,6 +5280,28 @@ pub struct ToolUpgradeArgs {
     #[arg(long, conflicts_with("name"))]
     pub all: bool,
 
+    /// Upgrade tools that were installed with a pinned version (e.g., `black==24.1.0`).
+    ///
+    /// By default, `--all` leaves tools that were installed with an exact `==` version specifier
+    /// unchanged. With `--force`, the pin is removed from the tool's receipt and the tool is
+    /// upgraded to the latest compatible version.
+    #[arg(long)]
+    pub force: bool,
+
+    /// Report the available upgrades, without modifying any tools.
+    ///
+    /// For each tool, displays the installed version, the version it would be upgraded to, and
+    /// whether the tool's Python interpreter would change, along with a link to the changelog, if
+    /// the tool declares one.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Check if any tools have available upgrades, without modifying any tools.
+    ///
+    /// Implies `--dry-run`. If any upgrades are available, uv will exit with an error.
+    #[arg(long)]
+    pub check: bool,
+
     /// Upgrade a tool, and specify it to use the given Python interpreter to build its environment.
     /// Use with `--all` to apply to all tools.
     ///
@@ -4647,7 +5325,13 @@ pub struct ToolUpgradeArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in any existing output
     /// file. Implies `--refresh-package`.
-    #[arg(hide = true, long, short = 'P', help_heading = "Resolver options")]
+    #[arg(
+        hide = true,
+        long,
+        short = 'P',
+        value_parser = parse_upgrade_package,
+        help_heading = "Resolver options"
+    )]
     pub upgrade_package: Vec<Requirement<VerbatimParsedUrl>>,
 
     #[command(flatten)]
@@ -4750,6 +5434,21 @@ pub struct ToolUpgradeArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// Prefer versions with a local version segment (e.g., `1.2.3+corp1`) over the equivalent
+    /// public version (e.g., `1.2.3`).
+    ///
+    /// By default, uv compares candidate versions as defined by PEP 440, such that, e.g.,
+    /// `1.2.3` may be selected over `1.2.3+corp1` under `--resolution lowest`. With this option,
+    /// uv will select a version with a local segment whenever one is available for the selected
+    /// public version.
+    #[arg(
+        long,
+        env = EnvVars::UV_PREFER_LOCAL_VERSIONS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Resolver options"
+    )]
+    pub prefer_local_versions: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -4962,6 +5661,17 @@ pub enum PythonCommand {
     /// Uninstall Python versions.
     Uninstall(PythonUninstallArgs),
 
+    /// Check that managed Python installations are functional.
+    ///
+    /// Executes each managed Python interpreter with a small probe script that imports modules
+    /// which depend on system libraries (like `ssl` and `zlib`). Installations that fail to start
+    /// or to import these modules, e.g., after an operating system upgrade, are reported as
+    /// broken, and uv will exit with an error.
+    ///
+    /// Probe results are cached for a short period. Broken installations can be repaired with `uv
+    /// python install --reinstall-broken`.
+    Check(PythonCheckArgs),
+
     /// Ensure that the Python executable directory is on the `PATH`.
     ///
     /// If the Python executable directory is not present on the `PATH`, uv will attempt to add it to
@@ -5134,6 +5844,17 @@ pub struct PythonInstallArgs {
     #[arg(long, short)]
     pub reinstall: bool,
 
+    /// Reinstall any existing Python installations that fail a health check.
+    ///
+    /// Each matching managed installation is executed with a small probe script (see `uv python
+    /// check`), and those that fail are re-downloaded in place, preserving the installation key
+    /// such that virtual environments that reference them continue to work. Healthy
+    /// installations are left untouched.
+    ///
+    /// If no targets are provided, all managed installations are checked.
+    #[arg(long, conflicts_with("reinstall"))]
+    pub reinstall_broken: bool,
+
     /// Replace existing Python executables during installation.
     ///
     /// By default, uv will refuse to replace executables that it does not manage.
@@ -5199,6 +5920,14 @@ pub struct PythonUpgradeArgs {
     /// Note that currently, only local paths are supported.
     #[arg(long, env = EnvVars::UV_PYTHON_DOWNLOADS_JSON_URL)]
     pub python_downloads_json_url: Option<String>,
+
+    /// Perform a dry run, i.e., don't actually install anything but show the upgrades that would
+    /// be performed.
+    ///
+    /// Any minor version directories that would be moved to the new patch version are listed as
+    /// well; virtual environments that use these directories will be upgraded transparently.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 #[derive(Args)]
@@ -5218,6 +5947,20 @@ pub struct PythonUninstallArgs {
     pub all: bool,
 }
 
+#[derive(Args)]
+pub struct PythonCheckArgs {
+    /// The directory where the Python was installed.
+    #[arg(long, short, env = EnvVars::UV_PYTHON_INSTALL_DIR)]
+    pub install_dir: Option<PathBuf>,
+
+    /// The Python version(s) to check.
+    ///
+    /// If not provided, all managed Python installations are checked.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub targets: Vec<String>,
+}
+
 #[derive(Args)]
 pub struct PythonFindArgs {
     /// The Python request.
@@ -5251,13 +5994,29 @@ pub struct PythonFindArgs {
     #[arg(long, overrides_with("system"), hide = true)]
     pub no_system: bool,
 
+    /// Only find Python interpreters managed by uv.
+    ///
+    /// Virtual environments and Python interpreters installed by other tools are ignored, even if
+    /// they satisfy the request.
+    #[arg(long, conflicts_with = "system_only")]
+    pub managed_only: bool,
+
+    /// Only find Python interpreters that are not managed by uv.
+    ///
+    /// Virtual environments and uv-managed Python interpreters are ignored, even if they satisfy
+    /// the request.
+    #[arg(long)]
+    pub system_only: bool,
+
     /// Find the environment for a Python script, rather than the current project.
     #[arg(
         long,
         conflicts_with = "request",
         conflicts_with = "no_project",
         conflicts_with = "system",
-        conflicts_with = "no_system"
+        conflicts_with = "no_system",
+        conflicts_with = "managed_only",
+        conflicts_with = "system_only"
     )]
     pub script: Option<PathBuf>,
 
@@ -5346,6 +6105,10 @@ pub struct GenerateShellCompletionArgs {
     pub config_file: Option<PathBuf>,
     #[arg(long, hide = true)]
     pub no_config: bool,
+    #[arg(long, hide = true)]
+    pub no_config_boundary: bool,
+    #[arg(long, hide = true)]
+    pub no_user_constraints: bool,
     #[arg(long, short, action = clap::ArgAction::HelpShort, hide = true)]
     pub help: Option<bool>,
     #[arg(short = 'V', long, hide = true)]
@@ -5506,6 +6269,16 @@ pub struct BuildOptionsArgs {
     /// Don't install pre-built wheels for a specific package.
     #[arg(long, help_heading = "Build options", env = EnvVars::UV_NO_BINARY_PACKAGE, value_delimiter = ' ')]
     pub no_binary_package: Vec<PackageName>,
+
+    /// Disable the `build-timeout` and `build-max-output-size` limits on source distribution
+    /// builds.
+    #[arg(
+        long,
+        env = EnvVars::UV_NO_BUILD_TIMEOUT,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Build options"
+    )]
+    pub no_build_timeout: bool,
 }
 
 /// Arguments that are used by commands that need to install (but not resolve) packages.
@@ -5594,6 +6367,12 @@ pub struct InstallerArgs {
     )]
     pub no_build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
+    #[arg(long, help_heading = "Build options")]
+    pub no_build_isolation_package: Vec<PackageName>,
+
     #[arg(
         long,
         overrides_with("no_build_isolation"),
@@ -5697,7 +6476,12 @@ pub struct ResolverArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in any existing output
     /// file. Implies `--refresh-package`.
-    #[arg(long, short = 'P', help_heading = "Resolver options")]
+    #[arg(
+        long,
+        short = 'P',
+        value_parser = parse_upgrade_package,
+        help_heading = "Resolver options"
+    )]
     pub upgrade_package: Vec<Requirement<VerbatimParsedUrl>>,
 
     /// The strategy to use when resolving against multiple index URLs.
@@ -5774,6 +6558,21 @@ pub struct ResolverArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// Prefer versions with a local version segment (e.g., `1.2.3+corp1`) over the equivalent
+    /// public version (e.g., `1.2.3`).
+    ///
+    /// By default, uv compares candidate versions as defined by PEP 440, such that, e.g.,
+    /// `1.2.3` may be selected over `1.2.3+corp1` under `--resolution lowest`. With this option,
+    /// uv will select a version with a local segment whenever one is available for the selected
+    /// public version.
+    #[arg(
+        long,
+        env = EnvVars::UV_PREFER_LOCAL_VERSIONS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Resolver options"
+    )]
+    pub prefer_local_versions: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -5885,7 +6684,12 @@ pub struct ResolverInstallerArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in any existing output file.
     /// Implies `--refresh-package`.
-    #[arg(long, short = 'P', help_heading = "Resolver options")]
+    #[arg(
+        long,
+        short = 'P',
+        value_parser = parse_upgrade_package,
+        help_heading = "Resolver options"
+    )]
     pub upgrade_package: Vec<Requirement<VerbatimParsedUrl>>,
 
     /// Reinstall all packages, regardless of whether they're already installed. Implies
@@ -5985,6 +6789,21 @@ pub struct ResolverInstallerArgs {
     )]
     pub fork_strategy: Option<ForkStrategy>,
 
+    /// Prefer versions with a local version segment (e.g., `1.2.3+corp1`) over the equivalent
+    /// public version (e.g., `1.2.3`).
+    ///
+    /// By default, uv compares candidate versions as defined by PEP 440, such that, e.g.,
+    /// `1.2.3` may be selected over `1.2.3+corp1` under `--resolution lowest`. With this option,
+    /// uv will select a version with a local segment whenever one is available for the selected
+    /// public version.
+    #[arg(
+        long,
+        env = EnvVars::UV_PREFER_LOCAL_VERSIONS,
+        value_parser = clap::builder::BoolishValueParser::new(),
+        help_heading = "Resolver options"
+    )]
+    pub prefer_local_versions: bool,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
     #[arg(
         long,
@@ -6267,6 +7086,10 @@ pub struct PublishArgs {
     /// file succeeds even without `--check-url`, while most other indexes error.
     ///
     /// The index must provide one of the supported hashes (SHA-256, SHA-384, or SHA-512).
+    ///
+    /// The index is queried with the same authentication as during resolution (e.g., credentials
+    /// from a `.netrc` file, the keyring, or a matching named index). If the index responds with a
+    /// 401 or 403, publishing fails, rather than assuming the file is absent.
     #[arg(long, env = EnvVars::UV_PUBLISH_CHECK_URL)]
     pub check_url: Option<IndexUrl>,
 
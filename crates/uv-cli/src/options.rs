@@ -61,6 +61,7 @@ impl From<ResolverArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            prefer_local_versions,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -79,6 +80,11 @@ impl From<ResolverArgs> for PipOptions {
             keyring_provider,
             resolution,
             fork_strategy,
+            prefer_local_versions: if prefer_local_versions {
+                Some(true)
+            } else {
+                None
+            },
             prerelease: if pre {
                 Some(PrereleaseMode::Allow)
             } else {
@@ -114,6 +120,7 @@ impl From<InstallerArgs> for PipOptions {
             config_setting,
             config_settings_package,
             no_build_isolation,
+            no_build_isolation_package,
             build_isolation,
             exclude_newer,
             link_mode,
@@ -136,6 +143,11 @@ impl From<InstallerArgs> for PipOptions {
                     .collect::<PackageConfigSettings>()
             }),
             no_build_isolation: flag(no_build_isolation, build_isolation, "build-isolation"),
+            no_build_isolation_package: if no_build_isolation_package.is_empty() {
+                None
+            } else {
+                Some(no_build_isolation_package)
+            },
             exclude_newer,
             exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
             link_mode,
@@ -162,6 +174,7 @@ impl From<ResolverInstallerArgs> for PipOptions {
             prerelease,
             pre,
             fork_strategy,
+            prefer_local_versions,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -189,6 +202,11 @@ impl From<ResolverInstallerArgs> for PipOptions {
                 prerelease
             },
             fork_strategy,
+            prefer_local_versions: if prefer_local_versions {
+                Some(true)
+            } else {
+                None
+            },
             config_settings: config_setting
                 .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
             config_settings_package: config_settings_package.map(|config_settings| {
@@ -283,6 +301,7 @@ pub fn resolver_options(
         prerelease,
         pre,
         fork_strategy,
+        prefer_local_versions,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -301,6 +320,7 @@ pub fn resolver_options(
         no_binary,
         binary,
         no_binary_package,
+        no_build_timeout,
     } = build_args;
 
     ResolverOptions {
@@ -338,12 +358,20 @@ pub fn resolver_options(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_overrides: None,
+        allow_prereleases: None,
         prerelease: if pre {
             Some(PrereleaseMode::Allow)
         } else {
             prerelease
         },
         fork_strategy,
+        prefer_local_versions: if prefer_local_versions {
+            Some(true)
+        } else {
+            None
+        },
+        hash_algorithm: None,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -356,6 +384,11 @@ pub fn resolver_options(
         no_build_isolation_package: Some(no_build_isolation_package),
         extra_build_dependencies: None,
         extra_build_variables: None,
+        build_timeout: None,
+        build_timeout_package: None,
+        build_max_output_size: None,
+        build_max_output_size_package: None,
+        no_build_timeout: if no_build_timeout { Some(true) } else { None },
         exclude_newer: ExcludeNewer::from_args(
             exclude_newer,
             exclude_newer_package.unwrap_or_default(),
@@ -388,6 +421,7 @@ pub fn resolver_installer_options(
         prerelease,
         pre,
         fork_strategy,
+        prefer_local_versions,
         config_setting,
         config_settings_package,
         no_build_isolation,
@@ -408,6 +442,7 @@ pub fn resolver_installer_options(
         no_binary,
         binary,
         no_binary_package,
+        no_build_timeout,
     } = build_args;
 
     let default_index = index_args
@@ -457,12 +492,20 @@ pub fn resolver_installer_options(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_overrides: None,
+        allow_prereleases: None,
         prerelease: if pre {
             Some(PrereleaseMode::Allow)
         } else {
             prerelease
         },
         fork_strategy,
+        prefer_local_versions: if prefer_local_versions {
+            Some(true)
+        } else {
+            None
+        },
+        hash_algorithm: None,
         dependency_metadata: None,
         config_settings: config_setting
             .map(|config_settings| config_settings.into_iter().collect::<ConfigSettings>()),
@@ -479,6 +522,11 @@ pub fn resolver_installer_options(
         },
         extra_build_dependencies: None,
         extra_build_variables: None,
+        build_timeout: None,
+        build_timeout_package: None,
+        build_max_output_size: None,
+        build_max_output_size_package: None,
+        no_build_timeout: if no_build_timeout { Some(true) } else { None },
         exclude_newer,
         exclude_newer_package: exclude_newer_package.map(ExcludeNewerPackage::from_iter),
         link_mode,
@@ -26,6 +26,16 @@ pub struct VersionInfo {
     ///
     /// `None` if not built from a git repo or if retrieval failed.
     commit_info: Option<CommitInfo>,
+    /// The date on which uv was built, such as "2025-01-31".
+    ///
+    /// Only set when printing uv's own version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_date: Option<String>,
+    /// The optional features that uv was compiled with, such as "self-update".
+    ///
+    /// Only set when printing uv's own version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    features: Option<Vec<&'static str>>,
 }
 
 impl VersionInfo {
@@ -34,6 +44,8 @@ impl VersionInfo {
             package_name: package_name.map(ToString::to_string),
             version: version.to_string(),
             commit_info: None,
+            build_date: None,
+            features: None,
         }
     }
 }
@@ -91,10 +103,20 @@ pub fn uv_self_version() -> VersionInfo {
             .map_or(0, |value| value.parse::<u32>().unwrap_or(0)),
     });
 
+    // The build date is set by `build.rs`
+    let build_date = option_env_str!("UV_BUILD_DATE");
+
+    let mut features = Vec::new();
+    if cfg!(feature = "self-update") {
+        features.push("self-update");
+    }
+
     VersionInfo {
         package_name: Some("uv".to_owned()),
         version,
         commit_info,
+        build_date,
+        features: Some(features),
     }
 }
 
@@ -110,6 +132,8 @@ mod tests {
             package_name: Some("uv".to_string()),
             version: "0.0.0".to_string(),
             commit_info: None,
+            build_date: None,
+            features: None,
         };
         assert_snapshot!(version, @"0.0.0");
     }
@@ -126,6 +150,8 @@ mod tests {
                 commit_date: "2023-10-19".to_string(),
                 commits_since_last_tag: 0,
             }),
+            build_date: None,
+            features: None,
         };
         assert_snapshot!(version, @"0.0.0 (53b0f5d92 2023-10-19)");
     }
@@ -142,6 +168,8 @@ mod tests {
                 commit_date: "2023-10-19".to_string(),
                 commits_since_last_tag: 24,
             }),
+            build_date: None,
+            features: None,
         };
         assert_snapshot!(version, @"0.0.0+24 (53b0f5d92 2023-10-19)");
     }
@@ -158,6 +186,8 @@ mod tests {
                 commit_date: "2023-10-19".to_string(),
                 commits_since_last_tag: 0,
             }),
+            build_date: Some("2023-10-20".to_string()),
+            features: Some(vec!["self-update"]),
         };
         assert_json_snapshot!(version, @r#"
     {
@@ -169,7 +199,11 @@ mod tests {
         "commit_date": "2023-10-19",
         "last_tag": "v0.0.1",
         "commits_since_last_tag": 0
-      }
+      },
+      "build_date": "2023-10-20",
+      "features": [
+        "self-update"
+      ]
     }
     "#);
     }
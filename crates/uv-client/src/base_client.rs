@@ -40,12 +40,15 @@ use uv_warnings::warn_user_once;
 
 use crate::Connectivity;
 use crate::linehaul::LineHaul;
-use crate::middleware::OfflineMiddleware;
+use crate::middleware::{OfflineMiddleware, RetryAfterMiddleware};
 use crate::tls::read_identity;
 
 /// Do not use this value directly outside tests, use [`retries_from_env`] instead.
 pub const DEFAULT_RETRIES: u32 = 3;
 
+/// The default base of the exponential backoff between retries.
+pub const DEFAULT_RETRY_BACKOFF: u32 = 2;
+
 /// Maximum number of redirects to follow before giving up.
 ///
 /// This is the default used by [`reqwest`].
@@ -72,6 +75,7 @@ pub struct BaseClientBuilder<'a> {
     native_tls: bool,
     built_in_root_certs: bool,
     retries: u32,
+    retry_backoff: u32,
     pub connectivity: Connectivity,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
@@ -134,6 +138,7 @@ impl BaseClientBuilder<'_> {
             built_in_root_certs: false,
             connectivity: Connectivity::Online,
             retries: DEFAULT_RETRIES,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
@@ -172,6 +177,13 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the base of the exponential backoff between retries.
+    #[must_use]
+    pub fn retry_backoff(mut self, retry_backoff: u32) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
     /// Read the retry count from [`EnvVars::UV_HTTP_RETRIES`] if set, otherwise use the default
     /// retries.
     ///
@@ -259,7 +271,7 @@ impl<'a> BaseClientBuilder<'a> {
 
     /// Create a [`RetryPolicy`] for the client.
     fn retry_policy(&self) -> ExponentialBackoff {
-        let mut builder = ExponentialBackoff::builder();
+        let mut builder = ExponentialBackoff::builder().base(self.retry_backoff);
         if env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_some() {
             builder = builder.retry_bounds(Duration::from_millis(0), Duration::from_millis(0));
         }
@@ -341,6 +353,7 @@ impl<'a> BaseClientBuilder<'a> {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
+            retry_backoff: self.retry_backoff,
             client,
             raw_client,
             dangerous_client,
@@ -367,6 +380,7 @@ impl<'a> BaseClientBuilder<'a> {
             connectivity: self.connectivity,
             allow_insecure_host: self.allow_insecure_host.clone(),
             retries: self.retries,
+            retry_backoff: self.retry_backoff,
             client,
             dangerous_client,
             raw_client: existing.raw_client.clone(),
@@ -442,6 +456,11 @@ impl<'a> BaseClientBuilder<'a> {
                         UvRetryableStrategy,
                     );
                     client = client.with(retry_strategy);
+
+                    // Honor `Retry-After` on rate-limited and unavailable responses.
+                    if env::var_os(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY).is_none() {
+                        client = client.with(RetryAfterMiddleware::new(self.retries));
+                    }
                 }
 
                 // Initialize the authentication middleware to set headers.
@@ -474,9 +493,19 @@ impl<'a> BaseClientBuilder<'a> {
 
                 client.build()
             }
-            Connectivity::Offline => reqwest_middleware::ClientBuilder::new(client)
-                .with(OfflineMiddleware)
-                .build(),
+            Connectivity::Offline => {
+                let mut client = reqwest_middleware::ClientBuilder::new(client);
+
+                // The extra middleware may serve responses without accessing the network (e.g.,
+                // when replaying a resolution bundle), so it runs ahead of the offline middleware.
+                if let Some(extra_middleware) = &self.extra_middleware {
+                    for middleware in &extra_middleware.0 {
+                        client = client.with_arc(middleware.clone());
+                    }
+                }
+
+                client.with(OfflineMiddleware).build()
+            }
         }
     }
 }
@@ -500,6 +529,8 @@ pub struct BaseClient {
     allow_insecure_host: Vec<TrustedHost>,
     /// The number of retries to attempt on transient errors.
     retries: u32,
+    /// The base of the exponential backoff between retries.
+    retry_backoff: u32,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -543,9 +574,16 @@ impl BaseClient {
         self.connectivity
     }
 
+    /// The number of retries to attempt on transient errors.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
     /// The [`RetryPolicy`] for the client.
     pub fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+        ExponentialBackoff::builder()
+            .base(self.retry_backoff)
+            .build_with_max_retries(self.retries)
     }
 }
 
@@ -0,0 +1,295 @@
+//! Record and replay the HTTP responses consumed during a resolution.
+//!
+//! A resolution bundle is a directory containing an `index.json` file, which maps each request
+//! to the response it received, and an `objects` directory, which contains the response bodies,
+//! addressed by their SHA-256 digest.
+//!
+//! When recording, responses are captured after authentication has been applied; credentials are
+//! stripped from all captured URLs, and sensitive headers are omitted. When replaying, responses
+//! are served from the bundle, and any request that isn't present in the bundle fails.
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use http::header::{AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, RANGE, SET_COOKIE};
+use http::{Extensions, HeaderName};
+use reqwest::{Request, Response, ResponseBuilderExt};
+use reqwest_middleware::{Middleware, Next};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+use uv_redacted::DisplaySafeUrl;
+
+use crate::ExtraMiddleware;
+
+/// The version of the bundle format.
+const BUNDLE_VERSION: u32 = 1;
+
+/// Headers that are never written to a bundle.
+const SENSITIVE_HEADERS: &[HeaderName] = &[
+    AUTHORIZATION,
+    PROXY_AUTHORIZATION,
+    COOKIE,
+    SET_COOKIE,
+    http::header::WWW_AUTHENTICATE,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    #[error("Failed to read resolution bundle index at `{}`", _0.display())]
+    ReadIndex(PathBuf, #[source] io::Error),
+    #[error("Failed to parse resolution bundle index at `{}`", _0.display())]
+    ParseIndex(PathBuf, #[source] serde_json::Error),
+    #[error(
+        "Unsupported resolution bundle version {0} (expected {BUNDLE_VERSION}); re-record the bundle with this version of uv"
+    )]
+    UnsupportedVersion(u32),
+    #[error("Failed to write resolution bundle at `{}`", _0.display())]
+    Write(PathBuf, #[source] io::Error),
+}
+
+/// Whether a [`ResolutionBundle`] is being recorded or replayed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BundleMode {
+    /// Capture all responses into the bundle.
+    Record,
+    /// Serve all responses from the bundle, without accessing the network.
+    Replay,
+}
+
+/// A captured response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct BundleEntry {
+    /// The HTTP status code.
+    status: u16,
+    /// The final URL of the response, after any redirects.
+    url: DisplaySafeUrl,
+    /// The response headers, excluding any sensitive headers.
+    headers: Vec<(String, String)>,
+    /// The SHA-256 digest of the response body.
+    body: String,
+}
+
+/// The contents of a bundle's `index.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct BundleIndex {
+    version: u32,
+    responses: BTreeMap<String, BundleEntry>,
+}
+
+/// A directory of captured responses, used to reproduce a resolution without network access.
+#[derive(Clone)]
+pub struct ResolutionBundle {
+    root: PathBuf,
+    mode: BundleMode,
+    responses: Arc<Mutex<BTreeMap<String, BundleEntry>>>,
+}
+
+impl Debug for ResolutionBundle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResolutionBundle")
+            .field("root", &self.root)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResolutionBundle {
+    /// Create a new bundle to record responses into the given directory.
+    pub fn record(root: impl Into<PathBuf>) -> Result<Self, BundleError> {
+        let root = root.into();
+        fs_err::create_dir_all(root.join("objects"))
+            .map_err(|err| BundleError::Write(root.clone(), err))?;
+        Ok(Self {
+            root,
+            mode: BundleMode::Record,
+            responses: Arc::default(),
+        })
+    }
+
+    /// Read an existing bundle from the given directory, to replay its responses.
+    pub fn replay(root: impl Into<PathBuf>) -> Result<Self, BundleError> {
+        let root = root.into();
+        let path = root.join("index.json");
+        let contents = fs_err::read_to_string(&path)
+            .map_err(|err| BundleError::ReadIndex(path.clone(), err))?;
+        let index: BundleIndex =
+            serde_json::from_str(&contents).map_err(|err| BundleError::ParseIndex(path, err))?;
+        if index.version != BUNDLE_VERSION {
+            return Err(BundleError::UnsupportedVersion(index.version));
+        }
+        Ok(Self {
+            root,
+            mode: BundleMode::Replay,
+            responses: Arc::new(Mutex::new(index.responses)),
+        })
+    }
+
+    /// Return the root directory of the bundle.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Return the [`BundleMode`] of the bundle.
+    pub fn mode(&self) -> BundleMode {
+        self.mode
+    }
+
+    /// Return the middleware that records responses into, or replays responses from, the bundle.
+    pub fn middleware(&self) -> ExtraMiddleware {
+        ExtraMiddleware(vec![Arc::new(BundleMiddleware(self.clone()))])
+    }
+
+    /// Write the captured responses to the bundle's `index.json`.
+    pub fn persist(&self) -> Result<(), BundleError> {
+        let index = BundleIndex {
+            version: BUNDLE_VERSION,
+            responses: self.responses.lock().unwrap().clone(),
+        };
+        let path = self.root.join("index.json");
+        let contents = serde_json::to_string_pretty(&index).expect("Serialization is infallible");
+        fs_err::write(&path, contents).map_err(|err| BundleError::Write(path, err))
+    }
+
+    /// Return the key used to identify a request in the bundle.
+    fn key(request: &Request) -> String {
+        let mut url = DisplaySafeUrl::from(request.url().clone());
+        url.remove_credentials();
+        match request
+            .headers()
+            .get(RANGE)
+            .and_then(|range| range.to_str().ok())
+        {
+            Some(range) => format!("{} {url} {range}", request.method()),
+            None => format!("{} {url}", request.method()),
+        }
+    }
+
+    /// Return the path to the object with the given digest.
+    fn object(&self, digest: &str) -> PathBuf {
+        self.root.join("objects").join(digest)
+    }
+
+    async fn record_response(
+        &self,
+        key: String,
+        response: Response,
+    ) -> reqwest_middleware::Result<Response> {
+        let status = response.status();
+        let version = response.version();
+        let url = response.url().clone();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        // Store the body, addressed by its digest.
+        let digest = format!("{:x}", Sha256::digest(&body));
+        let path = self.object(&digest);
+        if !path.exists() {
+            fs_err::tokio::write(&path, &body)
+                .await
+                .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+        }
+
+        let mut safe_url = DisplaySafeUrl::from(url.clone());
+        safe_url.remove_credentials();
+        let entry = BundleEntry {
+            status: status.as_u16(),
+            url: safe_url,
+            headers: headers
+                .iter()
+                .filter(|(name, _)| !SENSITIVE_HEADERS.contains(name))
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+            body: digest,
+        };
+        debug!("Recording response for `{key}` in resolution bundle");
+        self.responses.lock().unwrap().insert(key, entry);
+
+        // Reconstruct the response for the caller.
+        let mut builder = http::Response::builder()
+            .status(status)
+            .version(version)
+            .url(url);
+        if let Some(builder_headers) = builder.headers_mut() {
+            *builder_headers = headers;
+        }
+        let response = builder
+            .body(body)
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+        Ok(Response::from(response))
+    }
+
+    async fn replay_response(&self, key: &str) -> reqwest_middleware::Result<Response> {
+        let Some(entry) = self.responses.lock().unwrap().get(key).cloned() else {
+            return Err(reqwest_middleware::Error::Middleware(
+                BundleMissError {
+                    request: key.to_string(),
+                }
+                .into(),
+            ));
+        };
+        debug!("Replaying response for `{key}` from resolution bundle");
+
+        let body = fs_err::tokio::read(self.object(&entry.body))
+            .await
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+
+        let mut builder = http::Response::builder()
+            .status(entry.status)
+            .url(url::Url::from(entry.url));
+        for (name, value) in &entry.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(body)
+            .map_err(|err| reqwest_middleware::Error::Middleware(err.into()))?;
+        Ok(Response::from(response))
+    }
+}
+
+/// An error indicating that a request was not found in the bundle being replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BundleMissError {
+    request: String,
+}
+
+impl Display for BundleMissError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Network connectivity is disabled, but the requested data wasn't found in the resolution bundle for: `{}`",
+            self.request
+        )
+    }
+}
+
+impl std::error::Error for BundleMissError {}
+
+/// A middleware that records responses into, or replays responses from, a [`ResolutionBundle`].
+struct BundleMiddleware(ResolutionBundle);
+
+#[async_trait::async_trait]
+impl Middleware for BundleMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let key = ResolutionBundle::key(&req);
+        match self.0.mode {
+            BundleMode::Record => {
+                let response = next.run(req, extensions).await?;
+                self.0.record_response(key, response).await
+            }
+            BundleMode::Replay => self.0.replay_response(&key).await,
+        }
+    }
+}
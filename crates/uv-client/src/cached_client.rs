@@ -687,8 +687,10 @@ impl CachedClient {
                 let retry_decision = retry_policy.should_retry(start_time, total_retries);
                 if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
                     debug!(
-                        "Transient failure while handling response from {}; retrying...",
+                        "Transient failure while handling response from {}; retrying (attempt {} of {})...",
                         req.url(),
+                        total_retries + 1,
+                        self.uncached().retries(),
                     );
                     let duration = execute_after
                         .duration_since(SystemTime::now())
@@ -745,8 +747,10 @@ impl CachedClient {
                 let retry_decision = retry_policy.should_retry(start_time, total_retries);
                 if let reqwest_retry::RetryDecision::Retry { execute_after } = retry_decision {
                     debug!(
-                        "Transient failure while handling response from {}; retrying...",
+                        "Transient failure while handling response from {}; retrying (attempt {} of {})...",
                         req.url(),
+                        total_retries + 1,
+                        self.uncached().retries(),
                     );
                     let duration = execute_after
                         .duration_since(SystemTime::now())
@@ -869,7 +873,7 @@ impl DataWithCachePolicy {
     /// If the given byte buffer is not in a valid format or if reading the
     /// file given fails, then this returns an error.
     #[instrument]
-    fn from_path_sync(path: &Path) -> Result<Self, Error> {
+    pub fn from_path_sync(path: &Path) -> Result<Self, Error> {
         let file = fs_err::File::open(path).map_err(ErrorKind::Io)?;
         // Note that we don't wrap our file in a buffer because it will just
         // get passed to AlignedVec::extend_from_reader, which doesn't benefit
@@ -878,6 +882,19 @@ impl DataWithCachePolicy {
         Self::from_reader(file)
     }
 
+    /// Returns the `ETag` of the cached response, if any.
+    pub fn etag(&self) -> Option<&str> {
+        self.cache_policy
+            .etag()
+            .and_then(|etag| std::str::from_utf8(etag).ok())
+    }
+
+    /// Returns the time at which the cached response was received, as a Unix
+    /// timestamp.
+    pub fn fetched_at(&self) -> u64 {
+        self.cache_policy.response_unix_timestamp()
+    }
+
     /// Loads cached data and its associated HTTP cache policy from the given
     /// reader.
     ///
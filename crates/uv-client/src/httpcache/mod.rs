@@ -539,6 +539,21 @@ impl ArchivedCachePolicy {
         }
     }
 
+    /// Returns the `ETag` validator of the cached response, if any.
+    pub fn etag(&self) -> Option<&[u8]> {
+        self.response
+            .headers
+            .etag
+            .as_ref()
+            .map(|etag| etag.value.as_slice())
+    }
+
+    /// Returns the time at which the cached response was received, as a Unix
+    /// timestamp.
+    pub fn response_unix_timestamp(&self) -> u64 {
+        self.response.unix_timestamp.into()
+    }
+
     /// Returns true if and only if the response is storable as per
     /// [RFC 9111 S3].
     ///
@@ -1,12 +1,14 @@
 pub use base_client::{
-    AuthIntegration, BaseClient, BaseClientBuilder, DEFAULT_RETRIES, ExtraMiddleware,
-    RedirectClientWithMiddleware, RequestBuilder, RetryParsingError, UvRetryableStrategy,
-    is_extended_transient_error, retries_from_env,
+    AuthIntegration, BaseClient, BaseClientBuilder, DEFAULT_RETRIES, DEFAULT_RETRY_BACKOFF,
+    ExtraMiddleware, RedirectClientWithMiddleware, RequestBuilder, RetryParsingError,
+    UvRetryableStrategy, is_extended_transient_error, retries_from_env,
 };
+pub use bundle::{BundleError, BundleMode, ResolutionBundle};
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexEntry, FlatIndexError};
 pub use linehaul::LineHaul;
+pub use middleware::retry_after;
 pub use registry_client::{
     Connectivity, MetadataFormat, RegistryClient, RegistryClientBuilder, SimpleMetadata,
     SimpleMetadatum, VersionFiles,
@@ -14,6 +16,7 @@ pub use registry_client::{
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
 
 mod base_client;
+mod bundle;
 mod cached_client;
 mod error;
 mod flat_index;
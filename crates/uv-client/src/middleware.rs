@@ -1,5 +1,9 @@
-use http::Extensions;
+use http::header::RETRY_AFTER;
+use http::{Extensions, StatusCode};
+use jiff::Timestamp;
 use std::fmt::Debug;
+use std::time::Duration;
+use tracing::debug;
 use uv_redacted::DisplaySafeUrl;
 
 use reqwest::{Request, Response};
@@ -49,3 +53,105 @@ impl Middleware for OfflineMiddleware {
         ))
     }
 }
+
+/// The maximum delay to honor from a `Retry-After` header.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// The number of attempts made for a request, shared across retries via the request extensions.
+#[derive(Debug, Clone, Copy)]
+struct RetryAttempt(u32);
+
+/// A middleware that honors the `Retry-After` header on HTTP 429 and 503 responses.
+///
+/// Must be installed after (i.e., inside) the retry middleware, which reuses the request
+/// extensions across attempts. If the response will be retried, the middleware waits for the
+/// requested delay before returning it, such that the retry middleware's backoff is applied on top.
+pub(crate) struct RetryAfterMiddleware {
+    /// The number of retries that the retry middleware will attempt.
+    retries: u32,
+}
+
+impl RetryAfterMiddleware {
+    pub(crate) fn new(retries: u32) -> Self {
+        Self { retries }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryAfterMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let attempt = extensions
+            .get::<RetryAttempt>()
+            .map_or(0, |attempt| attempt.0);
+        extensions.insert(RetryAttempt(attempt + 1));
+
+        let response = next.run(req, extensions).await?;
+        if attempt < self.retries {
+            if let Some(delay) = retry_after(&response) {
+                debug!(
+                    "Received `Retry-After` of {:.1}s from {}; waiting before retrying (attempt {} of {})",
+                    delay.as_secs_f32(),
+                    response.url().host_str().unwrap_or("unknown host"),
+                    attempt + 1,
+                    self.retries,
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+        Ok(response)
+    }
+}
+
+/// Return the delay requested by the `Retry-After` header of an HTTP 429 or 503 response, if any.
+///
+/// The header may contain either a number of seconds or an HTTP date. The delay is capped at
+/// [`MAX_RETRY_AFTER`].
+pub fn retry_after(response: &Response) -> Option<Duration> {
+    if !matches!(
+        response.status(),
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    ) {
+        return None;
+    }
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, Timestamp::now()).map(|delay| delay.min(MAX_RETRY_AFTER))
+}
+
+/// Parse the value of a `Retry-After` header into a delay relative to `now`.
+fn parse_retry_after(value: &str, now: Timestamp) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = jiff::fmt::rfc2822::parse(value).ok()?;
+    // A date in the past requests no delay.
+    Some(Duration::try_from(date.timestamp().duration_since(now)).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_retry_after_value() {
+        let now: Timestamp = "2015-10-21T07:28:00Z".parse().unwrap();
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+}
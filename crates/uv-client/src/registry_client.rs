@@ -1259,7 +1259,8 @@ mod tests {
 
     use crate::RegistryClientBuilder;
     use uv_cache::Cache;
-    use uv_distribution_types::{FileLocation, ToUrlError};
+    use uv_distribution_types::{FileLocation, Index, IndexLocations, IndexUrl, ToUrlError};
+    use uv_pep508::VerbatimUrl;
     use uv_small_str::SmallString;
     use wiremock::matchers::{basic_auth, method, path_regex};
     use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -1338,6 +1339,92 @@ mod tests {
         Ok(())
     }
 
+    /// Credentials for an index should be propagated on redirects to hosts in its `auth-scope`,
+    /// but never to other hosts.
+    #[tokio::test]
+    async fn test_redirect_to_auth_scope() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        // A host that's part of the index's authentication scope.
+        let scoped_server = start_test_server(username, password).await;
+        let scoped_url = DisplaySafeUrl::parse(&scoped_server.uri())?;
+
+        // A host that isn't part of the index's authentication scope.
+        let unscoped_server = start_test_server(username, password).await;
+        let unscoped_url = DisplaySafeUrl::parse(&unscoped_server.uri())?;
+
+        // An index that redirects file downloads to either host.
+        let index_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex("/files/scoped"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("Location", format!("{}/file", scoped_server.uri())),
+            )
+            .mount(&index_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex("/files/unscoped"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("Location", format!("{}/file", unscoped_server.uri())),
+            )
+            .mount(&index_server)
+            .await;
+
+        let mut index_url = DisplaySafeUrl::parse(&format!("{}/simple", index_server.uri()))?;
+        let _ = index_url.set_username(username);
+        let _ = index_url.set_password(Some(password));
+        let index = Index {
+            auth_scope: vec![scoped_url],
+            ..Index::from_index_url(IndexUrl::from(VerbatimUrl::from_url(index_url)))
+        };
+        let index_locations = IndexLocations::new(vec![index], Vec::new(), false);
+        index_locations.cache_index_credentials();
+
+        let cache = Cache::temp()?;
+        let registry_client = RegistryClientBuilder::new(cache)
+            .index_locations(&index_locations)
+            .build();
+        let client = registry_client.cached_client().uncached();
+
+        let url = DisplaySafeUrl::parse(&format!("{}/files/scoped", index_server.uri()))?;
+        assert_eq!(
+            client
+                .for_host(&url)
+                .get(Url::from(url))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Credentials should be propagated to hosts in the authentication scope"
+        );
+
+        let url = DisplaySafeUrl::parse(&format!("{}/files/unscoped", index_server.uri()))?;
+        assert_eq!(
+            client
+                .for_host(&url)
+                .get(Url::from(url))
+                .send()
+                .await?
+                .status(),
+            401,
+            "Credentials should not be propagated to hosts outside of the authentication scope"
+        );
+        assert!(
+            unscoped_server
+                .received_requests()
+                .await
+                .unwrap()
+                .iter()
+                .all(|request| !request.headers.contains_key("authorization")),
+            "No request to {unscoped_url} should include credentials"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_redirect_root_relative_url() -> Result<(), Error> {
         let username = "user";
@@ -1,2 +1,3 @@
 mod remote_metadata;
+mod revalidation;
 mod user_agent_version;
@@ -0,0 +1,81 @@
+use std::io;
+use std::str::FromStr;
+
+use anyhow::Result;
+use url::Url;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_client::{CacheControl, Error, ErrorKind, RegistryClientBuilder};
+use uv_redacted::DisplaySafeUrl;
+
+/// A stale cached response should be revalidated with `If-None-Match` and `If-Modified-Since`,
+/// and a `304 Not Modified` should be treated as a cache hit that refreshes the entry's freshness.
+#[tokio::test]
+async fn revalidate_not_modified() -> Result<()> {
+    let server = MockServer::start().await;
+
+    // Respond to the revalidation request with a `304`, extending the freshness lifetime.
+    Mock::given(method("GET"))
+        .and(path("/simple/"))
+        .and(header("if-none-match", "\"abc\""))
+        .and(header("if-modified-since", "Mon, 01 Jan 2024 00:00:00 GMT"))
+        .respond_with(
+            ResponseTemplate::new(304)
+                .insert_header("etag", "\"abc\"")
+                .insert_header("cache-control", "max-age=600"),
+        )
+        .with_priority(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Respond to the initial request with a body that's immediately stale.
+    Mock::given(method("GET"))
+        .and(path("/simple/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"abc\"")
+                .insert_header("last-modified", "Mon, 01 Jan 2024 00:00:00 GMT")
+                .insert_header("cache-control", "max-age=0")
+                .set_body_string("index"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache.clone()).build();
+    let url = DisplaySafeUrl::from_str(&format!("{}/simple/", server.uri()))?;
+    let cache_entry = cache.entry(CacheBucket::Simple, "revalidation", "index.msgpack");
+
+    // The first request populates the cache; the second revalidates the stale entry and receives a
+    // `304`; the third is served from the (now fresh) cache without a request.
+    for _ in 0..3 {
+        let request = client
+            .cached_client()
+            .uncached()
+            .for_host(&url)
+            .get(Url::from(url.clone()))
+            .build()?;
+        let body = client
+            .cached_client()
+            .get_serde(
+                request,
+                &cache_entry,
+                CacheControl::None,
+                async |response: reqwest::Response| {
+                    response
+                        .text()
+                        .await
+                        .map_err(|err| Error::from(ErrorKind::Io(io::Error::other(err))))
+                },
+            )
+            .await
+            .map_err(Error::from)?;
+        assert_eq!(body, "index");
+    }
+
+    Ok(())
+}
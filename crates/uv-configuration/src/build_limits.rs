@@ -0,0 +1,381 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+use uv_normalize::PackageName;
+
+/// The maximum duration of a build backend hook (e.g., `600s`, `10m`, or `1h`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BuildTimeout(Duration);
+
+impl BuildTimeout {
+    /// Return the timeout as a [`Duration`].
+    pub fn as_duration(self) -> Duration {
+        self.0
+    }
+}
+
+impl From<Duration> for BuildTimeout {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl FromStr for BuildTimeout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value, unit) = split_unit(s);
+        let value = value.parse::<u64>().map_err(|_| {
+            format!("Invalid build timeout: `{s}` (expected, e.g., `600s` or `10m`)")
+        })?;
+        let seconds = match unit.to_ascii_lowercase().as_str() {
+            "" | "s" => value,
+            "m" => value.saturating_mul(60),
+            "h" => value.saturating_mul(60 * 60),
+            _ => {
+                return Err(format!(
+                    "Invalid build timeout: `{s}` (expected a unit of `s`, `m`, or `h`)"
+                ));
+            }
+        };
+        if seconds == 0 {
+            return Err(format!(
+                "Invalid build timeout: `{s}` (expected a non-zero duration)"
+            ));
+        }
+        Ok(Self(Duration::from_secs(seconds)))
+    }
+}
+
+impl Display for BuildTimeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let seconds = self.0.as_secs();
+        if seconds >= 60 * 60 && seconds % (60 * 60) == 0 {
+            write!(f, "{}h", seconds / (60 * 60))
+        } else if seconds >= 60 && seconds % 60 == 0 {
+            write!(f, "{}m", seconds / 60)
+        } else {
+            write!(f, "{seconds}s")
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BuildTimeout {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = BuildTimeout;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a duration (e.g., `600s`) or a number of seconds")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                BuildTimeout::from_str(v).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                BuildTimeout::from_str(&v.to_string()).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                BuildTimeout::from_str(&v.to_string()).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for BuildTimeout {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("BuildTimeout")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": ["string", "integer"],
+            "description": "A duration, e.g., `600s`, `10m`, or `1h`. Integers are interpreted as a number of seconds."
+        })
+    }
+}
+
+/// The maximum amount of disk space a build may consume (e.g., `512MB` or `10GiB`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BuildOutputSize(u64);
+
+impl BuildOutputSize {
+    /// Return the size in bytes.
+    pub fn as_bytes(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for BuildOutputSize {
+    fn from(bytes: u64) -> Self {
+        Self(bytes)
+    }
+}
+
+impl FromStr for BuildOutputSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value, unit) = split_unit(s);
+        let value = value
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid build output size: `{s}` (expected, e.g., `10GB`)"))?;
+        let multiplier: u64 = match unit.to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "kb" => 1000,
+            "mb" => 1000 * 1000,
+            "gb" => 1000 * 1000 * 1000,
+            "tb" => 1000 * 1000 * 1000 * 1000,
+            "kib" => 1 << 10,
+            "mib" => 1 << 20,
+            "gib" => 1 << 30,
+            "tib" => 1 << 40,
+            _ => {
+                return Err(format!(
+                    "Invalid build output size: `{s}` (expected a unit of `B`, `KB`, `MB`, `GB`, `TB`, `KiB`, `MiB`, `GiB`, or `TiB`)"
+                ));
+            }
+        };
+        if value == 0 {
+            return Err(format!(
+                "Invalid build output size: `{s}` (expected a non-zero size)"
+            ));
+        }
+        Ok(Self(value.saturating_mul(multiplier)))
+    }
+}
+
+impl Display for BuildOutputSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (unit, multiplier) in [
+            ("TiB", 1u64 << 40),
+            ("GiB", 1 << 30),
+            ("MiB", 1 << 20),
+            ("KiB", 1 << 10),
+            ("TB", 1000 * 1000 * 1000 * 1000),
+            ("GB", 1000 * 1000 * 1000),
+            ("MB", 1000 * 1000),
+            ("KB", 1000),
+        ] {
+            if self.0 >= multiplier && self.0 % multiplier == 0 {
+                return write!(f, "{}{unit}", self.0 / multiplier);
+            }
+        }
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BuildOutputSize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = BuildOutputSize;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a size (e.g., `10GB`) or a number of bytes")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                BuildOutputSize::from_str(v).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                BuildOutputSize::from_str(&v.to_string()).map_err(serde::de::Error::custom)
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                BuildOutputSize::from_str(&v.to_string()).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for BuildOutputSize {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("BuildOutputSize")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": ["string", "integer"],
+            "description": "A size, e.g., `512MB` or `10GiB`. Integers are interpreted as a number of bytes."
+        })
+    }
+}
+
+/// Split a string like `600s` into its numeric value and unit.
+fn split_unit(s: &str) -> (&str, &str) {
+    let index = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (value, unit) = s.split_at(index);
+    (value, unit.trim_start())
+}
+
+/// Limits to enforce on the build backend hooks invoked when building source distributions.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BuildLimits {
+    /// The maximum duration of each build backend hook.
+    timeout: Option<BuildTimeout>,
+    /// Per-package overrides of the build timeout.
+    timeout_package: BTreeMap<PackageName, BuildTimeout>,
+    /// The maximum disk space consumed by each build.
+    max_output_size: Option<BuildOutputSize>,
+    /// Per-package overrides of the maximum output size.
+    max_output_size_package: BTreeMap<PackageName, BuildOutputSize>,
+}
+
+impl BuildLimits {
+    pub fn new(
+        timeout: Option<BuildTimeout>,
+        timeout_package: BTreeMap<PackageName, BuildTimeout>,
+        max_output_size: Option<BuildOutputSize>,
+        max_output_size_package: BTreeMap<PackageName, BuildOutputSize>,
+    ) -> Self {
+        Self {
+            timeout,
+            timeout_package,
+            max_output_size,
+            max_output_size_package,
+        }
+    }
+
+    /// Determine the build limits from the command-line arguments and settings.
+    ///
+    /// If `no_build_timeout` is set, no limits are enforced.
+    pub fn from_args(
+        no_build_timeout: Option<bool>,
+        timeout: Option<BuildTimeout>,
+        timeout_package: Option<BTreeMap<PackageName, BuildTimeout>>,
+        max_output_size: Option<BuildOutputSize>,
+        max_output_size_package: Option<BTreeMap<PackageName, BuildOutputSize>>,
+    ) -> Self {
+        if no_build_timeout == Some(true) {
+            return Self::default();
+        }
+        Self::new(
+            timeout,
+            timeout_package.unwrap_or_default(),
+            max_output_size,
+            max_output_size_package.unwrap_or_default(),
+        )
+    }
+
+    /// Return the timeout to apply to the build backend hooks for the given package.
+    pub fn timeout(&self, package: Option<&PackageName>) -> Option<BuildTimeout> {
+        package
+            .and_then(|package| self.timeout_package.get(package))
+            .copied()
+            .or(self.timeout)
+    }
+
+    /// Return the maximum output size to apply to the build for the given package.
+    pub fn max_output_size(&self, package: Option<&PackageName>) -> Option<BuildOutputSize> {
+        package
+            .and_then(|package| self.max_output_size_package.get(package))
+            .copied()
+            .or(self.max_output_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_timeout() {
+        assert_eq!(
+            BuildTimeout::from_str("600s").unwrap().as_duration(),
+            Duration::from_secs(600)
+        );
+        assert_eq!(
+            BuildTimeout::from_str("600").unwrap().as_duration(),
+            Duration::from_secs(600)
+        );
+        assert_eq!(
+            BuildTimeout::from_str("10m").unwrap().as_duration(),
+            Duration::from_secs(600)
+        );
+        assert_eq!(
+            BuildTimeout::from_str("1h").unwrap().as_duration(),
+            Duration::from_secs(3600)
+        );
+        assert_eq!(BuildTimeout::from_str("600s").unwrap().to_string(), "10m");
+        assert_eq!(BuildTimeout::from_str("90s").unwrap().to_string(), "90s");
+        assert!(BuildTimeout::from_str("0s").is_err());
+        assert!(BuildTimeout::from_str("10d").is_err());
+        assert!(BuildTimeout::from_str("s").is_err());
+    }
+
+    #[test]
+    fn build_output_size() {
+        assert_eq!(
+            BuildOutputSize::from_str("10GB").unwrap().as_bytes(),
+            10_000_000_000
+        );
+        assert_eq!(
+            BuildOutputSize::from_str("512 MiB").unwrap().as_bytes(),
+            512 * 1024 * 1024
+        );
+        assert_eq!(BuildOutputSize::from_str("1024").unwrap().as_bytes(), 1024);
+        assert_eq!(
+            BuildOutputSize::from_str("10GiB").unwrap().to_string(),
+            "10GiB"
+        );
+        assert_eq!(
+            BuildOutputSize::from_str("10gb").unwrap().to_string(),
+            "10GB"
+        );
+        assert!(BuildOutputSize::from_str("0MB").is_err());
+        assert!(BuildOutputSize::from_str("10XB").is_err());
+    }
+
+    #[test]
+    fn package_overrides() {
+        let numpy = PackageName::from_str("numpy").unwrap();
+        let limits = BuildLimits::new(
+            Some(BuildTimeout::from_str("10m").unwrap()),
+            BTreeMap::from([(numpy.clone(), BuildTimeout::from_str("1h").unwrap())]),
+            None,
+            BTreeMap::default(),
+        );
+        assert_eq!(
+            limits.timeout(Some(&numpy)),
+            Some(BuildTimeout::from_str("1h").unwrap())
+        );
+        assert_eq!(
+            limits.timeout(Some(&PackageName::from_str("anyio").unwrap())),
+            Some(BuildTimeout::from_str("10m").unwrap())
+        );
+        assert_eq!(
+            limits.timeout(None),
+            Some(BuildTimeout::from_str("10m").unwrap())
+        );
+        assert_eq!(limits.max_output_size(Some(&numpy)), None);
+
+        let limits = BuildLimits::from_args(
+            Some(true),
+            Some(BuildTimeout::from_str("10m").unwrap()),
+            None,
+            Some(BuildOutputSize::from_str("10GB").unwrap()),
+            None,
+        );
+        assert_eq!(limits.timeout(Some(&numpy)), None);
+        assert_eq!(limits.max_output_size(Some(&numpy)), None);
+    }
+}
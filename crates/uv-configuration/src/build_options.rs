@@ -2,7 +2,7 @@ use std::fmt::{Display, Formatter};
 
 use uv_pep508::PackageName;
 
-use crate::{PackageNameSpecifier, PackageNameSpecifiers};
+use crate::{BuildLimits, PackageNameSpecifier, PackageNameSpecifiers};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub enum BuildKind {
@@ -40,6 +40,13 @@ pub enum BuildOutput {
 pub struct BuildOptions {
     no_binary: NoBinary,
     no_build: NoBuild,
+    /// Packages that may be built from source if no compatible wheel is available, even if
+    /// building from source is otherwise disabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    allow_binary_fallback: Vec<PackageName>,
+    /// The limits to enforce when building source distributions.
+    #[serde(skip)]
+    limits: BuildLimits,
 }
 
 impl BuildOptions {
@@ -47,6 +54,8 @@ impl BuildOptions {
         Self {
             no_binary,
             no_build,
+            allow_binary_fallback: Vec::new(),
+            limits: BuildLimits::default(),
         }
     }
 
@@ -55,9 +64,26 @@ impl BuildOptions {
         Self {
             no_binary: self.no_binary.combine(no_binary),
             no_build: self.no_build.combine(no_build),
+            allow_binary_fallback: self.allow_binary_fallback,
+            limits: self.limits,
         }
     }
 
+    /// Allow the given packages to be built from source, even if building from source is
+    /// otherwise disabled.
+    #[must_use]
+    pub fn with_allow_binary_fallback(mut self, packages: Vec<PackageName>) -> Self {
+        self.allow_binary_fallback.extend(packages);
+        self
+    }
+
+    /// Enforce the given limits when building source distributions.
+    #[must_use]
+    pub fn with_limits(mut self, limits: BuildLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     pub fn no_binary_package(&self, package_name: &PackageName) -> bool {
         match &self.no_binary {
             NoBinary::None => false,
@@ -71,6 +97,9 @@ impl BuildOptions {
     }
 
     pub fn no_build_package(&self, package_name: &PackageName) -> bool {
+        if self.allow_binary_fallback.contains(package_name) {
+            return false;
+        }
         match &self.no_build {
             NoBuild::All => match &self.no_binary {
                 // Allow `all` to be overridden by specific binary exclusions
@@ -113,6 +142,16 @@ impl BuildOptions {
     pub fn no_binary(&self) -> &NoBinary {
         &self.no_binary
     }
+
+    /// Return the packages that may be built from source if no compatible wheel is available.
+    pub fn allow_binary_fallback(&self) -> &[PackageName] {
+        &self.allow_binary_fallback
+    }
+
+    /// Return the limits to enforce when building source distributions.
+    pub fn limits(&self) -> &BuildLimits {
+        &self.limits
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -146,6 +146,20 @@ impl ConfigSettings {
         self.0.is_empty()
     }
 
+    /// Returns an iterator over the settings as `(key, value)` pairs, repeating the key for each
+    /// value of a setting that was provided multiple times.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().flat_map(|(key, value)| {
+            let values = match value {
+                ConfigSettingValue::String(value) => std::slice::from_ref(value),
+                ConfigSettingValue::List(values) => values.as_slice(),
+            };
+            values
+                .iter()
+                .map(move |value| (key.as_str(), value.as_str()))
+        })
+    }
+
     /// Convert the settings to a string that can be passed directly to a PEP 517 build backend.
     pub fn escape_for_python(&self) -> String {
         serde_json::to_string(self).expect("Failed to serialize config settings")
@@ -186,6 +200,17 @@ impl ConfigSettings {
         }
         Self(config)
     }
+
+    /// Add any settings from `other` whose keys are not already set in `self`, such that the
+    /// values in `self` override those in `other`.
+    #[must_use]
+    pub fn with_defaults(self, other: Self) -> Self {
+        let mut config = self.0;
+        for (key, value) in other.0 {
+            config.entry(key).or_insert(value);
+        }
+        Self(config)
+    }
 }
 
 impl uv_cache_key::CacheKey for ConfigSettings {
@@ -275,6 +300,11 @@ impl PackageConfigSettings {
         self.0.is_empty()
     }
 
+    /// Returns an iterator over the packages and their config settings.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &ConfigSettings)> {
+        self.0.iter()
+    }
+
     /// Merge two sets of package config settings, with the values in `self` taking precedence.
     #[must_use]
     pub fn merge(mut self, other: Self) -> Self {
@@ -291,6 +321,24 @@ impl PackageConfigSettings {
         }
         self
     }
+
+    /// Add any settings from `other` whose keys are not already set for the same package in
+    /// `self`, such that the values in `self` override those in `other`.
+    #[must_use]
+    pub fn with_defaults(mut self, other: Self) -> Self {
+        for (package, settings) in other.0 {
+            match self.0.entry(package) {
+                Entry::Vacant(vacant) => {
+                    vacant.insert(settings);
+                }
+                Entry::Occupied(mut occupied) => {
+                    let merged = occupied.get().clone().with_defaults(settings);
+                    occupied.insert(merged);
+                }
+            }
+        }
+        self
+    }
 }
 
 impl uv_cache_key::CacheKey for PackageConfigSettings {
@@ -424,6 +472,34 @@ mod tests {
         assert_eq!(settings.escape_for_python(), r#"{"key":"val\\1 {}value"}"#);
     }
 
+    #[test]
+    fn config_settings_with_defaults() {
+        let settings: ConfigSettings = vec![
+            ConfigSettingEntry::from_str("key=value").unwrap(),
+            ConfigSettingEntry::from_str("list=value1").unwrap(),
+            ConfigSettingEntry::from_str("list=value2").unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        let defaults: ConfigSettings = vec![
+            ConfigSettingEntry::from_str("key=default").unwrap(),
+            ConfigSettingEntry::from_str("other=default").unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        let settings = settings.with_defaults(defaults);
+        assert_eq!(
+            settings.iter().collect::<Vec<_>>(),
+            vec![
+                ("key", "value"),
+                ("list", "value1"),
+                ("list", "value2"),
+                ("other", "default"),
+            ]
+        );
+    }
+
     #[test]
     fn parse_config_setting_package_entry() {
         // Test valid parsing
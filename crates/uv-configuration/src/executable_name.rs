@@ -0,0 +1,93 @@
+use std::borrow::Borrow;
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::{fmt::Formatter, str::FromStr};
+
+/// The name of an executable provided by a package (e.g., `http`, as provided by `httpie`).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExecutableName(String);
+
+impl ExecutableName {
+    /// Return the underlying executable name as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExecutableNameError {
+    #[error("Executable name must not be empty")]
+    Empty,
+    #[error("Executable name `{0}` must not contain whitespace")]
+    Whitespace(String),
+    #[error("Executable name `{0}` must not contain a path separator")]
+    PathSeparator(String),
+    #[error("Executable name `{0}` must not contain a version (`@`)")]
+    Version(String),
+}
+
+impl FromStr for ExecutableName {
+    type Err = ExecutableNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ExecutableNameError::Empty);
+        }
+        if s.chars().any(char::is_whitespace) {
+            return Err(ExecutableNameError::Whitespace(s.to_string()));
+        }
+        if s.contains(['/', '\\']) {
+            return Err(ExecutableNameError::PathSeparator(s.to_string()));
+        }
+        if s.contains('@') {
+            return Err(ExecutableNameError::Version(s.to_string()));
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl Borrow<str> for ExecutableName {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ExecutableName {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("ExecutableName")
+    }
+
+    fn json_schema(_generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "The name of an executable provided by a package, e.g., `http`."
+        })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ExecutableName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = ExecutableName;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                ExecutableName::from_str(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+impl std::fmt::Display for ExecutableName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
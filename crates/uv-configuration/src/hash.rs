@@ -1,8 +1,13 @@
+use uv_pypi_types::HashAlgorithm;
+
 #[derive(Debug, Copy, Clone)]
 pub enum HashCheckingMode {
     /// Hashes should be validated against a pre-defined list of hashes. Every requirement must
     /// itself be hashable (e.g., Git dependencies are forbidden) _and_ have a hash in the lockfile.
     Require,
+    /// Like [`HashCheckingMode::Require`], but rather than failing on the first requirement that
+    /// lacks a hash, resolve the requirements, compute any missing hashes, and report them all.
+    Derive,
     /// Hashes should be validated, if present, but ignored if absent.
     Verify,
 }
@@ -11,10 +16,18 @@ impl HashCheckingMode {
     /// Return the [`HashCheckingMode`] from the command-line arguments, if any.
     ///
     /// By default, the hash checking mode is [`HashCheckingMode::Verify`]. If `--require-hashes` is
-    /// passed, the hash checking mode is [`HashCheckingMode::Require`]. If `--no-verify-hashes` is
+    /// passed, the hash checking mode is [`HashCheckingMode::Require`]. If `--derive-hashes` is
+    /// passed, the hash checking mode is [`HashCheckingMode::Derive`]. If `--no-verify-hashes` is
     /// passed, then no hash checking is performed.
-    pub fn from_args(require_hashes: Option<bool>, verify_hashes: Option<bool>) -> Option<Self> {
-        if require_hashes == Some(true) {
+    pub fn from_args(
+        require_hashes: Option<bool>,
+        verify_hashes: Option<bool>,
+        derive_hashes: Option<bool>,
+    ) -> Option<Self> {
+        if derive_hashes == Some(true) {
+            // Given `--derive-hashes`, require hashes, but report any that are missing.
+            Some(Self::Derive)
+        } else if require_hashes == Some(true) {
             // Given `--require-hashes`, always require hashes, regardless of any other flags.
             Some(Self::Require)
         } else if verify_hashes == Some(true) {
@@ -32,9 +45,14 @@ impl HashCheckingMode {
         }
     }
 
-    /// Returns `true` if the hash checking mode is `Require`.
+    /// Returns `true` if the hash checking mode is `Require` or `Derive`.
     pub fn is_require(&self) -> bool {
-        matches!(self, Self::Require)
+        matches!(self, Self::Require | Self::Derive)
+    }
+
+    /// Returns `true` if the hash checking mode is `Derive`.
+    pub fn is_derive(&self) -> bool {
+        matches!(self, Self::Derive)
     }
 }
 
@@ -42,7 +60,44 @@ impl std::fmt::Display for HashCheckingMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Require => write!(f, "--require-hashes"),
+            Self::Derive => write!(f, "--derive-hashes"),
             Self::Verify => write!(f, "--verify-hashes"),
         }
     }
 }
+
+/// The hash algorithm to record for each artifact when locking or generating hashes.
+///
+/// If an artifact doesn't provide a digest for the preferred algorithm, the strongest available
+/// digest is recorded instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum HashAlgorithmPreference {
+    /// Prefer SHA-256 digests.
+    Sha256,
+    /// Prefer SHA-384 digests.
+    Sha384,
+    /// Prefer SHA-512 digests.
+    Sha512,
+    /// Prefer BLAKE2b digests.
+    Blake2b,
+}
+
+impl From<HashAlgorithmPreference> for HashAlgorithm {
+    fn from(value: HashAlgorithmPreference) -> Self {
+        match value {
+            HashAlgorithmPreference::Sha256 => Self::Sha256,
+            HashAlgorithmPreference::Sha384 => Self::Sha384,
+            HashAlgorithmPreference::Sha512 => Self::Sha512,
+            HashAlgorithmPreference::Blake2b => Self::Blake2b,
+        }
+    }
+}
+
+impl std::fmt::Display for HashAlgorithmPreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        HashAlgorithm::from(*self).fmt(f)
+    }
+}
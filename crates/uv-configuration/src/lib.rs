@@ -1,4 +1,5 @@
 pub use authentication::*;
+pub use build_limits::*;
 pub use build_options::*;
 pub use concurrency::*;
 pub use config_settings::*;
@@ -6,6 +7,7 @@ pub use constraints::*;
 pub use dependency_groups::*;
 pub use dry_run::*;
 pub use editable::*;
+pub use executable_name::*;
 pub use export_format::*;
 pub use extras::*;
 pub use hash::*;
@@ -16,14 +18,17 @@ pub use package_options::*;
 pub use preview::*;
 pub use project_build_backend::*;
 pub use required_version::*;
+pub use script_template::*;
 pub use sources::*;
 pub use target_triple::*;
 pub use threading::*;
 pub use trusted_host::*;
 pub use trusted_publishing::*;
+pub use upgrade_policy::*;
 pub use vcs::*;
 
 mod authentication;
+mod build_limits;
 mod build_options;
 mod concurrency;
 mod config_settings;
@@ -31,6 +36,7 @@ mod constraints;
 mod dependency_groups;
 mod dry_run;
 mod editable;
+mod executable_name;
 mod export_format;
 mod extras;
 mod hash;
@@ -41,9 +47,11 @@ mod package_options;
 mod preview;
 mod project_build_backend;
 mod required_version;
+mod script_template;
 mod sources;
 mod target_triple;
 mod threading;
 mod trusted_host;
 mod trusted_publishing;
+mod upgrade_policy;
 mod vcs;
@@ -143,7 +143,9 @@ pub enum Upgrade {
     None,
 
     /// Allow package upgrades for all packages, ignoring the existing lockfile.
-    All,
+    ///
+    /// Tracks any packages that were also named explicitly (e.g., via `--upgrade-package`).
+    All(FxHashMap<PackageName, Vec<Requirement>>),
 
     /// Allow package upgrades, but only for the specified packages.
     Packages(FxHashMap<PackageName, Vec<Requirement>>),
@@ -152,22 +154,23 @@ pub enum Upgrade {
 impl Upgrade {
     /// Determine the [`Upgrade`] strategy from the command-line arguments.
     pub fn from_args(upgrade: Option<bool>, upgrade_package: Vec<Requirement>) -> Self {
+        let packages =
+            upgrade_package
+                .into_iter()
+                .fold(FxHashMap::default(), |mut map, requirement| {
+                    map.entry(requirement.name.clone())
+                        .or_default()
+                        .push(requirement);
+                    map
+                });
         match upgrade {
-            Some(true) => Self::All,
+            Some(true) => Self::All(packages),
             Some(false) => Self::None,
             None => {
-                if upgrade_package.is_empty() {
+                if packages.is_empty() {
                     Self::None
                 } else {
-                    Self::Packages(upgrade_package.into_iter().fold(
-                        FxHashMap::default(),
-                        |mut map, requirement| {
-                            map.entry(requirement.name.clone())
-                                .or_default()
-                                .push(requirement);
-                            map
-                        },
-                    ))
+                    Self::Packages(packages)
                 }
             }
         }
@@ -189,18 +192,27 @@ impl Upgrade {
 
     /// Returns `true` if all packages should be upgraded.
     pub fn is_all(&self) -> bool {
-        matches!(self, Self::All)
+        matches!(self, Self::All(_))
     }
 
     /// Returns `true` if the specified package should be upgraded.
     pub fn contains(&self, package_name: &PackageName) -> bool {
         match self {
             Self::None => false,
-            Self::All => true,
+            Self::All(_) => true,
             Self::Packages(packages) => packages.contains_key(package_name),
         }
     }
 
+    /// Returns `true` if the specified package was named explicitly (e.g., via
+    /// `--upgrade-package`), regardless of whether all packages should be upgraded.
+    pub fn is_explicit(&self, package_name: &PackageName) -> bool {
+        match self {
+            Self::None => false,
+            Self::All(packages) | Self::Packages(packages) => packages.contains_key(package_name),
+        }
+    }
+
     /// Returns an iterator over the constraints.
     ///
     /// When upgrading, users can provide bounds on the upgrade (e.g., `--upgrade-package flask<3`).
@@ -216,14 +228,41 @@ impl Upgrade {
         }
     }
 
+    /// Narrow an [`Upgrade::All`] strategy to the given packages, per the [`UpgradeStrategy`].
+    ///
+    /// Under [`UpgradeStrategy::OnlyIfNeeded`], only the requested packages are upgraded; any other
+    /// package retains its installed version unless an upgrade is required to satisfy the
+    /// requested packages.
+    #[must_use]
+    pub fn with_strategy(
+        self,
+        strategy: UpgradeStrategy,
+        requested: impl IntoIterator<Item = PackageName>,
+    ) -> Self {
+        match (self, strategy) {
+            (Self::All(_), UpgradeStrategy::OnlyIfNeeded) => Self::Packages(
+                requested
+                    .into_iter()
+                    .map(|package| (package, Vec::new()))
+                    .collect(),
+            ),
+            (upgrade, _) => upgrade,
+        }
+    }
+
     /// Combine a set of [`Upgrade`] values.
     #[must_use]
     pub fn combine(self, other: Self) -> Self {
         match (self, other) {
             // If both are `None`, the result is `None`.
             (Self::None, Self::None) => Self::None,
-            // If either is `All`, the result is `All`.
-            (Self::All, _) | (_, Self::All) => Self::All,
+            // If either is `All`, the result is `All`, retaining any explicitly named packages.
+            (Self::All(mut a), Self::All(b) | Self::Packages(b))
+            | (Self::Packages(b), Self::All(mut a)) => {
+                a.extend(b);
+                Self::All(a)
+            }
+            (Self::All(a), Self::None) | (Self::None, Self::All(a)) => Self::All(a),
             // If one is `None`, the result is the other.
             (Self::Packages(a), Self::None) => Self::Packages(a),
             (Self::None, Self::Packages(b)) => Self::Packages(b),
@@ -236,12 +275,25 @@ impl Upgrade {
     }
 }
 
+/// The strategy to use when upgrading the dependencies of requested packages.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum UpgradeStrategy {
+    /// Upgrade all packages, including the dependencies of the requested packages.
+    #[default]
+    Eager,
+    /// Upgrade the requested packages, and upgrade their dependencies only if required to satisfy
+    /// the requested packages.
+    OnlyIfNeeded,
+}
+
 /// Create a [`Refresh`] policy by integrating the [`Upgrade`] policy.
 impl From<Upgrade> for Refresh {
     fn from(value: Upgrade) -> Self {
         match value {
             Upgrade::None => Self::None(Timestamp::now()),
-            Upgrade::All => Self::All(Timestamp::now()),
+            Upgrade::All(_) => Self::All(Timestamp::now()),
             Upgrade::Packages(packages) => Self::Packages(
                 packages.into_keys().collect::<Vec<_>>(),
                 Vec::new(),
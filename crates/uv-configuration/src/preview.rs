@@ -15,6 +15,8 @@ bitflags::bitflags! {
         const PYLOCK = 1 << 3;
         const ADD_BOUNDS = 1 << 4;
         const EXTRA_BUILD_DEPENDENCIES = 1 << 5;
+        const WORKSPACE_LIST = 1 << 6;
+        const RESOLUTION_BUNDLE = 1 << 7;
     }
 }
 
@@ -30,6 +32,8 @@ impl PreviewFeatures {
             Self::PYLOCK => "pylock",
             Self::ADD_BOUNDS => "add-bounds",
             Self::EXTRA_BUILD_DEPENDENCIES => "extra-build-dependencies",
+            Self::WORKSPACE_LIST => "workspace-list",
+            Self::RESOLUTION_BUNDLE => "resolution-bundle",
             _ => panic!("`flag_as_str` can only be used for exactly one feature flag"),
         }
     }
@@ -73,6 +77,8 @@ impl FromStr for PreviewFeatures {
                 "pylock" => Self::PYLOCK,
                 "add-bounds" => Self::ADD_BOUNDS,
                 "extra-build-dependencies" => Self::EXTRA_BUILD_DEPENDENCIES,
+                "workspace-list" => Self::WORKSPACE_LIST,
+                "resolution-bundle" => Self::RESOLUTION_BUNDLE,
                 _ => {
                     warn_user_once!("Unknown preview feature: `{part}`");
                     continue;
@@ -239,6 +245,14 @@ mod tests {
             PreviewFeatures::EXTRA_BUILD_DEPENDENCIES.flag_as_str(),
             "extra-build-dependencies"
         );
+        assert_eq!(
+            PreviewFeatures::WORKSPACE_LIST.flag_as_str(),
+            "workspace-list"
+        );
+        assert_eq!(
+            PreviewFeatures::RESOLUTION_BUNDLE.flag_as_str(),
+            "resolution-bundle"
+        );
     }
 
     #[test]
@@ -0,0 +1,10 @@
+/// Available templates for the body of a script created with `uv init --script`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum ScriptTemplate {
+    /// A minimal `main` function that prints a greeting.
+    #[default]
+    Default,
+    /// A command-line interface with an `argparse`-based `main` function.
+    Cli,
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+
+/// The range of versions to which a locked package may be upgraded.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum UpgradePolicy {
+    /// Allow upgrades to any version.
+    #[default]
+    Any,
+    /// Allow upgrades within the locked minor release series (e.g., from `5.0.3` to `5.0.x`).
+    Minor,
+    /// Allow patch upgrades within the locked minor release series (e.g., from `5.0.3` to `5.0.x`).
+    Patch,
+    /// Disallow upgrades, retaining the locked version.
+    Pin,
+}
+
+impl UpgradePolicy {
+    /// Returns the constraint implied by the policy for a package locked at the given version, if
+    /// any.
+    ///
+    /// For example, under the [`UpgradePolicy::Minor`] policy, a package locked at `5.0.3` is
+    /// constrained to `>=5.0.3, <5.1`.
+    pub fn specifiers(self, version: &Version) -> Option<VersionSpecifiers> {
+        let segments = match self {
+            Self::Any => return None,
+            Self::Pin => {
+                return Some(VersionSpecifiers::from(VersionSpecifier::equals_version(
+                    version.clone(),
+                )));
+            }
+            Self::Minor | Self::Patch => 2,
+        };
+
+        // Bump the last retained segment of the release, padding with zeros as necessary.
+        let mut release = version.release().iter().copied().collect::<Vec<_>>();
+        release.resize(segments, 0);
+        release[segments - 1] += 1;
+        let upper = Version::new(release).with_epoch(version.epoch());
+
+        Some(
+            [
+                VersionSpecifier::greater_than_equal_version(version.clone()),
+                VersionSpecifier::less_than_version(upper),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn specifiers() {
+        let version = Version::from_str("5.0.3").unwrap();
+        assert_eq!(UpgradePolicy::Any.specifiers(&version), None);
+        assert_eq!(
+            UpgradePolicy::Minor
+                .specifiers(&version)
+                .unwrap()
+                .to_string(),
+            ">=5.0.3, <5.1"
+        );
+        assert_eq!(
+            UpgradePolicy::Patch
+                .specifiers(&version)
+                .unwrap()
+                .to_string(),
+            ">=5.0.3, <5.1"
+        );
+        assert_eq!(
+            UpgradePolicy::Pin.specifiers(&version).unwrap().to_string(),
+            "==5.0.3"
+        );
+
+        // Short releases are padded with zeros.
+        let version = Version::from_str("2").unwrap();
+        assert_eq!(
+            UpgradePolicy::Minor
+                .specifiers(&version)
+                .unwrap()
+                .to_string(),
+            ">=2, <2.1"
+        );
+    }
+}
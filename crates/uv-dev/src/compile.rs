@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use clap::Parser;
+use rustc_hash::FxHashSet;
 use tracing::info;
 
 use uv_cache::{Cache, CacheArgs};
@@ -38,6 +39,7 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         &interpreter,
         &Concurrency::default(),
         cache.root(),
+        &FxHashSet::default(),
     )
     .await?;
     info!("Compiled {files} files");
@@ -486,6 +486,7 @@ impl BuildContext for BuildDispatch<'_> {
             build_kind,
             environment_variables,
             build_output,
+            self.build_options.limits(),
             self.concurrency.builds,
             self.preview,
         )
@@ -520,6 +521,7 @@ impl BuildContext for BuildDispatch<'_> {
 
         let output_dir = output_dir.to_path_buf();
         let filename = tokio::task::spawn_blocking(move || -> Result<_> {
+            let source_date_epoch = uv_build_backend::source_date_epoch()?;
             let filename = match build_kind {
                 BuildKind::Wheel => {
                     let wheel = uv_build_backend::build_wheel(
@@ -527,6 +529,7 @@ impl BuildContext for BuildDispatch<'_> {
                         &output_dir,
                         None,
                         uv_version::version(),
+                        source_date_epoch,
                     )?;
                     DistFilename::WheelFilename(wheel)
                 }
@@ -535,6 +538,7 @@ impl BuildContext for BuildDispatch<'_> {
                         &source_tree,
                         &output_dir,
                         uv_version::version(),
+                        source_date_epoch,
                     )?;
                     DistFilename::SourceDistFilename(source_dist)
                 }
@@ -544,6 +548,7 @@ impl BuildContext for BuildDispatch<'_> {
                         &output_dir,
                         None,
                         uv_version::version(),
+                        source_date_epoch,
                     )?;
                     DistFilename::WheelFilename(wheel)
                 }
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 use std::str::FromStr;
@@ -87,12 +88,33 @@ impl WheelFilename {
 
     /// Returns `true` if the wheel is compatible with the given tags.
     pub fn is_compatible(&self, compatible_tags: &Tags) -> bool {
-        compatible_tags.is_compatible(self.python_tags(), self.abi_tags(), self.platform_tags())
+        compatible_tags.is_compatible_package(
+            &self.name,
+            self.python_tags(),
+            self.abi_tags(),
+            self.platform_tags(),
+        )
     }
 
     /// Return the [`TagCompatibility`] of the wheel with the given tags
     pub fn compatibility(&self, compatible_tags: &Tags) -> TagCompatibility {
-        compatible_tags.compatibility(self.python_tags(), self.abi_tags(), self.platform_tags())
+        compatible_tags.compatibility_package(
+            &self.name,
+            self.python_tags(),
+            self.abi_tags(),
+            self.platform_tags(),
+        )
+    }
+
+    /// Returns `true` if the wheel is only compatible with the given tags due to a platform tag
+    /// override for the package.
+    pub fn is_override(&self, compatible_tags: &Tags) -> bool {
+        compatible_tags.is_override(
+            &self.name,
+            self.python_tags(),
+            self.abi_tags(),
+            self.platform_tags(),
+        )
     }
 
     /// The wheel filename without the extension.
@@ -164,6 +186,30 @@ impl WheelFilename {
         }
     }
 
+    /// Break a tie between two wheels that are equally compatible with the target platform (i.e.,
+    /// that share the same tag priority and build tag).
+    ///
+    /// Returns [`Ordering::Greater`] if `self` should be preferred over `other`. Ties are broken
+    /// by, in order:
+    ///
+    /// 1. The number of platform tags, preferring fewer (e.g., `macosx_11_0_x86_64` over
+    ///    `macosx_11_0_x86_64.macosx_11_0_universal2`).
+    /// 2. The number of ABI tags, preferring fewer.
+    /// 3. The number of Python tags, preferring fewer.
+    /// 4. The filename, preferring the lexicographically smallest.
+    ///
+    /// The final step guarantees a total order, such that the selected wheel is independent of
+    /// the order in which the wheels were discovered.
+    pub fn cmp_tie_break(&self, other: &Self) -> Ordering {
+        other
+            .platform_tags()
+            .len()
+            .cmp(&self.platform_tags().len())
+            .then_with(|| other.abi_tags().len().cmp(&self.abi_tags().len()))
+            .then_with(|| other.python_tags().len().cmp(&self.python_tags().len()))
+            .then_with(|| other.to_string().cmp(&self.to_string()))
+    }
+
     /// Parse a wheel filename from the stem (e.g., `foo-1.2.3-py3-none-any`).
     pub fn from_stem(stem: &str) -> Result<Self, WheelFilenameError> {
         // The wheel stem should not contain the `.whl` extension.
@@ -456,6 +502,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cmp_tie_break() {
+        // Returns the preferred wheel among the given (equally compatible) wheels, regardless of
+        // the order in which they're provided.
+        fn best<'a>(wheels: &[&'a str]) -> &'a str {
+            let cmp = |a: &&str, b: &&str| {
+                WheelFilename::from_str(a)
+                    .unwrap()
+                    .cmp_tie_break(&WheelFilename::from_str(b).unwrap())
+            };
+            let forward = wheels.iter().copied().max_by(cmp).unwrap();
+            let backward = wheels.iter().rev().copied().max_by(cmp).unwrap();
+            assert_eq!(forward, backward);
+            forward
+        }
+
+        // Fewer platform tags are preferred.
+        assert_eq!(
+            best(&[
+                "foo-1.0-cp312-cp312-macosx_11_0_universal2.macosx_11_0_x86_64.whl",
+                "foo-1.0-cp312-cp312-macosx_11_0_x86_64.whl",
+            ]),
+            "foo-1.0-cp312-cp312-macosx_11_0_x86_64.whl"
+        );
+
+        // Fewer ABI tags are preferred.
+        assert_eq!(
+            best(&[
+                "foo-1.0-cp312-abi3.cp312-manylinux_2_17_x86_64.whl",
+                "foo-1.0-cp312-cp312-manylinux_2_17_x86_64.whl",
+            ]),
+            "foo-1.0-cp312-cp312-manylinux_2_17_x86_64.whl"
+        );
+
+        // Fewer Python tags are preferred.
+        assert_eq!(
+            best(&["foo-1.0-py2.py3-none-any.whl", "foo-1.0-py3-none-any.whl"]),
+            "foo-1.0-py3-none-any.whl"
+        );
+
+        // Platform tags take precedence over Python tags.
+        assert_eq!(
+            best(&[
+                "foo-1.0-py3-none-manylinux1_x86_64.manylinux_2_5_x86_64.whl",
+                "foo-1.0-py2.py3-none-manylinux_2_5_x86_64.whl",
+            ]),
+            "foo-1.0-py2.py3-none-manylinux_2_5_x86_64.whl"
+        );
+
+        // Otherwise, the lexicographically smallest filename is preferred.
+        assert_eq!(
+            best(&[
+                "foo-1.0-cp312-cp312-macosx_11_0_x86_64.whl",
+                "foo-1.0-cp312-cp312-macosx_10_9_x86_64.whl",
+                "foo-1.0-cp312-cp312-macosx_10_12_x86_64.whl",
+            ]),
+            "foo-1.0-cp312-cp312-macosx_10_12_x86_64.whl"
+        );
+
+        // Identical wheels compare equal.
+        let wheel = WheelFilename::from_str("foo-1.0-py3-none-any.whl").unwrap();
+        assert_eq!(wheel.cmp_tie_break(&wheel), Ordering::Equal);
+    }
+
     #[test]
     fn cache_key() {
         // Short names should use `version-tags` format.
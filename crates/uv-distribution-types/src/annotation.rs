@@ -25,12 +25,8 @@ impl std::fmt::Display for SourceAnnotation {
                 RequirementOrigin::Project(path, project_name) => {
                     write!(f, "{project_name} ({})", path.portable_display())
                 }
-                RequirementOrigin::Group(path, project_name, group) => {
-                    if let Some(project_name) = project_name {
-                        write!(f, "{project_name} ({}:{group})", path.portable_display())
-                    } else {
-                        write!(f, "({}:{group})", path.portable_display())
-                    }
+                RequirementOrigin::Group(path, _, group) => {
+                    write!(f, "group:{group} ({})", path.portable_display())
                 }
                 RequirementOrigin::Workspace => {
                     write!(f, "(workspace)")
@@ -105,6 +105,24 @@ pub struct Index {
     /// ```
     #[serde(default)]
     pub authenticate: AuthPolicy,
+    /// Additional URL prefixes that may receive the credentials configured for the index.
+    ///
+    /// By default, credentials for an index are only sent to URLs under the index URL (or its
+    /// root, for indexes with a `/simple` endpoint). If the index serves files from (or redirects
+    /// to) a different host, those hosts can be listed here to receive the same credentials, as
+    /// in:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.corp/simple"
+    /// auth-scope = ["https://files.internal.corp/"]
+    /// ```
+    ///
+    /// Credentials are never sent to URLs outside of the index URL and its declared scope,
+    /// including on redirects.
+    #[serde(default)]
+    pub auth_scope: Vec<DisplaySafeUrl>,
     /// Status codes that uv should ignore when deciding whether
     /// to continue searching in the next index after a failure.
     ///
@@ -166,6 +184,7 @@ impl Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_scope: Vec::new(),
             ignore_error_codes: None,
             cache_control: None,
         }
@@ -182,6 +201,7 @@ impl Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_scope: Vec::new(),
             ignore_error_codes: None,
             cache_control: None,
         }
@@ -198,6 +218,7 @@ impl Index {
             format: IndexFormat::Flat,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_scope: Vec::new(),
             ignore_error_codes: None,
             cache_control: None,
         }
@@ -277,6 +298,7 @@ impl From<IndexUrl> for Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_scope: Vec::new(),
             ignore_error_codes: None,
             cache_control: None,
         }
@@ -301,6 +323,7 @@ impl FromStr for Index {
                     format: IndexFormat::Simple,
                     publish_url: None,
                     authenticate: AuthPolicy::default(),
+                    auth_scope: Vec::new(),
                     ignore_error_codes: None,
                     cache_control: None,
                 });
@@ -318,6 +341,7 @@ impl FromStr for Index {
             format: IndexFormat::Simple,
             publish_url: None,
             authenticate: AuthPolicy::default(),
+            auth_scope: Vec::new(),
             ignore_error_codes: None,
             cache_control: None,
         })
@@ -462,6 +462,9 @@ impl<'a> IndexLocations {
                 if let Some(root_url) = index.root_url() {
                     uv_auth::store_credentials(&root_url, credentials.clone());
                 }
+                for scope in &index.auth_scope {
+                    uv_auth::store_credentials(scope, credentials.clone());
+                }
             }
         }
     }
@@ -500,6 +503,7 @@ impl From<&IndexLocations> for uv_auth::Indexes {
                 url,
                 root_url,
                 auth_policy: index.authenticate,
+                auth_scope: index.auth_scope.clone(),
             }
         }))
     }
@@ -780,6 +784,7 @@ mod tests {
                 format: IndexFormat::Simple,
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
+                auth_scope: Vec::new(),
                 ignore_error_codes: None,
             },
             Index {
@@ -792,6 +797,7 @@ mod tests {
                 format: IndexFormat::Simple,
                 publish_url: None,
                 authenticate: uv_auth::AuthPolicy::default(),
+                auth_scope: Vec::new(),
                 ignore_error_codes: None,
             },
         ];
@@ -383,9 +383,14 @@ impl PrioritizedDist {
         if !compatibility.is_excluded() {
             self.0.hashes.extend(hashes);
         }
-        // Track the highest-priority wheel.
-        if let Some((.., existing_compatibility)) = self.best_wheel() {
-            if compatibility.is_more_compatible(existing_compatibility) {
+        // Track the highest-priority wheel, breaking ties between equally compatible wheels
+        // deterministically.
+        if let Some((existing_dist, existing_compatibility)) = self.best_wheel() {
+            let is_preferred = compatibility.is_more_compatible(existing_compatibility)
+                || (compatibility.is_compatible()
+                    && compatibility == *existing_compatibility
+                    && dist.filename.cmp_tie_break(&existing_dist.filename).is_gt());
+            if is_preferred {
                 self.0.best_wheel_index = Some(self.0.wheels.len());
             }
         } else {
@@ -676,7 +681,8 @@ impl WheelCompatibility {
     /// Return `true` if the current compatibility is more compatible than another.
     ///
     /// Compatible wheels are always higher more compatible than incompatible wheels.
-    /// Compatible wheel ordering is determined by tag priority.
+    /// Compatible wheel ordering is determined by tag priority. Equally compatible wheels are
+    /// ordered by [`WheelFilename::cmp_tie_break`].
     pub fn is_more_compatible(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Compatible(_, _, _), Self::Incompatible(_)) => true,
@@ -864,6 +864,10 @@ impl From<RequirementSource> for RequirementSourceWire {
                     GitReference::Tag(tag) => {
                         url.query_pairs_mut().append_pair("tag", tag.as_str());
                     }
+                    GitReference::TagPattern(pattern) => {
+                        url.query_pairs_mut()
+                            .append_pair("tag-pattern", pattern.as_str());
+                    }
                     GitReference::BranchOrTag(rev)
                     | GitReference::BranchOrTagOrCommit(rev)
                     | GitReference::NamedRef(rev) => {
@@ -935,6 +939,7 @@ impl TryFrom<RequirementSourceWire> for RequirementSource {
                 for (key, val) in repository.query_pairs() {
                     match &*key {
                         "tag" => reference = GitReference::Tag(val.into_owned()),
+                        "tag-pattern" => reference = GitReference::TagPattern(val.into_owned()),
                         "branch" => reference = GitReference::Branch(val.into_owned()),
                         "rev" => reference = GitReference::from_rev(val.into_owned()),
                         "subdirectory" => {
@@ -20,7 +20,8 @@ use uv_client::{
 };
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{
-    BuildableSource, BuiltDist, Dist, HashPolicy, Hashed, IndexUrl, InstalledDist, Name, SourceDist,
+    BuildableSource, BuiltDist, Dist, HashPolicy, Hashed, IndexUrl, InstalledDist, Name,
+    Resolution, SourceDist,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
@@ -524,6 +525,20 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
         Ok(metadata)
     }
 
+    /// Resolve the build requirements for a source distribution, setting up its build environment.
+    ///
+    /// Returns `None` if the build environment isn't isolated.
+    pub async fn build_requirements(
+        &self,
+        dist: &SourceDist,
+        hashes: HashPolicy<'_>,
+    ) -> Result<Option<Resolution>, Error> {
+        self.builder
+            .download_and_resolve_build_requirements(dist, hashes, &self.client)
+            .boxed_local()
+            .await
+    }
+
     /// Return the [`RequiresDist`] from a `pyproject.toml`, if it can be statically extracted.
     pub async fn requires_dist(
         &self,
@@ -165,6 +165,7 @@ impl LoweredRequirement {
                             subdirectory,
                             rev,
                             tag,
+                            tag_pattern,
                             branch,
                             marker,
                             ..
@@ -174,6 +175,7 @@ impl LoweredRequirement {
                                 subdirectory.map(Box::<Path>::from),
                                 rev,
                                 tag,
+                                tag_pattern,
                                 branch,
                             )?;
                             (source, marker)
@@ -405,6 +407,7 @@ impl LoweredRequirement {
                             subdirectory,
                             rev,
                             tag,
+                            tag_pattern,
                             branch,
                             marker,
                             ..
@@ -414,6 +417,7 @@ impl LoweredRequirement {
                                 subdirectory.map(Box::<Path>::from),
                                 rev,
                                 tag,
+                                tag_pattern,
                                 branch,
                             )?;
                             (source, marker)
@@ -518,7 +522,7 @@ pub enum LoweringError {
         "`{0}` references a workspace in `tool.uv.sources` (e.g., `{0} = {{ workspace = true }}`), but is not a workspace member"
     )]
     UndeclaredWorkspacePackage(PackageName),
-    #[error("Can only specify one of: `rev`, `tag`, or `branch`")]
+    #[error("Can only specify one of: `rev`, `tag`, `tag-pattern`, or `branch`")]
     MoreThanOneGitRef,
     #[error(transparent)]
     GitUrlParse(#[from] GitUrlParseError),
@@ -579,13 +583,15 @@ fn git_source(
     subdirectory: Option<Box<Path>>,
     rev: Option<String>,
     tag: Option<String>,
+    tag_pattern: Option<String>,
     branch: Option<String>,
 ) -> Result<RequirementSource, LoweringError> {
-    let reference = match (rev, tag, branch) {
-        (None, None, None) => GitReference::DefaultBranch,
-        (Some(rev), None, None) => GitReference::from_rev(rev),
-        (None, Some(tag), None) => GitReference::Tag(tag),
-        (None, None, Some(branch)) => GitReference::Branch(branch),
+    let reference = match (rev, tag, tag_pattern, branch) {
+        (None, None, None, None) => GitReference::DefaultBranch,
+        (Some(rev), None, None, None) => GitReference::from_rev(rev),
+        (None, Some(tag), None, None) => GitReference::Tag(tag),
+        (None, None, Some(pattern), None) => GitReference::TagPattern(pattern),
+        (None, None, None, Some(branch)) => GitReference::Branch(branch),
         _ => return Err(LoweringError::MoreThanOneGitRef),
     };
 
@@ -15,7 +15,7 @@ pub use crate::metadata::build_requires::{BuildRequires, LoweredExtraBuildDepend
 pub use crate::metadata::dependency_groups::SourcedDependencyGroups;
 pub use crate::metadata::lowering::LoweredRequirement;
 pub use crate::metadata::lowering::LoweringError;
-pub use crate::metadata::requires_dist::{FlatRequiresDist, RequiresDist};
+pub use crate::metadata::requires_dist::{ExtraCycle, FlatRequiresDist, RequiresDist};
 
 mod build_requires;
 mod dependency_groups;
@@ -48,6 +48,20 @@ pub enum MetadataError {
         "Source entry for `{0}` only applies to dependency group `{1}`, but `{0}` was not found under the `dependency-groups` section for that group. When a group is present on a source (e.g., `group = \"{1}\"`), the relevant package must be included in the `dependency-groups` section for that extra (e.g., `dependency-groups = {{ \"{1}\" = [\"{0}\"] }}`)."
     )]
     IncompleteSourceGroup(PackageName, GroupName),
+    #[error("Detected a cycle in `project.optional-dependencies`: {0}")]
+    ExtraCycle(ExtraCycle),
+    #[error(
+        "`{0}[{1}]` is required by the project itself, but the `{1}` extra is not defined in `project.optional-dependencies`{hint}",
+        hint = did_you_mean(.2.as_ref())
+    )]
+    MissingSelfExtra(PackageName, ExtraName, Option<ExtraName>),
+}
+
+/// Format a suggestion for a misspelled extra, if any.
+fn did_you_mean(suggestion: Option<&ExtraName>) -> String {
+    suggestion
+        .map(|suggestion| format!(" (did you mean `{suggestion}`?)"))
+        .unwrap_or_default()
 }
 
 #[derive(Debug, Clone)]
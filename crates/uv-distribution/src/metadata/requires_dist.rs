@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::path::Path;
 use std::slice;
 
@@ -116,6 +116,9 @@ impl RequiresDist {
         // a valid extra or group, if present.
         Self::validate_sources(project_sources, &metadata, &dependency_groups)?;
 
+        // Validate any extras that the project requires from itself.
+        Self::validate_self_extras(&metadata)?;
+
         // Lower the dependency groups.
         let dependency_groups = dependency_groups
             .into_iter()
@@ -261,6 +264,105 @@ impl RequiresDist {
 
         Ok(())
     }
+
+    /// Validate the extras that a project requires from itself, e.g., `all = ["project[docs]"]`.
+    ///
+    /// Each referenced extra must be defined in `project.optional-dependencies`, and extras must not
+    /// include one another in a cycle.
+    fn validate_self_extras(metadata: &uv_pypi_types::RequiresDist) -> Result<(), MetadataError> {
+        /// Walk the extras included by the given extra, erroring if any extra includes itself.
+        fn visit<'data>(
+            extra: &'data ExtraName,
+            includes: &'data BTreeMap<ExtraName, BTreeSet<ExtraName>>,
+            parents: &mut Vec<&'data ExtraName>,
+            visited: &mut FxHashSet<&'data ExtraName>,
+        ) -> Result<(), MetadataError> {
+            if let Some(index) = parents.iter().position(|parent| *parent == extra) {
+                return Err(MetadataError::ExtraCycle(ExtraCycle(
+                    parents[index..].iter().copied().cloned().collect(),
+                )));
+            }
+            if !visited.insert(extra) {
+                return Ok(());
+            }
+            if let Some(included) = includes.get(extra) {
+                parents.push(extra);
+                for included in included {
+                    visit(included, includes, parents, visited)?;
+                }
+                parents.pop();
+            }
+            Ok(())
+        }
+
+        // Map each extra to the extras it includes from the project itself. (Since the package
+        // names are normalized, this also covers references that use a different spelling of the
+        // project name, e.g., `My_Project[docs]`.)
+        let mut includes: BTreeMap<ExtraName, BTreeSet<ExtraName>> = BTreeMap::new();
+        for requirement in &metadata.requires_dist {
+            if requirement.name != metadata.name {
+                continue;
+            }
+            for extra in &requirement.extras {
+                if !metadata.provides_extras.contains(extra) {
+                    let suggestion = metadata
+                        .provides_extras
+                        .iter()
+                        .map(|candidate| {
+                            (
+                                strsim::jaro_winkler(extra.as_str(), candidate.as_str()),
+                                candidate,
+                            )
+                        })
+                        .filter(|(similarity, _)| *similarity > 0.8)
+                        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+                        .map(|(_, candidate)| candidate.clone());
+                    return Err(MetadataError::MissingSelfExtra(
+                        metadata.name.clone(),
+                        extra.clone(),
+                        suggestion,
+                    ));
+                }
+                // An extra that includes itself (e.g., `foo = ["project[foo]"]`) is a no-op, rather
+                // than a cycle.
+                if let Some(top_level_extra) = requirement.marker.top_level_extra_name() {
+                    if *top_level_extra != *extra {
+                        includes
+                            .entry(top_level_extra.into_owned())
+                            .or_default()
+                            .insert(extra.clone());
+                    }
+                }
+            }
+        }
+
+        let mut parents = Vec::new();
+        let mut visited = FxHashSet::default();
+        for extra in includes.keys() {
+            visit(extra, &includes, &mut parents, &mut visited)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A cycle in the `project.optional-dependencies` table.
+#[derive(Debug)]
+pub struct ExtraCycle(Vec<ExtraName>);
+
+/// Display a cycle, e.g., `a -> b -> c -> a`.
+impl std::fmt::Display for ExtraCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [first, rest @ ..] = self.0.as_slice() else {
+            return Ok(());
+        };
+        write!(f, "`{first}`")?;
+        for extra in rest {
+            write!(f, " -> `{extra}`")?;
+        }
+        write!(f, " -> `{first}`")?;
+        Ok(())
+    }
 }
 
 impl From<Metadata> for RequiresDist {
@@ -459,7 +561,8 @@ mod test {
             &WorkspaceCache::default(),
         )
         .await?;
-        let requires_dist = uv_pypi_types::RequiresDist::parse_pyproject_toml(contents)?;
+        let requires_dist =
+            uv_pypi_types::RequiresDist::parse_pyproject_toml(contents, &std::env::current_dir()?)?;
         Ok(RequiresDist::from_project_workspace(
             requires_dist,
             &project_workspace,
@@ -523,7 +626,7 @@ mod test {
           |
         8 | tqdm = { git = "https://github.com/tqdm/tqdm", rev = "baaaaaab", tag = "v1.0.0" }
           |        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-        expected at most one of `rev`, `tag`, or `branch`
+        expected at most one of `rev`, `tag`, `tag-pattern`, or `branch`
         "###);
     }
 
@@ -545,7 +648,7 @@ mod test {
           |
         8 | tqdm = { git = "https://github.com/tqdm/tqdm", ref = "baaaaaab" }
           |                                                ^^^
-        unknown field `ref`, expected one of `git`, `subdirectory`, `rev`, `tag`, `branch`, `url`, `path`, `editable`, `package`, `index`, `workspace`, `marker`, `extra`, `group`
+        unknown field `ref`, expected one of `git`, `subdirectory`, `rev`, `tag`, `tag-pattern`, `branch`, `url`, `path`, `editable`, `package`, `index`, `workspace`, `marker`, `extra`, `group`
         "###);
     }
 
@@ -717,6 +820,64 @@ mod test {
         "###);
     }
 
+    #[tokio::test]
+    async fn extra_cycle() {
+        let input = indoc! {r#"
+            [project]
+            name = "foo"
+            version = "0.0.0"
+            dependencies = []
+
+            [project.optional-dependencies]
+            all = ["foo[docs]", "foo[test]"]
+            docs = ["sphinx", "foo[lint]"]
+            lint = ["ruff", "foo[all]"]
+            test = ["pytest"]
+        "#};
+
+        assert_snapshot!(format_err(input).await, @r###"
+        error: Detected a cycle in `project.optional-dependencies`: `all` -> `docs` -> `lint` -> `all`
+        "###);
+    }
+
+    #[tokio::test]
+    async fn missing_self_extra() {
+        // The self-reference uses a non-normalized spelling of the project name.
+        let input = indoc! {r#"
+            [project]
+            name = "my-project"
+            version = "0.0.0"
+            dependencies = []
+
+            [project.optional-dependencies]
+            all = ["My_Project[tset]"]
+            test = ["pytest"]
+        "#};
+
+        assert_snapshot!(format_err(input).await, @r###"
+        error: `my-project[tset]` is required by the project itself, but the `tset` extra is not defined in `project.optional-dependencies` (did you mean `test`?)
+        "###);
+    }
+
+    #[tokio::test]
+    async fn self_extras() {
+        // Acyclic self-references, and an extra that includes itself, are allowed.
+        let input = indoc! {r#"
+            [project]
+            name = "my-project"
+            version = "0.0.0"
+            dependencies = ["my.project[test]"]
+
+            [project.optional-dependencies]
+            all = ["My_Project[docs]", "my-project[test]"]
+            docs = ["sphinx", "my-project[docs]"]
+            test = ["pytest"]
+        "#};
+
+        let requires_dist = requires_dist_from_pyproject_toml(input).await.unwrap();
+        assert_eq!(requires_dist.provides_extras.len(), 3);
+    }
+
     #[test]
     fn test_flat_requires_dist_noop() {
         let name = PackageName::from_str("pkg").unwrap();
@@ -10,7 +10,7 @@
 
 use std::borrow::Cow;
 use std::ops::Bound;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -33,10 +33,11 @@ use uv_configuration::{BuildKind, BuildOutput, ConfigSettings, SourceStrategy};
 use uv_distribution_filename::{SourceDistExtension, WheelFilename};
 use uv_distribution_types::{
     BuildVariables, BuildableSource, DirectorySourceUrl, ExtraBuildRequirement, GitSourceUrl,
-    HashPolicy, Hashed, IndexUrl, PathSourceUrl, SourceDist, SourceUrl,
+    HashPolicy, Hashed, IndexUrl, PathSourceUrl, Resolution, SourceDist, SourceUrl,
 };
 use uv_extract::hash::Hasher;
 use uv_fs::{rename_with_retry, write_atomic};
+use uv_git::Fetch;
 use uv_git_types::{GitHubRepository, GitOid};
 use uv_metadata::read_archive_metadata;
 use uv_normalize::PackageName;
@@ -420,6 +421,212 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         name.and_then(|name| self.build_context.extra_build_variables().get(name))
     }
 
+    /// Download a [`SourceDist`] and resolve its build requirements, i.e., the requirements from
+    /// `build-system.requires` along with any requirements returned by the backend's
+    /// `get_requires_for_build_wheel` hook.
+    ///
+    /// Unlike [`SourceDistributionBuilder::download_and_build`], this always sets up the build
+    /// environment, even if a built wheel or its metadata is already present in the cache.
+    ///
+    /// Returns `None` if the build environment isn't isolated.
+    pub(crate) async fn download_and_resolve_build_requirements(
+        &self,
+        dist: &SourceDist,
+        hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
+    ) -> Result<Option<Resolution>, Error> {
+        let source = BuildableSource::Dist(dist);
+
+        // Locate the source tree, downloading and unpacking the source distribution if necessary.
+        let (source_root, subdirectory) = match dist {
+            SourceDist::Registry(dist) => {
+                let cache_shard = self.build_context.cache().shard(
+                    CacheBucket::SourceDistributions,
+                    WheelCache::Index(&dist.index)
+                        .wheel_dir(dist.name.as_ref())
+                        .join(dist.version.to_string()),
+                );
+
+                let url = dist.file.url.to_url()?;
+
+                // If the URL is a file URL, use the local path directly.
+                if url.scheme() == "file" {
+                    let path = url
+                        .to_file_path()
+                        .map_err(|()| Error::NonFileUrl(url.clone()))?;
+                    let resource = PathSourceUrl {
+                        url: &url,
+                        path: Cow::Owned(path),
+                        ext: dist.ext,
+                    };
+                    (
+                        self.archive_source_root(&source, &resource, &cache_shard, hashes)
+                            .await?,
+                        None,
+                    )
+                } else {
+                    (
+                        self.url_source_root(
+                            &source,
+                            &url,
+                            Some(&dist.index),
+                            &cache_shard,
+                            None,
+                            dist.ext,
+                            hashes,
+                            client,
+                        )
+                        .await?,
+                        None,
+                    )
+                }
+            }
+            SourceDist::DirectUrl(dist) => {
+                let cache_shard = self.build_context.cache().shard(
+                    CacheBucket::SourceDistributions,
+                    WheelCache::Url(&dist.url).root(),
+                );
+                (
+                    self.url_source_root(
+                        &source,
+                        &dist.url,
+                        None,
+                        &cache_shard,
+                        dist.subdirectory.as_deref(),
+                        dist.ext,
+                        hashes,
+                        client,
+                    )
+                    .await?,
+                    dist.subdirectory.as_deref(),
+                )
+            }
+            SourceDist::Path(dist) => {
+                let cache_shard = self.build_context.cache().shard(
+                    CacheBucket::SourceDistributions,
+                    WheelCache::Path(&dist.url).root(),
+                );
+                (
+                    self.archive_source_root(
+                        &source,
+                        &PathSourceUrl::from(dist),
+                        &cache_shard,
+                        hashes,
+                    )
+                    .await?,
+                    None,
+                )
+            }
+            SourceDist::Directory(dist) => (dist.install_path.to_path_buf(), None),
+            SourceDist::Git(dist) => {
+                if hashes.is_validate() {
+                    return Err(Error::HashesNotSupportedGit(source.to_string()));
+                }
+
+                let fetch = self.fetch_git(&GitSourceUrl::from(dist), client).await?;
+                (fetch.path().to_path_buf(), dist.subdirectory.as_deref())
+            }
+        };
+
+        let builder = self
+            .build_context
+            .setup_build(
+                &source_root,
+                subdirectory,
+                &source_root,
+                Some(&source.to_string()),
+                Some(dist),
+                SourceStrategy::Disabled,
+                BuildKind::Wheel,
+                BuildOutput::Debug,
+                self.build_stack.cloned().unwrap_or_default(),
+            )
+            .await
+            .map_err(|err| Error::Build(err.into()))?;
+
+        Ok(builder.build_requirements().cloned())
+    }
+
+    /// Return the unpacked source tree for a remote source distribution, downloading it if
+    /// necessary.
+    async fn url_source_root(
+        &self,
+        source: &BuildableSource<'_>,
+        url: &Url,
+        index: Option<&IndexUrl>,
+        cache_shard: &CacheShard,
+        subdirectory: Option<&Path>,
+        ext: SourceDistExtension,
+        hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
+    ) -> Result<PathBuf, Error> {
+        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+
+        // Fetch the revision for the source distribution.
+        let revision = self
+            .url_revision(source, ext, url, index, cache_shard, hashes, client)
+            .await?;
+
+        // Before running the build, check that the hashes match.
+        if !revision.satisfies(hashes) {
+            return Err(Error::hash_mismatch(
+                source.to_string(),
+                hashes.digests(),
+                revision.hashes(),
+            ));
+        }
+
+        // Ensure that the source is present.
+        let source_dist_entry = cache_shard.shard(revision.id()).entry(SOURCE);
+        self.ensure_url_source(
+            source,
+            ext,
+            url,
+            index,
+            subdirectory,
+            &source_dist_entry,
+            revision,
+            hashes,
+            client,
+        )
+        .await?;
+
+        Ok(source_dist_entry.into_path_buf())
+    }
+
+    /// Return the unpacked source tree for a local source distribution archive, extracting it if
+    /// necessary.
+    async fn archive_source_root(
+        &self,
+        source: &BuildableSource<'_>,
+        resource: &PathSourceUrl<'_>,
+        cache_shard: &CacheShard,
+        hashes: HashPolicy<'_>,
+    ) -> Result<PathBuf, Error> {
+        let _lock = cache_shard.lock().await.map_err(Error::CacheWrite)?;
+
+        // Fetch the revision for the source distribution.
+        let LocalRevisionPointer { revision, .. } = self
+            .archive_revision(source, resource, cache_shard, hashes)
+            .await?;
+
+        // Before running the build, check that the hashes match.
+        if !revision.satisfies(hashes) {
+            return Err(Error::hash_mismatch(
+                source.to_string(),
+                hashes.digests(),
+                revision.hashes(),
+            ));
+        }
+
+        // Ensure that the source is present.
+        let source_entry = cache_shard.shard(revision.id()).entry(SOURCE);
+        self.ensure_archive_source(source, resource, &source_entry, revision, hashes)
+            .await?;
+
+        Ok(source_entry.into_path_buf())
+    }
+
     /// Build a source distribution from a remote URL.
     async fn url<'data>(
         &self,
@@ -481,31 +688,19 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need to build a wheel. Before building, ensure that the source is present.
-        let revision = if source_dist_entry.path().is_dir() {
-            revision
-        } else {
-            self.heal_url_revision(
+        let revision = self
+            .ensure_url_source(
                 source,
                 ext,
                 url,
                 index,
+                subdirectory,
                 &source_dist_entry,
                 revision,
                 hashes,
                 client,
             )
-            .await?
-        };
-
-        // Validate that the subdirectory exists.
-        if let Some(subdirectory) = subdirectory {
-            if !source_dist_entry.path().join(subdirectory).is_dir() {
-                return Err(Error::MissingSubdirectory(
-                    url.clone(),
-                    subdirectory.to_path_buf(),
-                ));
-            }
-        }
+            .await?;
 
         let task = self
             .reporter
@@ -613,31 +808,19 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need a wheel.
-        let revision = if source_dist_entry.path().is_dir() {
-            revision
-        } else {
-            self.heal_url_revision(
+        let revision = self
+            .ensure_url_source(
                 source,
                 ext,
                 url,
                 index,
+                subdirectory,
                 &source_dist_entry,
                 revision,
                 hashes,
                 client,
             )
-            .await?
-        };
-
-        // Validate that the subdirectory exists.
-        if let Some(subdirectory) = subdirectory {
-            if !source_dist_entry.path().join(subdirectory).is_dir() {
-                return Err(Error::MissingSubdirectory(
-                    DisplaySafeUrl::from(url.clone()),
-                    subdirectory.to_path_buf(),
-                ));
-            }
-        }
+            .await?;
 
         // Otherwise, we either need to build the metadata.
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
@@ -888,12 +1071,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need to build a wheel, which requires a source distribution.
-        let revision = if source_entry.path().is_dir() {
-            revision
-        } else {
-            self.heal_archive_revision(source, resource, &source_entry, revision, hashes)
-                .await?
-        };
+        let revision = self
+            .ensure_archive_source(source, resource, &source_entry, revision, hashes)
+            .await?;
 
         let task = self
             .reporter
@@ -995,12 +1175,9 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Otherwise, we need a source distribution.
-        let revision = if source_entry.path().is_dir() {
-            revision
-        } else {
-            self.heal_archive_revision(source, resource, &source_entry, revision, hashes)
-                .await?
-        };
+        let revision = self
+            .ensure_archive_source(source, resource, &source_entry, revision, hashes)
+            .await?;
 
         // If the backend supports `prepare_metadata_for_build_wheel`, use it.
         if let Some(metadata) = self
@@ -1556,33 +1733,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Fetch the Git repository.
-        let fetch = self
-            .build_context
-            .git()
-            .fetch(
-                resource.git,
-                client
-                    .unmanaged
-                    .uncached_client(resource.git.repository())
-                    .clone(),
-                client.unmanaged.disable_ssl(resource.git.repository()),
-                client.unmanaged.connectivity() == Connectivity::Offline,
-                self.build_context.cache().bucket(CacheBucket::Git),
-                self.reporter
-                    .clone()
-                    .map(|reporter| reporter.into_git_reporter()),
-            )
-            .await?;
-
-        // Validate that the subdirectory exists.
-        if let Some(subdirectory) = resource.subdirectory {
-            if !fetch.path().join(subdirectory).is_dir() {
-                return Err(Error::MissingSubdirectory(
-                    resource.url.to_url(),
-                    subdirectory.to_path_buf(),
-                ));
-            }
-        }
+        let fetch = self.fetch_git(resource, client).await?;
 
         let git_sha = fetch.git().precise().expect("Exact commit after checkout");
         let cache_shard = self.build_context.cache().shard(
@@ -1755,33 +1906,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         }
 
         // Fetch the Git repository.
-        let fetch = self
-            .build_context
-            .git()
-            .fetch(
-                resource.git,
-                client
-                    .unmanaged
-                    .uncached_client(resource.git.repository())
-                    .clone(),
-                client.unmanaged.disable_ssl(resource.git.repository()),
-                client.unmanaged.connectivity() == Connectivity::Offline,
-                self.build_context.cache().bucket(CacheBucket::Git),
-                self.reporter
-                    .clone()
-                    .map(|reporter| reporter.into_git_reporter()),
-            )
-            .await?;
-
-        // Validate that the subdirectory exists.
-        if let Some(subdirectory) = resource.subdirectory {
-            if !fetch.path().join(subdirectory).is_dir() {
-                return Err(Error::MissingSubdirectory(
-                    resource.url.to_url(),
-                    subdirectory.to_path_buf(),
-                ));
-            }
-        }
+        let fetch = self.fetch_git(resource, client).await?;
 
         let git_sha = fetch.git().precise().expect("Exact commit after checkout");
         let cache_shard = self.build_context.cache().shard(
@@ -2137,6 +2262,94 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         Ok(Some(metadata))
     }
 
+    /// Ensure that the unpacked source distribution for a local archive is present in the cache,
+    /// re-extracting it if necessary.
+    async fn ensure_archive_source(
+        &self,
+        source: &BuildableSource<'_>,
+        resource: &PathSourceUrl<'_>,
+        entry: &CacheEntry,
+        revision: Revision,
+        hashes: HashPolicy<'_>,
+    ) -> Result<Revision, Error> {
+        if entry.path().is_dir() {
+            return Ok(revision);
+        }
+        self.heal_archive_revision(source, resource, entry, revision, hashes)
+            .await
+    }
+
+    /// Ensure that the unpacked source distribution for a remote archive is present in the cache,
+    /// re-downloading it if necessary, and that the requested subdirectory exists.
+    async fn ensure_url_source(
+        &self,
+        source: &BuildableSource<'_>,
+        ext: SourceDistExtension,
+        url: &Url,
+        index: Option<&IndexUrl>,
+        subdirectory: Option<&Path>,
+        entry: &CacheEntry,
+        revision: Revision,
+        hashes: HashPolicy<'_>,
+        client: &ManagedClient<'_>,
+    ) -> Result<Revision, Error> {
+        let revision = if entry.path().is_dir() {
+            revision
+        } else {
+            self.heal_url_revision(source, ext, url, index, entry, revision, hashes, client)
+                .await?
+        };
+
+        // Validate that the subdirectory exists.
+        if let Some(subdirectory) = subdirectory {
+            if !entry.path().join(subdirectory).is_dir() {
+                return Err(Error::MissingSubdirectory(
+                    DisplaySafeUrl::from(url.clone()),
+                    subdirectory.to_path_buf(),
+                ));
+            }
+        }
+
+        Ok(revision)
+    }
+
+    /// Fetch a Git repository, and validate that the requested subdirectory exists.
+    async fn fetch_git(
+        &self,
+        resource: &GitSourceUrl<'_>,
+        client: &ManagedClient<'_>,
+    ) -> Result<Fetch, Error> {
+        let fetch = self
+            .build_context
+            .git()
+            .fetch(
+                resource.git,
+                client
+                    .unmanaged
+                    .uncached_client(resource.git.repository())
+                    .clone(),
+                client.unmanaged.disable_ssl(resource.git.repository()),
+                client.unmanaged.connectivity() == Connectivity::Offline,
+                self.build_context.cache().bucket(CacheBucket::Git),
+                self.reporter
+                    .clone()
+                    .map(|reporter| reporter.into_git_reporter()),
+            )
+            .await?;
+
+        // Validate that the subdirectory exists.
+        if let Some(subdirectory) = resource.subdirectory {
+            if !fetch.path().join(subdirectory).is_dir() {
+                return Err(Error::MissingSubdirectory(
+                    resource.url.to_url(),
+                    subdirectory.to_path_buf(),
+                ));
+            }
+        }
+
+        Ok(fetch)
+    }
+
     /// Heal a [`Revision`] for a local archive.
     async fn heal_archive_revision(
         &self,
@@ -3053,7 +3266,7 @@ async fn read_requires_dist(project_root: &Path) -> Result<uv_pypi_types::Requir
     };
 
     // Parse the metadata.
-    let requires_dist = uv_pypi_types::RequiresDist::parse_pyproject_toml(&content)
+    let requires_dist = uv_pypi_types::RequiresDist::parse_pyproject_toml(&content, project_root)
         .map_err(Error::PyprojectToml)?;
 
     Ok(requires_dist)
@@ -1,6 +1,6 @@
 pub use crate::github::GitHubRepository;
 pub use crate::oid::{GitOid, OidParseError};
-pub use crate::reference::GitReference;
+pub use crate::reference::{GitReference, matches_tag_pattern};
 
 use thiserror::Error;
 use uv_redacted::DisplaySafeUrl;
@@ -139,7 +139,8 @@ impl From<GitUrl> for DisplaySafeUrl {
                 | GitReference::Tag(rev)
                 | GitReference::BranchOrTag(rev)
                 | GitReference::NamedRef(rev)
-                | GitReference::BranchOrTagOrCommit(rev) => {
+                | GitReference::BranchOrTagOrCommit(rev)
+                | GitReference::TagPattern(rev) => {
                     let path = format!("{}@{}", url.path(), rev);
                     url.set_path(&path);
                 }
@@ -14,6 +14,8 @@ pub enum GitReference {
     BranchOrTagOrCommit(String),
     /// From a named reference, like `refs/pull/493/head`.
     NamedRef(String),
+    /// The tag with the highest version among those matching a glob pattern, like `v*`.
+    TagPattern(String),
     /// The default branch of the repository, the reference named `HEAD`.
     DefaultBranch,
 }
@@ -39,6 +41,7 @@ impl GitReference {
             Self::BranchOrTag(rev) => Some(rev),
             Self::BranchOrTagOrCommit(rev) => Some(rev),
             Self::NamedRef(rev) => Some(rev),
+            Self::TagPattern(pattern) => Some(pattern),
             Self::DefaultBranch => None,
         }
     }
@@ -51,6 +54,7 @@ impl GitReference {
             Self::BranchOrTag(rev) => rev,
             Self::BranchOrTagOrCommit(rev) => rev,
             Self::NamedRef(rev) => rev,
+            Self::TagPattern(pattern) => pattern,
             Self::DefaultBranch => "HEAD",
         }
     }
@@ -63,6 +67,7 @@ impl GitReference {
             Self::BranchOrTag(_) => "branch or tag",
             Self::BranchOrTagOrCommit(_) => "branch, tag, or commit",
             Self::NamedRef(_) => "ref",
+            Self::TagPattern(_) => "tag pattern",
             Self::DefaultBranch => "default branch",
         }
     }
@@ -74,7 +79,38 @@ impl Display for GitReference {
     }
 }
 
+/// Returns `true` if the `tag` matches the glob `pattern`.
+///
+/// As in a Git refspec, the pattern may include at most one `*`, which matches any sequence of
+/// characters.
+pub fn matches_tag_pattern(pattern: &str, tag: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => {
+            tag.len() >= prefix.len() + suffix.len()
+                && tag.starts_with(prefix)
+                && tag.ends_with(suffix)
+        }
+        None => pattern == tag,
+    }
+}
+
 /// Whether a `rev` looks like a commit hash (ASCII hex digits).
 fn looks_like_commit_hash(rev: &str) -> bool {
     rev.len() >= 7 && rev.chars().all(|ch| ch.is_ascii_hexdigit())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::matches_tag_pattern;
+
+    #[test]
+    fn tag_pattern() {
+        assert!(matches_tag_pattern("v*", "v1.2.3"));
+        assert!(matches_tag_pattern("v*", "v"));
+        assert!(!matches_tag_pattern("v*", "1.2.3"));
+        assert!(matches_tag_pattern("release-*-final", "release-1.0-final"));
+        assert!(!matches_tag_pattern("release-*-final", "release-final"));
+        assert!(matches_tag_pattern("v1.2.3", "v1.2.3"));
+        assert!(!matches_tag_pattern("v1.2.3", "v1.2.4"));
+    }
+}
@@ -4,7 +4,7 @@
 use std::env;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
-use std::str::{self};
+use std::str::{self, FromStr};
 use std::sync::LazyLock;
 
 use anyhow::{Context, Result};
@@ -15,7 +15,8 @@ use tracing::{debug, warn};
 use url::Url;
 
 use uv_fs::Simplified;
-use uv_git_types::{GitHubRepository, GitOid, GitReference};
+use uv_git_types::{GitHubRepository, GitOid, GitReference, matches_tag_pattern};
+use uv_pep440::Version;
 use uv_redacted::DisplaySafeUrl;
 use uv_static::EnvVars;
 use uv_version::version;
@@ -98,6 +99,11 @@ impl ReferenceOrOid<'_> {
             // Resolve a named reference.
             Self::Reference(GitReference::NamedRef(s)) => repo.rev_parse(&format!("{s}^0")),
 
+            // Resolve the commit pointed to by the latest tag that matches the pattern.
+            Self::Reference(GitReference::TagPattern(pattern)) => repo
+                .latest_tag(pattern)
+                .and_then(|tag| repo.rev_parse(&format!("refs/remotes/origin/tags/{tag}^0"))),
+
             // Resolve a specific commit.
             Self::Oid(s) => repo.rev_parse(&format!("{s}^0")),
         };
@@ -192,6 +198,17 @@ impl GitRepository {
         })
     }
 
+    /// Returns `true` if the repository is a shallow clone, i.e., it is missing some history.
+    fn is_shallow(&self) -> Result<bool> {
+        let result = ProcessBuilder::new(GIT.as_ref()?)
+            .arg("rev-parse")
+            .arg("--is-shallow-repository")
+            .cwd(&self.path)
+            .exec_with_output()?;
+
+        Ok(String::from_utf8(result.stdout)?.trim() == "true")
+    }
+
     /// Parses the object ID of the given `refname`.
     fn rev_parse(&self, refname: &str) -> Result<GitOid> {
         let result = ProcessBuilder::new(GIT.as_ref()?)
@@ -204,6 +221,39 @@ impl GitRepository {
         result.truncate(result.trim_end().len());
         Ok(result.parse()?)
     }
+
+    /// Returns the fetched tag that matches the given `pattern` with the highest version.
+    ///
+    /// Tags that match the pattern, but can't be parsed as a PEP 440 version, are ignored.
+    fn latest_tag(&self, pattern: &str) -> Result<String> {
+        let result = ProcessBuilder::new(GIT.as_ref()?)
+            .arg("for-each-ref")
+            .arg("--format=%(refname:strip=4)")
+            .arg("refs/remotes/origin/tags/")
+            .cwd(&self.path)
+            .exec_with_output()?;
+        let tags = String::from_utf8(result.stdout)?;
+
+        let mut latest: Option<(Version, &str)> = None;
+        for tag in tags.lines() {
+            if !matches_tag_pattern(pattern, tag) {
+                continue;
+            }
+            let Ok(version) = Version::from_str(tag) else {
+                debug!("Ignoring tag `{tag}` matching `{pattern}`: not a valid version");
+                continue;
+            };
+            if latest.as_ref().is_none_or(|(latest, _)| version > *latest) {
+                latest = Some((version, tag));
+            }
+        }
+
+        let Some((_, tag)) = latest else {
+            anyhow::bail!("no tags with a valid version match `{pattern}`");
+        };
+        debug!("Resolved tag pattern `{pattern}` to: `{tag}`");
+        Ok(tag.to_string())
+    }
 }
 
 impl GitRemote {
@@ -480,6 +530,9 @@ fn fetch(
     let mut refspecs = Vec::new();
     let mut tags = false;
     let mut refspec_strategy = RefspecStrategy::All;
+    // Whether we can perform a shallow fetch, i.e., whether we only need the commits that the
+    // refspecs point to, rather than their history.
+    let mut shallow = true;
     // The `+` symbol on the refspec means to allow a forced (fast-forward)
     // update which is needed if there is ever a force push that requires a
     // fast-forward.
@@ -521,6 +574,8 @@ fn fetch(
                 refspecs.push(String::from("+refs/heads/*:refs/remotes/origin/*"));
                 refspecs.push(String::from("+HEAD:refs/remotes/origin/HEAD"));
                 tags = true;
+                // The commit could be anywhere in the history, so we need all of it.
+                shallow = false;
             }
         }
 
@@ -532,6 +587,13 @@ fn fetch(
             refspecs.push(format!("+{rev}:{rev}"));
         }
 
+        // For tag patterns, fetch all matching tags; the latest is selected on resolution.
+        ReferenceOrOid::Reference(GitReference::TagPattern(pattern)) => {
+            refspecs.push(format!(
+                "+refs/tags/{pattern}:refs/remotes/origin/tags/{pattern}"
+            ));
+        }
+
         ReferenceOrOid::Oid(rev) => {
             refspecs.push(format!("+{rev}:refs/commit/{rev}"));
         }
@@ -544,6 +606,7 @@ fn fetch(
             remote_url,
             refspecs.as_slice(),
             tags,
+            shallow,
             disable_ssl,
             offline,
         ),
@@ -557,6 +620,7 @@ fn fetch(
                         remote_url,
                         std::slice::from_ref(refspec),
                         tags,
+                        shallow,
                         disable_ssl,
                         offline,
                     );
@@ -599,11 +663,63 @@ fn fetch(
 }
 
 /// Attempts to use `git` CLI installed on the system to fetch a repository.
+///
+/// If `shallow` is set, only the commits that the refspecs point to are fetched (i.e., with
+/// `--depth 1`), falling back to a full fetch if the server doesn't support shallow fetches.
+/// Otherwise, if the repository is already shallow, its full history is fetched.
 fn fetch_with_cli(
     repo: &mut GitRepository,
     url: &Url,
     refspecs: &[String],
     tags: bool,
+    shallow: bool,
+    disable_ssl: bool,
+    offline: bool,
+) -> Result<()> {
+    if shallow {
+        match fetch_with_cli_impl(
+            repo,
+            url,
+            refspecs,
+            tags,
+            Depth::Shallow,
+            disable_ssl,
+            offline,
+        ) {
+            Ok(()) => return Ok(()),
+            Err(err) if err.downcast_ref::<GitError>().is_some() => return Err(err),
+            Err(err) => {
+                debug!("Shallow Git fetch failed, retrying with full history: {err}");
+            }
+        }
+    }
+
+    let depth = if repo.is_shallow().unwrap_or(false) {
+        Depth::Unshallow
+    } else {
+        Depth::Full
+    };
+    fetch_with_cli_impl(repo, url, refspecs, tags, depth, disable_ssl, offline)
+}
+
+/// The history to fetch for a refspec.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Depth {
+    /// Fetch only the commits that the refspecs point to.
+    Shallow,
+    /// Fetch the full history of the refspecs.
+    Full,
+    /// Fetch the full history of the refspecs, converting a shallow repository to a complete one.
+    Unshallow,
+}
+
+/// Runs `git fetch` for the given refspecs, with the given [`Depth`].
+fn fetch_with_cli_impl(
+    repo: &mut GitRepository,
+    url: &Url,
+    refspecs: &[String],
+    tags: bool,
+    depth: Depth,
     disable_ssl: bool,
     offline: bool,
 ) -> Result<()> {
@@ -617,6 +733,15 @@ fn fetch_with_cli(
     if tags {
         cmd.arg("--tags");
     }
+    match depth {
+        Depth::Shallow => {
+            cmd.arg("--depth").arg("1");
+        }
+        Depth::Full => {}
+        Depth::Unshallow => {
+            cmd.arg("--unshallow");
+        }
+    }
     if disable_ssl {
         debug!("Disabling SSL verification for Git fetch via `GIT_SSL_NO_VERIFY`");
         cmd.env(EnvVars::GIT_SSL_NO_VERIFY, "true");
@@ -749,6 +874,10 @@ fn github_fast_path(
         ReferenceOrOid::Reference(GitReference::Tag(tag)) => tag,
         ReferenceOrOid::Reference(GitReference::BranchOrTag(branch_or_tag)) => branch_or_tag,
         ReferenceOrOid::Reference(GitReference::NamedRef(rev)) => rev,
+        // Tag patterns must be resolved against the full list of tags.
+        ReferenceOrOid::Reference(GitReference::TagPattern(_)) => {
+            return Ok(FastPathRev::Indeterminate);
+        }
         ReferenceOrOid::Reference(GitReference::BranchOrTagOrCommit(rev)) => {
             // `revparse_single` (used by `resolve`) is the only way to turn
             // short hash -> long hash, but it also parses other things,
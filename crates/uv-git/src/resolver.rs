@@ -82,6 +82,11 @@ impl GitResolver {
             return Ok(Some(precise));
         }
 
+        // Tag patterns can only be resolved by listing the remote tags.
+        if matches!(url.reference(), GitReference::TagPattern(_)) {
+            return Ok(None);
+        }
+
         // If the URL is a GitHub URL, attempt to resolve it via the GitHub API.
         let Some(GitHubRepository { owner, repo }) = GitHubRepository::parse(url.repository())
         else {
@@ -0,0 +1,54 @@
+use std::path::Path;
+
+use fs_err as fs;
+use tracing::trace;
+
+use crate::Error;
+use crate::wheel::{read_record_file, write_file_recorded};
+
+/// Take ownership of the wheel represented by the given `.dist-info` directory, by rewriting its
+/// `INSTALLER` file and the corresponding entry in `RECORD`.
+///
+/// All other files are left as-is, including the `direct_url.json` and `.pth` files that back an
+/// editable install.
+pub fn adopt_wheel(dist_info: &Path, installer: &str) -> Result<(), Error> {
+    let (Some(site_packages), Some(dist_info_dir)) = (dist_info.parent(), dist_info.file_name())
+    else {
+        return Err(Error::BrokenVenv(
+            "dist-info directory is not in a site-packages directory".to_string(),
+        ));
+    };
+
+    // Read the RECORD file.
+    let record_path = dist_info.join("RECORD");
+    let mut record = {
+        let mut record_file = match fs::File::open(&record_path) {
+            Ok(record_file) => record_file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(Error::MissingRecord(record_path));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        read_record_file(&mut record_file)?
+    };
+
+    // Replace the `INSTALLER` file, along with its entry in the RECORD.
+    let relative_path = Path::new(dist_info_dir).join("INSTALLER");
+    let entry = format!("{}/INSTALLER", dist_info_dir.to_string_lossy());
+    record.retain(|record| record.path != entry);
+    write_file_recorded(site_packages, &relative_path, installer, &mut record)?;
+
+    trace!(
+        "Writing record for adopted distribution: {}",
+        dist_info.display()
+    );
+    let mut record_writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .escape(b'"')
+        .from_path(&record_path)?;
+    for entry in record {
+        record_writer.serialize(entry)?;
+    }
+
+    Ok(())
+}
@@ -10,11 +10,13 @@ use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::Scheme;
 
+pub use adopt::adopt_wheel;
 pub use install::install_wheel;
 pub use linker::{LinkMode, Locks};
 pub use uninstall::{Uninstall, uninstall_egg, uninstall_legacy_editable, uninstall_wheel};
 pub use wheel::{LibKind, parse_wheel_file, read_record_file};
 
+mod adopt;
 mod install;
 mod linker;
 mod record;
@@ -393,8 +393,10 @@ fn symlink_wheel_files(
             continue;
         }
 
-        // The `RECORD` file is modified during installation, so we copy it instead of symlinking.
-        if path.ends_with("RECORD") {
+        // The `.dist-info` directory is modified during installation (e.g., the `RECORD` file is
+        // rewritten, and the `INSTALLER` and `direct_url.json` files are written), so we copy it
+        // instead of symlinking, to avoid writing through the symlinks into the cache.
+        if is_dist_info(relative) {
             synchronized_copy(path, &out_path, locks)?;
             count += 1;
             continue;
@@ -473,6 +475,15 @@ fn symlink_wheel_files(
 /// writes to the same file, which can lead to corruption.
 ///
 /// See: <https://github.com/astral-sh/uv/issues/4831>
+/// Returns `true` if the relative path is within a top-level `.dist-info` directory.
+fn is_dist_info(relative: &Path) -> bool {
+    relative
+        .components()
+        .next()
+        .and_then(|component| component.as_os_str().to_str())
+        .is_some_and(|component| component.ends_with(".dist-info"))
+}
+
 fn synchronized_copy(from: &Path, to: &Path, locks: &Locks) -> std::io::Result<()> {
     // Ensure we have a lock for the directory.
     let dir_lock = {
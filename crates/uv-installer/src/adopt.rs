@@ -0,0 +1,24 @@
+use uv_distribution_types::InstalledDist;
+
+/// Take ownership of a package installed by another tool (e.g., `pip`), marking `uv` as its
+/// installer.
+pub fn adopt(dist: &InstalledDist) -> Result<(), AdoptError> {
+    match dist {
+        InstalledDist::Registry(_) | InstalledDist::Url(_) => {
+            Ok(uv_install_wheel::adopt_wheel(dist.install_path(), "uv")?)
+        }
+        InstalledDist::EggInfoDirectory(_)
+        | InstalledDist::EggInfoFile(_)
+        | InstalledDist::LegacyEditable(_) => Err(AdoptError::Unsupported(dist.clone())),
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum AdoptError {
+    #[error(
+        "Unable to adopt `{0}`. Only distributions with a `.dist-info` directory can be adopted."
+    )]
+    Unsupported(InstalledDist),
+    #[error(transparent)]
+    Adopt(#[from] uv_install_wheel::Error),
+}
@@ -5,6 +5,7 @@ use std::time::Duration;
 use std::{env, io, panic};
 
 use async_channel::{Receiver, SendError};
+use rustc_hash::FxHashSet;
 use tempfile::tempdir_in;
 use thiserror::Error;
 use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
@@ -69,12 +70,15 @@ pub enum CompileError {
 /// > Uninstallers should be smart enough to remove .pyc even if it is not mentioned in RECORD.
 ///
 /// We've confirmed that both uv and pip (as of 24.0.0) remove the `__pycache__` directory.
-#[instrument(skip(python_executable))]
+///
+/// Any files in `exclude` (given as absolute paths) are skipped.
+#[instrument(skip(python_executable, exclude))]
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
     concurrency: &Concurrency,
     cache: &Path,
+    exclude: &FxHashSet<PathBuf>,
 ) -> Result<usize, CompileError> {
     debug_assert!(
         dir.is_absolute(),
@@ -175,6 +179,9 @@ pub async fn compile_tree(
             };
         // https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L593-L604
         if metadata.is_file() && entry.path().extension().is_some_and(|ext| ext == "py") {
+            if exclude.contains(entry.path()) {
+                continue;
+            }
             source_files += 1;
             if let Err(err) = sender.send(entry.path().to_owned()).await {
                 // The workers exited.
@@ -1,3 +1,4 @@
+pub use adopt::{AdoptError, adopt};
 pub use compile::{CompileError, compile_tree};
 pub use installer::{Installer, Reporter as InstallReporter};
 pub use plan::{Plan, Planner};
@@ -5,6 +6,7 @@ pub use preparer::{Error as PrepareError, Preparer, Reporter as PrepareReporter}
 pub use site_packages::{SatisfiesResult, SitePackages, SitePackagesDiagnostic};
 pub use uninstall::{UninstallError, uninstall};
 
+mod adopt;
 mod compile;
 mod preparer;
 
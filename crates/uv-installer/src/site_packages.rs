@@ -288,6 +288,68 @@ impl SitePackages {
         Ok(diagnostics)
     }
 
+    /// Returns the installed distributions that are required (transitively) by the extras of the
+    /// given requirements, but not by the requirements themselves.
+    ///
+    /// Dependencies are read from the installed metadata; any dependency that isn't installed (or
+    /// is installed more than once) is skipped.
+    pub fn extra_dependencies<'a>(
+        &'a self,
+        requirements: impl Iterator<Item = &'a Requirement>,
+        markers: &ResolverMarkerEnvironment,
+    ) -> Vec<&'a InstalledDist> {
+        let mut stack = Vec::new();
+        let mut seen = FxHashSet::default();
+
+        // Add the dependencies that are only enabled by the requested extras to the queue.
+        for requirement in requirements {
+            if requirement.extras.is_empty() || !requirement.evaluate_markers(Some(markers), &[]) {
+                continue;
+            }
+            let [distribution] = self.get_packages(&requirement.name)[..] else {
+                continue;
+            };
+            let Ok(metadata) = distribution.metadata() else {
+                continue;
+            };
+            for dependency in metadata.requires_dist {
+                let dependency = Requirement::from(dependency);
+                if dependency.evaluate_markers(Some(markers), &requirement.extras)
+                    && !dependency.evaluate_markers(Some(markers), &[])
+                {
+                    if seen.insert(dependency.clone()) {
+                        stack.push(dependency);
+                    }
+                }
+            }
+        }
+
+        // Collect the installed dependencies, along with their own dependencies.
+        let mut dependencies = Vec::new();
+        let mut names = FxHashSet::default();
+        while let Some(requirement) = stack.pop() {
+            let [distribution] = self.get_packages(&requirement.name)[..] else {
+                continue;
+            };
+            if names.insert(requirement.name.clone()) {
+                dependencies.push(distribution);
+            }
+            let Ok(metadata) = distribution.metadata() else {
+                continue;
+            };
+            for dependency in metadata.requires_dist {
+                let dependency = Requirement::from(dependency);
+                if dependency.evaluate_markers(Some(markers), &requirement.extras) {
+                    if seen.insert(dependency.clone()) {
+                        stack.push(dependency);
+                    }
+                }
+            }
+        }
+
+        dependencies
+    }
+
     /// Returns if the installed packages satisfy the given requirements.
     pub fn satisfies_spec(
         &self,
@@ -0,0 +1,341 @@
+//! Parse, inspect, and serialize `uv.lock` files.
+//!
+//! This crate is the supported way to read a `uv.lock` outside of uv. A [`Lock`] is parsed with
+//! [`Lock::from_toml`], exposes typed access to its packages, and is serialized with
+//! [`Lock::to_toml`] using the same formatting as uv itself.
+//!
+//! Serializing an unmodified lockfile that was written by a compatible version of uv (i.e., one
+//! using the same lockfile version and revision) reproduces the original contents byte-for-byte.
+//!
+//! ```no_run
+//! let contents = std::fs::read_to_string("uv.lock")?;
+//! let lock = uv_lock::Lock::from_toml(&contents)?;
+//! for package in lock.packages() {
+//!     println!("{} {:?}", package.name(), package.version());
+//! }
+//! assert_eq!(lock.to_toml()?, contents);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::collections::BTreeSet;
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::{Version, VersionSpecifiers};
+use uv_pep508::MarkerTree;
+use uv_pypi_types::HashDigest;
+
+pub use uv_resolver::PackageSource as Source;
+pub use uv_resolver::VERSION;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to parse `uv.lock`")]
+    Parse(#[source] toml::de::Error),
+
+    #[error(
+        "`uv.lock` uses an unsupported schema version (v{found}, but only v{expected} is supported)"
+    )]
+    UnsupportedVersion { expected: u32, found: u32 },
+
+    #[error("Failed to serialize `uv.lock`")]
+    Serialize(#[from] toml_edit::ser::Error),
+}
+
+/// A parsed `uv.lock` file.
+#[derive(Debug, Clone)]
+pub struct Lock(uv_resolver::Lock);
+
+impl Lock {
+    /// Parse a [`Lock`] from the contents of a `uv.lock` file.
+    ///
+    /// Returns an error if the lockfile uses a schema version other than [`VERSION`].
+    pub fn from_toml(contents: &str) -> Result<Self, Error> {
+        match toml::from_str::<uv_resolver::Lock>(contents) {
+            Ok(lock) => {
+                if lock.version() != VERSION {
+                    return Err(Error::UnsupportedVersion {
+                        expected: VERSION,
+                        found: lock.version(),
+                    });
+                }
+                Ok(Self(lock))
+            }
+            Err(err) => {
+                // If we failed to parse the lockfile, determine whether it's a supported version.
+                if let Ok(lock) = toml::from_str::<uv_resolver::LockVersion>(contents) {
+                    if lock.version() != VERSION {
+                        return Err(Error::UnsupportedVersion {
+                            expected: VERSION,
+                            found: lock.version(),
+                        });
+                    }
+                }
+                Err(Error::Parse(err))
+            }
+        }
+    }
+
+    /// Serialize the [`Lock`] to the contents of a `uv.lock` file, using uv's formatting.
+    pub fn to_toml(&self) -> Result<String, Error> {
+        Ok(self.0.to_toml()?)
+    }
+
+    /// Returns the schema version of the lockfile.
+    pub fn version(&self) -> u32 {
+        self.0.version()
+    }
+
+    /// Returns the schema revision of the lockfile.
+    pub fn revision(&self) -> u32 {
+        self.0.revision()
+    }
+
+    /// Returns the range of Python versions supported by the lockfile.
+    pub fn requires_python(&self) -> &VersionSpecifiers {
+        self.0.requires_python().specifiers()
+    }
+
+    /// Returns the markers of the forks in the resolution, if any.
+    pub fn fork_markers(&self) -> impl Iterator<Item = MarkerTree> + '_ {
+        self.0.fork_markers().iter().map(|marker| marker.pep508())
+    }
+
+    /// Returns the packages in the lockfile, in the order in which they're written.
+    pub fn packages(&self) -> impl Iterator<Item = Package<'_>> {
+        self.0.packages().iter().map(Package)
+    }
+
+    /// Returns the packages in the lockfile with the given name.
+    ///
+    /// A lockfile can include multiple versions (or sources) of a package, e.g., when the
+    /// resolution forks on a marker.
+    pub fn find<'lock>(
+        &'lock self,
+        name: &'lock PackageName,
+    ) -> impl Iterator<Item = Package<'lock>> + 'lock {
+        self.packages()
+            .filter(move |package| package.name() == name)
+    }
+
+    /// Returns the underlying [`uv_resolver::Lock`].
+    pub fn into_inner(self) -> uv_resolver::Lock {
+        self.0
+    }
+}
+
+impl From<uv_resolver::Lock> for Lock {
+    fn from(lock: uv_resolver::Lock) -> Self {
+        Self(lock)
+    }
+}
+
+/// A package entry in a `uv.lock` file.
+#[derive(Debug, Clone, Copy)]
+pub struct Package<'lock>(&'lock uv_resolver::Package);
+
+impl<'lock> Package<'lock> {
+    /// Returns the name of the package.
+    pub fn name(&self) -> &'lock PackageName {
+        self.0.name()
+    }
+
+    /// Returns the version of the package.
+    ///
+    /// The version is omitted for local source trees with a dynamic version.
+    pub fn version(&self) -> Option<&'lock Version> {
+        self.0.version()
+    }
+
+    /// Returns the [`Source`] from which the package was locked.
+    pub fn source(&self) -> Source<'lock> {
+        self.0.source()
+    }
+
+    /// Returns the markers of the forks that include this package, if any.
+    pub fn fork_markers(&self) -> impl Iterator<Item = MarkerTree> + 'lock {
+        self.0.fork_markers().iter().map(|marker| marker.pep508())
+    }
+
+    /// Returns the hashes of the package's source distribution and wheels.
+    pub fn hashes(&self) -> Vec<HashDigest> {
+        self.0.hashes().to_vec()
+    }
+
+    /// Returns the dependencies of the package.
+    pub fn dependencies(&self) -> impl Iterator<Item = Dependency<'lock>> + 'lock {
+        self.0.dependencies().iter().map(Dependency)
+    }
+
+    /// Returns the dependencies of the package that are enabled by the given extra.
+    pub fn optional_dependencies(
+        &self,
+        extra: &ExtraName,
+    ) -> impl Iterator<Item = Dependency<'lock>> + 'lock {
+        self.0
+            .optional_dependencies()
+            .get(extra)
+            .into_iter()
+            .flatten()
+            .map(Dependency)
+    }
+
+    /// Returns the dependencies of the package's given dependency group.
+    pub fn group_dependencies(
+        &self,
+        group: &GroupName,
+    ) -> impl Iterator<Item = Dependency<'lock>> + 'lock {
+        self.0
+            .resolved_dependency_groups()
+            .get(group)
+            .into_iter()
+            .flatten()
+            .map(Dependency)
+    }
+
+    /// Returns the extras provided by the package.
+    pub fn provides_extras(&self) -> &'lock [ExtraName] {
+        self.0.provides_extras()
+    }
+}
+
+/// A dependency of a [`Package`] in a `uv.lock` file.
+#[derive(Debug, Clone, Copy)]
+pub struct Dependency<'lock>(&'lock uv_resolver::LockDependency);
+
+impl<'lock> Dependency<'lock> {
+    /// Returns the name of the dependency.
+    pub fn name(&self) -> &'lock PackageName {
+        self.0.package_name()
+    }
+
+    /// Returns the version of the dependency.
+    ///
+    /// The version is only included in the lockfile when it's needed to disambiguate between
+    /// multiple packages with the same name.
+    pub fn version(&self) -> Option<&'lock Version> {
+        self.0.package_version()
+    }
+
+    /// Returns the extras that are enabled for the dependency.
+    pub fn extras(&self) -> &'lock BTreeSet<ExtraName> {
+        self.0.extra()
+    }
+
+    /// Returns the marker under which the dependency applies.
+    pub fn marker(&self) -> MarkerTree {
+        self.0.marker()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_normalize::PackageName;
+    use uv_pep508::MarkerTree;
+
+    use crate::{Error, Lock, Source};
+
+    const LOCK: &str = r#"version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+]
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "idna" },
+    { name = "sniffio", marker = "sys_platform == 'win32'" },
+]
+
+[package.metadata]
+requires-dist = [
+    { name = "idna" },
+    { name = "sniffio", marker = "sys_platform == 'win32'" },
+]
+
+[[package]]
+name = "sniffio"
+version = "1.3.1"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372, upload-time = "2024-02-25T23:20:04.057Z" }
+wheels = [
+    { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235, upload-time = "2024-02-25T23:20:01.196Z" },
+]
+"#;
+
+    #[test]
+    fn round_trip() {
+        let lock = Lock::from_toml(LOCK).unwrap();
+        assert_eq!(lock.to_toml().unwrap(), LOCK);
+    }
+
+    #[test]
+    fn packages() {
+        let lock = Lock::from_toml(LOCK).unwrap();
+        assert_eq!(lock.requires_python().to_string(), ">=3.12");
+
+        let names = lock
+            .packages()
+            .map(|package| package.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["idna", "project", "sniffio"]);
+
+        let name = PackageName::from_str("idna").unwrap();
+        let idna = lock.find(&name).next().unwrap();
+        assert_eq!(idna.version().unwrap().to_string(), "3.6");
+        assert_eq!(idna.source(), Source::Registry("https://pypi.org/simple"));
+        assert_eq!(
+            idna.hashes()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca",
+                "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f",
+            ]
+        );
+
+        let name = PackageName::from_str("project").unwrap();
+        let project = lock.find(&name).next().unwrap();
+        assert!(matches!(project.source(), Source::Virtual(_)));
+        assert!(project.hashes().is_empty());
+
+        let dependencies = project
+            .dependencies()
+            .map(|dependency| (dependency.name().to_string(), dependency.marker()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dependencies,
+            [
+                ("idna".to_string(), MarkerTree::TRUE),
+                (
+                    "sniffio".to_string(),
+                    MarkerTree::from_str("sys_platform == 'win32'").unwrap()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let err = Lock::from_toml("version = 2\nrequires-python = \">=3.12\"\n").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::UnsupportedVersion {
+                expected: 1,
+                found: 2
+            }
+        ));
+    }
+}
@@ -3,7 +3,7 @@ use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 use serde::ser::SerializeSeq;
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use uv_small_str::SmallString;
 
@@ -97,8 +97,12 @@ impl Default for DefaultExtras {
 /// See:
 /// - <https://peps.python.org/pep-0685/#specification/>
 /// - <https://packaging.python.org/en/latest/specifications/name-normalization/>
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
-#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(extend("pattern" = crate::NAME_PATTERN))
+)]
 pub struct ExtraName(SmallString);
 
 impl ExtraName {
@@ -110,6 +114,28 @@ impl ExtraName {
         validate_and_normalize_ref(&name).map(Self)
     }
 
+    /// Create a validated, normalized extra name, discarding any invalid characters.
+    ///
+    /// Intended for extras declared in third-party metadata, which is not always standards
+    /// compliant. For example, `Dev Tools!` is normalized to `devtools`. Returns an error if no
+    /// valid name remains.
+    pub fn from_str_lenient(name: &str) -> Result<Self, InvalidNameError> {
+        if let Ok(name) = Self::from_str(name) {
+            return Ok(name);
+        }
+        let stripped = name
+            .chars()
+            .filter(|char| char.is_ascii_alphanumeric() || matches!(char, '-' | '_' | '.'))
+            .collect::<String>();
+        let trimmed = stripped.trim_matches(|char: char| !char.is_ascii_alphanumeric());
+        validate_and_normalize_ref(trimmed)
+            .map(Self)
+            .map_err(|err| InvalidNameError {
+                name: name.to_string(),
+                ..err
+            })
+    }
+
     /// Return the underlying extra name as a string.
     pub fn as_str(&self) -> &str {
         &self.0
@@ -151,6 +177,15 @@ impl<'de> Deserialize<'de> for ExtraName {
     }
 }
 
+impl Serialize for ExtraName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl Display for ExtraName {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
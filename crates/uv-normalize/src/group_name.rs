@@ -18,7 +18,11 @@ use crate::{
 /// - <https://peps.python.org/pep-0735/>
 /// - <https://packaging.python.org/en/latest/specifications/name-normalization/>
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(extend("pattern" = crate::NAME_PATTERN))
+)]
 pub struct GroupName(SmallString);
 
 impl GroupName {
@@ -76,7 +80,7 @@ impl Serialize for GroupName {
     where
         S: Serializer,
     {
-        self.0.serialize(serializer)
+        serializer.serialize_str(self.as_str())
     }
 }
 
@@ -13,11 +13,20 @@ mod extra_name;
 mod group_name;
 mod package_name;
 
+/// A regular expression matching any valid package, extra, or group name, prior to normalization.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/name-normalization/>
+#[cfg(feature = "schemars")]
+const NAME_PATTERN: &str = r"^[A-Za-z0-9]([A-Za-z0-9._-]*[A-Za-z0-9])?$";
+
 /// Validate and normalize an unowned package or extra name.
 pub(crate) fn validate_and_normalize_ref(
     name: impl AsRef<str>,
 ) -> Result<SmallString, InvalidNameError> {
     let name = name.as_ref();
+    if name.is_empty() {
+        return Err(InvalidNameError::new(name, InvalidNameKind::Empty));
+    }
     if is_normalized(name)? {
         Ok(SmallString::from(name))
     } else {
@@ -30,7 +39,7 @@ fn normalize(name: &str) -> Result<String, InvalidNameError> {
     let mut normalized = String::with_capacity(name.len());
 
     let mut last = None;
-    for char in name.bytes() {
+    for (index, char) in name.bytes().enumerate() {
         match char {
             b'A'..=b'Z' => {
                 normalized.push(char.to_ascii_lowercase() as char);
@@ -41,19 +50,27 @@ fn normalize(name: &str) -> Result<String, InvalidNameError> {
             b'-' | b'_' | b'.' => {
                 match last {
                     // Names can't start with punctuation.
-                    None => return Err(InvalidNameError(name.to_string())),
+                    None => {
+                        return Err(InvalidNameError::new(
+                            name,
+                            InvalidNameKind::LeadingSeparator,
+                        ));
+                    }
                     Some(b'-' | b'_' | b'.') => {}
                     Some(_) => normalized.push('-'),
                 }
             }
-            _ => return Err(InvalidNameError(name.to_string())),
+            _ => return Err(InvalidNameError::invalid_character(name, index)),
         }
         last = Some(char);
     }
 
     // Names can't end with punctuation.
     if matches!(last, Some(b'-' | b'_' | b'.')) {
-        return Err(InvalidNameError(name.to_string()));
+        return Err(InvalidNameError::new(
+            name,
+            InvalidNameKind::TrailingSeparator,
+        ));
     }
 
     Ok(normalized)
@@ -61,8 +78,9 @@ fn normalize(name: &str) -> Result<String, InvalidNameError> {
 
 /// Returns `true` if the name is already normalized.
 fn is_normalized(name: impl AsRef<str>) -> Result<bool, InvalidNameError> {
+    let name = name.as_ref();
     let mut last = None;
-    for char in name.as_ref().bytes() {
+    for (index, char) in name.bytes().enumerate() {
         match char {
             b'A'..=b'Z' => {
                 // Uppercase characters need to be converted to lowercase.
@@ -76,7 +94,12 @@ fn is_normalized(name: impl AsRef<str>) -> Result<bool, InvalidNameError> {
             b'-' => {
                 match last {
                     // Names can't start with punctuation.
-                    None => return Err(InvalidNameError(name.as_ref().to_string())),
+                    None => {
+                        return Err(InvalidNameError::new(
+                            name,
+                            InvalidNameKind::LeadingSeparator,
+                        ));
+                    }
                     Some(b'-') => {
                         // Runs of `-` are normalized to a single `-`.
                         return Ok(false);
@@ -84,14 +107,17 @@ fn is_normalized(name: impl AsRef<str>) -> Result<bool, InvalidNameError> {
                     Some(_) => {}
                 }
             }
-            _ => return Err(InvalidNameError(name.as_ref().to_string())),
+            _ => return Err(InvalidNameError::invalid_character(name, index)),
         }
         last = Some(char);
     }
 
     // Names can't end with punctuation.
     if matches!(last, Some(b'-' | b'_' | b'.')) {
-        return Err(InvalidNameError(name.as_ref().to_string()));
+        return Err(InvalidNameError::new(
+            name,
+            InvalidNameKind::TrailingSeparator,
+        ));
     }
 
     Ok(true)
@@ -99,12 +125,36 @@ fn is_normalized(name: impl AsRef<str>) -> Result<bool, InvalidNameError> {
 
 /// Invalid [`PackageName`] or [`ExtraName`].
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct InvalidNameError(String);
+pub struct InvalidNameError {
+    name: String,
+    kind: InvalidNameKind,
+}
 
 impl InvalidNameError {
+    fn new(name: impl Into<String>, kind: InvalidNameKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+        }
+    }
+
+    /// Create an error for the illegal character starting at the given byte offset.
+    fn invalid_character(name: &str, index: usize) -> Self {
+        let char = name[index..]
+            .chars()
+            .next()
+            .expect("index is within the name");
+        Self::new(name, InvalidNameKind::InvalidCharacter { char, index })
+    }
+
     /// Returns the invalid name.
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.name
+    }
+
+    /// Returns the reason the name is invalid.
+    pub fn kind(&self) -> &InvalidNameKind {
+        &self.kind
     }
 }
 
@@ -114,13 +164,47 @@ impl Display for InvalidNameError {
             f,
             "Not a valid package or extra name: \"{}\". Names must start and end with a letter or \
             digit and may only contain -, _, ., and alphanumeric characters.",
-            self.0
+            self.name
         )
     }
 }
 
 impl Error for InvalidNameError {}
 
+/// The reason a package, extra, or group name is invalid.
+///
+/// Names are not subject to a length limit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InvalidNameKind {
+    /// The name is empty.
+    Empty,
+    /// The name starts with `-`, `_`, or `.`.
+    LeadingSeparator,
+    /// The name ends with `-`, `_`, or `.`.
+    TrailingSeparator,
+    /// The name contains a character other than an ASCII letter, digit, `-`, `_`, or `.`.
+    InvalidCharacter {
+        /// The offending character.
+        char: char,
+        /// The byte offset of the offending character within the name.
+        index: usize,
+    },
+}
+
+impl Display for InvalidNameKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("names must not be empty"),
+            Self::LeadingSeparator => f.write_str("names must start with a letter or digit"),
+            Self::TrailingSeparator => f.write_str("names must end with a letter or digit"),
+            Self::InvalidCharacter { char, index } => {
+                write!(f, "invalid character `{char}` at position {index}")
+            }
+        }
+    }
+}
+
 /// Path didn't end with `pyproject.toml`
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct InvalidPipGroupPathError(String);
@@ -172,6 +256,9 @@ impl From<InvalidPipGroupPathError> for InvalidPipGroupError {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
     use super::*;
 
     #[test]
@@ -240,4 +327,143 @@ mod tests {
             assert!(is_normalized(input).is_err());
         }
     }
+
+    #[test]
+    fn failure_kinds() {
+        let failures = [
+            ("", InvalidNameKind::Empty),
+            ("-starts-with-dash", InvalidNameKind::LeadingSeparator),
+            ("_Starts-With-Underscore", InvalidNameKind::LeadingSeparator),
+            ("ends-with-dash-", InvalidNameKind::TrailingSeparator),
+            ("Ends.With.Dot.", InvalidNameKind::TrailingSeparator),
+            (
+                " starts-with-space",
+                InvalidNameKind::InvalidCharacter {
+                    char: ' ',
+                    index: 0,
+                },
+            ),
+            (
+                "includes!invalid-char",
+                InvalidNameKind::InvalidCharacter {
+                    char: '!',
+                    index: 8,
+                },
+            ),
+            (
+                "Alpha-α",
+                InvalidNameKind::InvalidCharacter {
+                    char: 'α',
+                    index: 6,
+                },
+            ),
+        ];
+        for (input, kind) in failures {
+            let err = validate_and_normalize_ref(input).unwrap_err();
+            assert_eq!(err.as_str(), input);
+            assert_eq!(err.kind(), &kind, "{input:?}");
+        }
+
+        // The error surfaces through the typed constructors.
+        let err = ExtraName::from_str("dev tools").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            &InvalidNameKind::InvalidCharacter {
+                char: ' ',
+                index: 3,
+            }
+        );
+        let err = PackageName::from_owned("flask-".to_string()).unwrap_err();
+        assert_eq!(err.kind(), &InvalidNameKind::TrailingSeparator);
+        assert_eq!(
+            err.to_string(),
+            "Not a valid package or extra name: \"flask-\". Names must start and end with a \
+            letter or digit and may only contain -, _, ., and alphanumeric characters."
+        );
+    }
+
+    #[test]
+    fn extra_lenient() {
+        let inputs = [
+            ("dev", "dev"),
+            ("Dev_Tools", "dev-tools"),
+            ("dev tools", "devtools"),
+            ("dev-tools!", "dev-tools"),
+            (" -dev- ", "dev"),
+            ("[dev]", "dev"),
+            ("alpha-α", "alpha"),
+        ];
+        for (input, expected) in inputs {
+            assert_eq!(
+                ExtraName::from_str_lenient(input).unwrap().as_str(),
+                expected,
+                "{input:?}"
+            );
+        }
+
+        let failures = ["", "!!!", " - ", "α"];
+        for input in failures {
+            let err = ExtraName::from_str_lenient(input).unwrap_err();
+            assert_eq!(err.as_str(), input);
+        }
+    }
+
+    #[test]
+    fn map_keys() {
+        // Keys are normalized on deserialization, and serialized as plain strings.
+        let extras: BTreeMap<ExtraName, Vec<String>> = toml::from_str(
+            r#"
+            Foo_Bar = ["anyio"]
+            baz = ["idna"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            toml::to_string(&extras).unwrap(),
+            "baz = [\"idna\"]\nfoo-bar = [\"anyio\"]\n"
+        );
+        assert_eq!(
+            serde_json::to_string(&extras).unwrap(),
+            r#"{"baz":["idna"],"foo-bar":["anyio"]}"#
+        );
+
+        let groups: BTreeMap<GroupName, BTreeMap<PackageName, String>> = toml::from_str(
+            r#"
+            [Dev]
+            Flask = "1.0"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_string(&groups).unwrap(),
+            r#"{"dev":{"flask":"1.0"}}"#
+        );
+    }
+
+    #[test]
+    fn map_keys_invalid() {
+        #[derive(Debug, serde::Deserialize)]
+        #[serde(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Project {
+            optional_dependencies: BTreeMap<ExtraName, Vec<String>>,
+        }
+
+        // The error should identify the invalid key, along with its location in the table.
+        let err = toml::from_str::<Project>(
+            r#"
+[optional-dependencies]
+valid = ["anyio"]
+"in valid" = ["idna"]
+"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("line 4"), "{err}");
+        assert!(err.contains(r#""in valid" = ["idna"]"#), "{err}");
+        assert!(
+            err.contains(r#"Not a valid package or extra name: "in valid""#),
+            "{err}"
+        );
+    }
 }
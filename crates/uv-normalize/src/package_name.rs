@@ -2,7 +2,7 @@ use std::borrow::Cow;
 use std::cmp::PartialEq;
 use std::str::FromStr;
 
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use uv_small_str::SmallString;
 
@@ -22,12 +22,15 @@ use crate::{InvalidNameError, validate_and_normalize_ref};
     PartialOrd,
     Ord,
     Hash,
-    Serialize,
     rkyv::Archive,
     rkyv::Deserialize,
     rkyv::Serialize,
 )]
-#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "schemars",
+    derive(schemars::JsonSchema),
+    schemars(extend("pattern" = crate::NAME_PATTERN))
+)]
 #[rkyv(derive(Debug))]
 pub struct PackageName(SmallString);
 
@@ -111,6 +114,15 @@ impl<'de> Deserialize<'de> for PackageName {
     }
 }
 
+impl Serialize for PackageName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl std::fmt::Display for PackageName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
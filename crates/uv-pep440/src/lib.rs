@@ -34,8 +34,8 @@ pub use {
         VersionPatternParseError,
     },
     version_specifier::{
-        TildeVersionSpecifier, VersionSpecifier, VersionSpecifierBuildError, VersionSpecifiers,
-        VersionSpecifiersParseError,
+        PreReleaseMode, TildeVersionSpecifier, VersionSpecifier, VersionSpecifierBuildError,
+        VersionSpecifiers, VersionSpecifiersParseError,
     },
 };
 
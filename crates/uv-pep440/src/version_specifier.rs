@@ -58,6 +58,17 @@ impl VersionSpecifiers {
         self.iter().all(|specifier| specifier.contains(version))
     }
 
+    /// Whether all specifiers match the given version under the given [`PreReleaseMode`].
+    ///
+    /// Under [`PreReleaseMode::IfNecessary`], a pre-release is accepted if any of the specifiers
+    /// includes a pre-release, e.g., `>=1.0rc1,<2` accepts `1.0rc2`.
+    pub fn contains_with(&self, version: &Version, prereleases: PreReleaseMode) -> bool {
+        self.contains(version)
+            && prereleases.accepts(version, || {
+                self.iter().any(VersionSpecifier::any_prerelease)
+            })
+    }
+
     /// Returns `true` if there are no specifiers.
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
@@ -268,6 +279,30 @@ impl VersionSpecifiersParseError {
 
 impl std::error::Error for VersionSpecifiersParseError {}
 
+/// The policy for matching pre-release versions against a version specifier, mirroring the
+/// pre-release strategies of uv's resolver.
+///
+/// A pre-release (including a developmental release) is only ever accepted if it falls within the
+/// range of the specifier; the policy determines whether a pre-release within that range is
+/// acceptable.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PreReleaseMode {
+    /// Reject all pre-release versions, even if the specifier itself includes a pre-release
+    /// (e.g., `>=1.0rc1`).
+    Disallow,
+    /// Accept pre-release versions only if the specifier explicitly opts in to pre-releases by
+    /// including one (e.g., `>=1.0rc1`).
+    ///
+    /// Per PEP 440, a pre-release is also acceptable if it's the only version that satisfies the
+    /// specifier. As that depends on the set of available versions, it can't be determined from a
+    /// single version: callers should fall back to [`PreReleaseMode::Allow`] if no stable version
+    /// matches, as uv's resolver does.
+    #[default]
+    IfNecessary,
+    /// Accept all pre-release versions that satisfy the specifier.
+    Allow,
+}
+
 /// A version range such as `>1.2.3`, `<=4!5.6.7-a8.post9.dev0` or `== 4.1.*`. Parse with
 /// `VersionSpecifier::from_str`
 ///
@@ -666,6 +701,49 @@ impl VersionSpecifier {
             | Operator::NotEqual => false,
         }
     }
+
+    /// Whether the given version satisfies the version range under the given
+    /// [`PreReleaseMode`].
+    ///
+    /// Unlike [`VersionSpecifier::contains`], which accepts any version within the range, this
+    /// method applies the pre-release policy used by uv's resolver:
+    ///
+    /// - [`PreReleaseMode::Allow`]: equivalent to [`VersionSpecifier::contains`].
+    /// - [`PreReleaseMode::IfNecessary`]: pre-releases are only accepted if the specifier itself
+    ///   includes a pre-release. For example, `>=1.0rc1` accepts `1.0rc2`, but `>=0.9` does not.
+    /// - [`PreReleaseMode::Disallow`]: pre-releases are never accepted, even if the specifier
+    ///   includes a pre-release, so `>=1.0rc1` only accepts `1.0` and later.
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use uv_pep440::{PreReleaseMode, Version, VersionSpecifier};
+    ///
+    /// let version = Version::from_str("1.0rc2").unwrap();
+    /// let explicit = VersionSpecifier::from_str(">=1.0rc1").unwrap();
+    /// let implicit = VersionSpecifier::from_str(">=0.9").unwrap();
+    /// assert!(explicit.contains_with(&version, PreReleaseMode::IfNecessary));
+    /// assert!(!implicit.contains_with(&version, PreReleaseMode::IfNecessary));
+    /// assert!(implicit.contains_with(&version, PreReleaseMode::Allow));
+    /// assert!(!explicit.contains_with(&version, PreReleaseMode::Disallow));
+    /// ```
+    pub fn contains_with(&self, version: &Version, prereleases: PreReleaseMode) -> bool {
+        self.contains(version) && prereleases.accepts(version, || self.any_prerelease())
+    }
+}
+
+impl PreReleaseMode {
+    /// Whether the given version is acceptable under this policy, assuming it falls within the
+    /// range of the specifier; `explicit` returns whether the specifier includes a pre-release.
+    fn accepts(self, version: &Version, explicit: impl FnOnce() -> bool) -> bool {
+        if !version.any_prerelease() {
+            return true;
+        }
+        match self {
+            Self::Disallow => false,
+            Self::IfNecessary => explicit(),
+            Self::Allow => true,
+        }
+    }
 }
 
 impl FromStr for VersionSpecifier {
@@ -1003,6 +1081,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_contains_with_prereleases() {
+        let stable = Version::from_str("1.0").unwrap();
+        let prerelease = Version::from_str("1.0rc2").unwrap();
+        let dev = Version::from_str("1.1.dev0").unwrap();
+
+        let explicit = VersionSpecifier::from_str(">=1.0rc1").unwrap();
+        let implicit = VersionSpecifier::from_str(">=0.9").unwrap();
+
+        for mode in [
+            PreReleaseMode::Disallow,
+            PreReleaseMode::IfNecessary,
+            PreReleaseMode::Allow,
+        ] {
+            assert!(explicit.contains_with(&stable, mode));
+            assert!(implicit.contains_with(&stable, mode));
+        }
+
+        assert!(!explicit.contains_with(&prerelease, PreReleaseMode::Disallow));
+        assert!(explicit.contains_with(&prerelease, PreReleaseMode::IfNecessary));
+        assert!(explicit.contains_with(&prerelease, PreReleaseMode::Allow));
+
+        assert!(!implicit.contains_with(&prerelease, PreReleaseMode::Disallow));
+        assert!(!implicit.contains_with(&prerelease, PreReleaseMode::IfNecessary));
+        assert!(implicit.contains_with(&prerelease, PreReleaseMode::Allow));
+
+        // Developmental releases are treated as pre-releases.
+        assert!(!implicit.contains_with(&dev, PreReleaseMode::IfNecessary));
+        assert!(explicit.contains_with(&dev, PreReleaseMode::IfNecessary));
+
+        // Versions outside the range are never accepted.
+        let excluded = VersionSpecifier::from_str("<1.0").unwrap();
+        assert!(!excluded.contains_with(&prerelease, PreReleaseMode::Allow));
+
+        // For a set of specifiers, any pre-release specifier opts in.
+        let specifiers = VersionSpecifiers::from_str(">=1.0rc1,<2").unwrap();
+        assert!(specifiers.contains_with(&prerelease, PreReleaseMode::IfNecessary));
+        assert!(!specifiers.contains_with(&prerelease, PreReleaseMode::Disallow));
+        let specifiers = VersionSpecifiers::from_str(">=0.9,<2").unwrap();
+        assert!(!specifiers.contains_with(&prerelease, PreReleaseMode::IfNecessary));
+    }
+
     const VERSIONS_ALL: &[&str] = &[
         // Implicit epoch of 0
         "1.0.dev456",
@@ -20,6 +20,7 @@
 use std::borrow::Cow;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::Range;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -37,9 +38,10 @@ pub use marker::{
     MarkerValueVersion, MarkerWarningKind, StringMarkerTree, StringVersion, VersionMarkerTree,
 };
 pub use origin::RequirementOrigin;
+pub use span::RequirementSpans;
 #[cfg(feature = "non-pep508-extensions")]
 pub use unnamed::{UnnamedRequirement, UnnamedRequirementUrl};
-pub use uv_normalize::{ExtraName, InvalidNameError, PackageName};
+pub use uv_normalize::{ExtraName, InvalidNameError, InvalidNameKind, PackageName};
 /// Version and version specifiers used in requirements (reexport).
 // https://github.com/konstin/pep508_rs/issues/19
 pub use uv_pep440;
@@ -52,6 +54,7 @@ pub use verbatim_url::{
 mod cursor;
 pub mod marker;
 mod origin;
+mod span;
 #[cfg(feature = "non-pep508-extensions")]
 mod unnamed;
 mod verbatim_url;
@@ -397,7 +400,7 @@ impl<T: Pep508Url> FromStr for Requirement<T> {
 
     /// Parse a [Dependency Specifier](https://packaging.python.org/en/latest/specifications/dependency-specifiers/).
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        parse_pep508_requirement::<T>(&mut Cursor::new(input), None, &mut TracingReporter)
+        parse_pep508_requirement::<T>(&mut Cursor::new(input), None, &mut TracingReporter, None)
     }
 }
 
@@ -408,6 +411,7 @@ impl<T: Pep508Url> Requirement<T> {
             &mut Cursor::new(input),
             Some(working_dir.as_ref()),
             &mut TracingReporter,
+            None,
         )
     }
 
@@ -422,8 +426,28 @@ impl<T: Pep508Url> Requirement<T> {
             &mut Cursor::new(input),
             Some(working_dir.as_ref()),
             reporter,
+            None,
         )
     }
+
+    /// Parse a [Dependency Specifier](https://packaging.python.org/en/latest/specifications/dependency-specifiers/),
+    /// along with the location of each of its components in the input.
+    ///
+    /// The [`RequirementSpans`] can be used to edit a single component of the requirement (e.g.,
+    /// the version specifiers) while retaining the original formatting of the rest of the input.
+    pub fn parse_lossless(
+        input: &str,
+        working_dir: impl AsRef<Path>,
+    ) -> Result<(Self, RequirementSpans), Pep508Error<T>> {
+        let mut spans = RequirementSpans::default();
+        let requirement = parse_pep508_requirement(
+            &mut Cursor::new(input),
+            Some(working_dir.as_ref()),
+            &mut TracingReporter,
+            Some(&mut spans),
+        )?;
+        Ok((requirement, spans))
+    }
 }
 
 /// A list of [`ExtraName`] that can be attached to a [`Requirement`].
@@ -916,10 +940,13 @@ fn parse_version_specifier_parentheses<T: Pep508Url>(
 }
 
 /// Parse a PEP 508-compliant [dependency specifier](https://packaging.python.org/en/latest/specifications/dependency-specifiers).
+///
+/// If `spans` is provided, it's populated with the location of each component in the input.
 fn parse_pep508_requirement<T: Pep508Url>(
     cursor: &mut Cursor,
     working_dir: Option<&Path>,
     reporter: &mut impl Reporter,
+    spans: Option<&mut RequirementSpans>,
 ) -> Result<Requirement<T>, Pep508Error<T>> {
     let start = cursor.pos();
 
@@ -944,15 +971,20 @@ fn parse_pep508_requirement<T: Pep508Url>(
     // wsp*
     cursor.eat_whitespace();
     // extras?
+    let extras_start = cursor.pos();
     let extras = parse_extras_cursor(cursor)?;
+    let extras_end = cursor.pos();
     // wsp*
     cursor.eat_whitespace();
 
     // ( url_req | name_req )?
+    let mut version_or_url_start = cursor.pos();
     let requirement_kind = match cursor.peek_char() {
         // url_req
         Some('@') => {
             cursor.next();
+            cursor.eat_whitespace();
+            version_or_url_start = cursor.pos();
             Some(VersionOrUrl::Url(parse_url(cursor, working_dir)?))
         }
         // name_req
@@ -1003,16 +1035,22 @@ fn parse_pep508_requirement<T: Pep508Url>(
         }
     }
 
+    let version_or_url_end = cursor.pos();
+
     // wsp*
     cursor.eat_whitespace();
     // quoted_marker?
+    let mut marker_start = cursor.pos();
     let marker = if cursor.peek_char() == Some(';') {
         // Skip past the semicolon
         cursor.next();
+        cursor.eat_whitespace();
+        marker_start = cursor.pos();
         marker::parse::parse_markers_cursor(cursor, reporter)?
     } else {
         None
     };
+    let marker_end = cursor.pos();
 
     // wsp*
     cursor.eat_whitespace();
@@ -1035,6 +1073,19 @@ fn parse_pep508_requirement<T: Pep508Url>(
         });
     }
 
+    if let Some(spans) = spans {
+        *spans = RequirementSpans {
+            name: name_start..name_end,
+            extras: (extras_end > extras_start).then_some(extras_start..extras_end),
+            version_or_url: requirement_kind
+                .as_ref()
+                .map(|_| trim_end(cursor, version_or_url_start..version_or_url_end)),
+            marker: marker
+                .as_ref()
+                .map(|_| trim_end(cursor, marker_start..marker_end)),
+        };
+    }
+
     Ok(Requirement {
         name,
         extras: extras.into_boxed_slice(),
@@ -1044,6 +1095,12 @@ fn parse_pep508_requirement<T: Pep508Url>(
     })
 }
 
+/// Shrink a range of the input to exclude any trailing whitespace.
+fn trim_end(cursor: &Cursor, range: Range<usize>) -> Range<usize> {
+    let len = cursor.slice(range.start, range.len()).trim_end().len();
+    range.start..range.start + len
+}
+
 #[cfg(test)]
 mod tests {
     //! Half of these tests are copied from <https://github.com/pypa/packaging/pull/624>
@@ -1864,4 +1921,82 @@ mod tests {
 
         Ok(())
     }
+
+    /// Requirements that use a deprecated alias for a marker variable are equivalent to (and
+    /// deduplicated against) those that use the canonical name.
+    #[test]
+    fn deduplicate_deprecated_marker_alias() {
+        let alias =
+            Requirement::<Url>::from_str("cffi; python_implementation == 'CPython'").unwrap();
+        let canonical =
+            Requirement::<Url>::from_str("cffi; platform_python_implementation == 'CPython'")
+                .unwrap();
+        let dotted =
+            Requirement::<Url>::from_str("cffi; platform.python_implementation == 'CPython'")
+                .unwrap();
+        assert_eq!(alias, canonical);
+        assert_eq!(dotted, canonical);
+
+        let requirements = [alias, canonical, dotted]
+            .into_iter()
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(requirements.len(), 1);
+
+        // The canonical name is used for display.
+        assert_snapshot!(
+            requirements.into_iter().next().unwrap(),
+            @r#"cffi ; platform_python_implementation == 'CPython'"#
+        );
+    }
+
+    #[test]
+    fn lossless_spans() {
+        let input = r#"  flask [ dotenv ] >= 2.0 , < 3 ;  python_version >= "3.8"  "#;
+        let (requirement, spans) = Requirement::<Url>::parse_lossless(input, "/").unwrap();
+        assert_eq!(requirement, Requirement::<Url>::from_str(input).unwrap());
+        assert_eq!(&input[spans.name], "flask");
+        assert_eq!(&input[spans.extras.unwrap()], "[ dotenv ]");
+        assert_eq!(&input[spans.version_or_url.unwrap()], ">= 2.0 , < 3");
+        assert_eq!(&input[spans.marker.unwrap()], r#"python_version >= "3.8""#);
+
+        let input = "black (>=24)";
+        let (_, spans) = Requirement::<Url>::parse_lossless(input, "/").unwrap();
+        assert_eq!(spans.extras, None);
+        assert_eq!(&input[spans.version_or_url.unwrap()], "(>=24)");
+        assert_eq!(spans.marker, None);
+
+        let input = "pip @ https://github.com/pypa/pip/archive/1.3.1.zip ; python_version > '3.8'";
+        let (_, spans) = Requirement::<Url>::parse_lossless(input, "/").unwrap();
+        assert_eq!(
+            &input[spans.version_or_url.unwrap()],
+            "https://github.com/pypa/pip/archive/1.3.1.zip"
+        );
+        assert_eq!(&input[spans.marker.unwrap()], "python_version > '3.8'");
+    }
+
+    #[test]
+    fn lossless_edits() {
+        let input = r#"flask[dotenv]   >=2.0,<3   ;   python_version >= "3.8""#;
+        let (_, spans) = Requirement::<Url>::parse_lossless(input, "/").unwrap();
+        assert_snapshot!(
+            spans.replace_version_or_url(input, ">=2.1,<3"),
+            @r#"flask[dotenv]   >=2.1,<3   ;   python_version >= "3.8""#
+        );
+        assert_snapshot!(
+            spans.replace_marker(input, "python_version >= '3.9'"),
+            @r#"flask[dotenv]   >=2.0,<3   ;   python_version >= '3.9'"#
+        );
+
+        // Without a version specifier or marker, the replacement is inserted in place.
+        let input = "flask[dotenv]";
+        let (_, spans) = Requirement::<Url>::parse_lossless(input, "/").unwrap();
+        assert_snapshot!(
+            spans.replace_version_or_url(input, ">=2.0"),
+            @"flask[dotenv]>=2.0"
+        );
+        assert_snapshot!(
+            spans.replace_marker(input, "sys_platform == 'linux'"),
+            @"flask[dotenv] ; sys_platform == 'linux'"
+        );
+    }
 }
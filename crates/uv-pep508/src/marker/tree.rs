@@ -2054,6 +2054,40 @@ mod test {
         }
     }
 
+    /// Deprecated aliases for marker variables are normalized to the canonical variable.
+    #[test]
+    fn test_deprecated_marker_alias_equivalence() {
+        let values = [
+            (
+                r#"python_implementation == "CPython""#,
+                r#"platform_python_implementation == "CPython""#,
+            ),
+            (
+                r#"platform.python_implementation == "CPython""#,
+                r#"platform_python_implementation == "CPython""#,
+            ),
+            (
+                r#"python_implementation == "CPython" or platform_python_implementation == "CPython""#,
+                r#"platform_python_implementation == "CPython""#,
+            ),
+            (
+                r#"python_implementation == "PyPy" and platform_python_implementation != "CPython""#,
+                r#"platform_python_implementation == "PyPy""#,
+            ),
+        ];
+
+        for (a, b) in values {
+            assert_eq!(m(a), m(b), "{a} {b}");
+        }
+
+        // An alias and the canonical variable contradict each other, as they would if both used
+        // the same spelling.
+        assert!(
+            m(r#"python_implementation == "CPython" and platform_python_implementation != "CPython""#)
+                .is_false()
+        );
+    }
+
     #[test]
     fn simplify_python_versions() {
         assert_eq!(
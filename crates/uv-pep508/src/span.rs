@@ -0,0 +1,63 @@
+use std::ops::Range;
+
+/// The byte ranges of the components of a [`Requirement`](crate::Requirement) in the string it was
+/// parsed from, as returned by [`Requirement::parse_lossless`](crate::Requirement::parse_lossless).
+///
+/// The ranges exclude any surrounding whitespace and delimiters (like `@` or `;`), such that a
+/// single component can be replaced while retaining the original formatting of the rest of the
+/// requirement, e.g., to bump the version in `flask [dotenv] >= 2.0 ; python_version >= '3.8'`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct RequirementSpans {
+    /// The package name, e.g., `flask`.
+    pub name: Range<usize>,
+    /// The extras, including the brackets, e.g., `[dotenv]`.
+    pub extras: Option<Range<usize>>,
+    /// The version specifiers, including any parentheses, e.g., `>= 2.0`, or the URL, excluding
+    /// the `@`.
+    pub version_or_url: Option<Range<usize>>,
+    /// The marker, excluding the `;`, e.g., `python_version >= '3.8'`.
+    pub marker: Option<Range<usize>>,
+}
+
+impl RequirementSpans {
+    /// Replace the version specifiers or URL in the given requirement string, which must be the
+    /// string from which the spans were parsed.
+    ///
+    /// If the requirement doesn't have a version specifier or URL, the replacement is inserted
+    /// directly after the name or extras; as such, a URL replacement should include the leading
+    /// `@` (e.g., ` @ https://...`) in that case.
+    pub fn replace_version_or_url(&self, input: &str, version_or_url: &str) -> String {
+        let range = self.version_or_url.clone().unwrap_or_else(|| {
+            let end = self.extras.as_ref().unwrap_or(&self.name).end;
+            end..end
+        });
+        splice(input, range, version_or_url)
+    }
+
+    /// Replace the marker in the given requirement string, which must be the string from which
+    /// the spans were parsed.
+    ///
+    /// If the requirement doesn't have a marker, it's appended, preceded by ` ; `.
+    pub fn replace_marker(&self, input: &str, marker: &str) -> String {
+        if let Some(range) = self.marker.clone() {
+            return splice(input, range, marker);
+        }
+        let end = [&self.version_or_url, &self.extras]
+            .into_iter()
+            .flatten()
+            .chain(std::iter::once(&self.name))
+            .map(|range| range.end)
+            .max()
+            .unwrap_or_default();
+        splice(input, end..end, &format!(" ; {marker}"))
+    }
+}
+
+/// Replace the given range of a string.
+fn splice(input: &str, range: Range<usize>, replacement: &str) -> String {
+    let mut output = String::with_capacity(input.len() - range.len() + replacement.len());
+    output.push_str(&input[..range.start]);
+    output.push_str(replacement);
+    output.push_str(&input[range.end..]);
+    output
+}
@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use serde::ser::SerializeMap;
+use uv_normalize::PackageName;
+
+use crate::PlatformTag;
+
+/// Additional platform tags to consider compatible for specific packages, e.g., to allow
+/// installing wheels that are tagged `linux_x86_64` rather than `manylinux_2_17_x86_64`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TagOverrides(BTreeMap<PackageName, Vec<PlatformTag>>);
+
+impl TagOverrides {
+    /// Returns `true` if there are no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the additional platform tags for the given package, if any.
+    pub fn get(&self, package: &PackageName) -> Option<&[PlatformTag]> {
+        self.0.get(package).map(Vec::as_slice)
+    }
+
+    /// Returns an iterator over the packages and their additional platform tags.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &[PlatformTag])> {
+        self.0
+            .iter()
+            .map(|(package, tags)| (package, tags.as_slice()))
+    }
+}
+
+impl FromIterator<(PackageName, Vec<PlatformTag>)> for TagOverrides {
+    fn from_iter<T: IntoIterator<Item = (PackageName, Vec<PlatformTag>)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl serde::Serialize for TagOverrides {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (package, tags) in &self.0 {
+            let tags = tags.iter().map(ToString::to_string).collect::<Vec<_>>();
+            map.serialize_entry(package, &tags)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TagOverrides {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let map = BTreeMap::<PackageName, Vec<String>>::deserialize(deserializer)?;
+        map.into_iter()
+            .map(|(package, tags)| {
+                let tags = tags
+                    .iter()
+                    .map(|tag| PlatformTag::from_str(tag))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(serde::de::Error::custom)?;
+                Ok((package, tags))
+            })
+            .collect()
+    }
+}
@@ -6,9 +6,10 @@ use std::{cmp, num::NonZeroU32};
 
 use rustc_hash::FxHashMap;
 
+use uv_normalize::PackageName;
 use uv_small_str::SmallString;
 
-use crate::{AbiTag, Arch, LanguageTag, Os, Platform, PlatformError, PlatformTag};
+use crate::{AbiTag, Arch, LanguageTag, Os, Platform, PlatformError, PlatformTag, TagOverrides};
 
 #[derive(Debug, thiserror::Error)]
 pub enum TagsError {
@@ -79,6 +80,8 @@ pub struct Tags {
     map: Arc<FxHashMap<LanguageTag, FxHashMap<AbiTag, FxHashMap<PlatformTag, TagPriority>>>>,
     /// The highest-priority tag for the Python version and platform.
     best: Option<(LanguageTag, AbiTag, PlatformTag)>,
+    /// Additional platform tags to consider compatible for specific packages.
+    overrides: Arc<TagOverrides>,
 }
 
 impl Tags {
@@ -104,9 +107,18 @@ impl Tags {
         Self {
             map: Arc::new(map),
             best,
+            overrides: Arc::default(),
         }
     }
 
+    /// Consider the given platform tags compatible for specific packages, in addition to the
+    /// platform tags supported by the current platform.
+    #[must_use]
+    pub fn with_overrides(mut self, overrides: TagOverrides) -> Self {
+        self.overrides = Arc::new(overrides);
+        self
+    }
+
     /// Returns the compatible tags for the given Python implementation (e.g., `cpython`), version,
     /// and platform.
     pub fn from_env(
@@ -297,6 +309,106 @@ impl Tags {
         max_compatibility
     }
 
+    /// Returns true when the given tags are compatible for the given package, including any
+    /// platform tag overrides for the package.
+    pub fn is_compatible_package(
+        &self,
+        package: &PackageName,
+        wheel_python_tags: &[LanguageTag],
+        wheel_abi_tags: &[AbiTag],
+        wheel_platform_tags: &[PlatformTag],
+    ) -> bool {
+        self.is_compatible(wheel_python_tags, wheel_abi_tags, wheel_platform_tags)
+            || self
+                .override_priority(
+                    package,
+                    wheel_python_tags,
+                    wheel_abi_tags,
+                    wheel_platform_tags,
+                )
+                .is_some()
+    }
+
+    /// Returns the [`TagCompatibility`] of the given tags for the given package, including any
+    /// platform tag overrides for the package.
+    pub fn compatibility_package(
+        &self,
+        package: &PackageName,
+        wheel_python_tags: &[LanguageTag],
+        wheel_abi_tags: &[AbiTag],
+        wheel_platform_tags: &[PlatformTag],
+    ) -> TagCompatibility {
+        let compatibility =
+            self.compatibility(wheel_python_tags, wheel_abi_tags, wheel_platform_tags);
+        if compatibility.is_compatible() {
+            return compatibility;
+        }
+        if let Some(priority) = self.override_priority(
+            package,
+            wheel_python_tags,
+            wheel_abi_tags,
+            wheel_platform_tags,
+        ) {
+            return TagCompatibility::Compatible(priority);
+        }
+        compatibility
+    }
+
+    /// Returns true if the given tags are only compatible for the given package due to a
+    /// platform tag override.
+    pub fn is_override(
+        &self,
+        package: &PackageName,
+        wheel_python_tags: &[LanguageTag],
+        wheel_abi_tags: &[AbiTag],
+        wheel_platform_tags: &[PlatformTag],
+    ) -> bool {
+        !self.is_compatible(wheel_python_tags, wheel_abi_tags, wheel_platform_tags)
+            && self
+                .override_priority(
+                    package,
+                    wheel_python_tags,
+                    wheel_abi_tags,
+                    wheel_platform_tags,
+                )
+                .is_some()
+    }
+
+    /// Returns the priority of the given tags if they're compatible due to a platform tag
+    /// override for the given package.
+    ///
+    /// The Python and ABI tags must still be compatible; overridden platform tags are given the
+    /// lowest priority of any platform tag for the matching Python and ABI tags.
+    fn override_priority(
+        &self,
+        package: &PackageName,
+        wheel_python_tags: &[LanguageTag],
+        wheel_abi_tags: &[AbiTag],
+        wheel_platform_tags: &[PlatformTag],
+    ) -> Option<TagPriority> {
+        let overrides = self.overrides.get(package)?;
+        if !wheel_platform_tags
+            .iter()
+            .any(|wheel_platform| overrides.contains(wheel_platform))
+        {
+            return None;
+        }
+        let mut priority = None;
+        for wheel_py in wheel_python_tags {
+            let Some(abis) = self.map.get(wheel_py) else {
+                continue;
+            };
+            for wheel_abi in wheel_abi_tags {
+                let Some(platforms) = abis.get(wheel_abi) else {
+                    continue;
+                };
+                let lowest = platforms.values().min().copied();
+                priority = priority.max(lowest);
+            }
+        }
+        priority
+    }
+
     /// Return the highest-priority Python tag for the [`Tags`].
     pub fn python_tag(&self) -> Option<LanguageTag> {
         self.best.as_ref().map(|(python, _, _)| *python)
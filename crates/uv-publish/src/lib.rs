@@ -9,6 +9,7 @@ use fs_err::tokio::File;
 use futures::TryStreamExt;
 use glob::{GlobError, PatternError, glob};
 use itertools::Itertools;
+use owo_colors::OwoColorize;
 use reqwest::header::AUTHORIZATION;
 use reqwest::multipart::Part;
 use reqwest::{Body, Response, StatusCode};
@@ -28,7 +29,7 @@ use uv_auth::Credentials;
 use uv_cache::{Cache, Refresh};
 use uv_client::{
     BaseClient, MetadataFormat, OwnedArchive, RegistryClientBuilder, RequestBuilder,
-    RetryParsingError, UvRetryableStrategy, retries_from_env,
+    UvRetryableStrategy, retry_after,
 };
 use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
@@ -66,6 +67,12 @@ pub enum PublishError {
     MixedCredentials(String),
     #[error("Failed to query check URL")]
     CheckUrlIndex(#[source] uv_client::Error),
+    #[error(
+        "Failed to query check URL `{0}` due to an authentication failure ({1})\n\n{hint}{colon} Provide credentials for the check URL via a `.netrc` file, the keyring (`--keyring-provider subprocess`), or, for a named index, the `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD` environment variables",
+        hint = "hint".bold().cyan(),
+        colon = ":".bold()
+    )]
+    CheckUrlAuthentication(IndexUrl, StatusCode),
     #[error(
         "Local file and index file do not match for {filename}. \
         Local: {hash_algorithm}={local}, Remote: {hash_algorithm}={remote}"
@@ -78,8 +85,6 @@ pub enum PublishError {
     },
     #[error("Hash is missing in index for {0}")]
     MissingHash(Box<DistFilename>),
-    #[error(transparent)]
-    RetryParsing(#[from] RetryParsingError),
 }
 
 /// Failure to get the metadata for a specific file.
@@ -390,6 +395,7 @@ pub async fn upload(
     credentials: &Credentials,
     check_url_client: Option<&CheckUrlClient<'_>>,
     download_concurrency: &Semaphore,
+    retry_policy: &ExponentialBackoff,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
     let form_metadata = FormMetadata::read_from_file(file, filename)
@@ -398,8 +404,6 @@ pub async fn upload(
 
     let mut n_past_retries = 0;
     let start_time = SystemTime::now();
-    // N.B. We cannot use the client policy here because it is set to zero retries
-    let retry_policy = ExponentialBackoff::builder().build_with_max_retries(retries_from_env()?);
     loop {
         let (request, idx) = build_request(
             file,
@@ -423,6 +427,12 @@ pub async fn upload(
                 let duration = execute_after
                     .duration_since(SystemTime::now())
                     .unwrap_or_else(|_| Duration::default());
+                // Wait at least as long as the registry requested.
+                let duration = result
+                    .as_ref()
+                    .ok()
+                    .and_then(retry_after)
+                    .map_or(duration, |retry_after| duration.max(retry_after));
                 tokio::time::sleep(duration).await;
                 n_past_retries += 1;
                 continue;
@@ -505,6 +515,21 @@ pub async fn check_url(
         Err(err) => {
             return match err.kind() {
                 uv_client::ErrorKind::PackageNotFound(_) => {
+                    // The registry client reports authentication failures as a missing package,
+                    // but we can't tell whether the file exists, and uploading it anyway may fail
+                    // as a duplicate.
+                    if index_capabilities.unauthorized(index_url) {
+                        return Err(PublishError::CheckUrlAuthentication(
+                            index_url.clone(),
+                            StatusCode::UNAUTHORIZED,
+                        ));
+                    }
+                    if index_capabilities.forbidden(index_url) {
+                        return Err(PublishError::CheckUrlAuthentication(
+                            index_url.clone(),
+                            StatusCode::FORBIDDEN,
+                        ));
+                    }
                     // The package doesn't exist, so we can't have uploaded it.
                     warn!(
                         "Package not found in the registry; skipping upload check for {filename}"
@@ -1,6 +1,7 @@
 use regex::Regex;
 use serde::{Deserialize, Deserializer, Serialize, de};
 use std::borrow::Cow;
+use std::path::Path;
 use std::str::FromStr;
 use std::sync::LazyLock;
 use tracing::warn;
@@ -86,8 +87,12 @@ fn remove_stray_quotes(input: &str) -> Cow<'_, str> {
     }
 }
 
-fn parse_with_fixups<Err, T: FromStr<Err = Err>>(input: &str, type_name: &str) -> Result<T, Err> {
-    match T::from_str(input) {
+fn parse_with_fixups<Err, T>(
+    input: &str,
+    type_name: &str,
+    parse: impl Fn(&str) -> Result<T, Err>,
+) -> Result<T, Err> {
+    match parse(input) {
         Ok(requirement) => Ok(requirement),
         Err(err) => {
             let mut patched_input = input.to_string();
@@ -97,7 +102,7 @@ fn parse_with_fixups<Err, T: FromStr<Err = Err>>(input: &str, type_name: &str) -
                 if patched != patched_input {
                     messages.push(*message);
 
-                    if let Ok(requirement) = T::from_str(&patched) {
+                    if let Ok(requirement) = parse(&patched) {
                         warn!(
                             "Fixing invalid {type_name} by {} (before: `{input}`; after: `{patched}`)",
                             messages.join(", ")
@@ -122,7 +127,22 @@ impl<T: Pep508Url> FromStr for LenientRequirement<T> {
     type Err = Pep508Error<T>;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        Ok(Self(parse_with_fixups(input, "requirement")?))
+        Ok(Self(parse_with_fixups(
+            input,
+            "requirement",
+            Requirement::from_str,
+        )?))
+    }
+}
+
+impl<T: Pep508Url> LenientRequirement<T> {
+    /// Parse a [`LenientRequirement`], resolving any relative paths against the given working
+    /// directory.
+    pub fn parse(input: &str, working_dir: impl AsRef<Path>) -> Result<Self, Pep508Error<T>> {
+        let working_dir = working_dir.as_ref();
+        Ok(Self(parse_with_fixups(input, "requirement", |input| {
+            Requirement::parse(input, working_dir)
+        })?))
     }
 }
 
@@ -142,7 +162,11 @@ impl FromStr for LenientVersionSpecifiers {
     type Err = VersionSpecifiersParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        Ok(Self(parse_with_fixups(input, "version specifier")?))
+        Ok(Self(parse_with_fixups(
+            input,
+            "version specifier",
+            VersionSpecifiers::from_str,
+        )?))
     }
 }
 
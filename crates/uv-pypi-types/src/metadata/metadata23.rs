@@ -179,6 +179,28 @@ impl Metadata23 {
         })
     }
 
+    /// Return the URL of the project's changelog, if declared via a `Project-URL` entry with a
+    /// well-known label (e.g., `Changelog` or `Release Notes`).
+    ///
+    /// Labels are normalized by removing punctuation and whitespace, and lowercasing, per the
+    /// [well-known project URLs](https://packaging.python.org/en/latest/specifications/well-known-project-urls/)
+    /// specification.
+    pub fn changelog_url(&self) -> Option<&str> {
+        self.project_urls.iter().find_map(|project_url| {
+            let (label, url) = project_url.split_once(',')?;
+            let label = label
+                .chars()
+                .filter(|c| !c.is_ascii_punctuation() && !c.is_whitespace())
+                .flat_map(char::to_lowercase)
+                .collect::<String>();
+            matches!(
+                label.as_str(),
+                "changelog" | "changes" | "releasenotes" | "history" | "whatsnew"
+            )
+            .then(|| url.trim())
+        })
+    }
+
     /// Convert to the pseudo-email format used by Python's METADATA.
     ///
     /// > The standard file format for metadata (including in wheels and installed projects) is
@@ -322,4 +344,19 @@ mod tests {
         assert_eq!(meta.author.as_deref(), Some("中文"));
         assert_eq!(meta.description.as_deref(), Some("一个 Python 包"));
     }
+
+    #[test]
+    fn changelog_url() {
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nProject-URL: Homepage, https://example.com\nProject-URL: Release Notes, https://example.com/releases";
+        let meta = Metadata23::parse(s.as_bytes()).unwrap();
+        assert_eq!(meta.changelog_url(), Some("https://example.com/releases"));
+
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nProject-URL: change_log, https://example.com/changelog";
+        let meta = Metadata23::parse(s.as_bytes()).unwrap();
+        assert_eq!(meta.changelog_url(), Some("https://example.com/changelog"));
+
+        let s = "Metadata-Version: 2.1\nName: asdf\nVersion: 1.0\nProject-URL: Source, https://example.com/source";
+        let meta = Metadata23::parse(s.as_bytes()).unwrap();
+        assert_eq!(meta.changelog_url(), None);
+    }
 }
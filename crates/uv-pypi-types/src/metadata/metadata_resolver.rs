@@ -1,20 +1,33 @@
 //! Derived from `pypi_types_crate`.
 
+use std::borrow::Cow;
 use std::str::FromStr;
+use std::sync::LazyLock;
 
 use itertools::Itertools;
+use owo_colors::OwoColorize;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use tracing::warn;
 
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::Requirement;
+use uv_warnings::warn_user_once;
 
 use crate::lenient_requirement::LenientRequirement;
 use crate::metadata::Headers;
 use crate::metadata::pyproject_toml::PyProjectToml;
 use crate::{LenientVersionSpecifiers, MetadataError, VerbatimParsedUrl, metadata};
 
+/// Matches the quoted value in an `extra` marker, e.g., `extra == 'Dev Tools'` or `'Dev Tools' == extra`.
+static EXTRA_MARKER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"\bextra\s*(?:==|!=)\s*(?:'([^']*)'|"([^"]*)")|(?:'([^']*)'|"([^"]*)")\s*(?:==|!=)\s*extra\b"#,
+    )
+    .unwrap()
+});
+
 /// A subset of the full core metadata specification, including only the
 /// fields that are relevant to dependency resolution.
 ///
@@ -53,8 +66,7 @@ impl ResolutionMetadata {
         .map_err(MetadataError::Pep440VersionError)?;
         let requires_dist = headers
             .get_all_values("Requires-Dist")
-            .map(|requires_dist| LenientRequirement::from_str(&requires_dist))
-            .map_ok(Requirement::from)
+            .map(|requires_dist| parse_requires_dist(&name, &requires_dist))
             .collect::<Result<Box<_>, _>>()?;
         let requires_python = headers
             .get_first_value("Requires-Python")
@@ -63,15 +75,7 @@ impl ResolutionMetadata {
             .map(VersionSpecifiers::from);
         let provides_extras = headers
             .get_all_values("Provides-Extra")
-            .filter_map(
-                |provides_extra| match ExtraName::from_owned(provides_extra) {
-                    Ok(extra_name) => Some(extra_name),
-                    Err(err) => {
-                        warn!("Ignoring invalid extra: {err}");
-                        None
-                    }
-                },
-            )
+            .filter_map(|provides_extra| parse_provides_extra(&name, &provides_extra))
             .collect::<Box<_>>();
         let dynamic = headers
             .get_all_values("Dynamic")
@@ -133,8 +137,7 @@ impl ResolutionMetadata {
         // The remaining fields are required to be present.
         let requires_dist = headers
             .get_all_values("Requires-Dist")
-            .map(|requires_dist| LenientRequirement::from_str(&requires_dist))
-            .map_ok(Requirement::from)
+            .map(|requires_dist| parse_requires_dist(&name, &requires_dist))
             .collect::<Result<Box<_>, _>>()?;
         let requires_python = headers
             .get_first_value("Requires-Python")
@@ -143,15 +146,7 @@ impl ResolutionMetadata {
             .map(VersionSpecifiers::from);
         let provides_extras = headers
             .get_all_values("Provides-Extra")
-            .filter_map(
-                |provides_extra| match ExtraName::from_owned(provides_extra) {
-                    Ok(extra_name) => Some(extra_name),
-                    Err(err) => {
-                        warn!("Ignoring invalid extra: {err}");
-                        None
-                    }
-                },
-            )
+            .filter_map(|provides_extra| parse_provides_extra(&name, &provides_extra))
             .collect::<Box<_>>();
 
         Ok(Self {
@@ -264,6 +259,125 @@ impl ResolutionMetadata {
     }
 }
 
+/// Parse a `Provides-Extra` entry from third-party metadata.
+///
+/// Invalid extras are normalized leniently (with a warning), rather than rejected, since the
+/// metadata is outside the user's control. Extras that can't be salvaged are ignored.
+fn parse_provides_extra(name: &PackageName, provides_extra: &str) -> Option<ExtraName> {
+    if let Ok(extra) = ExtraName::from_str(provides_extra) {
+        return Some(extra);
+    }
+    match ExtraName::from_str_lenient(provides_extra) {
+        Ok(extra) => {
+            warn_invalid_extra(name, provides_extra, &extra);
+            Some(extra)
+        }
+        Err(err) => {
+            warn!("Ignoring invalid extra for `{name}`: {err}");
+            None
+        }
+    }
+}
+
+/// Parse a `Requires-Dist` entry from third-party metadata.
+///
+/// If the requirement is invalid due to its extras (e.g., `foo[Dev Tools]`), the extras are
+/// normalized leniently (with a warning).
+fn parse_requires_dist(
+    name: &PackageName,
+    requires_dist: &str,
+) -> Result<Requirement<VerbatimParsedUrl>, MetadataError> {
+    // Normalize invalid extras in markers first: they parse successfully, but would never match.
+    let requires_dist = normalize_extra_markers(name, requires_dist);
+    let requires_dist = requires_dist.as_ref();
+
+    let err = match LenientRequirement::from_str(requires_dist) {
+        Ok(requirement) => return Ok(Requirement::from(requirement)),
+        Err(err) => err,
+    };
+
+    // Locate the extras, which precede any URL or markers.
+    let end = requires_dist
+        .find([';', '@'])
+        .unwrap_or(requires_dist.len());
+    let Some(start) = requires_dist[..end].find('[') else {
+        return Err(err.into());
+    };
+    let Some(len) = requires_dist[start..end].find(']') else {
+        return Err(err.into());
+    };
+    let verbatim = &requires_dist[start + 1..start + len];
+
+    let mut fixed = Vec::new();
+    let mut extras = Vec::new();
+    for extra in verbatim.split(',').map(str::trim) {
+        if let Ok(normalized) = ExtraName::from_str(extra) {
+            extras.push(normalized);
+        } else if let Ok(normalized) = ExtraName::from_str_lenient(extra) {
+            fixed.push((extra, normalized.clone()));
+            extras.push(normalized);
+        } else {
+            return Err(err.into());
+        }
+    }
+    if fixed.is_empty() {
+        return Err(err.into());
+    }
+
+    let patched = format!(
+        "{}[{}]{}",
+        &requires_dist[..start],
+        extras.iter().join(","),
+        &requires_dist[start + len + 1..]
+    );
+    let Ok(requirement) = LenientRequirement::from_str(&patched) else {
+        return Err(err.into());
+    };
+    for (extra, normalized) in fixed {
+        warn_invalid_extra(name, extra, &normalized);
+    }
+    Ok(Requirement::from(requirement))
+}
+
+/// Normalize invalid extras in `extra` markers (e.g., `extra == 'Dev Tools'`), using the same
+/// lenient normalization as `Provides-Extra`.
+fn normalize_extra_markers<'a>(name: &PackageName, requires_dist: &'a str) -> Cow<'a, str> {
+    let Some(start) = requires_dist.find(';') else {
+        return Cow::Borrowed(requires_dist);
+    };
+    let (requirement, markers) = requires_dist.split_at(start);
+    let markers = EXTRA_MARKER.replace_all(markers, |captures: &regex::Captures| {
+        let marker = captures.get(0).unwrap();
+        let value = (1..=4).find_map(|index| captures.get(index)).unwrap();
+        let extra = value.as_str();
+        if ExtraName::from_str(extra).is_ok() {
+            return marker.as_str().to_string();
+        }
+        let Ok(normalized) = ExtraName::from_str_lenient(extra) else {
+            return marker.as_str().to_string();
+        };
+        warn_invalid_extra(name, extra, &normalized);
+        format!(
+            "{}{normalized}{}",
+            &marker.as_str()[..value.start() - marker.start()],
+            &marker.as_str()[value.end() - marker.start()..]
+        )
+    });
+    match markers {
+        Cow::Borrowed(_) => Cow::Borrowed(requires_dist),
+        Cow::Owned(markers) => Cow::Owned(format!("{requirement}{markers}")),
+    }
+}
+
+/// Warn that an invalid extra in the metadata for the given package was normalized.
+fn warn_invalid_extra(name: &PackageName, extra: &str, normalized: &ExtraName) {
+    warn_user_once!(
+        "The metadata for `{}` includes an invalid extra (`{extra}`), which will be treated as `{}`",
+        name.cyan(),
+        normalized.cyan()
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -337,6 +451,52 @@ mod tests {
         assert_eq!(*meta.requires_dist, ["foo".parse().unwrap()]);
     }
 
+    #[test]
+    fn test_parse_invalid_extras() {
+        // Invalid extras in third-party metadata are normalized.
+        let s = "Metadata-Version: 2.3\nName: asdf\nVersion: 1.0\nProvides-Extra: Dev Tools\nProvides-Extra: !!!\nRequires-Dist: foo[Dev Tools, test] >=1.0; extra == 'Dev Tools'";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(*meta.provides_extras, ["devtools".parse().unwrap()]);
+        assert_eq!(
+            *meta.requires_dist,
+            ["foo[devtools,test]>=1.0; extra == 'devtools'"
+                .parse()
+                .unwrap()]
+        );
+
+        // Resolving `asdf[devtools]` selects `foo`.
+        let foo = &meta.requires_dist[0];
+        assert!(foo.marker.evaluate_extras(&["devtools".parse().unwrap()]));
+        assert!(!foo.marker.evaluate_extras(&[]));
+
+        // Reversed and double-quoted markers are normalized too.
+        let s = "Metadata-Version: 2.3\nName: asdf\nVersion: 1.0\nRequires-Dist: foo; \"Dev Tools\" == extra";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes()).unwrap();
+        assert_eq!(
+            *meta.requires_dist,
+            ["foo; extra == 'devtools'".parse().unwrap()]
+        );
+
+        let meta = ResolutionMetadata::parse_pkg_info(s.as_bytes()).unwrap();
+        assert_eq!(*meta.provides_extras, ["devtools".parse().unwrap()]);
+
+        // Unrelated errors are still surfaced.
+        let s =
+            "Metadata-Version: 2.3\nName: asdf\nVersion: 1.0\nRequires-Dist: foo[Dev Tools] >>1.0";
+        let meta = ResolutionMetadata::parse_metadata(s.as_bytes());
+        assert!(matches!(meta, Err(MetadataError::Pep508Error(_))));
+
+        // Extras in user-authored files are still validated strictly.
+        let s = r#"
+        [project]
+        name = "asdf"
+        version = "1.0"
+        [project.optional-dependencies]
+        "Dev Tools" = ["foo"]
+    "#;
+        assert!(PyProjectToml::from_toml(s).is_err());
+    }
+
     #[test]
     fn test_parse_pyproject_toml() {
         let s = r#"
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::path::Path;
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
@@ -27,7 +27,13 @@ pub struct RequiresDist {
 
 impl RequiresDist {
     /// Extract the [`RequiresDist`] from a `pyproject.toml` file, as specified in PEP 621.
-    pub fn parse_pyproject_toml(contents: &str) -> Result<Self, MetadataError> {
+    ///
+    /// Any relative paths in the requirements are resolved against the `project_root` (i.e., the
+    /// directory containing the `pyproject.toml`), rather than the current working directory.
+    pub fn parse_pyproject_toml(
+        contents: &str,
+        project_root: &Path,
+    ) -> Result<Self, MetadataError> {
         let pyproject_toml = PyProjectToml::from_toml(contents)?;
 
         let project = pyproject_toml
@@ -66,7 +72,7 @@ impl RequiresDist {
             .dependencies
             .unwrap_or_default()
             .into_iter()
-            .map(|requires_dist| LenientRequirement::from_str(&requires_dist))
+            .map(|requires_dist| LenientRequirement::parse(&requires_dist, project_root))
             .map_ok(Requirement::from)
             .chain(
                 project
@@ -77,7 +83,9 @@ impl RequiresDist {
                         index.iter().flat_map(|(extras, requirements)| {
                             requirements
                                 .iter()
-                                .map(|requires_dist| LenientRequirement::from_str(requires_dist))
+                                .map(|requires_dist| {
+                                    LenientRequirement::parse(requires_dist, project_root)
+                                })
                                 .map_ok(Requirement::from)
                                 .map_ok(move |requirement| requirement.with_extra_marker(extras))
                         })
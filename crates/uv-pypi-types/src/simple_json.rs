@@ -342,6 +342,13 @@ pub enum HashAlgorithm {
     Blake2b,
 }
 
+impl HashAlgorithm {
+    /// Returns `true` if the algorithm is too weak to verify the integrity of an artifact.
+    pub fn is_insecure(self) -> bool {
+        matches!(self, Self::Md5)
+    }
+}
+
 impl FromStr for HashAlgorithm {
     type Err = HashError;
 
@@ -482,6 +489,20 @@ impl HashDigests {
     pub fn sort_unstable(&mut self) {
         self.0.sort_unstable();
     }
+
+    /// Retain only the [`HashDigest`] entities for the given [`HashAlgorithm`].
+    ///
+    /// If none of the digests use the given algorithm, all digests are retained.
+    pub fn retain_preferred(&mut self, algorithm: HashAlgorithm) {
+        if self.0.iter().any(|digest| digest.algorithm == algorithm) {
+            self.0 = self
+                .0
+                .iter()
+                .filter(|digest| digest.algorithm == algorithm)
+                .cloned()
+                .collect();
+        }
+    }
 }
 
 /// Convert a set of [`Hashes`] into a list of [`HashDigest`]s.
@@ -586,7 +607,9 @@ pub enum HashError {
 
 #[cfg(test)]
 mod tests {
-    use crate::{HashError, Hashes};
+    use std::str::FromStr;
+
+    use crate::{HashAlgorithm, HashDigest, HashDigests, HashError, Hashes};
 
     #[test]
     fn parse_hashes() -> Result<(), HashError> {
@@ -675,4 +698,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn retain_preferred() -> Result<(), HashError> {
+        let sha256 = HashDigest::from_str(
+            "sha256:40627dcf047dadb22cd25ea7ecfe9cbf3bbbad0482ee5920b582f3809c97654f",
+        )?;
+        let sha512 = HashDigest::from_str(
+            "sha512:40627dcf047dadb22cd25ea7ecfe9cbf3bbbad0482ee5920b582f3809c97654f",
+        )?;
+
+        // If a digest for the preferred algorithm is present, retain only that digest.
+        let mut digests = HashDigests::from(vec![sha256.clone(), sha512.clone()]);
+        digests.retain_preferred(HashAlgorithm::Sha512);
+        assert_eq!(digests.as_slice(), std::slice::from_ref(&sha512));
+
+        // Otherwise, retain all digests.
+        let mut digests = HashDigests::from(vec![sha256.clone(), sha512.clone()]);
+        digests.retain_preferred(HashAlgorithm::Blake2b);
+        assert_eq!(digests.as_slice(), [sha256, sha512]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,155 @@
+//! Health checks for managed Python installations.
+//!
+//! Managed installations can break after they're installed, e.g., if an operating system upgrade
+//! removes a shared library that the interpreter links against. A probe executes the interpreter
+//! and imports a few extension modules that depend on such libraries.
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+use fs_err as fs;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{debug, trace};
+
+use uv_cache::{Cache, CacheBucket, CachedByTimestamp};
+use uv_cache_info::Timestamp;
+use uv_cache_key::cache_digest;
+use uv_fs::{Simplified, write_atomic_sync};
+
+use crate::managed::ManagedPythonInstallation;
+
+/// The script executed by the probe.
+///
+/// `ssl` and `zlib` link against system libraries, and so are the most likely to break.
+const PROBE_SCRIPT: &str = "import ssl, zlib";
+
+/// The duration for which a probe result is considered fresh.
+const PROBE_TTL: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    Encode(#[from] rmp_serde::encode::Error),
+}
+
+/// The health of a managed Python installation, as determined by a probe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PythonHealth {
+    /// The interpreter started and imported the probed modules.
+    Healthy,
+    /// The interpreter failed to start, or failed to import one of the probed modules.
+    Broken(String),
+}
+
+impl PythonHealth {
+    /// Returns `true` if the installation is broken.
+    pub fn is_broken(&self) -> bool {
+        matches!(self, Self::Broken(_))
+    }
+}
+
+/// A cached probe result.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedProbe {
+    /// The time at which the probe was run.
+    checked_at: SystemTime,
+    /// The result of the probe.
+    health: PythonHealth,
+}
+
+/// Probe a managed Python installation, re-using a recent result if available.
+///
+/// Results are cached for a short period, and invalidated whenever the interpreter executable is
+/// modified (e.g., by a reinstall).
+pub fn check(
+    installation: &ManagedPythonInstallation,
+    cache: &Cache,
+) -> Result<PythonHealth, Error> {
+    let executable = installation.executable(false);
+
+    let modified = match Timestamp::from_path(&executable) {
+        Ok(modified) => modified,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Ok(PythonHealth::Broken(format!(
+                "missing executable at `{}`",
+                executable.user_display()
+            )));
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let cache_entry = cache.entry(
+        CacheBucket::Interpreter,
+        "health",
+        format!("{}.msgpack", cache_digest(&installation.path())),
+    );
+
+    // Read from the cache.
+    if let Ok(data) = fs::read(cache_entry.path()) {
+        if let Ok(cached) = rmp_serde::from_slice::<CachedByTimestamp<CachedProbe>>(&data) {
+            let fresh = cached
+                .data
+                .checked_at
+                .elapsed()
+                .is_ok_and(|elapsed| elapsed < PROBE_TTL);
+            if cached.timestamp == modified && fresh {
+                trace!(
+                    "Found cached health check for `{}`, skipping probe",
+                    installation.key()
+                );
+                return Ok(cached.data.health);
+            }
+        }
+    }
+
+    // Otherwise, run the probe.
+    let health = probe(&executable);
+    debug!("Health check for `{}`: {health:?}", installation.key());
+
+    fs::create_dir_all(cache_entry.dir())?;
+    write_atomic_sync(
+        cache_entry.path(),
+        rmp_serde::to_vec(&CachedByTimestamp {
+            timestamp: modified,
+            data: CachedProbe {
+                checked_at: SystemTime::now(),
+                health: health.clone(),
+            },
+        })?,
+    )?;
+
+    Ok(health)
+}
+
+/// Execute the interpreter with the probe script.
+fn probe(executable: &Path) -> PythonHealth {
+    let output = match Command::new(executable)
+        .arg("-I")
+        .arg("-c")
+        .arg(PROBE_SCRIPT)
+        .output()
+    {
+        Ok(output) => output,
+        Err(err) => return PythonHealth::Broken(format!("failed to start: {err}")),
+    };
+
+    if output.status.success() {
+        return PythonHealth::Healthy;
+    }
+
+    // Report the last line of the traceback, e.g., `ModuleNotFoundError: No module named '_ssl'`.
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let reason = stderr
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(ToString::to_string)
+        .unwrap_or_else(|| format!("exited with {}", output.status));
+
+    PythonHealth::Broken(reason)
+}
@@ -32,6 +32,7 @@ pub use crate::virtualenv::{Error as VirtualEnvError, PyVenvConfiguration, Virtu
 mod discovery;
 pub mod downloads;
 mod environment;
+pub mod health;
 mod implementation;
 mod installation;
 mod interpreter;
@@ -47,7 +47,10 @@ use url::Url;
 #[cfg(feature = "http")]
 use uv_client::BaseClient;
 use uv_client::BaseClientBuilder;
-use uv_configuration::{NoBinary, NoBuild, PackageNameSpecifier};
+use uv_configuration::{
+    ConfigSettingEntry, ConfigSettingPackageEntry, ConfigSettings, NoBinary, NoBuild,
+    PackageConfigSettings, PackageNameSpecifier,
+};
 use uv_distribution_types::{
     Requirement, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
@@ -94,6 +97,10 @@ enum RequirementsTxtStatement {
     NoBinary(NoBinary),
     /// `--only-binary`
     OnlyBinary(NoBuild),
+    /// `--config-settings`
+    ConfigSettings(ConfigSettingEntry),
+    /// `--config-settings-package`
+    ConfigSettingsPackage(ConfigSettingPackageEntry),
     /// An unsupported option (e.g., `--trusted-host`).
     UnsupportedOption(UnsupportedOption),
 }
@@ -157,6 +164,11 @@ pub struct RequirementsTxt {
     pub no_binary: NoBinary,
     /// Whether to allow only wheels, specified with `--only-binary`.
     pub only_binary: NoBuild,
+    /// The settings to pass to build backends, specified with `--config-settings`.
+    pub config_settings: ConfigSettings,
+    /// The per-package settings to pass to build backends, specified with
+    /// `--config-settings-package`.
+    pub config_settings_package: PackageConfigSettings,
 }
 
 impl RequirementsTxt {
@@ -392,6 +404,15 @@ impl RequirementsTxt {
                 RequirementsTxtStatement::OnlyBinary(only_binary) => {
                     data.only_binary.extend(only_binary);
                 }
+                RequirementsTxtStatement::ConfigSettings(entry) => {
+                    data.config_settings = std::mem::take(&mut data.config_settings)
+                        .merge(std::iter::once(entry).collect());
+                }
+                RequirementsTxtStatement::ConfigSettingsPackage(entry) => {
+                    data.config_settings_package =
+                        std::mem::take(&mut data.config_settings_package)
+                            .merge(std::iter::once(entry).collect());
+                }
                 RequirementsTxtStatement::UnsupportedOption(flag) => {
                     if requirements_txt == Path::new("-") {
                         if flag.cli() {
@@ -438,6 +459,8 @@ impl RequirementsTxt {
             no_index,
             no_binary,
             only_binary,
+            config_settings,
+            config_settings_package,
         } = other;
         self.requirements.extend(requirements);
         self.constraints.extend(constraints);
@@ -450,6 +473,9 @@ impl RequirementsTxt {
         self.no_index = self.no_index || no_index;
         self.no_binary.extend(no_binary);
         self.only_binary.extend(only_binary);
+        self.config_settings = std::mem::take(&mut self.config_settings).merge(config_settings);
+        self.config_settings_package =
+            std::mem::take(&mut self.config_settings_package).merge(config_settings_package);
     }
 }
 
@@ -718,6 +744,40 @@ fn parse_entry(
             }
         })?;
         RequirementsTxtStatement::OnlyBinary(NoBuild::from_pip_arg(specifier))
+    } else if s.eat_if("--config-settings-package") {
+        let given = parse_value("--config-settings-package", content, s, |c: char| {
+            !is_terminal(c)
+        })?;
+        let given = unquote(given)
+            .ok()
+            .flatten()
+            .map(Cow::Owned)
+            .unwrap_or(Cow::Borrowed(given));
+        let entry = ConfigSettingPackageEntry::from_str(given.as_ref()).map_err(|_| {
+            RequirementsTxtParserError::ConfigSettings {
+                flag: "--config-settings-package",
+                setting: given.to_string(),
+                start,
+                end: s.cursor(),
+            }
+        })?;
+        RequirementsTxtStatement::ConfigSettingsPackage(entry)
+    } else if s.eat_if("--config-settings") {
+        let given = parse_value("--config-settings", content, s, |c: char| !is_terminal(c))?;
+        let given = unquote(given)
+            .ok()
+            .flatten()
+            .map(Cow::Owned)
+            .unwrap_or(Cow::Borrowed(given));
+        let entry = ConfigSettingEntry::from_str(given.as_ref()).map_err(|_| {
+            RequirementsTxtParserError::ConfigSettings {
+                flag: "--config-settings",
+                setting: given.to_string(),
+                start,
+                end: s.cursor(),
+            }
+        })?;
+        RequirementsTxtStatement::ConfigSettings(entry)
     } else if s.at(char::is_ascii_alphanumeric) || s.at(|char| matches!(char, '.' | '/' | '$')) {
         let source = if requirements_txt == Path::new("-") {
             None
@@ -1016,6 +1076,12 @@ pub enum RequirementsTxtParserError {
         start: usize,
         end: usize,
     },
+    ConfigSettings {
+        flag: &'static str,
+        setting: String,
+        start: usize,
+        end: usize,
+    },
     UnnamedConstraint {
         start: usize,
         end: usize,
@@ -1088,6 +1154,9 @@ impl Display for RequirementsTxtParserError {
             Self::OnlyBinary { specifier, .. } => {
                 write!(f, "Invalid specifier for `--only-binary`: {specifier}")
             }
+            Self::ConfigSettings { flag, setting, .. } => {
+                write!(f, "Invalid value for `{flag}`: {setting}")
+            }
             Self::UnnamedConstraint { .. } => {
                 write!(f, "Unnamed requirements are not allowed as constraints")
             }
@@ -1142,6 +1211,7 @@ impl std::error::Error for RequirementsTxtParserError {
             Self::MissingRequirementPrefix(_) => None,
             Self::NoBinary { source, .. } => Some(source),
             Self::OnlyBinary { source, .. } => Some(source),
+            Self::ConfigSettings { .. } => None,
             Self::UnnamedConstraint { .. } => None,
             Self::UnsupportedRequirement { source, .. } => Some(source),
             Self::Pep508 { source, .. } => Some(source),
@@ -1224,6 +1294,13 @@ impl Display for RequirementsTxtFileError {
                     self.file.user_display(),
                 )
             }
+            RequirementsTxtParserError::ConfigSettings { flag, setting, .. } => {
+                write!(
+                    f,
+                    "Invalid value for `{flag}` in `{}`: {setting}",
+                    self.file.user_display(),
+                )
+            }
             RequirementsTxtParserError::UnnamedConstraint { .. } => {
                 write!(
                     f,
@@ -1355,6 +1432,7 @@ fn calculate_row_column(content: &str, position: usize) -> (usize, usize) {
 #[cfg(test)]
 mod test {
     use std::path::{Path, PathBuf};
+    use std::str::FromStr;
 
     use anyhow::Result;
     use assert_fs::prelude::*;
@@ -1367,6 +1445,7 @@ mod test {
 
     use uv_client::BaseClientBuilder;
     use uv_fs::Simplified;
+    use uv_normalize::PackageName;
 
     use crate::{RequirementsTxt, calculate_row_column};
 
@@ -1932,6 +2011,12 @@ mod test {
                 no_index: false,
                 no_binary: None,
                 only_binary: None,
+                config_settings: ConfigSettings(
+                    {},
+                ),
+                config_settings_package: PackageConfigSettings(
+                    {},
+                ),
             }
             "###);
         });
@@ -2002,6 +2087,12 @@ mod test {
                     ],
                 ),
                 only_binary: None,
+                config_settings: ConfigSettings(
+                    {},
+                ),
+                config_settings_package: PackageConfigSettings(
+                    {},
+                ),
             }
             "###);
         });
@@ -2009,6 +2100,77 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn config_settings() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            --config-settings setup-args=-Dblas=openblas
+            --config-settings-package numpy:editable-verbose=true
+            -r child.txt
+            numpy
+        "})?;
+
+        let child = temp_dir.child("child.txt");
+        child.write_str(indoc! {"
+            --config-settings \"setup-args=-Dlapack=openblas\"
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            requirements.config_settings.iter().collect::<Vec<_>>(),
+            vec![
+                ("setup-args", "-Dblas=openblas"),
+                ("setup-args", "-Dlapack=openblas"),
+            ]
+        );
+        assert_eq!(
+            requirements
+                .config_settings_package
+                .get(&PackageName::from_str("numpy")?)
+                .map(|settings| settings.iter().collect::<Vec<_>>()),
+            Some(vec![("editable-verbose", "true")])
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_config_settings() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            --config-settings setup-args
+        "})?;
+
+        let error = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap_err();
+        let errors = anyhow::Error::new(error).chain().join("\n");
+
+        let requirement_txt = regex::escape(&requirements_txt.path().user_display().to_string());
+        let filters = vec![(requirement_txt.as_str(), "<REQUIREMENTS_TXT>")];
+        insta::with_settings!({
+            filters => filters
+        }, {
+            insta::assert_snapshot!(errors, @"Invalid value for `--config-settings` in `<REQUIREMENTS_TXT>`: setup-args");
+        });
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[cfg(not(windows))]
     async fn nested_editable() -> Result<()> {
@@ -2105,6 +2267,12 @@ mod test {
                 no_index: true,
                 no_binary: None,
                 only_binary: None,
+                config_settings: ConfigSettings(
+                    {},
+                ),
+                config_settings_package: PackageConfigSettings(
+                    {},
+                ),
             }
             "#);
         });
@@ -2362,6 +2530,12 @@ mod test {
                 no_index: false,
                 no_binary: All,
                 only_binary: None,
+                config_settings: ConfigSettings(
+                    {},
+                ),
+                config_settings_package: PackageConfigSettings(
+                    {},
+                ),
             }
             "#);
         });
@@ -2720,6 +2894,12 @@ mod test {
                 no_index: false,
                 no_binary: None,
                 only_binary: None,
+                config_settings: ConfigSettings(
+                    {},
+                ),
+                config_settings_package: PackageConfigSettings(
+                    {},
+                ),
             }
             "#);
         });
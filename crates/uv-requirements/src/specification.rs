@@ -36,7 +36,9 @@ use tracing::instrument;
 
 use uv_cache_key::CanonicalUrl;
 use uv_client::BaseClientBuilder;
-use uv_configuration::{DependencyGroups, NoBinary, NoBuild};
+use uv_configuration::{
+    ConfigSettings, DependencyGroups, NoBinary, NoBuild, PackageConfigSettings,
+};
 use uv_distribution_types::Requirement;
 use uv_distribution_types::{
     IndexUrl, NameRequirementSpecification, UnresolvedRequirement,
@@ -80,6 +82,10 @@ pub struct RequirementsSpecification {
     pub no_binary: NoBinary,
     /// The `--no-build` flags to enforce when selecting distributions.
     pub no_build: NoBuild,
+    /// The `--config-settings` to pass to build backends.
+    pub config_settings: ConfigSettings,
+    /// The `--config-settings-package` to pass to build backends, on a per-package basis.
+    pub config_settings_package: PackageConfigSettings,
 }
 
 impl RequirementsSpecification {
@@ -156,6 +162,8 @@ impl RequirementsSpecification {
                         .collect(),
                     no_binary: requirements_txt.no_binary,
                     no_build: requirements_txt.only_binary,
+                    config_settings: requirements_txt.config_settings,
+                    config_settings_package: requirements_txt.config_settings_package,
                     ..Self::default()
                 }
             }
@@ -349,12 +357,16 @@ impl RequirementsSpecification {
         // Read all requirements, and keep track of all requirements _and_ constraints.
         // A `requirements.txt` can contain a `-c constraints.txt` directive within it, so reading
         // a requirements file can also add constraints.
+        let only_groups = groups.is_some_and(|groups| groups.only);
         for source in requirement_sources {
-            spec.requirements.extend(source.requirements);
+            // If only the dependency groups were requested, omit the requirements themselves.
+            if !only_groups {
+                spec.requirements.extend(source.requirements);
+                spec.extras.extend(source.extras);
+                spec.source_trees.extend(source.source_trees);
+            }
             spec.constraints.extend(source.constraints);
             spec.overrides.extend(source.overrides);
-            spec.extras.extend(source.extras);
-            spec.source_trees.extend(source.source_trees);
 
             // Allow at most one `pylock.toml`.
             if let Some(pylock) = source.pylock {
@@ -388,6 +400,10 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.config_settings = spec.config_settings.merge(source.config_settings);
+            spec.config_settings_package = spec
+                .config_settings_package
+                .merge(source.config_settings_package);
         }
 
         // Read all constraints, treating both requirements _and_ constraints as constraints.
@@ -426,6 +442,10 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.config_settings = spec.config_settings.merge(source.config_settings);
+            spec.config_settings_package = spec
+                .config_settings_package
+                .merge(source.config_settings_package);
         }
 
         // Read all overrides, treating both requirements _and_ overrides as overrides.
@@ -450,6 +470,10 @@ impl RequirementsSpecification {
             spec.find_links.extend(source.find_links);
             spec.no_binary.extend(source.no_binary);
             spec.no_build.extend(source.no_build);
+            spec.config_settings = spec.config_settings.merge(source.config_settings);
+            spec.config_settings_package = spec
+                .config_settings_package
+                .merge(source.config_settings_package);
         }
 
         Ok(spec)
@@ -534,4 +558,6 @@ pub struct GroupsSpecification {
     pub root: PathBuf,
     /// The enabled groups.
     pub groups: Vec<PipGroupName>,
+    /// Whether to exclude requirements from all other sources (i.e., `--only-group`).
+    pub only: bool,
 }
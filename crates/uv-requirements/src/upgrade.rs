@@ -1,11 +1,17 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use anyhow::Result;
+use rustc_hash::FxHashMap;
 
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::Upgrade;
+use uv_configuration::{Upgrade, UpgradePolicy};
+use uv_distribution_types::{Requirement, RequirementSource};
 use uv_fs::CWD;
 use uv_git::ResolvedRepositoryReference;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_pep508::MarkerTree;
 use uv_requirements_txt::RequirementsTxt;
 use uv_resolver::{Lock, LockError, Preference, PreferenceError, PylockToml, PylockTomlErrorKind};
 
@@ -58,7 +64,7 @@ pub async fn read_requirements_txt(
         // Respect all pinned versions from the existing lockfile.
         Upgrade::None => preferences,
         // Ignore all pinned versions from the existing lockfile.
-        Upgrade::All => vec![],
+        Upgrade::All(_) => vec![],
         // Ignore pinned versions for the specified packages.
         Upgrade::Packages(packages) => preferences
             .into_iter()
@@ -101,6 +107,72 @@ pub fn read_lock_requirements(
     Ok(LockedRequirements { preferences, git })
 }
 
+/// Derive the constraints implied by the [`UpgradePolicy`] of each package in an existing lockfile.
+///
+/// Policies apply when upgrading (i.e., `--upgrade` or `--upgrade-package`); packages that are named
+/// explicitly via `--upgrade-package` are unconstrained, even alongside `--upgrade`.
+pub fn read_lock_upgrade_constraints(
+    lock: &Lock,
+    install_path: &Path,
+    upgrade: &Upgrade,
+    policies: &BTreeMap<PackageName, UpgradePolicy>,
+) -> Result<Vec<Requirement>, LockError> {
+    if upgrade.is_none() || policies.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Collect the locked version of each registry package with a policy.
+    let mut versions = FxHashMap::<&PackageName, Option<&Version>>::default();
+    for package in lock.packages() {
+        if !policies.contains_key(package.name()) {
+            continue;
+        }
+        let Some(version) = package.version() else {
+            continue;
+        };
+        if package.index(install_path)?.is_none() {
+            continue;
+        }
+        versions
+            .entry(package.name())
+            .and_modify(|existing| {
+                // If the package is locked at multiple versions (e.g., across forks), there's no
+                // single version to anchor the policy, so leave it unconstrained.
+                if existing.is_some_and(|existing| existing != version) {
+                    *existing = None;
+                }
+            })
+            .or_insert(Some(version));
+    }
+
+    let mut constraints = Vec::new();
+    for (name, policy) in policies {
+        if upgrade.is_explicit(name) {
+            continue;
+        }
+        let Some(Some(version)) = versions.get(name) else {
+            continue;
+        };
+        let Some(specifier) = policy.specifiers(version) else {
+            continue;
+        };
+        constraints.push(Requirement {
+            name: name.clone(),
+            extras: Box::default(),
+            groups: Box::default(),
+            marker: MarkerTree::TRUE,
+            source: RequirementSource::Registry {
+                specifier,
+                index: None,
+                conflict: None,
+            },
+            origin: None,
+        });
+    }
+
+    Ok(constraints)
+}
+
 /// Load the preferred requirements from an existing `pylock.toml` file, applying the upgrade strategy.
 pub async fn read_pylock_toml_requirements(
     output_file: &Path,
@@ -15,7 +15,7 @@ use uv_types::InstalledPackagesProvider;
 
 use crate::preferences::{Entry, PreferenceSource, Preferences};
 use crate::prerelease::{AllowPrerelease, PrereleaseStrategy};
-use crate::resolution_mode::ResolutionStrategy;
+use crate::resolution_mode::{ResolutionOverrideStrategy, ResolutionStrategy};
 use crate::version_map::{VersionMap, VersionMapDistHandle};
 use crate::{Exclusions, Manifest, Options, ResolverEnvironment};
 
@@ -23,8 +23,10 @@ use crate::{Exclusions, Manifest, Options, ResolverEnvironment};
 #[allow(clippy::struct_field_names)]
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
+    resolution_overrides: ResolutionOverrideStrategy,
     prerelease_strategy: PrereleaseStrategy,
     index_strategy: IndexStrategy,
+    prefer_local_versions: bool,
 }
 
 impl CandidateSelector {
@@ -41,13 +43,21 @@ impl CandidateSelector {
                 env,
                 options.dependency_mode,
             ),
+            resolution_overrides: ResolutionOverrideStrategy::from_overrides(
+                &options.resolution_overrides,
+                manifest,
+                env,
+                options.dependency_mode,
+            ),
             prerelease_strategy: PrereleaseStrategy::from_mode(
                 options.prerelease_mode,
+                &options.allow_prereleases,
                 manifest,
                 env,
                 options.dependency_mode,
             ),
             index_strategy: options.index_strategy,
+            prefer_local_versions: options.prefer_local_versions,
         }
     }
 
@@ -404,76 +414,124 @@ impl CandidateSelector {
             AllowPrerelease::IfNecessary => !version_maps.iter().any(VersionMap::stable),
         };
 
-        if self.index_strategy == IndexStrategy::UnsafeBestMatch {
-            if highest {
-                Self::select_candidate(
-                    version_maps
-                        .iter()
-                        .enumerate()
-                        .map(|(map_index, version_map)| {
-                            version_map
-                                .iter(range)
-                                .rev()
-                                .map(move |item| (map_index, item))
-                        })
-                        .kmerge_by(
-                            |(index1, (version1, _)), (index2, (version2, _))| match version1
-                                .cmp(version2)
-                            {
-                                std::cmp::Ordering::Equal => index1 < index2,
-                                std::cmp::Ordering::Less => false,
-                                std::cmp::Ordering::Greater => true,
-                            },
-                        )
-                        .map(|(_, item)| item),
-                    package_name,
-                    range,
-                    allow_prerelease,
-                )
-            } else {
-                Self::select_candidate(
-                    version_maps
-                        .iter()
-                        .enumerate()
-                        .map(|(map_index, version_map)| {
-                            version_map.iter(range).map(move |item| (map_index, item))
-                        })
-                        .kmerge_by(
-                            |(index1, (version1, _)), (index2, (version2, _))| match version1
-                                .cmp(version2)
-                            {
-                                std::cmp::Ordering::Equal => index1 < index2,
-                                std::cmp::Ordering::Less => true,
-                                std::cmp::Ordering::Greater => false,
-                            },
-                        )
-                        .map(|(_, item)| item),
-                    package_name,
-                    range,
-                    allow_prerelease,
-                )
-            }
-        } else {
-            if highest {
-                version_maps.iter().find_map(|version_map| {
+        let candidate =
+            if self.index_strategy == IndexStrategy::UnsafeBestMatch {
+                if highest {
                     Self::select_candidate(
-                        version_map.iter(range).rev(),
+                        version_maps
+                            .iter()
+                            .enumerate()
+                            .map(|(map_index, version_map)| {
+                                version_map
+                                    .iter(range)
+                                    .rev()
+                                    .map(move |item| (map_index, item))
+                            })
+                            .kmerge_by(|(index1, (version1, _)), (index2, (version2, _))| {
+                                match version1.cmp(version2) {
+                                    std::cmp::Ordering::Equal => index1 < index2,
+                                    std::cmp::Ordering::Less => false,
+                                    std::cmp::Ordering::Greater => true,
+                                }
+                            })
+                            .map(|(_, item)| item),
                         package_name,
                         range,
                         allow_prerelease,
                     )
-                })
-            } else {
-                version_maps.iter().find_map(|version_map| {
+                } else {
                     Self::select_candidate(
-                        version_map.iter(range),
+                        version_maps
+                            .iter()
+                            .enumerate()
+                            .map(|(map_index, version_map)| {
+                                version_map.iter(range).map(move |item| (map_index, item))
+                            })
+                            .kmerge_by(|(index1, (version1, _)), (index2, (version2, _))| {
+                                match version1.cmp(version2) {
+                                    std::cmp::Ordering::Equal => index1 < index2,
+                                    std::cmp::Ordering::Less => true,
+                                    std::cmp::Ordering::Greater => false,
+                                }
+                            })
+                            .map(|(_, item)| item),
                         package_name,
                         range,
                         allow_prerelease,
                     )
-                })
+                }
+            } else {
+                if highest {
+                    version_maps.iter().find_map(|version_map| {
+                        Self::select_candidate(
+                            version_map.iter(range).rev(),
+                            package_name,
+                            range,
+                            allow_prerelease,
+                        )
+                    })
+                } else {
+                    version_maps.iter().find_map(|version_map| {
+                        Self::select_candidate(
+                            version_map.iter(range),
+                            package_name,
+                            range,
+                            allow_prerelease,
+                        )
+                    })
+                }
+            };
+
+        // If requested, prefer a local variant of the selected version (e.g., `1.2.3+corp1` over
+        // `1.2.3`), regardless of the resolution strategy.
+        if self.prefer_local_versions {
+            if let Some(candidate) = candidate.as_ref() {
+                if let Some(local) = Self::select_local_variant(
+                    candidate,
+                    package_name,
+                    range,
+                    version_maps,
+                    allow_prerelease,
+                ) {
+                    debug!(
+                        "Preferring local version {} over {} for {package_name}",
+                        local.version(),
+                        candidate.version()
+                    );
+                    return Some(local);
+                }
             }
         }
+
+        candidate
+    }
+
+    /// Select the highest compatible [`Candidate`] that shares the public version of the given
+    /// candidate, but carries a local version segment.
+    fn select_local_variant<'a>(
+        candidate: &Candidate<'a>,
+        package_name: &'a PackageName,
+        range: &Range<Version>,
+        version_maps: &'a [VersionMap],
+        allow_prerelease: bool,
+    ) -> Option<Candidate<'a>> {
+        if candidate.version().is_local() {
+            return None;
+        }
+        version_maps
+            .iter()
+            .find_map(|version_map| {
+                Self::select_candidate(
+                    version_map.iter(range).rev().filter(|(version, _)| {
+                        version.is_local()
+                            && (*version).clone().without_local() == *candidate.version()
+                    }),
+                    package_name,
+                    range,
+                    allow_prerelease,
+                )
+            })
+            .filter(|local| matches!(local.dist(), CandidateDist::Compatible(_)))
     }
 
     /// By default, we select the latest version, but we also allow using the lowest version instead
@@ -483,7 +541,11 @@ impl CandidateSelector {
         package_name: &PackageName,
         env: &ResolverEnvironment,
     ) -> bool {
-        match &self.resolution_strategy {
+        let strategy = self
+            .resolution_overrides
+            .get(package_name, env)
+            .unwrap_or(&self.resolution_strategy);
+        match strategy {
             ResolutionStrategy::Highest => true,
             ResolutionStrategy::Lowest => false,
             ResolutionStrategy::LowestDirect(direct_dependencies) => {
@@ -27,7 +27,7 @@ use crate::dependency_provider::UvDependencyProvider;
 use crate::fork_indexes::ForkIndexes;
 use crate::fork_urls::ForkUrls;
 use crate::prerelease::AllowPrerelease;
-use crate::pubgrub::{PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
+use crate::pubgrub::{PubGrubHint, PubGrubPackage, PubGrubPackageInner, PubGrubReportFormatter};
 use crate::python_requirement::PythonRequirement;
 use crate::resolution::ConflictingDistributionError;
 use crate::resolver::{
@@ -181,6 +181,7 @@ pub struct NoSolutionError {
     index_capabilities: IndexCapabilities,
     unavailable_packages: FxHashMap<PackageName, UnavailablePackage>,
     incomplete_packages: FxHashMap<PackageName, BTreeMap<Version, MetadataUnavailable>>,
+    source_only_packages: BTreeMap<PackageName, BTreeSet<Version>>,
     fork_urls: ForkUrls,
     fork_indexes: ForkIndexes,
     env: ResolverEnvironment,
@@ -203,6 +204,7 @@ impl NoSolutionError {
         index_capabilities: IndexCapabilities,
         unavailable_packages: FxHashMap<PackageName, UnavailablePackage>,
         incomplete_packages: FxHashMap<PackageName, BTreeMap<Version, MetadataUnavailable>>,
+        source_only_packages: BTreeMap<PackageName, BTreeSet<Version>>,
         fork_urls: ForkUrls,
         fork_indexes: ForkIndexes,
         env: ResolverEnvironment,
@@ -222,6 +224,7 @@ impl NoSolutionError {
             index_capabilities,
             unavailable_packages,
             incomplete_packages,
+            source_only_packages,
             fork_urls,
             fork_indexes,
             env,
@@ -383,6 +386,38 @@ impl NoSolutionError {
         &self.error
     }
 
+    /// Hint at the packages that only provide source distributions, if building from source is
+    /// disabled.
+    fn hint_source_only(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.source_only_packages.is_empty() {
+            return Ok(());
+        }
+
+        let packages = self
+            .source_only_packages
+            .iter()
+            .map(|(package, versions)| {
+                format!(
+                    "`{}` ({})",
+                    package.cyan(),
+                    versions
+                        .iter()
+                        .map(|version| format!("v{version}"))
+                        .join(", ")
+                )
+            })
+            .join("; ");
+        write!(
+            f,
+            "\n\n{}{} Building from source is disabled for all packages (i.e., with `--no-build`), \
+            but the following packages only provide source distributions: {packages}. \
+            Use `--allow-binary-fallback <package>` to allow specific packages to be built from source.",
+            "hint".bold().cyan(),
+            ":".bold(),
+        )?;
+        Ok(())
+    }
+
     /// Hint at limiting the resolver environment if universal resolution failed for a target
     /// that is not the current platform or not the current Python version.
     fn hint_disjoint_targets(&self, f: &mut Formatter) -> std::fmt::Result {
@@ -445,6 +480,7 @@ impl std::fmt::Debug for NoSolutionError {
             index_capabilities,
             unavailable_packages,
             incomplete_packages,
+            source_only_packages,
             fork_urls,
             fork_indexes,
             env,
@@ -463,6 +499,7 @@ impl std::fmt::Debug for NoSolutionError {
             .field("index_capabilities", index_capabilities)
             .field("unavailable_packages", unavailable_packages)
             .field("incomplete_packages", incomplete_packages)
+            .field("source_only_packages", source_only_packages)
             .field("fork_urls", fork_urls)
             .field("fork_indexes", fork_indexes)
             .field("env", env)
@@ -548,9 +585,16 @@ impl std::fmt::Display for NoSolutionError {
             &mut additional_hints,
         );
         for hint in additional_hints {
+            // Packages without any wheels are summarized in a single hint, below.
+            if let PubGrubHint::NoBuild { package, .. } = &hint {
+                if self.source_only_packages.contains_key(package) {
+                    continue;
+                }
+            }
             write!(f, "\n\n{hint}")?;
         }
 
+        self.hint_source_only(f)?;
         self.hint_disjoint_targets(f)?;
 
         Ok(())
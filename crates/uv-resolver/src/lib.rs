@@ -7,9 +7,10 @@ pub use exclusions::Exclusions;
 pub use flat_index::{FlatDistributions, FlatIndex};
 pub use fork_strategy::ForkStrategy;
 pub use lock::{
-    Installable, Lock, LockError, LockVersion, Package, PackageMap, PylockToml,
+    CycloneDxExport, Dependency as LockDependency, DuplicatePackage, Installable, Lock, LockError,
+    LockRepairError, LockVersion, Package, PackageMap, PackageSource, PylockToml,
     PylockTomlErrorKind, RequirementsTxtExport, ResolverManifest, SatisfiesResult, TreeDisplay,
-    VERSION,
+    VERSION, WhyDisplay, WhyEdge, WhyNode, WhyVia, find_duplicate_packages, repair_lock,
 };
 pub use manifest::Manifest;
 pub use options::{Flexibility, Options, OptionsBuilder};
@@ -19,11 +20,13 @@ pub use python_requirement::PythonRequirement;
 pub use resolution::{
     AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
 };
-pub use resolution_mode::ResolutionMode;
+pub use resolution_mode::{
+    ResolutionMode, ResolutionOverrides, ResolutionTarget, ResolutionTargetError,
+};
 pub use resolver::{
     BuildId, DefaultResolverProvider, DerivationChainBuilder, InMemoryIndex, MetadataResponse,
-    PackageVersionsResult, Reporter as ResolverReporter, Resolver, ResolverEnvironment,
-    ResolverProvider, VersionsResponse, WheelMetadataResult,
+    PackageVersionsResult, Reporter as ResolverReporter, ResolutionLog, Resolver,
+    ResolverEnvironment, ResolverProvider, VersionsResponse, WheelMetadataResult,
 };
 pub use universal_marker::{ConflictMarker, UniversalMarker};
 pub use version_map::VersionMap;
@@ -0,0 +1,402 @@
+use std::collections::BTreeMap;
+
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use url::form_urlencoded;
+
+use uv_configuration::{
+    DependencyGroupsWithDefaults, ExtrasSpecificationWithDefaults, InstallOptions,
+};
+use uv_distribution_types::UrlString;
+use uv_fs::PortablePath;
+use uv_normalize::PackageName;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::lock::export::{ExportableRequirement, ExportableRequirements};
+use crate::lock::{
+    LockErrorKind, Package, PackageId, RegistrySource, Source, SourceDist, WheelWireSource,
+};
+use crate::{Installable, LockError};
+
+/// The default registry, which is omitted from the `repository_url` qualifier of the Package URL.
+const PYPI_SIMPLE_URL: &str = "https://pypi.org/simple";
+
+/// An export of a [`Lock`] that renders as a [CycloneDX](https://cyclonedx.org/) (v1.5) SBOM, in
+/// JSON format.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycloneDxExport {
+    bom_format: &'static str,
+    spec_version: &'static str,
+    version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<Metadata>,
+    components: Vec<Component>,
+    dependencies: Vec<Dependency>,
+}
+
+#[derive(Debug, Serialize)]
+struct Metadata {
+    component: Component,
+}
+
+#[derive(Debug, Serialize)]
+struct Component {
+    #[serde(rename = "type")]
+    kind: ComponentType,
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    name: PackageName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purl: Option<String>,
+    #[serde(rename = "externalReferences", skip_serializing_if = "Vec::is_empty")]
+    external_references: Vec<ExternalReference>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    properties: Vec<Property>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum ComponentType {
+    Application,
+    Library,
+}
+
+#[derive(Debug, Serialize)]
+struct ExternalReference {
+    #[serde(rename = "type")]
+    kind: ExternalReferenceType,
+    url: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hashes: Vec<Hash>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum ExternalReferenceType {
+    Distribution,
+    Vcs,
+}
+
+#[derive(Debug, Serialize)]
+struct Hash {
+    alg: &'static str,
+    content: String,
+}
+
+impl From<&HashDigest> for Hash {
+    fn from(digest: &HashDigest) -> Self {
+        let alg = match digest.algorithm {
+            HashAlgorithm::Md5 => "MD5",
+            HashAlgorithm::Sha256 => "SHA-256",
+            HashAlgorithm::Sha384 => "SHA-384",
+            HashAlgorithm::Sha512 => "SHA-512",
+            HashAlgorithm::Blake2b => "BLAKE2b-256",
+        };
+        Self {
+            alg,
+            content: digest.digest.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Property {
+    name: &'static str,
+    value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Dependency {
+    #[serde(rename = "ref")]
+    bom_ref: String,
+    depends_on: Vec<String>,
+}
+
+impl CycloneDxExport {
+    pub fn from_lock<'lock>(
+        target: &impl Installable<'lock>,
+        prune: &[PackageName],
+        extras: &ExtrasSpecificationWithDefaults,
+        dev: &DependencyGroupsWithDefaults,
+        install_options: &'lock InstallOptions,
+    ) -> Result<Self, LockError> {
+        // Extract the packages from the lock file, along with the relationships between them.
+        let ExportableRequirements(mut nodes) =
+            ExportableRequirements::from_lock(target, prune, extras, dev, true, install_options);
+
+        // Sort the nodes.
+        nodes.sort_unstable_by_key(|node| &node.package.id);
+
+        // Assign a unique reference to each package.
+        let mut refs = FxHashMap::<&PackageId, String>::default();
+        let mut seen = FxHashMap::<String, usize>::default();
+        for node in &nodes {
+            let mut bom_ref = bom_ref(node.package)?;
+            let count = seen.entry(bom_ref.clone()).or_default();
+            *count += 1;
+            if *count > 1 {
+                bom_ref = format!("{bom_ref}-{count}");
+            }
+            refs.insert(&node.package.id, bom_ref);
+        }
+
+        // Invert the dependents of each package to determine its dependencies.
+        let mut depends_on = BTreeMap::<&str, Vec<&str>>::new();
+        for node in &nodes {
+            let bom_ref = refs[&node.package.id].as_str();
+            depends_on.entry(bom_ref).or_default();
+            for dependent in &node.dependents {
+                if let Some(dependent) = refs.get(&dependent.id) {
+                    depends_on
+                        .entry(dependent.as_str())
+                        .or_default()
+                        .push(bom_ref);
+                }
+            }
+        }
+
+        // If the export targets a single project, use it as the subject of the SBOM, and attach
+        // any packages that the workspace requires directly (e.g., via dependency groups).
+        let root = target.project_name().and_then(|project| {
+            nodes
+                .iter()
+                .find(|node| node.package.id.name == *project)
+                .map(|node| &node.package.id)
+        });
+        if let Some(root) = root {
+            let root_ref = refs[root].as_str();
+            let direct = nodes
+                .iter()
+                .filter(|node| node.direct && node.package.id != *root)
+                .map(|node| refs[&node.package.id].as_str())
+                .collect::<Vec<_>>();
+            depends_on.entry(root_ref).or_default().extend(direct);
+        }
+
+        let dependencies = depends_on
+            .into_iter()
+            .map(|(bom_ref, mut depends_on)| {
+                depends_on.sort_unstable();
+                depends_on.dedup();
+                Dependency {
+                    bom_ref: bom_ref.to_string(),
+                    depends_on: depends_on.into_iter().map(ToString::to_string).collect(),
+                }
+            })
+            .collect();
+
+        let mut metadata = None;
+        let mut components = Vec::with_capacity(nodes.len());
+        for node in &nodes {
+            let bom_ref = refs[&node.package.id].clone();
+            if root.is_some_and(|root| node.package.id == *root) {
+                metadata = Some(Metadata {
+                    component: Component::from_node(node, bom_ref, ComponentType::Application)?,
+                });
+            } else {
+                components.push(Component::from_node(node, bom_ref, ComponentType::Library)?);
+            }
+        }
+
+        Ok(Self {
+            bom_format: "CycloneDX",
+            spec_version: "1.5",
+            version: 1,
+            metadata,
+            components,
+            dependencies,
+        })
+    }
+
+    /// Returns the JSON representation of this SBOM.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl Component {
+    fn from_node(
+        node: &ExportableRequirement<'_>,
+        bom_ref: String,
+        kind: ComponentType,
+    ) -> Result<Self, LockError> {
+        let package = node.package;
+
+        let mut properties = Vec::new();
+        if let Some(marker) = node.marker.contents() {
+            properties.push(Property {
+                name: "uv:marker",
+                value: marker.to_string(),
+            });
+        }
+        for group in &node.groups {
+            properties.push(Property {
+                name: "uv:dependency-group",
+                value: group.to_string(),
+            });
+        }
+        for (parent, extra) in &node.extras {
+            properties.push(Property {
+                name: "uv:extra",
+                value: format!("{parent}[{extra}]"),
+            });
+        }
+        match &package.id.source {
+            Source::Path(path) | Source::Directory(path) | Source::Virtual(path) => {
+                properties.push(Property {
+                    name: "uv:path",
+                    value: PortablePath::from(path).to_string(),
+                });
+            }
+            Source::Editable(path) => {
+                properties.push(Property {
+                    name: "uv:path",
+                    value: PortablePath::from(path).to_string(),
+                });
+                properties.push(Property {
+                    name: "uv:editable",
+                    value: "true".to_string(),
+                });
+            }
+            Source::Registry(_) | Source::Git(..) | Source::Direct(..) => {}
+        }
+
+        Ok(Self {
+            kind,
+            bom_ref,
+            name: package.id.name.clone(),
+            version: package.id.version.as_ref().map(ToString::to_string),
+            purl: purl(package)?,
+            external_references: external_references(package)?,
+            properties,
+        })
+    }
+}
+
+/// Returns the reference used to identify the package within the SBOM.
+///
+/// Packages with a Package URL are identified by it; local packages are identified by name and
+/// version.
+fn bom_ref(package: &Package) -> Result<String, LockError> {
+    if let Some(purl) = purl(package)? {
+        return Ok(purl);
+    }
+    Ok(match &package.id.version {
+        Some(version) => format!("{}@{version}", package.id.name),
+        None => package.id.name.to_string(),
+    })
+}
+
+/// Returns the [Package URL](https://github.com/package-url/purl-spec) for the package, if it can
+/// be retrieved from a registry or remote URL.
+///
+/// Local packages (e.g., path and editable dependencies) don't have a Package URL.
+fn purl(package: &Package) -> Result<Option<String>, LockError> {
+    let Some(version) = package.id.version.as_ref() else {
+        return Ok(None);
+    };
+    let qualifier = match &package.id.source {
+        Source::Registry(RegistrySource::Url(url)) => {
+            if url.as_ref().trim_end_matches('/') == PYPI_SIMPLE_URL {
+                None
+            } else {
+                Some(("repository_url", url.as_ref().to_string()))
+            }
+        }
+        Source::Git(url, git) => {
+            let mut url = url.to_url().map_err(LockErrorKind::InvalidUrl)?;
+            url.set_fragment(None);
+            url.set_query(None);
+            Some(("vcs_url", format!("git+{url}@{}", git.precise)))
+        }
+        Source::Direct(url, _) => Some(("download_url", url.without_fragment().to_string())),
+        Source::Registry(RegistrySource::Path(_))
+        | Source::Path(_)
+        | Source::Directory(_)
+        | Source::Editable(_)
+        | Source::Virtual(_) => return Ok(None),
+    };
+
+    let mut purl = format!(
+        "pkg:pypi/{}@{}",
+        package.id.name,
+        encode(&version.to_string())
+    );
+    if let Some((key, value)) = qualifier {
+        purl.push('?');
+        purl.push_str(key);
+        purl.push('=');
+        purl.push_str(&encode(&value));
+    }
+    Ok(Some(purl))
+}
+
+/// Returns the locations (and hashes) of the distributions for the package.
+fn external_references(package: &Package) -> Result<Vec<ExternalReference>, LockError> {
+    let mut references = Vec::new();
+
+    match &package.id.source {
+        Source::Git(url, _) => {
+            let mut url = url.to_url().map_err(LockErrorKind::InvalidUrl)?;
+            url.set_fragment(None);
+            url.set_query(None);
+            references.push(ExternalReference {
+                kind: ExternalReferenceType::Vcs,
+                url: url.to_string(),
+                hashes: Vec::new(),
+            });
+        }
+        Source::Direct(url, _) => {
+            if let Some(sdist) = package.sdist.as_ref() {
+                push_distribution(&mut references, url, sdist.hash().map(|hash| &hash.0));
+            }
+        }
+        Source::Registry(_)
+        | Source::Path(_)
+        | Source::Directory(_)
+        | Source::Editable(_)
+        | Source::Virtual(_) => {}
+    }
+
+    if let Some(sdist @ SourceDist::Url { url, .. }) = package.sdist.as_ref() {
+        push_distribution(&mut references, url, sdist.hash().map(|hash| &hash.0));
+    }
+    for wheel in &package.wheels {
+        if let WheelWireSource::Url { url } = &wheel.url {
+            push_distribution(
+                &mut references,
+                url,
+                wheel.hash.as_ref().map(|hash| &hash.0),
+            );
+        }
+    }
+
+    Ok(references)
+}
+
+/// Add a distribution to the list of references, unless it's already present (e.g., for a wheel
+/// at a direct URL).
+fn push_distribution(
+    references: &mut Vec<ExternalReference>,
+    url: &UrlString,
+    hash: Option<&HashDigest>,
+) {
+    let url = url.without_fragment().to_string();
+    if references.iter().any(|reference| reference.url == url) {
+        return;
+    }
+    references.push(ExternalReference {
+        kind: ExternalReferenceType::Distribution,
+        url,
+        hashes: hash.map(Hash::from).into_iter().collect(),
+    });
+}
+
+/// Percent-encode a Package URL component.
+fn encode(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
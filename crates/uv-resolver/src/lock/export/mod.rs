@@ -16,12 +16,14 @@ use uv_pep508::MarkerTree;
 use uv_pypi_types::ConflictItem;
 
 use crate::graph_ops::{Reachable, marker_reachability};
+pub use crate::lock::export::cyclonedx_json::CycloneDxExport;
 pub(crate) use crate::lock::export::pylock_toml::PylockTomlPackage;
 pub use crate::lock::export::pylock_toml::{PylockToml, PylockTomlErrorKind};
 pub use crate::lock::export::requirements_txt::RequirementsTxtExport;
 use crate::universal_marker::resolve_conflicts;
 use crate::{Installable, Package};
 
+mod cyclonedx_json;
 mod pylock_toml;
 mod requirements_txt;
 
@@ -34,6 +36,13 @@ struct ExportableRequirement<'lock> {
     marker: MarkerTree,
     /// The list of packages that depend on this package.
     dependents: Vec<&'lock Package>,
+    /// Whether the package is required directly by the workspace (e.g., as a workspace member, or
+    /// via a dependency group).
+    direct: bool,
+    /// The dependency groups that require this package directly.
+    groups: Vec<&'lock GroupName>,
+    /// The extras (along with the package that defines them) that require this package directly.
+    extras: Vec<(&'lock PackageName, &'lock ExtraName)>,
 }
 
 /// A set of flattened, exportable requirements, generated from a lockfile.
@@ -326,6 +335,40 @@ impl<'lock> ExportableRequirements<'lock> {
                 } else {
                     Vec::new()
                 },
+                direct: annotate
+                    && graph
+                        .edges_directed(index, Direction::Incoming)
+                        .any(|edge| matches!(graph[edge.source()], Node::Root)),
+                groups: if annotate {
+                    let mut groups = graph
+                        .edges_directed(index, Direction::Incoming)
+                        .filter_map(|edge| match edge.weight() {
+                            Edge::Dev(group, _) => Some(*group),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    groups.sort_unstable();
+                    groups.dedup();
+                    groups
+                } else {
+                    Vec::new()
+                },
+                extras: if annotate {
+                    let mut extras = graph
+                        .edges_directed(index, Direction::Incoming)
+                        .filter_map(|edge| match (&graph[edge.source()], edge.weight()) {
+                            (Node::Package(parent), Edge::Optional(extra, _)) => {
+                                Some((parent.name(), *extra))
+                            }
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    extras.sort_unstable();
+                    extras.dedup();
+                    extras
+                } else {
+                    Vec::new()
+                },
             })
             .filter(|requirement| !requirement.marker.is_false())
             .collect::<Vec<_>>();
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::ffi::OsStr;
 use std::path::Path;
 use std::str::FromStr;
@@ -1252,7 +1253,7 @@ impl PylockTomlPackage {
     fn find_best_wheel(&self, tags: &Tags) -> Option<&PylockTomlWheel> {
         type WheelPriority = (TagPriority, Option<BuildTag>);
 
-        let mut best: Option<(WheelPriority, &PylockTomlWheel)> = None;
+        let mut best: Option<(WheelPriority, Cow<'_, WheelFilename>, &PylockTomlWheel)> = None;
         for wheel in self.wheels.iter().flatten() {
             let Ok(filename) = wheel.filename(&self.name) else {
                 continue;
@@ -1264,17 +1265,23 @@ impl PylockTomlPackage {
             let wheel_priority = (tag_priority, build_tag);
             match &best {
                 None => {
-                    best = Some((wheel_priority, wheel));
+                    best = Some((wheel_priority, filename, wheel));
                 }
-                Some((best_priority, _)) => {
-                    if wheel_priority > *best_priority {
-                        best = Some((wheel_priority, wheel));
+                Some((best_priority, best_filename, _)) => {
+                    // Break ties between equally compatible wheels deterministically.
+                    let is_preferred = match wheel_priority.cmp(best_priority) {
+                        Ordering::Greater => true,
+                        Ordering::Less => false,
+                        Ordering::Equal => filename.cmp_tie_break(best_filename).is_gt(),
+                    };
+                    if is_preferred {
+                        best = Some((wheel_priority, filename, wheel));
                     }
                 }
             }
         }
 
-        best.map(|(_, i)| i)
+        best.map(|(.., wheel)| wheel)
     }
 
     /// Generate a [`WheelTagHint`] based on wheel-tag incompatibilities.
@@ -12,6 +12,8 @@ use uv_distribution_filename::{DistExtension, SourceDistExtension};
 use uv_fs::Simplified;
 use uv_git_types::GitReference;
 use uv_normalize::PackageName;
+use uv_pep508::{MarkerEnvironment, MarkerTree};
+use uv_platform_tags::Tags;
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl};
 use uv_redacted::DisplaySafeUrl;
 
@@ -25,6 +27,7 @@ pub struct RequirementsTxtExport<'lock> {
     nodes: Vec<ExportableRequirement<'lock>>,
     hashes: bool,
     editable: EditableMode,
+    tags: Option<&'lock Tags>,
 }
 
 impl<'lock> RequirementsTxtExport<'lock> {
@@ -37,6 +40,7 @@ impl<'lock> RequirementsTxtExport<'lock> {
         editable: EditableMode,
         hashes: bool,
         install_options: &'lock InstallOptions,
+        environment: Option<(&MarkerEnvironment, &'lock Tags)>,
     ) -> Result<Self, LockError> {
         // Extract the packages from the lock file.
         let ExportableRequirements(mut nodes) = ExportableRequirements::from_lock(
@@ -48,6 +52,19 @@ impl<'lock> RequirementsTxtExport<'lock> {
             install_options,
         );
 
+        // If exporting for a specific environment, evaluate the markers, such that only the
+        // packages required in that environment are included (without any markers).
+        let tags = if let Some((markers, tags)) = environment {
+            nodes.retain(|node| node.marker.evaluate(markers, &[]));
+            for node in &mut nodes {
+                node.marker = MarkerTree::TRUE;
+                node.package.compatible_hashes(tags)?;
+            }
+            Some(tags)
+        } else {
+            None
+        };
+
         // Sort the nodes, such that unnamed URLs (editables) appear at the top.
         nodes.sort_unstable_by(|a, b| {
             RequirementComparator::from(a.package).cmp(&RequirementComparator::from(b.package))
@@ -57,6 +74,7 @@ impl<'lock> RequirementsTxtExport<'lock> {
             nodes,
             hashes,
             editable,
+            tags,
         })
     }
 }
@@ -68,6 +86,7 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
             package,
             marker,
             dependents,
+            ..
         } in &self.nodes
         {
             match &package.id.source {
@@ -154,7 +173,15 @@ impl std::fmt::Display for RequirementsTxtExport<'_> {
             }
 
             if self.hashes {
-                let mut hashes = package.hashes();
+                // When exporting for a specific environment, only include the hashes of the
+                // artifacts that are compatible with that environment.
+                let mut hashes = if let Some(tags) = self.tags {
+                    package
+                        .compatible_hashes(tags)
+                        .map_err(|_| std::fmt::Error)?
+                } else {
+                    package.hashes()
+                };
                 hashes.sort_unstable();
                 if !hashes.is_empty() {
                     for hash in hashes.iter() {
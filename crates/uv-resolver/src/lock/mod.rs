@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
@@ -38,7 +39,8 @@ use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::{MarkerEnvironment, MarkerTree, VerbatimUrl, VerbatimUrlError, split_scheme};
 use uv_platform_tags::{
-    AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagCompatibility, TagPriority, Tags,
+    AbiTag, IncompatibleTag, LanguageTag, PlatformTag, TagCompatibility, TagOverrides, TagPriority,
+    Tags,
 };
 use uv_pypi_types::{
     ConflictPackage, Conflicts, HashAlgorithm, HashDigest, HashDigests, Hashes, ParsedArchiveUrl,
@@ -47,26 +49,33 @@ use uv_pypi_types::{
 use uv_redacted::DisplaySafeUrl;
 use uv_small_str::SmallString;
 use uv_types::{BuildContext, HashStrategy};
+use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceMember;
 
 use crate::fork_strategy::ForkStrategy;
 pub(crate) use crate::lock::export::PylockTomlPackage;
-pub use crate::lock::export::RequirementsTxtExport;
+pub use crate::lock::export::{CycloneDxExport, RequirementsTxtExport};
 pub use crate::lock::export::{PylockToml, PylockTomlErrorKind};
 pub use crate::lock::installable::Installable;
 pub use crate::lock::map::PackageMap;
+pub use crate::lock::repair::{
+    DuplicatePackage, LockRepairError, find_duplicate_packages, repair_lock,
+};
 pub use crate::lock::tree::TreeDisplay;
+pub use crate::lock::why::{WhyDisplay, WhyEdge, WhyNode, WhyVia};
 use crate::resolution::{AnnotatedDist, ResolutionGraphNode};
 use crate::universal_marker::{ConflictMarker, UniversalMarker};
 use crate::{
     ExcludeNewer, ExcludeNewerTimestamp, InMemoryIndex, MetadataResponse, PrereleaseMode,
-    ResolutionMode, ResolverOutput,
+    ResolutionMode, ResolutionOverrides, ResolverOutput,
 };
 
 mod export;
 mod installable;
 mod map;
+mod repair;
 mod tree;
+mod why;
 
 /// The current version of the lockfile format.
 pub const VERSION: u32 = 1;
@@ -262,7 +271,12 @@ impl Lock {
                 vec![]
             };
 
-            let mut package = Package::from_annotated_dist(dist, fork_markers, root)?;
+            let mut package = Package::from_annotated_dist(
+                dist,
+                fork_markers,
+                root,
+                resolution.options.hash_algorithm,
+            )?;
             Self::remove_unreachable_wheels(resolution, &requires_python, node_index, &mut package);
 
             // Add all dependencies
@@ -355,10 +369,15 @@ impl Lock {
 
         let options = ResolverOptions {
             resolution_mode: resolution.options.resolution_mode,
+            resolution_overrides: resolution.options.resolution_overrides.clone(),
             prerelease_mode: resolution.options.prerelease_mode,
+            allow_prereleases: resolution.options.allow_prereleases.clone(),
             fork_strategy: resolution.options.fork_strategy,
             exclude_newer,
             exclude_newer_package,
+            no_binary_package: BTreeSet::default(),
+            no_build_package: BTreeSet::default(),
+            compatible_tags: TagOverrides::default(),
         };
         let lock = Self::new(
             VERSION,
@@ -722,6 +741,28 @@ impl Lock {
         self
     }
 
+    /// Record the per-package platform tag overrides that were used to generate this lock.
+    #[must_use]
+    pub fn with_compatible_tags(mut self, compatible_tags: TagOverrides) -> Self {
+        self.options.compatible_tags = compatible_tags;
+        self
+    }
+
+    /// Record the per-package build options that were configured when generating this lock.
+    ///
+    /// Only the options from the project and user configuration are recorded, such that one-off
+    /// command-line flags don't affect the lockfile.
+    #[must_use]
+    pub fn with_build_options(
+        mut self,
+        no_binary_package: BTreeSet<PackageName>,
+        no_build_package: BTreeSet<PackageName>,
+    ) -> Self {
+        self.options.no_binary_package = no_binary_package;
+        self.options.no_build_package = no_build_package;
+        self
+    }
+
     /// Record the conflicting groups that were used to generate this lock.
     #[must_use]
     pub fn with_conflicts(mut self, conflicts: Conflicts) -> Self {
@@ -806,11 +847,21 @@ impl Lock {
         self.options.resolution_mode
     }
 
+    /// Returns the per-package resolution mode overrides used to generate this lock.
+    pub fn resolution_overrides(&self) -> &ResolutionOverrides {
+        &self.options.resolution_overrides
+    }
+
     /// Returns the pre-release mode used to generate this lock.
     pub fn prerelease_mode(&self) -> PrereleaseMode {
         self.options.prerelease_mode
     }
 
+    /// Returns the packages for which pre-releases were allowed when generating this lock.
+    pub fn allow_prereleases(&self) -> &BTreeSet<PackageName> {
+        &self.options.allow_prereleases
+    }
+
     /// Returns the multi-version mode used to generate this lock.
     pub fn fork_strategy(&self) -> ForkStrategy {
         self.options.fork_strategy
@@ -821,6 +872,22 @@ impl Lock {
         self.options.exclude_newer()
     }
 
+    /// Returns the packages for which pre-built wheels were disallowed when generating this lock.
+    pub fn no_binary_package(&self) -> &BTreeSet<PackageName> {
+        &self.options.no_binary_package
+    }
+
+    /// Returns the packages for which building from source was disallowed when generating this
+    /// lock.
+    pub fn no_build_package(&self) -> &BTreeSet<PackageName> {
+        &self.options.no_build_package
+    }
+
+    /// Returns the per-package platform tag overrides that were used to generate this lock.
+    pub fn compatible_tags(&self) -> &TagOverrides {
+        &self.options.compatible_tags
+    }
+
     /// Returns the conflicting groups that were used to generate this lock.
     pub fn conflicts(&self) -> &Conflicts {
         &self.conflicts
@@ -1052,12 +1119,31 @@ impl Lock {
                     value(self.options.resolution_mode.to_string()),
                 );
             }
+            if !self.options.resolution_overrides.is_empty() {
+                let mut resolution_overrides = InlineTable::new();
+                for (target, mode) in self.options.resolution_overrides.iter() {
+                    resolution_overrides.insert(&target.to_string(), Value::from(mode.to_string()));
+                }
+                options_table.insert("resolution-overrides", value(resolution_overrides));
+            }
             if self.options.prerelease_mode != PrereleaseMode::default() {
                 options_table.insert(
                     "prerelease-mode",
                     value(self.options.prerelease_mode.to_string()),
                 );
             }
+            if !self.options.allow_prereleases.is_empty() {
+                options_table.insert(
+                    "allow-prereleases",
+                    value(
+                        self.options
+                            .allow_prereleases
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Array>(),
+                    ),
+                );
+            }
             if self.options.fork_strategy != ForkStrategy::default() {
                 options_table.insert(
                     "fork-strategy",
@@ -1081,6 +1167,41 @@ impl Lock {
                 }
             }
 
+            if !self.options.no_binary_package.is_empty() {
+                options_table.insert(
+                    "no-binary-package",
+                    value(
+                        self.options
+                            .no_binary_package
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Array>(),
+                    ),
+                );
+            }
+            if !self.options.no_build_package.is_empty() {
+                options_table.insert(
+                    "no-build-package",
+                    value(
+                        self.options
+                            .no_build_package
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Array>(),
+                    ),
+                );
+            }
+            if !self.options.compatible_tags.is_empty() {
+                let mut compatible_tags = InlineTable::new();
+                for (name, tags) in self.options.compatible_tags.iter() {
+                    compatible_tags.insert(
+                        name.as_ref(),
+                        Value::from(tags.iter().map(ToString::to_string).collect::<Array>()),
+                    );
+                }
+                options_table.insert("compatible-tags", value(compatible_tags));
+            }
+
             if !options_table.is_empty() {
                 doc.insert("options", Item::Table(options_table));
             }
@@ -1162,6 +1283,46 @@ impl Lock {
                 manifest_table.insert("overrides", value(overrides));
             }
 
+            if !self.manifest.user_constraints.is_empty() {
+                let user_constraints = self
+                    .manifest
+                    .user_constraints
+                    .iter()
+                    .map(|requirement| {
+                        serde::Serialize::serialize(
+                            &requirement,
+                            toml_edit::ser::ValueSerializer::new(),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let user_constraints = match user_constraints.as_slice() {
+                    [] => Array::new(),
+                    [requirement] => Array::from_iter([requirement]),
+                    user_constraints => each_element_on_its_line_array(user_constraints.iter()),
+                };
+                manifest_table.insert("user-constraints", value(user_constraints));
+            }
+
+            if !self.manifest.user_overrides.is_empty() {
+                let user_overrides = self
+                    .manifest
+                    .user_overrides
+                    .iter()
+                    .map(|requirement| {
+                        serde::Serialize::serialize(
+                            &requirement,
+                            toml_edit::ser::ValueSerializer::new(),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let user_overrides = match user_overrides.as_slice() {
+                    [] => Array::new(),
+                    [requirement] => Array::from_iter([requirement]),
+                    user_overrides => each_element_on_its_line_array(user_overrides.iter()),
+                };
+                manifest_table.insert("user-overrides", value(user_overrides));
+            }
+
             if !self.manifest.build_constraints.is_empty() {
                 let build_constraints = self
                     .manifest
@@ -1446,6 +1607,8 @@ impl Lock {
         requirements: &[Requirement],
         constraints: &[Requirement],
         overrides: &[Requirement],
+        user_constraints: &[Requirement],
+        user_overrides: &[Requirement],
         build_constraints: &[Requirement],
         dependency_groups: &BTreeMap<GroupName, Vec<Requirement>>,
         dependency_metadata: &DependencyMetadata,
@@ -1541,6 +1704,44 @@ impl Lock {
             }
         }
 
+        // Validate that the lockfile was generated with the same user-level constraints.
+        {
+            let expected: BTreeSet<_> = user_constraints
+                .iter()
+                .cloned()
+                .map(|requirement| normalize_requirement(requirement, root, &self.requires_python))
+                .collect::<Result<_, _>>()?;
+            let actual: BTreeSet<_> = self
+                .manifest
+                .user_constraints
+                .iter()
+                .cloned()
+                .map(|requirement| normalize_requirement(requirement, root, &self.requires_python))
+                .collect::<Result<_, _>>()?;
+            if expected != actual {
+                return Ok(SatisfiesResult::MismatchedUserConstraints(expected, actual));
+            }
+        }
+
+        // Validate that the lockfile was generated with the same user-level overrides.
+        {
+            let expected: BTreeSet<_> = user_overrides
+                .iter()
+                .cloned()
+                .map(|requirement| normalize_requirement(requirement, root, &self.requires_python))
+                .collect::<Result<_, _>>()?;
+            let actual: BTreeSet<_> = self
+                .manifest
+                .user_overrides
+                .iter()
+                .cloned()
+                .map(|requirement| normalize_requirement(requirement, root, &self.requires_python))
+                .collect::<Result<_, _>>()?;
+            if expected != actual {
+                return Ok(SatisfiesResult::MismatchedUserOverrides(expected, actual));
+            }
+        }
+
         // Validate that the lockfile was generated with the same build constraints.
         {
             let expected: BTreeSet<_> = build_constraints
@@ -2004,6 +2205,10 @@ pub enum SatisfiesResult<'lock> {
     MismatchedConstraints(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of overrides.
     MismatchedOverrides(BTreeSet<Requirement>, BTreeSet<Requirement>),
+    /// The lockfile uses a different set of user-level constraints.
+    MismatchedUserConstraints(BTreeSet<Requirement>, BTreeSet<Requirement>),
+    /// The lockfile uses a different set of user-level overrides.
+    MismatchedUserOverrides(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of build constraints.
     MismatchedBuildConstraints(BTreeSet<Requirement>, BTreeSet<Requirement>),
     /// The lockfile uses a different set of dependency groups.
@@ -2051,9 +2256,15 @@ struct ResolverOptions {
     /// The [`ResolutionMode`] used to generate this lock.
     #[serde(default)]
     resolution_mode: ResolutionMode,
+    /// The per-package [`ResolutionOverrides`] used to generate this lock.
+    #[serde(default)]
+    resolution_overrides: ResolutionOverrides,
     /// The [`PrereleaseMode`] used to generate this lock.
     #[serde(default)]
     prerelease_mode: PrereleaseMode,
+    /// The packages for which pre-releases were allowed.
+    #[serde(default)]
+    allow_prereleases: BTreeSet<PackageName>,
     /// The [`ForkStrategy`] used to generate this lock.
     #[serde(default)]
     fork_strategy: ForkStrategy,
@@ -2061,6 +2272,15 @@ struct ResolverOptions {
     exclude_newer: Option<ExcludeNewerTimestamp>,
     /// Package-specific [`ExcludeNewer`] timestamps.
     exclude_newer_package: Option<FxHashMap<PackageName, ExcludeNewerTimestamp>>,
+    /// The packages for which pre-built wheels were disallowed.
+    #[serde(default)]
+    no_binary_package: BTreeSet<PackageName>,
+    /// The packages for which building from source was disallowed.
+    #[serde(default)]
+    no_build_package: BTreeSet<PackageName>,
+    /// The additional platform tags that were considered compatible for specific packages.
+    #[serde(default)]
+    compatible_tags: TagOverrides,
 }
 
 impl ResolverOptions {
@@ -2103,6 +2323,12 @@ pub struct ResolverManifest {
     /// The overrides provided to the resolver.
     #[serde(default)]
     overrides: BTreeSet<Requirement>,
+    /// The constraints provided to the resolver via the user-level configuration.
+    #[serde(default)]
+    user_constraints: BTreeSet<Requirement>,
+    /// The overrides provided to the resolver via the user-level configuration.
+    #[serde(default)]
+    user_overrides: BTreeSet<Requirement>,
     /// The build constraints provided to the resolver.
     #[serde(default)]
     build_constraints: BTreeSet<Requirement>,
@@ -2119,6 +2345,8 @@ impl ResolverManifest {
         requirements: impl IntoIterator<Item = Requirement>,
         constraints: impl IntoIterator<Item = Requirement>,
         overrides: impl IntoIterator<Item = Requirement>,
+        user_constraints: impl IntoIterator<Item = Requirement>,
+        user_overrides: impl IntoIterator<Item = Requirement>,
         build_constraints: impl IntoIterator<Item = Requirement>,
         dependency_groups: impl IntoIterator<Item = (GroupName, Vec<Requirement>)>,
         dependency_metadata: impl IntoIterator<Item = StaticMetadata>,
@@ -2128,6 +2356,8 @@ impl ResolverManifest {
             requirements: requirements.into_iter().collect(),
             constraints: constraints.into_iter().collect(),
             overrides: overrides.into_iter().collect(),
+            user_constraints: user_constraints.into_iter().collect(),
+            user_overrides: user_overrides.into_iter().collect(),
             build_constraints: build_constraints.into_iter().collect(),
             dependency_groups: dependency_groups
                 .into_iter()
@@ -2156,6 +2386,16 @@ impl ResolverManifest {
                 .into_iter()
                 .map(|requirement| requirement.relative_to(root))
                 .collect::<Result<BTreeSet<_>, _>>()?,
+            user_constraints: self
+                .user_constraints
+                .into_iter()
+                .map(|requirement| requirement.relative_to(root))
+                .collect::<Result<BTreeSet<_>, _>>()?,
+            user_overrides: self
+                .user_overrides
+                .into_iter()
+                .map(|requirement| requirement.relative_to(root))
+                .collect::<Result<BTreeSet<_>, _>>()?,
             build_constraints: self
                 .build_constraints
                 .into_iter()
@@ -2305,10 +2545,11 @@ impl Package {
         annotated_dist: &AnnotatedDist,
         fork_markers: Vec<UniversalMarker>,
         root: &Path,
+        hash_algorithm: Option<HashAlgorithm>,
     ) -> Result<Self, LockError> {
         let id = PackageId::from_annotated_dist(annotated_dist, root)?;
-        let sdist = SourceDist::from_annotated_dist(&id, annotated_dist)?;
-        let wheels = Wheel::from_annotated_dist(annotated_dist)?;
+        let sdist = SourceDist::from_annotated_dist(&id, annotated_dist, hash_algorithm)?;
+        let wheels = Wheel::from_annotated_dist(annotated_dist, hash_algorithm)?;
         let requires_dist = if id.source.is_immutable() {
             BTreeSet::default()
         } else {
@@ -2478,6 +2719,15 @@ impl Package {
 
         if !no_binary {
             if let Some(best_wheel_index) = self.find_best_wheel(tag_policy) {
+                let filename = &self.wheels[best_wheel_index].filename;
+                if let TagPolicy::Required(tags) = tag_policy {
+                    if filename.is_override(tags) {
+                        warn_user_once!(
+                            "Installing `{filename}` for `{}` due to a `compatible-tags` override; the wheel is not compatible with the current platform",
+                            self.id.name
+                        );
+                    }
+                }
                 return match &self.id.source {
                     Source::Registry(source) => {
                         let wheels = self
@@ -3008,8 +3258,17 @@ impl Package {
                 None => {
                     best = Some((wheel_priority, i));
                 }
-                Some((best_priority, _)) => {
-                    if wheel_priority > best_priority {
+                Some((best_priority, best_index)) => {
+                    // Break ties between equally compatible wheels deterministically.
+                    let is_preferred = match wheel_priority.cmp(&best_priority) {
+                        Ordering::Greater => true,
+                        Ordering::Less => false,
+                        Ordering::Equal => wheel
+                            .filename
+                            .cmp_tie_break(&self.wheels[best_index].filename)
+                            .is_gt(),
+                    };
+                    if is_preferred {
                         best = Some((wheel_priority, i));
                     }
                 }
@@ -3038,6 +3297,35 @@ impl Package {
         self.fork_markers.as_slice()
     }
 
+    /// Returns the [`PackageSource`] from which the package was locked.
+    pub fn source(&self) -> PackageSource<'_> {
+        match &self.id.source {
+            Source::Registry(RegistrySource::Url(url)) => PackageSource::Registry(url.as_ref()),
+            Source::Registry(RegistrySource::Path(path)) => PackageSource::RegistryPath(path),
+            Source::Git(url, _) => PackageSource::Git(url.as_ref()),
+            Source::Direct(url, _) => PackageSource::Direct(url.as_ref()),
+            Source::Path(path) => PackageSource::Path(path),
+            Source::Directory(path) => PackageSource::Directory(path),
+            Source::Editable(path) => PackageSource::Editable(path),
+            Source::Virtual(path) => PackageSource::Virtual(path),
+        }
+    }
+
+    /// Returns the resolved dependencies of the package.
+    pub fn dependencies(&self) -> &[Dependency] {
+        &self.dependencies
+    }
+
+    /// Returns the resolved optional dependencies of the package, keyed by extra.
+    pub fn optional_dependencies(&self) -> &BTreeMap<ExtraName, Vec<Dependency>> {
+        &self.optional_dependencies
+    }
+
+    /// Returns the resolved dependencies of the package's dependency groups, keyed by group.
+    pub fn resolved_dependency_groups(&self) -> &BTreeMap<GroupName, Vec<Dependency>> {
+        &self.dependency_groups
+    }
+
     /// Returns the [`IndexUrl`] for the package, if it is a registry source.
     pub fn index(&self, root: &Path) -> Result<Option<IndexUrl>, LockError> {
         match &self.id.source {
@@ -3059,7 +3347,7 @@ impl Package {
     }
 
     /// Returns all the hashes associated with this [`Package`].
-    fn hashes(&self) -> HashDigests {
+    pub fn hashes(&self) -> HashDigests {
         let mut hashes = Vec::with_capacity(
             usize::from(self.sdist.as_ref().and_then(|sdist| sdist.hash()).is_some())
                 + self
@@ -3079,6 +3367,44 @@ impl Package {
         HashDigests::from(hashes)
     }
 
+    /// Returns the hashes of the artifacts that can be installed on a platform with the given
+    /// [`Tags`], i.e., the source distribution and any compatible wheels.
+    ///
+    /// Returns an error if the package has wheels, but none are compatible with the given tags and
+    /// there's no source distribution to fall back to.
+    fn compatible_hashes(&self, tags: &Tags) -> Result<HashDigests, LockError> {
+        let tag_policy = TagPolicy::Required(tags);
+        if self.sdist.is_none()
+            && !self.wheels.is_empty()
+            && self.find_best_wheel(tag_policy).is_none()
+        {
+            let kind = if self.id.source.is_wheel() {
+                LockErrorKind::IncompatibleWheelOnly {
+                    id: self.id.clone(),
+                }
+            } else {
+                LockErrorKind::NeitherSourceDistNorWheel {
+                    id: self.id.clone(),
+                }
+            };
+            return Err(LockError {
+                kind: Box::new(kind),
+                hint: self.tag_hint(tag_policy),
+            });
+        }
+
+        let mut hashes = Vec::new();
+        if let Some(hash) = self.sdist.as_ref().and_then(|sdist| sdist.hash()) {
+            hashes.push(hash.0.clone());
+        }
+        for wheel in &self.wheels {
+            if wheel.filename.compatibility(tags).is_compatible() {
+                hashes.extend(wheel.hash.as_ref().map(|h| h.0.clone()));
+            }
+        }
+        Ok(HashDigests::from(hashes))
+    }
+
     /// Returns the [`ResolvedRepositoryReference`] for the package, if it is a Git source.
     pub fn as_git_ref(&self) -> Result<Option<ResolvedRepositoryReference>, LockError> {
         match &self.id.source {
@@ -3141,9 +3467,14 @@ struct PackageWire {
     fork_markers: Vec<SimplifiedMarkerTree>,
     #[serde(default)]
     dependencies: Vec<DependencyWire>,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "repair::deserialize_normalized_keys")]
     optional_dependencies: BTreeMap<ExtraName, Vec<DependencyWire>>,
-    #[serde(default, rename = "dev-dependencies", alias = "dependency-groups")]
+    #[serde(
+        default,
+        rename = "dev-dependencies",
+        alias = "dependency-groups",
+        deserialize_with = "repair::deserialize_normalized_keys"
+    )]
     dependency_groups: BTreeMap<GroupName, Vec<DependencyWire>>,
 }
 
@@ -3154,7 +3485,12 @@ struct PackageMetadata {
     requires_dist: BTreeSet<Requirement>,
     #[serde(default)]
     provides_extras: Box<[ExtraName]>,
-    #[serde(default, rename = "requires-dev", alias = "dependency-groups")]
+    #[serde(
+        default,
+        rename = "requires-dev",
+        alias = "dependency-groups",
+        deserialize_with = "repair::deserialize_normalized_keys"
+    )]
     dependency_groups: BTreeMap<GroupName, BTreeSet<Requirement>>,
 }
 
@@ -3361,6 +3697,27 @@ impl From<PackageId> for PackageIdForDependency {
     }
 }
 
+/// The source of a [`Package`] in a lockfile, as written to the lockfile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageSource<'lock> {
+    /// A registry or `--find-links` index, identified by its URL.
+    Registry(&'lock str),
+    /// A local registry or `--find-links` directory, relative to the workspace root.
+    RegistryPath(&'lock Path),
+    /// A Git repository, identified by its URL, including the locked commit.
+    Git(&'lock str),
+    /// A direct HTTP(S) URL.
+    Direct(&'lock str),
+    /// A path to a local source or built archive.
+    Path(&'lock Path),
+    /// A path to a local directory.
+    Directory(&'lock Path),
+    /// A path to a local directory that should be installed as editable.
+    Editable(&'lock Path),
+    /// A path to a local directory that should not be built or installed.
+    Virtual(&'lock Path),
+}
+
 /// A unique identifier to differentiate between different sources for the same version of a
 /// package.
 ///
@@ -3853,6 +4210,7 @@ impl GitSource {
         for (key, val) in url.query_pairs() {
             match &*key {
                 "tag" => kind = GitSourceKind::Tag(val.into_owned()),
+                "tag-pattern" => kind = GitSourceKind::TagPattern(val.into_owned()),
                 "branch" => kind = GitSourceKind::Branch(val.into_owned()),
                 "rev" => kind = GitSourceKind::Rev(val.into_owned()),
                 "subdirectory" => subdirectory = Some(PortablePathBuf::from(val.as_ref()).into()),
@@ -3874,6 +4232,7 @@ impl GitSource {
 #[serde(rename_all = "kebab-case")]
 enum GitSourceKind {
     Tag(String),
+    TagPattern(String),
     Branch(String),
     Rev(String),
     DefaultBranch,
@@ -3963,6 +4322,7 @@ impl SourceDist {
     fn from_annotated_dist(
         id: &PackageId,
         annotated_dist: &AnnotatedDist,
+        hash_algorithm: Option<HashAlgorithm>,
     ) -> Result<Option<Self>, LockError> {
         match annotated_dist.dist {
             // We pass empty installed packages for locking.
@@ -3972,6 +4332,7 @@ impl SourceDist {
                 dist,
                 annotated_dist.hashes.as_slice(),
                 annotated_dist.index(),
+                hash_algorithm,
             ),
         }
     }
@@ -3981,16 +4342,19 @@ impl SourceDist {
         dist: &Dist,
         hashes: &[HashDigest],
         index: Option<&IndexUrl>,
+        hash_algorithm: Option<HashAlgorithm>,
     ) -> Result<Option<Self>, LockError> {
         match *dist {
             Dist::Built(BuiltDist::Registry(ref built_dist)) => {
                 let Some(sdist) = built_dist.sdist.as_ref() else {
                     return Ok(None);
                 };
-                Self::from_registry_dist(sdist, index)
+                Self::from_registry_dist(sdist, index, hash_algorithm)
             }
             Dist::Built(_) => Ok(None),
-            Dist::Source(ref source_dist) => Self::from_source_dist(id, source_dist, hashes, index),
+            Dist::Source(ref source_dist) => {
+                Self::from_source_dist(id, source_dist, hashes, index, hash_algorithm)
+            }
         }
     }
 
@@ -3999,10 +4363,11 @@ impl SourceDist {
         source_dist: &uv_distribution_types::SourceDist,
         hashes: &[HashDigest],
         index: Option<&IndexUrl>,
+        hash_algorithm: Option<HashAlgorithm>,
     ) -> Result<Option<Self>, LockError> {
         match *source_dist {
             uv_distribution_types::SourceDist::Registry(ref reg_dist) => {
-                Self::from_registry_dist(reg_dist, index)
+                Self::from_registry_dist(reg_dist, index, hash_algorithm)
             }
             uv_distribution_types::SourceDist::DirectUrl(_) => {
                 Self::from_direct_dist(id, hashes).map(Some)
@@ -4021,6 +4386,7 @@ impl SourceDist {
     fn from_registry_dist(
         reg_dist: &RegistrySourceDist,
         index: Option<&IndexUrl>,
+        hash_algorithm: Option<HashAlgorithm>,
     ) -> Result<Option<Self>, LockError> {
         // Reject distributions from registries that don't match the index URL, as can occur with
         // `--find-links`.
@@ -4033,7 +4399,7 @@ impl SourceDist {
                 let url = normalize_file_location(&reg_dist.file.url)
                     .map_err(LockErrorKind::InvalidUrl)
                     .map_err(LockError::from)?;
-                let hash = reg_dist.file.hashes.iter().max().cloned().map(Hash::from);
+                let hash = Hash::select(reg_dist.file.hashes.iter(), hash_algorithm);
                 let size = reg_dist.file.size;
                 let upload_time = reg_dist
                     .file
@@ -4068,7 +4434,7 @@ impl SourceDist {
                         .or_else(|_| std::path::absolute(&reg_dist_path))
                         .map_err(LockErrorKind::DistributionRelativePath)?
                         .into_boxed_path();
-                    let hash = reg_dist.file.hashes.iter().max().cloned().map(Hash::from);
+                    let hash = Hash::select(reg_dist.file.hashes.iter(), hash_algorithm);
                     let size = reg_dist.file.size;
                     let upload_time = reg_dist
                         .file
@@ -4088,7 +4454,7 @@ impl SourceDist {
                     let url = normalize_file_location(&reg_dist.file.url)
                         .map_err(LockErrorKind::InvalidUrl)
                         .map_err(LockError::from)?;
-                    let hash = reg_dist.file.hashes.iter().max().cloned().map(Hash::from);
+                    let hash = Hash::select(reg_dist.file.hashes.iter(), hash_algorithm);
                     let size = reg_dist.file.size;
                     let upload_time = reg_dist
                         .file
@@ -4212,6 +4578,7 @@ impl From<GitReference> for GitSourceKind {
         match value {
             GitReference::Branch(branch) => Self::Branch(branch.to_string()),
             GitReference::Tag(tag) => Self::Tag(tag.to_string()),
+            GitReference::TagPattern(pattern) => Self::TagPattern(pattern.to_string()),
             GitReference::BranchOrTag(rev) => Self::Rev(rev.to_string()),
             GitReference::BranchOrTagOrCommit(rev) => Self::Rev(rev.to_string()),
             GitReference::NamedRef(rev) => Self::Rev(rev.to_string()),
@@ -4225,6 +4592,7 @@ impl From<GitSourceKind> for GitReference {
         match value {
             GitSourceKind::Branch(branch) => Self::Branch(branch),
             GitSourceKind::Tag(tag) => Self::Tag(tag),
+            GitSourceKind::TagPattern(pattern) => Self::TagPattern(pattern),
             GitSourceKind::Rev(rev) => Self::from_rev(rev),
             GitSourceKind::DefaultBranch => Self::DefaultBranch,
         }
@@ -4262,6 +4630,10 @@ fn locked_git_url(git_dist: &GitSourceDist) -> DisplaySafeUrl {
         GitReference::Tag(tag) => {
             url.query_pairs_mut().append_pair("tag", tag.as_str());
         }
+        GitReference::TagPattern(pattern) => {
+            url.query_pairs_mut()
+                .append_pair("tag-pattern", pattern.as_str());
+        }
         GitReference::BranchOrTag(rev)
         | GitReference::BranchOrTagOrCommit(rev)
         | GitReference::NamedRef(rev) => {
@@ -4316,7 +4688,10 @@ struct Wheel {
 }
 
 impl Wheel {
-    fn from_annotated_dist(annotated_dist: &AnnotatedDist) -> Result<Vec<Self>, LockError> {
+    fn from_annotated_dist(
+        annotated_dist: &AnnotatedDist,
+        hash_algorithm: Option<HashAlgorithm>,
+    ) -> Result<Vec<Self>, LockError> {
         match annotated_dist.dist {
             // We pass empty installed packages for locking.
             ResolvedDist::Installed { .. } => unreachable!(),
@@ -4324,6 +4699,7 @@ impl Wheel {
                 dist,
                 annotated_dist.hashes.as_slice(),
                 annotated_dist.index(),
+                hash_algorithm,
             ),
         }
     }
@@ -4332,9 +4708,12 @@ impl Wheel {
         dist: &Dist,
         hashes: &[HashDigest],
         index: Option<&IndexUrl>,
+        hash_algorithm: Option<HashAlgorithm>,
     ) -> Result<Vec<Self>, LockError> {
         match *dist {
-            Dist::Built(ref built_dist) => Self::from_built_dist(built_dist, hashes, index),
+            Dist::Built(ref built_dist) => {
+                Self::from_built_dist(built_dist, hashes, index, hash_algorithm)
+            }
             Dist::Source(uv_distribution_types::SourceDist::Registry(ref source_dist)) => {
                 source_dist
                     .wheels
@@ -4344,7 +4723,7 @@ impl Wheel {
                         // `--find-links`.
                         index.is_some_and(|index| *index == wheel.index)
                     })
-                    .map(Self::from_registry_wheel)
+                    .map(|wheel| Self::from_registry_wheel(wheel, hash_algorithm))
                     .collect()
             }
             Dist::Source(_) => Ok(vec![]),
@@ -4355,9 +4734,12 @@ impl Wheel {
         built_dist: &BuiltDist,
         hashes: &[HashDigest],
         index: Option<&IndexUrl>,
+        hash_algorithm: Option<HashAlgorithm>,
     ) -> Result<Vec<Self>, LockError> {
         match *built_dist {
-            BuiltDist::Registry(ref reg_dist) => Self::from_registry_dist(reg_dist, index),
+            BuiltDist::Registry(ref reg_dist) => {
+                Self::from_registry_dist(reg_dist, index, hash_algorithm)
+            }
             BuiltDist::DirectUrl(ref direct_dist) => {
                 Ok(vec![Self::from_direct_dist(direct_dist, hashes)])
             }
@@ -4368,6 +4750,7 @@ impl Wheel {
     fn from_registry_dist(
         reg_dist: &RegistryBuiltDist,
         index: Option<&IndexUrl>,
+        hash_algorithm: Option<HashAlgorithm>,
     ) -> Result<Vec<Self>, LockError> {
         reg_dist
             .wheels
@@ -4377,11 +4760,14 @@ impl Wheel {
                 // `--find-links`.
                 index.is_some_and(|index| *index == wheel.index)
             })
-            .map(Self::from_registry_wheel)
+            .map(|wheel| Self::from_registry_wheel(wheel, hash_algorithm))
             .collect()
     }
 
-    fn from_registry_wheel(wheel: &RegistryBuiltWheel) -> Result<Self, LockError> {
+    fn from_registry_wheel(
+        wheel: &RegistryBuiltWheel,
+        hash_algorithm: Option<HashAlgorithm>,
+    ) -> Result<Self, LockError> {
         let url = match &wheel.index {
             IndexUrl::Pypi(_) | IndexUrl::Url(_) => {
                 let url = normalize_file_location(&wheel.file.url)
@@ -4413,7 +4799,7 @@ impl Wheel {
             }
         };
         let filename = wheel.filename.clone();
-        let hash = wheel.file.hashes.iter().max().cloned().map(Hash::from);
+        let hash = Hash::select(wheel.file.hashes.iter(), hash_algorithm);
         let size = wheel.file.size;
         let upload_time = wheel
             .file
@@ -4657,7 +5043,7 @@ impl TryFrom<WheelWire> for Wheel {
 
 /// A single dependency of a package in a lockfile.
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-struct Dependency {
+pub struct Dependency {
     package_id: PackageId,
     extra: BTreeSet<ExtraName>,
     /// A marker simplified from the PEP 508 marker in `complexified_marker`
@@ -4687,6 +5073,28 @@ struct Dependency {
 }
 
 impl Dependency {
+    /// Returns the [`PackageName`] of the dependency.
+    pub fn package_name(&self) -> &PackageName {
+        &self.package_id.name
+    }
+
+    /// Returns the [`Version`] of the dependency, if it's included in the lockfile.
+    ///
+    /// The version is omitted when there's only a single package with the given name.
+    pub fn package_version(&self) -> Option<&Version> {
+        self.package_id.version.as_ref()
+    }
+
+    /// Returns the extras that are enabled for the dependency.
+    pub fn extra(&self) -> &BTreeSet<ExtraName> {
+        &self.extra
+    }
+
+    /// Returns the PEP 508 marker under which the dependency applies.
+    pub fn marker(&self) -> MarkerTree {
+        self.complexified_marker.pep508()
+    }
+
     fn new(
         requires_python: &RequiresPython,
         package_id: PackageId,
@@ -4801,6 +5209,26 @@ impl DependencyWire {
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct Hash(HashDigest);
 
+impl Hash {
+    /// Select the [`Hash`] to record for an artifact: the digest for the preferred algorithm, if
+    /// the artifact provides one, or the strongest available digest otherwise.
+    fn select<'a>(
+        hashes: impl Iterator<Item = &'a HashDigest>,
+        preferred: Option<HashAlgorithm>,
+    ) -> Option<Self> {
+        let mut strongest: Option<&HashDigest> = None;
+        for digest in hashes {
+            if preferred == Some(digest.algorithm) {
+                return Some(Self(digest.clone()));
+            }
+            if strongest.is_none_or(|strongest| digest > strongest) {
+                strongest = Some(digest);
+            }
+        }
+        strongest.cloned().map(Self)
+    }
+}
+
 impl From<HashDigest> for Hash {
     fn from(hd: HashDigest) -> Self {
         Self(hd)
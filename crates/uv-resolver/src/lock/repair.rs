@@ -0,0 +1,372 @@
+//! Detect and repair lockfile entries whose names collide after normalization.
+//!
+//! Package names, extras, and dependency groups are normalized when a lockfile is parsed, such that
+//! `Foo_Bar` and `foo-bar` refer to the same entry. A lockfile written by uv never contains such
+//! collisions, but they can be introduced by (e.g.) a bad resolution of a merge conflict.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Deserializer, de};
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+
+use crate::Lock;
+
+/// A pair of `[[package]]` entries in a lockfile whose names collide after normalization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicatePackage {
+    /// The normalized name of the package.
+    pub name: PackageName,
+    /// The name of the first entry, as written in the lockfile.
+    pub first: String,
+    /// The name of the second entry, as written in the lockfile.
+    pub second: String,
+}
+
+impl std::fmt::Display for DuplicatePackage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` (as `{}` and `{}`)",
+            self.name.cyan(),
+            self.first.cyan(),
+            self.second.cyan()
+        )
+    }
+}
+
+/// An error that occurs when a lockfile can't be repaired.
+#[derive(Debug, thiserror::Error)]
+pub enum LockRepairError {
+    #[error("Failed to parse `uv.lock`")]
+    Parse(#[source] toml::de::Error),
+    #[error(
+        "Unable to repair `uv.lock`: the entries for {0} differ; remove `uv.lock` and run `uv lock` to regenerate it"
+    )]
+    Conflict(DuplicatePackage),
+    #[error(
+        "Unable to repair `uv.lock`: the `{0}` entries for `{1}` differ; remove `uv.lock` and run `uv lock` to regenerate it"
+    )]
+    ConflictingKeys(&'static str, String),
+}
+
+/// Like [`Lock`], but limited to the identifying fields of each package. Used for error reporting:
+/// by limiting parsing to the package names, we can identify colliding entries in lockfiles that
+/// may otherwise be unparsable.
+#[derive(Debug, Deserialize)]
+struct LockPackages {
+    #[serde(rename = "package", alias = "distribution", default)]
+    packages: Vec<PackageEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PackageEntry {
+    name: String,
+    version: Option<String>,
+    source: Option<toml::Value>,
+}
+
+/// Find all `[[package]]` entries in the given lockfile contents whose names, versions, and
+/// sources collide after normalization.
+pub fn find_duplicate_packages(contents: &str) -> Vec<DuplicatePackage> {
+    let Ok(lock) = toml::from_str::<LockPackages>(contents) else {
+        return Vec::new();
+    };
+
+    let mut seen: Vec<(PackageName, &PackageEntry)> = Vec::new();
+    let mut duplicates = Vec::new();
+    for entry in &lock.packages {
+        let Ok(name) = PackageName::from_str(&entry.name) else {
+            continue;
+        };
+        if let Some((_, existing)) = seen.iter().find(|(existing_name, existing)| {
+            *existing_name == name
+                && existing.version == entry.version
+                && existing.source == entry.source
+        }) {
+            duplicates.push(DuplicatePackage {
+                name: name.clone(),
+                first: existing.name.clone(),
+                second: entry.name.clone(),
+            });
+        } else {
+            seen.push((name, entry));
+        }
+    }
+    duplicates
+}
+
+/// Repair the given lockfile contents by normalizing all package names, extras, and dependency
+/// groups, and removing any `[[package]]` entries that are identical to a preceding entry after
+/// normalization.
+///
+/// Returns `None` if the lockfile does not require any repairs. Returns an error if two entries
+/// collide after normalization, but are otherwise distinct.
+pub fn repair_lock(contents: &str) -> Result<Option<Lock>, LockRepairError> {
+    let mut document = toml::from_str::<toml::Table>(contents).map_err(LockRepairError::Parse)?;
+
+    let mut changed = false;
+    if let Some(toml::Value::Array(packages)) = document.get_mut("package") {
+        // Normalize every entry.
+        let mut originals = Vec::with_capacity(packages.len());
+        for package in packages.iter_mut() {
+            originals.push(
+                package
+                    .get("name")
+                    .and_then(toml::Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            );
+            if let toml::Value::Table(package) = package {
+                changed |= normalize_package(package)?;
+            }
+        }
+
+        // Remove any entries that are identical to a preceding entry.
+        let mut retained: Vec<(toml::Value, String)> = Vec::with_capacity(packages.len());
+        for (package, original) in packages.drain(..).zip(originals) {
+            if let Some((existing, existing_original)) = retained
+                .iter()
+                .find(|(existing, _)| is_same_package(existing, &package))
+            {
+                if *existing != package {
+                    let name = package
+                        .get("name")
+                        .and_then(toml::Value::as_str)
+                        .unwrap_or_default();
+                    let name = PackageName::from_str(name)
+                        .map_err(|err| LockRepairError::Parse(de::Error::custom(err)))?;
+                    return Err(LockRepairError::Conflict(DuplicatePackage {
+                        name,
+                        first: existing_original.clone(),
+                        second: original,
+                    }));
+                }
+                changed = true;
+            } else {
+                retained.push((package, original));
+            }
+        }
+        packages.extend(retained.into_iter().map(|(package, _)| package));
+    }
+
+    if !changed {
+        return Ok(None);
+    }
+
+    let lock = toml::Value::Table(document)
+        .try_into::<Lock>()
+        .map_err(LockRepairError::Parse)?;
+    Ok(Some(lock))
+}
+
+/// Returns `true` if the two `[[package]]` entries share a name, version, and source.
+fn is_same_package(a: &toml::Value, b: &toml::Value) -> bool {
+    ["name", "version", "source"]
+        .iter()
+        .all(|key| a.get(key) == b.get(key))
+}
+
+/// Normalize the names within a `[[package]]` entry, returning `true` if any name was changed.
+fn normalize_package(package: &mut toml::Table) -> Result<bool, LockRepairError> {
+    let mut changed = false;
+
+    if let Some(name) = package.get_mut("name") {
+        changed |= normalize_value::<PackageName>(name);
+    }
+    if let Some(toml::Value::Array(dependencies)) = package.get_mut("dependencies") {
+        changed |= normalize_dependencies(dependencies);
+    }
+    if let Some(toml::Value::Table(table)) = package.get_mut("optional-dependencies") {
+        changed |= normalize_keys::<ExtraName>("optional-dependencies", table)?;
+        for dependencies in table.values_mut() {
+            if let toml::Value::Array(dependencies) = dependencies {
+                changed |= normalize_dependencies(dependencies);
+            }
+        }
+    }
+    for key in ["dev-dependencies", "dependency-groups"] {
+        if let Some(toml::Value::Table(table)) = package.get_mut(key) {
+            changed |= normalize_keys::<GroupName>(key, table)?;
+            for dependencies in table.values_mut() {
+                if let toml::Value::Array(dependencies) = dependencies {
+                    changed |= normalize_dependencies(dependencies);
+                }
+            }
+        }
+    }
+
+    if let Some(toml::Value::Table(metadata)) = package.get_mut("metadata") {
+        if let Some(toml::Value::Array(requirements)) = metadata.get_mut("requires-dist") {
+            changed |= normalize_dependencies(requirements);
+        }
+        for key in ["requires-dev", "dependency-groups"] {
+            if let Some(toml::Value::Table(table)) = metadata.get_mut(key) {
+                changed |= normalize_keys::<GroupName>(key, table)?;
+                for requirements in table.values_mut() {
+                    if let toml::Value::Array(requirements) = requirements {
+                        changed |= normalize_dependencies(requirements);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Normalize the package names and extras within an array of dependency entries.
+fn normalize_dependencies(dependencies: &mut [toml::Value]) -> bool {
+    let mut changed = false;
+    for dependency in dependencies {
+        if let Some(name) = dependency.get_mut("name") {
+            changed |= normalize_value::<PackageName>(name);
+        }
+        if let Some(toml::Value::Array(extras)) = dependency.get_mut("extra") {
+            for extra in extras {
+                changed |= normalize_value::<ExtraName>(extra);
+            }
+        }
+    }
+    changed
+}
+
+/// Normalize a string value, returning `true` if it was changed.
+fn normalize_value<T: FromStr + Display>(value: &mut toml::Value) -> bool {
+    let Some(raw) = value.as_str() else {
+        return false;
+    };
+    let Ok(normalized) = T::from_str(raw) else {
+        return false;
+    };
+    let normalized = normalized.to_string();
+    if normalized == raw {
+        return false;
+    }
+    *value = toml::Value::String(normalized);
+    true
+}
+
+/// Normalize the keys of a table, merging any keys that collide after normalization if their
+/// values are identical.
+fn normalize_keys<T: FromStr + Display>(
+    field: &'static str,
+    table: &mut toml::Table,
+) -> Result<bool, LockRepairError> {
+    let mut changed = false;
+    let mut normalized = toml::Table::new();
+    for (key, value) in std::mem::take(table) {
+        let name = T::from_str(&key).map_or_else(|_| key.clone(), |name| name.to_string());
+        if name != key {
+            changed = true;
+        }
+        if let Some(existing) = normalized.get(&name) {
+            if *existing != value {
+                return Err(LockRepairError::ConflictingKeys(field, name));
+            }
+            changed = true;
+            continue;
+        }
+        normalized.insert(name, value);
+    }
+    *table = normalized;
+    Ok(changed)
+}
+
+/// Deserialize a map keyed by a normalized name (e.g., an [`ExtraName`]), rejecting any keys that
+/// collide after normalization (e.g., `Foo_Bar` and `foo-bar`).
+pub(super) fn deserialize_normalized_keys<'de, D, K, V>(
+    deserializer: D,
+) -> Result<BTreeMap<K, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: FromStr + Ord + Display,
+    K::Err: Display,
+    V: Deserialize<'de>,
+{
+    let raw = BTreeMap::<String, V>::deserialize(deserializer)?;
+    let mut spellings = BTreeMap::<String, String>::new();
+    let mut map = BTreeMap::new();
+    for (key, value) in raw {
+        let name = K::from_str(&key).map_err(de::Error::custom)?;
+        if let Some(existing) = spellings.insert(name.to_string(), key.clone()) {
+            return Err(de::Error::custom(format!(
+                "found duplicate entries `{existing}` and `{key}`, which both normalize to `{name}`; run `uv lock --repair` to merge them"
+            )));
+        }
+        map.insert(name, value);
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DUPLICATE: &str = r#"
+version = 1
+revision = 3
+requires-python = ">=3.12"
+
+[[package]]
+name = "Foo_Bar"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/foo_bar-1.0.0.tar.gz", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 1 }
+
+[[package]]
+name = "foo-bar"
+version = "1.0.0"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://files.pythonhosted.org/packages/foo_bar-1.0.0.tar.gz", hash = "sha256:37dd54208da7e1cd875388217d5e00ebd4179249f90fb72437e91a35459a0ad3", size = 1 }
+
+[[package]]
+name = "project"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+    { name = "Foo_Bar" },
+]
+"#;
+
+    #[test]
+    fn find_duplicates() {
+        let duplicates = find_duplicate_packages(DUPLICATE);
+        assert_eq!(
+            duplicates,
+            vec![DuplicatePackage {
+                name: PackageName::from_str("foo-bar").unwrap(),
+                first: "Foo_Bar".to_string(),
+                second: "foo-bar".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn repair_duplicates() {
+        // The lockfile can't be parsed as-is.
+        assert!(toml::from_str::<Lock>(DUPLICATE).is_err());
+
+        let lock = repair_lock(DUPLICATE).unwrap().unwrap();
+        let names = lock
+            .packages()
+            .iter()
+            .map(|package| package.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["foo-bar", "project"]);
+
+        // A normalized lockfile doesn't require any repairs.
+        let contents = lock.to_toml().unwrap();
+        assert!(repair_lock(&contents).unwrap().is_none());
+    }
+
+    #[test]
+    fn repair_conflict() {
+        let contents = DUPLICATE.replacen("size = 1", "size = 2", 1);
+        let err = repair_lock(&contents).unwrap_err();
+        assert!(matches!(err, LockRepairError::Conflict(_)));
+    }
+}
@@ -0,0 +1,358 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use uv_distribution_types::{Requirement, RequirementSource};
+use uv_normalize::{ExtraName, GroupName, PackageName};
+
+use crate::Lock;
+use crate::lock::Dependency;
+
+/// The optional dependencies through which a [`WhyNode`] requires its dependencies.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WhyVia {
+    /// The dependencies of an extra, e.g., `project[ssh]`.
+    Extra(ExtraName),
+    /// The dependencies of a dependency group, e.g., `dev`.
+    Group(GroupName),
+}
+
+/// A package (or the workspace itself) that requires another package.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WhyNode {
+    /// The requiring package, or `None` for requirements declared at the workspace level.
+    pub package: Option<PackageName>,
+    /// The extra or dependency group that declares the requirement, if any.
+    pub via: Option<WhyVia>,
+}
+
+impl std::fmt::Display for WhyNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.package {
+            Some(package) => write!(f, "{}", package.bold())?,
+            None => write!(f, "{}", "(workspace)".bold())?,
+        }
+        match &self.via {
+            Some(WhyVia::Extra(extra)) => write!(f, " {}", format!("(extra: {extra})").dimmed()),
+            Some(WhyVia::Group(group)) => write!(f, " {}", format!("(group: {group})").dimmed()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A requirement from a [`WhyNode`] on a package.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WhyEdge {
+    /// The requiring package.
+    pub from: WhyNode,
+    /// The required package.
+    pub to: PackageName,
+    /// The extras of the required package enabled by the requirement.
+    pub extras: BTreeSet<ExtraName>,
+    /// The requirement, as displayed to the user, including its marker.
+    pub requirement: String,
+}
+
+/// Every path through which a package is required by a root of the dependency graph.
+#[derive(Debug)]
+pub struct WhyDisplay {
+    /// The package being explained.
+    target: PackageName,
+    /// Whether the package is itself a root.
+    is_root: bool,
+    /// The de-duplicated paths, each starting at a root and ending at the target package.
+    paths: BTreeSet<Vec<WhyEdge>>,
+}
+
+impl WhyDisplay {
+    /// Find every path from one of the `roots` to the `target` package.
+    ///
+    /// Requirements declared at the workspace level (i.e., with no requiring package) are treated
+    /// as roots, too.
+    pub fn new(target: PackageName, roots: &BTreeSet<PackageName>, edges: Vec<WhyEdge>) -> Self {
+        let mut incoming: FxHashMap<&PackageName, Vec<&WhyEdge>> = FxHashMap::default();
+        for edge in &edges {
+            incoming.entry(&edge.to).or_default().push(edge);
+        }
+
+        let mut paths = BTreeSet::default();
+        let mut stack = Vec::new();
+        let mut visiting = FxHashSet::default();
+        let node = WhyNode {
+            package: Some(target.clone()),
+            via: None,
+        };
+        visiting.insert(node.clone());
+        Self::visit(
+            &node,
+            roots,
+            &incoming,
+            &mut stack,
+            &mut visiting,
+            &mut paths,
+        );
+
+        Self {
+            is_root: roots.contains(&target),
+            target,
+            paths,
+        }
+    }
+
+    /// Walk the incoming edges of a node, recording a path whenever a root is reached.
+    ///
+    /// The `stack` holds the edges from the current node to the target, in reverse order.
+    fn visit<'edge>(
+        node: &WhyNode,
+        roots: &BTreeSet<PackageName>,
+        incoming: &FxHashMap<&PackageName, Vec<&'edge WhyEdge>>,
+        stack: &mut Vec<&'edge WhyEdge>,
+        visiting: &mut FxHashSet<WhyNode>,
+        paths: &mut BTreeSet<Vec<WhyEdge>>,
+    ) {
+        let Some(package) = &node.package else {
+            paths.insert(stack.iter().rev().map(|edge| (*edge).clone()).collect());
+            return;
+        };
+
+        if !stack.is_empty() && roots.contains(package) {
+            paths.insert(stack.iter().rev().map(|edge| (*edge).clone()).collect());
+        }
+
+        // Dependency groups are local to their package, so they can't be required by others.
+        if matches!(node.via, Some(WhyVia::Group(_))) {
+            return;
+        }
+
+        let Some(edges) = incoming.get(package) else {
+            return;
+        };
+        for edge in edges {
+            // An extra is only enabled by requirements that request it.
+            if let Some(WhyVia::Extra(extra)) = &node.via {
+                if !edge.extras.contains(extra) {
+                    continue;
+                }
+            }
+            if !visiting.insert(edge.from.clone()) {
+                continue;
+            }
+            stack.push(edge);
+            Self::visit(&edge.from, roots, incoming, stack, visiting, paths);
+            stack.pop();
+            visiting.remove(&edge.from);
+        }
+    }
+
+    /// Find every path from a workspace member to the `target` package in the lockfile.
+    pub fn from_lock(lock: &Lock, target: PackageName) -> Self {
+        // Identify the workspace members, including the root package, which is omitted from the
+        // list of members for single-member workspaces.
+        let roots: BTreeSet<PackageName> = if lock.members().is_empty() {
+            lock.root()
+                .into_iter()
+                .map(|package| package.id.name.clone())
+                .collect()
+        } else {
+            lock.members().clone()
+        };
+
+        let mut edges = Vec::new();
+        for package in &lock.packages {
+            let from = WhyNode {
+                package: Some(package.id.name.clone()),
+                via: None,
+            };
+            for dependency in &package.dependencies {
+                let requirement = package.metadata.requires_dist.iter().find(|requirement| {
+                    requirement.name == dependency.package_id.name
+                        && requirement.marker.top_level_extra_name().is_none()
+                });
+                edges.push(WhyEdge::from_dependency(
+                    from.clone(),
+                    dependency,
+                    requirement,
+                ));
+            }
+            for (extra, dependencies) in &package.optional_dependencies {
+                let from = WhyNode {
+                    package: Some(package.id.name.clone()),
+                    via: Some(WhyVia::Extra(extra.clone())),
+                };
+                for dependency in dependencies {
+                    let requirement = package.metadata.requires_dist.iter().find(|requirement| {
+                        requirement.name == dependency.package_id.name
+                            && requirement
+                                .marker
+                                .top_level_extra_name()
+                                .is_some_and(|name| *name == *extra)
+                    });
+                    edges.push(WhyEdge::from_dependency(
+                        from.clone(),
+                        dependency,
+                        requirement,
+                    ));
+                }
+            }
+            for (group, dependencies) in &package.dependency_groups {
+                let from = WhyNode {
+                    package: Some(package.id.name.clone()),
+                    via: Some(WhyVia::Group(group.clone())),
+                };
+                for dependency in dependencies {
+                    let requirement =
+                        package
+                            .metadata
+                            .dependency_groups
+                            .get(group)
+                            .and_then(|requirements| {
+                                requirements.iter().find(|requirement| {
+                                    requirement.name == dependency.package_id.name
+                                })
+                            });
+                    edges.push(WhyEdge::from_dependency(
+                        from.clone(),
+                        dependency,
+                        requirement,
+                    ));
+                }
+            }
+        }
+
+        // Include any requirements declared at the workspace level, e.g., in a virtual root.
+        for requirement in lock.requirements() {
+            edges.push(WhyEdge::from_requirement(
+                WhyNode {
+                    package: None,
+                    via: None,
+                },
+                requirement,
+            ));
+        }
+        for (group, requirements) in lock.dependency_groups() {
+            for requirement in requirements {
+                edges.push(WhyEdge::from_requirement(
+                    WhyNode {
+                        package: None,
+                        via: Some(WhyVia::Group(group.clone())),
+                    },
+                    requirement,
+                ));
+            }
+        }
+
+        Self::new(target, &roots, edges)
+    }
+
+    /// The package being explained.
+    pub fn target(&self) -> &PackageName {
+        &self.target
+    }
+
+    /// Returns `true` if the package is itself a root, e.g., a workspace member.
+    pub fn is_root(&self) -> bool {
+        self.is_root
+    }
+
+    /// Returns `true` if no path leads to the package.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Returns `true` if the package is only required through the extras of a root, i.e., it's
+    /// not installed unless one of those extras is enabled.
+    pub fn only_via_extras(&self) -> bool {
+        !self.paths.is_empty()
+            && self.paths.iter().all(|path| {
+                path.first()
+                    .is_some_and(|edge| matches!(edge.from.via, Some(WhyVia::Extra(_))))
+            })
+    }
+
+    /// The extras of the roots through which the package is required.
+    pub fn root_extras(&self) -> BTreeSet<(&PackageName, &ExtraName)> {
+        self.paths
+            .iter()
+            .filter_map(|path| {
+                let edge = path.first()?;
+                let Some(WhyVia::Extra(extra)) = &edge.from.via else {
+                    return None;
+                };
+                Some((edge.from.package.as_ref()?, extra))
+            })
+            .collect()
+    }
+}
+
+impl WhyEdge {
+    /// Create an edge for a locked dependency, using the package's declared requirement (if
+    /// known) to display the version specifier.
+    fn from_dependency(
+        from: WhyNode,
+        dependency: &Dependency,
+        requirement: Option<&Requirement>,
+    ) -> Self {
+        let mut label = dependency.package_id.name.to_string();
+        if !dependency.extra.is_empty() {
+            write!(label, "[{}]", dependency.extra.iter().join(",")).unwrap();
+        }
+        if let Some(Requirement {
+            source: RequirementSource::Registry { specifier, .. },
+            ..
+        }) = requirement
+        {
+            write!(label, "{specifier}").unwrap();
+        }
+        if let Some(marker) = dependency.simplified_marker.try_to_string() {
+            write!(label, " ; {marker}").unwrap();
+        }
+        Self {
+            from,
+            to: dependency.package_id.name.clone(),
+            extras: dependency.extra.clone(),
+            requirement: label,
+        }
+    }
+
+    /// Create an edge for a requirement that isn't attached to a locked package.
+    fn from_requirement(from: WhyNode, requirement: &Requirement) -> Self {
+        let mut label = requirement.name.to_string();
+        if !requirement.extras.is_empty() {
+            write!(label, "[{}]", requirement.extras.iter().join(",")).unwrap();
+        }
+        if let RequirementSource::Registry { specifier, .. } = &requirement.source {
+            write!(label, "{specifier}").unwrap();
+        }
+        if let Some(marker) = requirement.marker.contents() {
+            write!(label, " ; {marker}").unwrap();
+        }
+        Self {
+            from,
+            to: requirement.name.clone(),
+            extras: requirement.extras.iter().cloned().collect(),
+            requirement: label,
+        }
+    }
+}
+
+impl std::fmt::Display for WhyDisplay {
+    /// Display each path as a chain of requirements, starting from its root.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, path) in self.paths.iter().enumerate() {
+            let Some(first) = path.first() else {
+                continue;
+            };
+            if index > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", first.from)?;
+            for (depth, edge) in path.iter().enumerate() {
+                writeln!(f, "{}└── {}", "    ".repeat(depth), edge.requirement)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,17 +1,24 @@
+use std::collections::BTreeSet;
+
 use uv_configuration::{BuildOptions, IndexStrategy};
-use uv_pypi_types::SupportedEnvironments;
+use uv_normalize::PackageName;
+use uv_pypi_types::{HashAlgorithm, SupportedEnvironments};
 use uv_torch::TorchStrategy;
 
 use crate::fork_strategy::ForkStrategy;
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode, ResolutionOverrides};
 
 /// Options for resolving a manifest.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
+    pub resolution_overrides: ResolutionOverrides,
     pub prerelease_mode: PrereleaseMode,
+    pub allow_prereleases: BTreeSet<PackageName>,
     pub dependency_mode: DependencyMode,
     pub fork_strategy: ForkStrategy,
+    pub prefer_local_versions: bool,
+    pub hash_algorithm: Option<HashAlgorithm>,
     pub exclude_newer: ExcludeNewer,
     pub index_strategy: IndexStrategy,
     pub required_environments: SupportedEnvironments,
@@ -24,9 +31,13 @@ pub struct Options {
 #[derive(Debug, Default, Clone)]
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
+    resolution_overrides: ResolutionOverrides,
     prerelease_mode: PrereleaseMode,
+    allow_prereleases: BTreeSet<PackageName>,
     dependency_mode: DependencyMode,
     fork_strategy: ForkStrategy,
+    prefer_local_versions: bool,
+    hash_algorithm: Option<HashAlgorithm>,
     exclude_newer: ExcludeNewer,
     index_strategy: IndexStrategy,
     required_environments: SupportedEnvironments,
@@ -48,6 +59,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`ResolutionOverrides`].
+    #[must_use]
+    pub fn resolution_overrides(mut self, resolution_overrides: ResolutionOverrides) -> Self {
+        self.resolution_overrides = resolution_overrides;
+        self
+    }
+
     /// Sets the [`PrereleaseMode`].
     #[must_use]
     pub fn prerelease_mode(mut self, prerelease_mode: PrereleaseMode) -> Self {
@@ -55,6 +73,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages for which pre-releases are allowed under [`PrereleaseMode::Forbid`].
+    #[must_use]
+    pub fn allow_prereleases(mut self, allow_prereleases: BTreeSet<PackageName>) -> Self {
+        self.allow_prereleases = allow_prereleases;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -69,6 +94,21 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets whether to prefer versions with a local version segment (e.g., `1.2.3+corp1`) over
+    /// the equivalent public version (e.g., `1.2.3`).
+    #[must_use]
+    pub fn prefer_local_versions(mut self, prefer_local_versions: bool) -> Self {
+        self.prefer_local_versions = prefer_local_versions;
+        self
+    }
+
+    /// Sets the preferred [`HashAlgorithm`] for recorded hashes.
+    #[must_use]
+    pub fn hash_algorithm(mut self, hash_algorithm: Option<HashAlgorithm>) -> Self {
+        self.hash_algorithm = hash_algorithm;
+        self
+    }
+
     /// Sets the exclusion date.
     #[must_use]
     pub fn exclude_newer(mut self, exclude_newer: ExcludeNewer) -> Self {
@@ -115,9 +155,13 @@ impl OptionsBuilder {
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
+            resolution_overrides: self.resolution_overrides,
             prerelease_mode: self.prerelease_mode,
+            allow_prereleases: self.allow_prereleases,
             dependency_mode: self.dependency_mode,
             fork_strategy: self.fork_strategy,
+            prefer_local_versions: self.prefer_local_versions,
+            hash_algorithm: self.hash_algorithm,
             exclude_newer: self.exclude_newer,
             index_strategy: self.index_strategy,
             required_environments: self.required_environments,
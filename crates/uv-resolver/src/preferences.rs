@@ -4,7 +4,7 @@ use std::str::FromStr;
 use rustc_hash::FxHashMap;
 use tracing::trace;
 
-use uv_distribution_types::{IndexUrl, InstalledDist};
+use uv_distribution_types::{IndexUrl, InstalledDist, Requirement, RequirementSource};
 use uv_normalize::PackageName;
 use uv_pep440::{Operator, Version};
 use uv_pep508::{MarkerTree, VerbatimUrl, VersionOrUrl};
@@ -79,6 +79,46 @@ impl Preference {
         }))
     }
 
+    /// Create a [`Preference`] from a pinned input requirement with `--hash` values.
+    ///
+    /// Only registry requirements with a single `==` specifier are eligible, as the hashes must
+    /// refer to a specific version.
+    pub fn from_requirement(
+        requirement: &Requirement,
+        hashes: &[String],
+    ) -> Result<Option<Self>, PreferenceError> {
+        if hashes.is_empty() {
+            return Ok(None);
+        }
+
+        let RequirementSource::Registry { specifier, .. } = &requirement.source else {
+            return Ok(None);
+        };
+
+        let [specifier] = specifier.as_ref() else {
+            return Ok(None);
+        };
+
+        if *specifier.operator() != Operator::Equal {
+            trace!("Excluding {requirement} from preferences due to inexact version specifier");
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            name: requirement.name.clone(),
+            version: specifier.version().clone(),
+            marker: requirement.marker,
+            fork_markers: vec![],
+            index: PreferenceIndex::Any,
+            hashes: hashes
+                .iter()
+                .map(String::as_str)
+                .map(HashDigest::from_str)
+                .collect::<Result<_, _>>()?,
+            source: PreferenceSource::RequirementsTxt,
+        }))
+    }
+
     /// Create a [`Preference`] from a locked distribution.
     pub fn from_lock(
         package: &crate::lock::Package,
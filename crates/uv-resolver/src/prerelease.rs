@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use uv_distribution_types::RequirementSource;
 use uv_normalize::PackageName;
 use uv_pep440::Operator;
@@ -27,6 +29,10 @@ pub enum PrereleaseMode {
     /// has an explicit pre-release marker in its version requirements.
     #[default]
     IfNecessaryOrExplicit,
+
+    /// Forbid all pre-release versions, failing the resolution if a pre-release would be required,
+    /// except for packages that are explicitly listed in `allow-prereleases`.
+    Forbid,
 }
 
 impl std::fmt::Display for PrereleaseMode {
@@ -37,6 +43,7 @@ impl std::fmt::Display for PrereleaseMode {
             Self::IfNecessary => write!(f, "if-necessary"),
             Self::Explicit => write!(f, "explicit"),
             Self::IfNecessaryOrExplicit => write!(f, "if-necessary-or-explicit"),
+            Self::Forbid => write!(f, "forbid"),
         }
     }
 }
@@ -61,11 +68,15 @@ pub(crate) enum PrereleaseStrategy {
     /// Allow pre-release versions if all versions of a package are pre-release, or if the package
     /// has an explicit pre-release marker in its version requirements.
     IfNecessaryOrExplicit(ForkSet),
+
+    /// Forbid all pre-release versions, except for the given packages.
+    Forbid(BTreeSet<PackageName>),
 }
 
 impl PrereleaseStrategy {
     pub(crate) fn from_mode(
         mode: PrereleaseMode,
+        allow_prereleases: &BTreeSet<PackageName>,
         manifest: &Manifest,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
@@ -76,6 +87,7 @@ impl PrereleaseStrategy {
             PrereleaseMode::Disallow => Self::Disallow,
             PrereleaseMode::Allow => Self::Allow,
             PrereleaseMode::IfNecessary => Self::IfNecessary,
+            PrereleaseMode::Forbid => Self::Forbid(allow_prereleases.clone()),
             _ => {
                 for requirement in manifest.requirements(env, dependencies) {
                     let RequirementSource::Registry { specifier, .. } = &requirement.source else {
@@ -126,8 +138,20 @@ impl PrereleaseStrategy {
                     AllowPrerelease::IfNecessary
                 }
             }
+            Self::Forbid(allowed) => {
+                if allowed.contains(package_name) {
+                    AllowPrerelease::Yes
+                } else {
+                    AllowPrerelease::No
+                }
+            }
         }
     }
+
+    /// Returns `true` if pre-releases are forbidden, rather than merely disallowed.
+    pub(crate) fn is_forbid(&self) -> bool {
+        matches!(self, Self::Forbid(_))
+    }
 }
 
 /// The pre-release strategy for a given package.
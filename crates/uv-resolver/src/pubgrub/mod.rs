@@ -2,7 +2,7 @@ pub(crate) use crate::pubgrub::dependencies::PubGrubDependency;
 pub(crate) use crate::pubgrub::distribution::PubGrubDistribution;
 pub use crate::pubgrub::package::{PubGrubPackage, PubGrubPackageInner, PubGrubPython};
 pub(crate) use crate::pubgrub::priority::{PubGrubPriorities, PubGrubPriority, PubGrubTiebreaker};
-pub(crate) use crate::pubgrub::report::PubGrubReportFormatter;
+pub(crate) use crate::pubgrub::report::{PubGrubHint, PubGrubReportFormatter};
 
 mod dependencies;
 mod distribution;
@@ -826,6 +826,14 @@ impl PubGrubReportFormatter<'_> {
             Some(UnavailablePackage::NoIndex) => {
                 if no_find_links {
                     hints.insert(PubGrubHint::NoIndex);
+                } else {
+                    hints.insert(PubGrubHint::NoIndexFindLinks {
+                        package: name.clone(),
+                        locations: index_locations
+                            .flat_indexes()
+                            .map(|index| index.url().clone())
+                            .collect(),
+                    });
                 }
             }
             Some(UnavailablePackage::Offline) => {
@@ -976,6 +984,7 @@ impl PubGrubReportFormatter<'_> {
                 hints.insert(PubGrubHint::PrereleaseRequested {
                     name: name.clone(),
                     range: set.clone(),
+                    forbidden: selector.prerelease_strategy().is_forbid(),
                 });
             }
         } else if let Some(version) = self.available_versions.get(name).and_then(|versions| {
@@ -990,6 +999,7 @@ impl PubGrubReportFormatter<'_> {
                 hints.insert(PubGrubHint::PrereleaseAvailable {
                     package: name.clone(),
                     version: version.clone(),
+                    forbidden: selector.prerelease_strategy().is_forbid(),
                 });
             }
         }
@@ -1005,6 +1015,8 @@ pub(crate) enum PubGrubHint {
         package: PackageName,
         // excluded from `PartialEq` and `Hash`
         version: Version,
+        // excluded from `PartialEq` and `Hash`
+        forbidden: bool,
     },
     /// A requirement included a pre-release marker, but pre-releases weren't enabled for that
     /// package.
@@ -1012,10 +1024,19 @@ pub(crate) enum PubGrubHint {
         name: PackageName,
         // excluded from `PartialEq` and `Hash`
         range: Range<Version>,
+        // excluded from `PartialEq` and `Hash`
+        forbidden: bool,
     },
     /// Requirements were unavailable due to lookups in the index being disabled and no extra
     /// index was provided via `--find-links`
     NoIndex,
+    /// A package was not found in any of the `--find-links` locations, and lookups in the index
+    /// were disabled.
+    NoIndexFindLinks {
+        package: PackageName,
+        // excluded from `PartialEq` and `Hash`
+        locations: Vec<IndexUrl>,
+    },
     /// A package was not found in the registry, but network access was disabled.
     Offline,
     /// Metadata for a package could not be parsed.
@@ -1162,6 +1183,9 @@ enum PubGrubHintCore {
         package: PackageName,
     },
     NoIndex,
+    NoIndexFindLinks {
+        package: PackageName,
+    },
     Offline,
     InvalidPackageMetadata {
         package: PackageName,
@@ -1231,6 +1255,7 @@ impl From<PubGrubHint> for PubGrubHintCore {
                 Self::PrereleaseRequested { package }
             }
             PubGrubHint::NoIndex => Self::NoIndex,
+            PubGrubHint::NoIndexFindLinks { package, .. } => Self::NoIndexFindLinks { package },
             PubGrubHint::Offline => Self::Offline,
             PubGrubHint::InvalidPackageMetadata { package, .. } => {
                 Self::InvalidPackageMetadata { package }
@@ -1302,7 +1327,27 @@ impl Eq for PubGrubHint {}
 impl std::fmt::Display for PubGrubHint {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::PrereleaseAvailable { package, version } => {
+            Self::PrereleaseAvailable {
+                package,
+                version,
+                forbidden: true,
+            } => {
+                write!(
+                    f,
+                    "{}{} Pre-releases are available for `{}` in the requested range (e.g., {}), but pre-releases are forbidden (to allow pre-releases of `{}`, add it to `{}`)",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.cyan(),
+                    version.cyan(),
+                    package.cyan(),
+                    "tool.uv.allow-prereleases".green(),
+                )
+            }
+            Self::PrereleaseAvailable {
+                package,
+                version,
+                forbidden: false,
+            } => {
                 write!(
                     f,
                     "{}{} Pre-releases are available for `{}` in the requested range (e.g., {}), but pre-releases weren't enabled (try: `{}`)",
@@ -1313,7 +1358,27 @@ impl std::fmt::Display for PubGrubHint {
                     "--prerelease=allow".green(),
                 )
             }
-            Self::PrereleaseRequested { name, range } => {
+            Self::PrereleaseRequested {
+                name,
+                range,
+                forbidden: true,
+            } => {
+                write!(
+                    f,
+                    "{}{} `{}` was requested with a pre-release marker (e.g., {}), but pre-releases are forbidden (to allow pre-releases of `{}`, add it to `{}`)",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    name.cyan(),
+                    PackageRange::compatibility(&PubGrubPackage::base(name), range, None).cyan(),
+                    name.cyan(),
+                    "tool.uv.allow-prereleases".green(),
+                )
+            }
+            Self::PrereleaseRequested {
+                name,
+                range,
+                forbidden: false,
+            } => {
                 write!(
                     f,
                     "{}{} `{}` was requested with a pre-release marker (e.g., {}), but pre-releases weren't enabled (try: `{}`)",
@@ -1333,6 +1398,20 @@ impl std::fmt::Display for PubGrubHint {
                     "--find-links <uri>".green(),
                 )
             }
+            Self::NoIndexFindLinks { package, locations } => {
+                write!(
+                    f,
+                    "{}{} `{}` was not found in the provided package locations ({}), and index lookups were disabled (i.e., with `{}`)",
+                    "hint".bold().cyan(),
+                    ":".bold(),
+                    package.cyan(),
+                    locations
+                        .iter()
+                        .map(|location| location.without_credentials().cyan().to_string())
+                        .join(", "),
+                    "--no-index".green(),
+                )
+            }
             Self::Offline => {
                 write!(
                     f,
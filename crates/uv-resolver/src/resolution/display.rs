@@ -1,13 +1,14 @@
 use std::collections::BTreeSet;
 
 use owo_colors::OwoColorize;
+use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Direction, Graph};
 use rustc_hash::{FxBuildHasher, FxHashMap};
 
 use uv_distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
-use uv_normalize::PackageName;
-use uv_pep508::MarkerTree;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep508::{MarkerEnvironment, MarkerTree};
 
 use crate::resolution::{RequirementsTxtDist, ResolutionGraphNode};
 use crate::{ResolverEnvironment, ResolverOutput};
@@ -19,6 +20,8 @@ pub struct DisplayResolutionGraph<'a> {
     resolution: &'a ResolverOutput,
     /// The resolver marker environment, used to determine the markers that apply to each package.
     env: &'a ResolverEnvironment,
+    /// If set, only the packages that apply to the given marker environment are included.
+    marker_env: Option<&'a MarkerEnvironment>,
     /// The packages to exclude from the output.
     no_emit_packages: &'a [PackageName],
     /// Whether to include hashes in the output.
@@ -72,6 +75,7 @@ impl<'a> DisplayResolutionGraph<'a> {
         Self {
             resolution: underlying,
             env,
+            marker_env: None,
             no_emit_packages,
             show_hashes,
             include_extras,
@@ -81,18 +85,42 @@ impl<'a> DisplayResolutionGraph<'a> {
             annotation_style,
         }
     }
+
+    /// Only include the packages that apply to the given marker environment, omitting their
+    /// markers from the output.
+    ///
+    /// Used to derive a platform-specific `requirements.txt` from a universal resolution.
+    #[must_use]
+    pub fn with_marker_environment(self, marker_env: &'a MarkerEnvironment) -> Self {
+        Self {
+            marker_env: Some(marker_env),
+            include_markers: false,
+            ..self
+        }
+    }
 }
 
 /// Write the graph in the `{name}=={version}` format of requirements.txt that pip uses.
 impl std::fmt::Display for DisplayResolutionGraph<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let marker_env = self.marker_env.or(self.env.marker_environment());
+
+        // Determine whether a package applies to the requested marker environment, if any.
+        let applies = |dist: &RequirementsTxtDist| {
+            self.marker_env
+                .is_none_or(|marker_env| dist.markers.evaluate(marker_env, &[]))
+        };
+
         // Determine the annotation sources for each package.
         let sources = if self.include_annotations {
             let mut sources = SourceAnnotations::default();
 
-            for requirement in self.resolution.requirements.iter().filter(|requirement| {
-                requirement.evaluate_markers(self.env.marker_environment(), &[])
-            }) {
+            for requirement in self
+                .resolution
+                .requirements
+                .iter()
+                .filter(|requirement| requirement.evaluate_markers(marker_env, &[]))
+            {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
                         &requirement.name,
@@ -105,9 +133,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 .resolution
                 .constraints
                 .requirements()
-                .filter(|requirement| {
-                    requirement.evaluate_markers(self.env.marker_environment(), &[])
-                })
+                .filter(|requirement| requirement.evaluate_markers(marker_env, &[]))
             {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
@@ -121,9 +147,7 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 .resolution
                 .overrides
                 .requirements()
-                .filter(|requirement| {
-                    requirement.evaluate_markers(self.env.marker_environment(), &[])
-                })
+                .filter(|requirement| requirement.evaluate_markers(marker_env, &[]))
             {
                 if let Some(origin) = &requirement.origin {
                     sources.add(
@@ -174,6 +198,9 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 if self.no_emit_packages.contains(name) {
                     return None;
                 }
+                if !applies(dist) {
+                    return None;
+                }
 
                 Some((index, dist))
             })
@@ -206,15 +233,29 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             // If enabled, include annotations to indicate the dependencies that requested each
             // package (e.g., `# via mypy`).
             if self.include_annotations {
-                // Display all dependents (i.e., all packages that depend on the current package).
+                // Display all dependents (i.e., all packages that depend on the current package),
+                // including the extra or dependency group through which the dependency was
+                // introduced, if any (e.g., `# via mypkg[http]` or `# via group:dev`).
                 let dependents = {
                     let mut dependents = graph
                         .edges_directed(index, Direction::Incoming)
-                        .map(|edge| &graph[edge.source()])
-                        .map(uv_distribution_types::Name::name)
+                        .filter(|edge| applies(&graph[edge.source()]))
+                        .flat_map(|edge| {
+                            let name = graph[edge.source()].name();
+                            edge.weight().iter().map(move |via| (name, via))
+                        })
                         .collect::<Vec<_>>();
                     dependents.sort_unstable();
                     dependents.dedup();
+                    let mut dependents = dependents
+                        .into_iter()
+                        .map(|(name, via)| match via {
+                            Via::Base => name.to_string(),
+                            Via::Extra(extra) => format!("{name}[{extra}]"),
+                            Via::Group(group) => format!("group:{group}"),
+                        })
+                        .collect::<Vec<_>>();
+                    dependents.dedup();
                     dependents
                 };
 
@@ -316,7 +357,20 @@ pub enum AnnotationStyle {
 /// We don't need the edge markers anymore since we switched to propagated markers.
 type IntermediatePetGraph<'dist> = Graph<DisplayResolutionGraphNode<'dist>, (), Directed>;
 
-type RequirementsTxtGraph<'dist> = Graph<RequirementsTxtDist<'dist>, (), Directed>;
+/// The reduced graph, in which each edge tracks the extras and dependency groups of the source
+/// package through which the dependency was introduced.
+type RequirementsTxtGraph<'dist> = Graph<RequirementsTxtDist<'dist>, BTreeSet<Via>, Directed>;
+
+/// The part of a source package through which a dependency was introduced.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Via {
+    /// The source package's base dependencies.
+    Base,
+    /// An extra of the source package (e.g., `mypkg[http]`).
+    Extra(ExtraName),
+    /// A dependency group of the source package (e.g., `group:dev`).
+    Group(GroupName),
+}
 
 /// Reduce the graph, such that all nodes for a single package are combined, regardless of
 /// the extras, as long as they have the same version and markers.
@@ -373,7 +427,7 @@ fn combine_extras<'dist>(graph: &IntermediatePetGraph<'dist>) -> RequirementsTxt
         let source = inverse[&version_marker(source_node)];
         let target = inverse[&version_marker(target_node)];
 
-        next.update_edge(source, target, ());
+        add_via_edge(&mut next, source, target, source_node);
     }
 
     next
@@ -430,8 +484,31 @@ fn strip_extras<'dist>(graph: &IntermediatePetGraph<'dist>) -> RequirementsTxtGr
         let source = inverse[&source_node.version_id()];
         let target = inverse[&target_node.version_id()];
 
-        next.update_edge(source, target, ());
+        add_via_edge(&mut next, source, target, source_node);
     }
 
     next
 }
+
+/// Add an edge to the reduced graph, recording the extra or dependency group of the source node
+/// (if any) through which the dependency was introduced.
+fn add_via_edge<'dist>(
+    graph: &mut RequirementsTxtGraph<'dist>,
+    source: NodeIndex,
+    target: NodeIndex,
+    source_node: &RequirementsTxtDist,
+) {
+    // Prior to reduction, each node has at most one extra or dependency group.
+    let via = if let Some(extra) = source_node.extras.first() {
+        Via::Extra(extra.clone())
+    } else if let Some(group) = &source_node.group {
+        Via::Group(group.clone())
+    } else {
+        Via::Base
+    };
+    if let Some(edge) = graph.find_edge(source, target) {
+        graph[edge].insert(via);
+    } else {
+        graph.add_edge(source, target, BTreeSet::from([via]));
+    }
+}
@@ -3,6 +3,7 @@ use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
 use indexmap::IndexSet;
+use owo_colors::OwoColorize;
 use petgraph::{
     Directed, Direction,
     graph::{Graph, NodeIndex},
@@ -13,13 +14,14 @@ use uv_configuration::{Constraints, Overrides};
 use uv_distribution::Metadata;
 use uv_distribution_types::{
     Dist, DistributionMetadata, Edge, IndexUrl, Name, Node, Requirement, RequiresPython,
-    ResolutionDiagnostic, ResolvedDist, VersionId, VersionOrUrlRef,
+    ResolutionDiagnostic, ResolvedDist, SourceDist, VersionId, VersionOrUrlRef,
 };
 use uv_git::GitResolver;
 use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::{Version, VersionSpecifier};
 use uv_pep508::{MarkerEnvironment, MarkerTree, MarkerTreeKind};
 use uv_pypi_types::{Conflicts, HashDigests, ParsedUrlError, VerbatimParsedUrl, Yanked};
+use uv_warnings::warn_user_once;
 
 use crate::graph_ops::{marker_reachability, simplify_conflict_markers};
 use crate::pins::FilePins;
@@ -221,6 +223,16 @@ impl ResolverOutput {
 
         simplify_conflict_markers(conflicts, &mut graph);
 
+        // If a hash algorithm is preferred, discard any other digests for distributions that
+        // provide a digest for the preferred algorithm.
+        if let Some(algorithm) = options.hash_algorithm {
+            for node in graph.node_weights_mut() {
+                if let ResolutionGraphNode::Dist(dist) = node {
+                    dist.hashes.retain_preferred(algorithm);
+                }
+            }
+        }
+
         // Discard any unreachable nodes.
         graph.retain_nodes(|graph, node| !graph[node].marker().is_false());
 
@@ -519,14 +531,50 @@ impl ResolverOutput {
         preferences: &Preferences,
         in_memory: &InMemoryIndex,
     ) -> HashDigests {
-        // 1. Look for hashes from the lockfile.
+        let available =
+            Self::get_available_hashes(name, index, url, version_id, version, in_memory);
+
+        // Prefer any hashes that were provided by the lockfile or the input requirements, as long
+        // as they're consistent with the hashes reported for the distribution.
         if let Some(digests) = preferences.match_hashes(name, version) {
             if !digests.is_empty() {
-                return HashDigests::from(digests);
+                let mut verified = Vec::with_capacity(digests.len());
+                for digest in digests {
+                    // If the index doesn't report a hash with the same algorithm, we can't verify
+                    // the preferred hash, so we retain it as-is.
+                    let verifiable = available
+                        .iter()
+                        .any(|available| available.algorithm == digest.algorithm);
+                    if !verifiable || available.iter().any(|available| available == digest) {
+                        verified.push(digest.clone());
+                    } else {
+                        warn_user_once!(
+                            "Discarding hash `{}` for `{}`, which doesn't match any distribution on the index",
+                            digest.to_string().cyan(),
+                            format!("{name}=={version}").cyan(),
+                        );
+                    }
+                }
+                if !verified.is_empty() {
+                    return HashDigests::from(verified);
+                }
             }
         }
 
-        // 2. Look for hashes for the distribution (i.e., the specific wheel or source distribution).
+        available
+    }
+
+    /// Identify the hashes reported for the [`VersionId`] by the distribution itself or by the
+    /// registry.
+    fn get_available_hashes(
+        name: &PackageName,
+        index: Option<&IndexUrl>,
+        url: Option<&VerbatimParsedUrl>,
+        version_id: &VersionId,
+        version: &Version,
+        in_memory: &InMemoryIndex,
+    ) -> HashDigests {
+        // 1. Look for hashes for the distribution (i.e., the specific wheel or source distribution).
         if let Some(metadata_response) = in_memory.distributions().get(version_id) {
             if let MetadataResponse::Found(ref archive) = *metadata_response {
                 let mut digests = archive.hashes.clone();
@@ -537,7 +585,7 @@ impl ResolverOutput {
             }
         }
 
-        // 3. Look for hashes from the registry, which are served at the package level.
+        // 2. Look for hashes from the registry, which are served at the package level.
         if url.is_none() {
             // Query the implicit and explicit indexes (lazily) for the hashes.
             let implicit_response = in_memory.implicit().get(name);
@@ -613,6 +661,21 @@ impl ResolverOutput {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Returns an iterator over the source distributions in the graph, along with their hashes.
+    pub fn source_dists(&self) -> impl Iterator<Item = (&SourceDist, &HashDigests)> {
+        self.dists()
+            .filter(|dist| dist.is_base())
+            .filter_map(|dist| match &dist.dist {
+                ResolvedDist::Installable {
+                    dist: installable, ..
+                } => match installable.as_ref() {
+                    Dist::Source(source) => Some((source, &dist.hashes)),
+                    Dist::Built(_) => None,
+                },
+                ResolvedDist::Installed { .. } => None,
+            })
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
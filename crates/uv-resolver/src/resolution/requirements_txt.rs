@@ -8,7 +8,7 @@ use uv_distribution_types::{
     DistributionMetadata, Name, RequiresPython, ResolvedDist, SimplifiedMarkerTree, Verbatim,
     VersionOrUrlRef,
 };
-use uv_normalize::{ExtraName, PackageName};
+use uv_normalize::{ExtraName, GroupName, PackageName};
 use uv_pep440::Version;
 use uv_pep508::{MarkerTree, Scheme, split_scheme};
 use uv_pypi_types::HashDigest;
@@ -23,6 +23,7 @@ pub(crate) struct RequirementsTxtDist<'dist> {
     pub(crate) hashes: &'dist [HashDigest],
     pub(crate) markers: MarkerTree,
     pub(crate) extras: Vec<ExtraName>,
+    pub(crate) group: Option<GroupName>,
 }
 
 impl<'dist> RequirementsTxtDist<'dist> {
@@ -187,6 +188,7 @@ impl<'dist> RequirementsTxtDist<'dist> {
             } else {
                 vec![]
             },
+            group: annotated.dev.clone(),
         }
     }
 }
@@ -1,3 +1,13 @@
+#[cfg(feature = "schemars")]
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use rustc_hash::FxHashMap;
+use serde::ser::SerializeMap;
+
+use uv_normalize::{GroupName, InvalidNameError, PackageName};
+
 use crate::resolver::{ForkMap, ForkSet};
 use crate::{DependencyMode, Manifest, ResolverEnvironment};
 
@@ -59,3 +69,224 @@ impl ResolutionStrategy {
         }
     }
 }
+
+/// The packages to which a [`ResolutionMode`] override applies.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResolutionTarget {
+    /// A specific package.
+    Package(PackageName),
+    /// All direct dependencies, i.e., `:direct:`.
+    Direct,
+    /// All dependencies declared in the given dependency group, i.e., `:group:dev:`.
+    Group(GroupName),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResolutionTargetError {
+    #[error(
+        "Unknown resolution target `{0}`; expected a package name, `:direct:`, or `:group:<name>:`"
+    )]
+    Unknown(String),
+    #[error(transparent)]
+    InvalidName(#[from] InvalidNameError),
+}
+
+impl FromStr for ResolutionTarget {
+    type Err = ResolutionTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == ":direct:" {
+            return Ok(Self::Direct);
+        }
+        if let Some(group) = s
+            .strip_prefix(":group:")
+            .and_then(|group| group.strip_suffix(':'))
+        {
+            return Ok(Self::Group(GroupName::from_str(group)?));
+        }
+        if s.starts_with(':') {
+            return Err(ResolutionTargetError::Unknown(s.to_string()));
+        }
+        Ok(Self::Package(PackageName::from_str(s)?))
+    }
+}
+
+impl std::fmt::Display for ResolutionTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Package(name) => write!(f, "{name}"),
+            Self::Direct => write!(f, ":direct:"),
+            Self::Group(group) => write!(f, ":group:{group}:"),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ResolutionTarget {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl serde::Serialize for ResolutionTarget {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Per-package [`ResolutionMode`] overrides, which take precedence over the global
+/// [`ResolutionMode`].
+///
+/// An override for a specific package takes precedence over an override for a dependency group,
+/// which in turn takes precedence over an override for all direct dependencies.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResolutionOverrides(BTreeMap<ResolutionTarget, ResolutionMode>);
+
+impl ResolutionOverrides {
+    /// Returns `true` if there are no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the targets and their [`ResolutionMode`].
+    pub fn iter(&self) -> impl Iterator<Item = (&ResolutionTarget, &ResolutionMode)> {
+        self.0.iter()
+    }
+
+    /// Replace any dependency group targets with the packages declared in the corresponding
+    /// groups, as returned by `members`.
+    ///
+    /// Packages with an explicit override retain it; if a package appears in multiple overridden
+    /// groups, the first group (in lexicographic order) wins.
+    #[must_use]
+    pub fn expand_groups<'a, I>(self, members: impl Fn(&GroupName) -> I) -> Self
+    where
+        I: IntoIterator<Item = &'a PackageName>,
+    {
+        let mut expanded = BTreeMap::default();
+        let mut groups = Vec::new();
+        for (target, mode) in self.0 {
+            match target {
+                ResolutionTarget::Group(group) => groups.push((group, mode)),
+                target => {
+                    expanded.insert(target, mode);
+                }
+            }
+        }
+        for (group, mode) in groups {
+            for package in members(&group) {
+                expanded
+                    .entry(ResolutionTarget::Package(package.clone()))
+                    .or_insert(mode);
+            }
+        }
+        Self(expanded)
+    }
+}
+
+impl FromIterator<(ResolutionTarget, ResolutionMode)> for ResolutionOverrides {
+    fn from_iter<T: IntoIterator<Item = (ResolutionTarget, ResolutionMode)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl serde::Serialize for ResolutionOverrides {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (target, mode) in &self.0 {
+            map.serialize_entry(target, mode)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ResolutionOverrides {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(Self(BTreeMap::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for ResolutionOverrides {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("ResolutionOverrides")
+    }
+
+    fn json_schema(generator: &mut schemars::generate::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "description": "A map from a package name, `:direct:`, or `:group:<name>:` to the resolution strategy to use for the matching packages.",
+            "additionalProperties": generator.subschema_for::<ResolutionMode>(),
+        })
+    }
+}
+
+/// Like [`ResolutionOverrides`], but with any additional information required to select a
+/// candidate, like the set of direct dependencies.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ResolutionOverrideStrategy {
+    /// The strategies for specific packages.
+    packages: FxHashMap<PackageName, ResolutionStrategy>,
+    /// The strategy for direct dependencies, along with the set of direct dependencies.
+    direct: Option<(ResolutionStrategy, ForkSet)>,
+}
+
+impl ResolutionOverrideStrategy {
+    pub(crate) fn from_overrides(
+        overrides: &ResolutionOverrides,
+        manifest: &Manifest,
+        env: &ResolverEnvironment,
+        dependencies: DependencyMode,
+    ) -> Self {
+        let mut packages = FxHashMap::default();
+        let mut direct = None;
+        for (target, mode) in overrides.iter() {
+            let strategy = ResolutionStrategy::from_mode(*mode, manifest, env, dependencies);
+            match target {
+                ResolutionTarget::Package(name) => {
+                    packages.insert(name.clone(), strategy);
+                }
+                ResolutionTarget::Direct => {
+                    let mut first_party = ForkMap::default();
+                    for requirement in manifest.user_requirements(env, dependencies) {
+                        first_party.add(&requirement, ());
+                    }
+                    direct = Some((strategy, first_party));
+                }
+                // Dependency groups are expanded to the packages they contain prior to
+                // resolution; see [`ResolutionOverrides::expand_groups`].
+                ResolutionTarget::Group(_) => {}
+            }
+        }
+        Self { packages, direct }
+    }
+
+    /// Returns the [`ResolutionStrategy`] that applies to the given package, if overridden.
+    pub(crate) fn get(
+        &self,
+        package_name: &PackageName,
+        env: &ResolverEnvironment,
+    ) -> Option<&ResolutionStrategy> {
+        if let Some(strategy) = self.packages.get(package_name) {
+            return Some(strategy);
+        }
+        if let Some((strategy, direct_dependencies)) = &self.direct {
+            if direct_dependencies.contains(package_name, env) {
+                return Some(strategy);
+            }
+        }
+        None
+    }
+}
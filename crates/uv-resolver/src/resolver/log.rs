@@ -0,0 +1,236 @@
+//! A structured log of the decisions made by the resolver.
+//!
+//! When enabled, each decision is written to the log file as a single line of JSON, such that the
+//! log can be inspected (or attached to a bug report) even if the resolution fails.
+
+use std::io::{self, LineWriter, Write};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use fs_err::File;
+use itertools::Itertools;
+use pubgrub::{Id, Range, State};
+use serde::Serialize;
+use tracing::warn;
+
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
+
+use crate::dependency_provider::UvDependencyProvider;
+use crate::pubgrub::PubGrubPackage;
+use crate::resolver::environment::ResolverEnvironment;
+use crate::resolver::{DerivationChainBuilder, Resolution};
+
+/// The destination of the resolution log, if enabled.
+static LOG: OnceLock<Mutex<LineWriter<File>>> = OnceLock::new();
+
+/// A newline-delimited JSON log of resolution events.
+#[derive(Debug)]
+pub struct ResolutionLog;
+
+impl ResolutionLog {
+    /// Write resolution events to the file at the given path, truncating any existing contents.
+    pub fn init(path: &Path) -> io::Result<()> {
+        let file = File::create(path)?;
+        if LOG.set(Mutex::new(LineWriter::new(file))).is_err() {
+            warn!("Resolution log was already initialized");
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the resolution log is enabled.
+    pub(crate) fn enabled() -> bool {
+        LOG.get().is_some()
+    }
+
+    /// Log the start of a fork.
+    pub(crate) fn fork(env: &ResolverEnvironment) {
+        if !Self::enabled() {
+            return;
+        }
+        Self::emit(&Event::Fork {
+            marker: env.end_user_fork_display(),
+        });
+    }
+
+    /// Log a version that was chosen as a candidate for a package.
+    pub(crate) fn candidate(
+        id: Id<PubGrubPackage>,
+        version: &Version,
+        state: &State<UvDependencyProvider>,
+    ) {
+        if !Self::enabled() {
+            return;
+        }
+        let package = &state.package_store[id];
+        let Some(name) = package.name() else {
+            return;
+        };
+        Self::emit(&Event::Candidate {
+            package: name,
+            extra: package.extra(),
+            group: package.dev(),
+            version,
+            chain: chain(id, version, state),
+        });
+    }
+
+    /// Log a rejected version of a package, or the rejection of all versions in the given range if
+    /// no specific version was considered.
+    pub(crate) fn rejected(
+        id: Id<PubGrubPackage>,
+        version: Option<&Version>,
+        range: &Range<Version>,
+        reason: impl ToString,
+        state: &State<UvDependencyProvider>,
+    ) {
+        if !Self::enabled() {
+            return;
+        }
+        let package = &state.package_store[id];
+        let Some(name) = package.name() else {
+            return;
+        };
+        Self::emit(&Event::Rejected {
+            package: name,
+            extra: package.extra(),
+            group: package.dev(),
+            version,
+            constraint: range.to_string(),
+            reason: reason.to_string(),
+            chain: version
+                .map(|version| chain(id, version, state))
+                .unwrap_or_default(),
+        });
+    }
+
+    /// Log a backtrack away from a frequently conflicting package.
+    pub(crate) fn backtrack(package: &PubGrubPackage, decisions: u32) {
+        if !Self::enabled() {
+            return;
+        }
+        let Some(name) = package.name() else {
+            return;
+        };
+        Self::emit(&Event::Backtrack {
+            package: name,
+            decisions,
+        });
+    }
+
+    /// Log the versions selected for a completed fork.
+    pub(crate) fn selected(resolution: &Resolution) {
+        if !Self::enabled() {
+            return;
+        }
+        let marker = resolution.env.end_user_fork_display();
+        for (package, version) in resolution.nodes.iter().sorted_by(|(a, _), (b, _)| {
+            (&a.name, &a.extra, &a.dev).cmp(&(&b.name, &b.extra, &b.dev))
+        }) {
+            Self::emit(&Event::Selected {
+                package: &package.name,
+                extra: package.extra.as_ref(),
+                group: package.dev.as_ref(),
+                version,
+                marker: marker.as_deref(),
+            });
+        }
+    }
+
+    /// Log a fork for which no solution exists.
+    pub(crate) fn no_solution(env: &ResolverEnvironment) {
+        if !Self::enabled() {
+            return;
+        }
+        Self::emit(&Event::NoSolution {
+            marker: env.end_user_fork_display(),
+        });
+    }
+
+    fn emit(event: &Event) {
+        let Some(log) = LOG.get() else {
+            return;
+        };
+        let mut writer = log.lock().unwrap();
+        let result = serde_json::to_writer(&mut *writer, event)
+            .map_err(io::Error::from)
+            .and_then(|()| writer.write_all(b"\n"));
+        if let Err(err) = result {
+            warn!("Failed to write to resolution log: {err}");
+        }
+    }
+}
+
+/// An event in the resolution log.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+enum Event<'a> {
+    /// The resolver started solving a fork.
+    Fork { marker: Option<String> },
+    /// A version was chosen as a candidate for a package.
+    Candidate {
+        package: &'a PackageName,
+        extra: Option<&'a ExtraName>,
+        group: Option<&'a GroupName>,
+        version: &'a Version,
+        chain: Vec<Step>,
+    },
+    /// A version (or range of versions) was rejected.
+    Rejected {
+        package: &'a PackageName,
+        extra: Option<&'a ExtraName>,
+        group: Option<&'a GroupName>,
+        version: Option<&'a Version>,
+        constraint: String,
+        reason: String,
+        chain: Vec<Step>,
+    },
+    /// The resolver backtracked after repeated conflicts on a package.
+    Backtrack {
+        package: &'a PackageName,
+        decisions: u32,
+    },
+    /// A version was selected in the final resolution of a fork.
+    Selected {
+        package: &'a PackageName,
+        extra: Option<&'a ExtraName>,
+        group: Option<&'a GroupName>,
+        version: &'a Version,
+        marker: Option<&'a str>,
+    },
+    /// No solution exists for a fork.
+    NoSolution { marker: Option<String> },
+}
+
+/// A step in the chain of requirements that led to a package.
+#[derive(Debug, Serialize)]
+struct Step {
+    package: PackageName,
+    extra: Option<ExtraName>,
+    group: Option<GroupName>,
+    version: Option<Version>,
+    /// The constraint that the package imposes on the next step in the chain.
+    constraint: String,
+}
+
+/// Compute the chain of requirements from the root to the given package.
+fn chain(
+    id: Id<PubGrubPackage>,
+    version: &Version,
+    state: &State<UvDependencyProvider>,
+) -> Vec<Step> {
+    DerivationChainBuilder::from_state(id, version, state)
+        .map(|chain| {
+            chain
+                .into_iter()
+                .map(|step| Step {
+                    constraint: step.range.to_string(),
+                    package: step.name,
+                    extra: step.extra,
+                    group: step.group,
+                    version: step.version,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
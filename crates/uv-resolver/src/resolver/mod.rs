@@ -67,6 +67,7 @@ use crate::resolver::environment::{
 pub(crate) use crate::resolver::fork_map::{ForkMap, ForkSet};
 pub use crate::resolver::index::InMemoryIndex;
 use crate::resolver::indexes::Indexes;
+pub use crate::resolver::log::ResolutionLog;
 pub use crate::resolver::provider::{
     DefaultResolverProvider, MetadataResponse, PackageVersionsResult, ResolverProvider,
     VersionsResponse, WheelMetadataResult,
@@ -90,6 +91,7 @@ mod environment;
 mod fork_map;
 mod index;
 mod indexes;
+mod log;
 mod provider;
 mod reporter;
 mod system;
@@ -346,6 +348,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 let requires_python = state.python_requirement.target();
                 debug!("Solving {split} (requires-python: {requires_python:?})");
             }
+            ResolutionLog::fork(&state.env);
             let start = Instant::now();
             loop {
                 let highest_priority_pkg =
@@ -360,6 +363,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         match result {
                             Err(err) => {
                                 // If unit propagation failed, there is no solution.
+                                ResolutionLog::no_solution(&state.env);
                                 return Err(self.convert_no_solution_err(
                                     err,
                                     state.fork_urls,
@@ -425,6 +429,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             );
 
                             let resolution = state.into_resolution();
+                            ResolutionLog::selected(&resolution);
 
                             // Walk over the selected versions, and mark them as preferences. We have to
                             // add forks back as to not override the preferences from the lockfile for
@@ -535,6 +540,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         if let PubGrubPackageInner::Package { name, .. } = &**next_package {
                             // Check if the decision was due to the package being unavailable
                             if let Some(entry) = self.unavailable_packages.get(name) {
+                                ResolutionLog::rejected(
+                                    next_id,
+                                    None,
+                                    term_intersection.unwrap_positive(),
+                                    UnavailableReason::Package(entry.clone()),
+                                    &state.pubgrub,
+                                );
                                 state
                                     .pubgrub
                                     .add_incompatibility(Incompatibility::custom_term(
@@ -546,6 +558,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             }
                         }
 
+                        ResolutionLog::rejected(
+                            next_id,
+                            None,
+                            term_intersection.unwrap_positive(),
+                            "no versions of the package satisfy the constraint",
+                            &state.pubgrub,
+                        );
                         state
                             .pubgrub
                             .add_incompatibility(Incompatibility::no_versions(
@@ -562,11 +581,20 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                             continue 'FORK;
                         }
                         ResolverVersion::Unavailable(version, reason) => {
+                            ResolutionLog::rejected(
+                                next_id,
+                                Some(&version),
+                                term_intersection.unwrap_positive(),
+                                &reason,
+                                &state.pubgrub,
+                            );
                             state.add_unavailable_version(version, reason);
                             continue;
                         }
                     };
 
+                    ResolutionLog::candidate(next_id, &version, &state.pubgrub);
+
                     // Only consider registry packages for prefetch.
                     if url.is_none() {
                         state.prefetcher.prefetch_batches(
@@ -620,6 +648,22 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                 match forked_deps {
                     ForkedDependencies::Unavailable(reason) => {
+                        if ResolutionLog::enabled() {
+                            let range = state
+                                .pubgrub
+                                .partial_solution
+                                .term_intersection_for_package(next_id)
+                                .map(|term| term.unwrap_positive().clone())
+                                .unwrap_or_else(Range::full);
+                            ResolutionLog::rejected(
+                                next_id,
+                                Some(&version),
+                                &range,
+                                &reason,
+                                &state.pubgrub,
+                            );
+                        }
+
                         // Then here, if we get a reason that we consider unrecoverable, we should
                         // show the derivation chain.
                         state
@@ -775,6 +819,10 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 let backtrack_level = state.pubgrub.backtrack_package(package);
                 if let Some(backtrack_level) = backtrack_level {
                     debug!("Backtracked {backtrack_level} decisions");
+                    ResolutionLog::backtrack(
+                        &state.pubgrub.package_store[package],
+                        backtrack_level,
+                    );
                 } else {
                     debug!(
                         "Package {} is not decided, cannot backtrack",
@@ -2574,6 +2622,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
         let mut available_indexes = FxHashMap::default();
         let mut available_versions = FxHashMap::default();
+        let mut source_only_packages = BTreeMap::default();
         for package in err.packages() {
             let Some(name) = package.name() else { continue };
             if !visited.contains(name) {
@@ -2592,6 +2641,13 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             };
             if let Some(response) = versions_response {
                 if let VersionsResponse::Found(ref version_maps) = *response {
+                    // Track the versions that only provide a source distribution, if building
+                    // from source is disabled for all packages.
+                    let no_build = self.options.build_options.no_build_all()
+                        && self.options.build_options.no_build_package(name);
+                    let mut has_wheels = false;
+                    let mut source_only_versions = BTreeSet::new();
+
                     // Track the available versions, across all indexes.
                     for version_map in version_maps {
                         let package_versions = available_versions
@@ -2615,10 +2671,27 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                                 }
                             }
 
+                            if no_build && !has_wheels {
+                                if let Some(prioritized_dist) = dists.prioritized_dist() {
+                                    if prioritized_dist.best_wheel().is_some() {
+                                        has_wheels = true;
+                                    } else if matches!(
+                                        prioritized_dist.incompatible_source(),
+                                        Some(IncompatibleSource::NoBuild)
+                                    ) {
+                                        source_only_versions.insert(version.clone());
+                                    }
+                                }
+                            }
+
                             package_versions.insert(version.clone());
                         }
                     }
 
+                    if !has_wheels && !source_only_versions.is_empty() {
+                        source_only_packages.insert(name.clone(), source_only_versions);
+                    }
+
                     // Track the indexes in which the package is available.
                     available_indexes
                         .entry(name.clone())
@@ -2643,6 +2716,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             self.capabilities.clone(),
             unavailable_packages,
             incomplete_packages,
+            source_only_packages,
             fork_urls,
             fork_indexes,
             env,
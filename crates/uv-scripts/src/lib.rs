@@ -9,7 +9,7 @@ use serde::Deserialize;
 use thiserror::Error;
 use url::Url;
 
-use uv_configuration::SourceStrategy;
+use uv_configuration::{ScriptTemplate, SourceStrategy};
 use uv_pep440::VersionSpecifiers;
 use uv_pep508::PackageName;
 use uv_pypi_types::VerbatimParsedUrl;
@@ -266,10 +266,16 @@ impl Pep723Script {
     }
 
     /// Create a PEP 723 script at the given path.
+    ///
+    /// If the existing contents already include a metadata block, it is replaced; otherwise, a
+    /// metadata block is prepended to the existing contents. If there are no existing contents,
+    /// the script body is generated from the given [`ScriptTemplate`].
     pub async fn create(
         file: impl AsRef<Path>,
         requires_python: &VersionSpecifiers,
+        dependencies: &[uv_pep508::Requirement<VerbatimParsedUrl>],
         existing_contents: Option<Vec<u8>>,
+        template: ScriptTemplate,
     ) -> Result<(), Pep723Error> {
         let file = file.as_ref();
 
@@ -278,46 +284,85 @@ impl Pep723Script {
             .and_then(|name| name.to_str())
             .ok_or_else(|| Pep723Error::InvalidFilename(file.to_string_lossy().to_string()))?;
 
+        // Format the dependencies as `uv add --script` would, such that subsequent edits don't
+        // reformat the array.
+        let dependencies = if dependencies.is_empty() {
+            "[]".to_string()
+        } else {
+            let mut array = String::from("[\n");
+            for requirement in dependencies {
+                array.push_str("    ");
+                array.push_str(&toml::Value::String(requirement.to_string()).to_string());
+                array.push_str(",\n");
+            }
+            array.push(']');
+            array
+        };
+
         let default_metadata = indoc::formatdoc! {r#"
             requires-python = "{requires_python}"
-            dependencies = []
+            dependencies = {dependencies}
             "#,
         };
         let metadata = serialize_metadata(&default_metadata);
 
         let script = if let Some(existing_contents) = existing_contents {
-            let (mut shebang, contents) = extract_shebang(&existing_contents)?;
-            if !shebang.is_empty() {
-                shebang.push_str("\n#\n");
-                // If the shebang doesn't contain `uv`, it's probably something like
-                // `#! /usr/bin/env python`, which isn't going to respect the inline metadata.
-                // Issue a warning for users who might not know that.
-                // TODO: There are a lot of mistakes we could consider detecting here, like
-                // `uv run` without `--script` when the file doesn't end in `.py`.
-                if !regex::Regex::new(r"\buv\b").unwrap().is_match(&shebang) {
-                    warn_user!(
-                        "If you execute {} directly, it might ignore its inline metadata.\nConsider replacing its shebang with: {}",
-                        file.to_string_lossy().cyan(),
-                        "#!/usr/bin/env -S uv run --script".cyan(),
-                    );
+            if let Some(tag) = ScriptTag::parse(&existing_contents)? {
+                format!("{}{metadata}{}", tag.prelude, tag.postlude)
+            } else {
+                let (mut shebang, contents) = extract_shebang(&existing_contents)?;
+                if !shebang.is_empty() {
+                    shebang.push_str("\n#\n");
+                    // If the shebang doesn't contain `uv`, it's probably something like
+                    // `#! /usr/bin/env python`, which isn't going to respect the inline metadata.
+                    // Issue a warning for users who might not know that.
+                    // TODO: There are a lot of mistakes we could consider detecting here, like
+                    // `uv run` without `--script` when the file doesn't end in `.py`.
+                    if !regex::Regex::new(r"\buv\b").unwrap().is_match(&shebang) {
+                        warn_user!(
+                            "If you execute {} directly, it might ignore its inline metadata.\nConsider replacing its shebang with: {}",
+                            file.to_string_lossy().cyan(),
+                            "#!/usr/bin/env -S uv run --script".cyan(),
+                        );
+                    }
                 }
+                indoc::formatdoc! {r"
+                {shebang}{metadata}
+                {contents}" }
             }
-            indoc::formatdoc! {r"
-            {shebang}{metadata}
-            {contents}" }
         } else {
-            indoc::formatdoc! {r#"
-            {metadata}
+            match template {
+                ScriptTemplate::Default => indoc::formatdoc! {r#"
+                    {metadata}
+
+                    def main() -> None:
+                        print("Hello from {name}!")
 
-            def main() -> None:
-                print("Hello from {name}!")
+
+                    if __name__ == "__main__":
+                        main()
+                "#,
+                    metadata = metadata,
+                    name = script_name,
+                },
+                ScriptTemplate::Cli => indoc::formatdoc! {r#"
+                    {metadata}
+                    import argparse
 
 
-            if __name__ == "__main__":
-                main()
-        "#,
-                metadata = metadata,
-                name = script_name,
+                    def main() -> None:
+                        parser = argparse.ArgumentParser(prog="{name}")
+                        parser.add_argument("--name", default="world", help="who to greet")
+                        args = parser.parse_args()
+                        print(f"Hello, {{args.name}}!")
+
+
+                    if __name__ == "__main__":
+                        main()
+                "#,
+                    metadata = metadata,
+                    name = script_name,
+                },
             }
         };
 
@@ -4,19 +4,21 @@ use std::{collections::BTreeMap, num::NonZeroUsize};
 use url::Url;
 
 use uv_configuration::{
-    ConfigSettings, ExportFormat, IndexStrategy, KeyringProviderType, PackageConfigSettings,
+    BuildOutputSize, BuildTimeout, ConfigSettings, ExecutableName, ExportFormat,
+    HashAlgorithmPreference, IndexStrategy, KeyringProviderType, PackageConfigSettings,
     RequiredVersion, TargetTriple, TrustedPublishing,
 };
 use uv_distribution_types::{
     ExtraBuildVariables, Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex,
 };
 use uv_install_wheel::LinkMode;
+use uv_normalize::PackageName;
 use uv_pypi_types::{SchemaConflicts, SupportedEnvironments};
 use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    PrereleaseMode, ResolutionMode, ResolutionOverrides,
 };
 use uv_torch::TorchMode;
 use uv_workspace::pyproject::ExtraBuildDependencies;
@@ -83,10 +85,13 @@ macro_rules! impl_combine_or {
 
 impl_combine_or!(AddBoundsKind);
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(BuildOutputSize);
+impl_combine_or!(BuildTimeout);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(ExcludeNewerTimestamp);
 impl_combine_or!(ExportFormat);
 impl_combine_or!(ForkStrategy);
+impl_combine_or!(HashAlgorithmPreference);
 impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
 impl_combine_or!(IndexUrl);
@@ -104,6 +109,7 @@ impl_combine_or!(PythonPreference);
 impl_combine_or!(PythonVersion);
 impl_combine_or!(RequiredVersion);
 impl_combine_or!(ResolutionMode);
+impl_combine_or!(ResolutionOverrides);
 impl_combine_or!(SchemaConflicts);
 impl_combine_or!(String);
 impl_combine_or!(SupportedEnvironments);
@@ -142,6 +148,51 @@ impl<K: Ord, T> Combine for Option<BTreeMap<K, Vec<T>>> {
     }
 }
 
+impl Combine for Option<BTreeMap<ExecutableName, PackageName>> {
+    /// Combine two maps of tool aliases, preferring the entries in `self` for any shared key.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (key, value) in b {
+                    a.entry(key).or_insert(value);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<BTreeMap<PackageName, BuildTimeout>> {
+    /// Combine two maps of per-package build timeouts, preferring the entries in `self` for any shared key.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (key, value) in b {
+                    a.entry(key).or_insert(value);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl Combine for Option<BTreeMap<PackageName, BuildOutputSize>> {
+    /// Combine two maps of per-package build output sizes, preferring the entries in `self` for any shared key.
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(mut a), Some(b)) => {
+                for (key, value) in b {
+                    a.entry(key).or_insert(value);
+                }
+                Some(a)
+            }
+            (a, b) => a.or(b),
+        }
+    }
+}
+
 impl Combine for Option<ExcludeNewerPackage> {
     /// Combine two [`ExcludeNewerPackage`] instances by merging them, with the values in `self` taking precedence.
     fn combine(self, other: Self) -> Self {
@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 
 use uv_dirs::{system_config_file, user_config_dir};
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
 
@@ -21,6 +22,23 @@ impl FilesystemOptions {
     pub fn into_options(self) -> Options {
         self.0
     }
+
+    /// Mark the [`FilesystemOptions`] as user-level configuration.
+    ///
+    /// User-level `constraint-dependencies` and `override-dependencies` are layered beneath any
+    /// project-level declarations, rather than being replaced by them. If `no_user_constraints` is
+    /// set, they're discarded entirely.
+    #[must_use]
+    pub fn into_user_layer(self, no_user_constraints: bool) -> Self {
+        let mut options = self.0;
+        let constraint_dependencies = options.constraint_dependencies.take();
+        let override_dependencies = options.override_dependencies.take();
+        if !no_user_constraints {
+            options.user_constraint_dependencies = constraint_dependencies;
+            options.user_override_dependencies = override_dependencies;
+        }
+        Self(options)
+    }
 }
 
 impl Deref for FilesystemOptions {
@@ -45,6 +63,7 @@ impl FilesystemOptions {
             Ok(options) => {
                 tracing::debug!("Found user configuration in: `{}`", file.display());
                 validate_uv_toml(&file, &options)?;
+                validate_build_options(&file, &options)?;
                 Ok(Some(Self(options)))
             }
             Err(Error::Io(err))
@@ -69,6 +88,7 @@ impl FilesystemOptions {
         tracing::debug!("Found system configuration in: `{}`", file.display());
         let options = read_file(&file)?;
         validate_uv_toml(&file, &options)?;
+        validate_build_options(&file, &options)?;
         Ok(Some(Self(options)))
     }
 
@@ -76,7 +96,16 @@ impl FilesystemOptions {
     ///
     /// The search starts at the given path and goes up the directory tree until a `uv.toml` file or
     /// `pyproject.toml` file is found.
-    pub fn find(path: &Path) -> Result<Option<Self>, Error> {
+    ///
+    /// If `bounded` is set, the search stops at the given workspace root or at the root of the
+    /// enclosing version control repository (i.e., the first directory that contains a `.git`
+    /// entry), whichever comes first, such that configuration files outside the workspace or
+    /// repository are never applied.
+    pub fn find(
+        path: &Path,
+        workspace_root: Option<&Path>,
+        bounded: bool,
+    ) -> Result<Option<Self>, Error> {
         for ancestor in path.ancestors() {
             match Self::from_directory(ancestor) {
                 Ok(Some(options)) => {
@@ -98,6 +127,23 @@ impl FilesystemOptions {
                     return Err(err);
                 }
             }
+
+            if bounded {
+                if workspace_root.is_some_and(|root| root == ancestor) {
+                    tracing::debug!(
+                        "Stopping configuration discovery at workspace root: `{}`",
+                        ancestor.display()
+                    );
+                    break;
+                }
+                if ancestor.join(".git").exists() {
+                    tracing::debug!(
+                        "Stopping configuration discovery at repository root: `{}`",
+                        ancestor.display()
+                    );
+                    break;
+                }
+            }
         }
         Ok(None)
     }
@@ -128,6 +174,7 @@ impl FilesystemOptions {
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
                 validate_uv_toml(&path, &options)?;
+                validate_build_options(&path, &options)?;
                 return Ok(Some(Self(options)));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
@@ -159,6 +206,7 @@ impl FilesystemOptions {
                 let options = options.relative_to(&std::path::absolute(dir)?)?;
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
+                validate_build_options(&path, &options)?;
                 return Ok(Some(Self(options)));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
@@ -175,6 +223,7 @@ impl FilesystemOptions {
 
         let options = read_file(path)?;
         validate_uv_toml(path, &options)?;
+        validate_build_options(path, &options)?;
         Ok(Self(options))
     }
 }
@@ -206,14 +255,21 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
         install_mirrors: _,
         publish: _,
         add: _,
+        tool_run: _,
         pip: _,
         cache_keys: _,
         override_dependencies: _,
         constraint_dependencies: _,
         build_constraint_dependencies: _,
+        user_override_dependencies: _,
+        user_constraint_dependencies: _,
         environments,
         required_environments,
         conflicts,
+        compatible_tags,
+        upgrade_policy,
+        test_pythons,
+        compile_bytecode_exclude,
         workspace,
         sources,
         dev_dependencies,
@@ -229,6 +285,30 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     if conflicts.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "conflicts"));
     }
+    if compatible_tags.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "compatible-tags",
+        ));
+    }
+    if upgrade_policy.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "upgrade-policy",
+        ));
+    }
+    if test_pythons.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "test-pythons",
+        ));
+    }
+    if compile_bytecode_exclude.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "compile-bytecode-exclude",
+        ));
+    }
     if workspace.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "workspace"));
     }
@@ -280,6 +360,27 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validate that no package is both required to be built from source (`no-binary-package`) and
+/// forbidden from being built from source (`no-build-package`).
+fn validate_build_options(path: &Path, options: &Options) -> Result<(), Error> {
+    let (Some(no_binary_package), Some(no_build_package)) = (
+        options.top_level.no_binary_package.as_ref(),
+        options.top_level.no_build_package.as_ref(),
+    ) else {
+        return Ok(());
+    };
+    if let Some(package) = no_binary_package
+        .iter()
+        .find(|package| no_build_package.contains(package))
+    {
+        return Err(Error::ConflictingBuildOptions(
+            path.to_path_buf(),
+            package.clone(),
+        ));
+    }
+    Ok(())
+}
+
 /// Validate that an [`Options`] contains no fields that `uv.toml` would mask
 ///
 /// This is essentially the inverse of [`validated_uv_toml`][].
@@ -310,8 +411,12 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 index_strategy,
                 keyring_provider,
                 resolution,
+                resolution_overrides,
+                allow_prereleases,
                 prerelease,
                 fork_strategy,
+                prefer_local_versions,
+                hash_algorithm,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -319,6 +424,11 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 no_build_isolation_package,
                 extra_build_dependencies,
                 extra_build_variables,
+                build_timeout,
+                build_timeout_package,
+                build_max_output_size,
+                build_max_output_size_package,
+                no_build_timeout,
                 exclude_newer,
                 exclude_newer_package,
                 link_mode,
@@ -346,14 +456,21 @@ fn warn_uv_toml_masked_fields(options: &Options) {
                 check_url,
             },
         add: AddOptions { add_bounds },
+        tool_run: ToolRunOptions { tool_aliases },
         pip,
         cache_keys,
         override_dependencies,
         constraint_dependencies,
         build_constraint_dependencies,
+        user_override_dependencies: _,
+        user_constraint_dependencies: _,
         environments: _,
         required_environments: _,
         conflicts: _,
+        compatible_tags: _,
+        upgrade_policy: _,
+        test_pythons: _,
+        compile_bytecode_exclude: _,
         workspace: _,
         sources: _,
         dev_dependencies: _,
@@ -426,12 +543,24 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if resolution.is_some() {
         masked_fields.push("resolution");
     }
+    if resolution_overrides.is_some() {
+        masked_fields.push("resolution-overrides");
+    }
+    if allow_prereleases.is_some() {
+        masked_fields.push("allow-prereleases");
+    }
     if prerelease.is_some() {
         masked_fields.push("prerelease");
     }
     if fork_strategy.is_some() {
         masked_fields.push("fork-strategy");
     }
+    if prefer_local_versions.is_some() {
+        masked_fields.push("prefer-local-versions");
+    }
+    if hash_algorithm.is_some() {
+        masked_fields.push("hash-algorithm");
+    }
     if dependency_metadata.is_some() {
         masked_fields.push("dependency-metadata");
     }
@@ -453,6 +582,21 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if extra_build_variables.is_some() {
         masked_fields.push("extra-build-variables");
     }
+    if build_timeout.is_some() {
+        masked_fields.push("build-timeout");
+    }
+    if build_timeout_package.is_some() {
+        masked_fields.push("build-timeout-package");
+    }
+    if build_max_output_size.is_some() {
+        masked_fields.push("build-max-output-size");
+    }
+    if build_max_output_size_package.is_some() {
+        masked_fields.push("build-max-output-size-package");
+    }
+    if no_build_timeout.is_some() {
+        masked_fields.push("no-build-timeout");
+    }
     if exclude_newer.is_some() {
         masked_fields.push("exclude-newer");
     }
@@ -513,6 +657,9 @@ fn warn_uv_toml_masked_fields(options: &Options) {
     if add_bounds.is_some() {
         masked_fields.push("add-bounds");
     }
+    if tool_aliases.is_some() {
+        masked_fields.push("tool-aliases");
+    }
     if pip.is_some() {
         masked_fields.push("pip");
     }
@@ -554,6 +701,9 @@ pub enum Error {
     #[error("Failed to parse: `{}`. The `{}` field is not allowed in a `uv.toml` file. `{}` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.", _0.user_display(), _1, _1)]
     PyprojectOnlyField(PathBuf, &'static str),
 
+    #[error("Failed to parse: `{}`. The package `{}` cannot be listed in both `no-binary-package` and `no-build-package` (or `only-binary-package`).", _0.user_display(), _1)]
+    ConflictingBuildOptions(PathBuf, PackageName),
+
     #[error("Failed to parse environment variable `{name}` with invalid value `{value}`: {err}")]
     InvalidEnvironmentVariable {
         name: String,
@@ -1,11 +1,12 @@
-use std::{fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
+use std::{collections::BTreeMap, fmt::Debug, num::NonZeroUsize, path::Path, path::PathBuf};
 
 use serde::{Deserialize, Serialize};
 
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageConfigSettings,
-    PackageNameSpecifier, RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing,
+    BuildOutputSize, BuildTimeout, ConfigSettings, ExecutableName, HashAlgorithmPreference,
+    IndexStrategy, KeyringProviderType, PackageConfigSettings, PackageNameSpecifier,
+    RequiredVersion, TargetTriple, TrustedHost, TrustedPublishing,
 };
 use uv_distribution_types::{
     ExtraBuildVariables, Index, IndexUrl, IndexUrlError, PipExtraIndex, PipFindLinks, PipIndex,
@@ -20,7 +21,7 @@ use uv_python::{PythonDownloads, PythonPreference, PythonVersion};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, ExcludeNewer, ExcludeNewerPackage, ExcludeNewerTimestamp, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    PrereleaseMode, ResolutionMode, ResolutionOverrides,
 };
 use uv_static::EnvVars;
 use uv_torch::TorchMode;
@@ -43,9 +44,9 @@ pub(crate) struct Tools {
 /// A `[tool.uv]` section.
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize, CombineOptions, OptionsMetadata)]
-#[serde(from = "OptionsWire", rename_all = "kebab-case")]
+#[serde(try_from = "OptionsWire", rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
-#[cfg_attr(feature = "schemars", schemars(!from))]
+#[cfg_attr(feature = "schemars", schemars(!try_from))]
 pub struct Options {
     #[serde(flatten)]
     pub globals: GlobalOptions,
@@ -62,6 +63,9 @@ pub struct Options {
     #[serde(flatten)]
     pub add: AddOptions,
 
+    #[serde(flatten)]
+    pub tool_run: ToolRunOptions,
+
     #[option_group]
     pub pip: Option<PipOptions>,
 
@@ -121,6 +125,22 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub build_constraint_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
 
+    /// The `override-dependencies` declared in the user-level configuration.
+    ///
+    /// Unlike other settings, these are layered beneath (rather than replaced by) any
+    /// project-level declarations. Populated via [`crate::FilesystemOptions::into_user_layer`].
+    #[serde(skip)]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub user_override_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+
+    /// The `constraint-dependencies` declared in the user-level configuration.
+    ///
+    /// Unlike other settings, these are layered beneath (rather than replaced by) any
+    /// project-level declarations. Populated via [`crate::FilesystemOptions::into_user_layer`].
+    #[serde(skip)]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub user_constraint_dependencies: Option<Vec<Requirement<VerbatimParsedUrl>>>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub environments: Option<SupportedEnvironments>,
 
@@ -133,6 +153,18 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub conflicts: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub compatible_tags: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub upgrade_policy: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub test_pythons: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub compile_bytecode_exclude: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub workspace: Option<serde::de::IgnoredAny>,
 
@@ -176,6 +208,42 @@ impl Options {
             ..self
         })
     }
+
+    /// Return the `constraint-dependencies`, with any user-level constraints layered beneath those
+    /// declared by the project.
+    pub fn layered_constraint_dependencies(&self) -> Vec<Requirement<VerbatimParsedUrl>> {
+        layer_requirements(
+            self.constraint_dependencies.as_deref(),
+            self.user_constraint_dependencies.as_deref(),
+        )
+    }
+
+    /// Return the `override-dependencies`, with any user-level overrides layered beneath those
+    /// declared by the project.
+    pub fn layered_override_dependencies(&self) -> Vec<Requirement<VerbatimParsedUrl>> {
+        layer_requirements(
+            self.override_dependencies.as_deref(),
+            self.user_override_dependencies.as_deref(),
+        )
+    }
+}
+
+/// Layer the user-level requirements beneath the project-level requirements, such that a user-level
+/// requirement is omitted if the project declares a requirement for the same package.
+pub fn layer_requirements(
+    project: Option<&[Requirement<VerbatimParsedUrl>]>,
+    user: Option<&[Requirement<VerbatimParsedUrl>]>,
+) -> Vec<Requirement<VerbatimParsedUrl>> {
+    let project = project.unwrap_or_default();
+    project
+        .iter()
+        .chain(user.unwrap_or_default().iter().filter(|requirement| {
+            !project
+                .iter()
+                .any(|existing| existing.name == requirement.name)
+        }))
+        .cloned()
+        .collect()
 }
 
 /// Global settings, relevant to all invocations.
@@ -361,8 +429,12 @@ pub struct ResolverOptions {
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub resolution: Option<ResolutionMode>,
+    pub resolution_overrides: Option<ResolutionOverrides>,
+    pub allow_prereleases: Option<Vec<PackageName>>,
     pub prerelease: Option<PrereleaseMode>,
     pub fork_strategy: Option<ForkStrategy>,
+    pub prefer_local_versions: Option<bool>,
+    pub hash_algorithm: Option<HashAlgorithmPreference>,
     pub dependency_metadata: Option<Vec<StaticMetadata>>,
     pub config_settings: Option<ConfigSettings>,
     pub config_settings_package: Option<PackageConfigSettings>,
@@ -378,6 +450,11 @@ pub struct ResolverOptions {
     pub no_build_isolation_package: Option<Vec<PackageName>>,
     pub extra_build_dependencies: Option<ExtraBuildDependencies>,
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    pub build_timeout: Option<BuildTimeout>,
+    pub build_timeout_package: Option<BTreeMap<PackageName, BuildTimeout>>,
+    pub build_max_output_size: Option<BuildOutputSize>,
+    pub build_max_output_size_package: Option<BTreeMap<PackageName, BuildOutputSize>>,
+    pub no_build_timeout: Option<bool>,
     pub no_sources: Option<bool>,
 }
 
@@ -528,6 +605,28 @@ pub struct ResolverInstallerOptions {
         possible_values = true
     )]
     pub resolution: Option<ResolutionMode>,
+    /// The strategy to use when selecting between the different compatible versions of specific
+    /// packages, overriding [`resolution`](#resolution).
+    ///
+    /// Each key is a package name, `:direct:` to select all direct dependencies, or
+    /// `:group:<name>:` to select all dependencies declared in the given dependency group of any
+    /// workspace member. An override for a specific package takes precedence over an override for
+    /// a dependency group, which in turn takes precedence over `:direct:`.
+    ///
+    /// For example, to test the declared lower bounds of a library's dependencies while using the
+    /// latest versions of its test dependencies, use `lowest` for `:direct:` and `highest` for
+    /// `:group:dev:`.
+    ///
+    /// The overrides are recorded in the lockfile, such that changing them invalidates the lock.
+    /// Dependency group overrides are only supported in projects.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            resolution-overrides = { ":direct:" = "lowest", ":group:dev:" = "highest" }
+        "#
+    )]
+    pub resolution_overrides: Option<ResolutionOverrides>,
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, uv will accept pre-releases for packages that _only_ publish pre-releases,
@@ -542,6 +641,22 @@ pub struct ResolverInstallerOptions {
         possible_values = true
     )]
     pub prerelease: Option<PrereleaseMode>,
+    /// Packages for which pre-release versions are allowed, regardless of
+    /// [`prerelease`](#prerelease).
+    ///
+    /// Only respected when `prerelease` is set to `forbid`, in which case uv will fail rather than
+    /// select a pre-release for any package that isn't listed here. Listed packages are treated as
+    /// if `prerelease` were set to `allow`.
+    ///
+    /// Only supported in projects.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            allow-prereleases = ["mylib"]
+        "#
+    )]
+    pub allow_prereleases: Option<Vec<PackageName>>,
     /// The strategy to use when selecting multiple versions of a given package across Python
     /// versions and platforms.
     ///
@@ -561,6 +676,37 @@ pub struct ResolverInstallerOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// Prefer versions with a local version segment (e.g., `1.2.3+corp1`) over the equivalent
+    /// public version (e.g., `1.2.3`).
+    ///
+    /// By default, uv compares candidate versions as defined by PEP 440, under which, e.g.,
+    /// `1.2.3` may be selected over `1.2.3+corp1` when resolving with `resolution = "lowest"`.
+    /// When enabled, uv will select a version with a local segment whenever one is available
+    /// for the selected public version.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            prefer-local-versions = true
+        "#
+    )]
+    pub prefer_local_versions: Option<bool>,
+    /// The hash algorithm to record for each artifact in the lockfile.
+    ///
+    /// When an artifact provides a digest for the preferred algorithm, uv records that digest;
+    /// otherwise, uv falls back to the strongest digest the artifact provides, such that a
+    /// lockfile may mix algorithms across artifacts.
+    ///
+    /// By default, uv records the strongest digest each artifact provides.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            hash-algorithm = "sha512"
+        "#,
+        possible_values = true
+    )]
+    pub hash_algorithm: Option<HashAlgorithmPreference>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -657,6 +803,65 @@ pub struct ResolverInstallerOptions {
         "#
     )]
     pub extra_build_variables: Option<ExtraBuildVariables>,
+    /// The maximum duration of each build backend hook invoked when building a source
+    /// distribution (e.g., `600s`, `10m`, or `1h`).
+    ///
+    /// If a build backend hook exceeds the timeout, the build process (and any processes it
+    /// spawned) will be terminated, and the build will fail with the tail of its output.
+    ///
+    /// By default, builds are not subject to a timeout.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-timeout = "600s"
+        "#
+    )]
+    pub build_timeout: Option<BuildTimeout>,
+    /// The maximum duration of each build backend hook for specific packages, overriding
+    /// `build-timeout`.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            build-timeout-package = { torch = "1h" }
+        "#
+    )]
+    pub build_timeout_package: Option<BTreeMap<PackageName, BuildTimeout>>,
+    /// The maximum disk space a source distribution build may consume in its temporary
+    /// directory (e.g., `512MB` or `10GiB`).
+    ///
+    /// If a build exceeds the limit, the build process will be terminated and its temporary
+    /// directory removed.
+    ///
+    /// By default, builds are not subject to a size limit.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            build-max-output-size = "10GB"
+        "#
+    )]
+    pub build_max_output_size: Option<BuildOutputSize>,
+    /// The maximum disk space a source distribution build may consume for specific packages,
+    /// overriding `build-max-output-size`.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            build-max-output-size-package = { torch = "50GB" }
+        "#
+    )]
+    pub build_max_output_size_package: Option<BTreeMap<PackageName, BuildOutputSize>>,
+    /// Disable the `build-timeout` and `build-max-output-size` limits.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            no-build-timeout = true
+        "#
+    )]
+    pub no_build_timeout: Option<bool>,
     /// Limit candidate packages to those that were uploaded prior to a given point in time.
     ///
     /// Accepts a superset of [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339.html) (e.g.,
@@ -782,6 +987,9 @@ pub struct ResolverInstallerOptions {
     )]
     pub no_build: Option<bool>,
     /// Don't build source distributions for a specific package.
+    ///
+    /// Also accepted as `only-binary-package`. A package may not be listed in both
+    /// `no-build-package` and `no-binary-package`.
     #[option(
         default = "[]",
         value_type = "list[str]",
@@ -1145,6 +1353,19 @@ pub struct PipOptions {
         "#
     )]
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
+    /// Allow building the given packages from source if no compatible wheel is available, even
+    /// when building from source is disabled (e.g., with `only-binary = [":all:"]`).
+    ///
+    /// Pre-built wheels are still preferred for these packages.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            only-binary = [":all:"]
+            allow-binary-fallback = ["ruff"]
+        "#
+    )]
+    pub allow_binary_fallback: Option<Vec<PackageName>>,
     /// Disable isolation when building source distributions.
     ///
     /// Assumes that build dependencies specified by [PEP 518](https://peps.python.org/pep-0518/)
@@ -1313,6 +1534,36 @@ pub struct PipOptions {
         possible_values = true
     )]
     pub fork_strategy: Option<ForkStrategy>,
+    /// Prefer versions with a local version segment (e.g., `1.2.3+corp1`) over the equivalent
+    /// public version (e.g., `1.2.3`).
+    ///
+    /// By default, uv compares candidate versions as defined by PEP 440, under which, e.g.,
+    /// `1.2.3` may be selected over `1.2.3+corp1` when resolving with `resolution = "lowest"`.
+    /// When enabled, uv will select a version with a local segment whenever one is available
+    /// for the selected public version.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            prefer-local-versions = true
+        "#
+    )]
+    pub prefer_local_versions: Option<bool>,
+    /// The hash algorithm to emit when generating hashes with `--generate-hashes`.
+    ///
+    /// When an artifact provides a digest for the preferred algorithm, uv emits only that digest;
+    /// otherwise, uv emits every digest the artifact provides.
+    ///
+    /// By default, uv emits every digest each artifact provides.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            hash-algorithm = "sha512"
+        "#,
+        possible_values = true
+    )]
+    pub hash_algorithm: Option<HashAlgorithmPreference>,
     /// Pre-defined static metadata for dependencies of the project (direct or transitive). When
     /// provided, enables the resolver to use the specified metadata instead of querying the
     /// registry or building the relevant package from source.
@@ -1525,7 +1776,8 @@ pub struct PipOptions {
         "#
     )]
     pub emit_find_links: Option<bool>,
-    /// Include `--no-binary` and `--only-binary` entries in the output file generated by `uv pip compile`.
+    /// Include `--no-binary`, `--only-binary`, and `--config-settings` entries in the output file
+    /// generated by `uv pip compile`.
     #[option(
         default = "false",
         value_type = "bool",
@@ -1613,10 +1865,11 @@ pub struct PipOptions {
     ///
     /// Hash-checking mode introduces a number of additional constraints:
     ///
-    /// - Git dependencies are not supported.
-    /// - Editable installations are not supported.
+    /// - Git dependencies are not supported, unless `allow-unhashable-sources` is enabled.
+    /// - Editable installations are not supported, unless `allow-unhashable-sources` is enabled.
     /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
-    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory (or
+    ///   `allow-unhashable-sources` is enabled).
     #[option(
         default = "false",
         value_type = "bool",
@@ -1625,6 +1878,32 @@ pub struct PipOptions {
         "#
     )]
     pub require_hashes: Option<bool>,
+    /// Require a matching hash for each requirement, reporting any that are missing.
+    ///
+    /// Like `require-hashes`, but rather than failing on the first requirement without a hash, uv
+    /// will resolve the requirements (including any transitive dependencies) and fail with a list
+    /// of every distribution that lacks a hash, along with its computed hashes, such that they can
+    /// be added to the requirements file.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            derive-hashes = true
+        "#
+    )]
+    pub derive_hashes: Option<bool>,
+    /// Allow Git dependencies and local directories when hashes are required.
+    ///
+    /// These requirements can't be hashed. With `allow-unhashable-sources`, they're exempt from
+    /// `require-hashes` (with a warning), though their dependencies must still be hashed.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            allow-unhashable-sources = true
+        "#
+    )]
+    pub allow_unhashable_sources: Option<bool>,
     /// Validate any hashes provided in the requirements file.
     ///
     /// Unlike `--require-hashes`, `--verify-hashes` does not require that all requirements have
@@ -1762,8 +2041,12 @@ impl From<ResolverInstallerOptions> for ResolverOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_overrides: value.resolution_overrides,
+            allow_prereleases: value.allow_prereleases,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prefer_local_versions: value.prefer_local_versions,
+            hash_algorithm: value.hash_algorithm,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -1787,6 +2070,11 @@ impl From<ResolverInstallerOptions> for ResolverOptions {
             no_build_isolation_package: value.no_build_isolation_package,
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_timeout: value.build_timeout,
+            build_timeout_package: value.build_timeout_package,
+            build_max_output_size: value.build_max_output_size,
+            build_max_output_size_package: value.build_max_output_size_package,
+            no_build_timeout: value.no_build_timeout,
             no_sources: value.no_sources,
         }
     }
@@ -1909,8 +2197,12 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             index_strategy: value.index_strategy,
             keyring_provider: value.keyring_provider,
             resolution: value.resolution,
+            resolution_overrides: None,
+            allow_prereleases: None,
             prerelease: value.prerelease,
             fork_strategy: value.fork_strategy,
+            prefer_local_versions: None,
+            hash_algorithm: None,
             dependency_metadata: value.dependency_metadata,
             config_settings: value.config_settings,
             config_settings_package: value.config_settings_package,
@@ -1918,6 +2210,11 @@ impl From<ToolOptions> for ResolverInstallerOptions {
             no_build_isolation_package: value.no_build_isolation_package,
             extra_build_dependencies: value.extra_build_dependencies,
             extra_build_variables: value.extra_build_variables,
+            build_timeout: None,
+            build_timeout_package: None,
+            build_max_output_size: None,
+            build_max_output_size_package: None,
+            no_build_timeout: None,
             exclude_newer: value.exclude_newer,
             exclude_newer_package: value.exclude_newer_package,
             link_mode: value.link_mode,
@@ -1965,8 +2262,12 @@ pub struct OptionsWire {
     keyring_provider: Option<KeyringProviderType>,
     allow_insecure_host: Option<Vec<TrustedHost>>,
     resolution: Option<ResolutionMode>,
+    resolution_overrides: Option<ResolutionOverrides>,
+    allow_prereleases: Option<Vec<PackageName>>,
     prerelease: Option<PrereleaseMode>,
     fork_strategy: Option<ForkStrategy>,
+    prefer_local_versions: Option<bool>,
+    hash_algorithm: Option<HashAlgorithmPreference>,
     dependency_metadata: Option<Vec<StaticMetadata>>,
     config_settings: Option<ConfigSettings>,
     config_settings_package: Option<PackageConfigSettings>,
@@ -1974,6 +2275,11 @@ pub struct OptionsWire {
     no_build_isolation_package: Option<Vec<PackageName>>,
     extra_build_dependencies: Option<ExtraBuildDependencies>,
     extra_build_variables: Option<ExtraBuildVariables>,
+    build_timeout: Option<BuildTimeout>,
+    build_timeout_package: Option<BTreeMap<PackageName, BuildTimeout>>,
+    build_max_output_size: Option<BuildOutputSize>,
+    build_max_output_size_package: Option<BTreeMap<PackageName, BuildOutputSize>>,
+    no_build_timeout: Option<bool>,
     exclude_newer: Option<ExcludeNewerTimestamp>,
     exclude_newer_package: Option<ExcludeNewerPackage>,
     link_mode: Option<LinkMode>,
@@ -1985,6 +2291,7 @@ pub struct OptionsWire {
     reinstall_package: Option<Vec<PackageName>>,
     no_build: Option<bool>,
     no_build_package: Option<Vec<PackageName>>,
+    only_binary_package: Option<Vec<PackageName>>,
     no_binary: Option<bool>,
     no_binary_package: Option<Vec<PackageName>>,
 
@@ -2004,6 +2311,10 @@ pub struct OptionsWire {
     // add: AddOptions
     add_bounds: Option<AddBoundsKind>,
 
+    // #[serde(flatten)]
+    // tool_run: ToolRunOptions
+    tool_aliases: Option<BTreeMap<ExecutableName, PackageName>>,
+
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
 
@@ -2020,6 +2331,10 @@ pub struct OptionsWire {
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're only respected in `pyproject.toml` files, and should be rejected in `uv.toml` files.
     conflicts: Option<serde::de::IgnoredAny>,
+    compatible_tags: Option<serde::de::IgnoredAny>,
+    upgrade_policy: Option<serde::de::IgnoredAny>,
+    test_pythons: Option<serde::de::IgnoredAny>,
+    compile_bytecode_exclude: Option<serde::de::IgnoredAny>,
     workspace: Option<serde::de::IgnoredAny>,
     sources: Option<serde::de::IgnoredAny>,
     managed: Option<serde::de::IgnoredAny>,
@@ -2032,8 +2347,10 @@ pub struct OptionsWire {
     build_backend: Option<serde::de::IgnoredAny>,
 }
 
-impl From<OptionsWire> for Options {
-    fn from(value: OptionsWire) -> Self {
+impl TryFrom<OptionsWire> for Options {
+    type Error = String;
+
+    fn try_from(value: OptionsWire) -> Result<Self, Self::Error> {
         let OptionsWire {
             required_version,
             native_tls,
@@ -2058,8 +2375,12 @@ impl From<OptionsWire> for Options {
             keyring_provider,
             allow_insecure_host,
             resolution,
+            resolution_overrides,
+            allow_prereleases,
             prerelease,
             fork_strategy,
+            prefer_local_versions,
+            hash_algorithm,
             dependency_metadata,
             config_settings,
             config_settings_package,
@@ -2076,6 +2397,7 @@ impl From<OptionsWire> for Options {
             reinstall_package,
             no_build,
             no_build_package,
+            only_binary_package,
             no_binary,
             no_binary_package,
             pip,
@@ -2086,6 +2408,10 @@ impl From<OptionsWire> for Options {
             environments,
             required_environments,
             conflicts,
+            compatible_tags,
+            upgrade_policy,
+            test_pythons,
+            compile_bytecode_exclude,
             publish_url,
             trusted_publishing,
             check_url,
@@ -2095,15 +2421,31 @@ impl From<OptionsWire> for Options {
             dependency_groups,
             extra_build_dependencies,
             extra_build_variables,
+            build_timeout,
+            build_timeout_package,
+            build_max_output_size,
+            build_max_output_size_package,
+            no_build_timeout,
             dev_dependencies,
             managed,
             package,
             add_bounds: bounds,
+            tool_aliases,
             // Used by the build backend
             build_backend,
         } = value;
 
-        Self {
+        // `only-binary-package` is an alias for `no-build-package`.
+        let no_build_package = match (no_build_package, only_binary_package) {
+            (Some(_), Some(_)) => {
+                return Err(
+                    "`no-build-package` and `only-binary-package` are aliases for the same setting; provide only one of them".to_string(),
+                );
+            }
+            (no_build_package, only_binary_package) => no_build_package.or(only_binary_package),
+        };
+
+        Ok(Self {
             globals: GlobalOptions {
                 required_version,
                 native_tls,
@@ -2128,8 +2470,12 @@ impl From<OptionsWire> for Options {
                 index_strategy,
                 keyring_provider,
                 resolution,
+                resolution_overrides,
+                allow_prereleases,
                 prerelease,
                 fork_strategy,
+                prefer_local_versions,
+                hash_algorithm,
                 dependency_metadata,
                 config_settings,
                 config_settings_package,
@@ -2137,6 +2483,11 @@ impl From<OptionsWire> for Options {
                 no_build_isolation_package,
                 extra_build_dependencies,
                 extra_build_variables,
+                build_timeout,
+                build_timeout_package,
+                build_max_output_size,
+                build_max_output_size_package,
+                no_build_timeout,
                 exclude_newer,
                 exclude_newer_package,
                 link_mode,
@@ -2157,6 +2508,8 @@ impl From<OptionsWire> for Options {
             override_dependencies,
             constraint_dependencies,
             build_constraint_dependencies,
+            user_override_dependencies: None,
+            user_constraint_dependencies: None,
             environments,
             required_environments,
             install_mirrors: PythonInstallMirrors::resolve(
@@ -2165,12 +2518,17 @@ impl From<OptionsWire> for Options {
                 python_downloads_json_url,
             ),
             conflicts,
+            compatible_tags,
+            upgrade_policy,
+            test_pythons,
+            compile_bytecode_exclude,
             publish: PublishOptions {
                 publish_url,
                 trusted_publishing,
                 check_url,
             },
             add: AddOptions { add_bounds: bounds },
+            tool_run: ToolRunOptions { tool_aliases },
             workspace,
             sources,
             dev_dependencies,
@@ -2178,7 +2536,7 @@ impl From<OptionsWire> for Options {
             dependency_groups,
             managed,
             package,
-        }
+        })
     }
 }
 
@@ -2258,3 +2616,28 @@ pub struct AddOptions {
     )]
     pub add_bounds: Option<AddBoundsKind>,
 }
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, CombineOptions, OptionsMetadata)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ToolRunOptions {
+    /// A mapping from executable names to the packages that provide them.
+    ///
+    /// By default, `uv tool run` (and `uvx`) assume that an executable is provided by a package of
+    /// the same name. When an alias is defined for the executable, the aliased package is installed
+    /// instead, as if `--from` had been provided. For example, with
+    /// `tool-aliases = { http = "httpie" }`, `uvx http` is equivalent to `uvx --from httpie http`.
+    ///
+    /// uv includes a small set of built-in aliases for common mismatches (e.g., `http` is provided
+    /// by `httpie`); any alias defined here takes precedence over a built-in alias of the same name.
+    ///
+    /// Aliases are ignored when `--from` is provided.
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            tool-aliases = { http = "httpie", convert = "imagemagick-python" }
+        "#
+    )]
+    pub tool_aliases: Option<BTreeMap<ExecutableName, PackageName>>,
+}
@@ -53,6 +53,10 @@ impl EnvVars {
     /// `lowest-direct`, uv will install the lowest compatible versions of all direct dependencies.
     pub const UV_RESOLUTION: &'static str = "UV_RESOLUTION";
 
+    /// Equivalent to the `--resolution-log` command-line argument. If set, uv will write a
+    /// newline-delimited JSON log of the resolver's decisions to the given file.
+    pub const UV_RESOLUTION_LOG: &'static str = "UV_RESOLUTION_LOG";
+
     /// Equivalent to the `--prerelease` command-line argument. For example, if set to
     /// `allow`, uv will allow pre-release versions for all dependencies.
     pub const UV_PRERELEASE: &'static str = "UV_PRERELEASE";
@@ -61,6 +65,10 @@ impl EnvVars {
     /// resolution.
     pub const UV_FORK_STRATEGY: &'static str = "UV_FORK_STRATEGY";
 
+    /// Equivalent to the `--prefer-local-versions` command-line argument. If set to `true`, uv
+    /// will prefer versions with a local version segment over the equivalent public version.
+    pub const UV_PREFER_LOCAL_VERSIONS: &'static str = "UV_PREFER_LOCAL_VERSIONS";
+
     /// Equivalent to the `--system` command-line argument. If set to `true`, uv will
     /// use the first Python interpreter found in the system `PATH`.
     ///
@@ -136,6 +144,16 @@ impl EnvVars {
     /// directories.
     pub const UV_NO_CONFIG: &'static str = "UV_NO_CONFIG";
 
+    /// Equivalent to the `--no-config-boundary` command-line argument. If set, uv will continue
+    /// discovering configuration files above the workspace root and the root of the enclosing Git
+    /// repository.
+    pub const UV_NO_CONFIG_BOUNDARY: &'static str = "UV_NO_CONFIG_BOUNDARY";
+
+    /// Equivalent to the `--no-user-constraints` command-line argument. If set, uv will ignore
+    /// any `constraint-dependencies` and `override-dependencies` declared in the user-level
+    /// configuration file.
+    pub const UV_NO_USER_CONSTRAINTS: &'static str = "UV_NO_USER_CONSTRAINTS";
+
     /// Equivalent to the `--exclude-newer` command-line argument. If set, uv will
     /// exclude distributions published after the specified date.
     pub const UV_EXCLUDE_NEWER: &'static str = "UV_EXCLUDE_NEWER";
@@ -187,6 +205,10 @@ impl EnvVars {
     /// not build source distributions for the given space-delimited list of packages.
     pub const UV_NO_BUILD_PACKAGE: &'static str = "UV_NO_BUILD_PACKAGE";
 
+    /// Equivalent to the `--no-build-timeout` command-line argument. If set, uv will not
+    /// enforce the `build-timeout` or `build-max-output-size` limits.
+    pub const UV_NO_BUILD_TIMEOUT: &'static str = "UV_NO_BUILD_TIMEOUT";
+
     /// Equivalent to the `--publish-url` command-line argument. The URL of the upload
     /// endpoint of the index to use with `uv publish`.
     pub const UV_PUBLISH_URL: &'static str = "UV_PUBLISH_URL";
@@ -262,6 +284,10 @@ impl EnvVars {
     /// example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
 
+    /// Equivalent to the `--progress` command-line argument. If set to `json`, uv will emit
+    /// progress events as newline-delimited JSON on stderr, rather than displaying progress bars.
+    pub const UV_PROGRESS: &'static str = "UV_PROGRESS";
+
     /// Specifies the directory where uv stores managed tools.
     pub const UV_TOOL_DIR: &'static str = "UV_TOOL_DIR";
 
@@ -383,6 +409,15 @@ impl EnvVars {
     #[attr_hidden]
     pub const UV_LAST_TAG_DISTANCE: &'static str = "UV_LAST_TAG_DISTANCE";
 
+    /// Used to set the uv build date at build time via `build.rs`.
+    #[attr_hidden]
+    pub const UV_BUILD_DATE: &'static str = "UV_BUILD_DATE";
+
+    /// Used as the modification time of the files in distributions built with `uv build`, for
+    /// reproducible builds (equivalent to `--source-date-epoch`). Also fixes the uv build date when
+    /// building uv itself, via `build.rs`.
+    pub const SOURCE_DATE_EPOCH: &'static str = "SOURCE_DATE_EPOCH";
+
     /// Used to set the spawning/parent interpreter when using --system in the test suite.
     #[attr_hidden]
     pub const UV_INTERNAL__PARENT_INTERPRETER: &'static str = "UV_INTERNAL__PARENT_INTERPRETER";
@@ -445,6 +480,9 @@ impl EnvVars {
     /// The number of retries for HTTP requests. (default: 3)
     pub const UV_HTTP_RETRIES: &'static str = "UV_HTTP_RETRIES";
 
+    /// The base of the exponential backoff between retries of HTTP requests. (default: 2)
+    pub const UV_HTTP_RETRY_BACKOFF: &'static str = "UV_HTTP_RETRY_BACKOFF";
+
     /// Timeout (in seconds) for HTTP requests. Equivalent to `UV_HTTP_TIMEOUT`.
     pub const UV_REQUEST_TIMEOUT: &'static str = "UV_REQUEST_TIMEOUT";
 
@@ -515,6 +553,18 @@ impl EnvVars {
     /// The standard `HOME` env var.
     pub const HOME: &'static str = "HOME";
 
+    /// The standard `TMPDIR` env var, used to redirect the temporary files of build backends.
+    #[attr_hidden]
+    pub const TMPDIR: &'static str = "TMPDIR";
+
+    /// The standard `TEMP` env var, used to redirect the temporary files of build backends.
+    #[attr_hidden]
+    pub const TEMP: &'static str = "TEMP";
+
+    /// The standard `TMP` env var, used to redirect the temporary files of build backends.
+    #[attr_hidden]
+    pub const TMP: &'static str = "TMP";
+
     /// The standard `SHELL` posix env var.
     pub const SHELL: &'static str = "SHELL";
 
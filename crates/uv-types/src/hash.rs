@@ -1,17 +1,20 @@
+use std::collections::BTreeSet;
 use std::str::FromStr;
 use std::sync::Arc;
 
+use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
 
 use uv_configuration::HashCheckingMode;
 use uv_distribution_types::{
     DistributionMetadata, HashGeneration, HashPolicy, Name, Requirement, RequirementSource,
-    Resolution, UnresolvedRequirement, VersionId,
+    Resolution, ResolvedDist, UnresolvedRequirement, VersionId,
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_pypi_types::{HashDigest, HashDigests, HashError, ResolverMarkerEnvironment};
 use uv_redacted::DisplaySafeUrl;
+use uv_warnings::warn_user;
 
 #[derive(Debug, Default, Clone)]
 pub enum HashStrategy {
@@ -27,6 +30,9 @@ pub enum HashStrategy {
     /// Hashes should be validated against a pre-defined list of hashes.
     ///
     /// If necessary, hashes should be generated to ensure that the archive is valid.
+    ///
+    /// Distributions that are mapped to an empty list of hashes are exempt from hash-checking
+    /// (e.g., Git repositories and local directories, which can't be hashed).
     Require(Arc<FxHashMap<VersionId, Vec<HashDigest>>>),
 }
 
@@ -43,12 +49,7 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&distribution.version_id())
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
-            ),
+            Self::Require(hashes) => Self::require(hashes.get(&distribution.version_id())),
         }
     }
 
@@ -66,12 +67,9 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&VersionId::from_registry(name.clone(), version.clone()))
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
-            ),
+            Self::Require(hashes) => {
+                Self::require(hashes.get(&VersionId::from_registry(name.clone(), version.clone())))
+            }
         }
     }
 
@@ -87,12 +85,18 @@ impl HashStrategy {
                     HashPolicy::None
                 }
             }
-            Self::Require(hashes) => HashPolicy::Validate(
-                hashes
-                    .get(&VersionId::from_url(url))
-                    .map(Vec::as_slice)
-                    .unwrap_or_default(),
-            ),
+            Self::Require(hashes) => Self::require(hashes.get(&VersionId::from_url(url))),
+        }
+    }
+
+    /// Return the [`HashPolicy`] for a distribution under [`HashStrategy::Require`], given its
+    /// required hashes, if any.
+    fn require(hashes: Option<&Vec<HashDigest>>) -> HashPolicy<'_> {
+        match hashes {
+            // The distribution is exempt from hash-checking.
+            Some(hashes) if hashes.is_empty() => HashPolicy::None,
+            Some(hashes) => HashPolicy::Validate(hashes.as_slice()),
+            None => HashPolicy::Validate(&[]),
         }
     }
 
@@ -124,11 +128,15 @@ impl HashStrategy {
     /// that reference the environment as true. In other words, it does
     /// environment independent expression evaluation. (Which in turn devolves
     /// to "only evaluate marker expressions that reference an extra name.")
+    ///
+    /// If `allow_unhashable` is set, requirements that can't be hashed (like Git repositories and
+    /// local directories) are exempt from hash-checking, rather than rejected.
     pub fn from_requirements<'a>(
         requirements: impl Iterator<Item = (&'a UnresolvedRequirement, &'a [String])>,
         constraints: impl Iterator<Item = (&'a Requirement, &'a [String])>,
         marker_env: Option<&ResolverMarkerEnvironment>,
         mode: HashCheckingMode,
+        allow_unhashable: bool,
     ) -> Result<Self, HashStrategyError> {
         let mut constraint_hashes = FxHashMap::<VersionId, Vec<HashDigest>>::default();
 
@@ -171,6 +179,7 @@ impl HashStrategy {
                 continue;
             }
 
+            Self::check_insecure(requirement, &digests)?;
             constraint_hashes.insert(id, digests);
         }
 
@@ -243,17 +252,34 @@ impl HashStrategy {
                 digests
             };
 
-            // Under `--require-hashes`, every requirement must include a hash.
+            // Under `--require-hashes`, every requirement must include a hash, unless the source
+            // can't be hashed and such sources are allowed. Under `--derive-hashes`, missing hashes
+            // are reported once the requirements have been resolved (see
+            // [`HashStrategy::check_resolution`]).
             if digests.is_empty() {
                 if mode.is_require() {
-                    return Err(HashStrategyError::MissingHashes(
-                        requirement.to_string(),
-                        mode,
-                    ));
+                    let exemption = allow_unhashable
+                        .then(|| HashExemption::from_source(&requirement.source()))
+                        .flatten();
+                    let Some(reason) = exemption else {
+                        if mode.is_derive() {
+                            continue;
+                        }
+                        return Err(HashStrategyError::MissingHashes(
+                            requirement.to_string(),
+                            mode,
+                        ));
+                    };
+                    warn_user!(
+                        "Skipping hash verification for `{}`: {reason}",
+                        requirement.to_string().cyan()
+                    );
+                    requirement_hashes.insert(id, Vec::new());
                 }
                 continue;
             }
 
+            Self::check_insecure(requirement, &digests)?;
             requirement_hashes.insert(id, digests);
         }
 
@@ -265,7 +291,9 @@ impl HashStrategy {
             .collect();
         match mode {
             HashCheckingMode::Verify => Ok(Self::Verify(Arc::new(hashes))),
-            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes))),
+            HashCheckingMode::Require | HashCheckingMode::Derive => {
+                Ok(Self::Require(Arc::new(hashes)))
+            }
         }
     }
 
@@ -287,15 +315,85 @@ impl HashStrategy {
                 }
                 continue;
             }
+            Self::check_insecure(dist.name(), digests)?;
             hashes.insert(dist.version_id(), digests.to_vec());
         }
 
         match mode {
             HashCheckingMode::Verify => Ok(Self::Verify(Arc::new(hashes))),
-            HashCheckingMode::Require => Ok(Self::Require(Arc::new(hashes))),
+            HashCheckingMode::Require | HashCheckingMode::Derive => {
+                Ok(Self::Require(Arc::new(hashes)))
+            }
         }
     }
 
+    /// Verify that hashes were provided for every distribution in the [`Resolution`], reporting
+    /// the hashes computed during resolution for any that are missing.
+    ///
+    /// Used under `--derive-hashes`, in which the requirements are resolved without enforcing
+    /// hashes, such that all missing hashes can be reported at once. Distributions for which no
+    /// hashes could be computed (e.g., Git dependencies) are reported as unhashable, unless they
+    /// were explicitly exempted.
+    pub fn check_resolution(
+        &self,
+        resolution: &Resolution,
+        mode: HashCheckingMode,
+    ) -> Result<(), HashStrategyError> {
+        let Self::Require(hashes) = self else {
+            return Ok(());
+        };
+
+        let mut missing = resolution
+            .hashes()
+            .filter(|(dist, _)| {
+                // Installed distributions aren't re-hashed.
+                matches!(dist, ResolvedDist::Installable { .. })
+                    && !hashes.contains_key(&dist.version_id())
+            })
+            .map(|(dist, digests)| {
+                if digests.is_empty() {
+                    return format!("    {dist} (unhashable: no hashes could be computed)");
+                }
+                let mut entry = format!("    {dist}");
+                for digest in digests {
+                    entry.push_str(&format!(" \\\n        --hash={digest}"));
+                }
+                entry
+            })
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        missing.sort_unstable();
+        Err(HashStrategyError::DerivedHashes(missing.join("\n"), mode))
+    }
+
+    /// Reject a set of digests that can only be verified with insecure algorithms (e.g., MD5).
+    fn check_insecure(
+        requirement: impl std::fmt::Display,
+        digests: &[HashDigest],
+    ) -> Result<(), HashStrategyError> {
+        if digests
+            .iter()
+            .all(|digest| digest.algorithm().is_insecure())
+        {
+            let algorithms = digests
+                .iter()
+                .map(|digest| digest.algorithm().to_string())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(HashStrategyError::InsecureHashes(
+                requirement.to_string(),
+                algorithms,
+            ));
+        }
+        Ok(())
+    }
+
     /// Pin a [`Requirement`] to a [`PackageId`], if possible.
     fn pin(requirement: &Requirement) -> Option<VersionId> {
         match &requirement.source {
@@ -323,6 +421,37 @@ impl HashStrategy {
     }
 }
 
+/// The reason a requirement is exempt from hash-checking.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum HashExemption {
+    /// The requirement is a Git repository.
+    Git,
+    /// The requirement is a local directory.
+    Directory,
+}
+
+impl HashExemption {
+    /// Return the [`HashExemption`] for a requirement with the given source, if it can't be hashed.
+    fn from_source(source: &RequirementSource) -> Option<Self> {
+        match source {
+            RequirementSource::Git { .. } => Some(Self::Git),
+            RequirementSource::Directory { .. } => Some(Self::Directory),
+            RequirementSource::Registry { .. }
+            | RequirementSource::Url { .. }
+            | RequirementSource::Path { .. } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for HashExemption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Git => f.write_str("hash-checking is not supported for Git repositories"),
+            Self::Directory => f.write_str("hash-checking is not supported for local directories"),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum HashStrategyError {
     #[error(transparent)]
@@ -333,8 +462,16 @@ pub enum HashStrategyError {
     UnpinnedRequirement(String, HashCheckingMode),
     #[error("In `{1}` mode, all requirements must have a hash, but none were provided for: {0}")]
     MissingHashes(String, HashCheckingMode),
+    #[error(
+        "In `{1}` mode, all requirements must have a hash, but none were provided for the following distributions. Add the computed hashes to your requirements:\n{0}"
+    )]
+    DerivedHashes(String, HashCheckingMode),
     #[error(
         "In `{1}` mode, all requirements must have a hash, but there were no overlapping hashes between the requirements and constraints for: {0}"
     )]
     NoIntersection(String, HashCheckingMode),
+    #[error(
+        "Hash-checking requires a digest using a secure algorithm (e.g., `sha256`), but only insecure digests ({1}) were provided for: {0}"
+    )]
+    InsecureHashes(String, String),
 }
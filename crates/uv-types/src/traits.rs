@@ -186,6 +186,12 @@ pub trait SourceBuildTrait {
         &'a self,
         wheel_dir: &'a Path,
     ) -> impl Future<Output = Result<String, AnyErrorBuild>> + 'a;
+
+    /// The resolved requirements of the build environment, including those returned by
+    /// `get_requires_for_build_wheel`.
+    ///
+    /// Returns `None` if build isolation is disabled.
+    fn build_requirements(&self) -> Option<&Resolution>;
 }
 
 /// A wrapper for [`uv_installer::SitePackages`]
@@ -27,7 +27,8 @@ pub struct FlatDependencyGroup {
 impl FlatDependencyGroups {
     /// Gather and flatten all the dependency-groups defined in the given pyproject.toml
     ///
-    /// The path is only used in diagnostics.
+    /// The path is the project root, against which any relative paths in the requirements are
+    /// resolved (rather than the current working directory).
     pub fn from_pyproject_toml(
         path: &Path,
         pyproject_toml: &PyProjectToml,
@@ -57,8 +58,8 @@ impl FlatDependencyGroups {
 
         // Flatten the dependency groups.
         let mut dependency_groups =
-            Self::from_dependency_groups(&dependency_groups, group_settings.inner()).map_err(
-                |err| DependencyGroupError {
+            Self::from_dependency_groups(path, &dependency_groups, group_settings.inner())
+                .map_err(|err| DependencyGroupError {
                     package: pyproject_toml
                         .project
                         .as_ref()
@@ -66,8 +67,7 @@ impl FlatDependencyGroups {
                         .unwrap_or_default(),
                     path: path.user_display().to_string(),
                     error: err.with_dev_dependencies(dev_dependencies),
-                },
-            )?;
+                })?;
 
         // Add the `dev` group, if the legacy `dev-dependencies` is defined.
         //
@@ -90,10 +90,12 @@ impl FlatDependencyGroups {
     /// Resolve the dependency groups (which may contain references to other groups) into concrete
     /// lists of requirements.
     fn from_dependency_groups(
+        root: &Path,
         groups: &BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
         settings: &BTreeMap<GroupName, DependencyGroupSettings>,
     ) -> Result<Self, DependencyGroupErrorInner> {
         fn resolve_group<'data>(
+            root: &Path,
             resolved: &mut BTreeMap<GroupName, FlatDependencyGroup>,
             groups: &'data BTreeMap<&GroupName, &Vec<DependencyGroupSpecifier>>,
             settings: &BTreeMap<GroupName, DependencyGroupSettings>,
@@ -131,7 +133,12 @@ impl FlatDependencyGroups {
             for specifier in *specifiers {
                 match specifier {
                     DependencyGroupSpecifier::Requirement(requirement) => {
-                        match uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement) {
+                        let requirement_or_err = if root.is_absolute() {
+                            uv_pep508::Requirement::<VerbatimParsedUrl>::parse(requirement, root)
+                        } else {
+                            uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(requirement)
+                        };
+                        match requirement_or_err {
                             Ok(requirement) => requirements.push(requirement),
                             Err(err) => {
                                 return Err(DependencyGroupErrorInner::GroupParseError(
@@ -143,7 +150,7 @@ impl FlatDependencyGroups {
                         }
                     }
                     DependencyGroupSpecifier::IncludeGroup { include_group } => {
-                        resolve_group(resolved, groups, settings, include_group, parents)?;
+                        resolve_group(root, resolved, groups, settings, include_group, parents)?;
                         if let Some(included) = resolved.get(include_group) {
                             requirements.extend(included.requirements.iter().cloned());
 
@@ -166,8 +173,10 @@ impl FlatDependencyGroups {
             }
 
             let empty_settings = DependencyGroupSettings::default();
-            let DependencyGroupSettings { requires_python } =
-                settings.get(name).unwrap_or(&empty_settings);
+            let DependencyGroupSettings {
+                requires_python,
+                marker,
+            } = settings.get(name).unwrap_or(&empty_settings);
             if let Some(requires_python) = requires_python {
                 // Intersect the requires-python for this group to get the final requires-python
                 // that will be used by interpreter discovery and checking.
@@ -186,6 +195,15 @@ impl FlatDependencyGroups {
                 }
             }
 
+            if let Some(marker) = marker {
+                // Add the group marker to each requirement, such that the group is omitted in any
+                // environments that don't satisfy the marker (while still being locked for those
+                // that do).
+                for requirement in &mut requirements {
+                    requirement.marker.and(*marker);
+                }
+            }
+
             parents.pop();
 
             resolved.insert(
@@ -214,7 +232,7 @@ impl FlatDependencyGroups {
         let mut resolved = BTreeMap::new();
         for name in groups.keys() {
             let mut parents = Vec::new();
-            resolve_group(&mut resolved, groups, settings, name, &mut parents)?;
+            resolve_group(root, &mut resolved, groups, settings, name, &mut parents)?;
         }
         Ok(Self(resolved))
     }
@@ -22,6 +22,7 @@ use serde::{Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 
 use uv_build_backend::BuildBackendSettings;
+use uv_configuration::UpgradePolicy;
 use uv_distribution_types::{ExtraBuildVariables, Index, IndexName, RequirementSource};
 use uv_fs::{PortablePathBuf, relative_to};
 use uv_git_types::GitReference;
@@ -30,6 +31,7 @@ use uv_normalize::{DefaultGroups, ExtraName, GroupName, PackageName};
 use uv_options_metadata::{OptionSet, OptionsMetadata, Visit};
 use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::{MarkerTree, VersionOrUrl};
+use uv_platform_tags::TagOverrides;
 use uv_pypi_types::{
     Conflicts, DependencyGroups, SchemaConflicts, SupportedEnvironments, VerbatimParsedUrl,
 };
@@ -412,9 +414,10 @@ pub struct ToolUv {
 
     /// Additional settings for `dependency-groups`.
     ///
-    /// Currently this can only be used to add `requires-python` constraints
-    /// to dependency groups (typically to inform uv that your dev tooling
-    /// has a higher python requirement than your actual project).
+    /// This can be used to add `requires-python` constraints to dependency groups (typically to
+    /// inform uv that your dev tooling has a higher python requirement than your actual project),
+    /// or to add a `marker` that limits the environments in which a group is installed (e.g., to
+    /// only install a group on Linux).
     ///
     /// This cannot be used to define dependency groups, use the top-level
     /// `[dependency-groups]` table for that.
@@ -424,6 +427,7 @@ pub struct ToolUv {
         example = r#"
             [tool.uv.dependency-groups]
             my-group = {requires-python = ">=3.12"}
+            gpu-dev = {marker = "sys_platform == 'linux'"}
         "#
     )]
     pub dependency_groups: Option<ToolUvDependencyGroups>,
@@ -644,6 +648,106 @@ pub struct ToolUv {
     )]
     pub required_environments: Option<SupportedEnvironments>,
 
+    /// Additional platform tags to consider compatible for specific packages.
+    ///
+    /// By default, uv will only install wheels with a platform tag that's compatible with the
+    /// current platform. For example, uv will reject a wheel tagged `linux_x86_64` when a
+    /// `manylinux` tag is required. The `compatible-tags` setting extends the set of compatible
+    /// platform tags for the given packages, without affecting any other packages. The Python and
+    /// ABI tags of the wheel must still be compatible, and hashes are verified as usual.
+    ///
+    /// The overrides are recorded in the lockfile, and uv will display a warning whenever a wheel
+    /// is installed due to an override.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<BTreeMap<PackageName, Vec<String>>>",
+            description = "A map from package name to a list of additional compatible platform tags, e.g., `linux_x86_64`."
+        )
+    )]
+    #[option(
+        default = "{}",
+        value_type = "dict[str, list[str]]",
+        example = r#"
+            # Allow installing `internal-pkg` from wheels tagged `linux_x86_64`.
+            compatible-tags = { internal-pkg = ["linux_x86_64"] }
+        "#
+    )]
+    pub compatible_tags: Option<TagOverrides>,
+
+    /// The range of versions to which specific packages may be upgraded when running with
+    /// `--upgrade` or `--upgrade-package`.
+    ///
+    /// By default, `--upgrade` allows any package to be upgraded to its latest compatible version.
+    /// The `upgrade-policy` setting restricts upgrades for the given packages, based on the version
+    /// in the existing lockfile:
+    ///
+    /// - `any`: allow upgrades to any version (the default).
+    /// - `minor`: allow upgrades within the locked minor release series, e.g., a package locked at
+    ///   `5.0.3` is constrained to `>=5.0.3, <5.1`.
+    /// - `patch`: allow patch upgrades within the locked minor release series, e.g., a package
+    ///   locked at `5.0.3` is constrained to `>=5.0.3, <5.1`.
+    /// - `pin`: retain the locked version.
+    ///
+    /// The policy applies to both `--upgrade` and `--upgrade-package`, but has no effect on packages
+    /// that are absent from the lockfile (e.g., when creating a lockfile for the first time), or on
+    /// packages that are named explicitly via `--upgrade-package`, even alongside `--upgrade`.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<BTreeMap<PackageName, UpgradePolicy>>",
+            description = "A map from package name to the range of versions to which the package may be upgraded."
+        )
+    )]
+    #[option(
+        default = "{}",
+        value_type = "dict[str, str]",
+        example = r#"
+            # Restrict `django` to releases within its locked minor series on `--upgrade`.
+            upgrade-policy = { django = "minor" }
+        "#
+    )]
+    pub upgrade_policy: Option<BTreeMap<PackageName, UpgradePolicy>>,
+
+    /// The Python versions to sync when running `uv sync --all-pythons`.
+    ///
+    /// For each version, uv creates (or updates) a dedicated virtual environment at
+    /// `.venv-<version>` in the workspace root, installing from the same `uv.lock`. Managed Python
+    /// interpreters are downloaded as needed.
+    ///
+    /// Each version must be compatible with the project's `requires-python`.
+    #[cfg_attr(
+        feature = "schemars",
+        schemars(
+            with = "Option<Vec<String>>",
+            description = "A list of Python versions, e.g., `3.9`."
+        )
+    )]
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            # Sync an environment for the oldest and newest supported Python versions.
+            test-pythons = ["3.9", "3.13"]
+        "#
+    )]
+    pub test_pythons: Option<Vec<Version>>,
+
+    /// Packages to exclude from bytecode compilation.
+    ///
+    /// When bytecode compilation is enabled (e.g., via `--compile-bytecode`), the Python source
+    /// files installed by the listed packages are left uncompiled. This is useful for large
+    /// packages for which startup time is irrelevant, or for packages that include source files
+    /// that fail to compile.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            compile-bytecode-exclude = ["torch", "tensorflow"]
+        "#
+    )]
+    pub compile_bytecode_exclude: Option<Vec<PackageName>>,
+
     /// Declare collections of extras or dependency groups that are conflicting
     /// (i.e., mutually exclusive).
     ///
@@ -768,6 +872,9 @@ pub struct DependencyGroupSettings {
     /// Version of python to require when installing this group
     #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
     pub requires_python: Option<VersionSpecifiers>,
+    /// Environment marker to require when installing this group
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub marker: Option<MarkerTree>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -899,28 +1006,31 @@ impl<'de> serde::de::Deserialize<'de> for ExtraBuildDependencies {
 pub struct ToolUvWorkspace {
     /// Packages to include as workspace members.
     ///
-    /// Supports both globs and explicit paths.
+    /// Supports both globs and explicit paths. Patterns are evaluated in order; a pattern prefixed
+    /// with `!` negates a preceding match, such that the last matching pattern wins.
     ///
     /// For more information on the glob syntax, refer to the [`glob` documentation](https://docs.rs/glob/latest/glob/struct.Pattern.html).
     #[option(
         default = "[]",
         value_type = "list[str]",
         example = r#"
-            members = ["member1", "path/to/member2", "libs/*"]
+            members = ["member1", "path/to/member2", "libs/*", "!libs/legacy"]
         "#
     )]
     pub members: Option<Vec<SerdePattern>>,
     /// Packages to exclude as workspace members. If a package matches both `members` and
     /// `exclude`, it will be excluded.
     ///
-    /// Supports both globs and explicit paths.
+    /// Supports both globs and explicit paths, including `**` to match directories at any depth.
+    /// Patterns are evaluated in order; a pattern prefixed with `!` re-includes a package excluded
+    /// by a preceding pattern, such that the last matching pattern wins.
     ///
     /// For more information on the glob syntax, refer to the [`glob` documentation](https://docs.rs/glob/latest/glob/struct.Pattern.html).
     #[option(
         default = "[]",
         value_type = "list[str]",
         example = r#"
-            exclude = ["member1", "path/to/member2", "libs/*"]
+            exclude = ["member1", "libs/**/legacy*", "!libs/legacy-core"]
         "#
     )]
     pub exclude: Option<Vec<SerdePattern>>,
@@ -972,6 +1082,19 @@ impl schemars::JsonSchema for SerdePattern {
     }
 }
 
+impl SerdePattern {
+    /// Returns `true` if the pattern is negated, i.e., prefixed with `!`.
+    pub fn is_negated(&self) -> bool {
+        self.0.as_str().starts_with('!')
+    }
+
+    /// Returns the glob to match against, without the `!` prefix of a negated pattern.
+    pub fn glob(&self) -> &str {
+        let pattern = self.0.as_str();
+        pattern.strip_prefix('!').unwrap_or(pattern)
+    }
+}
+
 impl Deref for SerdePattern {
     type Target = Pattern;
 
@@ -1133,9 +1256,13 @@ pub enum Source {
         git: DisplaySafeUrl,
         /// The path to the directory with the `pyproject.toml`, if it's not in the archive root.
         subdirectory: Option<PortablePathBuf>,
-        // Only one of the three may be used; we'll validate this later and emit a custom error.
+        // Only one of the four may be used; we'll validate this later and emit a custom error.
         rev: Option<String>,
         tag: Option<String>,
+        /// A glob pattern (e.g., `v*`) selecting the tag with the highest version among those that
+        /// match. The resolved commit is reused until the package is upgraded.
+        #[serde(rename = "tag-pattern")]
+        tag_pattern: Option<String>,
         branch: Option<String>,
         #[serde(
             skip_serializing_if = "uv_pep508::marker::ser::is_empty",
@@ -1232,6 +1359,7 @@ impl<'de> Deserialize<'de> for Source {
             subdirectory: Option<PortablePathBuf>,
             rev: Option<String>,
             tag: Option<String>,
+            tag_pattern: Option<String>,
             branch: Option<String>,
             url: Option<DisplaySafeUrl>,
             path: Option<PortablePathBuf>,
@@ -1255,6 +1383,7 @@ impl<'de> Deserialize<'de> for Source {
             subdirectory,
             rev,
             tag,
+            tag_pattern,
             branch,
             url,
             path,
@@ -1307,19 +1436,35 @@ impl<'de> Deserialize<'de> for Source {
                 ));
             }
 
-            // At most one of `rev`, `tag`, or `branch` may be set.
-            match (rev.as_ref(), tag.as_ref(), branch.as_ref()) {
-                (None, None, None) => {}
-                (Some(_), None, None) => {}
-                (None, Some(_), None) => {}
-                (None, None, Some(_)) => {}
+            // At most one of `rev`, `tag`, `tag-pattern`, or `branch` may be set.
+            match (
+                rev.as_ref(),
+                tag.as_ref(),
+                tag_pattern.as_ref(),
+                branch.as_ref(),
+            ) {
+                (None, None, None, None) => {}
+                (Some(_), None, None, None) => {}
+                (None, Some(_), None, None) => {}
+                (None, None, Some(_), None) => {}
+                (None, None, None, Some(_)) => {}
                 _ => {
                     return Err(serde::de::Error::custom(
-                        "expected at most one of `rev`, `tag`, or `branch`",
+                        "expected at most one of `rev`, `tag`, `tag-pattern`, or `branch`",
                     ));
                 }
             }
 
+            // Like a Git refspec, a tag pattern may include at most one `*`.
+            if tag_pattern
+                .as_ref()
+                .is_some_and(|pattern| pattern.matches('*').count() > 1)
+            {
+                return Err(serde::de::Error::custom(
+                    "`tag-pattern` may include at most one `*`",
+                ));
+            }
+
             // If the user prefixed the URL with `git+`, strip it.
             let git = if let Some(git) = git.as_str().strip_prefix("git+") {
                 DisplaySafeUrl::parse(git).map_err(serde::de::Error::custom)?
@@ -1332,6 +1477,7 @@ impl<'de> Deserialize<'de> for Source {
                 subdirectory,
                 rev,
                 tag,
+                tag_pattern,
                 branch,
                 marker,
                 extra,
@@ -1371,6 +1517,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `url` and `tag`",
                 ));
             }
+            if tag_pattern.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `url` and `tag-pattern`",
+                ));
+            }
             if branch.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `url` and `branch`",
@@ -1428,6 +1579,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `path` and `tag`",
                 ));
             }
+            if tag_pattern.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `path` and `tag-pattern`",
+                ));
+            }
             if branch.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `path` and `branch`",
@@ -1483,6 +1639,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `index` and `tag`",
                 ));
             }
+            if tag_pattern.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `index` and `tag-pattern`",
+                ));
+            }
             if branch.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `index` and `branch`",
@@ -1539,6 +1700,11 @@ impl<'de> Deserialize<'de> for Source {
                     "cannot specify both `workspace` and `tag`",
                 ));
             }
+            if tag_pattern.is_some() {
+                return Err(serde::de::Error::custom(
+                    "cannot specify both `workspace` and `tag-pattern`",
+                ));
+            }
             if branch.is_some() {
                 return Err(serde::de::Error::custom(
                     "cannot specify both `workspace` and `branch`",
@@ -1649,6 +1815,7 @@ impl Source {
                                 subdirectory: subdirectory.clone(),
                                 rev,
                                 tag,
+                                tag_pattern: None,
                                 branch,
                                 marker: *marker,
                                 extra: extra.clone(),
@@ -1757,11 +1924,12 @@ impl Source {
                         GitReference::BranchOrTag(rev) => Some(rev),
                         GitReference::BranchOrTagOrCommit(rev) => Some(rev),
                         GitReference::NamedRef(rev) => Some(rev),
-                        GitReference::DefaultBranch => None,
+                        GitReference::TagPattern(_) | GitReference::DefaultBranch => None,
                     };
                     Self::Git {
                         rev: rev.cloned(),
                         tag,
+                        tag_pattern: None,
                         branch,
                         git: git.repository().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
@@ -1773,6 +1941,7 @@ impl Source {
                     Self::Git {
                         rev,
                         tag,
+                        tag_pattern: None,
                         branch,
                         git: git.repository().clone(),
                         subdirectory: subdirectory.map(PortablePathBuf::from),
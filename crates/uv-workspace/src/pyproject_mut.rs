@@ -249,6 +249,17 @@ impl AddBoundsKind {
     }
 }
 
+/// The form in which a requirement is written to a `pyproject.toml` file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RequirementFormat<'a> {
+    /// Write the normalized requirement, omitting any credentials.
+    Normalized,
+    /// Write the normalized requirement, including any credentials.
+    Raw,
+    /// Write the requirement exactly as provided by the user.
+    Verbatim(&'a str),
+}
+
 /// Specifies whether dependencies are added to a script file or a `pyproject.toml` file.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum DependencyTarget {
@@ -337,7 +348,7 @@ impl PyProjectTomlMut {
         &mut self,
         req: &Requirement,
         source: Option<&Source>,
-        raw: bool,
+        format: RequirementFormat<'_>,
     ) -> Result<ArrayEdit, Error> {
         // Get or create `project.dependencies`.
         let dependencies = self
@@ -347,7 +358,7 @@ impl PyProjectTomlMut {
             .as_array_mut()
             .ok_or(Error::MalformedDependencies)?;
 
-        let edit = add_dependency(req, dependencies, source.is_some(), raw)?;
+        let edit = add_dependency(req, dependencies, source.is_some(), format)?;
 
         if let Some(source) = source {
             self.add_source(&req.name, source)?;
@@ -363,7 +374,7 @@ impl PyProjectTomlMut {
         &mut self,
         req: &Requirement,
         source: Option<&Source>,
-        raw: bool,
+        format: RequirementFormat<'_>,
     ) -> Result<ArrayEdit, Error> {
         // Get or create `tool.uv.dev-dependencies`.
         let dev_dependencies = self
@@ -381,7 +392,7 @@ impl PyProjectTomlMut {
             .as_array_mut()
             .ok_or(Error::MalformedDependencies)?;
 
-        let edit = add_dependency(req, dev_dependencies, source.is_some(), raw)?;
+        let edit = add_dependency(req, dev_dependencies, source.is_some(), format)?;
 
         if let Some(source) = source {
             self.add_source(&req.name, source)?;
@@ -571,7 +582,7 @@ impl PyProjectTomlMut {
         group: &ExtraName,
         req: &Requirement,
         source: Option<&Source>,
-        raw: bool,
+        format: RequirementFormat<'_>,
     ) -> Result<ArrayEdit, Error> {
         // Get or create `project.optional-dependencies`.
         let optional_dependencies = self
@@ -600,7 +611,7 @@ impl PyProjectTomlMut {
         .as_array_mut()
         .ok_or(Error::MalformedDependencies)?;
 
-        let added = add_dependency(req, group, source.is_some(), raw)?;
+        let added = add_dependency(req, group, source.is_some(), format)?;
 
         // If `project.optional-dependencies` is an inline table, reformat it.
         //
@@ -629,7 +640,7 @@ impl PyProjectTomlMut {
         group: &GroupName,
         req: &Requirement,
         source: Option<&Source>,
-        raw: bool,
+        format: RequirementFormat<'_>,
     ) -> Result<ArrayEdit, Error> {
         // Get or create `dependency-groups`.
         let dependency_groups = self
@@ -665,7 +676,7 @@ impl PyProjectTomlMut {
         .as_array_mut()
         .ok_or(Error::MalformedDependencies)?;
 
-        let added = add_dependency(req, group, source.is_some(), raw)?;
+        let added = add_dependency(req, group, source.is_some(), format)?;
 
         // To avoid churn in pyproject.toml, we only sort new group keys if the
         // existing keys were sorted.
@@ -1163,7 +1174,7 @@ pub fn add_dependency(
     req: &Requirement,
     deps: &mut Array,
     has_source: bool,
-    raw: bool,
+    format: RequirementFormat<'_>,
 ) -> Result<ArrayEdit, Error> {
     let mut to_replace = find_dependencies(&req.name, Some(&req.marker), deps);
 
@@ -1222,10 +1233,10 @@ pub fn add_dependency(
                 Sort::Unsorted
             };
 
-            let req_string = if raw {
-                req.displayable_with_credentials().to_string()
-            } else {
-                req.to_string()
+            let req_string = match format {
+                RequirementFormat::Normalized => req.to_string(),
+                RequirementFormat::Raw => req.displayable_with_credentials().to_string(),
+                RequirementFormat::Verbatim(given) => given.to_string(),
             };
             let index = match sort {
                 Sort::CaseInsensitive => deps.iter().position(|dep| {
@@ -1408,8 +1419,12 @@ pub fn add_dependency(
         }
         [_] => {
             let (i, mut old_req) = to_replace.remove(0);
-            update_requirement(&mut old_req, req, has_source);
-            deps.replace(i, old_req.to_string());
+            if let RequirementFormat::Verbatim(given) = format {
+                deps.replace(i, given);
+            } else {
+                update_requirement(&mut old_req, req, has_source);
+                deps.replace(i, old_req.to_string());
+            }
             reformat_array_multiline(deps);
             Ok(ArrayEdit::Update(i))
         }
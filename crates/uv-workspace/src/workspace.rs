@@ -8,19 +8,21 @@ use glob::{GlobError, PatternError, glob};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace, warn};
 
-use uv_configuration::DependencyGroupsWithDefaults;
+use uv_configuration::{DependencyGroupsWithDefaults, UpgradePolicy};
 use uv_distribution_types::{Index, Requirement, RequirementSource};
 use uv_fs::{CWD, Simplified};
 use uv_normalize::{DEV_DEPENDENCIES, GroupName, PackageName};
-use uv_pep440::VersionSpecifiers;
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::{MarkerTree, VerbatimUrl};
+use uv_platform_tags::TagOverrides;
 use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
 use uv_static::EnvVars;
 use uv_warnings::warn_user_once;
 
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroup, FlatDependencyGroups};
 use crate::pyproject::{
-    Project, PyProjectToml, PyprojectTomlError, Source, Sources, ToolUvSources, ToolUvWorkspace,
+    Project, PyProjectToml, PyprojectTomlError, SerdePattern, Source, Sources, ToolUvSources,
+    ToolUvWorkspace,
 };
 
 type WorkspaceMembers = Arc<BTreeMap<PackageName, WorkspaceMember>>;
@@ -121,6 +123,9 @@ pub struct Workspace {
     indexes: Vec<Index>,
     /// The `pyproject.toml` of the workspace root.
     pyproject_toml: PyProjectToml,
+    /// An explicit path for the project virtual environment, overriding the default.
+    #[cfg_attr(test, serde(skip))]
+    project_environment: Option<PathBuf>,
 }
 
 impl Workspace {
@@ -252,6 +257,16 @@ impl Workspace {
         })
     }
 
+    /// Use the given path for the project virtual environment, overriding `UV_PROJECT_ENVIRONMENT`
+    /// and the default `.venv` location.
+    #[must_use]
+    pub fn with_project_environment(self, project_environment: PathBuf) -> Self {
+        Self {
+            project_environment: Some(project_environment),
+            ..self
+        }
+    }
+
     /// Set the [`ProjectWorkspace`] for a given workspace member.
     ///
     /// Assumes that the project name is unchanged in the updated [`PyProjectToml`].
@@ -487,6 +502,43 @@ impl Workspace {
             .and_then(|uv| uv.required_environments.as_ref())
     }
 
+    /// Returns the additional compatible platform tags for the workspace.
+    pub fn compatible_tags(&self) -> Option<&TagOverrides> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.compatible_tags.as_ref())
+    }
+
+    /// Returns the per-package upgrade policies for the workspace.
+    pub fn upgrade_policy(&self) -> Option<&BTreeMap<PackageName, UpgradePolicy>> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.upgrade_policy.as_ref())
+    }
+
+    /// Returns the Python versions to sync with `uv sync --all-pythons`.
+    pub fn test_pythons(&self) -> Option<&[Version]> {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.test_pythons.as_deref())
+    }
+
+    /// Returns the packages to exclude from bytecode compilation.
+    pub fn compile_bytecode_exclude(&self) -> &[PackageName] {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.compile_bytecode_exclude.as_deref())
+            .unwrap_or_default()
+    }
+
     /// Returns the set of conflicts for the workspace.
     pub fn conflicts(&self) -> Conflicts {
         let mut conflicting = Conflicts::empty();
@@ -667,6 +719,11 @@ impl Workspace {
             Some(CWD.join(path))
         }
 
+        // An explicit path takes precedence over all other sources.
+        if let Some(project_environment) = &self.project_environment {
+            return project_environment.clone();
+        }
+
         // Determine the default value
         let project_env = from_project_environment_variable(self)
             .unwrap_or_else(|| self.install_path.join(".venv"));
@@ -755,6 +812,46 @@ impl Workspace {
         }
     }
 
+    /// Return the directories matched by the `members` entries of the workspace, along with the
+    /// pattern that determined whether each directory is a workspace member.
+    ///
+    /// Unlike [`Workspace::packages`], the candidates include directories that were excluded by a
+    /// negated `members` pattern or an `exclude` pattern.
+    pub fn member_candidates(&self) -> Result<Vec<MemberCandidate>, WorkspaceError> {
+        let Some(workspace) = self
+            .pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.workspace.as_ref())
+        else {
+            return Ok(Vec::new());
+        };
+
+        let mut seen = FxHashSet::default();
+        let mut candidates = Vec::new();
+        for member_glob in workspace.members.iter().flatten() {
+            if member_glob.is_negated() {
+                continue;
+            }
+            let absolute_glob = absolute_workspace_glob(&self.install_path, member_glob.glob());
+            for root in glob(&absolute_glob)
+                .map_err(|err| WorkspaceError::Pattern(absolute_glob.clone(), err))?
+            {
+                let root =
+                    root.map_err(|err| WorkspaceError::GlobWalk(absolute_glob.clone(), err))?;
+                let root = std::path::absolute(&root).map_err(WorkspaceError::Normalize)?;
+                if root == self.install_path || !seen.insert(root.clone()) {
+                    continue;
+                }
+                let status = member_status(&root, &self.install_path, workspace)?
+                    .unwrap_or_else(|| MemberStatus::Included(member_glob.to_string()));
+                candidates.push(MemberCandidate { root, status });
+            }
+        }
+        Ok(candidates)
+    }
+
     /// Collect the workspace member projects from the `members` and `excludes` entries.
     async fn collect_members(
         workspace_root: PathBuf,
@@ -840,6 +937,7 @@ impl Workspace {
             sources: workspace_sources,
             indexes: workspace_indexes,
             pyproject_toml: workspace_pyproject_toml,
+            project_environment: None,
         })
     }
 
@@ -877,14 +975,13 @@ impl Workspace {
             );
         }
 
-        // Add all other workspace members.
+        // Add all other workspace members. Negated patterns can only remove members, so they're
+        // skipped during enumeration and applied when checking each member below.
         for member_glob in workspace_definition.clone().members.unwrap_or_default() {
-            let absolute_glob = PathBuf::from(glob::Pattern::escape(
-                workspace_root.simplified().to_string_lossy().as_ref(),
-            ))
-            .join(member_glob.as_str())
-            .to_string_lossy()
-            .to_string();
+            if member_glob.is_negated() {
+                continue;
+            }
+            let absolute_glob = absolute_workspace_glob(workspace_root, member_glob.glob());
             for member_root in glob(&absolute_glob)
                 .map_err(|err| WorkspaceError::Pattern(absolute_glob.to_string(), err))?
             {
@@ -912,12 +1009,15 @@ impl Workspace {
                 }
 
                 // If the member is excluded, ignore it.
-                if is_excluded_from_workspace(&member_root, workspace_root, workspace_definition)? {
-                    debug!(
-                        "Ignoring workspace member: `{}`",
-                        member_root.simplified_display()
-                    );
-                    continue;
+                match member_status(&member_root, workspace_root, workspace_definition)? {
+                    Some(MemberStatus::Negated(pattern) | MemberStatus::Excluded(pattern)) => {
+                        debug!(
+                            "Ignoring workspace member: `{}` (excluded by `{pattern}`)",
+                            member_root.simplified_display()
+                        );
+                        continue;
+                    }
+                    Some(MemberStatus::Included(_)) | None => {}
                 }
 
                 trace!(
@@ -1339,6 +1439,7 @@ impl ProjectWorkspace {
                     sources: workspace_sources,
                     indexes: Vec::default(),
                     pyproject_toml: project_pyproject_toml.clone(),
+                    project_environment: None,
                 },
             });
         };
@@ -1464,25 +1565,71 @@ async fn find_workspace(
     Ok(None)
 }
 
+/// Whether a directory is a workspace member, along with the pattern that determined it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MemberStatus {
+    /// The directory is included by the given `members` pattern.
+    Included(String),
+    /// The directory is excluded by the given negated `members` pattern.
+    Negated(String),
+    /// The directory is excluded by the given `exclude` pattern.
+    Excluded(String),
+}
+
+/// A directory matched by the `members` entries of a workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemberCandidate {
+    /// The absolute path to the directory.
+    pub root: PathBuf,
+    /// Whether the directory is a workspace member.
+    pub status: MemberStatus,
+}
+
+/// Determine whether a directory is a member of the workspace.
+///
+/// The `members` and `exclude` patterns are each evaluated in order, such that the last matching
+/// pattern wins; a `!`-prefixed pattern negates the preceding matches. Returns `None` if the
+/// directory isn't matched by any `members` pattern.
+fn member_status(
+    project_path: &Path,
+    workspace_root: &Path,
+    workspace: &ToolUvWorkspace,
+) -> Result<Option<MemberStatus>, WorkspaceError> {
+    let Some(member_glob) = last_match(
+        project_path,
+        workspace_root,
+        workspace.members.as_deref().unwrap_or_default(),
+    )?
+    else {
+        return Ok(None);
+    };
+    if member_glob.is_negated() {
+        return Ok(Some(MemberStatus::Negated(member_glob.to_string())));
+    }
+    if let Some(exclude_glob) = last_match(
+        project_path,
+        workspace_root,
+        workspace.exclude.as_deref().unwrap_or_default(),
+    )? {
+        if !exclude_glob.is_negated() {
+            return Ok(Some(MemberStatus::Excluded(exclude_glob.to_string())));
+        }
+    }
+    Ok(Some(MemberStatus::Included(member_glob.to_string())))
+}
+
 /// Check if we're in the `tool.uv.workspace.excluded` of a workspace.
 fn is_excluded_from_workspace(
     project_path: &Path,
     workspace_root: &Path,
     workspace: &ToolUvWorkspace,
 ) -> Result<bool, WorkspaceError> {
-    for exclude_glob in workspace.exclude.iter().flatten() {
-        let absolute_glob = PathBuf::from(glob::Pattern::escape(
-            workspace_root.simplified().to_string_lossy().as_ref(),
-        ))
-        .join(exclude_glob.as_str());
-        let absolute_glob = absolute_glob.to_string_lossy();
-        let exclude_pattern = glob::Pattern::new(&absolute_glob)
-            .map_err(|err| WorkspaceError::Pattern(absolute_glob.to_string(), err))?;
-        if exclude_pattern.matches_path(project_path) {
-            return Ok(true);
-        }
-    }
-    Ok(false)
+    let exclude_glob = last_match(
+        project_path,
+        workspace_root,
+        workspace.exclude.as_deref().unwrap_or_default(),
+    )?;
+    Ok(exclude_glob.is_some_and(|exclude_glob| !exclude_glob.is_negated()))
 }
 
 /// Check if we're in the `tool.uv.workspace.members` of a workspace.
@@ -1491,19 +1638,42 @@ fn is_included_in_workspace(
     workspace_root: &Path,
     workspace: &ToolUvWorkspace,
 ) -> Result<bool, WorkspaceError> {
-    for member_glob in workspace.members.iter().flatten() {
-        let absolute_glob = PathBuf::from(glob::Pattern::escape(
-            workspace_root.simplified().to_string_lossy().as_ref(),
-        ))
-        .join(member_glob.as_str());
-        let absolute_glob = absolute_glob.to_string_lossy();
-        let include_pattern = glob::Pattern::new(&absolute_glob)
-            .map_err(|err| WorkspaceError::Pattern(absolute_glob.to_string(), err))?;
-        if include_pattern.matches_path(project_path) {
-            return Ok(true);
+    let member_glob = last_match(
+        project_path,
+        workspace_root,
+        workspace.members.as_deref().unwrap_or_default(),
+    )?;
+    Ok(member_glob.is_some_and(|member_glob| !member_glob.is_negated()))
+}
+
+/// Return the last of the given patterns that matches the path, if any.
+///
+/// Negated patterns are matched without their `!` prefix.
+fn last_match<'a>(
+    project_path: &Path,
+    workspace_root: &Path,
+    patterns: &'a [SerdePattern],
+) -> Result<Option<&'a SerdePattern>, WorkspaceError> {
+    let mut matched = None;
+    for pattern in patterns {
+        let absolute_glob = absolute_workspace_glob(workspace_root, pattern.glob());
+        let absolute_pattern = glob::Pattern::new(&absolute_glob)
+            .map_err(|err| WorkspaceError::Pattern(absolute_glob.clone(), err))?;
+        if absolute_pattern.matches_path(project_path) {
+            matched = Some(pattern);
         }
     }
-    Ok(false)
+    Ok(matched)
+}
+
+/// Resolve a glob relative to the workspace root.
+fn absolute_workspace_glob(workspace_root: &Path, glob: &str) -> String {
+    PathBuf::from(glob::Pattern::escape(
+        workspace_root.simplified().to_string_lossy().as_ref(),
+    ))
+    .join(glob)
+    .to_string_lossy()
+    .to_string()
 }
 
 /// A project that can be discovered.
@@ -1978,6 +2148,10 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "compatible-tags": null,
+                      "upgrade-policy": null,
+                      "test-pythons": null,
+                      "compile-bytecode-exclude": null,
                       "conflicts": null,
                       "build-backend": null
                     }
@@ -2080,6 +2254,10 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "compatible-tags": null,
+                      "upgrade-policy": null,
+                      "test-pythons": null,
+                      "compile-bytecode-exclude": null,
                       "conflicts": null,
                       "build-backend": null
                     }
@@ -2295,6 +2473,10 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "compatible-tags": null,
+                      "upgrade-policy": null,
+                      "test-pythons": null,
+                      "compile-bytecode-exclude": null,
                       "conflicts": null,
                       "build-backend": null
                     }
@@ -2406,6 +2588,10 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "compatible-tags": null,
+                      "upgrade-policy": null,
+                      "test-pythons": null,
+                      "compile-bytecode-exclude": null,
                       "conflicts": null,
                       "build-backend": null
                     }
@@ -2530,6 +2716,10 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "compatible-tags": null,
+                      "upgrade-policy": null,
+                      "test-pythons": null,
+                      "compile-bytecode-exclude": null,
                       "conflicts": null,
                       "build-backend": null
                     }
@@ -2628,6 +2818,10 @@ mod tests {
                       "build-constraint-dependencies": null,
                       "environments": null,
                       "required-environments": null,
+                      "compatible-tags": null,
+                      "upgrade-policy": null,
+                      "test-pythons": null,
+                      "compile-bytecode-exclude": null,
                       "conflicts": null,
                       "build-backend": null
                     }
@@ -2642,6 +2836,73 @@ mod tests {
         Ok(())
     }
 
+    /// Negated `members` and `exclude` patterns are evaluated in order, with the last match
+    /// winning, and `**` matches excluded directories at any depth.
+    #[tokio::test]
+    async fn exclude_package_negated() -> Result<()> {
+        let root = tempfile::TempDir::new()?;
+        let root = ChildPath::new(root.path());
+
+        root.child("pyproject.toml").write_str(
+            r#"
+            [project]
+            name = "albatross"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [tool.uv.workspace]
+            members = ["services/*", "services/*/*", "!services/internal/scratch"]
+            exclude = ["services/**/legacy*", "!services/legacy-auth"]
+            "#,
+        )?;
+
+        for path in [
+            "services/api",
+            "services/legacy-auth",
+            "services/legacy-billing",
+            "services/internal/legacy-search",
+            "services/internal/scratch",
+            "services/internal/worker",
+        ] {
+            let name = path.rsplit('/').next().unwrap();
+            root.child(path)
+                .child("pyproject.toml")
+                .write_str(&format!(
+                    r#"
+                [project]
+                name = "{name}"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+                "#
+                ))?;
+        }
+        root.child("services")
+            .child("internal")
+            .child("pyproject.toml")
+            .write_str(
+                r#"
+                [project]
+                name = "internal"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+                "#,
+            )?;
+
+        let (project, _) = temporary_test(root.as_ref()).await.unwrap();
+        let members = project
+            .workspace()
+            .packages()
+            .keys()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            members,
+            ["albatross", "api", "internal", "legacy-auth", "worker"]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn read_dependency_groups() {
         let toml = r#"
@@ -1,11 +1,14 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::fmt::Write as _;
 use std::io::Write as _;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::str::FromStr;
 use std::{fmt, io};
 
 use anyhow::{Context, Result};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use thiserror::Error;
 use tracing::instrument;
@@ -27,6 +30,7 @@ use uv_distribution_types::{
     DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, RequiresPython, SourceDist,
 };
 use uv_fs::{Simplified, relative_to};
+use uv_git::GIT;
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -38,6 +42,7 @@ use uv_python::{
 use uv_requirements::RequirementsSource;
 use uv_resolver::{ExcludeNewer, FlatIndex};
 use uv_settings::PythonInstallMirrors;
+use uv_static::EnvVars;
 use uv_types::{AnyErrorBuild, BuildContext, BuildIsolation, BuildStack, HashStrategy};
 use uv_workspace::pyproject::ExtraBuildDependencies;
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceError};
@@ -99,14 +104,17 @@ enum Error {
 pub(crate) async fn build_frontend(
     project_dir: &Path,
     src: Option<PathBuf>,
-    package: Option<PackageName>,
+    packages: Vec<PackageName>,
     all_packages: bool,
+    since: Option<String>,
     output_dir: Option<PathBuf>,
+    output_dir_template: Option<String>,
     sdist: bool,
     wheel: bool,
     list: bool,
     build_logs: bool,
     force_pep517: bool,
+    source_date_epoch: Option<u64>,
     build_constraints: Vec<RequirementsSource>,
     hash_checking: Option<HashCheckingMode>,
     python: Option<String>,
@@ -124,14 +132,17 @@ pub(crate) async fn build_frontend(
     let build_result = build_impl(
         project_dir,
         src.as_deref(),
-        package.as_ref(),
+        &packages,
         all_packages,
+        since.as_deref(),
         output_dir.as_deref(),
+        output_dir_template.as_deref(),
         sdist,
         wheel,
         list,
         build_logs,
         force_pep517,
+        source_date_epoch,
         &build_constraints,
         hash_checking,
         python.as_deref(),
@@ -167,14 +178,17 @@ enum BuildResult {
 async fn build_impl(
     project_dir: &Path,
     src: Option<&Path>,
-    package: Option<&PackageName>,
+    packages: &[PackageName],
     all_packages: bool,
+    since: Option<&str>,
     output_dir: Option<&Path>,
+    output_dir_template: Option<&str>,
     sdist: bool,
     wheel: bool,
     list: bool,
     build_logs: bool,
     force_pep517: bool,
+    source_date_epoch: Option<u64>,
     build_constraints: &[RequirementsSource],
     hash_checking: Option<HashCheckingMode>,
     python_request: Option<&str>,
@@ -195,8 +209,12 @@ async fn build_impl(
         index_strategy,
         keyring_provider,
         resolution: _,
+        resolution_overrides: _,
+        allow_prereleases: _,
         prerelease: _,
         fork_strategy: _,
+        hash_algorithm: _,
+        prefer_local_versions: _,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -209,10 +227,15 @@ async fn build_impl(
         upgrade: _,
         build_options,
         sources,
+        user_constraints: _,
+        user_overrides: _,
+        configured_no_binary_package: _,
+        configured_no_build_package: _,
     } = settings;
 
     let client_builder = BaseClientBuilder::default()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -248,138 +271,193 @@ async fn build_impl(
     )
     .await;
 
-    // If a `--package` or `--all-packages` was provided, adjust the source directory.
-    let packages = if let Some(package) = package {
+    // If a `--package`, `--all-packages`, or `--since` was provided, adjust the source directory.
+    let mut skipped = Vec::new();
+    let targets = if !packages.is_empty() || all_packages || since.is_some() {
+        let flag = if !packages.is_empty() {
+            "--package"
+        } else if all_packages {
+            "--all-packages"
+        } else {
+            "--since"
+        };
+
         if matches!(src, Source::File(_)) {
             return Err(anyhow::anyhow!(
-                "Cannot specify `--package` when building from a file"
+                "Cannot specify `{flag}` when building from a file"
             ));
         }
 
         let workspace = match workspace {
             Ok(ref workspace) => workspace,
             Err(err) => {
-                return Err(err).context("`--package` was provided, but no workspace was found");
+                return Err(err)
+                    .with_context(|| format!("`{flag}` was provided, but no workspace was found"));
             }
         };
 
-        let package = workspace
-            .packages()
-            .get(package)
-            .ok_or_else(|| anyhow::anyhow!("Package `{package}` not found in workspace"))?;
+        let mut members = if packages.is_empty() {
+            if workspace.packages().is_empty() {
+                return Err(anyhow::anyhow!("No packages found in workspace"));
+            }
 
-        if !package.pyproject_toml().is_package(true) {
-            let name = &package.project().name;
-            let pyproject_toml = package.root().join("pyproject.toml");
-            return Err(anyhow::anyhow!(
-                "Package `{}` is missing a `{}`. For example, to build with `{}`, add the following to `{}`:\n```toml\n[build-system]\nrequires = [\"setuptools\"]\nbuild-backend = \"setuptools.build_meta\"\n```",
-                name.cyan(),
-                "build-system".green(),
-                "setuptools".cyan(),
-                pyproject_toml.user_display().cyan()
-            ));
-        }
+            let (members, non_packages): (Vec<_>, Vec<_>) = workspace
+                .packages()
+                .values()
+                .partition(|member| member.pyproject_toml().is_package(true));
 
-        vec![AnnotatedSource::from(Source::Directory(Cow::Borrowed(
-            package.root(),
-        )))]
-    } else if all_packages {
-        if matches!(src, Source::File(_)) {
-            return Err(anyhow::anyhow!(
-                "Cannot specify `--all-packages` when building from a file"
-            ));
-        }
+            if members.is_empty() {
+                let member = workspace.packages().values().next().unwrap();
+                let name = &member.project().name;
+                let pyproject_toml = member.root().join("pyproject.toml");
+                return Err(anyhow::anyhow!(
+                    "Workspace does not contain any buildable packages. For example, to build `{}` with `{}`, add a `{}` to `{}`:\n```toml\n[build-system]\nrequires = [\"setuptools\"]\nbuild-backend = \"setuptools.build_meta\"\n```",
+                    name.cyan(),
+                    "setuptools".cyan(),
+                    "build-system".green(),
+                    pyproject_toml.user_display().cyan()
+                ));
+            }
 
-        let workspace = match workspace {
-            Ok(ref workspace) => workspace,
-            Err(err) => {
-                return Err(err)
-                    .context("`--all-packages` was provided, but no workspace was found");
+            skipped.extend(non_packages.into_iter().map(|member| {
+                (
+                    member.project().name.clone(),
+                    format!("no `{}`", "build-system".green()),
+                )
+            }));
+
+            members
+        } else {
+            let mut members = Vec::with_capacity(packages.len());
+            for package in packages.iter().unique() {
+                let member = workspace
+                    .packages()
+                    .get(package)
+                    .ok_or_else(|| anyhow::anyhow!("Package `{package}` not found in workspace"))?;
+
+                if !member.pyproject_toml().is_package(true) {
+                    let name = &member.project().name;
+                    let pyproject_toml = member.root().join("pyproject.toml");
+                    return Err(anyhow::anyhow!(
+                        "Package `{}` is missing a `{}`. For example, to build with `{}`, add the following to `{}`:\n```toml\n[build-system]\nrequires = [\"setuptools\"]\nbuild-backend = \"setuptools.build_meta\"\n```",
+                        name.cyan(),
+                        "build-system".green(),
+                        "setuptools".cyan(),
+                        pyproject_toml.user_display().cyan()
+                    ));
+                }
+
+                members.push(member);
             }
+            members
         };
 
-        if workspace.packages().is_empty() {
-            return Err(anyhow::anyhow!("No packages found in workspace"));
+        // Skip any members without changes since the given reference.
+        if let Some(since) = since {
+            let changed = changed_members(workspace, since)?;
+            members.retain(|member| {
+                let name = &member.project().name;
+                if changed.contains(name) {
+                    true
+                } else {
+                    skipped.push((name.clone(), format!("no changes since `{}`", since.cyan())));
+                    false
+                }
+            });
         }
+        skipped.sort();
 
-        let packages: Vec<_> = workspace
-            .packages()
-            .values()
-            .filter(|package| package.pyproject_toml().is_package(true))
-            .map(|package| AnnotatedSource {
-                source: Source::Directory(Cow::Borrowed(package.root())),
-                package: Some(package.project().name.clone()),
+        // Annotate the output with the package name, unless a single package was requested.
+        let annotate = packages.len() != 1;
+        members
+            .into_iter()
+            .map(|member| {
+                let name = &member.project().name;
+                let output_dir = output_dir_template
+                    .map(|template| PathBuf::from(template.replace("{name}", name.as_str())));
+                let source = AnnotatedSource {
+                    source: Source::Directory(Cow::Borrowed(member.root())),
+                    package: annotate.then(|| name.clone()),
+                };
+                (source, output_dir)
             })
-            .collect();
-
-        if packages.is_empty() {
-            let member = workspace.packages().values().next().unwrap();
-            let name = &member.project().name;
-            let pyproject_toml = member.root().join("pyproject.toml");
-            return Err(anyhow::anyhow!(
-                "Workspace does not contain any buildable packages. For example, to build `{}` with `{}`, add a `{}` to `{}`:\n```toml\n[build-system]\nrequires = [\"setuptools\"]\nbuild-backend = \"setuptools.build_meta\"\n```",
-                name.cyan(),
-                "setuptools".cyan(),
-                "build-system".green(),
-                pyproject_toml.user_display().cyan()
-            ));
-        }
-
-        packages
+            .collect::<Vec<_>>()
     } else {
-        vec![AnnotatedSource::from(src)]
+        let output_dir = if let Some(template) = output_dir_template {
+            // Determine the name of the package from the workspace member at the source directory.
+            let member = workspace.as_ref().ok().and_then(|workspace| {
+                workspace
+                    .packages()
+                    .values()
+                    .find(|member| member.root() == src.path())
+            });
+            let Some(member) = member else {
+                return Err(anyhow::anyhow!(
+                    "`--out-dir-template` requires a project to build, but `{}` is not a project",
+                    src.path().user_display()
+                ));
+            };
+            Some(PathBuf::from(
+                template.replace("{name}", member.project().name.as_str()),
+            ))
+        } else {
+            None
+        };
+        vec![(AnnotatedSource::from(src), output_dir)]
     };
 
-    let results: Vec<_> = futures::future::join_all(packages.into_iter().map(|source| {
-        let future = build_package(
-            source.clone(),
-            output_dir,
-            python_request,
-            install_mirrors.clone(),
-            no_config,
-            workspace.as_ref(),
-            python_preference,
-            python_downloads,
-            cache,
-            printer,
-            index_locations,
-            client_builder.clone(),
-            hash_checking,
-            build_logs,
-            force_pep517,
-            build_constraints,
-            *no_build_isolation,
-            no_build_isolation_package,
-            extra_build_dependencies,
-            extra_build_variables,
-            *index_strategy,
-            *keyring_provider,
-            exclude_newer.clone(),
-            *sources,
-            concurrency,
-            build_options,
-            sdist,
-            wheel,
-            list,
-            dependency_metadata,
-            *link_mode,
-            config_setting,
-            config_settings_package,
-            preview,
-        );
-        async {
-            let result = future.await;
-            (source, result)
-        }
-    }))
-    .await;
+    let results: Vec<_> =
+        futures::future::join_all(targets.iter().map(|(source, source_output_dir)| {
+            let future = build_package(
+                source.clone(),
+                source_output_dir.as_deref().or(output_dir),
+                python_request,
+                install_mirrors.clone(),
+                no_config,
+                workspace.as_ref(),
+                python_preference,
+                python_downloads,
+                cache,
+                printer,
+                index_locations,
+                client_builder.clone(),
+                hash_checking,
+                build_logs,
+                force_pep517,
+                source_date_epoch,
+                build_constraints,
+                *no_build_isolation,
+                no_build_isolation_package,
+                extra_build_dependencies,
+                extra_build_variables,
+                *index_strategy,
+                *keyring_provider,
+                exclude_newer.clone(),
+                *sources,
+                concurrency,
+                build_options,
+                sdist,
+                wheel,
+                list,
+                dependency_metadata,
+                *link_mode,
+                config_setting,
+                config_settings_package,
+                preview,
+            );
+            async {
+                let result = future.await;
+                (source, result)
+            }
+        }))
+        .await;
 
     let mut success = true;
     for (source, result) in results {
         match result {
             Ok(messages) => {
                 for message in messages {
-                    message.print(printer)?;
+                    message.print(&source.message_prefix(), printer)?;
                 }
             }
             Err(err) => {
@@ -403,6 +481,10 @@ async fn build_impl(
         }
     }
 
+    for (package, reason) in skipped {
+        writeln!(printer.stderr(), "Skipped `{}` ({reason})", package.cyan())?;
+    }
+
     if success {
         Ok(BuildResult::Success)
     } else {
@@ -427,6 +509,7 @@ async fn build_package(
     hash_checking: Option<HashCheckingMode>,
     build_logs: bool,
     force_pep517: bool,
+    source_date_epoch: Option<u64>,
     build_constraints: &[RequirementsSource],
     no_build_isolation: bool,
     no_build_isolation_package: &[PackageName],
@@ -521,6 +604,7 @@ async fn build_package(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&interpreter.resolver_marker_environment()),
             hash_checking,
+            false,
         )?
     } else {
         HashStrategy::None
@@ -595,6 +679,9 @@ async fn build_package(
         workspace_cache,
         concurrency,
         preview,
+    )
+    .with_build_extra_env_vars(
+        source_date_epoch.map(|epoch| (EnvVars::SOURCE_DATE_EPOCH, epoch.to_string())),
     );
 
     prepare_output_directory(&output_dir).await?;
@@ -634,7 +721,8 @@ async fn build_package(
                 BuildOutput::Quiet
             }
         }
-        Printer::Quiet | Printer::Silent => BuildOutput::Quiet,
+        // Avoid interleaving build logs with the JSON progress events.
+        Printer::Quiet | Printer::Silent | Printer::JsonProgress => BuildOutput::Quiet,
     };
 
     let mut build_results = Vec::new();
@@ -650,6 +738,7 @@ async fn build_package(
                     &source,
                     printer,
                     "source distribution",
+                    source_date_epoch,
                     &build_dispatch,
                     sources,
                     dist,
@@ -667,6 +756,7 @@ async fn build_package(
                 &source,
                 printer,
                 "source distribution",
+                source_date_epoch,
                 &build_dispatch,
                 sources,
                 dist,
@@ -699,6 +789,7 @@ async fn build_package(
                 &source,
                 printer,
                 "wheel from source distribution",
+                source_date_epoch,
                 &build_dispatch,
                 sources,
                 dist,
@@ -718,6 +809,7 @@ async fn build_package(
                 &source,
                 printer,
                 "source distribution",
+                source_date_epoch,
                 &build_dispatch,
                 sources,
                 dist,
@@ -736,6 +828,7 @@ async fn build_package(
                 &source,
                 printer,
                 "wheel",
+                source_date_epoch,
                 &build_dispatch,
                 sources,
                 dist,
@@ -755,6 +848,7 @@ async fn build_package(
                 &source,
                 printer,
                 "source distribution",
+                source_date_epoch,
                 &build_dispatch,
                 sources,
                 dist,
@@ -771,6 +865,7 @@ async fn build_package(
                 &source,
                 printer,
                 "wheel",
+                source_date_epoch,
                 &build_dispatch,
                 sources,
                 dist,
@@ -814,6 +909,7 @@ async fn build_package(
                 &source,
                 printer,
                 "wheel from source distribution",
+                source_date_epoch,
                 &build_dispatch,
                 sources,
                 dist,
@@ -861,6 +957,7 @@ async fn build_sdist(
     source: &AnnotatedSource<'_>,
     printer: Printer,
     build_kind_message: &str,
+    source_date_epoch: Option<u64>,
     // Below is only used with PEP 517 builds
     build_dispatch: &BuildDispatch<'_>,
     sources: SourceStrategy,
@@ -902,6 +999,7 @@ async fn build_sdist(
                     &source_tree,
                     &output_dir_,
                     uv_version::version(),
+                    source_date_epoch,
                 )
             })
             .await??
@@ -964,6 +1062,7 @@ async fn build_wheel(
     source: &AnnotatedSource<'_>,
     printer: Printer,
     build_kind_message: &str,
+    source_date_epoch: Option<u64>,
     // Below is only used with PEP 517 builds
     build_dispatch: &BuildDispatch<'_>,
     sources: SourceStrategy,
@@ -1008,6 +1107,7 @@ async fn build_wheel(
                     &output_dir_,
                     None,
                     uv_version::version(),
+                    source_date_epoch,
                 )
             })
             .await??;
@@ -1081,6 +1181,64 @@ async fn prepare_output_directory(output_dir: &Path) -> Result<(), Error> {
     Ok(())
 }
 
+/// Return the names of the workspace members with changes since the given Git reference.
+///
+/// Each changed file is attributed to the innermost member that contains it.
+fn changed_members<'a>(workspace: &'a Workspace, since: &str) -> Result<BTreeSet<&'a PackageName>> {
+    let Ok(git) = GIT.as_ref() else {
+        return Err(anyhow::anyhow!(
+            "`--since` requires Git, but `git` could not be found"
+        ));
+    };
+
+    // Collect the files that differ from the reference, along with any untracked files.
+    let root = workspace.install_path();
+    let mut paths = git_paths(
+        git,
+        root,
+        &["diff", "--name-only", "--relative", "-z", since, "--"],
+    )?;
+    paths.extend(git_paths(
+        git,
+        root,
+        &["ls-files", "--others", "--exclude-standard", "-z"],
+    )?);
+
+    Ok(paths
+        .iter()
+        .filter_map(|path| {
+            workspace
+                .packages()
+                .values()
+                .filter(|member| path.starts_with(member.root()))
+                .max_by_key(|member| member.root().components().count())
+        })
+        .map(|member| &member.project().name)
+        .collect())
+}
+
+/// Run a Git command that returns a NUL-separated list of paths, relative to the given directory.
+fn git_paths(git: &Path, root: &Path, args: &[&str]) -> Result<Vec<PathBuf>> {
+    let output = Command::new(git)
+        .args(args)
+        .env(EnvVars::LC_ALL, "C")
+        .current_dir(root)
+        .output()?;
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "`git {}` failed:\n{}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(output
+        .stdout
+        .split(|byte| *byte == b'\0')
+        .filter(|path| !path.is_empty())
+        .map(|path| root.join(&*String::from_utf8_lossy(path)))
+        .collect())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct AnnotatedSource<'a> {
     /// The underlying [`Source`] to build.
@@ -1203,7 +1361,7 @@ impl BuildMessage {
         }
     }
 
-    fn print(&self, printer: Printer) -> Result<()> {
+    fn print(&self, prefix: &str, printer: Printer) -> Result<()> {
         match self {
             Self::Build {
                 raw_filename,
@@ -1212,7 +1370,7 @@ impl BuildMessage {
             } => {
                 writeln!(
                     printer.stderr(),
-                    "Successfully built {}",
+                    "{prefix}Successfully built {}",
                     output_dir.join(raw_filename).user_display().bold().cyan()
                 )?;
             }
@@ -3,7 +3,9 @@ use std::fmt::Write;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use uv_cache::{Cache, Removal};
+use uv_cache::{Cache, CacheBucket, Removal};
+use uv_cli::CacheBucketName;
+use uv_distribution_types::IndexUrl;
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
@@ -11,9 +13,11 @@ use crate::commands::reporters::{CleaningDirectoryReporter, CleaningPackageRepor
 use crate::commands::{ExitStatus, human_readable_bytes};
 use crate::printer::Printer;
 
-/// Clear the cache, removing all entries or those linked to specific packages.
+/// Clear the cache, removing all entries or those linked to specific packages or a specific index.
 pub(crate) fn cache_clean(
     packages: &[PackageName],
+    bucket: Option<CacheBucketName>,
+    index: Option<&IndexUrl>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -26,7 +30,19 @@ pub(crate) fn cache_clean(
         return Ok(ExitStatus::Success);
     }
 
-    let summary = if packages.is_empty() {
+    if let Some(index) = index {
+        writeln!(
+            printer.stderr(),
+            "Clearing cache entries for index: {}",
+            index.cyan()
+        )?;
+
+        let summary = cache
+            .remove_index(index)
+            .with_context(|| format!("Failed to clear cache entries for index: {index}"))?;
+
+        write_summary(&summary, None, printer)?;
+    } else if packages.is_empty() {
         writeln!(
             printer.stderr(),
             "Clearing cache at: {}",
@@ -36,23 +52,40 @@ pub(crate) fn cache_clean(
         let num_paths = walkdir::WalkDir::new(cache.root()).into_iter().count();
         let reporter = CleaningDirectoryReporter::new(printer, num_paths);
 
-        cache
-            .clear(Box::new(reporter))
-            .with_context(|| format!("Failed to clear cache at: {}", cache.root().user_display()))?
+        let summary = cache.clear(Box::new(reporter)).with_context(|| {
+            format!("Failed to clear cache at: {}", cache.root().user_display())
+        })?;
+
+        write_summary(&summary, None, printer)?;
     } else {
         let reporter = CleaningPackageReporter::new(printer, packages.len());
-        let mut summary = Removal::default();
+        let mut removals = Vec::with_capacity(packages.len());
 
         for package in packages {
-            let removed = cache.remove(package)?;
-            summary += removed;
-            reporter.on_clean(package.as_str(), &summary);
+            let removal = cache.remove(package, bucket.map(CacheBucket::from))?;
+            reporter.on_clean(package.as_str(), &removal);
+            removals.push((package, removal));
         }
         reporter.on_complete();
 
-        summary
-    };
+        // Write a summary for each package, followed by the total if multiple packages were
+        // requested.
+        let mut summary = Removal::default();
+        for (package, removal) in removals {
+            write_summary(&removal, Some(package), printer)?;
+            summary += removal;
+        }
+        if packages.len() > 1 {
+            write_summary(&summary, None, printer)?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
 
+/// Write a summary of the number of files, directories, and bytes removed, optionally scoped to a
+/// specific package.
+fn write_summary(summary: &Removal, package: Option<&PackageName>, printer: Printer) -> Result<()> {
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
         (0, 0) => {
@@ -72,6 +105,10 @@ pub(crate) fn cache_clean(
         }
     }
 
+    if let Some(package) = package {
+        write!(printer.stderr(), " for {}", package.cyan())?;
+    }
+
     // If any, write a summary of the total byte count removed.
     if summary.total_bytes > 0 {
         let bytes = if summary.total_bytes < 1024 {
@@ -85,5 +122,5 @@ pub(crate) fn cache_clean(
 
     writeln!(printer.stderr())?;
 
-    Ok(ExitStatus::Success)
+    Ok(())
 }
@@ -1,10 +1,40 @@
 use anstream::println;
+use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 
-use uv_cache::Cache;
+use uv_cache::{Cache, CacheBucket};
+use uv_cli::CacheBucketName;
 use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+use crate::commands::ExitStatus;
 
 /// Show the cache directory.
 pub(crate) fn cache_dir(cache: &Cache) {
     println!("{}", cache.root().simplified_display().cyan());
 }
+
+/// Show the cache entries for a specific package.
+///
+/// Returns a failure status if the package isn't present in the cache.
+pub(crate) fn cache_dir_package(
+    package: &PackageName,
+    version: Option<&Version>,
+    bucket: Option<CacheBucketName>,
+    cache: &Cache,
+) -> Result<ExitStatus> {
+    let paths = cache
+        .find(package, version, bucket.map(CacheBucket::from))
+        .with_context(|| format!("Failed to read cache entries for: `{package}`"))?;
+
+    if paths.is_empty() {
+        return Ok(ExitStatus::Failure);
+    }
+
+    for path in paths {
+        println!("{}", path.simplified_display().cyan());
+    }
+
+    Ok(ExitStatus::Success)
+}
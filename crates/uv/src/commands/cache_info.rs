@@ -0,0 +1,158 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::debug;
+
+use uv_cache::{Cache, CacheBucket, CacheSize};
+use uv_cli::{CacheBucketName, CacheInfoFormat};
+use uv_client::DataWithCachePolicy;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// An entry in the cache for a given package.
+#[derive(Debug, Serialize)]
+struct CacheInfoEntry {
+    /// The bucket containing the entry.
+    bucket: &'static str,
+    /// The path to the entry.
+    path: String,
+    /// The size of the entry, in bytes.
+    size: u64,
+    /// For registry metadata, the time at which the metadata was fetched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fetched_at: Option<jiff::Timestamp>,
+    /// For registry metadata, the `ETag` of the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    etag: Option<String>,
+    /// For wheels and source distributions, the time at which the entry was last modified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    modified_at: Option<jiff::Timestamp>,
+}
+
+#[derive(Debug, Serialize)]
+struct CacheInfo<'a> {
+    package: &'a PackageName,
+    entries: Vec<CacheInfoEntry>,
+}
+
+/// Show the cache entries for a specific package.
+pub(crate) fn cache_info(
+    package: &PackageName,
+    output_format: CacheInfoFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let mut entries = Vec::new();
+    for (name, bucket) in [
+        (CacheBucketName::Metadata, "metadata"),
+        (CacheBucketName::Wheels, "wheels"),
+        (CacheBucketName::Sdists, "sdists"),
+    ] {
+        let paths = cache
+            .find(package, None, Some(CacheBucket::from(name)))
+            .with_context(|| format!("Failed to read cache entries for: `{package}`"))?;
+        for path in paths {
+            entries.push(read_entry(&path, name, bucket)?);
+        }
+    }
+
+    match output_format {
+        CacheInfoFormat::Json => {
+            let info = CacheInfo { package, entries };
+            writeln!(printer.stdout(), "{}", serde_json::to_string(&info)?)?;
+        }
+        CacheInfoFormat::Text => {
+            if entries.is_empty() {
+                writeln!(
+                    printer.stderr(),
+                    "No cache entries found for {}",
+                    package.cyan()
+                )?;
+                return Ok(ExitStatus::Success);
+            }
+
+            let mut heading = None;
+            for entry in entries {
+                if heading != Some(entry.bucket) {
+                    writeln!(printer.stdout(), "{}:", entry.bucket.bold())?;
+                    heading = Some(entry.bucket);
+                }
+
+                let mut details = vec![format_bytes(entry.size)];
+                if let Some(fetched_at) = entry.fetched_at {
+                    details.push(format!("fetched {fetched_at}"));
+                }
+                if let Some(etag) = entry.etag {
+                    details.push(format!("etag {etag}"));
+                }
+                if let Some(modified_at) = entry.modified_at {
+                    details.push(format!("modified {modified_at}"));
+                }
+                writeln!(
+                    printer.stdout(),
+                    "  {} {}",
+                    entry.path.cyan(),
+                    format!("({})", details.join(", ")).dimmed()
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Read the details of a cache entry.
+fn read_entry(path: &Path, name: CacheBucketName, bucket: &'static str) -> Result<CacheInfoEntry> {
+    let size = CacheSize::from_path(path)
+        .with_context(|| format!("Failed to read cache entry at: {}", path.user_display()))?;
+
+    let mut entry = CacheInfoEntry {
+        bucket,
+        path: path.simplified_display().to_string(),
+        size: size.physical_bytes,
+        fetched_at: None,
+        etag: None,
+        modified_at: None,
+    };
+
+    if name == CacheBucketName::Metadata {
+        // Registry metadata is stored alongside the HTTP cache policy of the response.
+        match DataWithCachePolicy::from_path_sync(path) {
+            Ok(data) => {
+                entry.fetched_at = i64::try_from(data.fetched_at())
+                    .ok()
+                    .and_then(|seconds| jiff::Timestamp::from_second(seconds).ok());
+                entry.etag = data.etag().map(ToString::to_string);
+            }
+            Err(err) => {
+                debug!(
+                    "Failed to read cache policy from {}: {err}",
+                    path.user_display()
+                );
+            }
+        }
+    } else {
+        entry.modified_at = fs_err::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| jiff::Timestamp::try_from(modified).ok());
+    }
+
+    Ok(entry)
+}
+
+/// Format a byte count in human-readable units.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt::Write;
+
+use anyhow::{Context, Result};
+
+use uv_cache::Cache;
+use uv_fs::Simplified;
+
+use crate::commands::{ExitStatus, human_readable_bytes};
+use crate::printer::Printer;
+
+/// Display the size of the cache.
+pub(crate) fn cache_size(
+    cache: &Cache,
+    human: bool,
+    shared: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let size = cache
+        .size()
+        .with_context(|| format!("Failed to read cache at: {}", cache.root().user_display()))?;
+
+    if shared {
+        writeln!(
+            printer.stdout(),
+            "Logical size: {}",
+            format_bytes(size.logical_bytes, human)
+        )?;
+        writeln!(
+            printer.stdout(),
+            "Physical size: {}",
+            format_bytes(size.physical_bytes, human)
+        )?;
+        writeln!(
+            printer.stdout(),
+            "Shared: {}",
+            format_bytes(size.shared_bytes(), human)
+        )?;
+    } else {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            format_bytes(size.physical_bytes, human)
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Format a byte count, optionally in human-readable units.
+fn format_bytes(bytes: u64, human: bool) -> String {
+    if !human {
+        bytes.to_string()
+    } else if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
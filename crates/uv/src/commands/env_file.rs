@@ -0,0 +1,342 @@
+//! Loading of dotenv files for `uv run` and `uv tool run`.
+//!
+//! The variables are passed to the spawned command, rather than applied to uv's own environment.
+//!
+//! Files are applied in order, with later files overriding values defined in earlier files.
+//! Variables that are already set in the ambient environment always take precedence.
+//!
+//! Values support `${VAR}` and `$VAR` interpolation against the ambient environment and any
+//! variables defined earlier (in the same file or a previous file). A literal `$` can be written
+//! as `$$`. Single-quoted values are taken verbatim.
+
+use std::path::PathBuf;
+
+use rustc_hash::FxHashMap;
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum EnvFileError {
+    #[error("No environment file found at: `{}`", _0.user_display())]
+    NotFound(PathBuf),
+    #[error("Failed to read environment file `{}`", _0.user_display())]
+    Io(PathBuf, #[source] std::io::Error),
+    #[error("Failed to parse environment file `{}` at line {}: {}", path.user_display(), err.line, err.message)]
+    Parse { path: PathBuf, err: ParseError },
+}
+
+/// An error encountered while parsing a single line of a dotenv file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    /// The (one-based) line number at which the error occurred.
+    line: usize,
+    message: String,
+}
+
+/// Read the given dotenv files, returning the variables to set on the spawned command, in order.
+pub(crate) fn load_env_files(paths: &[PathBuf]) -> Result<Vec<(String, String)>, EnvFileError> {
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut defined: FxHashMap<String, String> = FxHashMap::default();
+    let mut order = Vec::new();
+    for path in paths {
+        let content = match fs_err::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(EnvFileError::NotFound(path.clone()));
+            }
+            Err(err) => return Err(EnvFileError::Io(path.clone(), err)),
+        };
+
+        let entries = parse(&content, |name| {
+            std::env::var(name)
+                .ok()
+                .or_else(|| defined.get(name).cloned())
+        })
+        .map_err(|err| EnvFileError::Parse {
+            path: path.clone(),
+            err,
+        })?;
+
+        for (key, value) in entries {
+            // Never override variables from the ambient environment.
+            if std::env::var_os(&key).is_some() {
+                continue;
+            }
+            if defined.insert(key.clone(), value).is_none() {
+                order.push(key);
+            }
+        }
+
+        debug!("Read environment file at: `{}`", path.simplified_display());
+    }
+
+    Ok(order
+        .into_iter()
+        .map(|key| {
+            let value = defined.remove(&key).unwrap_or_default();
+            (key, value)
+        })
+        .collect())
+}
+
+/// Parse the contents of a dotenv file into an ordered list of assignments.
+///
+/// The `lookup` function resolves variables that were defined before the current file; variables
+/// defined earlier in the same file are resolved internally.
+fn parse(
+    content: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<Vec<(String, String)>, ParseError> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+    let mut local: FxHashMap<String, String> = FxHashMap::default();
+
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+    while let Some((number, line)) = lines.next() {
+        let error = |message: String| ParseError {
+            line: number,
+            message,
+        };
+
+        let line = line.trim_start();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").map_or(line, str::trim_start);
+
+        // Parse the key.
+        let end = line
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .unwrap_or(line.len());
+        let (key, rest) = line.split_at(end);
+        if key.is_empty() || key.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(error(format!("Invalid variable name in `{line}`")));
+        }
+        let Some(rest) = rest.trim_start().strip_prefix('=') else {
+            return Err(error(format!("Expected `=` after `{key}`")));
+        };
+        let rest = rest.trim_start();
+
+        let resolve = |name: &str| -> Option<String> {
+            std::env::var(name)
+                .ok()
+                .or_else(|| local.get(name).cloned())
+                .or_else(|| lookup(name))
+        };
+
+        let value = if let Some(quote) = rest.chars().next().filter(|c| matches!(c, '\'' | '"')) {
+            // Quoted values may span multiple lines.
+            let mut raw = String::new();
+            let mut remainder = &rest[1..];
+            let trailing = loop {
+                if let Some(close) = find_closing_quote(remainder, quote) {
+                    raw.push_str(&remainder[..close]);
+                    break &remainder[close + 1..];
+                }
+                raw.push_str(remainder);
+                raw.push('\n');
+                let Some((_, next)) = lines.next() else {
+                    return Err(error(format!("Unterminated quoted value for `{key}`")));
+                };
+                remainder = next;
+            };
+            let trailing = trailing.trim_start();
+            if !(trailing.is_empty() || trailing.starts_with('#')) {
+                return Err(error(format!(
+                    "Unexpected content after quoted value for `{key}`: `{trailing}`"
+                )));
+            }
+            if quote == '\'' {
+                raw
+            } else {
+                interpolate(&unescape(&raw), &resolve).map_err(error)?
+            }
+        } else {
+            // Unquoted values end at the first inline comment.
+            let raw = match rest.find(" #").or_else(|| rest.find("\t#")) {
+                Some(index) => &rest[..index],
+                None => rest,
+            };
+            interpolate(raw.trim_end(), &resolve).map_err(error)?
+        };
+
+        local.insert(key.to_string(), value.clone());
+        if let Some(entry) = entries.iter_mut().find(|(existing, _)| existing == key) {
+            entry.1 = value;
+        } else {
+            entries.push((key.to_string(), value));
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Find the index of the closing quote, skipping escaped quotes in double-quoted values.
+fn find_closing_quote(value: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in value.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' && quote == '"' {
+            escaped = true;
+        } else if c == quote {
+            return Some(index);
+        }
+    }
+    None
+}
+
+/// Process backslash escapes in a double-quoted value.
+///
+/// `\$` is preserved as `$$` so that it is treated as a literal `$` during interpolation.
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some('$') => result.push_str("$$"),
+            Some(c @ ('"' | '\\')) => result.push(c),
+            Some(c) => {
+                result.push('\\');
+                result.push(c);
+            }
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
+/// Expand `${VAR}` and `$VAR` references, treating `$$` as a literal `$`.
+///
+/// Undefined variables expand to an empty string.
+fn interpolate(value: &str, resolve: impl Fn(&str) -> Option<String>) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if c != '$' {
+            result.push(c);
+            continue;
+        }
+        match chars.peek().copied() {
+            Some((_, '$')) => {
+                chars.next();
+                result.push('$');
+            }
+            Some((start, '{')) => {
+                chars.next();
+                let Some(len) = value[start + 1..].find('}') else {
+                    return Err(format!("Unterminated variable reference in `{value}`"));
+                };
+                let name = &value[start + 1..start + 1 + len];
+                if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                    return Err(format!("Invalid variable reference `${{{name}}}`"));
+                }
+                result.push_str(&resolve(name).unwrap_or_default());
+                for _ in 0..=len {
+                    chars.next();
+                }
+            }
+            Some((start, c)) if c.is_ascii_alphabetic() || c == '_' => {
+                let end = value[start..]
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .map_or(value.len(), |len| start + len);
+                result.push_str(&resolve(&value[start..end]).unwrap_or_default());
+                while chars.peek().is_some_and(|(index, _)| *index < end) {
+                    chars.next();
+                }
+            }
+            _ => result.push('$'),
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_with(
+        content: &str,
+        outer: &[(&str, &str)],
+    ) -> Result<Vec<(String, String)>, ParseError> {
+        parse(content, |name| {
+            outer
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).to_string())
+        })
+    }
+
+    fn pairs(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+        entries
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn assignments() {
+        let content =
+            "# comment\n\nexport A=1\nB = two # trailing\nC='single $A'\nD=\"double\\n$A\"\n";
+        assert_eq!(
+            parse_with(content, &[]).unwrap(),
+            pairs(&[
+                ("A", "1"),
+                ("B", "two"),
+                ("C", "single $A"),
+                ("D", "double\n1")
+            ])
+        );
+    }
+
+    #[test]
+    fn interpolation() {
+        let content = "UV_TEST_HOST=localhost\nURL=http://${UV_TEST_HOST}:$UV_TEST_PORT/$$HOME\nESCAPED=\"\\$UV_TEST_HOST\"\nMISSING=${UV_TEST_ENV_FILE_UNDEFINED}\n";
+        assert_eq!(
+            parse_with(content, &[("UV_TEST_PORT", "8000")]).unwrap(),
+            pairs(&[
+                ("UV_TEST_HOST", "localhost"),
+                ("URL", "http://localhost:8000/$HOME"),
+                ("ESCAPED", "$UV_TEST_HOST"),
+                ("MISSING", ""),
+            ])
+        );
+    }
+
+    #[test]
+    fn multiline() {
+        let content = "KEY=\"first\nsecond\"\nNEXT=value\n";
+        assert_eq!(
+            parse_with(content, &[]).unwrap(),
+            pairs(&[("KEY", "first\nsecond"), ("NEXT", "value")])
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse_with("A=1\nTHE_^EMPIRE=vader\n", &[]).unwrap_err();
+        assert_eq!(err.line, 2);
+
+        let err = parse_with("A=1\n\nB\n", &[]).unwrap_err();
+        assert_eq!(err.line, 3);
+
+        let err = parse_with("A=${B\n", &[]).unwrap_err();
+        assert_eq!(err.line, 1);
+
+        let err = parse_with("A=\"open\nB=2\n", &[]).unwrap_err();
+        assert_eq!(err.line, 1);
+    }
+}
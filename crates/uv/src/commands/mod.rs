@@ -1,16 +1,20 @@
 use anstream::AutoStream;
 use anyhow::Context;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use std::borrow::Cow;
 use std::io::stdout;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
+use tracing::debug;
 
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
-pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_dir::{cache_dir, cache_dir_package};
+pub(crate) use cache_info::cache_info;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_size::cache_size;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -27,10 +31,12 @@ pub(crate) use project::init::{InitKind, InitProjectKind, init};
 pub(crate) use project::lock::lock;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{RunCommand, run};
-pub(crate) use project::sync::sync;
+pub(crate) use project::sync::{ForeignPackages, sync, sync_all_pythons};
 pub(crate) use project::tree::tree;
 pub(crate) use project::version::{project_version, self_version};
+pub(crate) use project::why::why;
 pub(crate) use publish::publish;
+pub(crate) use python::check::check as python_check;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
 pub(crate) use python::find::find_script as python_find_script;
@@ -51,13 +57,15 @@ pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
 use uv_cache::Cache;
 use uv_configuration::Concurrency;
-use uv_distribution_types::InstalledMetadata;
-use uv_fs::{CWD, Simplified};
-use uv_installer::compile_tree;
+use uv_distribution_types::{InstalledDist, InstalledMetadata};
+use uv_fs::{CWD, Simplified, normalize_path_buf};
+use uv_install_wheel::read_record_file;
+use uv_installer::{SitePackages, compile_tree};
 use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
 use uv_scripts::Pep723Script;
 pub(crate) use venv::venv;
+pub(crate) use workspace::list::list as workspace_list;
 
 use crate::printer::Printer;
 
@@ -65,8 +73,11 @@ pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_info;
 mod cache_prune;
+mod cache_size;
 mod diagnostics;
+mod env_file;
 mod help;
 pub(crate) mod pip;
 mod project;
@@ -77,6 +88,7 @@ pub(crate) mod reporters;
 mod self_update;
 mod tool;
 mod venv;
+mod workspace;
 
 #[derive(Copy, Clone)]
 pub(crate) enum ExitStatus {
@@ -146,14 +158,35 @@ pub(super) struct DryRunEvent<T: Display> {
 /// Compile all Python source files in site-packages to bytecode, to speed up the
 /// initial run of any subsequent executions.
 ///
+/// The source files installed by any package in `exclude` are left uncompiled.
+///
 /// See the `--compile` option on `pip sync` and `pip install`.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    exclude: &[PackageName],
     concurrency: &Concurrency,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
+
+    // Collect the files installed by the excluded packages.
+    let mut skipped = 0;
+    let mut excluded_files = FxHashSet::default();
+    if !exclude.is_empty() {
+        let site_packages = SitePackages::from_environment(venv)?;
+        for name in exclude {
+            let dists = site_packages.get_packages(name);
+            if dists.is_empty() {
+                continue;
+            }
+            for dist in dists {
+                excluded_files.extend(installed_files(dist)?);
+            }
+            skipped += 1;
+        }
+    }
+
     let mut files = 0;
     for site_packages in venv.site_packages() {
         let site_packages = CWD.join(site_packages);
@@ -162,6 +195,7 @@ pub(super) async fn compile_bytecode(
             venv.python_executable(),
             concurrency,
             cache.root(),
+            &excluded_files,
         )
         .await
         .with_context(|| {
@@ -172,11 +206,17 @@ pub(super) async fn compile_bytecode(
         })?;
     }
     let s = if files == 1 { "" } else { "s" };
+    let skipped = if skipped == 0 {
+        String::new()
+    } else {
+        let s = if skipped == 1 { "" } else { "s" };
+        format!(", skipped {}", format!("{skipped} package{s}").bold())
+    };
     writeln!(
         printer.stderr(),
         "{}",
         format!(
-            "Bytecode compiled {} {}",
+            "Bytecode compiled {}{skipped} {}",
             format!("{files} file{s}").bold(),
             format!("in {}", elapsed(start.elapsed())).dimmed()
         )
@@ -185,6 +225,26 @@ pub(super) async fn compile_bytecode(
     Ok(())
 }
 
+/// Return the absolute paths to the files installed by a distribution, as listed in its `RECORD`.
+fn installed_files(dist: &InstalledDist) -> anyhow::Result<Vec<PathBuf>> {
+    let (InstalledDist::Registry(_) | InstalledDist::Url(_)) = dist else {
+        debug!("Unable to determine the files installed by: {dist}");
+        return Ok(Vec::new());
+    };
+    let dist_info = CWD.join(dist.install_path());
+    let Some(site_packages) = dist_info.parent() else {
+        return Ok(Vec::new());
+    };
+    let record_path = dist_info.join("RECORD");
+    let mut record_file = fs_err::File::open(&record_path)?;
+    let record = read_record_file(&mut record_file)
+        .with_context(|| format!("Failed to read: {}", record_path.user_display()))?;
+    Ok(record
+        .into_iter()
+        .map(|entry| normalize_path_buf(site_packages.join(entry.path)))
+        .collect())
+}
+
 /// Formats a number of bytes into a human readable SI-prefixed size.
 ///
 /// Returns a tuple of `(quantity, units)`.
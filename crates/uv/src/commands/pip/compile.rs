@@ -1,10 +1,11 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::ffi::OsStr;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-use anyhow::{Result, anyhow};
+use anyhow::{Context, Result, anyhow};
+use clap::ValueEnum;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
@@ -13,23 +14,23 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, Constraints, ExportFormat, ExtrasSpecification,
-    IndexStrategy, NoBinary, NoBuild, PackageConfigSettings, Preview, PreviewFeatures, Reinstall,
-    SourceStrategy, Upgrade,
+    BuildOptions, Concurrency, ConfigSettings, Constraints, ExtrasSpecification,
+    HashAlgorithmPreference, IndexStrategy, NoBinary, NoBuild, PackageConfigSettings,
+    PipCompileFormat, Preview, PreviewFeatures, Reinstall, SourceStrategy, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
-use uv_distribution::LoweredExtraBuildDependencies;
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
 use uv_distribution_types::{
-    DependencyMetadata, ExtraBuildVariables, HashGeneration, Index, IndexLocations,
-    NameRequirementSpecification, Origin, Requirement, RequiresPython,
+    DependencyMetadata, ExtraBuildVariables, HashGeneration, HashPolicy, Index, IndexLocations,
+    Name, NameRequirementSpecification, Origin, Requirement, RequiresPython, UnresolvedRequirement,
     UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
 use uv_install_wheel::LinkMode;
 use uv_normalize::PackageName;
-use uv_pypi_types::{Conflicts, SupportedEnvironments};
+use uv_pypi_types::{Conflicts, HashAlgorithm, SupportedEnvironments};
 use uv_python::{
     EnvironmentPreference, PythonEnvironment, PythonInstallation, PythonPreference, PythonRequest,
     PythonVersion, VersionRequest,
@@ -41,8 +42,8 @@ use uv_requirements::{
 };
 use uv_resolver::{
     AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode,
-    ResolverEnvironment,
+    InMemoryIndex, OptionsBuilder, Preference, PrereleaseMode, PylockToml, PythonRequirement,
+    ResolutionMode, ResolverEnvironment,
 };
 use uv_torch::{TorchMode, TorchStrategy};
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
@@ -70,13 +71,15 @@ pub(crate) async fn pip_compile(
     extras: ExtrasSpecification,
     groups: GroupsSpecification,
     output_file: Option<&Path>,
-    format: Option<ExportFormat>,
+    format: Option<PipCompileFormat>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
     fork_strategy: ForkStrategy,
+    prefer_local_versions: bool,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     generate_hashes: bool,
+    hash_algorithm: Option<HashAlgorithmPreference>,
     no_emit_packages: Vec<PackageName>,
     include_extras: bool,
     include_markers: bool,
@@ -88,6 +91,9 @@ pub(crate) async fn pip_compile(
     include_build_options: bool,
     include_marker_expression: bool,
     include_index_annotation: bool,
+    emit_build_requirements: bool,
+    build_requirements_output_file: Option<&Path>,
+    split_by_platform: Option<&[TargetTriple]>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
@@ -142,16 +148,16 @@ pub(crate) async fn pip_compile(
     let format = format.unwrap_or_else(|| {
         let extension = output_file.and_then(Path::extension);
         if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("txt")) {
-            ExportFormat::RequirementsTxt
+            PipCompileFormat::RequirementsTxt
         } else if extension.is_some_and(|ext| ext.eq_ignore_ascii_case("toml")) {
-            ExportFormat::PylockToml
+            PipCompileFormat::PylockToml
         } else {
-            ExportFormat::RequirementsTxt
+            PipCompileFormat::RequirementsTxt
         }
     });
 
     // If the user is exporting to PEP 751, ensure the filename matches the specification.
-    if matches!(format, ExportFormat::PylockToml) {
+    if matches!(format, PipCompileFormat::PylockToml) {
         if let Some(file_name) = output_file
             .and_then(Path::file_name)
             .and_then(OsStr::to_str)
@@ -164,6 +170,25 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Splitting by platform derives additional files from a universal `requirements.txt` output.
+    if split_by_platform.is_some() {
+        if !universal {
+            return Err(anyhow!(
+                "The `--split-by-platform` option requires `--universal`"
+            ));
+        }
+        if output_file.is_none() {
+            return Err(anyhow!(
+                "The `--split-by-platform` option requires an output file (`--output-file`)"
+            ));
+        }
+        if !matches!(format, PipCompileFormat::RequirementsTxt) {
+            return Err(anyhow!(
+                "The `--split-by-platform` option is only supported for `requirements.txt` output"
+            ));
+        }
+    }
+
     // Respect `UV_PYTHON`
     if python.is_none() && python_version.is_none() {
         if let Ok(request) = std::env::var("UV_PYTHON") {
@@ -196,7 +221,8 @@ pub(crate) async fn pip_compile(
     }
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
@@ -218,6 +244,8 @@ pub(crate) async fn pip_compile(
         find_links,
         no_binary,
         no_build,
+        config_settings: requirements_config_settings,
+        config_settings_package: requirements_config_settings_package,
     } = RequirementsSpecification::from_sources(
         requirements,
         constraints,
@@ -369,6 +397,17 @@ pub(crate) async fn pip_compile(
         PythonRequirement::from_interpreter(&interpreter)
     };
 
+    // Determine the marker environments for each platform-specific output file.
+    let split_markers = if let Some(platforms) = split_by_platform {
+        let (_, base) = resolution_environment(python_version.clone(), None, &interpreter)?;
+        platforms
+            .iter()
+            .map(|platform| (*platform, platform.markers(&base)))
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
     // Determine the environment for the resolution.
     let (tags, resolver_env) = if universal {
         (
@@ -383,7 +422,7 @@ pub(crate) async fn pip_compile(
 
     // Generate, but don't enforce hashes for the requirements. PEP 751 _requires_ a hash to be
     // present, but otherwise, we omit them by default.
-    let hasher = if generate_hashes || matches!(format, ExportFormat::PylockToml) {
+    let hasher = if generate_hashes || matches!(format, PipCompileFormat::PylockToml) {
         HashStrategy::Generate(HashGeneration::All)
     } else {
         HashStrategy::None
@@ -435,10 +474,10 @@ pub(crate) async fn pip_compile(
     let LockedRequirements { preferences, git } =
         if let Some(output_file) = output_file.filter(|output_file| output_file.exists()) {
             match format {
-                ExportFormat::RequirementsTxt => LockedRequirements::from_preferences(
+                PipCompileFormat::RequirementsTxt => LockedRequirements::from_preferences(
                     read_requirements_txt(output_file, &upgrade).await?,
                 ),
-                ExportFormat::PylockToml => {
+                PipCompileFormat::PylockToml => {
                     read_pylock_toml_requirements(output_file, &upgrade).await?
                 }
             }
@@ -446,6 +485,21 @@ pub(crate) async fn pip_compile(
             LockedRequirements::default()
         };
 
+    // Carry forward any `--hash` values from pinned input requirements, giving them precedence
+    // over those in the existing output file.
+    let preferences = requirements
+        .iter()
+        .filter_map(|entry| match &entry.requirement {
+            UnresolvedRequirement::Named(requirement) => {
+                Preference::from_requirement(requirement, &entry.hashes).transpose()
+            }
+            UnresolvedRequirement::Unnamed(_) => None,
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .chain(preferences)
+        .collect::<Vec<_>>();
+
     // Populate the Git resolver.
     for ResolvedRepositoryReference { reference, sha } in git {
         debug!("Inserting Git reference into resolver: `{reference:?}` at `{sha}`");
@@ -455,6 +509,12 @@ pub(crate) async fn pip_compile(
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // Combine the `--config-settings` from the requirements files, with the command-line values
+    // taking precedence.
+    let config_settings = config_settings.with_defaults(requirements_config_settings);
+    let config_settings_package =
+        config_settings_package.with_defaults(requirements_config_settings_package);
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
@@ -519,6 +579,8 @@ pub(crate) async fn pip_compile(
         .resolution_mode(resolution_mode)
         .prerelease_mode(prerelease_mode)
         .fork_strategy(fork_strategy)
+        .prefer_local_versions(prefer_local_versions)
+        .hash_algorithm(hash_algorithm.map(HashAlgorithm::from))
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(index_strategy)
@@ -565,6 +627,42 @@ pub(crate) async fn pip_compile(
         }
     };
 
+    // If requested, resolve the build requirements for each source distribution.
+    let build_requirements = if emit_build_requirements {
+        let database = DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads);
+        let mut build_requirements =
+            BTreeMap::<(PackageName, String), BTreeSet<PackageName>>::new();
+        for (dist, _) in resolution.source_dists() {
+            let Some(requirements) = database
+                .build_requirements(dist, HashPolicy::None)
+                .await
+                .with_context(|| format!("Failed to resolve build requirements for `{dist}`"))?
+            else {
+                continue;
+            };
+            for requirement in requirements.distributions() {
+                build_requirements
+                    .entry((requirement.name().clone(), requirement.to_string()))
+                    .or_default()
+                    .insert(dist.name().clone());
+            }
+        }
+
+        // Build constraints can only pin a single version of each package.
+        for (name, pins) in &build_requirements.keys().chunk_by(|(name, _)| name.clone()) {
+            if pins.count() > 1 {
+                warn_user!(
+                    "Multiple versions of `{}` were resolved across build environments; the emitted build requirements cannot be used as build constraints as-is",
+                    name.cyan()
+                );
+            }
+        }
+
+        Some(build_requirements)
+    } else {
+        None
+    };
+
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
@@ -582,7 +680,7 @@ pub(crate) async fn pip_compile(
                 cmd(
                     include_index_url,
                     include_find_links,
-                    custom_compile_command
+                    custom_compile_command.clone()
                 )
             )
             .green()
@@ -590,7 +688,7 @@ pub(crate) async fn pip_compile(
     }
 
     match format {
-        ExportFormat::RequirementsTxt => {
+        PipCompileFormat::RequirementsTxt => {
             if include_marker_expression {
                 if let Some(marker_env) = resolver_env.marker_environment() {
                     let relevant_markers = resolution.marker_tree(&top_level_index, marker_env)?;
@@ -630,7 +728,8 @@ pub(crate) async fn pip_compile(
                 }
             }
 
-            // If necessary, include the `--no-binary` and `--only-binary` options.
+            // If necessary, include the `--no-binary`, `--only-binary`, and `--config-settings`
+            // options.
             if include_build_options {
                 match build_options.no_binary() {
                     NoBinary::None => {}
@@ -658,6 +757,16 @@ pub(crate) async fn pip_compile(
                         }
                     }
                 }
+                for (key, value) in config_settings.iter() {
+                    writeln!(writer, "--config-settings {key}={value}")?;
+                    wrote_preamble = true;
+                }
+                for (package, settings) in config_settings_package.iter() {
+                    for (key, value) in settings.iter() {
+                        writeln!(writer, "--config-settings-package {package}:{key}={value}")?;
+                        wrote_preamble = true;
+                    }
+                }
             }
 
             // If we wrote an index, add a newline to separate it from the requirements
@@ -681,7 +790,7 @@ pub(crate) async fn pip_compile(
                 )
             )?;
         }
-        ExportFormat::PylockToml => {
+        PipCompileFormat::PylockToml => {
             if include_marker_expression {
                 warn_user!(
                     "The `--emit-marker-expression` option is not supported for `pylock.toml` output"
@@ -722,6 +831,46 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Write a platform-specific file for each requested platform, alongside the universal output.
+    if let Some(output_file) = output_file {
+        for (platform, marker_env) in &split_markers {
+            let path = platform_output_file(output_file, platform);
+            let mut writer = OutputWriter::new(false, Some(&path));
+            if include_header {
+                writeln!(
+                    writer,
+                    "# This file was autogenerated by uv via the following command:"
+                )?;
+                writeln!(
+                    writer,
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command.clone()
+                    )
+                )?;
+            }
+            write!(
+                writer,
+                "{}",
+                DisplayResolutionGraph::new(
+                    &resolution,
+                    &resolver_env,
+                    &no_emit_packages,
+                    generate_hashes,
+                    include_extras,
+                    false,
+                    include_annotations,
+                    include_index_annotation,
+                    annotation_style,
+                )
+                .with_marker_environment(marker_env)
+            )?;
+            writer.commit().await?;
+        }
+    }
+
     // If any "unsafe" packages were excluded, notify the user.
     let excluded = no_emit_packages
         .into_iter()
@@ -739,6 +888,62 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Write the build requirements, either to a dedicated file or as a commented section.
+    if let Some(build_requirements) = build_requirements {
+        if let Some(build_requirements_output_file) = build_requirements_output_file {
+            let mut writer = OutputWriter::new(false, Some(build_requirements_output_file));
+            if include_header {
+                writeln!(
+                    writer,
+                    "# This file was autogenerated by uv via the following command:"
+                )?;
+                writeln!(
+                    writer,
+                    "#    {}",
+                    cmd(
+                        include_index_url,
+                        include_find_links,
+                        custom_compile_command
+                    )
+                )?;
+            }
+            for ((_, pin), sources) in &build_requirements {
+                writeln!(writer, "{pin}")?;
+                if include_annotations {
+                    writeln!(
+                        writer,
+                        "    # via {}",
+                        sources.iter().map(ToString::to_string).join(", ")
+                    )?;
+                }
+            }
+            writer.commit().await?;
+        } else if matches!(format, PipCompileFormat::PylockToml) {
+            warn_user!(
+                "The `--emit-build-requirements` option is not supported for `pylock.toml` output; use `--emit-build-requirements-output` instead"
+            );
+        } else if !build_requirements.is_empty() {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "{}",
+                "# The following build requirements were resolved for source distributions:"
+                    .green()
+            )?;
+            for ((_, pin), sources) in &build_requirements {
+                if include_annotations {
+                    writeln!(
+                        writer,
+                        "# {pin} (via {})",
+                        sources.iter().map(ToString::to_string).join(", ")
+                    )?;
+                } else {
+                    writeln!(writer, "# {pin}")?;
+                }
+            }
+        }
+    }
+
     // Commit the output to disk.
     writer.commit().await?;
 
@@ -748,6 +953,24 @@ pub(crate) async fn pip_compile(
     Ok(ExitStatus::Success)
 }
 
+/// Derive the path of a platform-specific output file, e.g., `requirements-linux.txt` for
+/// `requirements.txt`.
+fn platform_output_file(output_file: &Path, platform: &TargetTriple) -> PathBuf {
+    let name = platform
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_else(|| format!("{platform:?}").to_lowercase());
+    let stem = output_file
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .unwrap_or("requirements");
+    let file_name = match output_file.extension().and_then(OsStr::to_str) {
+        Some(extension) => format!("{stem}-{name}.{extension}"),
+        None => format!("{stem}-{name}"),
+    };
+    output_file.with_file_name(file_name)
+}
+
 /// Format the uv command used to generate the output file.
 #[allow(clippy::fn_params_excessive_bools)]
 fn cmd(
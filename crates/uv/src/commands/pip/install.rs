@@ -11,14 +11,15 @@ use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun, ExtrasSpecification,
     HashCheckingMode, IndexStrategy, PackageConfigSettings, Preview, PreviewFeatures, Reinstall,
-    SourceStrategy, Upgrade,
+    SourceStrategy, Upgrade, UpgradeStrategy,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, NameRequirementSpecification,
-    Origin, Requirement, Resolution, UnresolvedRequirementSpecification,
+    DependencyMetadata, ExtraBuildVariables, HashGeneration, Index, IndexLocations,
+    NameRequirementSpecification, Origin, Requirement, Resolution, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
@@ -63,8 +64,10 @@ pub(crate) async fn pip_install(
     groups: &GroupsSpecification,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
+    prefer_local_versions: bool,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
+    upgrade_strategy: UpgradeStrategy,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
@@ -75,6 +78,7 @@ pub(crate) async fn pip_install(
     link_mode: LinkMode,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
+    allow_unhashable_sources: bool,
     installer_metadata: bool,
     config_settings: &ConfigSettings,
     config_settings_package: &PackageConfigSettings,
@@ -113,7 +117,8 @@ pub(crate) async fn pip_install(
     }
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
@@ -134,6 +139,8 @@ pub(crate) async fn pip_install(
         find_links,
         no_binary,
         no_build,
+        config_settings: requirements_config_settings,
+        config_settings_package: requirements_config_settings_package,
         extras: _,
     } = operations::read_requirements(
         requirements,
@@ -154,6 +161,17 @@ pub(crate) async fn pip_install(
         }
     }
 
+    // Limit the upgrade to the requested packages, if requested.
+    let upgrade = upgrade.with_strategy(
+        upgrade_strategy,
+        requirements
+            .iter()
+            .filter_map(|requirement| match &requirement.requirement {
+                UnresolvedRequirement::Named(requirement) => Some(requirement.name.clone()),
+                UnresolvedRequirement::Unnamed(_) => None,
+            }),
+    );
+
     let constraints: Vec<NameRequirementSpecification> = constraints
         .iter()
         .cloned()
@@ -335,11 +353,20 @@ pub(crate) async fn pip_install(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             hash_checking,
+            allow_unhashable_sources,
         )?
     } else {
         HashStrategy::None
     };
 
+    // Under `--derive-hashes`, resolve without enforcing hashes, but compute them for every
+    // distribution, such that any missing hashes can be reported at once.
+    let resolve_hasher = if hash_checking.is_some_and(|mode| mode.is_derive()) {
+        HashStrategy::Generate(HashGeneration::All)
+    } else {
+        hasher.clone()
+    };
+
     // Incorporate any index locations from the provided sources.
     let index_locations = index_locations.combine(
         extra_index_urls
@@ -385,13 +412,22 @@ pub(crate) async fn pip_install(
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // Combine the `--config-settings` from the requirements files, with the command-line values
+    // taking precedence.
+    let config_settings = &config_settings
+        .clone()
+        .with_defaults(requirements_config_settings);
+    let config_settings_package = &config_settings_package
+        .clone()
+        .with_defaults(requirements_config_settings_package);
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+        FlatIndex::from_entries(entries, Some(&tags), &resolve_hasher, &build_options)
     };
 
     // Determine whether to enable build isolation.
@@ -413,6 +449,7 @@ pub(crate) async fn pip_install(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             HashCheckingMode::Verify,
+            false,
         )?
     } else {
         HashStrategy::None
@@ -512,6 +549,7 @@ pub(crate) async fn pip_install(
         let options = OptionsBuilder::new()
             .resolution_mode(resolution_mode)
             .prerelease_mode(prerelease_mode)
+            .prefer_local_versions(prefer_local_versions)
             .dependency_mode(dependency_mode)
             .exclude_newer(exclude_newer.clone())
             .index_strategy(index_strategy)
@@ -531,7 +569,7 @@ pub(crate) async fn pip_install(
             &groups,
             preferences,
             site_packages.clone(),
-            &hasher,
+            &resolve_hasher,
             &reinstall,
             &upgrade,
             Some(&tags),
@@ -558,6 +596,11 @@ pub(crate) async fn pip_install(
             }
         };
 
+        // Under `--derive-hashes`, report any distributions that lack a hash.
+        if let Some(mode) = hash_checking.filter(HashCheckingMode::is_derive) {
+            hasher.check_resolution(&resolution, mode)?;
+        }
+
         (resolution, hasher)
     };
 
@@ -599,6 +642,7 @@ pub(crate) async fn pip_install(
         &build_options,
         link_mode,
         compile,
+        &[],
         &hasher,
         &tags,
         &client,
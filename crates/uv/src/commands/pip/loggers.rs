@@ -10,7 +10,7 @@ use uv_distribution_types::{InstalledMetadata, Name};
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 
-use crate::commands::pip::operations::Changelog;
+use crate::commands::pip::operations::{Changelog, PlannedChange};
 use crate::commands::{ChangeEvent, ChangeEventKind, elapsed};
 use crate::printer::Printer;
 
@@ -35,6 +35,11 @@ pub(crate) trait InstallLogger {
 
     /// Log the completion of the operation.
     fn on_complete(&self, changelog: &Changelog, printer: Printer) -> fmt::Result;
+
+    /// Log the planned changes to the environment, in lieu of performing them, during a dry run.
+    fn on_dry_run(&self, _changes: &[PlannedChange], _printer: Printer) -> fmt::Result {
+        Ok(())
+    }
 }
 
 /// The default logger for install operations.
@@ -174,6 +179,43 @@ impl InstallLogger for DefaultInstallLogger {
     }
 }
 
+/// A logger that behaves like the [`DefaultInstallLogger`], but additionally writes the planned
+/// changes of a dry run to stdout as JSON.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct JsonDryRunInstallLogger;
+
+impl InstallLogger for JsonDryRunInstallLogger {
+    fn on_audit(&self, count: usize, start: std::time::Instant, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_audit(count, start, printer)
+    }
+
+    fn on_prepare(&self, count: usize, start: std::time::Instant, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_prepare(count, start, printer)
+    }
+
+    fn on_uninstall(
+        &self,
+        count: usize,
+        start: std::time::Instant,
+        printer: Printer,
+    ) -> fmt::Result {
+        DefaultInstallLogger.on_uninstall(count, start, printer)
+    }
+
+    fn on_install(&self, count: usize, start: std::time::Instant, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_install(count, start, printer)
+    }
+
+    fn on_complete(&self, changelog: &Changelog, printer: Printer) -> fmt::Result {
+        DefaultInstallLogger.on_complete(changelog, printer)
+    }
+
+    fn on_dry_run(&self, changes: &[PlannedChange], printer: Printer) -> fmt::Result {
+        let json = serde_json::to_string_pretty(changes).map_err(|_| fmt::Error)?;
+        writeln!(printer.stdout(), "{json}")
+    }
+}
+
 /// A logger that only shows installs and uninstalls, the minimal logging necessary to understand
 /// environment changes.
 #[derive(Debug, Default, Clone, Copy)]
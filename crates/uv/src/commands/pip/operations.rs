@@ -1,5 +1,6 @@
 //! Common operations shared across the `pip` API and subcommands.
 
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
 use std::path::PathBuf;
@@ -8,6 +9,8 @@ use std::sync::Arc;
 use anyhow::{Context, anyhow};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 use tracing::debug;
 
 use uv_cache::Cache;
@@ -22,7 +25,9 @@ use uv_distribution_types::{
     CachedDist, Diagnostic, InstalledDist, LocalDist, NameRequirementSpecification, Requirement,
     ResolutionDiagnostic, UnresolvedRequirement, UnresolvedRequirementSpecification,
 };
-use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Resolution};
+use uv_distribution_types::{
+    DistributionMetadata, InstalledMetadata, Name, Resolution, VersionOrUrlRef,
+};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{Plan, Planner, Preparer, SitePackages};
@@ -438,6 +443,7 @@ pub(crate) async fn install(
     build_options: &BuildOptions,
     link_mode: LinkMode,
     compile: bool,
+    compile_exclude: &[PackageName],
     hasher: &HashStrategy,
     tags: &Tags,
     client: &RegistryClient,
@@ -473,6 +479,8 @@ pub(crate) async fn install(
         .context("Failed to determine installation plan")?;
 
     if dry_run.enabled() {
+        let changes = PlannedChange::from_plan(resolution, &plan, modifications);
+        logger.on_dry_run(&changes, printer)?;
         report_dry_run(dry_run, resolution, plan, modifications, start, printer)?;
         return Ok(Changelog::default());
     }
@@ -587,7 +595,7 @@ pub(crate) async fn install(
     }
 
     if compile {
-        compile_bytecode(venv, &concurrency, cache, printer).await?;
+        compile_bytecode(venv, compile_exclude, &concurrency, cache, printer).await?;
     }
 
     // Construct a summary of the changes made to the environment.
@@ -834,6 +842,132 @@ fn report_dry_run(
     Ok(())
 }
 
+/// The action that would be taken for a package, as reported by a dry run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum PlannedAction {
+    /// The package is not installed, and would be installed.
+    Install,
+    /// The package is installed, and would be replaced with a newer version.
+    Upgrade,
+    /// The package is installed, and would be replaced with an older version.
+    Downgrade,
+    /// The package is installed, and would be replaced with the same version (e.g., due to
+    /// `--reinstall`, or a change in the distribution's source).
+    Reinstall,
+    /// The package is installed, and would be removed.
+    Uninstall,
+    /// The package is installed, and already satisfies the requirements.
+    Satisfied,
+}
+
+/// A planned change to a single package in the environment, as reported by a dry run.
+#[derive(Debug, Serialize)]
+pub(crate) struct PlannedChange {
+    name: PackageName,
+    action: PlannedAction,
+    /// The currently installed version, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+    /// The version (or URL) that would be installed, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to: Option<String>,
+}
+
+impl PlannedChange {
+    /// Compute the per-package changes described by an installation [`Plan`].
+    fn from_plan(resolution: &Resolution, plan: &Plan, modifications: Modifications) -> Vec<Self> {
+        // If we're in `install` mode, ignore any extraneous distributions.
+        let extraneous = match modifications {
+            Modifications::Sufficient => &[][..],
+            Modifications::Exact => &plan.extraneous[..],
+        };
+
+        let mut removed = plan
+            .reinstalls
+            .iter()
+            .chain(extraneous)
+            .map(|dist| (dist.name(), dist.installed_version().version().clone()))
+            .collect::<FxHashMap<_, _>>();
+
+        let added = plan
+            .remote
+            .iter()
+            .map(|dist| {
+                let (version, to) = match dist.version_or_url() {
+                    VersionOrUrlRef::Version(version) => {
+                        (Some(version.clone()), version.to_string())
+                    }
+                    VersionOrUrlRef::Url(url) => (None, url.to_string()),
+                };
+                (dist.name(), version, to)
+            })
+            .chain(plan.cached.iter().map(|dist| {
+                let version = dist.installed_version().version().clone();
+                let to = version.to_string();
+                (dist.name(), Some(version), to)
+            }));
+
+        let mut changes = Vec::new();
+        for (name, version, to) in added {
+            let change = if let Some(from) = removed.remove(name) {
+                let action = match version.as_ref().map(|version| version.cmp(&from)) {
+                    Some(Ordering::Greater) => PlannedAction::Upgrade,
+                    Some(Ordering::Less) => PlannedAction::Downgrade,
+                    Some(Ordering::Equal) | None => PlannedAction::Reinstall,
+                };
+                Self {
+                    name: name.clone(),
+                    action,
+                    from: Some(from.to_string()),
+                    to: Some(to),
+                }
+            } else {
+                Self {
+                    name: name.clone(),
+                    action: PlannedAction::Install,
+                    from: None,
+                    to: Some(to),
+                }
+            };
+            changes.push(change);
+        }
+
+        for (name, from) in removed {
+            changes.push(Self {
+                name: name.clone(),
+                action: PlannedAction::Uninstall,
+                from: Some(from.to_string()),
+                to: None,
+            });
+        }
+
+        // Any resolved distribution that isn't otherwise changed is already satisfied.
+        let changed = changes
+            .iter()
+            .map(|change| change.name.clone())
+            .collect::<FxHashSet<_>>();
+        for dist in resolution.distributions() {
+            if changed.contains(dist.name()) {
+                continue;
+            }
+            let version = match dist.version_or_url() {
+                VersionOrUrlRef::Version(version) => version.to_string(),
+                VersionOrUrlRef::Url(url) => url.to_string(),
+            };
+            changes.push(Self {
+                name: dist.name().clone(),
+                action: PlannedAction::Satisfied,
+                from: Some(version.clone()),
+                to: Some(version),
+            });
+        }
+
+        changes.sort_unstable_by(|a, b| a.name.cmp(&b.name).then_with(|| a.action.cmp(&b.action)));
+        changes
+    }
+}
+
 /// Report any diagnostics on resolved distributions.
 #[allow(clippy::result_large_err)]
 pub(crate) fn diagnose_resolution(
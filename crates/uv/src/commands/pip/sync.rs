@@ -1,11 +1,13 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
+use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, ConfigSettings, Constraints, DryRun, ExtrasSpecification,
@@ -16,14 +18,16 @@ use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, Origin, Resolution,
+    DependencyMetadata, ExtraBuildVariables, HashGeneration, Index, IndexLocations, InstalledDist,
+    Name, Origin, Requirement, Resolution, UnresolvedRequirement,
+    UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::SitePackages;
 use uv_normalize::{DefaultExtras, DefaultGroups};
 use uv_pep508::PackageName;
-use uv_pypi_types::Conflicts;
+use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment, VerbatimParsedUrl};
 use uv_python::{
     EnvironmentPreference, Prefix, PythonEnvironment, PythonInstallation, PythonPreference,
     PythonRequest, PythonVersion, Target,
@@ -39,7 +43,9 @@ use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
-use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::loggers::{
+    DefaultInstallLogger, DefaultResolveLogger, InstallLogger, JsonDryRunInstallLogger,
+};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
@@ -59,6 +65,7 @@ pub(crate) async fn pip_sync(
     link_mode: LinkMode,
     compile: bool,
     hash_checking: Option<HashCheckingMode>,
+    allow_unhashable_sources: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
@@ -88,6 +95,7 @@ pub(crate) async fn pip_sync(
     concurrency: Concurrency,
     cache: Cache,
     dry_run: DryRun,
+    output_format: SyncFormat,
     printer: Printer,
     preview: Preview,
 ) -> Result<ExitStatus> {
@@ -101,7 +109,8 @@ pub(crate) async fn pip_sync(
     }
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
@@ -129,6 +138,8 @@ pub(crate) async fn pip_sync(
         find_links,
         no_binary,
         no_build,
+        config_settings: requirements_config_settings,
+        config_settings_package: requirements_config_settings_package,
         extras: _,
     } = operations::read_requirements(
         requirements,
@@ -272,11 +283,20 @@ pub(crate) async fn pip_sync(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             hash_checking,
+            allow_unhashable_sources,
         )?
     } else {
         HashStrategy::None
     };
 
+    // Under `--derive-hashes`, resolve without enforcing hashes, but compute them for every
+    // distribution, such that any missing hashes can be reported at once.
+    let resolve_hasher = if hash_checking.is_some_and(|mode| mode.is_derive()) {
+        HashStrategy::Generate(HashGeneration::All)
+    } else {
+        hasher.clone()
+    };
+
     // Incorporate any index locations from the provided sources.
     let index_locations = index_locations.combine(
         extra_index_urls
@@ -322,13 +342,22 @@ pub(crate) async fn pip_sync(
     // Combine the `--no-binary` and `--no-build` flags from the requirements files.
     let build_options = build_options.combine(no_binary, no_build);
 
+    // Combine the `--config-settings` from the requirements files, with the command-line values
+    // taking precedence.
+    let config_settings = &config_settings
+        .clone()
+        .with_defaults(requirements_config_settings);
+    let config_settings_package = &config_settings_package
+        .clone()
+        .with_defaults(requirements_config_settings_package);
+
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(client.cached_client(), client.connectivity(), &cache);
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+        FlatIndex::from_entries(entries, Some(&tags), &resolve_hasher, &build_options)
     };
 
     // Determine whether to enable build isolation.
@@ -350,6 +379,7 @@ pub(crate) async fn pip_sync(
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             Some(&marker_env),
             HashCheckingMode::Verify,
+            false,
         )?
     } else {
         HashStrategy::None
@@ -446,6 +476,15 @@ pub(crate) async fn pip_sync(
 
         (resolution, hasher)
     } else {
+        // Since `pip sync` installs the listed requirements without their dependencies, any
+        // installed packages that are only required by a requested extra would otherwise be
+        // removed as extraneous. Retain them at their installed versions.
+        let requirements = if hash_checking.is_some_and(|mode| mode.is_require()) {
+            requirements
+        } else {
+            with_extra_dependencies(requirements, &site_packages, &marker_env)?
+        };
+
         // When resolving, don't take any external preferences into account.
         let preferences = Vec::default();
 
@@ -470,7 +509,7 @@ pub(crate) async fn pip_sync(
             &groups,
             preferences,
             site_packages.clone(),
-            &hasher,
+            &resolve_hasher,
             &reinstall,
             &upgrade,
             Some(&tags),
@@ -497,6 +536,11 @@ pub(crate) async fn pip_sync(
             }
         };
 
+        // Under `--derive-hashes`, report any distributions that lack a hash.
+        if let Some(mode) = hash_checking.filter(HashCheckingMode::is_derive) {
+            hasher.check_resolution(&resolution, mode)?;
+        }
+
         (resolution, hasher)
     };
 
@@ -530,6 +574,10 @@ pub(crate) async fn pip_sync(
     );
 
     // Sync the environment.
+    let logger: Box<dyn InstallLogger> = match output_format {
+        SyncFormat::Text => Box::new(DefaultInstallLogger),
+        SyncFormat::Json => Box::new(JsonDryRunInstallLogger),
+    };
     match operations::install(
         &resolution,
         site_packages,
@@ -538,6 +586,7 @@ pub(crate) async fn pip_sync(
         &build_options,
         link_mode,
         compile,
+        &[],
         &hasher,
         &tags,
         &client,
@@ -546,7 +595,7 @@ pub(crate) async fn pip_sync(
         &build_dispatch,
         &cache,
         &environment,
-        Box::new(DefaultInstallLogger),
+        logger,
         installer_metadata,
         dry_run,
         printer,
@@ -571,3 +620,42 @@ pub(crate) async fn pip_sync(
 
     Ok(ExitStatus::Success)
 }
+
+/// Extend the requirements with the installed dependencies of any requested extras, pinned to
+/// their installed versions.
+fn with_extra_dependencies(
+    mut requirements: Vec<UnresolvedRequirementSpecification>,
+    site_packages: &SitePackages,
+    marker_env: &ResolverMarkerEnvironment,
+) -> Result<Vec<UnresolvedRequirementSpecification>> {
+    let pins = {
+        let named = requirements
+            .iter()
+            .filter_map(|entry| match &entry.requirement {
+                UnresolvedRequirement::Named(requirement) => Some(requirement),
+                UnresolvedRequirement::Unnamed(_) => None,
+            })
+            .collect::<Vec<_>>();
+        site_packages
+            .extra_dependencies(named.iter().copied(), marker_env)
+            .into_iter()
+            .filter(|dist| matches!(dist, InstalledDist::Registry(_)))
+            .filter(|dist| {
+                !named
+                    .iter()
+                    .any(|requirement| requirement.name == *dist.name())
+            })
+            .map(|dist| format!("{}=={}", dist.name(), dist.version()))
+            .collect::<Vec<_>>()
+    };
+
+    for pin in pins {
+        debug!("Retaining `{pin}`, which is required by a requested extra");
+        let requirement = uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(&pin)?;
+        requirements.push(UnresolvedRequirementSpecification::from(Requirement::from(
+            requirement,
+        )));
+    }
+
+    Ok(requirements)
+}
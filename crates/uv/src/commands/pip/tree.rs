@@ -87,7 +87,8 @@ pub(crate) async fn pip_tree(
         let capabilities = IndexCapabilities::default();
 
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retries(network_settings.retries)
+            .retry_backoff(network_settings.retry_backoff)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
             .keyring(keyring_provider)
@@ -42,7 +42,8 @@ pub(crate) async fn pip_uninstall(
     let start = std::time::Instant::now();
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
@@ -31,7 +31,7 @@ use uv_fs::{LockedFile, Simplified};
 use uv_git::GIT_STORE;
 use uv_git_types::GitReference;
 use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups, PackageName};
-use uv_pep508::{ExtraName, MarkerTree, UnnamedRequirement, VersionOrUrl};
+use uv_pep508::{ExtraName, MarkerTree, UnnamedRequirement, VerbatimUrl, VersionOrUrl};
 use uv_pypi_types::{ParsedUrl, VerbatimParsedUrl};
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_redacted::DisplaySafeUrl;
@@ -42,7 +42,9 @@ use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::warn_user_once;
 use uv_workspace::pyproject::{DependencyType, Source, SourceError, Sources, ToolUvSources};
-use uv_workspace::pyproject_mut::{AddBoundsKind, ArrayEdit, DependencyTarget, PyProjectTomlMut};
+use uv_workspace::pyproject_mut::{
+    AddBoundsKind, ArrayEdit, DependencyTarget, PyProjectTomlMut, RequirementFormat,
+};
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::{
@@ -75,6 +77,7 @@ pub(crate) async fn add(
     editable: Option<bool>,
     dependency_type: DependencyType,
     raw: bool,
+    verbatim: Vec<String>,
     bounds: Option<AddBoundsKind>,
     indexes: Vec<Index>,
     rev: Option<String>,
@@ -188,7 +191,8 @@ pub(crate) async fn add(
         }
 
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retries(network_settings.retries)
+            .retry_backoff(network_settings.retry_backoff)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -321,6 +325,7 @@ pub(crate) async fn add(
                 no_sync,
                 no_config,
                 active,
+                false,
                 cache,
                 DryRun::Disabled,
                 printer,
@@ -342,7 +347,8 @@ pub(crate) async fn add(
         .ok();
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(settings.resolver.keyring_provider)
@@ -637,12 +643,23 @@ pub(crate) async fn add(
         ),
     }?;
 
+    // With `--raw`, retain the requirements as provided, such that they can be written verbatim.
+    let verbatim = verbatim
+        .iter()
+        .filter_map(|given| {
+            let given = given.trim();
+            let requirement = uv_pep508::Requirement::<VerbatimUrl>::from_str(given).ok()?;
+            Some((requirement, given))
+        })
+        .collect::<Vec<_>>();
+
     let edits = edits(
         requirements,
         &target,
         editable,
         &dependency_type,
         raw,
+        &verbatim,
         rev.as_deref(),
         tag.as_deref(),
         branch.as_deref(),
@@ -771,6 +788,7 @@ fn edits(
     editable: Option<bool>,
     dependency_type: &DependencyType,
     raw: bool,
+    verbatim: &[(uv_pep508::Requirement<VerbatimUrl>, &str)],
     rev: Option<&str>,
     tag: Option<&str>,
     branch: Option<&str>,
@@ -845,6 +863,7 @@ fn edits(
                 subdirectory,
                 rev,
                 tag,
+                tag_pattern,
                 branch,
                 marker,
                 extra,
@@ -863,6 +882,7 @@ fn edits(
                     subdirectory,
                     rev,
                     tag,
+                    tag_pattern,
                     branch,
                     marker,
                     extra,
@@ -910,17 +930,41 @@ fn edits(
             DependencyType::Group(group) => DependencyType::Group(group.clone()),
         };
 
+        // With `--raw`, write the requirement exactly as provided, unless it was modified (e.g.,
+        // by `--extra` or `--marker`).
+        let format = if raw {
+            verbatim
+                .iter()
+                .find(|(given, _)| {
+                    given.name == requirement.name
+                        && given.marker == requirement.marker
+                        && given
+                            .extras
+                            .iter()
+                            .sorted()
+                            .dedup()
+                            .eq(requirement.extras.iter())
+                })
+                .map_or(RequirementFormat::Raw, |(_, given)| {
+                    RequirementFormat::Verbatim(given)
+                })
+        } else {
+            RequirementFormat::Normalized
+        };
+
         // Update the `pyproject.toml`.
         let edit = match &dependency_type {
             DependencyType::Production => {
-                toml.add_dependency(&requirement, source.as_ref(), raw)?
+                toml.add_dependency(&requirement, source.as_ref(), format)?
+            }
+            DependencyType::Dev => {
+                toml.add_dev_dependency(&requirement, source.as_ref(), format)?
             }
-            DependencyType::Dev => toml.add_dev_dependency(&requirement, source.as_ref(), raw)?,
             DependencyType::Optional(extra) => {
-                toml.add_optional_dependency(extra, &requirement, source.as_ref(), raw)?
+                toml.add_optional_dependency(extra, &requirement, source.as_ref(), format)?
             }
             DependencyType::Group(group) => {
-                toml.add_dependency_group_requirement(group, &requirement, source.as_ref(), raw)?
+                toml.add_dependency_group_requirement(group, &requirement, source.as_ref(), format)?
             }
         };
 
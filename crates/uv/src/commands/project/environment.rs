@@ -10,7 +10,7 @@ use crate::commands::project::{
 use crate::printer::Printer;
 use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 
-use uv_cache::{Cache, CacheBucket};
+use uv_cache::{Cache, CacheBucket, CacheEntry};
 use uv_cache_key::{cache_digest, hash_digest};
 use uv_configuration::{Concurrency, Constraints, Preview};
 use uv_distribution_types::{Name, Resolution};
@@ -106,8 +106,12 @@ impl From<CachedEnvironment> for PythonEnvironment {
 
 impl CachedEnvironment {
     /// Get or create an [`CachedEnvironment`] based on a given set of requirements.
+    ///
+    /// If a `key` is provided, the environment is additionally indexed by the key, such that
+    /// subsequent requests with the same key can reuse the environment without resolving.
     pub(crate) async fn from_spec(
         spec: EnvironmentSpecification<'_>,
+        key: Option<&str>,
         build_constraints: Constraints,
         interpreter: &Interpreter,
         settings: &ResolverInstallerSettings,
@@ -123,6 +127,46 @@ impl CachedEnvironment {
     ) -> Result<Self, ProjectError> {
         let interpreter = Self::base_interpreter(interpreter, cache)?;
 
+        // Construct a hash for the interpreter.
+        //
+        // Use the canonicalized base interpreter path since that's the interpreter we performed the
+        // resolution with and the interpreter the environment will be created with.
+        //
+        // TODO(zanieb): We should include the version of the base interpreter in the hash, so if
+        // the interpreter at the canonicalized path changes versions we construct a new
+        // environment.
+        let interpreter_hash =
+            cache_digest(&canonicalize_executable(interpreter.sys_executable())?);
+
+        // If the environment was previously created for the same key, reuse it without resolving.
+        let key_entry = key.map(|key| {
+            cache.entry(
+                CacheBucket::Environments,
+                Path::new(&interpreter_hash).join("keys"),
+                key,
+            )
+        });
+        if cache.refresh().is_none() {
+            if let Some(key_entry) = key_entry.as_ref() {
+                if let Ok(resolution_hash) = fs_err::read_to_string(key_entry.path()) {
+                    let cache_entry = cache.entry(
+                        CacheBucket::Environments,
+                        &interpreter_hash,
+                        resolution_hash.trim(),
+                    );
+                    if let Ok(root) = cache.resolve_link(cache_entry.path()) {
+                        if let Ok(environment) = PythonEnvironment::from_root(root, cache) {
+                            debug!(
+                                "Reusing cached environment for key: `{}`",
+                                key_entry.path().display()
+                            );
+                            return Ok(Self(environment));
+                        }
+                    }
+                }
+            }
+        }
+
         // Resolve the requirements with the interpreter.
         let resolution = Resolution::from(
             resolve_environment(
@@ -150,29 +194,25 @@ impl CachedEnvironment {
             hash_digest(&distributions)
         };
 
-        // Construct a hash for the environment.
-        //
-        // Use the canonicalized base interpreter path since that's the interpreter we performed the
-        // resolution with and the interpreter the environment will be created with.
+        // Search in the content-addressed cache.
         //
         // We cache environments independent of the environment they'd be layered on top of. The
         // assumption is such that the environment will _not_ be modified by the user or uv;
         // otherwise, we risk cache poisoning. For example, if we were to write a `.pth` file to
         // the cached environment, it would be shared across all projects that use the same
         // interpreter and the same cached dependencies.
-        //
-        // TODO(zanieb): We should include the version of the base interpreter in the hash, so if
-        // the interpreter at the canonicalized path changes versions we construct a new
-        // environment.
-        let interpreter_hash =
-            cache_digest(&canonicalize_executable(interpreter.sys_executable())?);
-
-        // Search in the content-addressed cache.
-        let cache_entry = cache.entry(CacheBucket::Environments, interpreter_hash, resolution_hash);
+        let cache_entry = cache.entry(
+            CacheBucket::Environments,
+            &interpreter_hash,
+            &resolution_hash,
+        );
 
         if cache.refresh().is_none() {
             if let Ok(root) = cache.resolve_link(cache_entry.path()) {
                 if let Ok(environment) = PythonEnvironment::from_root(root, cache) {
+                    if let Some(key_entry) = key_entry.as_ref() {
+                        Self::write_key(key_entry, &resolution_hash).await?;
+                    }
                     return Ok(Self(environment));
                 }
             }
@@ -213,9 +253,21 @@ impl CachedEnvironment {
         let id = cache.persist(temp_dir.keep(), cache_entry.path()).await?;
         let root = cache.archive(&id);
 
+        // Index the environment by its key, if any.
+        if let Some(key_entry) = key_entry.as_ref() {
+            Self::write_key(key_entry, &resolution_hash).await?;
+        }
+
         Ok(Self(PythonEnvironment::from_root(root, cache)?))
     }
 
+    /// Record the resolution hash of the environment associated with the given key.
+    async fn write_key(key_entry: &CacheEntry, resolution_hash: &str) -> Result<(), ProjectError> {
+        fs_err::tokio::create_dir_all(key_entry.dir()).await?;
+        uv_fs::write_atomic(key_entry.path(), resolution_hash).await?;
+        Ok(())
+    }
+
     /// Return the [`Interpreter`] to use for the cached environment, based on a given
     /// [`Interpreter`].
     ///
@@ -9,17 +9,18 @@ use owo_colors::OwoColorize;
 use uv_cache::Cache;
 use uv_configuration::{
     Concurrency, DependencyGroups, EditableMode, ExportFormat, ExtrasSpecification, InstallOptions,
-    Preview,
+    Preview, TargetTriple,
 };
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest, PythonVersion};
 use uv_requirements::is_pylock_toml;
-use uv_resolver::{PylockToml, RequirementsTxtExport};
+use uv_resolver::{CycloneDxExport, PylockToml, RequirementsTxtExport};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::pip::{resolution_markers, resolution_tags};
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{LockMode, LockOperation};
 use crate::commands::project::lock_target::LockTarget;
@@ -59,6 +60,9 @@ pub(crate) async fn export(
     package: Option<PackageName>,
     prune: Vec<PackageName>,
     hashes: bool,
+    no_markers: bool,
+    python_platform: Option<TargetTriple>,
+    python_version: Option<PythonVersion>,
     install_options: InstallOptions,
     output_file: Option<PathBuf>,
     extras: ExtrasSpecification,
@@ -126,8 +130,9 @@ pub(crate) async fn export(
     let groups = groups.with_defaults(default_groups);
     let extras = extras.with_defaults(default_extras);
 
-    // Find an interpreter for the project, unless `--frozen` is set.
-    let interpreter = if frozen {
+    // Find an interpreter for the project, unless `--frozen` is set (and `--no-markers` is not,
+    // since the target environment is derived from the interpreter).
+    let interpreter = if frozen && !no_markers {
         None
     } else {
         Some(match &target {
@@ -284,6 +289,13 @@ pub(crate) async fn export(
             .is_some_and(is_pylock_toml)
         {
             ExportFormat::PylockToml
+        } else if output_file
+            .as_deref()
+            .and_then(Path::file_name)
+            .and_then(OsStr::to_str)
+            .is_some_and(|file_name| file_name.to_ascii_lowercase().ends_with(".cdx.json"))
+        {
+            ExportFormat::CycloneDxJson
         } else {
             ExportFormat::RequirementsTxt
         }
@@ -304,6 +316,32 @@ pub(crate) async fn export(
         }
     }
 
+    if no_markers && !matches!(format, ExportFormat::RequirementsTxt) {
+        return Err(anyhow!(
+            "`--no-markers` is only supported when exporting to `requirements.txt`"
+        ));
+    }
+
+    // Determine the target environment, if exporting for a single environment.
+    let environment = if no_markers {
+        let interpreter = interpreter
+            .as_ref()
+            .expect("an interpreter is required with `--no-markers`");
+        let markers = resolution_markers(
+            python_version.as_ref(),
+            python_platform.as_ref(),
+            interpreter,
+        );
+        let tags = resolution_tags(
+            python_version.as_ref(),
+            python_platform.as_ref(),
+            interpreter,
+        )?;
+        Some((markers, tags))
+    } else {
+        None
+    };
+
     // Generate the export.
     match format {
         ExportFormat::RequirementsTxt => {
@@ -316,6 +354,9 @@ pub(crate) async fn export(
                 editable,
                 hashes,
                 &install_options,
+                environment
+                    .as_ref()
+                    .map(|(markers, tags)| (&**markers, tags.as_ref())),
             )?;
 
             if include_header {
@@ -349,6 +390,13 @@ pub(crate) async fn export(
             }
             write!(writer, "{}", export.to_toml()?)?;
         }
+        ExportFormat::CycloneDxJson => {
+            let export =
+                CycloneDxExport::from_lock(&target, &prune, &extras, &groups, &install_options)?;
+
+            // JSON doesn't support comments, so the header is always omitted.
+            writeln!(writer, "{}", export.to_json()?)?;
+        }
     }
 
     writer.commit().await?;
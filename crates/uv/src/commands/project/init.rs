@@ -12,13 +12,14 @@ use uv_cache::Cache;
 use uv_cli::AuthorFrom;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{
-    DependencyGroupsWithDefaults, Preview, ProjectBuildBackend, VersionControlError,
-    VersionControlSystem,
+    DependencyGroupsWithDefaults, Preview, ProjectBuildBackend, ScriptTemplate,
+    VersionControlError, VersionControlSystem,
 };
 use uv_fs::{CWD, Simplified};
 use uv_git::GIT;
 use uv_pep440::Version;
 use uv_pep508::PackageName;
+use uv_pypi_types::VerbatimParsedUrl;
 use uv_python::{
     EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVariant, PythonVersionFile, VersionFileDiscoveryOptions,
@@ -56,6 +57,9 @@ pub(crate) async fn init(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     no_workspace: bool,
+    with: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+    template: ScriptTemplate,
+    force: bool,
     network_settings: &NetworkSettings,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
@@ -84,6 +88,9 @@ pub(crate) async fn init(
                 author_from,
                 pin_python,
                 package,
+                &with,
+                template,
+                force,
                 no_config,
                 preview,
             )
@@ -200,6 +207,9 @@ async fn init_script(
     author_from: Option<AuthorFrom>,
     pin_python: bool,
     package: bool,
+    with: &[uv_pep508::Requirement<VerbatimParsedUrl>],
+    template: ScriptTemplate,
+    force: bool,
     no_config: bool,
     preview: Preview,
 ) -> Result<()> {
@@ -216,7 +226,8 @@ async fn init_script(
         warn_user_once!("`--package` is a no-op for Python scripts, which are standalone");
     }
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -226,12 +237,13 @@ async fn init_script(
     // If the file already exists, read its content.
     let content = match fs_err::tokio::read(script_path).await {
         Ok(metadata) => {
-            // If the file is already a script, raise an error.
-            if ScriptTag::parse(&metadata)?.is_some() {
+            // If the file is already a script, raise an error, unless `--force` was provided.
+            if !force && ScriptTag::parse(&metadata)?.is_some() {
                 anyhow::bail!(
-                    "`{}` is already a PEP 723 script; use `{}` to execute it",
+                    "`{}` is already a PEP 723 script; use `{}` to execute it, or `{}` to replace its metadata",
                     script_path.simplified_display().cyan(),
-                    "uv run".green()
+                    "uv run".green(),
+                    "--force".green()
                 );
             }
 
@@ -267,7 +279,14 @@ async fn init_script(
         fs_err::tokio::create_dir_all(parent).await?;
     }
 
-    Pep723Script::create(script_path, requires_python.specifiers(), content).await?;
+    Pep723Script::create(
+        script_path,
+        requires_python.specifiers(),
+        with,
+        content,
+        template,
+    )
+    .await?;
 
     Ok(())
 }
@@ -347,7 +366,8 @@ async fn init_project(
 
     let reporter = PythonDownloadReporter::single(printer);
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
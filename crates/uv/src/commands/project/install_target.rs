@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -7,12 +8,13 @@ use rustc_hash::FxHashSet;
 
 use uv_configuration::{Constraints, DependencyGroupsWithDefaults, ExtrasSpecification};
 use uv_distribution_types::Index;
-use uv_normalize::PackageName;
+use uv_normalize::{GroupName, PackageName};
+use uv_pep508::MarkerTree;
 use uv_pypi_types::{DependencyGroupSpecifier, LenientRequirement, VerbatimParsedUrl};
 use uv_resolver::{Installable, Lock, Package};
 use uv_scripts::Pep723Script;
-use uv_workspace::Workspace;
 use uv_workspace::pyproject::{Source, Sources, ToolUvSources};
+use uv_workspace::{Workspace, WorkspaceMember};
 
 use crate::commands::project::ProjectError;
 
@@ -93,6 +95,16 @@ impl<'lock> Installable<'lock> for InstallTarget<'lock> {
 }
 
 impl<'lock> InstallTarget<'lock> {
+    /// Return the packages to exclude from bytecode compilation.
+    pub(crate) fn compile_bytecode_exclude(self) -> &'lock [PackageName] {
+        match self {
+            Self::Project { workspace, .. }
+            | Self::Workspace { workspace, .. }
+            | Self::NonProjectWorkspace { workspace, .. } => workspace.compile_bytecode_exclude(),
+            Self::Script { .. } => &[],
+        }
+    }
+
     /// Return an iterator over the [`Index`] definitions in the target.
     pub(crate) fn indexes(self) -> impl Iterator<Item = &'lock Index> {
         match self {
@@ -299,6 +311,47 @@ impl<'lock> InstallTarget<'lock> {
         Ok(())
     }
 
+    /// Return the markers attached to the dependency groups of the target, as declared in
+    /// `tool.uv.dependency-groups`.
+    pub(crate) fn group_markers(self) -> BTreeMap<&'lock GroupName, MarkerTree> {
+        let pyprojects = match self {
+            Self::Project {
+                workspace, name, ..
+            } => Either::Left(
+                workspace
+                    .packages()
+                    .get(name)
+                    .map(WorkspaceMember::pyproject_toml)
+                    .into_iter(),
+            ),
+            Self::Workspace { workspace, .. } | Self::NonProjectWorkspace { workspace, .. } => {
+                Either::Right(
+                    std::iter::once(workspace.pyproject_toml()).chain(
+                        workspace
+                            .packages()
+                            .values()
+                            .map(WorkspaceMember::pyproject_toml),
+                    ),
+                )
+            }
+            Self::Script { .. } => return BTreeMap::new(),
+        };
+
+        pyprojects
+            .filter_map(|pyproject| {
+                pyproject
+                    .tool
+                    .as_ref()?
+                    .uv
+                    .as_ref()?
+                    .dependency_groups
+                    .as_ref()
+            })
+            .flat_map(|settings| settings.inner())
+            .filter_map(|(group, settings)| Some((group, settings.marker?)))
+            .collect()
+    }
+
     /// Validate the dependency groups requested by the [`DependencyGroupSpecifier`].
     #[allow(clippy::result_large_err)]
     pub(crate) fn validate_groups(
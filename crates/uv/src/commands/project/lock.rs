@@ -3,14 +3,21 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::path::Path;
+use std::str::FromStr;
 use std::sync::Arc;
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
+use version_ranges::Ranges;
 
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_cli::{LockFormat, ReleaseSegment};
+use uv_client::{
+    BaseClientBuilder, BundleMode, Connectivity, FlatIndexClient, RegistryClientBuilder,
+    ResolutionBundle,
+};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification, Preview,
     PreviewFeatures, Reinstall, Upgrade,
@@ -19,27 +26,38 @@ use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
 use uv_distribution_types::{
     DependencyMetadata, HashGeneration, Index, IndexLocations, NameRequirementSpecification,
-    Requirement, RequiresPython, UnresolvedRequirementSpecification,
+    Requirement, RequirementSource, RequiresPython, UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::{GroupName, PackageName};
-use uv_pep440::Version;
-use uv_pypi_types::{Conflicts, SupportedEnvironments};
-use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_pep440::{Version, VersionSpecifier};
+use uv_pep508::{
+    MarkerEnvironment, MarkerExpression, MarkerTree, MarkerValueVersion, VersionOrUrl,
+};
+use uv_platform_tags::TagOverrides;
+use uv_pypi_types::{Conflicts, HashAlgorithm, SupportedEnvironments, VerbatimParsedUrl};
+use uv_python::{
+    Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, PythonVersion,
+};
 use uv_requirements::ExtrasResolver;
-use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
+use uv_requirements::upgrade::{
+    LockedRequirements, read_lock_requirements, read_lock_upgrade_constraints,
+};
 use uv_resolver::{
     FlatIndex, InMemoryIndex, Lock, Options, OptionsBuilder, PythonRequirement,
-    ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
+    ResolutionOverrides, ResolverEnvironment, ResolverManifest, SatisfiesResult, UniversalMarker,
 };
 use uv_scripts::Pep723Script;
-use uv_settings::PythonInstallMirrors;
+use uv_settings::{PythonInstallMirrors, layer_requirements};
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
+use uv_workspace::dependency_groups::{FlatDependencyGroup, FlatDependencyGroups};
 use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache, WorkspaceMember};
 
 use crate::commands::pip::loggers::{DefaultResolveLogger, ResolveLogger, SummaryResolveLogger};
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::upgrade_report::UpgradeReport;
 use crate::commands::project::{
     ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
     init_script_python_requirement, script_extra_build_requires,
@@ -82,11 +100,16 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: DryRun,
+    repair: bool,
     python: Option<String>,
+    python_versions: Vec<PythonVersion>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverSettings,
     network_settings: NetworkSettings,
     script: Option<ScriptPath>,
+    bundle: Option<ResolutionBundle>,
+    output_format: LockFormat,
+    fail_on: Option<ReleaseSegment>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     concurrency: Concurrency,
@@ -95,11 +118,20 @@ pub(crate) async fn lock(
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
+    if preview.is_enabled(PreviewFeatures::JSON_OUTPUT) && matches!(output_format, LockFormat::Json)
+    {
+        warn_user!(
+            "The `--output-format json` option is experimental and the schema may change without warning. Pass `--preview-features {}` to disable this warning.",
+            PreviewFeatures::JSON_OUTPUT
+        );
+    }
+
     // If necessary, initialize the PEP 723 script.
     let script = match script {
         Some(ScriptPath::Path(path)) => {
             let client_builder = BaseClientBuilder::new()
-                .retries_from_env()?
+                .retries(network_settings.retries)
+                .retry_backoff(network_settings.retry_backoff)
                 .connectivity(network_settings.connectivity)
                 .native_tls(network_settings.native_tls)
                 .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -136,6 +168,15 @@ pub(crate) async fn lock(
         LockTarget::Workspace(&workspace)
     };
 
+    // If requested, repair the existing lockfile prior to locking.
+    if repair && target.repair().await? {
+        writeln!(
+            printer.stderr(),
+            "Repaired `{}` by merging entries with equivalent names",
+            target.lock_path().user_display().cyan()
+        )?;
+    }
+
     // Determine the lock mode.
     let interpreter;
     let mode = if frozen {
@@ -188,6 +229,20 @@ pub(crate) async fn lock(
         }
     };
 
+    // If recording a resolution bundle, capture the inputs to the resolution.
+    if let Some(bundle) = bundle
+        .as_ref()
+        .filter(|bundle| bundle.mode() == BundleMode::Record)
+    {
+        let interpreter = match mode {
+            LockMode::Write(interpreter)
+            | LockMode::DryRun(interpreter)
+            | LockMode::Locked(interpreter) => Some(interpreter),
+            LockMode::Frozen => None,
+        };
+        record_bundle_inputs(target, interpreter, bundle)?;
+    }
+
     // Initialize any shared state.
     let state = UniversalState::default();
 
@@ -204,10 +259,24 @@ pub(crate) async fn lock(
         printer,
         preview,
     )
+    .with_python_versions(&python_versions)
+    .with_bundle(bundle.as_ref())
     .execute(target)
     .await
     {
         Ok(lock) => {
+            if let Some(bundle) = bundle
+                .as_ref()
+                .filter(|bundle| bundle.mode() == BundleMode::Record)
+            {
+                bundle.persist()?;
+                writeln!(
+                    printer.stderr(),
+                    "Recorded resolution bundle to `{}`",
+                    bundle.root().user_display().cyan()
+                )?;
+            }
+
             if dry_run.enabled() {
                 // In `--dry-run` mode, show all changes.
                 let mut changed = false;
@@ -232,6 +301,47 @@ pub(crate) async fn lock(
                 }
             }
 
+            // If requested, report on the changes to the lockfile.
+            if matches!(output_format, LockFormat::Json) || fail_on.is_some() {
+                let report = match &lock {
+                    LockResult::Changed(previous, lock) => {
+                        UpgradeReport::new(previous.as_ref(), lock, dry_run)
+                    }
+                    LockResult::Unchanged(lock) => UpgradeReport::new(Some(lock), lock, dry_run),
+                };
+
+                if matches!(output_format, LockFormat::Json) {
+                    writeln!(
+                        printer.stdout_important(),
+                        "{}",
+                        serde_json::to_string_pretty(&report)?
+                    )?;
+                }
+
+                if let Some(segment) = fail_on {
+                    let violations = report.violations(segment).collect::<Vec<_>>();
+                    if !violations.is_empty() {
+                        writeln!(
+                            printer.stderr(),
+                            "{}",
+                            format!(
+                                "Direct dependencies were updated across a {} version boundary:",
+                                match segment {
+                                    ReleaseSegment::Major => "major",
+                                    ReleaseSegment::Minor => "minor",
+                                    ReleaseSegment::Patch => "patch",
+                                }
+                            )
+                            .bold()
+                        )?;
+                        for change in violations {
+                            writeln!(printer.stderr(), " {} {change}", "-".dimmed())?;
+                        }
+                        return Ok(ExitStatus::Failure);
+                    }
+                }
+            }
+
             Ok(ExitStatus::Success)
         }
         Err(err @ ProjectError::LockMismatch(..)) => {
@@ -247,6 +357,53 @@ pub(crate) async fn lock(
     }
 }
 
+/// The environment in which a resolution bundle was recorded.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct BundleEnvironment<'env> {
+    uv_version: &'env str,
+    markers: &'env MarkerEnvironment,
+}
+
+/// Copy the files that define the [`LockTarget`] (along with any existing lockfile) into the
+/// [`ResolutionBundle`], alongside the marker environment of the interpreter used for the
+/// resolution.
+fn record_bundle_inputs(
+    target: LockTarget<'_>,
+    interpreter: Option<&Interpreter>,
+    bundle: &ResolutionBundle,
+) -> anyhow::Result<()> {
+    let destination = bundle.root().join("workspace");
+    let lock_path = target.lock_path();
+    for path in target
+        .manifests()
+        .into_iter()
+        .chain(lock_path.is_file().then_some(lock_path))
+    {
+        let Ok(relative) = path.strip_prefix(target.install_path()) else {
+            continue;
+        };
+        let path_in_bundle = destination.join(relative);
+        if let Some(parent) = path_in_bundle.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        fs_err::copy(&path, &path_in_bundle)?;
+    }
+
+    if let Some(interpreter) = interpreter {
+        let environment = BundleEnvironment {
+            uv_version: uv_version::version(),
+            markers: interpreter.markers(),
+        };
+        fs_err::write(
+            bundle.root().join("environment.json"),
+            serde_json::to_string_pretty(&environment)?,
+        )?;
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(super) enum LockMode<'env> {
     /// Write the lockfile to disk.
@@ -263,8 +420,10 @@ pub(super) enum LockMode<'env> {
 pub(super) struct LockOperation<'env> {
     mode: LockMode<'env>,
     constraints: Vec<NameRequirementSpecification>,
+    python_versions: &'env [PythonVersion],
     settings: &'env ResolverSettings,
     network_settings: &'env NetworkSettings,
+    bundle: Option<&'env ResolutionBundle>,
     state: &'env UniversalState,
     logger: Box<dyn ResolveLogger>,
     concurrency: Concurrency,
@@ -291,8 +450,10 @@ impl<'env> LockOperation<'env> {
         Self {
             mode,
             constraints: vec![],
+            python_versions: &[],
             settings,
             network_settings,
+            bundle: None,
             state,
             logger,
             concurrency,
@@ -313,6 +474,21 @@ impl<'env> LockOperation<'env> {
         self
     }
 
+    /// Set the Python versions that the resolution must support for the [`LockOperation`].
+    #[must_use]
+    pub(super) fn with_python_versions(mut self, python_versions: &'env [PythonVersion]) -> Self {
+        self.python_versions = python_versions;
+        self
+    }
+
+    /// Set the [`ResolutionBundle`] to record responses into, or replay responses from, for the
+    /// [`LockOperation`].
+    #[must_use]
+    pub(super) fn with_bundle(mut self, bundle: Option<&'env ResolutionBundle>) -> Self {
+        self.bundle = bundle;
+        self
+    }
+
     /// Perform a [`LockOperation`].
     pub(super) async fn execute(self, target: LockTarget<'_>) -> Result<LockResult, ProjectError> {
         match self.mode {
@@ -337,8 +513,10 @@ impl<'env> LockOperation<'env> {
                     interpreter,
                     Some(existing),
                     self.constraints,
+                    self.python_versions,
                     self.settings,
                     self.network_settings,
+                    self.bundle,
                     self.state,
                     self.logger,
                     self.concurrency,
@@ -379,8 +557,10 @@ impl<'env> LockOperation<'env> {
                     interpreter,
                     existing,
                     self.constraints,
+                    self.python_versions,
                     self.settings,
                     self.network_settings,
+                    self.bundle,
                     self.state,
                     self.logger,
                     self.concurrency,
@@ -410,8 +590,10 @@ async fn do_lock(
     interpreter: &Interpreter,
     existing_lock: Option<Lock>,
     external: Vec<NameRequirementSpecification>,
+    python_versions: &[PythonVersion],
     settings: &ResolverSettings,
     network_settings: &NetworkSettings,
+    bundle: Option<&ResolutionBundle>,
     state: &UniversalState,
     logger: Box<dyn ResolveLogger>,
     concurrency: Concurrency,
@@ -428,8 +610,12 @@ async fn do_lock(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_overrides,
+        allow_prereleases,
         prerelease,
         fork_strategy,
+        hash_algorithm,
+        prefer_local_versions,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -442,6 +628,10 @@ async fn do_lock(
         upgrade,
         build_options,
         sources,
+        user_constraints,
+        user_overrides,
+        configured_no_binary_package,
+        configured_no_build_package,
     } = settings;
 
     if !preview.is_enabled(PreviewFeatures::EXTRA_BUILD_DEPENDENCIES)
@@ -464,10 +654,32 @@ async fn do_lock(
     let dependency_groups = target.dependency_groups()?;
     let source_trees = vec![];
 
+    // Ensure that any bounds passed to `--upgrade-package` are compatible with the requirements
+    // declared by the workspace members.
+    validate_upgrade_constraints(packages, upgrade)?;
+
+    // Expand any dependency group targets in the per-package resolution overrides.
+    let resolution_overrides =
+        expand_resolution_overrides(resolution_overrides, packages, &dependency_groups)?;
+
+    // Layer the user-level constraints and overrides beneath those of the project, such that the
+    // project's declaration takes precedence for any given package. The user-level requirements
+    // are tracked separately in the lockfile, so retain only those that survive the layering.
+    let user_constraints = layer_requirements(
+        Some(constraints.as_slice()),
+        Some(user_constraints.as_slice()),
+    )
+    .split_off(constraints.len());
+    let user_overrides =
+        layer_requirements(Some(overrides.as_slice()), Some(user_overrides.as_slice()))
+            .split_off(overrides.len());
+
     // If necessary, lower the overrides and constraints.
     let requirements = target.lower(requirements, index_locations, *sources)?;
     let overrides = target.lower(overrides, index_locations, *sources)?;
     let constraints = target.lower(constraints, index_locations, *sources)?;
+    let user_overrides = target.lower(user_overrides, index_locations, *sources)?;
+    let user_constraints = target.lower(user_constraints, index_locations, *sources)?;
     let build_constraints = target.lower(build_constraints, index_locations, *sources)?;
     let dependency_groups = dependency_groups
         .into_iter()
@@ -558,6 +770,9 @@ async fn do_lock(
         None
     };
 
+    // Collect the per-package platform tag overrides.
+    let compatible_tags = target.compatible_tags();
+
     // Determine the supported Python range. If no range is defined, and warn and default to the
     // current minor version.
     let requires_python = target.requires_python()?;
@@ -603,18 +818,65 @@ async fn do_lock(
         }
     }
 
+    // If any Python versions were requested, require that the resolution is installable on each
+    // of them (within any of the required environments), without persisting them to the lockfile.
+    let resolver_environments = if python_versions.is_empty() {
+        required_environments.cloned()
+    } else {
+        let mut markers = Vec::new();
+        for python_version in python_versions
+            .iter()
+            .unique_by(|python_version| python_version.python_version())
+        {
+            let marker = MarkerTree::expression(MarkerExpression::Version {
+                key: MarkerValueVersion::PythonVersion,
+                specifier: VersionSpecifier::equals_version(python_version.python_version()),
+            });
+            if requires_python.to_marker_tree().is_disjoint(marker) {
+                return Err(ProjectError::RequestedPythonVersionIncompatibility(
+                    python_version.clone(),
+                    requires_python.clone(),
+                ));
+            }
+            if let Some(required_environments) = required_environments {
+                for environment in required_environments {
+                    let mut environment = *environment;
+                    environment.and(marker);
+                    if !environment.is_false() {
+                        markers.push(environment);
+                    }
+                }
+            } else {
+                markers.push(marker);
+            }
+        }
+        Some(SupportedEnvironments::from_markers(markers))
+    };
+
     // Determine the Python requirement.
     let python_requirement =
         PythonRequirement::from_requires_python(interpreter, requires_python.clone());
 
     // Initialize the client.
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
+    // If requested, record responses into (or replay responses from) the resolution bundle. When
+    // replaying, the bundle serves every HTTP response, and the client is otherwise offline, such
+    // that anything outside the bundle (e.g., a Git fetch) fails rather than accessing the network.
+    let client_builder = match bundle {
+        Some(bundle) if bundle.mode() == BundleMode::Replay => client_builder
+            .connectivity(Connectivity::Offline)
+            .extra_middleware(bundle.middleware()),
+        Some(bundle) => client_builder.extra_middleware(bundle.middleware()),
+        None => client_builder,
+    };
+
     index_locations.cache_index_credentials();
 
     for index in target.indexes() {
@@ -624,6 +886,9 @@ async fn do_lock(
             if let Some(root_url) = index.root_url() {
                 uv_auth::store_credentials(&root_url, credentials.clone());
             }
+            for scope in &index.auth_scope {
+                uv_auth::store_credentials(scope, credentials.clone());
+            }
         }
     }
 
@@ -650,12 +915,16 @@ async fn do_lock(
 
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
+        .resolution_overrides(resolution_overrides)
+        .allow_prereleases(allow_prereleases.clone())
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .prefer_local_versions(*prefer_local_versions)
+        .hash_algorithm(hash_algorithm.map(HashAlgorithm::from))
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
-        .required_environments(required_environments.cloned().unwrap_or_default())
+        .required_environments(resolver_environments.clone().unwrap_or_default())
         .build();
     let hasher = HashStrategy::Generate(HashGeneration::Url);
 
@@ -732,10 +1001,15 @@ async fn do_lock(
             &dependency_groups,
             &constraints,
             &overrides,
+            &user_constraints,
+            &user_overrides,
             &build_constraints,
             &conflicts,
             environments,
-            required_environments,
+            resolver_environments.as_ref(),
+            compatible_tags,
+            configured_no_binary_package,
+            configured_no_build_package,
             dependency_metadata,
             interpreter,
             &requires_python,
@@ -790,6 +1064,18 @@ async fn do_lock(
                 .transpose()?
                 .unwrap_or_default();
 
+            // If upgrading, restrict each package to the range permitted by its upgrade policy,
+            // relative to the version in the existing lockfile (which is otherwise ignored).
+            let upgrade_constraints = match (
+                existing_lock.as_ref().map(ValidatedLock::lock),
+                target.upgrade_policy(),
+            ) {
+                (Some(lock), Some(policies)) => {
+                    read_lock_upgrade_constraints(lock, target.install_path(), upgrade, policies)?
+                }
+                _ => Vec::new(),
+            };
+
             // Populate the Git resolver.
             for ResolvedRepositoryReference { reference, sha } in git {
                 debug!("Inserting Git reference into resolver: `{reference:?}` at `{sha}`");
@@ -845,12 +1131,15 @@ async fn do_lock(
                     .collect(),
                 constraints
                     .iter()
+                    .chain(&user_constraints)
                     .cloned()
+                    .chain(upgrade_constraints)
                     .map(NameRequirementSpecification::from)
                     .chain(external)
                     .collect(),
                 overrides
                     .iter()
+                    .chain(&user_overrides)
                     .cloned()
                     .map(UnresolvedRequirementSpecification::from)
                     .collect(),
@@ -892,6 +1181,8 @@ async fn do_lock(
                 requirements,
                 constraints,
                 overrides,
+                user_constraints,
+                user_overrides,
                 build_constraints,
                 dependency_groups,
                 dependency_metadata.values().cloned(),
@@ -913,13 +1204,132 @@ async fn do_lock(
                         .cloned()
                         .map(SupportedEnvironments::into_markers)
                         .unwrap_or_default(),
+                )
+                .with_compatible_tags(compatible_tags.cloned().unwrap_or_default())
+                .with_build_options(
+                    configured_no_binary_package.clone(),
+                    configured_no_build_package.clone(),
                 );
 
+            // If the existing lockfile was only re-resolved to enforce the requested Python
+            // versions, and it already satisfied them, treat it as unchanged.
+            if !python_versions.is_empty() {
+                if let Some(previous) = previous.as_ref() {
+                    if previous.to_toml()? == lock.to_toml()? {
+                        return Ok(LockResult::Unchanged(lock));
+                    }
+                }
+            }
+
             Ok(LockResult::Changed(previous, lock))
         }
     }
 }
 
+/// Validate that the bounds provided via `--upgrade-package` (e.g., `--upgrade-package "flask<3"`)
+/// don't conflict with the requirements declared by any workspace member.
+///
+/// The resolver would reject such a resolution regardless, but without attributing the bound to
+/// the command line.
+fn validate_upgrade_constraints(
+    packages: &BTreeMap<PackageName, WorkspaceMember>,
+    upgrade: &Upgrade,
+) -> Result<(), ProjectError> {
+    for constraint in upgrade.constraints() {
+        let RequirementSource::Registry {
+            specifier: upgrade_specifier,
+            ..
+        } = &constraint.source
+        else {
+            continue;
+        };
+        if upgrade_specifier.is_empty() {
+            continue;
+        }
+        let upgrade_range = Ranges::from(upgrade_specifier.clone());
+
+        for (member, workspace_member) in packages {
+            let Some(project) = workspace_member.pyproject_toml().project.as_ref() else {
+                continue;
+            };
+            let dependencies = project.dependencies.iter().flatten().chain(
+                project
+                    .optional_dependencies
+                    .iter()
+                    .flat_map(|extras| extras.values().flatten()),
+            );
+            for dependency in dependencies {
+                let Ok(requirement) =
+                    uv_pep508::Requirement::<VerbatimParsedUrl>::from_str(dependency)
+                else {
+                    continue;
+                };
+                if requirement.name != constraint.name {
+                    continue;
+                }
+                if requirement.marker.is_disjoint(constraint.marker) {
+                    continue;
+                }
+                let Some(VersionOrUrl::VersionSpecifier(specifier)) = requirement.version_or_url
+                else {
+                    continue;
+                };
+                if Ranges::from(specifier.clone())
+                    .intersection(&upgrade_range)
+                    .is_empty()
+                {
+                    return Err(ProjectError::UpgradeConflict(
+                        constraint.name.clone(),
+                        upgrade_specifier.clone(),
+                        specifier,
+                        member.clone(),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Expand any dependency group targets in the [`ResolutionOverrides`] (e.g., `:group:dev:`) to the
+/// packages declared in the corresponding group of any workspace member, or of a non-project
+/// workspace root.
+fn expand_resolution_overrides(
+    resolution_overrides: &ResolutionOverrides,
+    packages: &BTreeMap<PackageName, WorkspaceMember>,
+    dependency_groups: &BTreeMap<GroupName, FlatDependencyGroup>,
+) -> Result<ResolutionOverrides, ProjectError> {
+    if resolution_overrides.is_empty() {
+        return Ok(ResolutionOverrides::default());
+    }
+
+    let mut groups: BTreeMap<GroupName, BTreeSet<PackageName>> = BTreeMap::new();
+    for member in packages.values() {
+        let member_groups =
+            FlatDependencyGroups::from_pyproject_toml(member.root(), member.pyproject_toml())?;
+        for (group, flat_group) in member_groups {
+            groups.entry(group).or_default().extend(
+                flat_group
+                    .requirements
+                    .into_iter()
+                    .map(|requirement| requirement.name),
+            );
+        }
+    }
+    for (group, flat_group) in dependency_groups {
+        groups.entry(group.clone()).or_default().extend(
+            flat_group
+                .requirements
+                .iter()
+                .map(|requirement| requirement.name.clone()),
+        );
+    }
+
+    Ok(resolution_overrides
+        .clone()
+        .expand_groups(|group| groups.get(group).into_iter().flatten()))
+}
+
 #[derive(Debug)]
 enum ValidatedLock {
     /// An existing lockfile was provided, and it satisfies the workspace requirements.
@@ -946,10 +1356,15 @@ impl ValidatedLock {
         dependency_groups: &BTreeMap<GroupName, Vec<Requirement>>,
         constraints: &[Requirement],
         overrides: &[Requirement],
+        user_constraints: &[Requirement],
+        user_overrides: &[Requirement],
         build_constraints: &[Requirement],
         conflicts: &Conflicts,
         environments: Option<&SupportedEnvironments>,
         required_environments: Option<&SupportedEnvironments>,
+        compatible_tags: Option<&TagOverrides>,
+        no_binary_package: &BTreeSet<PackageName>,
+        no_build_package: &BTreeSet<PackageName>,
         dependency_metadata: &DependencyMetadata,
         interpreter: &Interpreter,
         requires_python: &RequiresPython,
@@ -971,6 +1386,13 @@ impl ValidatedLock {
             );
             return Ok(Self::Unusable(lock));
         }
+        if *lock.resolution_overrides() != options.resolution_overrides {
+            let _ = writeln!(
+                printer.stderr(),
+                "Ignoring existing lockfile due to change in resolution overrides"
+            );
+            return Ok(Self::Unusable(lock));
+        }
         if lock.prerelease_mode() != options.prerelease_mode {
             let _ = writeln!(
                 printer.stderr(),
@@ -980,6 +1402,15 @@ impl ValidatedLock {
             );
             return Ok(Self::Preferable(lock));
         }
+        if *lock.allow_prereleases() != options.allow_prereleases {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in `allow-prereleases`: `{}` vs. `{}`",
+                lock.allow_prereleases().iter().join(", ").cyan(),
+                options.allow_prereleases.iter().join(", ").cyan()
+            );
+            return Ok(Self::Preferable(lock));
+        }
         if lock.fork_strategy() != options.fork_strategy {
             let _ = writeln!(
                 printer.stderr(),
@@ -1025,9 +1456,40 @@ impl ValidatedLock {
             }
         }
 
+        // If the configured per-package build options changed, the set of usable distributions may
+        // differ.
+        if lock.no_binary_package() != no_binary_package {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in `no-binary-package`: `{}` vs. `{}`",
+                lock.no_binary_package().iter().join(", ").cyan(),
+                no_binary_package.iter().join(", ").cyan()
+            );
+            return Ok(Self::Preferable(lock));
+        }
+        if lock.no_build_package() != no_build_package {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in `no-build-package`: `{}` vs. `{}`",
+                lock.no_build_package().iter().join(", ").cyan(),
+                no_build_package.iter().join(", ").cyan()
+            );
+            return Ok(Self::Preferable(lock));
+        }
+
+        // If the platform tag overrides changed, the set of usable wheels may differ.
+        let options_compatible_tags = compatible_tags.cloned().unwrap_or_default();
+        if *lock.compatible_tags() != options_compatible_tags {
+            let _ = writeln!(
+                printer.stderr(),
+                "Resolving despite existing lockfile due to change in `compatible-tags`"
+            );
+            return Ok(Self::Preferable(lock));
+        }
+
         match upgrade {
             Upgrade::None => {}
-            Upgrade::All => {
+            Upgrade::All(_) => {
                 // If the user specified `--upgrade`, then we can't use the existing lockfile.
                 debug!("Ignoring existing lockfile due to `--upgrade`");
                 return Ok(Self::Unusable(lock));
@@ -1163,6 +1625,8 @@ impl ValidatedLock {
                 requirements,
                 constraints,
                 overrides,
+                user_constraints,
+                user_overrides,
                 build_constraints,
                 dependency_groups,
                 dependency_metadata,
@@ -1242,6 +1706,20 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedUserConstraints(expected, actual) => {
+                debug!(
+                    "Resolving despite existing lockfile due to mismatched user-level constraints:\n  Requested: {:?}\n  Existing: {:?}",
+                    expected, actual
+                );
+                Ok(Self::Preferable(lock))
+            }
+            SatisfiesResult::MismatchedUserOverrides(expected, actual) => {
+                debug!(
+                    "Resolving despite existing lockfile due to mismatched user-level overrides:\n  Requested: {:?}\n  Existing: {:?}",
+                    expected, actual
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MismatchedBuildConstraints(expected, actual) => {
                 debug!(
                     "Resolving despite existing lockfile due to mismatched build constraints:\n  Requested: {:?}\n  Existing: {:?}",
@@ -1329,6 +1807,16 @@ impl ValidatedLock {
         }
     }
 
+    /// Return the underlying [`Lock`], regardless of whether it's usable.
+    fn lock(&self) -> &Lock {
+        match self {
+            Self::Unusable(lock) => lock,
+            Self::Satisfies(lock) => lock,
+            Self::Preferable(lock) => lock,
+            Self::Versions(lock) => lock,
+        }
+    }
+
     /// Convert the [`ValidatedLock`] into a [`Lock`].
     #[must_use]
     fn into_lock(self) -> Lock {
@@ -1341,6 +1829,19 @@ impl ValidatedLock {
     }
 }
 
+/// Summarize the changes required to bring an existing lockfile up-to-date, for a lockfile that
+/// can't be updated (e.g., due to `--locked`).
+pub(crate) fn report_lock_mismatch(
+    existing_lock: Option<&Lock>,
+    new_lock: &Lock,
+    printer: Printer,
+) -> std::fmt::Result {
+    for event in LockEvent::detect_changes(existing_lock, new_lock, DryRun::Enabled) {
+        writeln!(printer.stderr(), "{event}")?;
+    }
+    Ok(())
+}
+
 /// A modification to a lockfile.
 #[derive(Debug, Clone)]
 pub(crate) enum LockEvent<'lock> {
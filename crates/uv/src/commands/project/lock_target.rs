@@ -3,13 +3,14 @@ use std::path::{Path, PathBuf};
 
 use itertools::Either;
 
-use uv_configuration::{DependencyGroupsWithDefaults, SourceStrategy};
+use uv_configuration::{DependencyGroupsWithDefaults, SourceStrategy, UpgradePolicy};
 use uv_distribution::LoweredRequirement;
 use uv_distribution_types::{Index, IndexLocations, Requirement, RequiresPython};
 use uv_normalize::{GroupName, PackageName};
 use uv_pep508::RequirementOrigin;
+use uv_platform_tags::TagOverrides;
 use uv_pypi_types::{Conflicts, SupportedEnvironments, VerbatimParsedUrl};
-use uv_resolver::{Lock, LockVersion, VERSION};
+use uv_resolver::{Lock, LockVersion, VERSION, find_duplicate_packages, repair_lock};
 use uv_scripts::Pep723Script;
 use uv_workspace::dependency_groups::{DependencyGroupError, FlatDependencyGroup};
 use uv_workspace::{Workspace, WorkspaceMember};
@@ -188,6 +189,22 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Returns the per-package platform tag overrides for the [`LockTarget`].
+    pub(crate) fn compatible_tags(self) -> Option<&'lock TagOverrides> {
+        match self {
+            Self::Workspace(workspace) => workspace.compatible_tags(),
+            Self::Script(_) => None,
+        }
+    }
+
+    /// Returns the per-package upgrade policies for the [`LockTarget`].
+    pub(crate) fn upgrade_policy(self) -> Option<&'lock BTreeMap<PackageName, UpgradePolicy>> {
+        match self {
+            Self::Workspace(workspace) => workspace.upgrade_policy(),
+            Self::Script(_) => None,
+        }
+    }
+
     /// Returns the set of conflicts for the [`LockTarget`].
     pub(crate) fn conflicts(self) -> Conflicts {
         match self {
@@ -241,6 +258,27 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Return the paths to the files that define the [`LockTarget`], i.e., the `pyproject.toml` of
+    /// each workspace member (along with any `uv.toml` at the workspace root), or the script
+    /// itself.
+    pub(crate) fn manifests(self) -> Vec<PathBuf> {
+        match self {
+            Self::Workspace(workspace) => {
+                let mut manifests = BTreeSet::new();
+                manifests.insert(workspace.install_path().join("pyproject.toml"));
+                for member in workspace.packages().values() {
+                    manifests.insert(member.root().join("pyproject.toml"));
+                }
+                let uv_toml = workspace.install_path().join("uv.toml");
+                if uv_toml.is_file() {
+                    manifests.insert(uv_toml);
+                }
+                manifests.into_iter().collect()
+            }
+            Self::Script(script) => vec![script.path.clone()],
+        }
+    }
+
     /// Return the path to the lock root.
     pub(crate) fn install_path(self) -> &'lock Path {
         match self {
@@ -295,6 +333,12 @@ impl<'lock> LockTarget<'lock> {
                                 ));
                             }
                         }
+                        // If the lockfile contains entries that collide after normalization,
+                        // suggest a repair.
+                        let duplicates = find_duplicate_packages(&encoded);
+                        if !duplicates.is_empty() {
+                            return Err(ProjectError::UvLockDuplicatePackages(duplicates, err));
+                        }
                         Err(ProjectError::UvLockParse(err))
                     }
                 }
@@ -304,6 +348,23 @@ impl<'lock> LockTarget<'lock> {
         }
     }
 
+    /// Repair the lockfile in the workspace, normalizing any names and merging any entries that
+    /// collide after normalization.
+    ///
+    /// Returns `Ok(true)` if the lockfile was rewritten.
+    pub(crate) async fn repair(self) -> Result<bool, ProjectError> {
+        let encoded = match fs_err::tokio::read_to_string(self.lock_path()).await {
+            Ok(encoded) => encoded,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(err) => return Err(err.into()),
+        };
+        let Some(lock) = repair_lock(&encoded)? else {
+            return Ok(false);
+        };
+        self.commit(&lock).await?;
+        Ok(true)
+    }
+
     /// Read the lockfile from the workspace as bytes.
     pub(crate) async fn read_bytes(self) -> Result<Option<Vec<u8>>, std::io::Error> {
         match fs_err::tokio::read(self.lock_path()).await {
@@ -31,7 +31,8 @@ use uv_pypi_types::{ConflictPackage, ConflictSet, Conflicts};
 use uv_python::{
     EnvironmentPreference, Interpreter, InvalidEnvironmentKind, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonSource, PythonVariant,
-    PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest, satisfies_python_preference,
+    PythonVersion, PythonVersionFile, VersionFileDiscoveryOptions, VersionRequest,
+    satisfies_python_preference,
 };
 use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
@@ -70,7 +71,9 @@ pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
 pub(crate) mod tree;
+mod upgrade_report;
 pub(crate) mod version;
+pub(crate) mod why;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ProjectError {
@@ -134,6 +137,11 @@ pub(crate) enum ProjectError {
     )]
     RequiresPythonProjectIncompatibility(Version, RequiresPython, RequiresPythonSources, bool),
 
+    #[error(
+        "The requested Python version ({0}) is incompatible with the Python requirement: `{1}`"
+    )]
+    RequestedPythonVersionIncompatibility(PythonVersion, RequiresPython),
+
     #[error(
         "The requested interpreter resolved to Python {0}, which is incompatible with the script's Python requirement: `{1}`"
     )]
@@ -187,12 +195,32 @@ pub(crate) enum ProjectError {
     #[error("Environment marker is empty")]
     EmptyEnvironment,
 
+    #[error(
+        "The requested upgrade `{0}{1}` (from `--upgrade-package`) is incompatible with `{0}{2}` (required by `{3}`)"
+    )]
+    UpgradeConflict(
+        PackageName,
+        VersionSpecifiers,
+        VersionSpecifiers,
+        PackageName,
+    ),
+
     #[error("Project virtual environment directory `{0}` cannot be used because {1}")]
     InvalidProjectEnvironmentDir(PathBuf, String),
 
     #[error("Failed to parse `uv.lock`")]
     UvLockParse(#[source] toml::de::Error),
 
+    #[error(
+        "Failed to parse `uv.lock`, which contains multiple entries for {}; run `{}` to merge them",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+        "uv lock --repair".green()
+    )]
+    UvLockDuplicatePackages(
+        Vec<uv_resolver::DuplicatePackage>,
+        #[source] toml::de::Error,
+    ),
+
     #[error("Failed to parse `pyproject.toml`")]
     PyprojectTomlParse(#[source] toml::de::Error),
 
@@ -229,6 +257,9 @@ pub(crate) enum ProjectError {
     #[error(transparent)]
     Lock(#[from] uv_resolver::LockError),
 
+    #[error(transparent)]
+    LockRepair(#[from] uv_resolver::LockRepairError),
+
     #[error(transparent)]
     Operation(#[from] pip::operations::Error),
 
@@ -696,7 +727,8 @@ impl ScriptInterpreter {
         }
 
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retries(network_settings.retries)
+            .retry_backoff(network_settings.retry_backoff)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -980,7 +1012,8 @@ impl ProjectInterpreter {
         }
 
         let client_builder = BaseClientBuilder::default()
-            .retries_from_env()?
+            .retries(network_settings.retries)
+            .retry_backoff(network_settings.retry_backoff)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -1262,6 +1295,7 @@ enum ProjectEnvironment {
 
 impl ProjectEnvironment {
     /// Initialize a virtual environment for the current project.
+    #[allow(clippy::fn_params_excessive_bools)]
     pub(crate) async fn get_or_init(
         workspace: &Workspace,
         groups: &DependencyGroupsWithDefaults,
@@ -1273,6 +1307,7 @@ impl ProjectEnvironment {
         no_sync: bool,
         no_config: bool,
         active: Option<bool>,
+        relocatable: bool,
         cache: &Cache,
         dry_run: DryRun,
         printer: Printer,
@@ -1291,7 +1326,7 @@ impl ProjectEnvironment {
                 .as_ref()
                 .is_none_or(|request| !request.includes_patch());
 
-        match ProjectInterpreter::discover(
+        let interpreter = match ProjectInterpreter::discover(
             workspace,
             workspace.install_path().as_ref(),
             groups,
@@ -1309,123 +1344,134 @@ impl ProjectEnvironment {
         )
         .await?
         {
-            // If we found an existing, compatible environment, use it.
-            ProjectInterpreter::Environment(environment) => Ok(Self::Existing(environment)),
+            // If we found an existing, compatible environment, use it, unless it needs to be
+            // recreated as a relocatable environment.
+            ProjectInterpreter::Environment(environment) => {
+                if relocatable && !environment.relocatable() {
+                    debug!(
+                        "Recreating project environment at `{}` as a relocatable environment",
+                        environment.root().user_display()
+                    );
+                    environment.into_interpreter()
+                } else {
+                    return Ok(Self::Existing(environment));
+                }
+            }
 
             // Otherwise, create a virtual environment with the discovered interpreter.
-            ProjectInterpreter::Interpreter(interpreter) => {
-                let root = workspace.venv(active);
-
-                // Avoid removing things that are not virtual environments
-                let replace = match (root.try_exists(), root.join("pyvenv.cfg").try_exists()) {
-                    // It's a virtual environment we can remove it
-                    (_, Ok(true)) => true,
-                    // It doesn't exist at all, we should use it without deleting it to avoid TOCTOU bugs
-                    (Ok(false), Ok(false)) => false,
-                    // If it's not a virtual environment, bail
-                    (Ok(true), Ok(false)) => {
-                        // Unless it's empty, in which case we just ignore it
-                        if root.read_dir().is_ok_and(|mut dir| dir.next().is_none()) {
-                            false
-                        } else {
-                            return Err(ProjectError::InvalidProjectEnvironmentDir(
-                                root,
-                                "it is not a compatible environment but cannot be recreated because it is not a virtual environment".to_string(),
-                            ));
-                        }
-                    }
-                    // Similarly, if we can't _tell_ if it exists we should bail
-                    (_, Err(err)) | (Err(err), _) => {
-                        return Err(ProjectError::InvalidProjectEnvironmentDir(
-                            root,
-                            format!(
-                                "it is not a compatible environment but cannot be recreated because uv cannot determine if it is a virtual environment: {err}"
-                            ),
-                        ));
-                    }
-                };
-
-                // Determine a prompt for the environment, in order of preference:
-                //
-                // 1) The name of the project
-                // 2) The name of the directory at the root of the workspace
-                // 3) No prompt
-                let prompt = workspace
-                    .pyproject_toml()
-                    .project
-                    .as_ref()
-                    .map(|p| p.name.to_string())
-                    .or_else(|| {
-                        workspace
-                            .install_path()
-                            .file_name()
-                            .map(|f| f.to_string_lossy().to_string())
-                    })
-                    .map(uv_virtualenv::Prompt::Static)
-                    .unwrap_or(uv_virtualenv::Prompt::None);
+            ProjectInterpreter::Interpreter(interpreter) => interpreter,
+        };
 
-                // Under `--dry-run`, avoid modifying the environment.
-                if dry_run.enabled() {
-                    let temp_dir = cache.venv_dir()?;
-                    let environment = uv_virtualenv::create_venv(
-                        temp_dir.path(),
-                        interpreter,
-                        prompt,
-                        false,
-                        uv_virtualenv::OnExisting::Remove,
-                        false,
-                        false,
-                        upgradeable,
-                        preview,
-                    )?;
-                    return Ok(if replace {
-                        Self::WouldReplace(root, environment, temp_dir)
-                    } else {
-                        Self::WouldCreate(root, environment, temp_dir)
-                    });
-                }
+        let root = workspace.venv(active);
 
-                // Remove the existing virtual environment if it doesn't meet the requirements.
-                if replace {
-                    match remove_virtualenv(&root) {
-                        Ok(()) => {
-                            writeln!(
-                                printer.stderr(),
-                                "Removed virtual environment at: {}",
-                                root.user_display().cyan()
-                            )?;
-                        }
-                        Err(uv_virtualenv::Error::Io(err))
-                            if err.kind() == std::io::ErrorKind::NotFound => {}
-                        Err(err) => return Err(err.into()),
-                    }
+        // Avoid removing things that are not virtual environments
+        let replace = match (root.try_exists(), root.join("pyvenv.cfg").try_exists()) {
+            // It's a virtual environment we can remove it
+            (_, Ok(true)) => true,
+            // It doesn't exist at all, we should use it without deleting it to avoid TOCTOU bugs
+            (Ok(false), Ok(false)) => false,
+            // If it's not a virtual environment, bail
+            (Ok(true), Ok(false)) => {
+                // Unless it's empty, in which case we just ignore it
+                if root.read_dir().is_ok_and(|mut dir| dir.next().is_none()) {
+                    false
+                } else {
+                    return Err(ProjectError::InvalidProjectEnvironmentDir(
+                        root,
+                        "it is not a compatible environment but cannot be recreated because it is not a virtual environment".to_string(),
+                    ));
                 }
+            }
+            // Similarly, if we can't _tell_ if it exists we should bail
+            (_, Err(err)) | (Err(err), _) => {
+                return Err(ProjectError::InvalidProjectEnvironmentDir(
+                    root,
+                    format!(
+                        "it is not a compatible environment but cannot be recreated because uv cannot determine if it is a virtual environment: {err}"
+                    ),
+                ));
+            }
+        };
 
-                writeln!(
-                    printer.stderr(),
-                    "Creating virtual environment at: {}",
-                    root.user_display().cyan()
-                )?;
-
-                let environment = uv_virtualenv::create_venv(
-                    &root,
-                    interpreter,
-                    prompt,
-                    false,
-                    uv_virtualenv::OnExisting::Remove,
-                    false,
-                    false,
-                    upgradeable,
-                    preview,
-                )?;
+        // Determine a prompt for the environment, in order of preference:
+        //
+        // 1) The name of the project
+        // 2) The name of the directory at the root of the workspace
+        // 3) No prompt
+        let prompt = workspace
+            .pyproject_toml()
+            .project
+            .as_ref()
+            .map(|p| p.name.to_string())
+            .or_else(|| {
+                workspace
+                    .install_path()
+                    .file_name()
+                    .map(|f| f.to_string_lossy().to_string())
+            })
+            .map(uv_virtualenv::Prompt::Static)
+            .unwrap_or(uv_virtualenv::Prompt::None);
+
+        // Under `--dry-run`, avoid modifying the environment.
+        if dry_run.enabled() {
+            let temp_dir = cache.venv_dir()?;
+            let environment = uv_virtualenv::create_venv(
+                temp_dir.path(),
+                interpreter,
+                prompt,
+                false,
+                uv_virtualenv::OnExisting::Remove,
+                relocatable,
+                false,
+                upgradeable,
+                preview,
+            )?;
+            return Ok(if replace {
+                Self::WouldReplace(root, environment, temp_dir)
+            } else {
+                Self::WouldCreate(root, environment, temp_dir)
+            });
+        }
 
-                if replace {
-                    Ok(Self::Replaced(environment))
-                } else {
-                    Ok(Self::Created(environment))
+        // Remove the existing virtual environment if it doesn't meet the requirements.
+        if replace {
+            match remove_virtualenv(&root) {
+                Ok(()) => {
+                    writeln!(
+                        printer.stderr(),
+                        "Removed virtual environment at: {}",
+                        root.user_display().cyan()
+                    )?;
                 }
+                Err(uv_virtualenv::Error::Io(err))
+                    if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
             }
         }
+
+        writeln!(
+            printer.stderr(),
+            "Creating virtual environment at: {}",
+            root.user_display().cyan()
+        )?;
+
+        let environment = uv_virtualenv::create_venv(
+            &root,
+            interpreter,
+            prompt,
+            false,
+            uv_virtualenv::OnExisting::Remove,
+            relocatable,
+            false,
+            upgradeable,
+            preview,
+        )?;
+
+        if replace {
+            Ok(Self::Replaced(environment))
+        } else {
+            Ok(Self::Created(environment))
+        }
     }
 
     /// Convert the [`ProjectEnvironment`] into a [`PythonEnvironment`].
@@ -1693,6 +1739,8 @@ pub(crate) async fn resolve_names(
                 dependency_metadata,
                 exclude_newer,
                 fork_strategy: _,
+                hash_algorithm: _,
+                prefer_local_versions: _,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -1703,16 +1751,22 @@ pub(crate) async fn resolve_names(
                 extra_build_variables,
                 prerelease: _,
                 resolution: _,
+                resolution_overrides: _,
+                allow_prereleases: _,
                 sources,
                 upgrade: _,
+                user_constraints: _,
+                user_overrides: _,
+                configured_no_binary_package: _,
+                configured_no_build_package: _,
             },
         compile_bytecode: _,
         reinstall: _,
     } = settings;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()
-        .map_err(|err| uv_requirements::Error::ClientError(err.into()))?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
@@ -1854,8 +1908,12 @@ pub(crate) async fn resolve_environment(
         index_strategy,
         keyring_provider,
         resolution,
+        resolution_overrides,
+        allow_prereleases,
         prerelease,
         fork_strategy,
+        hash_algorithm: _,
+        prefer_local_versions,
         dependency_metadata,
         config_setting,
         config_settings_package,
@@ -1868,6 +1926,10 @@ pub(crate) async fn resolve_environment(
         upgrade: _,
         build_options,
         sources,
+        user_constraints: _,
+        user_overrides: _,
+        configured_no_binary_package: _,
+        configured_no_build_package: _,
     } = settings;
 
     // Respect all requirements from the provided sources.
@@ -1881,7 +1943,8 @@ pub(crate) async fn resolve_environment(
     } = spec.requirements;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
@@ -1917,8 +1980,11 @@ pub(crate) async fn resolve_environment(
 
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
+        .resolution_overrides(resolution_overrides.clone())
+        .allow_prereleases(allow_prereleases.clone())
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .prefer_local_versions(*prefer_local_versions)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -2064,7 +2130,8 @@ pub(crate) async fn sync_environment(
     } = settings;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
@@ -2152,6 +2219,7 @@ pub(crate) async fn sync_environment(
         build_options,
         link_mode,
         compile_bytecode,
+        &[],
         &hasher,
         tags,
         &client,
@@ -2220,6 +2288,8 @@ pub(crate) async fn update_environment(
                 dependency_metadata,
                 exclude_newer,
                 fork_strategy,
+                hash_algorithm: _,
+                prefer_local_versions,
                 index_locations,
                 index_strategy,
                 keyring_provider,
@@ -2230,15 +2300,22 @@ pub(crate) async fn update_environment(
                 extra_build_variables,
                 prerelease,
                 resolution,
+                resolution_overrides,
+                allow_prereleases,
                 sources,
                 upgrade,
+                user_constraints: _,
+                user_overrides: _,
+                configured_no_binary_package: _,
+                configured_no_build_package: _,
             },
         compile_bytecode,
         reinstall,
     } = settings;
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(*keyring_provider)
@@ -2315,8 +2392,11 @@ pub(crate) async fn update_environment(
 
     let options = OptionsBuilder::new()
         .resolution_mode(*resolution)
+        .resolution_overrides(resolution_overrides.clone())
+        .allow_prereleases(allow_prereleases.clone())
         .prerelease_mode(*prerelease)
         .fork_strategy(*fork_strategy)
+        .prefer_local_versions(*prefer_local_versions)
         .exclude_newer(exclude_newer.clone())
         .index_strategy(*index_strategy)
         .build_options(build_options.clone())
@@ -2413,6 +2493,7 @@ pub(crate) async fn update_environment(
         build_options,
         *link_mode,
         *compile_bytecode,
+        &[],
         &hasher,
         tags,
         &client,
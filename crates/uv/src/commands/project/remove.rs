@@ -1,10 +1,13 @@
+use std::collections::BTreeSet;
 use std::fmt::Write;
 use std::io;
 use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
 use tracing::{debug, warn};
 
 use uv_cache::Cache;
@@ -16,6 +19,7 @@ use uv_fs::Simplified;
 use uv_normalize::{DEV_DEPENDENCIES, DefaultExtras, DefaultGroups};
 use uv_pep508::PackageName;
 use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_resolver::{Lock, Package};
 use uv_scripts::{Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user_once;
@@ -27,7 +31,7 @@ use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::add::{AddTarget, PythonTarget};
 use crate::commands::project::install_target::InstallTarget;
-use crate::commands::project::lock::LockMode;
+use crate::commands::project::lock::{LockMode, LockResult};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptInterpreter, UniversalState,
@@ -121,32 +125,32 @@ pub(crate) async fn remove(
         ),
     }?;
 
-    for package in packages {
+    for package in &packages {
         match dependency_type {
             DependencyType::Production => {
-                let deps = toml.remove_dependency(&package)?;
+                let deps = toml.remove_dependency(package)?;
                 if deps.is_empty() {
-                    show_other_dependency_type_hint(printer, &package, &toml)?;
+                    show_other_dependency_type_hint(printer, package, &toml)?;
                     anyhow::bail!(
                         "The dependency `{package}` could not be found in `project.dependencies`"
                     )
                 }
             }
             DependencyType::Dev => {
-                let dev_deps = toml.remove_dev_dependency(&package)?;
+                let dev_deps = toml.remove_dev_dependency(package)?;
                 let group_deps =
-                    toml.remove_dependency_group_requirement(&package, &DEV_DEPENDENCIES)?;
+                    toml.remove_dependency_group_requirement(package, &DEV_DEPENDENCIES)?;
                 if dev_deps.is_empty() && group_deps.is_empty() {
-                    show_other_dependency_type_hint(printer, &package, &toml)?;
+                    show_other_dependency_type_hint(printer, package, &toml)?;
                     anyhow::bail!(
                         "The dependency `{package}` could not be found in `tool.uv.dev-dependencies` or `tool.uv.dependency-groups.dev`"
                     );
                 }
             }
             DependencyType::Optional(ref extra) => {
-                let deps = toml.remove_optional_dependency(&package, extra)?;
+                let deps = toml.remove_optional_dependency(package, extra)?;
                 if deps.is_empty() {
-                    show_other_dependency_type_hint(printer, &package, &toml)?;
+                    show_other_dependency_type_hint(printer, package, &toml)?;
                     anyhow::bail!(
                         "The dependency `{package}` could not be found in `project.optional-dependencies.{extra}`"
                     );
@@ -154,19 +158,19 @@ pub(crate) async fn remove(
             }
             DependencyType::Group(ref group) => {
                 if group == &*DEV_DEPENDENCIES {
-                    let dev_deps = toml.remove_dev_dependency(&package)?;
+                    let dev_deps = toml.remove_dev_dependency(package)?;
                     let group_deps =
-                        toml.remove_dependency_group_requirement(&package, &DEV_DEPENDENCIES)?;
+                        toml.remove_dependency_group_requirement(package, &DEV_DEPENDENCIES)?;
                     if dev_deps.is_empty() && group_deps.is_empty() {
-                        show_other_dependency_type_hint(printer, &package, &toml)?;
+                        show_other_dependency_type_hint(printer, package, &toml)?;
                         anyhow::bail!(
                             "The dependency `{package}` could not be found in `tool.uv.dev-dependencies` or `tool.uv.dependency-groups.dev`"
                         );
                     }
                 } else {
-                    let deps = toml.remove_dependency_group_requirement(&package, group)?;
+                    let deps = toml.remove_dependency_group_requirement(package, group)?;
                     if deps.is_empty() {
-                        show_other_dependency_type_hint(printer, &package, &toml)?;
+                        show_other_dependency_type_hint(printer, package, &toml)?;
                         anyhow::bail!(
                             "The dependency `{package}` could not be found in `dependency-groups.{group}`"
                         );
@@ -248,6 +252,7 @@ pub(crate) async fn remove(
                     no_sync,
                     no_config,
                     active,
+                    false,
                     cache,
                     DryRun::Disabled,
                     printer,
@@ -315,7 +320,12 @@ pub(crate) async fn remove(
     .execute((&target).into())
     .await
     {
-        Ok(result) => result.into_lock(),
+        Ok(result) => {
+            if let LockResult::Changed(Some(previous), lock) = &result {
+                report_orphans(previous, lock, &packages, printer)?;
+            }
+            result.into_lock()
+        }
         Err(ProjectError::Operation(err)) => {
             return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                 .report(err)
@@ -384,6 +394,51 @@ pub(crate) async fn remove(
     Ok(ExitStatus::Success)
 }
 
+/// Report any packages that are no longer needed after the removal (i.e., packages that were
+/// dropped from the lockfile, beyond those that were explicitly removed).
+fn report_orphans(
+    previous: &Lock,
+    lock: &Lock,
+    packages: &[PackageName],
+    printer: Printer,
+) -> Result<()> {
+    let remaining = lock
+        .packages()
+        .iter()
+        .map(Package::name)
+        .collect::<FxHashSet<_>>();
+    let (removed, orphaned): (Vec<_>, Vec<_>) = previous
+        .packages()
+        .iter()
+        .map(Package::name)
+        .filter(|name| !remaining.contains(name))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .partition(|name| packages.contains(name));
+
+    if orphaned.is_empty() {
+        return Ok(());
+    }
+
+    let summary = format!(
+        "{} no-longer-needed package{}: {}",
+        orphaned.len(),
+        if orphaned.len() == 1 { "" } else { "s" },
+        orphaned.iter().map(|name| name.cyan()).join(", ")
+    );
+    if removed.is_empty() {
+        writeln!(printer.stderr(), "Removed {summary}")?;
+    } else {
+        writeln!(
+            printer.stderr(),
+            "Removed {} and {summary}",
+            removed.iter().map(|name| name.cyan()).join(", ")
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Represents the destination where dependencies are added, either to a project or a script.
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
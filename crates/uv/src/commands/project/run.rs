@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::env::VarError;
 use std::ffi::OsString;
 use std::fmt::Write;
@@ -21,11 +22,13 @@ use uv_configuration::{
     Concurrency, Constraints, DependencyGroups, DryRun, EditableMode, ExtrasSpecification,
     InstallOptions, Preview,
 };
-use uv_distribution_types::Requirement;
+use uv_distribution::SourcedDependencyGroups;
+use uv_distribution_types::{Requirement, UnresolvedRequirementSpecification};
 use uv_fs::which::is_executable;
 use uv_fs::{PythonExt, Simplified, create_symlink};
 use uv_installer::{SatisfiesResult, SitePackages};
-use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_normalize::{DefaultExtras, DefaultGroups, ExtraName, GroupName, PackageName};
+use uv_pep508::RequirementOrigin;
 use uv_python::{
     EnvironmentPreference, Interpreter, PyVenvConfiguration, PythonDownloads, PythonEnvironment,
     PythonInstallation, PythonPreference, PythonRequest, PythonVersionFile,
@@ -53,13 +56,14 @@ struct GistResponse {
 struct GistFile {
     raw_url: String,
 }
+use crate::commands::env_file::load_env_files;
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations::Modifications;
 use crate::commands::project::environment::{CachedEnvironment, EphemeralEnvironment};
 use crate::commands::project::install_target::InstallTarget;
-use crate::commands::project::lock::LockMode;
+use crate::commands::project::lock::{LockMode, LockResult};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
     EnvironmentSpecification, PreferenceLocation, ProjectEnvironment, ProjectError,
@@ -79,7 +83,9 @@ pub(crate) async fn run(
     script: Option<Pep723Item>,
     command: Option<RunCommand>,
     requirements: Vec<RequirementsSource>,
+    with_groups: Vec<GroupName>,
     show_resolution: bool,
+    show_environment: bool,
     locked: bool,
     frozen: bool,
     active: Option<bool>,
@@ -160,43 +166,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     let sync_state = lock_state.fork();
     let workspace_cache = WorkspaceCache::default();
 
-    // Read from the `.env` file, if necessary.
-    if !no_env_file {
-        for env_file_path in env_file.iter().rev().map(PathBuf::as_path) {
-            match dotenvy::from_path(env_file_path) {
-                Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
-                    bail!(
-                        "No environment file found at: `{}`",
-                        env_file_path.simplified_display()
-                    );
-                }
-                Err(dotenvy::Error::Io(err)) => {
-                    bail!(
-                        "Failed to read environment file `{}`: {err}",
-                        env_file_path.simplified_display()
-                    );
-                }
-                Err(dotenvy::Error::LineParse(content, position)) => {
-                    warn_user!(
-                        "Failed to parse environment file `{}` at position {position}: {content}",
-                        env_file_path.simplified_display(),
-                    );
-                }
-                Err(err) => {
-                    warn_user!(
-                        "Failed to parse environment file `{}`: {err}",
-                        env_file_path.simplified_display(),
-                    );
-                }
-                Ok(()) => {
-                    debug!(
-                        "Read environment file at: `{}`",
-                        env_file_path.simplified_display()
-                    );
-                }
-            }
-        }
-    }
+    // Read from the `.env` files, if necessary.
+    let env_vars = if no_env_file {
+        Vec::new()
+    } else {
+        load_env_files(&env_file)?
+    };
 
     // Initialize any output reporters.
     let download_reporter = PythonDownloadReporter::single(printer);
@@ -204,6 +179,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     // The lockfile used for the base environment.
     let mut base_lock: Option<(Lock, PathBuf)> = None;
 
+    // The requirements from any `--with-group` dependency groups.
+    let mut group_requirements: Vec<Requirement> = Vec::new();
+
+    // A description of the base environment, for `--show-environment`.
+    let mut report = EnvironmentReport::default();
+
     // Determine whether the command to execute is a PEP 723 script.
     let temp_dir;
     let script_interpreter = if let Some(script) = script {
@@ -225,6 +206,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             }
         }
 
+        report.kind = RunEnvironmentKind::Script;
+        report.source = Some(match &script {
+            Pep723Item::Script(script) => format!("script `{}`", script.path.simplified_display()),
+            Pep723Item::Stdin(..) => "script from stdin".to_string(),
+            Pep723Item::Remote(_, url) => format!("script `{url}`"),
+        });
+
         // If a lockfile already exists, lock the script.
         if let Some(target) = script
             .as_script()
@@ -289,7 +277,10 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             .execute(target)
             .await
             {
-                Ok(result) => result.into_lock(),
+                Ok(result) => {
+                    report.lock = Some(LockStatus::from_result(&result, frozen));
+                    result.into_lock()
+                }
                 Err(ProjectError::Operation(err)) => {
                     return diagnostics::OperationDiagnostic::native_tls(
                         network_settings.native_tls,
@@ -497,6 +488,11 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     // Discover and sync the base environment.
     let temp_dir;
     let base_interpreter = if let Some(script_interpreter) = script_interpreter {
+        // Dependency groups are read from the enclosing project, which scripts don't have.
+        if !with_groups.is_empty() {
+            bail!("`--with-group` is not supported for Python scripts with inline metadata");
+        }
+
         // If we found a PEP 723 script and the user provided a project-only setting, warn.
         if no_project {
             debug!(
@@ -609,6 +605,13 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             }
         }
 
+        if !with_groups.is_empty() && project.is_none() {
+            bail!(
+                "`--with-group` requires a project, but no `pyproject.toml` was found in `{}` or any parent directory",
+                project_dir.user_display()
+            );
+        }
+
         if let Some(project) = project {
             if let Some(project_name) = project.project_name() {
                 debug!(
@@ -627,13 +630,66 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
             let groups = groups.with_defaults(default_groups);
             let extras = extras.with_defaults(default_extras);
 
+            report.kind = if isolated {
+                RunEnvironmentKind::Isolated
+            } else {
+                RunEnvironmentKind::Project
+            };
+            report.source = Some(if let Some(project_name) = project.project_name() {
+                format!(
+                    "project `{project_name}` at `{}`",
+                    project.root().simplified_display()
+                )
+            } else {
+                format!(
+                    "virtual workspace at `{}`",
+                    project.workspace().install_path().simplified_display()
+                )
+            });
+            for pyproject_toml in std::iter::once(project.workspace().pyproject_toml()).chain(
+                project
+                    .workspace()
+                    .packages()
+                    .values()
+                    .map(|member| member.pyproject_toml()),
+            ) {
+                if let Some(optional_dependencies) = pyproject_toml
+                    .project
+                    .as_ref()
+                    .and_then(|project| project.optional_dependencies.as_ref())
+                {
+                    report.extras.extend(
+                        optional_dependencies
+                            .keys()
+                            .filter(|extra| extras.contains(extra))
+                            .cloned(),
+                    );
+                }
+                if let Some(dependency_groups) = pyproject_toml.dependency_groups.as_ref() {
+                    report.groups.extend(
+                        dependency_groups
+                            .keys()
+                            .filter(|group| groups.contains(group))
+                            .cloned(),
+                    );
+                }
+            }
+
+            // Read the `--with-group` requirements, which are layered atop the project environment
+            // alongside any `--with` requirements.
+            if !with_groups.is_empty() {
+                group_requirements =
+                    read_with_groups(&project, &with_groups, &settings, &workspace_cache).await?;
+            }
+
             let venv = if isolated {
                 debug!("Creating isolated virtual environment");
 
                 // If we're isolating the environment, use an ephemeral virtual environment as the
                 // base environment for the project.
                 let client_builder = BaseClientBuilder::new()
-                    .retries_from_env()?
+                    .retries(network_settings.retries)
+                    .retry_backoff(network_settings.retry_backoff)
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -705,6 +761,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     no_sync,
                     no_config,
                     active,
+                    false,
                     cache,
                     DryRun::Disabled,
                     printer,
@@ -716,10 +773,20 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
             if no_sync {
                 debug!("Skipping environment synchronization due to `--no-sync`");
+                report.lock = Some(LockStatus::NoSync);
+
+                // If the environment was synced against a different manifest, it's likely stale.
+                if !isolated && project::sync::is_stale(project.workspace(), &venv) {
+                    warn_user!(
+                        "The project environment is out of date with `pyproject.toml` or `uv.lock`; run `{}` to update it, or omit `{}`",
+                        "uv sync".green(),
+                        "--no-sync".green(),
+                    );
+                }
 
                 // If we're not syncing, we should still attempt to respect the locked preferences
                 // in any `--with` requirements.
-                if !isolated && !requirements.is_empty() {
+                if !isolated && (!requirements.is_empty() || !group_requirements.is_empty()) {
                     base_lock = LockTarget::from(project.workspace())
                         .read()
                         .await
@@ -736,6 +803,16 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                     })
                     .ok();
 
+                // With `--frozen`, refuse to use an environment that was synced against a different
+                // manifest, rather than re-syncing it from the (possibly outdated) lockfile.
+                if frozen && !isolated && project::sync::is_stale(project.workspace(), &venv) {
+                    bail!(
+                        "The project environment is out of date with `pyproject.toml` or `uv.lock`; run `{}` to update it, or omit `{}`",
+                        "uv sync".green(),
+                        "--frozen".green(),
+                    );
+                }
+
                 // Determine the lock mode.
                 let mode = if frozen {
                     LockMode::Frozen
@@ -764,7 +841,10 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
                 .execute(project.workspace().into())
                 .await
                 {
-                    Ok(result) => result,
+                    Ok(result) => {
+                        report.lock = Some(LockStatus::from_result(&result, frozen));
+                        result
+                    }
                     Err(ProjectError::Operation(err)) => {
                         return diagnostics::OperationDiagnostic::native_tls(
                             network_settings.native_tls,
@@ -875,7 +955,8 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
             let interpreter = {
                 let client_builder = BaseClientBuilder::new()
-                    .retries_from_env()?
+                    .retries(network_settings.retries)
+                    .retry_backoff(network_settings.retry_backoff)
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -914,6 +995,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
             if isolated {
                 debug!("Creating isolated virtual environment");
+                report.kind = RunEnvironmentKind::Isolated;
 
                 // If we're isolating the environment, use an ephemeral virtual environment.
                 temp_dir = cache.venv_dir()?;
@@ -942,17 +1024,23 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     );
 
     // Read the requirements.
-    let spec = if requirements.is_empty() {
+    let spec = if requirements.is_empty() && group_requirements.is_empty() {
         None
     } else {
         let client_builder = BaseClientBuilder::new()
-            .retries_from_env()?
+            .retries(network_settings.retries)
+            .retry_backoff(network_settings.retry_backoff)
             .connectivity(network_settings.connectivity)
             .native_tls(network_settings.native_tls)
             .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
-        let spec =
+        let mut spec =
             RequirementsSpecification::from_simple_sources(&requirements, &client_builder).await?;
+        spec.requirements.extend(
+            group_requirements
+                .into_iter()
+                .map(UnresolvedRequirementSpecification::from),
+        );
 
         Some(spec)
     };
@@ -992,6 +1080,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
             let result = CachedEnvironment::from_spec(
                 spec,
+                None,
                 build_constraints.unwrap_or_default(),
                 &base_interpreter,
                 &settings,
@@ -1185,6 +1274,12 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
         .or(requirements_env.as_ref())
         .map_or_else(|| &base_interpreter, |env| env.interpreter());
 
+    // If requested, display the environment instead of running the command.
+    if show_environment {
+        report.write(interpreter, requirements_env.is_some(), printer)?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Check if any run command is given.
     // If not, print the available scripts for the current interpreter.
     let Some(command) = command else {
@@ -1258,6 +1353,7 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
 
     debug!("Running `{command}`");
     let mut process = command.as_command(interpreter);
+    process.envs(env_vars);
 
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
@@ -1312,6 +1408,60 @@ hint: If you are running a script with `{}` in the shebang, you may need to incl
     run_to_completion(handle).await
 }
 
+/// Read the requirements for the given `--with-group` dependency groups from the project's
+/// `pyproject.toml`, without including the project itself.
+async fn read_with_groups(
+    project: &VirtualProject,
+    with_groups: &[GroupName],
+    settings: &ResolverInstallerSettings,
+    workspace_cache: &WorkspaceCache,
+) -> anyhow::Result<Vec<Requirement>> {
+    let pyproject_path = project.root().join("pyproject.toml");
+    let metadata = SourcedDependencyGroups::from_virtual_project(
+        &pyproject_path,
+        None,
+        &settings.resolver.index_locations,
+        settings.resolver.sources,
+        workspace_cache,
+    )
+    .await
+    .with_context(|| {
+        format!(
+            "Failed to read dependency groups from: {}",
+            pyproject_path.user_display()
+        )
+    })?;
+
+    let mut requirements = Vec::new();
+    for name in with_groups {
+        let Some(group) = metadata.dependency_groups.get(name) else {
+            let declared = if metadata.dependency_groups.is_empty() {
+                "none".to_string()
+            } else {
+                metadata
+                    .dependency_groups
+                    .keys()
+                    .map(|group| format!("`{group}`"))
+                    .join(", ")
+            };
+            bail!(
+                "The dependency group `{name}` was not found in `{}` (declared groups: {declared})",
+                pyproject_path.user_display()
+            );
+        };
+        requirements.extend(group.iter().cloned().map(|requirement| Requirement {
+            origin: Some(RequirementOrigin::Group(
+                pyproject_path.clone(),
+                metadata.name.clone(),
+                name.clone(),
+            )),
+            ..requirement
+        }));
+    }
+
+    Ok(requirements)
+}
+
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
 fn can_skip_ephemeral(
     spec: &RequirementsSpecification,
@@ -1356,6 +1506,140 @@ fn can_skip_ephemeral(
     }
 }
 
+/// The kind of base environment in which `uv run` executes a command.
+#[derive(Debug, Default, Clone, Copy)]
+enum RunEnvironmentKind {
+    /// The project environment (e.g., `.venv`, or `UV_PROJECT_ENVIRONMENT`).
+    Project,
+    /// An environment for a PEP 723 script.
+    Script,
+    /// An isolated, temporary environment, due to `--isolated`.
+    Isolated,
+    /// A Python interpreter discovered outside of a project.
+    #[default]
+    Interpreter,
+}
+
+impl std::fmt::Display for RunEnvironmentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Project => write!(f, "project"),
+            Self::Script => write!(f, "script"),
+            Self::Isolated => write!(f, "isolated"),
+            Self::Interpreter => write!(f, "interpreter"),
+        }
+    }
+}
+
+/// The state of the lockfile used to sync the base environment.
+#[derive(Debug, Clone, Copy)]
+enum LockStatus {
+    /// The lockfile was up-to-date.
+    Fresh,
+    /// The lockfile was outdated, and has been updated.
+    Updated,
+    /// The lockfile was not checked, due to `--frozen`.
+    Frozen,
+    /// The lockfile was not checked, due to `--no-sync`.
+    NoSync,
+}
+
+impl LockStatus {
+    fn from_result(result: &LockResult, frozen: bool) -> Self {
+        if frozen {
+            Self::Frozen
+        } else {
+            match result {
+                LockResult::Unchanged(_) => Self::Fresh,
+                LockResult::Changed(..) => Self::Updated,
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for LockStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fresh => write!(f, "up-to-date"),
+            Self::Updated => write!(f, "updated"),
+            Self::Frozen => write!(f, "not checked (`--frozen`)"),
+            Self::NoSync => write!(f, "not checked (`--no-sync`)"),
+        }
+    }
+}
+
+/// A description of the environment in which `uv run` would execute a command, as displayed by
+/// `uv run --show-environment`.
+#[derive(Debug, Default)]
+struct EnvironmentReport {
+    /// The kind of base environment.
+    kind: RunEnvironmentKind,
+    /// The project or script from which the base environment was derived.
+    source: Option<String>,
+    /// The enabled extras.
+    extras: BTreeSet<ExtraName>,
+    /// The enabled dependency groups.
+    groups: BTreeSet<GroupName>,
+    /// The state of the lockfile, if one was used.
+    lock: Option<LockStatus>,
+}
+
+impl EnvironmentReport {
+    /// Write the report for the given interpreter.
+    ///
+    /// If `ephemeral` is set, the command would run in an ephemeral environment layered atop the
+    /// base environment (e.g., due to `--with`).
+    fn write(
+        &self,
+        interpreter: &Interpreter,
+        ephemeral: bool,
+        printer: Printer,
+    ) -> anyhow::Result<()> {
+        writeln!(
+            printer.stdout(),
+            "Interpreter: {}",
+            interpreter.sys_executable().simplified_display()
+        )?;
+        if ephemeral {
+            writeln!(
+                printer.stdout(),
+                "Environment: ephemeral (layered atop the {} environment)",
+                self.kind
+            )?;
+        } else {
+            writeln!(printer.stdout(), "Environment: {}", self.kind)?;
+        }
+        if let Some(source) = &self.source {
+            writeln!(printer.stdout(), "Source: {source}")?;
+        }
+        writeln!(
+            printer.stdout(),
+            "Extras: {}",
+            if self.extras.is_empty() {
+                "none".to_string()
+            } else {
+                self.extras.iter().join(", ")
+            }
+        )?;
+        writeln!(
+            printer.stdout(),
+            "Groups: {}",
+            if self.groups.is_empty() {
+                "none".to_string()
+            } else {
+                self.groups.iter().join(", ")
+            }
+        )?;
+        writeln!(
+            printer.stdout(),
+            "Lockfile: {}",
+            self.lock
+                .map_or_else(|| "none".to_string(), ToString::to_string)
+        )?;
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum RunCommand {
     /// Execute `python`.
@@ -1622,7 +1906,8 @@ async fn resolve_gist_url(
     let api_url = format!("https://api.github.com/gists/{gist_id}");
 
     let client = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
@@ -1722,7 +2007,8 @@ impl RunCommand {
                     .tempfile()?;
 
                 let client = BaseClientBuilder::new()
-                    .retries_from_env()?
+                    .retries(network_settings.retries)
+                    .retry_backoff(network_settings.retry_backoff)
                     .connectivity(network_settings.connectivity)
                     .native_tls(network_settings.native_tls)
                     .allow_insecure_host(network_settings.allow_insecure_host.clone())
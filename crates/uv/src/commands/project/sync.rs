@@ -1,6 +1,8 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::fmt::Write;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use anyhow::{Context, Result};
@@ -9,43 +11,49 @@ use owo_colors::OwoColorize;
 use serde::Serialize;
 use tracing::warn;
 use uv_cache::Cache;
+use uv_cache_key::hash_digest;
 use uv_cli::SyncFormat;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun, EditableMode,
-    ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode, InstallOptions,
-    Preview, PreviewFeatures, TargetTriple, Upgrade,
+    BuildOptions, Concurrency, Constraints, DependencyGroups, DependencyGroupsWithDefaults, DryRun,
+    EditableMode, ExtrasSpecification, ExtrasSpecificationWithDefaults, HashCheckingMode,
+    InstallOptions, Preview, PreviewFeatures, TargetTriple, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    DirectorySourceDist, Dist, Index, Requirement, Resolution, ResolvedDist, SourceDist,
+    DirectorySourceDist, Dist, Index, Name, Requirement, Resolution, ResolvedDist, SourceDist,
 };
 use uv_fs::{PortablePathBuf, Simplified};
 use uv_installer::SitePackages;
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
 use uv_pep508::{MarkerTree, VersionOrUrl};
+use uv_platform_tags::Tags;
 use uv_pypi_types::{ParsedArchiveUrl, ParsedGitUrl, ParsedUrl};
-use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
-use uv_resolver::{FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode};
+use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_resolver::{
+    FlatIndex, ForkStrategy, Installable, Lock, PrereleaseMode, ResolutionMode, ResolutionOverrides,
+};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::pyproject::Source;
-use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache};
+use uv_workspace::{
+    DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace, WorkspaceCache, WorkspaceMember,
+};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Modifications;
 use crate::commands::pip::resolution_markers;
 use crate::commands::pip::{operations, resolution_tags};
 use crate::commands::project::install_target::InstallTarget;
-use crate::commands::project::lock::{LockMode, LockOperation, LockResult};
+use crate::commands::project::lock::{LockMode, LockOperation, LockResult, report_lock_mismatch};
 use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{
-    PlatformState, ProjectEnvironment, ProjectError, ScriptEnvironment, UniversalState,
-    default_dependency_groups, detect_conflicts, script_extra_build_requires, script_specification,
-    update_environment,
+    PlatformState, ProjectEnvironment, ProjectError, ProjectInterpreter, ScriptEnvironment,
+    UniversalState, default_dependency_groups, detect_conflicts, find_requires_python,
+    script_extra_build_requires, script_specification, update_environment,
 };
 use crate::commands::{ExitStatus, diagnostics};
 use crate::printer::Printer;
@@ -61,6 +69,7 @@ pub(crate) async fn sync(
     frozen: bool,
     dry_run: DryRun,
     active: Option<bool>,
+    relocatable: bool,
     all_packages: bool,
     package: Option<PackageName>,
     extras: ExtrasSpecification,
@@ -83,6 +92,8 @@ pub(crate) async fn sync(
     printer: Printer,
     preview: Preview,
     output_format: SyncFormat,
+    foreign: ForeignPackages,
+    project_environment: Option<PathBuf>,
 ) -> Result<ExitStatus> {
     if preview.is_enabled(PreviewFeatures::JSON_OUTPUT) && matches!(output_format, SyncFormat::Json)
     {
@@ -147,25 +158,38 @@ pub(crate) async fn sync(
 
     // Discover or create the virtual environment.
     let environment = match &target {
-        SyncTarget::Project(project) => SyncEnvironment::Project(
-            ProjectEnvironment::get_or_init(
-                project.workspace(),
-                &groups,
-                python.as_deref().map(PythonRequest::parse),
-                &install_mirrors,
-                &network_settings,
-                python_preference,
-                python_downloads,
-                false,
-                no_config,
-                active,
-                cache,
-                dry_run,
-                printer,
-                preview,
+        SyncTarget::Project(project) => {
+            // Use the explicit environment path, if provided (e.g., with `--all-pythons`).
+            let workspace = match &project_environment {
+                Some(root) => Cow::Owned(
+                    project
+                        .workspace()
+                        .clone()
+                        .with_project_environment(root.clone()),
+                ),
+                None => Cow::Borrowed(project.workspace()),
+            };
+            SyncEnvironment::Project(
+                ProjectEnvironment::get_or_init(
+                    &workspace,
+                    &groups,
+                    python.as_deref().map(PythonRequest::parse),
+                    &install_mirrors,
+                    &network_settings,
+                    python_preference,
+                    python_downloads,
+                    false,
+                    no_config,
+                    active,
+                    relocatable,
+                    cache,
+                    dry_run,
+                    printer,
+                    preview,
+                )
+                .await?,
             )
-            .await?,
-        ),
+        }
         SyncTarget::Script(script) => SyncEnvironment::Script(
             ScriptEnvironment::get_or_init(
                 script.into(),
@@ -206,6 +230,9 @@ pub(crate) async fn sync(
         writeln!(printer.stderr(), "{message}")?;
     }
 
+    // Identify any packages that were installed by another tool, before modifying the environment.
+    handle_foreign_packages(&environment, foreign, dry_run, printer)?;
+
     // Special-case: we're syncing a script that doesn't have an associated lockfile. In that case,
     // we don't create a lockfile, so the resolve-and-install semantics are different.
     if let SyncTarget::Script(script) = &target {
@@ -344,6 +371,7 @@ pub(crate) async fn sync(
                 // sync operation, but exit with a non-zero status.
                 Outcome::LockMismatch(prev, cur)
             } else {
+                report_lock_mismatch(prev.as_deref(), &cur, printer)?;
                 writeln!(
                     printer.stderr(),
                     "{}",
@@ -416,6 +444,7 @@ pub(crate) async fn sync(
     match outcome {
         Outcome::Success(..) => Ok(ExitStatus::Success),
         Outcome::LockMismatch(prev, cur) => {
+            report_lock_mismatch(prev.as_deref(), &cur, printer)?;
             writeln!(
                 printer.stderr(),
                 "{}",
@@ -426,6 +455,227 @@ pub(crate) async fn sync(
     }
 }
 
+/// Sync a project environment for each Python version in `tool.uv.test-pythons`.
+///
+/// Each environment is created at `.venv-<version>` in the workspace root, and all environments
+/// are synced from the same lockfile. Every version is validated against the lockfile before any
+/// environment is synced.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn sync_all_pythons(
+    project_dir: &Path,
+    locked: bool,
+    frozen: bool,
+    dry_run: DryRun,
+    relocatable: bool,
+    all_packages: bool,
+    package: Option<PackageName>,
+    extras: ExtrasSpecification,
+    groups: DependencyGroups,
+    editable: EditableMode,
+    install_options: InstallOptions,
+    modifications: Modifications,
+    python_platform: Option<TargetTriple>,
+    install_mirrors: PythonInstallMirrors,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    settings: ResolverInstallerSettings,
+    network_settings: NetworkSettings,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+    output_format: SyncFormat,
+    foreign: ForeignPackages,
+) -> Result<ExitStatus> {
+    // Identify the project.
+    let project = VirtualProject::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+    let workspace = project.workspace();
+
+    let versions = workspace.test_pythons().unwrap_or_default();
+    if versions.is_empty() {
+        return Err(anyhow::anyhow!(
+            "`uv sync --all-pythons` requires at least one Python version in `{}`",
+            "tool.uv.test-pythons".green()
+        ));
+    }
+
+    // Validate every version up-front, so that we don't sync a subset of the environments before
+    // failing.
+    let default_groups = default_dependency_groups(project.pyproject_toml())?;
+    let groups_with_defaults = groups.clone().with_defaults(default_groups);
+    let extras_with_defaults = extras.clone().with_defaults(DefaultExtras::default());
+    if let Some(requires_python) = find_requires_python(workspace, &groups_with_defaults)? {
+        if let Some(version) = versions
+            .iter()
+            .find(|version| !requires_python.contains(version))
+        {
+            return Err(anyhow::anyhow!(
+                "Python {} in `{}` is incompatible with the project's Python requirement: `{}`",
+                version.cyan(),
+                "tool.uv.test-pythons".green(),
+                requires_python
+            ));
+        }
+    }
+
+    // Find (or download) an interpreter for each version, without creating any environments.
+    let mut interpreters = Vec::with_capacity(versions.len());
+    for version in &versions {
+        let root = workspace.install_path().join(format!(".venv-{version}"));
+        let interpreter = ProjectInterpreter::discover(
+            &workspace.clone().with_project_environment(root),
+            project_dir,
+            &groups_with_defaults,
+            Some(PythonRequest::parse(&version.to_string())),
+            &network_settings,
+            python_preference,
+            python_downloads,
+            &install_mirrors,
+            false,
+            no_config,
+            None,
+            cache,
+            Printer::Quiet,
+            preview,
+        )
+        .await?
+        .into_interpreter();
+        interpreters.push(interpreter);
+    }
+
+    // Lock the project once, such that every interpreter is validated against the same lockfile.
+    let mode = if frozen {
+        LockMode::Frozen
+    } else if locked {
+        LockMode::Locked(&interpreters[0])
+    } else if dry_run.enabled() {
+        LockMode::DryRun(&interpreters[0])
+    } else {
+        LockMode::Write(&interpreters[0])
+    };
+    let state = UniversalState::default();
+    let lock = match LockOperation::new(
+        mode,
+        &settings.resolver,
+        &network_settings,
+        &state,
+        Box::new(DefaultResolveLogger),
+        concurrency,
+        cache,
+        &WorkspaceCache::default(),
+        Printer::Quiet,
+        preview,
+    )
+    .execute(LockTarget::from(workspace))
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        // Validate against the updated lockfile; the mismatch itself is reported by `sync`.
+        Err(ProjectError::LockMismatch(_, lock)) => *lock,
+        Err(err) => return Err(err.into()),
+    };
+
+    // Validate that the lockfile contains a valid solution for every interpreter.
+    let target = SyncTarget::Project(project.clone());
+    let install_target =
+        identify_installation_target(&target, &lock, all_packages, package.as_ref());
+    for (version, interpreter) in versions.iter().zip(&interpreters) {
+        locked_resolution(
+            install_target,
+            interpreter,
+            &extras_with_defaults,
+            &groups_with_defaults,
+            python_platform.as_ref(),
+            &settings.resolver.build_options,
+            &install_options,
+            Printer::Quiet,
+        )
+        .with_context(|| {
+            format!(
+                "The lockfile does not support Python {} in `{}`",
+                version.cyan(),
+                "tool.uv.test-pythons".green()
+            )
+        })?;
+    }
+
+    let mut synced = Vec::with_capacity(versions.len());
+    for version in versions {
+        let root = workspace.install_path().join(format!(".venv-{version}"));
+
+        let status = Box::pin(sync(
+            project_dir,
+            locked,
+            frozen,
+            dry_run,
+            None,
+            relocatable,
+            all_packages,
+            package.clone(),
+            extras.clone(),
+            groups.clone(),
+            editable,
+            install_options.clone(),
+            modifications,
+            Some(version.to_string()),
+            python_platform,
+            install_mirrors.clone(),
+            python_preference,
+            python_downloads,
+            settings.clone(),
+            network_settings.clone(),
+            None,
+            installer_metadata,
+            concurrency,
+            no_config,
+            cache,
+            printer,
+            preview,
+            output_format,
+            foreign,
+            Some(root.clone()),
+        ))
+        .await?;
+
+        // Stop at the first environment that fails to sync.
+        if !matches!(status, ExitStatus::Success) {
+            return Ok(status);
+        }
+
+        synced.push((version, root));
+    }
+
+    // Summarize the synced environments.
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!("Synced {} environments:", synced.len()).dimmed()
+    )?;
+    for (version, root) in synced {
+        writeln!(
+            printer.stderr(),
+            " {} Python {} at: {}",
+            "-".dimmed(),
+            version.cyan(),
+            root.user_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
 /// The outcome of a `lock` operation within a `sync` operation.
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
@@ -500,6 +750,86 @@ fn identify_installation_target<'a>(
 }
 
 #[derive(Debug, Clone)]
+/// How to treat packages in the environment that were installed by a tool other than uv.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ForeignPackages {
+    /// Warn about any foreign packages, but proceed with the sync.
+    #[default]
+    Warn,
+    /// Fail if the environment contains any foreign packages.
+    Check,
+    /// Mark any foreign packages as installed by uv.
+    Adopt,
+}
+
+/// Identify the packages in the environment whose `INSTALLER` is not uv, and warn about, reject,
+/// or adopt them, per the [`ForeignPackages`] policy.
+fn handle_foreign_packages(
+    venv: &PythonEnvironment,
+    foreign: ForeignPackages,
+    dry_run: DryRun,
+    printer: Printer,
+) -> Result<()> {
+    let site_packages = SitePackages::from_environment(venv)?;
+    let packages = site_packages
+        .iter()
+        .filter_map(|dist| match dist.installer() {
+            Ok(Some(installer)) if installer != "uv" => Some((dist, installer)),
+            Ok(_) => None,
+            Err(err) => {
+                warn!("Failed to read `INSTALLER` for `{dist}`: {err}");
+                None
+            }
+        })
+        .sorted_by(|(a, _), (b, _)| a.name().cmp(b.name()))
+        .collect::<Vec<_>>();
+    if packages.is_empty() {
+        return Ok(());
+    }
+
+    let list = packages
+        .iter()
+        .map(|(dist, installer)| format!("`{}` (installed by `{installer}`)", dist.cyan()))
+        .join(", ");
+
+    match foreign {
+        ForeignPackages::Warn => {
+            warn_user!(
+                "The environment contains packages that were not installed by uv: {list}; use `{}` to manage them with uv, or `{}` to fail instead",
+                "--adopt-foreign".green(),
+                "--check-foreign".green(),
+            );
+        }
+        ForeignPackages::Check => {
+            return Err(anyhow::anyhow!(
+                "The environment contains packages that were not installed by uv: {list}; use `{}` to manage them with uv",
+                "--adopt-foreign".green(),
+            ));
+        }
+        ForeignPackages::Adopt => {
+            let s = if packages.len() == 1 { "" } else { "s" };
+            if dry_run.enabled() {
+                writeln!(
+                    printer.stderr(),
+                    "Would adopt {}: {list}",
+                    format!("{} package{s}", packages.len()).bold(),
+                )?;
+                return Ok(());
+            }
+            for (dist, _) in &packages {
+                uv_installer::adopt(dist).with_context(|| format!("Failed to adopt `{dist}`"))?;
+            }
+            writeln!(
+                printer.stderr(),
+                "Adopted {}: {list}",
+                format!("{} package{s}", packages.len()).bold(),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
 #[allow(clippy::large_enum_variant)]
 enum SyncTarget {
     /// Sync a project environment.
@@ -552,6 +882,107 @@ impl Deref for SyncEnvironment {
     }
 }
 
+/// Read the [`Resolution`] for the given interpreter from the lockfile.
+///
+/// Validates that the lockfile supports the interpreter's Python version and platform, and that the
+/// requested extras and dependency groups are defined in the lockfile.
+fn locked_resolution<'env>(
+    target: InstallTarget<'_>,
+    interpreter: &'env Interpreter,
+    extras: &ExtrasSpecificationWithDefaults,
+    groups: &DependencyGroupsWithDefaults,
+    python_platform: Option<&TargetTriple>,
+    build_options: &BuildOptions,
+    install_options: &InstallOptions,
+    printer: Printer,
+) -> Result<(Resolution, Cow<'env, Tags>), ProjectError> {
+    // Validate that the Python version is supported by the lockfile.
+    if !target
+        .lock()
+        .requires_python()
+        .contains(interpreter.python_version())
+    {
+        return Err(ProjectError::LockedPythonIncompatibility(
+            interpreter.python_version().clone(),
+            target.lock().requires_python().clone(),
+        ));
+    }
+
+    // Validate that the set of requested extras and development groups are compatible.
+    detect_conflicts(target.lock(), extras, groups)?;
+
+    // Validate that the set of requested extras and development groups are defined in the lockfile.
+    target.validate_extras(extras)?;
+    target.validate_groups(groups)?;
+
+    // Determine the markers to use for resolution.
+    let marker_env = resolution_markers(None, python_platform, interpreter);
+
+    // Validate that the platform is supported by the lockfile.
+    let environments = target.lock().supported_environments();
+    if !environments.is_empty() {
+        if !environments
+            .iter()
+            .any(|env| env.evaluate(&marker_env, &[]))
+        {
+            return Err(ProjectError::LockedPlatformIncompatibility(
+                // For error reporting, we use the "simplified"
+                // supported environments, because these correspond to
+                // what the end user actually wrote. The non-simplified
+                // environments, by contrast, are explicitly
+                // constrained by `requires-python`.
+                target
+                    .lock()
+                    .simplified_supported_environments()
+                    .into_iter()
+                    .filter_map(MarkerTree::contents)
+                    .map(|env| format!("`{env}`"))
+                    .join(", "),
+            ));
+        }
+    }
+
+    // Report any requested dependency groups that will be omitted due to their markers.
+    for (group, marker) in target.group_markers() {
+        if !groups.explicit_names().any(|name| name == group) {
+            continue;
+        }
+        if marker.evaluate(&marker_env, &[]) {
+            continue;
+        }
+        if let Some(marker) = marker.contents() {
+            writeln!(
+                printer.stderr(),
+                "Skipping group `{}`, since its marker (`{marker}`) does not match the current environment",
+                group.cyan(),
+            )?;
+        }
+    }
+
+    // Determine the tags to use for the resolution, including any per-package overrides.
+    let tags = resolution_tags(None, python_platform, interpreter)?;
+    let tags = if target.lock().compatible_tags().is_empty() {
+        tags
+    } else {
+        Cow::Owned(
+            tags.into_owned()
+                .with_overrides(target.lock().compatible_tags().clone()),
+        )
+    };
+
+    // Read the lockfile.
+    let resolution = target.to_resolution(
+        &marker_env,
+        &tags,
+        extras,
+        groups,
+        build_options,
+        install_options,
+    )?;
+
+    Ok((resolution, tags))
+}
+
 /// Sync a lockfile with an environment.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(super) async fn do_sync(
@@ -625,6 +1056,8 @@ pub(super) async fn do_sync(
                 dependency_metadata: dependency_metadata.clone(),
                 exclude_newer: exclude_newer.clone(),
                 fork_strategy: ForkStrategy::default(),
+                hash_algorithm: None,
+                prefer_local_versions: false,
                 index_locations: index_locations.clone(),
                 index_strategy,
                 keyring_provider,
@@ -635,8 +1068,14 @@ pub(super) async fn do_sync(
                 extra_build_variables: extra_build_variables.clone(),
                 prerelease: PrereleaseMode::default(),
                 resolution: ResolutionMode::default(),
+                resolution_overrides: ResolutionOverrides::default(),
+                allow_prereleases: BTreeSet::default(),
                 sources,
                 upgrade: Upgrade::default(),
+                user_constraints: Vec::new(),
+                user_overrides: Vec::new(),
+                configured_no_binary_package: BTreeSet::default(),
+                configured_no_build_package: BTreeSet::default(),
             };
             script_extra_build_requires((*script).into(), &resolver_settings)?
         }
@@ -644,69 +1083,23 @@ pub(super) async fn do_sync(
     .into_inner();
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .keyring(keyring_provider)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
 
-    // Validate that the Python version is supported by the lockfile.
-    if !target
-        .lock()
-        .requires_python()
-        .contains(venv.interpreter().python_version())
-    {
-        return Err(ProjectError::LockedPythonIncompatibility(
-            venv.interpreter().python_version().clone(),
-            target.lock().requires_python().clone(),
-        ));
-    }
-
-    // Validate that the set of requested extras and development groups are compatible.
-    detect_conflicts(target.lock(), extras, groups)?;
-
-    // Validate that the set of requested extras and development groups are defined in the lockfile.
-    target.validate_extras(extras)?;
-    target.validate_groups(groups)?;
-
-    // Determine the markers to use for resolution.
-    let marker_env = resolution_markers(None, python_platform, venv.interpreter());
-
-    // Validate that the platform is supported by the lockfile.
-    let environments = target.lock().supported_environments();
-    if !environments.is_empty() {
-        if !environments
-            .iter()
-            .any(|env| env.evaluate(&marker_env, &[]))
-        {
-            return Err(ProjectError::LockedPlatformIncompatibility(
-                // For error reporting, we use the "simplified"
-                // supported environments, because these correspond to
-                // what the end user actually wrote. The non-simplified
-                // environments, by contrast, are explicitly
-                // constrained by `requires-python`.
-                target
-                    .lock()
-                    .simplified_supported_environments()
-                    .into_iter()
-                    .filter_map(MarkerTree::contents)
-                    .map(|env| format!("`{env}`"))
-                    .join(", "),
-            ));
-        }
-    }
-
-    // Determine the tags to use for the resolution.
-    let tags = resolution_tags(None, python_platform, venv.interpreter())?;
-
     // Read the lockfile.
-    let resolution = target.to_resolution(
-        &marker_env,
-        &tags,
+    let (resolution, tags) = locked_resolution(
+        target,
+        venv.interpreter(),
         extras,
         groups,
+        python_platform,
         build_options,
         &install_options,
+        printer,
     )?;
 
     // Always skip virtual projects, which shouldn't be built or installed.
@@ -715,6 +1108,23 @@ pub(super) async fn do_sync(
     // If necessary, convert editable to non-editable distributions.
     let resolution = apply_editable_mode(resolution, editable);
 
+    // Editable installs reference their source tree by absolute path, so they can't be relocated
+    // along with a relocatable environment.
+    if venv.relocatable() {
+        let editables = resolution
+            .distributions()
+            .filter(|dist| dist.is_editable())
+            .map(|dist| format!("`{}`", dist.name().cyan()))
+            .collect::<Vec<_>>();
+        if !editables.is_empty() {
+            warn_user!(
+                "The environment at `{}` is relocatable, but editable installs reference their source by absolute path and cannot be relocated: {}. To install them as non-editable, pass `--no-editable`.",
+                venv.root().user_display(),
+                editables.join(", ")
+            );
+        }
+    }
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -797,6 +1207,7 @@ pub(super) async fn do_sync(
         build_options,
         link_mode,
         compile_bytecode,
+        target.compile_bytecode_exclude(),
         &hasher,
         &tags,
         &client,
@@ -812,9 +1223,56 @@ pub(super) async fn do_sync(
     )
     .await?;
 
+    // Record the manifest that the environment was synced against.
+    if !dry_run.enabled() {
+        match &target {
+            InstallTarget::Project { workspace, .. }
+            | InstallTarget::Workspace { workspace, .. }
+            | InstallTarget::NonProjectWorkspace { workspace, .. } => {
+                if let Err(err) =
+                    fs_err::write(venv.root().join(SYNC_MARKER), manifest_digest(workspace))
+                {
+                    warn!("Failed to write sync marker: {err}");
+                }
+            }
+            InstallTarget::Script { .. } => {}
+        }
+    }
+
     Ok(())
 }
 
+/// The file, within a project environment, that records the digest of the workspace manifest as
+/// of the last sync.
+const SYNC_MARKER: &str = ".uv-sync";
+
+/// Compute a digest of the workspace manifest, i.e., the `pyproject.toml` of each workspace member
+/// along with the lockfile.
+fn manifest_digest(workspace: &Workspace) -> String {
+    let pyprojects = std::iter::once(workspace.pyproject_toml())
+        .chain(
+            workspace
+                .packages()
+                .values()
+                .map(WorkspaceMember::pyproject_toml),
+        )
+        .map(|pyproject_toml| pyproject_toml.raw.as_str())
+        .collect::<Vec<_>>();
+    let lock = fs_err::read_to_string(workspace.install_path().join("uv.lock")).ok();
+    hash_digest(&(pyprojects, lock))
+}
+
+/// Returns `true` if the environment was last synced against a different workspace manifest.
+///
+/// Environments without a sync marker (e.g., those created by an older version of uv) are not
+/// considered stale.
+pub(super) fn is_stale(workspace: &Workspace, venv: &PythonEnvironment) -> bool {
+    let Ok(marker) = fs_err::read_to_string(venv.root().join(SYNC_MARKER)) else {
+        return false;
+    };
+    marker != manifest_digest(workspace)
+}
+
 /// Filter out any virtual workspace members.
 fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
@@ -885,6 +1343,9 @@ fn store_credentials_from_target(target: InstallTarget<'_>) {
             if let Some(root_url) = index.root_url() {
                 uv_auth::store_credentials(&root_url, credentials.clone());
             }
+            for scope in &index.auth_scope {
+                uv_auth::store_credentials(scope, credentials.clone());
+            }
         }
     }
 
@@ -981,7 +1442,7 @@ enum SchemaVersion {
 }
 
 #[derive(Serialize, Debug, Default)]
-struct SchemaReport {
+pub(crate) struct SchemaReport {
     /// The version of the schema.
     version: SchemaVersion,
 }
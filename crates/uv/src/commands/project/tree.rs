@@ -196,8 +196,12 @@ pub(crate) async fn tree(
                 index_strategy: _,
                 keyring_provider,
                 resolution: _,
+                resolution_overrides: _,
+                allow_prereleases: _,
                 prerelease: _,
                 fork_strategy: _,
+                hash_algorithm: _,
+                prefer_local_versions: _,
                 dependency_metadata: _,
                 config_setting: _,
                 config_settings_package: _,
@@ -210,6 +214,10 @@ pub(crate) async fn tree(
                 upgrade: _,
                 build_options: _,
                 sources: _,
+                user_constraints: _,
+                user_overrides: _,
+                configured_no_binary_package: _,
+                configured_no_build_package: _,
             } = &settings;
 
             let capabilities = IndexCapabilities::default();
@@ -218,7 +226,8 @@ pub(crate) async fn tree(
             let client = RegistryClientBuilder::new(
                 cache.clone().with_refresh(Refresh::All(Timestamp::now())),
             )
-            .retries_from_env()?
+            .retries(network_settings.retries)
+            .retry_backoff(network_settings.retry_backoff)
             .native_tls(network_settings.native_tls)
             .connectivity(network_settings.connectivity)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
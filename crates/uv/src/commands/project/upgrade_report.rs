@@ -0,0 +1,420 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+
+use uv_cli::ReleaseSegment;
+use uv_configuration::DryRun;
+use uv_normalize::{ExtraName, GroupName, PackageName};
+use uv_pep440::Version;
+use uv_resolver::{Lock, Package};
+
+use crate::commands::project::lock::LockEvent;
+use crate::commands::project::sync::SchemaReport;
+
+/// The most significant segment of a version that changed between two versions of a package.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Bump {
+    /// Only the pre-release, post-release, development release, or local segment changed.
+    Other,
+    /// The third release segment changed.
+    Patch,
+    /// The second release segment changed.
+    Minor,
+    /// The epoch or the first release segment changed.
+    Major,
+}
+
+impl Bump {
+    /// Classify the change between two versions, based on their PEP 440 release segments.
+    ///
+    /// Missing release segments are treated as zero, such that `1.0` to `1.0.1` is a patch
+    /// change.
+    pub(crate) fn between(previous: &Version, version: &Version) -> Option<Self> {
+        if previous == version {
+            return None;
+        }
+        if previous.epoch() != version.epoch() {
+            return Some(Self::Major);
+        }
+        let previous_release = previous.release();
+        let release = version.release();
+        let segment = |release: &[u64], index: usize| release.get(index).copied().unwrap_or(0);
+        if segment(&previous_release, 0) != segment(&release, 0) {
+            Some(Self::Major)
+        } else if segment(&previous_release, 1) != segment(&release, 1) {
+            Some(Self::Minor)
+        } else if segment(&previous_release, 2) != segment(&release, 2) {
+            Some(Self::Patch)
+        } else {
+            Some(Self::Other)
+        }
+    }
+
+    /// Returns `true` if this change reaches the given release segment (e.g., a major change
+    /// reaches the minor segment).
+    fn reaches(self, segment: ReleaseSegment) -> bool {
+        match segment {
+            ReleaseSegment::Major => self >= Self::Major,
+            ReleaseSegment::Minor => self >= Self::Minor,
+            ReleaseSegment::Patch => self >= Self::Patch,
+        }
+    }
+}
+
+impl std::fmt::Display for Bump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Other => write!(f, "other"),
+            Self::Patch => write!(f, "patch"),
+            Self::Minor => write!(f, "minor"),
+            Self::Major => write!(f, "major"),
+        }
+    }
+}
+
+/// A workspace member, extra, or dependency group that (directly or transitively) requires a
+/// package.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+struct RootReport {
+    /// The workspace member, or `None` for dependencies declared at the workspace root of a
+    /// non-project workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package: Option<PackageName>,
+    /// The extra of the workspace member, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extra: Option<ExtraName>,
+    /// The dependency group, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<GroupName>,
+}
+
+/// The kind of change to a package in the lockfile.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ChangeAction {
+    Add,
+    Update,
+    Remove,
+}
+
+/// A change to a single package in the lockfile.
+#[derive(Debug, Serialize)]
+pub(crate) struct ChangeReport<'lock> {
+    /// The name of the package.
+    name: PackageName,
+    /// Whether the package was added, updated, or removed.
+    action: ChangeAction,
+    /// The versions of the package in the existing lockfile; `null` for dynamic versions.
+    previous_versions: Vec<Option<&'lock Version>>,
+    /// The versions of the package in the updated lockfile; `null` for dynamic versions.
+    versions: Vec<Option<&'lock Version>>,
+    /// The most significant segment that changed between the highest previous version and the
+    /// highest updated version, for updated packages.
+    bump: Option<Bump>,
+    /// Whether the package is a direct dependency of a workspace member (or of the workspace
+    /// root).
+    direct: bool,
+    /// The workspace members, extras, and dependency groups that require the package.
+    required_by: Vec<RootReport>,
+}
+
+/// A report of the changes between an existing and an updated lockfile.
+#[derive(Debug, Serialize)]
+pub(crate) struct UpgradeReport<'lock> {
+    /// The schema of this report.
+    schema: SchemaReport,
+    /// The changes to the lockfile, sorted by package name.
+    changes: Vec<ChangeReport<'lock>>,
+    /// Whether this is a dry run.
+    dry_run: bool,
+}
+
+impl<'lock> UpgradeReport<'lock> {
+    /// Generate a report of the changes between an (optional) existing and updated lockfile.
+    pub(crate) fn new(
+        existing_lock: Option<&'lock Lock>,
+        new_lock: &'lock Lock,
+        dry_run: DryRun,
+    ) -> Self {
+        // Removed packages are only reachable in the existing lockfile.
+        let existing_graph = existing_lock.map(Requirers::from_lock).unwrap_or_default();
+        let new_graph = Requirers::from_lock(new_lock);
+
+        let changes = LockEvent::detect_changes(existing_lock, new_lock, dry_run)
+            .map(|event| {
+                let (name, action, previous_versions, versions) = match event {
+                    LockEvent::Update(_, name, previous, new) => {
+                        (name, ChangeAction::Update, previous, new)
+                    }
+                    LockEvent::Add(_, name, new) => (name, ChangeAction::Add, BTreeSet::new(), new),
+                    LockEvent::Remove(_, name, previous) => {
+                        (name, ChangeAction::Remove, previous, BTreeSet::new())
+                    }
+                };
+
+                let bump = match (
+                    previous_versions.iter().flatten().max(),
+                    versions.iter().flatten().max(),
+                ) {
+                    (Some(previous), Some(version)) => Bump::between(previous, version),
+                    _ => None,
+                };
+
+                let graph = if action == ChangeAction::Remove {
+                    &existing_graph
+                } else {
+                    &new_graph
+                };
+                let (direct, required_by) = graph.get(&name);
+
+                ChangeReport {
+                    name,
+                    action,
+                    previous_versions: previous_versions.into_iter().collect(),
+                    versions: versions.into_iter().collect(),
+                    bump,
+                    direct,
+                    required_by,
+                }
+            })
+            .collect();
+
+        Self {
+            schema: SchemaReport::default(),
+            changes,
+            dry_run: dry_run.enabled(),
+        }
+    }
+
+    /// Return the direct dependencies that were updated across the given release segment.
+    pub(crate) fn violations(
+        &self,
+        segment: ReleaseSegment,
+    ) -> impl Iterator<Item = &ChangeReport<'lock>> {
+        self.changes.iter().filter(move |change| {
+            change.action == ChangeAction::Update
+                && change.direct
+                && change.bump.is_some_and(|bump| bump.reaches(segment))
+        })
+    }
+}
+
+impl std::fmt::Display for ChangeReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        /// Format a set of versions for inclusion in the report.
+        fn format_versions(versions: &[Option<&Version>]) -> String {
+            versions
+                .iter()
+                .map(|version| {
+                    version
+                        .map(|version| format!("v{version}"))
+                        .unwrap_or_else(|| "(dynamic)".to_string())
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+
+        write!(
+            f,
+            "{} {} -> {}",
+            self.name,
+            format_versions(&self.previous_versions),
+            format_versions(&self.versions)
+        )?;
+        if let Some(bump) = self.bump {
+            write!(f, " ({bump})")?;
+        }
+        Ok(())
+    }
+}
+
+/// The direct and transitive requirers of each package in a lockfile.
+#[derive(Debug, Default)]
+struct Requirers {
+    /// The packages that are direct dependencies of a root.
+    direct: FxHashSet<PackageName>,
+    /// The roots from which each package is reachable.
+    roots: FxHashMap<PackageName, BTreeSet<RootReport>>,
+}
+
+impl Requirers {
+    /// Walk the lockfile from each workspace member, extra, and dependency group.
+    fn from_lock(lock: &Lock) -> Self {
+        // Index the packages by name. A package may appear multiple times, at different versions,
+        // in a forked resolution; since we report changes by name, we merge the edges.
+        let mut packages: FxHashMap<&PackageName, Vec<&Package>> = FxHashMap::default();
+        for package in lock.packages() {
+            packages.entry(package.name()).or_default().push(package);
+        }
+
+        // Identify the workspace members. The root package is omitted from the list of members for
+        // single-member workspaces.
+        let members: Vec<&Package> = if lock.members().is_empty() {
+            lock.root().into_iter().collect()
+        } else {
+            lock.packages()
+                .iter()
+                .filter(|package| lock.members().contains(package.name()))
+                .collect()
+        };
+
+        // Collect the immediate dependencies of each root.
+        let mut roots: BTreeMap<RootReport, Vec<(PackageName, Vec<ExtraName>)>> = BTreeMap::new();
+        for member in members {
+            let root = |extra: Option<&ExtraName>, group: Option<&GroupName>| RootReport {
+                package: Some(member.name().clone()),
+                extra: extra.cloned(),
+                group: group.cloned(),
+            };
+            roots
+                .entry(root(None, None))
+                .or_default()
+                .extend(member.dependencies().iter().map(|dependency| {
+                    (
+                        dependency.package_name().clone(),
+                        dependency.extra().iter().cloned().collect(),
+                    )
+                }));
+            for (extra, dependencies) in member.optional_dependencies() {
+                roots
+                    .entry(root(Some(extra), None))
+                    .or_default()
+                    .extend(dependencies.iter().map(|dependency| {
+                        (
+                            dependency.package_name().clone(),
+                            dependency.extra().iter().cloned().collect(),
+                        )
+                    }));
+            }
+            for (group, dependencies) in member.resolved_dependency_groups() {
+                roots
+                    .entry(root(None, Some(group)))
+                    .or_default()
+                    .extend(dependencies.iter().map(|dependency| {
+                        (
+                            dependency.package_name().clone(),
+                            dependency.extra().iter().cloned().collect(),
+                        )
+                    }));
+            }
+        }
+
+        // Include any requirements declared at the workspace root of a non-project workspace.
+        for requirement in lock.requirements() {
+            roots
+                .entry(RootReport {
+                    package: None,
+                    extra: None,
+                    group: None,
+                })
+                .or_default()
+                .push((requirement.name.clone(), requirement.extras.to_vec()));
+        }
+        for (group, requirements) in lock.dependency_groups() {
+            roots
+                .entry(RootReport {
+                    package: None,
+                    extra: None,
+                    group: Some(group.clone()),
+                })
+                .or_default()
+                .extend(
+                    requirements
+                        .iter()
+                        .map(|requirement| (requirement.name.clone(), requirement.extras.to_vec())),
+                );
+        }
+
+        // Walk the dependency graph from each root.
+        let mut requirers = Self::default();
+        for (root, dependencies) in roots {
+            let mut queue: VecDeque<(PackageName, Option<ExtraName>)> = VecDeque::new();
+            let mut seen = FxHashSet::default();
+            for (name, extras) in dependencies {
+                requirers.direct.insert(name.clone());
+                for extra in extras {
+                    if seen.insert((name.clone(), Some(extra.clone()))) {
+                        queue.push_back((name.clone(), Some(extra)));
+                    }
+                }
+                if seen.insert((name.clone(), None)) {
+                    queue.push_back((name, None));
+                }
+            }
+
+            while let Some((name, extra)) = queue.pop_front() {
+                requirers
+                    .roots
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(root.clone());
+
+                for package in packages.get(&name).into_iter().flatten() {
+                    let dependencies = match extra.as_ref() {
+                        None => package.dependencies(),
+                        Some(extra) => package
+                            .optional_dependencies()
+                            .get(extra)
+                            .map(Vec::as_slice)
+                            .unwrap_or_default(),
+                    };
+                    for dependency in dependencies {
+                        let name = dependency.package_name();
+                        if seen.insert((name.clone(), None)) {
+                            queue.push_back((name.clone(), None));
+                        }
+                        for extra in dependency.extra() {
+                            if seen.insert((name.clone(), Some(extra.clone()))) {
+                                queue.push_back((name.clone(), Some(extra.clone())));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        requirers
+    }
+
+    /// Return whether the package is a direct dependency, along with the roots that require it.
+    fn get(&self, name: &PackageName) -> (bool, Vec<RootReport>) {
+        (
+            self.direct.contains(name),
+            self.roots
+                .get(name)
+                .map(|roots| roots.iter().cloned().collect())
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::Version;
+
+    use super::Bump;
+
+    #[test]
+    fn bump() {
+        let bump = |previous: &str, version: &str| {
+            Bump::between(
+                &Version::from_str(previous).unwrap(),
+                &Version::from_str(version).unwrap(),
+            )
+        };
+        assert_eq!(bump("1.2.3", "1.2.3"), None);
+        assert_eq!(bump("1.2.3", "2.0.0"), Some(Bump::Major));
+        assert_eq!(bump("2.0.0", "1.9.0"), Some(Bump::Major));
+        assert_eq!(bump("1!1.0", "2!1.0"), Some(Bump::Major));
+        assert_eq!(bump("1.2.3", "1.3.0"), Some(Bump::Minor));
+        assert_eq!(bump("1.2.3", "1.2.4"), Some(Bump::Patch));
+        assert_eq!(bump("1.0", "1.0.1"), Some(Bump::Patch));
+        assert_eq!(bump("1.0", "1.0.0"), None);
+        assert_eq!(bump("1.2.3", "1.2.3.1"), Some(Bump::Other));
+        assert_eq!(bump("1.2.3rc1", "1.2.3"), Some(Bump::Other));
+    }
+}
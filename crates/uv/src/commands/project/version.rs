@@ -559,6 +559,7 @@ async fn lock_and_sync(
             no_sync,
             no_config,
             active,
+            false,
             cache,
             DryRun::Disabled,
             printer,
@@ -0,0 +1,162 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_configuration::Preview;
+use uv_distribution_types::Name;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_python::{EnvironmentPreference, PythonEnvironment, PythonPreference, PythonRequest};
+use uv_resolver::{WhyDisplay, WhyEdge, WhyNode, WhyVia};
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::commands::pip::operations::report_target_environment;
+use crate::commands::project::ProjectError;
+use crate::commands::project::lock_target::LockTarget;
+use crate::printer::Printer;
+
+/// Show why a package is required by the project.
+pub(crate) async fn why(
+    project_dir: &Path,
+    package: PackageName,
+    environment: bool,
+    python: Option<String>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<ExitStatus> {
+    let display = if environment {
+        why_environment(package, python.as_deref(), cache, printer, preview)?
+    } else {
+        let workspace_cache = WorkspaceCache::default();
+        let workspace =
+            Workspace::discover(project_dir, &DiscoveryOptions::default(), &workspace_cache)
+                .await?;
+        let Some(lock) = LockTarget::from(&workspace).read().await? else {
+            return Err(ProjectError::MissingLockfile.into());
+        };
+        WhyDisplay::from_lock(&lock, package)
+    };
+
+    if display.is_empty() {
+        if display.is_root() {
+            writeln!(
+                printer.stderr(),
+                "`{}` is not required by any other package",
+                display.target().cyan()
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+        writeln!(
+            printer.stderr(),
+            "{}{} `{}` is not required by the project",
+            "warning".yellow().bold(),
+            ":".bold(),
+            display.target().cyan()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    write!(printer.stdout(), "{display}")?;
+
+    if display.only_via_extras() {
+        writeln!(
+            printer.stderr(),
+            "{}{} `{}` is only required through optional dependencies: {}",
+            "note".bold(),
+            ":".bold(),
+            display.target().cyan(),
+            display
+                .root_extras()
+                .into_iter()
+                .map(|(package, extra)| format!("`{package}[{extra}]`").cyan().to_string())
+                .join(", ")
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Find the paths to a package using the metadata of the packages installed in an environment.
+fn why_environment(
+    package: PackageName,
+    python: Option<&str>,
+    cache: &Cache,
+    printer: Printer,
+    preview: Preview,
+) -> Result<WhyDisplay> {
+    // Detect the current Python interpreter.
+    let environment = PythonEnvironment::find(
+        &python.map(PythonRequest::parse).unwrap_or_default(),
+        EnvironmentPreference::from_system_flag(false, false),
+        PythonPreference::default(),
+        cache,
+        preview,
+    )?;
+
+    report_target_environment(&environment, cache, printer)?;
+
+    // Build the installed index.
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    // Determine the markers to use for evaluating requirements.
+    let markers = environment.interpreter().resolver_marker_environment();
+
+    // Collect the requirements between installed packages, attributing any requirement that is
+    // only enabled by an extra to that extra.
+    let mut edges = Vec::new();
+    for dist in site_packages.iter() {
+        let Ok(metadata) = dist.metadata() else {
+            continue;
+        };
+        for requirement in &metadata.requires_dist {
+            if site_packages.get_packages(&requirement.name).is_empty() {
+                continue;
+            }
+            let vias = if requirement.evaluate_markers(&markers, &[]) {
+                vec![None]
+            } else {
+                metadata
+                    .provides_extras
+                    .iter()
+                    .filter(|extra| {
+                        requirement.evaluate_markers(&markers, std::slice::from_ref(*extra))
+                    })
+                    .map(|extra| Some(WhyVia::Extra(extra.clone())))
+                    .collect()
+            };
+            for via in vias {
+                edges.push(WhyEdge {
+                    from: WhyNode {
+                        package: Some(dist.name().clone()),
+                        via,
+                    },
+                    to: requirement.name.clone(),
+                    extras: requirement.extras.iter().cloned().collect(),
+                    requirement: requirement.to_string(),
+                });
+            }
+        }
+    }
+
+    // Treat any package that isn't required by another installed package as a root.
+    let required: BTreeSet<&PackageName> = edges
+        .iter()
+        .filter(|edge| edge.from.package.as_ref() != Some(&edge.to))
+        .map(|edge| &edge.to)
+        .collect();
+    let roots: BTreeSet<PackageName> = site_packages
+        .iter()
+        .map(Name::name)
+        .filter(|name| !required.contains(name))
+        .cloned()
+        .collect();
+
+    Ok(WhyDisplay::new(package, &roots, edges))
+}
@@ -5,6 +5,7 @@ use std::time::Duration;
 use anyhow::{Context, Result, bail};
 use console::Term;
 use owo_colors::{AnsiColors, OwoColorize};
+use reqwest_retry::policies::ExponentialBackoff;
 use tokio::sync::Semaphore;
 use tracing::{debug, info, trace};
 use uv_auth::Credentials;
@@ -32,6 +33,7 @@ pub(crate) async fn publish(
     username: Option<String>,
     password: Option<String>,
     check_url: Option<IndexUrl>,
+    index_locations: &IndexLocations,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -70,6 +72,10 @@ pub(crate) async fn publish(
     let oidc_client = BaseClientBuilder::new()
         .auth_integration(AuthIntegration::NoAuthMiddleware)
         .wrap_existing(&upload_client);
+    // The upload client doesn't retry, so the upload loop applies the retry settings itself.
+    let retry_policy = ExponentialBackoff::builder()
+        .base(network_settings.retry_backoff)
+        .build_with_max_retries(network_settings.retries);
     // We're only checking a single URL and one at a time, so 1 permit is sufficient
     let download_concurrency = Arc::new(Semaphore::new(1));
 
@@ -88,13 +94,18 @@ pub(crate) async fn publish(
 
     // Initialize the registry client.
     let check_url_client = if let Some(index_url) = &check_url {
-        let index_locations = IndexLocations::new(
-            vec![Index::from_index_url(index_url.clone())],
-            Vec::new(),
-            false,
-        );
+        // If the check URL matches a configured index, use it, such that any credentials for the
+        // index (e.g., `UV_INDEX_<NAME>_USERNAME`) are respected.
+        let index = index_locations
+            .simple_indexes()
+            .find(|index| index.url == *index_url)
+            .cloned()
+            .unwrap_or_else(|| Index::from_index_url(index_url.clone()));
+        let index_locations = IndexLocations::new(vec![index], Vec::new(), false);
+        index_locations.cache_index_credentials();
         let registry_client_builder = RegistryClientBuilder::new(cache.clone())
-            .retries_from_env()?
+            .retries(network_settings.retries)
+            .retry_backoff(network_settings.retry_backoff)
             .native_tls(network_settings.native_tls)
             .connectivity(network_settings.connectivity)
             .allow_insecure_host(network_settings.allow_insecure_host.clone())
@@ -139,6 +150,7 @@ pub(crate) async fn publish(
             &credentials,
             check_url_client.as_ref(),
             &download_concurrency,
+            &retry_policy,
             // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
             Arc::new(reporter),
         )
@@ -0,0 +1,85 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_cache::Cache;
+use uv_python::PythonRequest;
+use uv_python::downloads::PythonDownloadRequest;
+use uv_python::health::{self, PythonHealth};
+use uv_python::managed::ManagedPythonInstallations;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Check that managed Python installations are functional.
+pub(crate) fn check(
+    install_dir: Option<PathBuf>,
+    targets: Vec<String>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let installations = ManagedPythonInstallations::from_settings(install_dir)?.init()?;
+
+    let download_requests = targets
+        .iter()
+        .map(|target| PythonRequest::parse(target.as_str()))
+        .map(|request| {
+            PythonDownloadRequest::from_request(&request)
+                .map(|request| request.with_prereleases(true))
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Cannot check managed Python for request: {request}")
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let matching_installations = installations
+        .find_all()?
+        .filter(|installation| {
+            download_requests.is_empty()
+                || download_requests
+                    .iter()
+                    .any(|request| request.satisfied_by_key(installation.key()))
+        })
+        .collect::<Vec<_>>();
+
+    if matching_installations.is_empty() {
+        writeln!(printer.stderr(), "No Python installations found")?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let mut broken = 0usize;
+    for installation in &matching_installations {
+        match health::check(installation, cache)? {
+            PythonHealth::Healthy => {
+                writeln!(printer.stdout(), "{} {}", installation.key(), "ok".green())?;
+            }
+            PythonHealth::Broken(reason) => {
+                broken += 1;
+                writeln!(
+                    printer.stdout(),
+                    "{} {} ({reason})",
+                    installation.key(),
+                    "broken".red()
+                )?;
+            }
+        }
+    }
+
+    if broken > 0 {
+        writeln!(
+            printer.stderr(),
+            "Found {broken} broken {}; run `{}` to repair",
+            if broken == 1 {
+                "installation"
+            } else {
+                "installations"
+            },
+            "uv python install --reinstall-broken".green()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
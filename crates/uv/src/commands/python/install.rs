@@ -14,12 +14,14 @@ use owo_colors::{AnsiColors, OwoColorize};
 use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{debug, trace};
 
-use uv_configuration::{Preview, PreviewFeatures};
+use uv_cache::Cache;
+use uv_configuration::{DryRun, Preview, PreviewFeatures};
 use uv_fs::Simplified;
 use uv_platform::{Arch, Libc};
 use uv_python::downloads::{
     self, ArchRequest, DownloadResult, ManagedPythonDownload, PythonDownloadRequest,
 };
+use uv_python::health::{self, PythonHealth};
 use uv_python::managed::{
     ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink,
     create_link_to_executable, python_executable_dir,
@@ -150,6 +152,7 @@ pub(crate) async fn install(
     install_dir: Option<PathBuf>,
     targets: Vec<String>,
     reinstall: bool,
+    reinstall_broken: bool,
     upgrade: bool,
     bin: Option<bool>,
     registry: Option<bool>,
@@ -161,6 +164,8 @@ pub(crate) async fn install(
     default: bool,
     python_downloads: PythonDownloads,
     no_config: bool,
+    dry_run: DryRun,
+    cache: &Cache,
     preview: Preview,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -188,6 +193,9 @@ pub(crate) async fn install(
         anyhow::bail!("The `--default` flag cannot be used with multiple targets");
     }
 
+    // Broken installations are reinstalled in place.
+    let reinstall = reinstall || reinstall_broken;
+
     // Read the existing installations, lock the directory for the duration
     let installations = ManagedPythonInstallations::from_settings(install_dir.clone())?.init()?;
     let installations_dir = installations.root();
@@ -248,6 +256,47 @@ pub(crate) async fn install(
             .collect::<Result<Vec<_>>>()?
     };
 
+    // With `--reinstall-broken`, only reinstall the existing installations that fail a health
+    // check, requesting each by its exact key so that it's replaced in place.
+    let requests = if reinstall_broken {
+        let mut broken = Vec::new();
+        for installation in &existing_installations {
+            if !targets.is_empty()
+                && !requests
+                    .iter()
+                    .any(|request| request.matches_installation(installation))
+            {
+                continue;
+            }
+            match health::check(installation, cache)? {
+                PythonHealth::Healthy => {
+                    debug!("Installation `{}` is healthy", installation.key().green());
+                }
+                PythonHealth::Broken(reason) => {
+                    writeln!(
+                        printer.stderr(),
+                        "Found broken installation `{}`: {reason}",
+                        installation.key().green()
+                    )?;
+                    broken.push(InstallRequest::new(
+                        PythonRequest::Key(installation.into()),
+                        python_downloads_json_url.as_deref(),
+                    )?);
+                }
+            }
+        }
+        if broken.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "All requested Python installations are healthy"
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+        broken
+    } else {
+        requests
+    };
+
     let Some(first_request) = requests.first() else {
         if upgrade {
             writeln!(
@@ -390,9 +439,58 @@ pub(crate) async fn install(
         .unique_by(|download| download.key())
         .collect::<Vec<_>>();
 
+    // In dry-run mode, report the versions that would be installed, along with any minor version
+    // directories (and, in turn, virtual environments) that would be moved to the new version.
+    if dry_run.enabled() {
+        if downloads.is_empty() {
+            if upgrade {
+                writeln!(
+                    printer.stderr(),
+                    "All requested versions already on latest supported patch release"
+                )?;
+            } else {
+                writeln!(printer.stderr(), "All requested versions already installed")?;
+            }
+            return Ok(ExitStatus::Success);
+        }
+
+        let minor_versions =
+            PythonInstallationMinorVersionKey::highest_installations_by_minor_version_key(
+                &existing_installations,
+            );
+        for download in &downloads {
+            let Some(existing) =
+                minor_versions.get(PythonInstallationMinorVersionKey::ref_cast(download.key()))
+            else {
+                writeln!(printer.stderr(), "Would install {}", download.key().green())?;
+                continue;
+            };
+            writeln!(
+                printer.stderr(),
+                "Would upgrade {} to {}",
+                existing.key().green(),
+                download.key().green()
+            )?;
+            if let Some(minor_version_link) =
+                PythonMinorVersionLink::from_installation(existing, preview)
+            {
+                if minor_version_link.exists() {
+                    writeln!(
+                        printer.stderr(),
+                        "Would update {} to use {}",
+                        minor_version_link.symlink_directory.user_display().cyan(),
+                        download.key().green()
+                    )?;
+                }
+            }
+        }
+        return Ok(ExitStatus::Success);
+    }
+
     // Download and unpack the Python versions concurrently
     let client = uv_client::BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone())
@@ -43,6 +43,10 @@ struct PrintData {
     path: Option<String>,
     symlink: Option<String>,
     url: Option<String>,
+    /// Whether the interpreter is installed, rather than available for download.
+    installed: bool,
+    /// Whether the interpreter is, or would be, managed by uv.
+    managed: bool,
     os: String,
     variant: String,
     implementation: String,
@@ -210,14 +214,14 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, uri));
+        include.push((key, kind, uri));
     }
 
     match output_format {
         PythonListFormat::Json => {
             let data = include
                 .iter()
-                .map(|(key, uri)| -> Result<_> {
+                .map(|(key, kind, uri)| -> Result<_> {
                     let mut path_or_none: Option<String> = None;
                     let mut symlink_or_none: Option<String> = None;
                     let mut url_or_none: Option<String> = None;
@@ -250,6 +254,8 @@ pub(crate) async fn list(
                         path: path_or_none,
                         symlink: symlink_or_none,
                         url: url_or_none,
+                        installed: !matches!(kind, Kind::Download),
+                        managed: !matches!(kind, Kind::System),
                         arch: key.arch().to_string(),
                         implementation: key.implementation().to_string(),
                         os: key.os().to_string(),
@@ -264,9 +270,9 @@ pub(crate) async fn list(
             // Compute the width of the first column.
             let width = include
                 .iter()
-                .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+                .fold(0usize, |acc, (key, _, _)| acc.max(key.to_string().len()));
 
-            for (key, uri) in include {
+            for (key, _, uri) in include {
                 let key = key.to_string();
                 match uri {
                     Either::Left(path) => {
@@ -1,3 +1,4 @@
+pub(crate) mod check;
 pub(crate) mod dir;
 pub(crate) mod find;
 pub(crate) mod install;
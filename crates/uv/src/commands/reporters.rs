@@ -2,12 +2,14 @@ use std::env;
 use std::fmt::Write;
 use std::ops::Deref;
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
 
 use crate::commands::human_readable_bytes;
 use crate::printer::Printer;
@@ -31,6 +33,8 @@ struct ProgressReporter {
     printer: Printer,
     root: ProgressBar,
     mode: ProgressMode,
+    /// The JSON event emitter, if progress should be reported as JSON rather than as progress bars.
+    json: Option<JsonProgress>,
 }
 
 #[derive(Debug)]
@@ -130,7 +134,12 @@ impl From<uv_python::downloads::Direction> for Direction {
 }
 
 impl ProgressReporter {
-    fn new(root: ProgressBar, multi_progress: MultiProgress, printer: Printer) -> Self {
+    fn new(
+        root: ProgressBar,
+        multi_progress: MultiProgress,
+        printer: Printer,
+        phase: Option<JsonPhase>,
+    ) -> Self {
         let mode = if env::var(EnvVars::JPY_SESSION_NAME).is_ok() {
             // Disable concurrent progress bars when running inside a Jupyter notebook
             // because the Jupyter terminal does not support clearing previous lines.
@@ -143,14 +152,21 @@ impl ProgressReporter {
             }
         };
 
+        let json = (printer == Printer::JsonProgress).then(|| JsonProgress::new(printer, phase));
+
         Self {
             printer,
             root,
             mode,
+            json,
         }
     }
 
     fn on_build_start(&self, source: &BuildableSource) -> usize {
+        if let Some(json) = &self.json {
+            return json.start(JsonPhase::Build, Some(source.to_string()), None);
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -184,6 +200,11 @@ impl ProgressReporter {
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
+        if let Some(json) = &self.json {
+            json.complete(id);
+            return;
+        }
+
         let ProgressMode::Multi {
             state,
             multi_progress,
@@ -210,6 +231,10 @@ impl ProgressReporter {
     }
 
     fn on_request_start(&self, direction: Direction, name: String, size: Option<u64>) -> usize {
+        if let Some(json) = &self.json {
+            return json.start(JsonPhase::from(direction), Some(name), size);
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -299,6 +324,11 @@ impl ProgressReporter {
     }
 
     fn on_request_progress(&self, id: usize, bytes: u64) {
+        if let Some(json) = &self.json {
+            json.progress(id, bytes, None);
+            return;
+        }
+
         let ProgressMode::Multi { state, .. } = &self.mode else {
             return;
         };
@@ -307,6 +337,11 @@ impl ProgressReporter {
     }
 
     fn on_request_complete(&self, direction: Direction, id: usize) {
+        if let Some(json) = &self.json {
+            json.complete(id);
+            return;
+        }
+
         let ProgressMode::Multi {
             state,
             multi_progress,
@@ -359,6 +394,10 @@ impl ProgressReporter {
     }
 
     fn on_checkout_start(&self, url: &DisplaySafeUrl, rev: &str) -> usize {
+        if let Some(json) = &self.json {
+            return json.start(JsonPhase::Checkout, Some(format!("{url} ({rev})")), None);
+        }
+
         let ProgressMode::Multi {
             multi_progress,
             state,
@@ -389,6 +428,11 @@ impl ProgressReporter {
     }
 
     fn on_checkout_complete(&self, url: &DisplaySafeUrl, rev: &str, id: usize) {
+        if let Some(json) = &self.json {
+            json.complete(id);
+            return;
+        }
+
         let ProgressMode::Multi {
             state,
             multi_progress,
@@ -433,7 +477,8 @@ impl From<Printer> for PrepareReporter {
         );
         root.set_message("Preparing packages...");
 
-        let reporter = ProgressReporter::new(root, multi_progress, printer);
+        let reporter =
+            ProgressReporter::new(root, multi_progress, printer, Some(JsonPhase::Prepare));
         Self { reporter }
     }
 }
@@ -441,17 +486,27 @@ impl From<Printer> for PrepareReporter {
 impl PrepareReporter {
     #[must_use]
     pub(crate) fn with_length(self, length: u64) -> Self {
+        if let Some(json) = &self.reporter.json {
+            json.root_total(length);
+        }
         self.reporter.root.set_length(length);
         self
     }
 }
 
 impl uv_installer::PrepareReporter for PrepareReporter {
-    fn on_progress(&self, _dist: &CachedDist) {
+    fn on_progress(&self, dist: &CachedDist) {
+        if let Some(json) = &self.reporter.json {
+            json.root_progress(1, Some(dist.to_string()));
+        }
         self.reporter.root.inc(1);
     }
 
     fn on_complete(&self) {
+        if let Some(json) = &self.reporter.json {
+            json.root_complete();
+        }
+
         // Need an extra call to `set_message` here to fully clear avoid leaving ghost output
         // in Jupyter notebooks.
         self.reporter.root.set_message("");
@@ -495,6 +550,9 @@ pub(crate) struct ResolverReporter {
 impl ResolverReporter {
     #[must_use]
     pub(crate) fn with_length(self, length: u64) -> Self {
+        if let Some(json) = &self.reporter.json {
+            json.root_total(length);
+        }
         self.reporter.root.set_length(length);
         self
     }
@@ -512,13 +570,19 @@ impl From<Printer> for ResolverReporter {
         );
         root.set_message("Resolving dependencies...");
 
-        let reporter = ProgressReporter::new(root, multi_progress, printer);
+        let reporter =
+            ProgressReporter::new(root, multi_progress, printer, Some(JsonPhase::Resolve));
         Self { reporter }
     }
 }
 
 impl uv_resolver::ResolverReporter for ResolverReporter {
     fn on_progress(&self, name: &PackageName, version_or_url: &VersionOrUrlRef) {
+        if let Some(json) = &self.reporter.json {
+            json.root_progress(0, Some(format!("{name}{version_or_url}")));
+            return;
+        }
+
         match version_or_url {
             VersionOrUrlRef::Version(version) => {
                 self.reporter.root.set_message(format!("{name}=={version}"));
@@ -530,6 +594,9 @@ impl uv_resolver::ResolverReporter for ResolverReporter {
     }
 
     fn on_complete(&self) {
+        if let Some(json) = &self.reporter.json {
+            json.root_complete();
+        }
         self.reporter.root.set_message("");
         self.reporter.root.finish_and_clear();
     }
@@ -596,6 +663,7 @@ impl uv_distribution::Reporter for ResolverReporter {
 #[derive(Debug)]
 pub(crate) struct InstallReporter {
     progress: ProgressBar,
+    json: Option<JsonProgress>,
 }
 
 impl From<Printer> for InstallReporter {
@@ -605,13 +673,18 @@ impl From<Printer> for InstallReporter {
             ProgressStyle::with_template("{bar:20} [{pos}/{len}] {wide_msg:.dim}").unwrap(),
         );
         progress.set_message("Installing wheels...");
-        Self { progress }
+        let json = (printer == Printer::JsonProgress)
+            .then(|| JsonProgress::new(printer, Some(JsonPhase::Install)));
+        Self { progress, json }
     }
 }
 
 impl InstallReporter {
     #[must_use]
     pub(crate) fn with_length(self, length: u64) -> Self {
+        if let Some(json) = &self.json {
+            json.root_total(length);
+        }
         self.progress.set_length(length);
         self
     }
@@ -619,11 +692,17 @@ impl InstallReporter {
 
 impl uv_installer::InstallReporter for InstallReporter {
     fn on_install_progress(&self, wheel: &CachedDist) {
+        if let Some(json) = &self.json {
+            json.root_progress(1, Some(wheel.to_string()));
+        }
         self.progress.set_message(format!("{wheel}"));
         self.progress.inc(1);
     }
 
     fn on_install_complete(&self) {
+        if let Some(json) = &self.json {
+            json.root_complete();
+        }
         self.progress.set_message("");
         self.progress.finish_and_clear();
     }
@@ -647,7 +726,7 @@ impl PythonDownloadReporter {
             Some(length),
             printer.target(),
         ));
-        let reporter = ProgressReporter::new(root, multi_progress, printer);
+        let reporter = ProgressReporter::new(root, multi_progress, printer, None);
         Self { reporter }
     }
 }
@@ -690,7 +769,7 @@ impl PublishReporter {
             Some(length),
             printer.target(),
         ));
-        let reporter = ProgressReporter::new(root, multi_progress, printer);
+        let reporter = ProgressReporter::new(root, multi_progress, printer, None);
         Self { reporter }
     }
 }
@@ -811,6 +890,206 @@ impl CleaningPackageReporter {
     }
 }
 
+/// The phase of an operation, as reported by `--progress json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum JsonPhase {
+    /// Resolving dependencies.
+    Resolve,
+    /// Preparing distributions for installation (i.e., downloading and building).
+    Prepare,
+    /// Downloading an artifact.
+    Download,
+    /// Uploading an artifact.
+    Upload,
+    /// Extracting an artifact.
+    Extract,
+    /// Building a source distribution.
+    Build,
+    /// Fetching a Git repository.
+    Checkout,
+    /// Installing distributions into an environment.
+    Install,
+}
+
+impl From<Direction> for JsonPhase {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Download => Self::Download,
+            Direction::Upload => Self::Upload,
+            Direction::Extract => Self::Extract,
+        }
+    }
+}
+
+/// A machine-readable progress event, emitted as a single line of JSON on stderr.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+enum JsonEvent<'a> {
+    /// A task has started.
+    Start {
+        id: usize,
+        phase: JsonPhase,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<&'a str>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total: Option<u64>,
+    },
+    /// A task has made progress, e.g., in bytes downloaded or packages installed.
+    Progress {
+        id: usize,
+        phase: JsonPhase,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<&'a str>,
+        done: u64,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        total: Option<u64>,
+    },
+    /// A task has completed.
+    Complete {
+        id: usize,
+        phase: JsonPhase,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<&'a str>,
+        duration_ms: u64,
+    },
+}
+
+/// A monotonic counter for JSON task IDs, shared across reporters such that IDs are unique within
+/// a single invocation.
+static JSON_TASK_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The minimum interval between progress events for a single task.
+const JSON_PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+struct JsonTask {
+    phase: JsonPhase,
+    name: Option<String>,
+    started: Instant,
+    /// The last time a progress event was emitted for the task.
+    reported: Instant,
+    done: u64,
+    total: Option<u64>,
+}
+
+/// Reports progress as newline-delimited JSON events, in lieu of progress bars.
+#[derive(Debug)]
+struct JsonProgress {
+    printer: Printer,
+    /// The ID of the top-level task (e.g., the resolution as a whole), if any.
+    root: Option<usize>,
+    tasks: Mutex<FxHashMap<usize, JsonTask>>,
+}
+
+impl JsonProgress {
+    fn new(printer: Printer, phase: Option<JsonPhase>) -> Self {
+        let mut progress = Self {
+            printer,
+            root: None,
+            tasks: Mutex::default(),
+        };
+        progress.root = phase.map(|phase| progress.start(phase, None, None));
+        progress
+    }
+
+    /// Start a new task, returning its ID.
+    fn start(&self, phase: JsonPhase, name: Option<String>, total: Option<u64>) -> usize {
+        let id = JSON_TASK_ID.fetch_add(1, Ordering::Relaxed) + 1;
+        self.emit(&JsonEvent::Start {
+            id,
+            phase,
+            name: name.as_deref(),
+            total,
+        });
+        let now = Instant::now();
+        self.tasks.lock().unwrap().insert(
+            id,
+            JsonTask {
+                phase,
+                name,
+                started: now,
+                reported: now,
+                done: 0,
+                total,
+            },
+        );
+        id
+    }
+
+    /// Record progress for a task.
+    ///
+    /// Progress without a new name (e.g., bytes downloaded) is throttled to avoid flooding the
+    /// output.
+    fn progress(&self, id: usize, inc: u64, name: Option<String>) {
+        let mut tasks = self.tasks.lock().unwrap();
+        let Some(task) = tasks.get_mut(&id) else {
+            return;
+        };
+        task.done += inc;
+
+        let now = Instant::now();
+        if name.is_none() && now.duration_since(task.reported) < JSON_PROGRESS_INTERVAL {
+            return;
+        }
+        if name.is_some() {
+            task.name = name;
+        }
+        task.reported = now;
+
+        self.emit(&JsonEvent::Progress {
+            id,
+            phase: task.phase,
+            name: task.name.as_deref(),
+            done: task.done,
+            total: task.total,
+        });
+    }
+
+    /// Mark a task as complete.
+    fn complete(&self, id: usize) {
+        let Some(task) = self.tasks.lock().unwrap().remove(&id) else {
+            return;
+        };
+        self.emit(&JsonEvent::Complete {
+            id,
+            phase: task.phase,
+            name: task.name.as_deref(),
+            duration_ms: u64::try_from(task.started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        });
+    }
+
+    /// Set the total for the top-level task.
+    fn root_total(&self, total: u64) {
+        let Some(root) = self.root else {
+            return;
+        };
+        if let Some(task) = self.tasks.lock().unwrap().get_mut(&root) {
+            task.total = Some(total);
+        }
+    }
+
+    /// Record progress for the top-level task.
+    fn root_progress(&self, inc: u64, name: Option<String>) {
+        if let Some(root) = self.root {
+            self.progress(root, inc, name);
+        }
+    }
+
+    /// Mark the top-level task as complete.
+    fn root_complete(&self) {
+        if let Some(root) = self.root {
+            self.complete(root);
+        }
+    }
+
+    fn emit(&self, event: &JsonEvent) {
+        if let Ok(line) = serde_json::to_string(event) {
+            let _ = writeln!(self.printer.stderr(), "{line}");
+        }
+    }
+}
+
 /// Like [`std::fmt::Display`], but with colors.
 trait ColorDisplay {
     fn to_color_string(&self) -> String;
@@ -5,8 +5,10 @@ use axoupdater::{AxoUpdater, AxoupdateError, UpdateRequest};
 use owo_colors::OwoColorize;
 use tracing::debug;
 
+use uv_cli::SelfUpdateChannel;
 use uv_client::WrappedReqwestError;
 use uv_fs::Simplified;
+use uv_pep440::Version;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
@@ -14,12 +16,37 @@ use crate::settings::NetworkSettings;
 
 /// Attempt to update the uv binary.
 pub(crate) async fn self_update(
-    version: Option<String>,
+    version: Option<Version>,
+    channel: SelfUpdateChannel,
+    allow_downgrade: bool,
     token: Option<String>,
     dry_run: bool,
     printer: Printer,
     network_settings: NetworkSettings,
 ) -> Result<ExitStatus> {
+    // Refuse to downgrade unless explicitly requested.
+    if let Some(version) = version.as_ref() {
+        if !allow_downgrade {
+            if let Ok(current) = env!("CARGO_PKG_VERSION").parse::<Version>() {
+                if *version < current {
+                    writeln!(
+                        printer.stderr(),
+                        "{}",
+                        format_args!(
+                            "{}{} Refusing to downgrade uv from {} to {}; pass {} to downgrade",
+                            "error".red().bold(),
+                            ":".bold(),
+                            format!("v{current}").bold().cyan(),
+                            format!("v{version}").bold().cyan(),
+                            "`--allow-downgrade`".green().bold()
+                        )
+                    )?;
+                    return Ok(ExitStatus::Error);
+                }
+            }
+        }
+    }
+
     if network_settings.connectivity.is_offline() {
         writeln!(
             printer.stderr(),
@@ -108,9 +135,12 @@ pub(crate) async fn self_update(
     )?;
 
     let update_request = if let Some(version) = version {
-        UpdateRequest::SpecificTag(version)
+        UpdateRequest::SpecificTag(version.to_string())
     } else {
-        UpdateRequest::Latest
+        match channel {
+            SelfUpdateChannel::Stable => UpdateRequest::Latest,
+            SelfUpdateChannel::Preview => UpdateRequest::LatestMaybePrerelease,
+        }
     };
 
     updater.configure_version_specifier(update_request.clone());
@@ -66,7 +66,8 @@ pub(crate) async fn install(
     preview: Preview,
 ) -> Result<ExitStatus> {
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -98,7 +99,8 @@ pub(crate) async fn install(
     let workspace_cache = WorkspaceCache::default();
 
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -196,6 +198,28 @@ pub(crate) async fn install(
                 origin: None,
             }
         }
+        // Ex) `ruff@>=0.6.0,<0.7.0`
+        ToolRequest::Package {
+            target: Target::Specifiers(.., name, extras, specifiers),
+            ..
+        } => {
+            if editable {
+                bail!("`--editable` is only supported for local packages");
+            }
+
+            Requirement {
+                name: name.clone(),
+                extras: extras.clone(),
+                groups: Box::new([]),
+                marker: MarkerTree::default(),
+                source: RequirementSource::Registry {
+                    specifier: specifiers.clone(),
+                    index: None,
+                    conflict: None,
+                },
+                origin: None,
+            }
+        }
         // Ex) `ruff@latest`
         ToolRequest::Package {
             target: Target::Latest(.., name, extras),
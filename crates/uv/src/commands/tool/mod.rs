@@ -3,7 +3,7 @@ use std::str::FromStr;
 use tracing::debug;
 
 use uv_normalize::{ExtraName, PackageName};
-use uv_pep440::Version;
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_python::PythonRequest;
 
 mod common;
@@ -62,6 +62,18 @@ impl<'a> ToolRequest<'a> {
         }
     }
 
+    /// Returns `true` if the target includes a version request (e.g., `ruff@0.6.0` or
+    /// `ruff@>=0.6,<0.7`).
+    pub(crate) fn has_version(&self) -> bool {
+        matches!(
+            self,
+            Self::Package {
+                target: Target::Version(..) | Target::Specifiers(..),
+                ..
+            }
+        )
+    }
+
     /// Returns `true` if the target is `latest`.
     pub(crate) fn is_latest(&self) -> bool {
         matches!(
@@ -80,6 +92,8 @@ pub(crate) enum Target<'a> {
     Unspecified(&'a str),
     /// e.g., `ruff[extra]@0.6.0`
     Version(&'a str, PackageName, Box<[ExtraName]>, Version),
+    /// e.g., `ruff[extra]@>=0.6,<0.7`
+    Specifiers(&'a str, PackageName, Box<[ExtraName]>, VersionSpecifiers),
     /// e.g., `ruff[extra]@latest`
     Latest(&'a str, PackageName, Box<[ExtraName]>),
 }
@@ -135,6 +149,9 @@ impl<'a> Target<'a> {
             version => {
                 if let Ok(version) = Version::from_str(version) {
                     Self::Version(executable, name, extras, version)
+                } else if let Ok(specifiers) = VersionSpecifiers::from_str(version) {
+                    // e.g., `ruff@>=0.6,<0.7`
+                    Self::Specifiers(executable, name, extras, specifiers)
                 } else {
                     // e.g. `ruff@invalid`, warn and treat the whole thing as the command
                     debug!("Ignoring invalid version request `{version}` in command");
@@ -198,6 +215,29 @@ mod tests {
         );
         assert_eq!(target, expected);
 
+        let target = Target::parse("flask@>=3.0,<3.1");
+        let expected = Target::Specifiers(
+            "flask",
+            PackageName::from_str("flask").unwrap(),
+            Box::new([]),
+            VersionSpecifiers::from_str(">=3.0,<3.1").unwrap(),
+        );
+        assert_eq!(target, expected);
+
+        let target = Target::parse("flask[dotenv]@~=3.0");
+        let expected = Target::Specifiers(
+            "flask",
+            PackageName::from_str("flask").unwrap(),
+            Box::new([ExtraName::from_str("dotenv").unwrap()]),
+            VersionSpecifiers::from_str("~=3.0").unwrap(),
+        );
+        assert_eq!(target, expected);
+
+        // An invalid version request.
+        let target = Target::parse("flask@invalid");
+        let expected = Target::Unspecified("flask@invalid");
+        assert_eq!(target, expected);
+
         // Missing a closing `]`.
         let target = Target::parse("flask[dotenv");
         let expected = Target::Unspecified("flask[dotenv");
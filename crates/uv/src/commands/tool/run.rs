@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Write;
 use std::path::Path;
@@ -14,10 +15,11 @@ use tracing::{debug, warn};
 
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
+use uv_cache_key::cache_digest;
 use uv_cli::ExternalCommand;
 use uv_client::BaseClientBuilder;
 use uv_configuration::Constraints;
-use uv_configuration::{Concurrency, Preview};
+use uv_configuration::{Concurrency, ExecutableName, Preview};
 use uv_distribution_types::InstalledDist;
 use uv_distribution_types::{
     IndexUrl, Name, NameRequirementSpecification, Requirement, RequirementSource,
@@ -37,12 +39,12 @@ use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
 use uv_shell::runnable::WindowsRunnable;
 use uv_static::EnvVars;
 use uv_tool::{InstalledTools, entrypoint_paths};
-use uv_warnings::warn_user;
 use uv_warnings::warn_user_once;
 use uv_workspace::WorkspaceCache;
 
 use crate::child::run_to_completion;
 use crate::commands::ExitStatus;
+use crate::commands::env_file::load_env_files;
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
@@ -81,6 +83,7 @@ impl Display for ToolRunCommand {
 pub(crate) async fn run(
     command: Option<ExternalCommand>,
     from: Option<String>,
+    tool_aliases: &BTreeMap<ExecutableName, PackageName>,
     with: &[RequirementsSource],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
@@ -109,43 +112,12 @@ pub(crate) async fn run(
             .is_some_and(|ext| ext.eq_ignore_ascii_case("py") || ext.eq_ignore_ascii_case("pyw"))
     }
 
-    // Read from the `.env` file, if necessary.
-    if !no_env_file {
-        for env_file_path in env_file.iter().rev().map(PathBuf::as_path) {
-            match dotenvy::from_path(env_file_path) {
-                Err(dotenvy::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
-                    bail!(
-                        "No environment file found at: `{}`",
-                        env_file_path.simplified_display()
-                    );
-                }
-                Err(dotenvy::Error::Io(err)) => {
-                    bail!(
-                        "Failed to read environment file `{}`: {err}",
-                        env_file_path.simplified_display()
-                    );
-                }
-                Err(dotenvy::Error::LineParse(content, position)) => {
-                    warn_user!(
-                        "Failed to parse environment file `{}` at position {position}: {content}",
-                        env_file_path.simplified_display(),
-                    );
-                }
-                Err(err) => {
-                    warn_user!(
-                        "Failed to parse environment file `{}`: {err}",
-                        env_file_path.simplified_display(),
-                    );
-                }
-                Ok(()) => {
-                    debug!(
-                        "Read environment file at: `{}`",
-                        env_file_path.simplified_display()
-                    );
-                }
-            }
-        }
-    }
+    // Read from the `.env` files, if necessary.
+    let env_vars = if no_env_file {
+        Vec::new()
+    } else {
+        load_env_files(&env_file)?
+    };
 
     let Some(command) = command else {
         // When a command isn't provided, we'll show a brief help including available tools
@@ -247,6 +219,17 @@ pub(crate) async fn run(
         }
     }
 
+    // If the executable is provided by a package of a different name (e.g., `http`, as provided by
+    // `httpie`), install that package instead, as if `--from` had been provided.
+    let explicit_from = from.is_some();
+    let from = match from {
+        Some(from) => Some(from),
+        None => resolve_tool_alias(target, tool_aliases).map(|(executable, from)| {
+            target = executable;
+            from
+        }),
+    };
+
     let request = ToolRequest::parse(target, from.as_deref())?;
 
     // If the user passed, e.g., `ruff@latest`, refresh the cache.
@@ -280,12 +263,11 @@ pub(crate) async fn run(
     ))
     .await;
 
-    let explicit_from = from.is_some();
     let (from, environment) = match result {
         Ok(resolution) => resolution,
         Err(ProjectError::Operation(err)) => {
             // If the user ran `uvx run ...`, the `run` is likely a mistake. Show a dedicated hint.
-            if from.is_none() && invocation_source == ToolRunCommand::Uvx && target == "run" {
+            if !explicit_from && invocation_source == ToolRunCommand::Uvx && target == "run" {
                 let rest = args.iter().map(|s| s.to_string_lossy()).join(" ");
                 return diagnostics::OperationDiagnostic::native_tls(network_settings.native_tls)
                     .with_hint(format!(
@@ -356,6 +338,7 @@ pub(crate) async fn run(
     };
 
     process.args(args);
+    process.envs(env_vars);
 
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
@@ -399,6 +382,41 @@ pub(crate) async fn run(
     run_to_completion(handle).await
 }
 
+/// Built-in aliases for common executables that are provided by a package of a different name.
+///
+/// User-defined aliases (via `tool-aliases`) take precedence.
+const BUILTIN_TOOL_ALIASES: &[(&str, &str)] = &[("http", "httpie"), ("https", "httpie")];
+
+/// Resolve the package that provides the given target executable, if the executable is aliased.
+///
+/// Returns the executable name, stripped of any version request, along with the package request
+/// to use in lieu of `--from` (e.g., `http@3.2.4` resolves to `http` and `httpie@3.2.4`).
+fn resolve_tool_alias<'a>(
+    target: &'a str,
+    tool_aliases: &BTreeMap<ExecutableName, PackageName>,
+) -> Option<(&'a str, String)> {
+    let (executable, version) = match target.split_once('@') {
+        Some((executable, version)) if !version.is_empty() => (executable, Some(version)),
+        _ => (target, None),
+    };
+
+    let package = tool_aliases
+        .get(executable)
+        .map(ToString::to_string)
+        .or_else(|| {
+            BUILTIN_TOOL_ALIASES
+                .iter()
+                .find(|(alias, _)| *alias == executable)
+                .map(|(_, package)| (*package).to_string())
+        })?;
+
+    debug!("Resolved executable `{executable}` to package `{package}` via tool alias");
+    match version {
+        Some(version) => Some((executable, format!("{package}@{version}"))),
+        None => Some((executable, package)),
+    }
+}
+
 /// Return the entry points for the specified package.
 fn get_entrypoints(
     from: &PackageName,
@@ -689,7 +707,8 @@ async fn get_or_create_environment(
     preview: Preview,
 ) -> Result<(ToolRequirement, PythonEnvironment), ProjectError> {
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -836,6 +855,26 @@ async fn get_or_create_environment(
 
                     (executable, requirement)
                 }
+                // Ex) `ruff@>=0.6.0,<0.7.0`
+                Target::Specifiers(executable, name, extras, specifiers) => {
+                    let executable = request_executable
+                        .map(ToString::to_string)
+                        .unwrap_or_else(|| (*executable).to_string());
+                    let requirement = Requirement {
+                        name: name.clone(),
+                        extras: extras.clone(),
+                        groups: Box::new([]),
+                        marker: MarkerTree::default(),
+                        source: RequirementSource::Registry {
+                            specifier: specifiers.clone(),
+                            index: None,
+                            conflict: None,
+                        },
+                        origin: None,
+                    };
+
+                    (executable, requirement)
+                }
                 // Ex) `ruff@latest`
                 Target::Latest(executable, name, extras) => {
                     let executable = request_executable
@@ -964,6 +1003,29 @@ async fn get_or_create_environment(
         }
     }
 
+    // Read the `--build-constraints` requirements.
+    let build_constraints = Constraints::from_requirements(
+        operations::read_constraints(build_constraints, &client_builder)
+            .await?
+            .into_iter()
+            .map(|constraint| constraint.requirement),
+    );
+
+    // If the user requested a specific version (e.g., `ruff@0.6.0` or `ruff@>=0.6,<0.7`), key the
+    // cached environment on the request itself, such that subsequent invocations can skip
+    // resolution entirely.
+    let environment_key = request.has_version().then(|| {
+        cache_digest(&vec![
+            cache_digest(&requirements),
+            cache_digest(&constraints),
+            cache_digest(&overrides),
+            cache_digest(&build_constraints.requirements().collect::<Vec<_>>()),
+            // The resolver settings (indexes, pre-release mode, resolution strategy, etc.) don't
+            // implement `CacheKey`, so key on their debug representation instead.
+            cache_digest(&format!("{:?}", settings.resolver)),
+        ])
+    });
+
     // Create a `RequirementsSpecification` from the resolved requirements, to avoid re-resolving.
     let spec = EnvironmentSpecification::from(RequirementsSpecification {
         requirements: requirements
@@ -981,19 +1043,12 @@ async fn get_or_create_environment(
         ..spec
     });
 
-    // Read the `--build-constraints` requirements.
-    let build_constraints = Constraints::from_requirements(
-        operations::read_constraints(build_constraints, &client_builder)
-            .await?
-            .into_iter()
-            .map(|constraint| constraint.requirement),
-    );
-
     // TODO(zanieb): When implementing project-level tools, discover the project and check if it has the tool.
     // TODO(zanieb): Determine if we should layer on top of the project environment if it is present.
 
     let result = CachedEnvironment::from_spec(
         spec.clone(),
+        environment_key.as_deref(),
         build_constraints.clone(),
         &interpreter,
         settings,
@@ -1053,6 +1108,7 @@ async fn get_or_create_environment(
 
                 CachedEnvironment::from_spec(
                     spec,
+                    environment_key.as_deref(),
                     build_constraints,
                     &interpreter,
                     settings,
@@ -9,12 +9,17 @@ use tracing::{debug, trace};
 use uv_cache::Cache;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{Concurrency, Constraints, DryRun, Preview};
-use uv_distribution_types::{ExtraBuildRequires, Requirement};
+use uv_distribution_types::{
+    ExtraBuildRequires, InstalledDist, Name, Requirement, RequirementSource, Resolution,
+};
 use uv_fs::CWD;
+use uv_installer::SitePackages;
 use uv_normalize::PackageName;
+use uv_pep440::{Operator, VersionSpecifiers};
+use uv_pypi_types::Metadata23;
 use uv_python::{
-    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
-    PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_requirements::RequirementsSpecification;
 use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
@@ -38,6 +43,8 @@ use crate::settings::{NetworkSettings, ResolverInstallerSettings};
 /// Upgrade a tool.
 pub(crate) async fn upgrade(
     names: Vec<String>,
+    force: bool,
+    dry_run: DryRun,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     args: ResolverInstallerOptions,
@@ -54,6 +61,9 @@ pub(crate) async fn upgrade(
     let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = installed_tools.lock().await?;
 
+    // If no tools were named, upgrade all installed tools.
+    let all = names.is_empty();
+
     // Collect the tools to upgrade, along with any constraints.
     let names: BTreeMap<PackageName, Vec<Requirement>> = {
         if names.is_empty() {
@@ -82,7 +92,8 @@ pub(crate) async fn upgrade(
 
     let reporter = PythonDownloadReporter::single(printer);
     let client_builder = BaseClientBuilder::new()
-        .retries_from_env()?
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
@@ -117,6 +128,12 @@ pub(crate) async fn upgrade(
     // Determine whether we applied any upgrades.
     let mut did_upgrade_environment = vec![];
 
+    // Determine whether we skipped any pinned tools.
+    let mut did_skip_pinned = vec![];
+
+    // Determine whether any tools were already up-to-date.
+    let mut did_noop = vec![];
+
     let mut errors = Vec::new();
     for (name, constraints) in &names {
         debug!("Upgrading tool: `{name}`");
@@ -124,6 +141,9 @@ pub(crate) async fn upgrade(
             name,
             constraints,
             interpreter.as_ref(),
+            all,
+            force,
+            dry_run,
             printer,
             &installed_tools,
             &args,
@@ -140,11 +160,26 @@ pub(crate) async fn upgrade(
             Ok(UpgradeOutcome::UpgradeEnvironment) => {
                 did_upgrade_environment.push(name);
             }
-            Ok(UpgradeOutcome::UpgradeDependencies | UpgradeOutcome::UpgradeTool) => {
+            Ok(
+                UpgradeOutcome::UpgradeDependencies
+                | UpgradeOutcome::UpgradeTool
+                | UpgradeOutcome::WouldUpgrade,
+            ) => {
                 did_upgrade_tool.push(name);
             }
+            Ok(UpgradeOutcome::Pinned(specifier)) => {
+                writeln!(
+                    printer.stderr(),
+                    "Skipped {} (pinned to `{}`; use `{}` to upgrade)",
+                    name.cyan(),
+                    specifier.cyan(),
+                    "--force".green()
+                )?;
+                did_skip_pinned.push(name);
+            }
             Ok(UpgradeOutcome::NoOp) => {
                 debug!("Upgrading `{name}` was a no-op");
+                did_noop.push(name);
             }
             Err(err) => {
                 errors.push((name, err));
@@ -152,25 +187,19 @@ pub(crate) async fn upgrade(
         }
     }
 
-    if !errors.is_empty() {
-        for (name, err) in errors
-            .into_iter()
-            .sorted_unstable_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b))
-        {
-            trace!("Error trace: {err:?}");
-            write_error_chain(
-                err.context(format!("Failed to upgrade {}", name.green()))
-                    .as_ref(),
-                printer.stderr(),
-                "error",
-                AnsiColors::Red,
-            )?;
-        }
-        return Ok(ExitStatus::Failure);
-    }
-
-    if did_upgrade_tool.is_empty() && did_upgrade_environment.is_empty() {
-        writeln!(printer.stderr(), "Nothing to upgrade")?;
+    let failed = errors.len();
+    for (name, err) in errors
+        .into_iter()
+        .sorted_unstable_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b))
+    {
+        trace!("Error trace: {err:?}");
+        write_error_chain(
+            err.context(format!("Failed to upgrade {}", name.green()))
+                .as_ref(),
+            printer.stderr(),
+            "error",
+            AnsiColors::Red,
+        )?;
     }
 
     if let Some(python_request) = python_request {
@@ -189,10 +218,45 @@ pub(crate) async fn upgrade(
         }
     }
 
+    // When upgrading multiple tools, summarize the outcome.
+    let upgraded = did_upgrade_tool.len() + did_upgrade_environment.len();
+    if names.len() > 1 && (upgraded > 0 || failed > 0) {
+        let s = if upgraded == 1 { "" } else { "s" };
+        let verb = if dry_run.enabled() {
+            "Would upgrade"
+        } else {
+            "Upgraded"
+        };
+        let mut summary = vec![format!("{verb} {upgraded} tool{s}")];
+        if !did_noop.is_empty() {
+            summary.push(format!("{} already up-to-date", did_noop.len()));
+        }
+        if !did_skip_pinned.is_empty() {
+            summary.push(format!("{} pinned", did_skip_pinned.len()));
+        }
+        if failed > 0 {
+            summary.push(format!("{failed} failed"));
+        }
+        writeln!(printer.stderr(), "{}", summary.join(", "))?;
+    }
+
+    if failed > 0 {
+        return Ok(ExitStatus::Failure);
+    }
+
+    if upgraded == 0 && did_skip_pinned.is_empty() {
+        writeln!(printer.stderr(), "Nothing to upgrade")?;
+    }
+
+    // With `--check`, fail if any upgrades are available.
+    if dry_run == DryRun::Check && upgraded > 0 {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum UpgradeOutcome {
     /// The tool itself was upgraded.
     UpgradeTool,
@@ -202,13 +266,21 @@ enum UpgradeOutcome {
     UpgradeEnvironment,
     /// The tool was already up-to-date.
     NoOp,
+    /// The tool was skipped, as it's pinned to an exact version.
+    Pinned(VersionSpecifiers),
+    /// The tool would be upgraded, but the upgrade was not applied (i.e., in dry-run mode).
+    WouldUpgrade,
 }
 
 /// Upgrade a specific tool.
+#[allow(clippy::fn_params_excessive_bools)]
 async fn upgrade_tool(
     name: &PackageName,
     constraints: &[Requirement],
     interpreter: Option<&Interpreter>,
+    all: bool,
+    force: bool,
+    dry_run: DryRun,
     printer: Printer,
     installed_tools: &InstalledTools,
     args: &ResolverInstallerOptions,
@@ -260,6 +332,26 @@ async fn upgrade_tool(
         }
     };
 
+    // When upgrading all tools, leave any tool that's pinned to an exact version unchanged, unless
+    // we're recreating its environment. With `--force`, lift the pin instead.
+    let mut requirements = existing_tool_receipt.requirements().to_vec();
+    let changes_interpreter = interpreter.is_some_and(|interpreter| !environment.uses(interpreter));
+    if let Some(requirement) = requirements
+        .iter_mut()
+        .find(|requirement| requirement.name == *name)
+    {
+        if let RequirementSource::Registry { specifier, .. } = &mut requirement.source {
+            if is_pinned(specifier) {
+                if force {
+                    debug!("Removing pin `{specifier}` from `{name}`");
+                    *specifier = VersionSpecifiers::empty();
+                } else if all && !changes_interpreter {
+                    return Ok(UpgradeOutcome::Pinned(specifier.clone()));
+                }
+            }
+        }
+    }
+
     // Resolve the appropriate settings, preferring: CLI > receipt > user.
     let options = args.clone().combine(
         ResolverInstallerOptions::from(existing_tool_receipt.options().clone())
@@ -272,7 +364,7 @@ async fn upgrade_tool(
 
     // Resolve the requirements.
     let spec = RequirementsSpecification::from_overrides(
-        existing_tool_receipt.requirements().to_vec(),
+        requirements.clone(),
         existing_tool_receipt
             .constraints()
             .iter()
@@ -286,6 +378,72 @@ async fn upgrade_tool(
     let state = PlatformState::default();
     let workspace_cache = WorkspaceCache::default();
 
+    // In dry-run mode, resolve the tool's requirements and report the changes, without modifying
+    // the environment.
+    if dry_run.enabled() {
+        let target = interpreter
+            .filter(|interpreter| !environment.uses(interpreter))
+            .unwrap_or_else(|| environment.interpreter());
+
+        let resolution = resolve_environment(
+            spec.into(),
+            target,
+            build_constraints,
+            &settings.resolver,
+            network_settings,
+            &state,
+            Box::new(SummaryResolveLogger),
+            concurrency,
+            cache,
+            printer,
+            preview,
+        )
+        .await?;
+        let resolution = Resolution::from(resolution);
+
+        let site_packages = SitePackages::from_environment(&environment)?;
+        let installed = site_packages.get_packages(name).into_iter().next();
+
+        let from = installed.map(InstalledDist::version);
+        let to = resolution
+            .distributions()
+            .find(|dist| dist.name() == name)
+            .and_then(|dist| dist.version());
+        let python = interpreter
+            .filter(|interpreter| !environment.uses(interpreter))
+            .map(|interpreter| {
+                (
+                    environment.interpreter().python_version(),
+                    interpreter.python_version(),
+                )
+            });
+
+        let mut message = match (from, to) {
+            (Some(from), Some(to)) if from != to => {
+                format!(
+                    "Would upgrade {} from {} to {}",
+                    name.cyan(),
+                    format!("v{from}").cyan(),
+                    format!("v{to}").cyan()
+                )
+            }
+            _ if python.is_some() => {
+                format!("Would recreate the environment for {}", name.cyan())
+            }
+            _ => return Ok(UpgradeOutcome::NoOp),
+        };
+        if let Some((from, to)) = python {
+            message.push_str(&format!(" (Python {from} -> {to})"));
+        }
+        writeln!(printer.stderr(), "{message}")?;
+
+        if let Some(url) = installed.and_then(changelog_url) {
+            writeln!(printer.stderr(), "  Changelog: {}", url.cyan())?;
+        }
+
+        return Ok(UpgradeOutcome::WouldUpgrade);
+    }
+
     // Check if we need to create a new environment — if so, resolve it first, then
     // install the requested tool
     let (environment, outcome) = if let Some(interpreter) =
@@ -389,13 +547,47 @@ async fn upgrade_tool(
             &ToolOptions::from(options),
             true,
             existing_tool_receipt.python().to_owned(),
-            existing_tool_receipt.requirements().to_vec(),
+            requirements,
             existing_tool_receipt.constraints().to_vec(),
             existing_tool_receipt.overrides().to_vec(),
             existing_tool_receipt.build_constraints().to_vec(),
             printer,
         )?;
+
+        // If the tool itself was upgraded, link to its changelog, if any.
+        if outcome == UpgradeOutcome::UpgradeTool {
+            if let Some(url) = tool_changelog_url(installed_tools, name, cache) {
+                writeln!(printer.stderr(), "  Changelog: {}", url.cyan())?;
+            }
+        }
     }
 
     Ok(outcome)
 }
+
+/// Read the changelog URL from the core metadata of the installed tool, if declared.
+fn tool_changelog_url(
+    installed_tools: &InstalledTools,
+    name: &PackageName,
+    cache: &Cache,
+) -> Option<String> {
+    let environment: PythonEnvironment = installed_tools.get_environment(name, cache).ok()??;
+    let site_packages = SitePackages::from_environment(&environment).ok()?;
+    let installed = site_packages.get_packages(name).into_iter().next()?;
+    changelog_url(installed)
+}
+
+/// Read the changelog URL from the core metadata of an installed distribution, if declared.
+fn changelog_url(dist: &InstalledDist) -> Option<String> {
+    let contents = fs_err::read(dist.install_path().join("METADATA")).ok()?;
+    let metadata = Metadata23::parse(&contents).ok()?;
+    metadata.changelog_url().map(ToString::to_string)
+}
+
+/// Returns `true` if the specifiers pin a single, exact version (e.g., `==1.0.0`).
+fn is_pinned(specifiers: &VersionSpecifiers) -> bool {
+    matches!(
+        specifiers.iter().exactly_one(),
+        Ok(specifier) if matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+    )
+}
@@ -124,10 +124,9 @@ pub(crate) async fn venv(
             .unwrap_or(PathBuf::from(".venv")),
     );
 
-    // TODO(zanieb): We don't use [`BaseClientBuilder::retries_from_env`] here because it's a pain
-    // to map into a miette diagnostic. We should just remove miette diagnostics here, we're not
-    // using them elsewhere.
     let client_builder = BaseClientBuilder::default()
+        .retries(network_settings.retries)
+        .retry_backoff(network_settings.retry_backoff)
         .connectivity(network_settings.connectivity)
         .native_tls(network_settings.native_tls)
         .allow_insecure_host(network_settings.allow_insecure_host.clone());
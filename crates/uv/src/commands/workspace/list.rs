@@ -0,0 +1,93 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_configuration::{Preview, PreviewFeatures};
+use uv_fs::Simplified;
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, MemberStatus, Workspace, WorkspaceCache};
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the members of the workspace, along with the pattern that matched each directory.
+pub(crate) async fn list(
+    project_dir: &Path,
+    preview: Preview,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    if !preview.is_enabled(PreviewFeatures::WORKSPACE_LIST) {
+        warn_user!(
+            "`uv workspace list` is experimental and may change without warning. Pass `--preview-features {}` to disable this warning",
+            PreviewFeatures::WORKSPACE_LIST
+        );
+    }
+
+    let workspace = Workspace::discover(
+        project_dir,
+        &DiscoveryOptions::default(),
+        &WorkspaceCache::default(),
+    )
+    .await?;
+    let root = workspace.install_path();
+
+    // The workspace root is always a member, if it's a project.
+    if let Some((name, _)) = workspace
+        .packages()
+        .iter()
+        .find(|(_, member)| member.root() == root)
+    {
+        writeln!(
+            printer.stdout(),
+            "{} ({}) {}",
+            name.cyan(),
+            root.user_display_from(root),
+            "workspace root".dimmed()
+        )?;
+    }
+
+    for candidate in workspace.member_candidates()? {
+        let path = candidate.root.user_display_from(root);
+        match candidate.status {
+            MemberStatus::Included(pattern) => {
+                if let Some((name, _)) = workspace
+                    .packages()
+                    .iter()
+                    .find(|(_, member)| member.root() == &candidate.root)
+                {
+                    writeln!(
+                        printer.stdout(),
+                        "{} ({path}) {}",
+                        name.cyan(),
+                        format!("included by `{pattern}`").dimmed()
+                    )?;
+                } else {
+                    // Hidden directories and files are matched, but skipped during discovery.
+                    writeln!(
+                        printer.stdout(),
+                        "{path} {}",
+                        format!("ignored: matched `{pattern}`, but is not a project").dimmed()
+                    )?;
+                }
+            }
+            MemberStatus::Negated(pattern) => {
+                writeln!(
+                    printer.stdout(),
+                    "{path} {}",
+                    format!("excluded by `members` entry `{pattern}`").dimmed()
+                )?;
+            }
+            MemberStatus::Excluded(pattern) => {
+                writeln!(
+                    printer.stdout(),
+                    "{path} {}",
+                    format!("excluded by `exclude` entry `{pattern}`").dimmed()
+                )?;
+            }
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -25,10 +25,12 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands, PipCommand, PipNamespace,
-    ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand,
-    ToolNamespace, TopLevelArgs, compat::CompatArgs,
+    ProgressFormat, ProjectCommand, PythonCommand, PythonNamespace, SelfCommand, SelfNamespace,
+    ToolCommand, ToolNamespace, TopLevelArgs, WorkspaceCommand, WorkspaceNamespace,
+    compat::CompatArgs,
 };
-use uv_configuration::min_stack_size;
+use uv_client::ResolutionBundle;
+use uv_configuration::{DryRun, PreviewFeatures, min_stack_size};
 use uv_fs::{CWD, Simplified};
 #[cfg(feature = "self-update")]
 use uv_pep440::release_specifiers_to_ranges;
@@ -125,9 +127,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // Load configuration from the filesystem, prioritizing (in order):
     // 1. The configuration file specified on the command-line.
     // 2. The nearest configuration file (`uv.toml` or `pyproject.toml`) above the workspace root.
-    //    If found, this file is combined with the user configuration file.
+    //    If found, this file is combined with the user configuration file. User-level constraints
+    //    and overrides are layered beneath (rather than replaced by) the project's declarations.
     // 3. The nearest configuration file (`uv.toml` or `pyproject.toml`) in the directory tree,
     //    starting from the current directory.
+    //
+    // Unless `--no-config-boundary` is provided, the search in (2) and (3) stops at the workspace
+    // root or at the root of the enclosing Git repository.
     let workspace_cache = WorkspaceCache::default();
     let filesystem = if let Some(config_file) = cli.top_level.config_file.as_ref() {
         if config_file
@@ -143,18 +149,27 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         None
     } else if matches!(&*cli.command, Commands::Tool(_) | Commands::Self_(_)) {
         // For commands that operate at the user-level, ignore local configuration.
-        FilesystemOptions::user()?.combine(FilesystemOptions::system()?)
+        FilesystemOptions::user()?
+            .map(|user| user.into_user_layer(cli.top_level.no_user_constraints))
+            .combine(FilesystemOptions::system()?)
     } else if let Ok(workspace) =
         Workspace::discover(&project_dir, &DiscoveryOptions::default(), &workspace_cache).await
     {
-        let project = FilesystemOptions::find(workspace.install_path())?;
+        let project = FilesystemOptions::find(
+            workspace.install_path(),
+            Some(workspace.install_path()),
+            !cli.top_level.no_config_boundary,
+        )?;
         let system = FilesystemOptions::system()?;
-        let user = FilesystemOptions::user()?;
+        let user = FilesystemOptions::user()?
+            .map(|user| user.into_user_layer(cli.top_level.no_user_constraints));
         project.combine(user).combine(system)
     } else {
-        let project = FilesystemOptions::find(&project_dir)?;
+        let project =
+            FilesystemOptions::find(&project_dir, None, !cli.top_level.no_config_boundary)?;
         let system = FilesystemOptions::system()?;
-        let user = FilesystemOptions::user()?;
+        let user = FilesystemOptions::user()?
+            .map(|user| user.into_user_layer(cli.top_level.no_user_constraints));
         project.combine(user).combine(system)
     };
 
@@ -372,6 +387,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         Printer::Quiet
     } else if globals.quiet > 1 {
         Printer::Silent
+    } else if globals.progress == ProgressFormat::Json {
+        Printer::JsonProgress
     } else if globals.verbose > 0 {
         Printer::Verbose
     } else if globals.no_progress {
@@ -389,6 +406,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
     anstream::ColorChoice::write_global(globals.color.into());
 
+    // Configure the resolution log, which records the resolver's decisions.
+    if let Some(path) = globals.resolution_log.as_deref() {
+        uv_resolver::ResolutionLog::init(path).with_context(|| {
+            format!(
+                "Failed to create resolution log at: `{}`",
+                path.user_display()
+            )
+        })?;
+    }
+
     miette::set_hook(Box::new(|_| {
         Box::new(
             miette::MietteHandlerOpts::new()
@@ -473,7 +500,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 .collect::<Result<Vec<_>, _>>()?;
             let groups = GroupsSpecification {
                 root: project_dir.to_path_buf(),
-                groups: args.settings.groups,
+                only: !args.only_group.is_empty(),
+                groups: args
+                    .settings
+                    .groups
+                    .into_iter()
+                    .chain(args.only_group)
+                    .collect(),
             };
 
             commands::pip_compile(
@@ -492,9 +525,11 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.resolution,
                 args.settings.prerelease,
                 args.settings.fork_strategy,
+                args.settings.prefer_local_versions,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
                 args.settings.generate_hashes,
+                args.settings.hash_algorithm,
                 args.settings.no_emit_package,
                 args.settings.no_strip_extras,
                 args.settings.no_strip_markers,
@@ -506,6 +541,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.emit_build_options,
                 args.settings.emit_marker_expression,
                 args.settings.emit_index_annotation,
+                args.emit_build_requirements,
+                args.emit_build_requirements_output.as_deref(),
+                args.split_by_platform.as_deref(),
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
@@ -571,6 +609,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let groups = GroupsSpecification {
                 root: project_dir.to_path_buf(),
                 groups: args.settings.groups,
+                only: false,
             };
 
             commands::pip_sync(
@@ -583,6 +622,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
+                args.settings.allow_unhashable_sources,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
@@ -612,6 +652,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.concurrency,
                 cache,
                 args.dry_run,
+                args.output_format,
                 printer,
                 globals.preview,
             )
@@ -658,7 +699,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 .collect::<Result<Vec<_>, _>>()?;
             let groups = GroupsSpecification {
                 root: project_dir.to_path_buf(),
-                groups: args.settings.groups,
+                only: !args.only_group.is_empty(),
+                groups: args
+                    .settings
+                    .groups
+                    .into_iter()
+                    .chain(args.only_group)
+                    .collect(),
             };
 
             // Special-case: any source trees specified on the command-line are automatically
@@ -722,8 +769,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &groups,
                 args.settings.resolution,
                 args.settings.prerelease,
+                args.settings.prefer_local_versions,
                 args.settings.dependency_mode,
                 args.settings.upgrade,
+                args.upgrade_strategy,
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
@@ -734,6 +783,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.link_mode,
                 args.settings.compile_bytecode,
                 args.settings.hash_checking,
+                args.settings.allow_unhashable_sources,
                 globals.installer_metadata,
                 &args.settings.config_setting,
                 &args.settings.config_settings_package,
@@ -929,7 +979,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         })
         | Commands::Clean(args) => {
             show_settings!(args);
-            commands::cache_clean(&args.package, &cache, printer)
+            commands::cache_clean(
+                &args.package,
+                args.bucket,
+                args.index.as_ref(),
+                &cache,
+                printer,
+            )
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Prune(args),
@@ -938,10 +994,27 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::cache_prune(args.ci, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
-            command: CacheCommand::Dir,
+            command: CacheCommand::Dir(args),
         }) => {
-            commands::cache_dir(&cache);
-            Ok(ExitStatus::Success)
+            show_settings!(args);
+            if let Some(package) = &args.package {
+                commands::cache_dir_package(package, args.version.as_ref(), args.bucket, &cache)
+            } else {
+                commands::cache_dir(&cache);
+                Ok(ExitStatus::Success)
+            }
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Size(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_size(&cache, args.human, args.shared, printer)
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Info(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_info(&args.package, args.output_format, &cache, printer)
         }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
@@ -966,12 +1039,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.src,
                 args.package,
                 args.all_packages,
+                args.since,
                 args.out_dir,
+                args.out_dir_template,
                 args.sdist,
                 args.wheel,
                 args.list,
                 args.build_logs,
                 args.force_pep517,
+                args.source_date_epoch,
                 build_constraints,
                 args.hash_checking,
                 args.python,
@@ -1076,12 +1152,16 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command:
                 SelfCommand::Update(SelfUpdateArgs {
                     target_version,
+                    channel,
+                    allow_downgrade,
                     token,
                     dry_run,
                 }),
         }) => {
             commands::self_update(
                 target_version,
+                channel.unwrap_or_default(),
+                allow_downgrade,
                 token,
                 dry_run,
                 printer,
@@ -1193,6 +1273,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             Box::pin(commands::tool_run(
                 args.command,
                 args.from,
+                &args.tool_aliases,
                 &requirements,
                 &constraints,
                 &overrides,
@@ -1334,6 +1415,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             Box::pin(commands::tool_upgrade(
                 args.names,
+                args.force,
+                args.dry_run,
                 args.python,
                 args.install_mirrors,
                 args.args,
@@ -1410,11 +1493,15 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             // TODO(john): If we later want to support `--upgrade`, we need to replace this.
             let upgrade = false;
 
+            // Initialize the cache.
+            let cache = cache.init()?;
+
             commands::python_install(
                 &project_dir,
                 args.install_dir,
                 args.targets,
                 args.reinstall,
+                args.reinstall_broken,
                 upgrade,
                 args.bin,
                 args.registry,
@@ -1426,6 +1513,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.default,
                 globals.python_downloads,
                 cli.top_level.no_config,
+                DryRun::Disabled,
+                &cache,
                 globals.preview,
                 printer,
             )
@@ -1438,13 +1527,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             let args = settings::PythonUpgradeSettings::resolve(args, filesystem);
             show_settings!(args);
             let reinstall = false;
+            let reinstall_broken = false;
             let upgrade = true;
 
+            // Initialize the cache.
+            let cache = cache.init()?;
+
             commands::python_install(
                 &project_dir,
                 args.install_dir,
                 args.targets,
                 reinstall,
+                reinstall_broken,
                 upgrade,
                 args.bin,
                 args.registry,
@@ -1456,6 +1550,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.default,
                 globals.python_downloads,
                 cli.top_level.no_config,
+                args.dry_run,
+                &cache,
                 globals.preview,
                 printer,
             )
@@ -1477,6 +1573,18 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Check(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonCheckSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::python_check(args.install_dir, args.targets, &cache, printer)
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Find(args),
         }) => {
@@ -1507,7 +1615,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     args.no_project,
                     cli.top_level.no_config,
                     args.system,
-                    globals.python_preference,
+                    args.python_preference.unwrap_or(globals.python_preference),
                     &cache,
                     printer,
                     globals.preview,
@@ -1557,6 +1665,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::python_update_shell(printer).await?;
             Ok(ExitStatus::Success)
         }
+        Commands::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::List,
+        }) => commands::workspace_list(&project_dir, globals.preview, printer).await,
         Commands::Publish(args) => {
             show_settings!(args);
 
@@ -1628,6 +1739,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 username,
                 password,
                 check_url,
+                &index_locations,
                 &cache,
                 printer,
             )
@@ -1722,6 +1834,9 @@ async fn run_project(
                 args.python,
                 args.install_mirrors,
                 args.no_workspace,
+                args.with,
+                args.template,
+                args.force,
                 &globals.network_settings,
                 globals.python_preference,
                 globals.python_downloads,
@@ -1765,7 +1880,9 @@ async fn run_project(
                 script,
                 command,
                 requirements,
+                args.with_group,
                 args.show_resolution || globals.verbose > 0,
+                args.show_environment,
                 args.locked,
                 args.frozen,
                 args.active,
@@ -1815,12 +1932,45 @@ async fn run_project(
                 Pep723Item::Remote(..) => unreachable!("`uv lock` does not support remote files"),
             });
 
+            if args.all_pythons {
+                return Box::pin(commands::sync_all_pythons(
+                    project_dir,
+                    args.locked,
+                    args.frozen,
+                    args.dry_run,
+                    args.relocatable,
+                    args.all_packages,
+                    args.package,
+                    args.extras,
+                    args.groups,
+                    args.editable,
+                    args.install_options,
+                    args.modifications,
+                    args.python_platform,
+                    args.install_mirrors,
+                    globals.python_preference,
+                    globals.python_downloads,
+                    args.settings,
+                    globals.network_settings,
+                    globals.installer_metadata,
+                    globals.concurrency,
+                    no_config,
+                    &cache,
+                    printer,
+                    globals.preview,
+                    args.output_format,
+                    args.foreign,
+                ))
+                .await;
+            }
+
             Box::pin(commands::sync(
                 project_dir,
                 args.locked,
                 args.frozen,
                 args.dry_run,
                 args.active,
+                args.relocatable,
                 args.all_packages,
                 args.package,
                 args.extras,
@@ -1843,6 +1993,8 @@ async fn run_project(
                 printer,
                 globals.preview,
                 args.output_format,
+                args.foreign,
+                None,
             ))
             .await
         }
@@ -1851,8 +2003,33 @@ async fn run_project(
             let args = settings::LockSettings::resolve(args, filesystem);
             show_settings!(args);
 
-            // Initialize the cache.
-            let cache = cache.init()?.with_refresh(
+            // If requested, record into (or replay from) a resolution bundle.
+            let bundle = if let Some(path) = args.record_bundle.as_deref() {
+                Some(ResolutionBundle::record(path)?)
+            } else if let Some(path) = args.replay_bundle.as_deref() {
+                Some(ResolutionBundle::replay(path)?)
+            } else {
+                None
+            };
+            if bundle.is_some()
+                && !globals
+                    .preview
+                    .is_enabled(PreviewFeatures::RESOLUTION_BUNDLE)
+            {
+                warn_user_once!(
+                    "Resolution bundles are experimental and may change without warning. Pass `--preview-features {}` to disable this warning.",
+                    PreviewFeatures::RESOLUTION_BUNDLE
+                );
+            }
+
+            // Initialize the cache. When using a resolution bundle, use a temporary cache, such
+            // that every response is recorded into (or replayed from) the bundle.
+            let cache = if bundle.is_some() {
+                Cache::temp()?.init()?
+            } else {
+                cache.init()?
+            }
+            .with_refresh(
                 args.refresh
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
@@ -1875,11 +2052,16 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.repair,
                 args.python,
+                args.python_versions,
                 args.install_mirrors,
                 args.settings,
                 globals.network_settings,
                 script,
+                bundle,
+                args.output_format,
+                args.fail_on,
                 globals.python_preference,
                 globals.python_downloads,
                 globals.concurrency,
@@ -1986,6 +2168,7 @@ async fn run_project(
                 args.editable,
                 args.dependency_type,
                 args.raw,
+                if args.raw { args.packages } else { Vec::new() },
                 args.bounds,
                 args.indexes,
                 args.rev,
@@ -2138,6 +2321,25 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Why(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WhySettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::why(
+                project_dir,
+                args.package,
+                args.environment,
+                args.python,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
+        }
         ProjectCommand::Export(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::ExportSettings::resolve(args, filesystem);
@@ -2160,6 +2362,9 @@ async fn run_project(
                 args.package,
                 args.prune,
                 args.hashes,
+                args.no_markers,
+                args.python_platform,
+                args.python_version,
                 args.install_options,
                 args.output_file,
                 args.extras,
@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::env::VarError;
 use std::num::NonZeroUsize;
 use std::path::PathBuf;
@@ -7,38 +8,40 @@ use std::str::FromStr;
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
-    PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs,
-    SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs,
-    ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs, VersionBump, VersionFormat,
+    AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, LockFormat,
+    Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
+    PipSyncArgs, PipTreeArgs, PipUninstallArgs, ProgressFormat, PythonCheckArgs, PythonFindArgs,
+    PythonInstallArgs, PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs,
+    PythonUpgradeArgs, ReleaseSegment, RemoveArgs, RunArgs, SyncArgs, SyncFormat, ToolDirArgs,
+    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs,
+    VersionBump, VersionFormat, WhyArgs,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs,
     ToolUpgradeArgs,
     options::{flag, resolver_installer_options, resolver_options},
 };
-use uv_client::Connectivity;
+use uv_client::{Connectivity, DEFAULT_RETRIES, DEFAULT_RETRY_BACKOFF};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, DependencyGroups, DryRun, EditableMode,
-    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
-    KeyringProviderType, NoBinary, NoBuild, PackageConfigSettings, Preview, ProjectBuildBackend,
-    Reinstall, RequiredVersion, SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing,
-    Upgrade, VersionControlSystem,
+    BuildLimits, BuildOptions, Concurrency, ConfigSettings, DependencyGroups, DryRun, EditableMode,
+    ExecutableName, ExportFormat, ExtrasSpecification, HashAlgorithmPreference, HashCheckingMode,
+    IndexStrategy, InstallOptions, KeyringProviderType, NoBinary, NoBuild, PackageConfigSettings,
+    PipCompileFormat, Preview, ProjectBuildBackend, Reinstall, RequiredVersion, ScriptTemplate,
+    SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade, UpgradeStrategy,
+    VersionControlSystem,
 };
 use uv_distribution_types::{
     DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, IndexUrl, Requirement,
 };
 use uv_install_wheel::LinkMode;
-use uv_normalize::{PackageName, PipGroupName};
+use uv_normalize::{GroupName, PackageName, PipGroupName};
 use uv_pep508::{ExtraName, MarkerTree, RequirementOrigin};
-use uv_pypi_types::SupportedEnvironments;
+use uv_pypi_types::{SupportedEnvironments, VerbatimParsedUrl};
 use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    PrereleaseMode, ResolutionMode, ResolutionOverrides,
 };
 use uv_settings::{
     Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
@@ -51,7 +54,7 @@ use uv_workspace::pyproject::{DependencyType, ExtraBuildDependencies};
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
 use crate::commands::ToolRunCommand;
-use crate::commands::{InitKind, InitProjectKind, pip::operations::Modifications};
+use crate::commands::{ForeignPackages, InitKind, InitProjectKind, pip::operations::Modifications};
 
 /// The default publish URL.
 const PYPI_PUBLISH_URL: &str = "https://upload.pypi.org/legacy/";
@@ -70,7 +73,9 @@ pub(crate) struct GlobalSettings {
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
+    pub(crate) progress: ProgressFormat,
     pub(crate) installer_metadata: bool,
+    pub(crate) resolution_log: Option<PathBuf>,
 }
 
 impl GlobalSettings {
@@ -140,7 +145,9 @@ impl GlobalSettings {
             // Disable the progress bar with `RUST_LOG` to avoid progress fragments interleaving
             // with log messages.
             no_progress: args.no_progress || std::env::var_os(EnvVars::RUST_LOG).is_some(),
+            progress: args.progress.unwrap_or_default(),
             installer_metadata: !args.no_installer_metadata,
+            resolution_log: args.resolution_log.clone(),
         }
     }
 }
@@ -166,6 +173,8 @@ pub(crate) struct NetworkSettings {
     pub(crate) connectivity: Connectivity,
     pub(crate) native_tls: bool,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) retries: u32,
+    pub(crate) retry_backoff: u32,
 }
 
 impl NetworkSettings {
@@ -202,6 +211,8 @@ impl NetworkSettings {
             connectivity,
             native_tls,
             allow_insecure_host,
+            retries: args.retries.unwrap_or(DEFAULT_RETRIES),
+            retry_backoff: args.retry_backoff.unwrap_or(DEFAULT_RETRY_BACKOFF),
         }
     }
 }
@@ -246,6 +257,9 @@ pub(crate) struct InitSettings {
     pub(crate) no_workspace: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) with: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+    pub(crate) template: ScriptTemplate,
+    pub(crate) force: bool,
 }
 
 impl InitSettings {
@@ -272,6 +286,9 @@ impl InitSettings {
             pin_python,
             no_workspace,
             python,
+            with,
+            template,
+            force,
             ..
         } = args;
 
@@ -312,6 +329,9 @@ impl InitSettings {
             no_workspace,
             python: python.and_then(Maybe::into_option),
             install_mirrors,
+            with,
+            template: template.unwrap_or_default(),
+            force,
         }
     }
 }
@@ -328,8 +348,10 @@ pub(crate) struct RunSettings {
     pub(crate) with: Vec<String>,
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
+    pub(crate) with_group: Vec<GroupName>,
     pub(crate) isolated: bool,
     pub(crate) show_resolution: bool,
+    pub(crate) show_environment: bool,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) no_project: bool,
@@ -377,6 +399,7 @@ impl RunSettings {
             with,
             with_editable,
             with_requirements,
+            with_group,
             isolated,
             active,
             no_active,
@@ -391,6 +414,7 @@ impl RunSettings {
             no_project,
             python,
             show_resolution,
+            show_environment,
             env_file,
             no_env_file,
             max_recursion_depth,
@@ -441,8 +465,10 @@ impl RunSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            with_group,
             isolated,
             show_resolution,
+            show_environment,
             all_packages,
             package,
             no_project,
@@ -482,6 +508,7 @@ pub(crate) struct ToolRunSettings {
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) env_file: Vec<PathBuf>,
     pub(crate) no_env_file: bool,
+    pub(crate) tool_aliases: BTreeMap<ExecutableName, PackageName>,
 }
 
 impl ToolRunSettings {
@@ -547,10 +574,16 @@ impl ToolRunSettings {
         );
 
         let install_mirrors = filesystem
+            .clone()
             .map(FilesystemOptions::into_options)
             .map(|options| options.install_mirrors)
             .unwrap_or_default();
 
+        let tool_aliases = filesystem
+            .map(FilesystemOptions::into_options)
+            .and_then(|options| options.tool_run.tool_aliases)
+            .unwrap_or_default();
+
         let settings = ResolverInstallerSettings::from(options.clone());
 
         Self {
@@ -589,6 +622,7 @@ impl ToolRunSettings {
             install_mirrors,
             env_file,
             no_env_file,
+            tool_aliases,
         }
     }
 }
@@ -697,6 +731,8 @@ impl ToolInstallSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct ToolUpgradeSettings {
     pub(crate) names: Vec<String>,
+    pub(crate) force: bool,
+    pub(crate) dry_run: DryRun,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) args: ResolverInstallerOptions,
@@ -713,6 +749,9 @@ impl ToolUpgradeSettings {
             upgrade_package,
             index_args,
             all,
+            force,
+            dry_run,
+            check,
             reinstall,
             no_reinstall,
             reinstall_package,
@@ -722,6 +761,7 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            prefer_local_versions,
             config_setting,
             config_setting_package: config_settings_package,
             no_build_isolation,
@@ -758,6 +798,7 @@ impl ToolUpgradeSettings {
             prerelease,
             pre,
             fork_strategy,
+            prefer_local_versions,
             config_setting,
             config_settings_package,
             no_build_isolation,
@@ -783,6 +824,12 @@ impl ToolUpgradeSettings {
 
         Self {
             names: if all { vec![] } else { name },
+            force,
+            dry_run: if check {
+                DryRun::Check
+            } else {
+                DryRun::from_args(dry_run)
+            },
             python: python.and_then(Maybe::into_option),
             args,
             filesystem: top_level,
@@ -947,6 +994,7 @@ pub(crate) struct PythonInstallSettings {
     pub(crate) install_dir: Option<PathBuf>,
     pub(crate) targets: Vec<String>,
     pub(crate) reinstall: bool,
+    pub(crate) reinstall_broken: bool,
     pub(crate) force: bool,
     pub(crate) bin: Option<bool>,
     pub(crate) registry: Option<bool>,
@@ -982,6 +1030,7 @@ impl PythonInstallSettings {
             install_dir,
             targets,
             reinstall,
+            reinstall_broken,
             bin,
             no_bin,
             registry,
@@ -997,6 +1046,7 @@ impl PythonInstallSettings {
             install_dir,
             targets,
             reinstall,
+            reinstall_broken,
             force,
             bin: flag(bin, no_bin, "bin").or(environment.python_install_bin),
             registry: flag(registry, no_registry, "registry")
@@ -1022,6 +1072,7 @@ pub(crate) struct PythonUpgradeSettings {
     pub(crate) python_downloads_json_url: Option<String>,
     pub(crate) default: bool,
     pub(crate) bin: Option<bool>,
+    pub(crate) dry_run: DryRun,
 }
 
 impl PythonUpgradeSettings {
@@ -1052,6 +1103,7 @@ impl PythonUpgradeSettings {
             mirror: _,
             pypy_mirror: _,
             python_downloads_json_url: _,
+            dry_run,
         } = args;
 
         Self {
@@ -1064,6 +1116,7 @@ impl PythonUpgradeSettings {
             python_downloads_json_url,
             default,
             bin,
+            dry_run: DryRun::from_args(dry_run),
         }
     }
 }
@@ -1097,6 +1150,29 @@ impl PythonUninstallSettings {
     }
 }
 
+/// The resolved settings to use for a `python check` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonCheckSettings {
+    pub(crate) install_dir: Option<PathBuf>,
+    pub(crate) targets: Vec<String>,
+}
+
+impl PythonCheckSettings {
+    /// Resolve the [`PythonCheckSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonCheckArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonCheckArgs {
+            install_dir,
+            targets,
+        } = args;
+
+        Self {
+            install_dir,
+            targets,
+        }
+    }
+}
+
 /// The resolved settings to use for a `python find` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PythonFindSettings {
@@ -1104,6 +1180,7 @@ pub(crate) struct PythonFindSettings {
     pub(crate) show_version: bool,
     pub(crate) no_project: bool,
     pub(crate) system: bool,
+    pub(crate) python_preference: Option<PythonPreference>,
 }
 
 impl PythonFindSettings {
@@ -1116,14 +1193,28 @@ impl PythonFindSettings {
             no_project,
             system,
             no_system,
+            managed_only,
+            system_only,
             script: _,
         } = args;
 
+        let python_preference = if managed_only {
+            Some(PythonPreference::OnlyManaged)
+        } else if system_only {
+            Some(PythonPreference::OnlySystem)
+        } else {
+            None
+        };
+
         Self {
             request,
             show_version,
             no_project,
-            system: flag(system, no_system, "system").unwrap_or_default(),
+            // Virtual environments are neither managed nor system interpreters, so restricting
+            // the source also skips them.
+            system: flag(system, no_system, "system").unwrap_or_default()
+                || python_preference.is_some(),
+            python_preference,
         }
     }
 }
@@ -1176,6 +1267,7 @@ pub(crate) struct SyncSettings {
     pub(crate) dry_run: DryRun,
     pub(crate) script: Option<PathBuf>,
     pub(crate) active: Option<bool>,
+    pub(crate) relocatable: bool,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) groups: DependencyGroups,
     pub(crate) editable: EditableMode,
@@ -1183,12 +1275,14 @@ pub(crate) struct SyncSettings {
     pub(crate) modifications: Modifications,
     pub(crate) all_packages: bool,
     pub(crate) package: Option<PackageName>,
+    pub(crate) all_pythons: bool,
     pub(crate) python: Option<String>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) output_format: SyncFormat,
+    pub(crate) foreign: ForeignPackages,
 }
 
 impl SyncSettings {
@@ -1218,6 +1312,7 @@ impl SyncSettings {
             frozen,
             active,
             no_active,
+            relocatable,
             dry_run,
             installer,
             build,
@@ -1225,10 +1320,13 @@ impl SyncSettings {
             all_packages,
             package,
             script,
+            all_pythons,
             python,
             python_platform,
             check,
             no_check,
+            check_foreign,
+            adopt_foreign,
             output_format,
         } = args;
         let install_mirrors = filesystem
@@ -1255,6 +1353,7 @@ impl SyncSettings {
             dry_run,
             script,
             active: flag(active, no_active, "active"),
+            relocatable,
             extras: ExtrasSpecification::from_args(
                 extra.unwrap_or_default(),
                 no_extra,
@@ -1287,11 +1386,19 @@ impl SyncSettings {
             },
             all_packages,
             package,
+            all_pythons,
             python: python.and_then(Maybe::into_option),
             python_platform,
             refresh: Refresh::from(refresh),
             settings,
             install_mirrors,
+            foreign: if check_foreign {
+                ForeignPackages::Check
+            } else if adopt_foreign {
+                ForeignPackages::Adopt
+            } else {
+                ForeignPackages::Warn
+            },
         }
     }
 }
@@ -1303,8 +1410,14 @@ pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: DryRun,
+    pub(crate) repair: bool,
     pub(crate) script: Option<PathBuf>,
+    pub(crate) record_bundle: Option<PathBuf>,
+    pub(crate) replay_bundle: Option<PathBuf>,
+    pub(crate) output_format: LockFormat,
+    pub(crate) fail_on: Option<ReleaseSegment>,
     pub(crate) python: Option<String>,
+    pub(crate) python_versions: Vec<PythonVersion>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -1318,11 +1431,17 @@ impl LockSettings {
             check,
             check_exists,
             dry_run,
+            repair,
             script,
+            record_bundle,
+            replay_bundle,
+            output_format,
+            fail_on,
             resolver,
             build,
             refresh,
             python,
+            python_versions,
         } = args;
 
         let install_mirrors = filesystem
@@ -1334,8 +1453,14 @@ impl LockSettings {
             locked: check,
             frozen: check_exists,
             dry_run: DryRun::from_args(dry_run),
+            repair,
             script,
+            record_bundle,
+            replay_bundle,
+            output_format,
+            fail_on,
             python: python.and_then(Maybe::into_option),
+            python_versions,
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
             install_mirrors,
@@ -1749,6 +1874,31 @@ impl TreeSettings {
     }
 }
 
+/// The resolved settings to use for a `why` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct WhySettings {
+    pub(crate) package: PackageName,
+    pub(crate) environment: bool,
+    pub(crate) python: Option<String>,
+}
+
+impl WhySettings {
+    /// Resolve the [`WhySettings`] from the CLI and workspace configuration.
+    pub(crate) fn resolve(args: WhyArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let WhyArgs {
+            package,
+            environment,
+            python,
+        } = args;
+
+        Self {
+            package,
+            environment,
+            python: python.and_then(Maybe::into_option),
+        }
+    }
+}
+
 /// The resolved settings to use for an `export` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -1761,6 +1911,9 @@ pub(crate) struct ExportSettings {
     pub(crate) groups: DependencyGroups,
     pub(crate) editable: EditableMode,
     pub(crate) hashes: bool,
+    pub(crate) no_markers: bool,
+    pub(crate) python_platform: Option<TargetTriple>,
+    pub(crate) python_version: Option<PythonVersion>,
     pub(crate) install_options: InstallOptions,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) locked: bool,
@@ -1802,6 +1955,9 @@ impl ExportSettings {
             no_editable,
             hashes,
             no_hashes,
+            no_markers,
+            python_platform,
+            python_version,
             output_file,
             no_emit_project,
             no_emit_workspace,
@@ -1845,6 +2001,9 @@ impl ExportSettings {
             ),
             editable: EditableMode::from_args(no_editable),
             hashes: flag(hashes, no_hashes, "hashes").unwrap_or(true),
+            no_markers,
+            python_platform,
+            python_version,
             install_options: InstallOptions::new(
                 no_emit_project,
                 no_emit_workspace,
@@ -1867,15 +2026,19 @@ impl ExportSettings {
 /// The resolved settings to use for a `pip compile` invocation.
 #[derive(Debug, Clone)]
 pub(crate) struct PipCompileSettings {
-    pub(crate) format: Option<ExportFormat>,
+    pub(crate) format: Option<PipCompileFormat>,
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) only_group: Vec<PipGroupName>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) emit_build_requirements: bool,
+    pub(crate) emit_build_requirements_output: Option<PathBuf>,
+    pub(crate) split_by_platform: Option<Vec<TargetTriple>>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -1895,6 +2058,7 @@ impl PipCompileSettings {
             no_deps,
             deps,
             group,
+            only_group,
             output_file,
             format,
             no_strip_extras,
@@ -1917,10 +2081,12 @@ impl PipCompileSettings {
             build,
             no_binary,
             only_binary,
+            allow_binary_fallback,
             python_version,
             python_platform,
             universal,
             no_universal,
+            split_by_platform,
             no_emit_package,
             emit_index_url,
             no_emit_index_url,
@@ -1932,15 +2098,16 @@ impl PipCompileSettings {
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            emit_build_requirements,
+            no_emit_build_requirements,
+            emit_build_requirements_output,
             torch_backend,
             compat_args: _,
         } = args;
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
             configuration
-                .constraint_dependencies
-                .clone()
-                .unwrap_or_default()
+                .layered_constraint_dependencies()
                 .into_iter()
                 .map(|requirement| {
                     Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
@@ -1952,9 +2119,7 @@ impl PipCompileSettings {
 
         let overrides_from_workspace = if let Some(configuration) = &filesystem {
             configuration
-                .override_dependencies
-                .clone()
-                .unwrap_or_default()
+                .layered_override_dependencies()
                 .into_iter()
                 .map(|requirement| {
                     Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
@@ -1995,6 +2160,7 @@ impl PipCompileSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            only_group,
             overrides: overrides
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -2003,6 +2169,25 @@ impl PipCompileSettings {
             overrides_from_workspace,
             build_constraints_from_workspace,
             environments,
+            emit_build_requirements: emit_build_requirements_output.is_some()
+                || flag(
+                    emit_build_requirements,
+                    no_emit_build_requirements,
+                    "emit-build-requirements",
+                )
+                .unwrap_or_default(),
+            emit_build_requirements_output,
+            split_by_platform: split_by_platform.map(|platforms| {
+                if platforms.is_empty() {
+                    vec![
+                        TargetTriple::Linux,
+                        TargetTriple::Macos,
+                        TargetTriple::Windows,
+                    ]
+                } else {
+                    platforms
+                }
+            }),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2011,6 +2196,7 @@ impl PipCompileSettings {
                     no_build: flag(no_build, build, "build"),
                     no_binary,
                     only_binary,
+                    allow_binary_fallback,
                     extra,
                     all_extras: flag(all_extras, no_all_extras, "all-extras"),
                     no_deps: flag(no_deps, deps, "deps"),
@@ -2060,6 +2246,7 @@ pub(crate) struct PipSyncSettings {
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) dry_run: DryRun,
+    pub(crate) output_format: SyncFormat,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2079,6 +2266,10 @@ impl PipSyncSettings {
             refresh,
             require_hashes,
             no_require_hashes,
+            derive_hashes,
+            no_derive_hashes,
+            allow_unhashable_sources,
+            no_allow_unhashable_sources,
             verify_hashes,
             no_verify_hashes,
             python,
@@ -2094,11 +2285,13 @@ impl PipSyncSettings {
             build,
             no_binary,
             only_binary,
+            allow_binary_fallback,
             python_version,
             python_platform,
             strict,
             no_strict,
             dry_run,
+            output_format,
             torch_backend,
             compat_args: _,
         } = *args;
@@ -2114,6 +2307,7 @@ impl PipSyncSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             dry_run: DryRun::from_args(dry_run),
+            output_format,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2127,10 +2321,17 @@ impl PipSyncSettings {
                     target,
                     prefix,
                     require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    derive_hashes: flag(derive_hashes, no_derive_hashes, "derive-hashes"),
+                    allow_unhashable_sources: flag(
+                        allow_unhashable_sources,
+                        no_allow_unhashable_sources,
+                        "allow-unhashable-sources",
+                    ),
                     verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
                     no_build: flag(no_build, build, "build"),
                     no_binary,
                     only_binary,
+                    allow_binary_fallback,
                     allow_empty_requirements: flag(
                         allow_empty_requirements,
                         no_allow_empty_requirements,
@@ -2160,11 +2361,13 @@ pub(crate) struct PipInstallSettings {
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) only_group: Vec<PipGroupName>,
     pub(crate) dry_run: DryRun,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
+    pub(crate) upgrade_strategy: UpgradeStrategy,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2183,12 +2386,18 @@ impl PipInstallSettings {
             all_extras,
             no_all_extras,
             installer,
+            upgrade_strategy,
             refresh,
             no_deps,
             deps,
             group,
+            only_group,
             require_hashes,
             no_require_hashes,
+            derive_hashes,
+            no_derive_hashes,
+            allow_unhashable_sources,
+            no_allow_unhashable_sources,
             verify_hashes,
             no_verify_hashes,
             python,
@@ -2202,6 +2411,7 @@ impl PipInstallSettings {
             build,
             no_binary,
             only_binary,
+            allow_binary_fallback,
             python_version,
             python_platform,
             inexact,
@@ -2215,9 +2425,7 @@ impl PipInstallSettings {
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
             configuration
-                .constraint_dependencies
-                .clone()
-                .unwrap_or_default()
+                .layered_constraint_dependencies()
                 .into_iter()
                 .map(|requirement| {
                     Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
@@ -2229,9 +2437,7 @@ impl PipInstallSettings {
 
         let overrides_from_workspace = if let Some(configuration) = &filesystem {
             configuration
-                .override_dependencies
-                .clone()
-                .unwrap_or_default()
+                .layered_override_dependencies()
                 .into_iter()
                 .map(|requirement| {
                     Requirement::from(requirement.with_origin(RequirementOrigin::Workspace))
@@ -2271,6 +2477,7 @@ impl PipInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            only_group,
             dry_run: DryRun::from_args(dry_run),
             constraints_from_workspace,
             overrides_from_workspace,
@@ -2280,6 +2487,7 @@ impl PipInstallSettings {
             } else {
                 Modifications::Sufficient
             },
+            upgrade_strategy: upgrade_strategy.unwrap_or_default(),
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2295,6 +2503,7 @@ impl PipInstallSettings {
                     no_build: flag(no_build, build, "build"),
                     no_binary,
                     only_binary,
+                    allow_binary_fallback,
                     strict: flag(strict, no_strict, "strict"),
                     extra,
                     all_extras: flag(all_extras, no_all_extras, "all-extras"),
@@ -2303,6 +2512,12 @@ impl PipInstallSettings {
                     python_version,
                     python_platform,
                     require_hashes: flag(require_hashes, no_require_hashes, "require-hashes"),
+                    derive_hashes: flag(derive_hashes, no_derive_hashes, "derive-hashes"),
+                    allow_unhashable_sources: flag(
+                        allow_unhashable_sources,
+                        no_allow_unhashable_sources,
+                        "allow-unhashable-sources",
+                    ),
                     verify_hashes: flag(verify_hashes, no_verify_hashes, "verify-hashes"),
                     torch_backend,
                     ..PipOptions::from(installer)
@@ -2568,14 +2783,17 @@ impl PipCheckSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct BuildSettings {
     pub(crate) src: Option<PathBuf>,
-    pub(crate) package: Option<PackageName>,
+    pub(crate) package: Vec<PackageName>,
     pub(crate) all_packages: bool,
+    pub(crate) since: Option<String>,
     pub(crate) out_dir: Option<PathBuf>,
+    pub(crate) out_dir_template: Option<String>,
     pub(crate) sdist: bool,
     pub(crate) wheel: bool,
     pub(crate) list: bool,
     pub(crate) build_logs: bool,
     pub(crate) force_pep517: bool,
+    pub(crate) source_date_epoch: Option<u64>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) hash_checking: Option<HashCheckingMode>,
     pub(crate) python: Option<String>,
@@ -2590,12 +2808,15 @@ impl BuildSettings {
         let BuildArgs {
             src,
             out_dir,
+            out_dir_template,
             package,
             all_packages,
+            since,
             sdist,
             wheel,
             list,
             force_pep517,
+            source_date_epoch,
             build_constraints,
             require_hashes,
             no_require_hashes,
@@ -2618,7 +2839,9 @@ impl BuildSettings {
             src,
             package,
             all_packages,
+            since,
             out_dir,
+            out_dir_template,
             sdist,
             wheel,
             list,
@@ -2628,9 +2851,11 @@ impl BuildSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             force_pep517,
+            source_date_epoch,
             hash_checking: HashCheckingMode::from_args(
                 flag(require_hashes, no_require_hashes, "require-hashes"),
                 flag(verify_hashes, no_verify_hashes, "verify-hashes"),
+                None,
             ),
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
@@ -2744,6 +2969,7 @@ pub(crate) struct ResolverSettings {
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) exclude_newer: ExcludeNewer,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) hash_algorithm: Option<HashAlgorithmPreference>,
     pub(crate) index_locations: IndexLocations,
     pub(crate) index_strategy: IndexStrategy,
     pub(crate) keyring_provider: KeyringProviderType,
@@ -2752,15 +2978,26 @@ pub(crate) struct ResolverSettings {
     pub(crate) no_build_isolation_package: Vec<PackageName>,
     pub(crate) extra_build_dependencies: ExtraBuildDependencies,
     pub(crate) extra_build_variables: ExtraBuildVariables,
+    pub(crate) prefer_local_versions: bool,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) resolution: ResolutionMode,
+    pub(crate) resolution_overrides: ResolutionOverrides,
+    pub(crate) allow_prereleases: BTreeSet<PackageName>,
     pub(crate) sources: SourceStrategy,
     pub(crate) upgrade: Upgrade,
+    pub(crate) user_constraints: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+    pub(crate) user_overrides: Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+    pub(crate) configured_no_binary_package: BTreeSet<PackageName>,
+    pub(crate) configured_no_build_package: BTreeSet<PackageName>,
 }
 
 impl ResolverSettings {
     /// Resolve the [`ResolverSettings`] from the CLI and filesystem configuration.
     pub(crate) fn combine(args: ResolverOptions, filesystem: Option<FilesystemOptions>) -> Self {
+        let (user_constraints, user_overrides) = user_requirements(filesystem.as_ref());
+        let (configured_no_binary_package, configured_no_build_package) =
+            configured_build_options(filesystem.as_ref());
+
         let options = args.combine(ResolverOptions::from(
             filesystem
                 .map(FilesystemOptions::into_options)
@@ -2768,10 +3005,69 @@ impl ResolverSettings {
                 .unwrap_or_default(),
         ));
 
-        Self::from(options)
+        Self {
+            user_constraints,
+            user_overrides,
+            configured_no_binary_package,
+            configured_no_build_package,
+            ..Self::from(options)
+        }
     }
 }
 
+/// Extract the user-level constraints and overrides from the [`FilesystemOptions`].
+///
+/// These are layered beneath the project's own `constraint-dependencies` and
+/// `override-dependencies` at lock time.
+fn user_requirements(
+    filesystem: Option<&FilesystemOptions>,
+) -> (
+    Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+    Vec<uv_pep508::Requirement<VerbatimParsedUrl>>,
+) {
+    let Some(filesystem) = filesystem else {
+        return (Vec::new(), Vec::new());
+    };
+    (
+        filesystem
+            .user_constraint_dependencies
+            .clone()
+            .unwrap_or_default(),
+        filesystem
+            .user_override_dependencies
+            .clone()
+            .unwrap_or_default(),
+    )
+}
+
+/// Extract the per-package build options from the [`FilesystemOptions`].
+///
+/// These are recorded in the lockfile, unlike those passed on the command line, which only apply
+/// to a single invocation.
+fn configured_build_options(
+    filesystem: Option<&FilesystemOptions>,
+) -> (BTreeSet<PackageName>, BTreeSet<PackageName>) {
+    let Some(filesystem) = filesystem else {
+        return (BTreeSet::default(), BTreeSet::default());
+    };
+    (
+        filesystem
+            .top_level
+            .no_binary_package
+            .iter()
+            .flatten()
+            .cloned()
+            .collect(),
+        filesystem
+            .top_level
+            .no_build_package
+            .iter()
+            .flatten()
+            .cloned()
+            .collect(),
+    )
+}
+
 impl From<ResolverOptions> for ResolverSettings {
     fn from(value: ResolverOptions) -> Self {
         let index_locations = IndexLocations::new(
@@ -2793,8 +3089,12 @@ impl From<ResolverOptions> for ResolverSettings {
         Self {
             index_locations,
             resolution: value.resolution.unwrap_or_default(),
+            resolution_overrides: value.resolution_overrides.unwrap_or_default(),
+            allow_prereleases: value.allow_prereleases.into_iter().flatten().collect(),
             prerelease: value.prerelease.unwrap_or_default(),
             fork_strategy: value.fork_strategy.unwrap_or_default(),
+            hash_algorithm: value.hash_algorithm,
+            prefer_local_versions: value.prefer_local_versions.unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 value.dependency_metadata.into_iter().flatten(),
             ),
@@ -2821,7 +3121,18 @@ impl From<ResolverOptions> for ResolverSettings {
             build_options: BuildOptions::new(
                 NoBinary::from_args(value.no_binary, value.no_binary_package.unwrap_or_default()),
                 NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
-            ),
+            )
+            .with_limits(BuildLimits::from_args(
+                value.no_build_timeout,
+                value.build_timeout,
+                value.build_timeout_package,
+                value.build_max_output_size,
+                value.build_max_output_size_package,
+            )),
+            user_constraints: Vec::new(),
+            user_overrides: Vec::new(),
+            configured_no_binary_package: BTreeSet::default(),
+            configured_no_build_package: BTreeSet::default(),
         }
     }
 }
@@ -2844,6 +3155,10 @@ impl ResolverInstallerSettings {
         args: ResolverInstallerOptions,
         filesystem: Option<FilesystemOptions>,
     ) -> Self {
+        let (user_constraints, user_overrides) = user_requirements(filesystem.as_ref());
+        let (configured_no_binary_package, configured_no_build_package) =
+            configured_build_options(filesystem.as_ref());
+
         let options = args.combine(
             filesystem
                 .map(FilesystemOptions::into_options)
@@ -2851,7 +3166,17 @@ impl ResolverInstallerSettings {
                 .unwrap_or_default(),
         );
 
-        Self::from(options)
+        let settings = Self::from(options);
+        Self {
+            resolver: ResolverSettings {
+                user_constraints,
+                user_overrides,
+                configured_no_binary_package,
+                configured_no_build_package,
+                ..settings.resolver
+            },
+            ..settings
+        }
     }
 }
 
@@ -2881,7 +3206,14 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         value.no_binary_package.unwrap_or_default(),
                     ),
                     NoBuild::from_args(value.no_build, value.no_build_package.unwrap_or_default()),
-                ),
+                )
+                .with_limits(BuildLimits::from_args(
+                    value.no_build_timeout,
+                    value.build_timeout,
+                    value.build_timeout_package,
+                    value.build_max_output_size,
+                    value.build_max_output_size_package,
+                )),
                 config_setting: value.config_settings.unwrap_or_default(),
                 config_settings_package: value.config_settings_package.unwrap_or_default(),
                 dependency_metadata: DependencyMetadata::from_entries(
@@ -2897,6 +3229,8 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         .collect(),
                 ),
                 fork_strategy: value.fork_strategy.unwrap_or_default(),
+                hash_algorithm: value.hash_algorithm,
+                prefer_local_versions: value.prefer_local_versions.unwrap_or_default(),
                 index_locations,
                 index_strategy: value.index_strategy.unwrap_or_default(),
                 keyring_provider: value.keyring_provider.unwrap_or_default(),
@@ -2907,6 +3241,8 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                 extra_build_variables: value.extra_build_variables.unwrap_or_default(),
                 prerelease: value.prerelease.unwrap_or_default(),
                 resolution: value.resolution.unwrap_or_default(),
+                resolution_overrides: value.resolution_overrides.unwrap_or_default(),
+                allow_prereleases: value.allow_prereleases.into_iter().flatten().collect(),
                 sources: SourceStrategy::from_args(value.no_sources.unwrap_or_default()),
                 upgrade: Upgrade::from_args(
                     value.upgrade,
@@ -2917,6 +3253,10 @@ impl From<ResolverInstallerOptions> for ResolverInstallerSettings {
                         .map(Requirement::from)
                         .collect(),
                 ),
+                user_constraints: Vec::new(),
+                user_overrides: Vec::new(),
+                configured_no_binary_package: BTreeSet::default(),
+                configured_no_build_package: BTreeSet::default(),
             },
             compile_bytecode: value.compile_bytecode.unwrap_or_default(),
             reinstall: Reinstall::from_args(
@@ -2956,6 +3296,8 @@ pub(crate) struct PipSettings {
     pub(crate) resolution: ResolutionMode,
     pub(crate) prerelease: PrereleaseMode,
     pub(crate) fork_strategy: ForkStrategy,
+    pub(crate) hash_algorithm: Option<HashAlgorithmPreference>,
+    pub(crate) prefer_local_versions: bool,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
@@ -2981,6 +3323,7 @@ pub(crate) struct PipSettings {
     pub(crate) compile_bytecode: bool,
     pub(crate) sources: SourceStrategy,
     pub(crate) hash_checking: Option<HashCheckingMode>,
+    pub(crate) allow_unhashable_sources: bool,
     pub(crate) upgrade: Upgrade,
     pub(crate) reinstall: Reinstall,
 }
@@ -3014,6 +3357,7 @@ impl PipSettings {
             no_build,
             no_binary,
             only_binary,
+            allow_binary_fallback,
             no_build_isolation,
             no_build_isolation_package,
             extra_build_dependencies,
@@ -3028,6 +3372,8 @@ impl PipSettings {
             resolution,
             prerelease,
             fork_strategy,
+            hash_algorithm,
+            prefer_local_versions,
             dependency_metadata,
             output_file,
             no_strip_extras,
@@ -3052,6 +3398,8 @@ impl PipSettings {
             link_mode,
             compile_bytecode,
             require_hashes,
+            derive_hashes,
+            allow_unhashable_sources,
             verify_hashes,
             no_sources,
             upgrade,
@@ -3070,8 +3418,14 @@ impl PipSettings {
             index_strategy: top_level_index_strategy,
             keyring_provider: top_level_keyring_provider,
             resolution: top_level_resolution,
+            // Per-package resolution overrides and pre-release allowances are only respected in
+            // projects.
+            resolution_overrides: _,
+            allow_prereleases: _,
             prerelease: top_level_prerelease,
             fork_strategy: top_level_fork_strategy,
+            hash_algorithm: top_level_hash_algorithm,
+            prefer_local_versions: top_level_prefer_local_versions,
             dependency_metadata: top_level_dependency_metadata,
             config_settings: top_level_config_settings,
             config_settings_package: top_level_config_settings_package,
@@ -3092,6 +3446,11 @@ impl PipSettings {
             no_binary: top_level_no_binary,
             no_binary_package: top_level_no_binary_package,
             exclude_newer_package: top_level_exclude_newer_package,
+            build_timeout: top_level_build_timeout,
+            build_timeout_package: top_level_build_timeout_package,
+            build_max_output_size: top_level_build_max_output_size,
+            build_max_output_size_package: top_level_build_max_output_size_package,
+            no_build_timeout: top_level_no_build_timeout,
         } = top_level;
 
         // Merge the top-level options (`tool.uv`) with the pip-specific options (`tool.uv.pip`),
@@ -3108,6 +3467,8 @@ impl PipSettings {
         let resolution = resolution.combine(top_level_resolution);
         let prerelease = prerelease.combine(top_level_prerelease);
         let fork_strategy = fork_strategy.combine(top_level_fork_strategy);
+        let hash_algorithm = hash_algorithm.combine(top_level_hash_algorithm);
+        let prefer_local_versions = prefer_local_versions.combine(top_level_prefer_local_versions);
         let dependency_metadata = dependency_metadata.combine(top_level_dependency_metadata);
         let config_settings = config_settings.combine(top_level_config_settings);
         let config_settings_package =
@@ -3176,6 +3537,11 @@ impl PipSettings {
                 .fork_strategy
                 .combine(fork_strategy)
                 .unwrap_or_default(),
+            hash_algorithm: args.hash_algorithm.combine(hash_algorithm),
+            prefer_local_versions: args
+                .prefer_local_versions
+                .combine(prefer_local_versions)
+                .unwrap_or_default(),
             dependency_metadata: DependencyMetadata::from_entries(
                 args.dependency_metadata
                     .combine(dependency_metadata)
@@ -3273,7 +3639,12 @@ impl PipSettings {
             hash_checking: HashCheckingMode::from_args(
                 args.require_hashes.combine(require_hashes),
                 args.verify_hashes.combine(verify_hashes),
+                args.derive_hashes.combine(derive_hashes),
             ),
+            allow_unhashable_sources: args
+                .allow_unhashable_sources
+                .combine(allow_unhashable_sources)
+                .unwrap_or_default(),
             python: args.python.combine(python),
             system: args.system.combine(system).unwrap_or_default(),
             break_system_packages: args
@@ -3319,7 +3690,19 @@ impl PipSettings {
                     top_level_no_build,
                     top_level_no_build_package.unwrap_or_default(),
                 )),
-            ),
+            )
+            .with_allow_binary_fallback(
+                args.allow_binary_fallback
+                    .combine(allow_binary_fallback)
+                    .unwrap_or_default(),
+            )
+            .with_limits(BuildLimits::from_args(
+                top_level_no_build_timeout,
+                top_level_build_timeout,
+                top_level_build_timeout_package,
+                top_level_build_max_output_size,
+                top_level_build_max_output_size_package,
+            )),
             install_mirrors,
         }
     }
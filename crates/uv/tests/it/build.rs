@@ -598,10 +598,11 @@ fn build_workspace() -> Result<()> {
     [PKG] Building source distribution...
     [PKG] Building wheel from source distribution...
     [PKG] Building wheel from source distribution...
-    Successfully built dist/member-0.1.0.tar.gz
-    Successfully built dist/member-0.1.0-py3-none-any.whl
-    Successfully built dist/project-0.1.0.tar.gz
-    Successfully built dist/project-0.1.0-py3-none-any.whl
+    [PKG] Successfully built dist/member-0.1.0.tar.gz
+    [PKG] Successfully built dist/member-0.1.0-py3-none-any.whl
+    [PKG] Successfully built dist/project-0.1.0.tar.gz
+    [PKG] Successfully built dist/project-0.1.0-py3-none-any.whl
+    Skipped `virtual` (no `build-system`)
     "###);
 
     project
@@ -645,10 +646,11 @@ fn build_workspace() -> Result<()> {
     [PKG] Building source distribution...
     [PKG] Building wheel from source distribution...
     [PKG] Building wheel from source distribution...
-    Successfully built project/dist/member-0.1.0.tar.gz
-    Successfully built project/dist/member-0.1.0-py3-none-any.whl
-    Successfully built project/dist/project-0.1.0.tar.gz
-    Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    [PKG] Successfully built project/dist/member-0.1.0.tar.gz
+    [PKG] Successfully built project/dist/member-0.1.0-py3-none-any.whl
+    [PKG] Successfully built project/dist/project-0.1.0.tar.gz
+    [PKG] Successfully built project/dist/project-0.1.0-py3-none-any.whl
+    Skipped `virtual` (no `build-system`)
     "###);
 
     // Fail when `--package` is provided without a workspace.
@@ -686,6 +688,164 @@ fn build_workspace() -> Result<()> {
     Ok(())
 }
 
+/// Build a subset of the workspace members, with per-member output directories.
+#[test]
+fn build_workspace_members() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"\\\.", ""),
+            (r"\[project\]", "[PKG]"),
+            (r"\[member-\w+\]", "[PKG]"),
+        ])
+        .collect::<Vec<_>>();
+
+    let project = context.temp_dir.child("project");
+
+    project.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+    project.child("README").touch()?;
+    project.child(".gitignore").write_str("dist/\n")?;
+
+    for name in ["member_a", "member_b"] {
+        let member = project.child("packages").child(name);
+        member.child("pyproject.toml").write_str(&format!(
+            r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+
+            [build-system]
+            requires = ["hatchling"]
+            build-backend = "hatchling.build"
+            "#
+        ))?;
+        member
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .touch()?;
+        member.child("README").touch()?;
+    }
+
+    // Build the requested members into their own directories.
+    uv_snapshot!(&filters, context.build()
+        .arg("--package").arg("member-a")
+        .arg("--package").arg("member-b")
+        .arg("--out-dir-template").arg("dist/{name}")
+        .arg("--no-build-logs")
+        .current_dir(&project), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    [PKG] Building source distribution...
+    [PKG] Building source distribution...
+    [PKG] Building wheel from source distribution...
+    [PKG] Building wheel from source distribution...
+    [PKG] Successfully built dist/member-a/member_a-0.1.0.tar.gz
+    [PKG] Successfully built dist/member-a/member_a-0.1.0-py3-none-any.whl
+    [PKG] Successfully built dist/member-b/member_b-0.1.0.tar.gz
+    [PKG] Successfully built dist/member-b/member_b-0.1.0-py3-none-any.whl
+    "###);
+
+    project
+        .child("dist")
+        .child("member-a")
+        .child("member_a-0.1.0-py3-none-any.whl")
+        .assert(predicate::path::is_file());
+    project
+        .child("dist")
+        .child("member-b")
+        .child("member_b-0.1.0-py3-none-any.whl")
+        .assert(predicate::path::is_file());
+    project
+        .child("dist")
+        .child("project")
+        .assert(predicate::path::missing());
+
+    // Commit the workspace, then change one of the members.
+    for args in [
+        &["init"][..],
+        &["add", "."],
+        &[
+            "-c",
+            "user.name=uv",
+            "-c",
+            "user.email=uv@example.com",
+            "commit",
+            "-m",
+            "Initial commit",
+        ],
+    ] {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(&project)
+            .assert()
+            .success();
+    }
+    project
+        .child("packages")
+        .child("member_b")
+        .child("README")
+        .write_str("Changed")?;
+
+    // Only the changed member should be built.
+    uv_snapshot!(&filters, context.build()
+        .arg("--since").arg("HEAD")
+        .arg("--no-build-logs")
+        .current_dir(&project), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    [PKG] Building source distribution...
+    [PKG] Building wheel from source distribution...
+    [PKG] Successfully built dist/member_b-0.1.0.tar.gz
+    [PKG] Successfully built dist/member_b-0.1.0-py3-none-any.whl
+    Skipped `member-a` (no changes since `HEAD`)
+    Skipped `project` (no changes since `HEAD`)
+    "###);
+
+    // Fail when a requested member doesn't exist.
+    uv_snapshot!(&filters, context.build()
+        .arg("--package").arg("member-a")
+        .arg("--package").arg("member-c")
+        .current_dir(&project), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Package `member-c` not found in workspace
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn build_all_with_failure() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -800,14 +960,14 @@ fn build_all_with_failure() -> Result<()> {
     [PKG] Building source distribution...
     [PKG] Building wheel from source distribution...
     [PKG] Building wheel from source distribution...
-    Successfully built dist/member_a-0.1.0.tar.gz
-    Successfully built dist/member_a-0.1.0-py3-none-any.whl
+    [PKG] Successfully built dist/member_a-0.1.0.tar.gz
+    [PKG] Successfully built dist/member_a-0.1.0-py3-none-any.whl
       × Failed to build `member-b @ [TEMP_DIR]/project/packages/member_b`
       ├─▶ The build backend returned an error
       ╰─▶ Call to `setuptools.build_meta.build_sdist` failed (exit status: 1)
           hint: This usually indicates a problem with the package or the build environment.
-    Successfully built dist/project-0.1.0.tar.gz
-    Successfully built dist/project-0.1.0-py3-none-any.whl
+    [PKG] Successfully built dist/project-0.1.0.tar.gz
+    [PKG] Successfully built dist/project-0.1.0-py3-none-any.whl
     "###);
 
     // project and member_a should be built, regardless of member_b build failure
@@ -2024,3 +2184,84 @@ fn force_pep517() -> Result<()> {
 
     Ok(())
 }
+
+/// Check that building the same sources twice with `SOURCE_DATE_EPOCH` produces byte-identical
+/// distributions, even if the modification times of the sources differ.
+#[test]
+fn build_source_date_epoch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("project");
+    context
+        .init()
+        .arg("--lib")
+        .arg(project.path())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg(project.path())
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output1"))
+        .arg("--source-date-epoch")
+        .arg("1700000000"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built output1/project-0.1.0.tar.gz
+    Successfully built output1/project-0.1.0-py3-none-any.whl
+    ");
+
+    // Change the modification time of the sources, and build again.
+    filetime::set_file_mtime(
+        project.child("src").child("project").child("__init__.py"),
+        filetime::FileTime::now(),
+    )?;
+
+    uv_snapshot!(context.filters(), context.build()
+        .arg(project.path())
+        .arg("--out-dir")
+        .arg(context.temp_dir.join("output2"))
+        .env("SOURCE_DATE_EPOCH", "1700000000"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Building source distribution (uv build backend)...
+    Building wheel from source distribution (uv build backend)...
+    Successfully built output2/project-0.1.0.tar.gz
+    Successfully built output2/project-0.1.0-py3-none-any.whl
+    ");
+
+    for filename in ["project-0.1.0.tar.gz", "project-0.1.0-py3-none-any.whl"] {
+        assert_eq!(
+            fs_err::read(context.temp_dir.join("output1").join(filename))?,
+            fs_err::read(context.temp_dir.join("output2").join(filename))?,
+            "`{filename}` differs between builds"
+        );
+    }
+
+    // All archive members should use the given timestamp (2023-11-14 22:13:20 UTC).
+    let mut wheel = ZipArchive::new(File::open(
+        context
+            .temp_dir
+            .join("output1")
+            .join("project-0.1.0-py3-none-any.whl"),
+    )?)?;
+    for index in 0..wheel.len() {
+        let file = wheel.by_index(index)?;
+        assert_eq!(
+            file.last_modified(),
+            Some(zip::DateTime::from_date_and_time(2023, 11, 14, 22, 13, 20)?),
+            "Unexpected timestamp for `{}`",
+            file.name()
+        );
+    }
+
+    Ok(())
+}
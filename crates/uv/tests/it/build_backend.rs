@@ -760,6 +760,126 @@ fn complex_namespace_packages() -> Result<()> {
     Ok(())
 }
 
+/// Two editable installs contributing to the same namespace, with a flat layout, where the module
+/// root (the project root) contains more than the namespace package.
+#[test]
+fn namespace_packages_editable_flat_layout() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let projects = [
+        ("part_a", "def one():\n    return 1\n"),
+        (
+            "part_b",
+            "from shared.part_a import one\n\ndef two():\n    return one() + one()\n",
+        ),
+    ];
+
+    for (part_name, init_py) in projects {
+        let project = context.temp_dir.child(format!("shared-{part_name}"));
+        project.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "shared-{part_name}"
+            version = "1.0.0"
+
+            [tool.uv.build-backend]
+            module-name = "shared.{part_name}"
+            module-root = ""
+
+            [build-system]
+            requires = ["uv_build>=0.7,<10000"]
+            build-backend = "uv_build"
+            "#
+        })?;
+        project
+            .child("shared")
+            .child(part_name)
+            .child("__init__.py")
+            .write_str(init_py)?;
+        // A sibling of the namespace in the module root, which must not become importable.
+        project
+            .child("tests")
+            .child("__init__.py")
+            .write_str(&format!("NAME = {part_name:?}\n"))?;
+    }
+
+    uv_snapshot!(
+        context.filters(),
+        context
+            .pip_install()
+            .arg("-e")
+            .arg("shared-part_a")
+            .arg("-e")
+            .arg("shared-part_b"),
+        @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + shared-part-a==1.0.0 (from file://[TEMP_DIR]/shared-part_a)
+     + shared-part-b==1.0.0 (from file://[TEMP_DIR]/shared-part_b)
+    "
+    );
+
+    // Both portions of the namespace are importable at the same time.
+    uv_snapshot!(context.python_command()
+        .arg("-c")
+        .arg("import shared.part_a, shared.part_b; print(shared.part_b.two()); print(len(shared.__path__))"),
+        @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    2
+    2
+
+    ----- stderr -----
+    "
+    );
+
+    // The rest of the project root is not added to `sys.path`.
+    uv_snapshot!(context.python_command()
+        .arg("-c")
+        .arg("import tests"),
+        @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Traceback (most recent call last):
+      File "<string>", line 1, in <module>
+    ModuleNotFoundError: No module named 'tests'
+    "#
+    );
+
+    // Changes to the source tree are reflected without reinstalling.
+    context
+        .temp_dir
+        .child("shared-part_a")
+        .child("shared")
+        .child("part_a")
+        .child("__init__.py")
+        .write_str("def one():\n    return 2\n")?;
+
+    uv_snapshot!(context.python_command()
+        .arg("-c")
+        .arg("from shared.part_b import two; print(two())"),
+        @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    4
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
 /// Test that a symlinked file (here: license) gets included.
 #[test]
 #[cfg(unix)]
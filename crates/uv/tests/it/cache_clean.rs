@@ -81,7 +81,7 @@ fn clean_package_pypi() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     DEBUG Removing dangling cache entry: [CACHE_DIR]/archive-v0/[ENTRY]
-    Removed [N] files ([SIZE])
+    Removed [N] files for iniconfig ([SIZE])
     "###);
 
     // Assert that the `.rkyv` file is removed for `iniconfig`.
@@ -156,7 +156,7 @@ fn clean_package_index() -> Result<()> {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     DEBUG Removing dangling cache entry: [CACHE_DIR]/archive-v0/[ENTRY]
-    Removed [N] files ([SIZE])
+    Removed [N] files for iniconfig ([SIZE])
     "###);
 
     // Assert that the `.rkyv` file is removed for `iniconfig`.
@@ -167,3 +167,171 @@ fn clean_package_index() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache clean --index` should remove the entries for a single index, retaining those for any
+/// other index.
+#[test]
+fn clean_index() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement from an alternate index, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--index-url")
+        .arg("https://test.pypi.org/simple")
+        .assert()
+        .success();
+
+    // Install the same requirement from PyPI.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--reinstall")
+        .assert()
+        .success();
+
+    let index_rkyv = context
+        .cache_dir
+        .child("simple-v16")
+        .child("index")
+        .child("e8208120cae3ba69")
+        .child("iniconfig.rkyv");
+    let pypi_rkyv = context
+        .cache_dir
+        .child("simple-v16")
+        .child("pypi")
+        .child("iniconfig.rkyv");
+    assert!(index_rkyv.exists());
+    assert!(pypi_rkyv.exists());
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The file count varies by operating system, so we filter it out.
+            ("Removed \\d+ files?", "Removed [N] files"),
+        ])
+        .collect();
+
+    uv_snapshot!(&filters, context.clean().arg("--index").arg("https://test.pypi.org/simple"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Clearing cache entries for index: https://test.pypi.org/simple
+    Removed [N] files ([SIZE])
+    ");
+
+    // The entries for the alternate index should be removed, but those for PyPI retained.
+    assert!(
+        !index_rkyv.exists(),
+        "Expected the `.rkyv` file to be removed for the alternate index"
+    );
+    assert!(
+        pypi_rkyv.exists(),
+        "Expected the `.rkyv` file to be retained for PyPI"
+    );
+
+    Ok(())
+}
+
+/// `cache clean` should report the removal for each package, including those without any cache
+/// entries.
+#[test]
+fn clean_package_multiple() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("typing-extensions\niniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The file count varies by operating system, so we filter it out.
+            ("Removed \\d+ files?", "Removed [N] files"),
+        ])
+        .collect();
+
+    // Package names should be normalized, and missing packages should be reported (but not
+    // treated as an error).
+    uv_snapshot!(&filters, context.clean().arg("INICONFIG").arg("typing_extensions").arg("anyio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed [N] files for iniconfig ([SIZE])
+    Removed [N] files for typing-extensions ([SIZE])
+    No cache entries found for anyio
+    Removed [N] files ([SIZE])
+    ");
+
+    // The packages should no longer be present in the cache.
+    uv_snapshot!(&filters, context.clean().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache entries found for iniconfig
+    ");
+
+    Ok(())
+}
+
+/// `cache clean iniconfig --bucket metadata` should only remove the registry metadata.
+#[test]
+fn clean_package_bucket() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let rkyv = context
+        .cache_dir
+        .child("simple-v16")
+        .child("pypi")
+        .child("iniconfig.rkyv");
+    let wheels = context
+        .cache_dir
+        .child("wheels-v5")
+        .child("pypi")
+        .child("iniconfig");
+    assert!(rkyv.exists());
+    assert!(wheels.exists());
+
+    uv_snapshot!(context.filters(), context.clean().arg("iniconfig").arg("--bucket").arg("metadata"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed 1 file for iniconfig ([SIZE])
+    ");
+
+    // The metadata should be removed, but the wheel should be retained.
+    assert!(!rkyv.exists());
+    assert!(wheels.exists());
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `cache dir --package iniconfig` should show the cache entries for `iniconfig`.
+#[test]
+fn cache_dir_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio\niniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Every entry should belong to `iniconfig`.
+    let output = context
+        .cache_dir()
+        .arg("--package")
+        .arg("iniconfig")
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(!stdout.is_empty());
+    assert!(stdout.lines().all(|line| line.contains("iniconfig")));
+
+    // Restrict the entries to the registry metadata.
+    uv_snapshot!(context.filters(), context.cache_dir().arg("--package").arg("iniconfig").arg("--bucket").arg("metadata"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/simple-v16/pypi/iniconfig.rkyv
+
+    ----- stderr -----
+    "###);
+
+    // Restrict the entries to a specific version.
+    let output = context
+        .cache_dir()
+        .arg("--package")
+        .arg("iniconfig")
+        .arg("--version")
+        .arg("2.0.0")
+        .arg("--bucket")
+        .arg("wheels")
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(!stdout.is_empty());
+    assert!(
+        stdout
+            .lines()
+            .all(|line| line.contains("wheels-v5") && line.contains("2.0.0"))
+    );
+
+    // A version that isn't cached should show nothing, and fail.
+    uv_snapshot!(context.filters(), context.cache_dir().arg("--package").arg("iniconfig").arg("--version").arg("1.0.0").arg("--bucket").arg("wheels"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// `cache dir --package` should fail for a package that isn't in the cache.
+#[test]
+fn cache_dir_package_missing() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.cache_dir().arg("--package").arg("iniconfig"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+}
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// `cache info iniconfig` should show the cache entries for `iniconfig`, by bucket.
+#[test]
+fn cache_info_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio\niniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            (r"\d{4}-\d{2}-\d{2}T[0-9:.]+Z", "[TIME]"),
+            (r"etag [^,)]+", "etag [ETAG]"),
+        ])
+        .collect();
+
+    uv_snapshot!(filters, context.cache_info().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    metadata:
+      [CACHE_DIR]/simple-v16/pypi/iniconfig.rkyv ([SIZE], fetched [TIME], etag [ETAG])
+    wheels:
+      [CACHE_DIR]/wheels-v5/pypi/iniconfig ([SIZE], modified [TIME])
+
+    ----- stderr -----
+    ");
+
+    // In JSON, every entry should belong to `iniconfig`.
+    let output = context
+        .cache_info()
+        .arg("iniconfig")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(info["package"], "iniconfig");
+    let entries = info["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|entry| {
+        entry["path"].as_str().unwrap().contains("iniconfig") && entry["size"].as_u64().unwrap() > 0
+    }));
+    assert_eq!(entries[0]["bucket"], "metadata");
+    assert!(entries[0]["fetched_at"].is_string());
+    assert_eq!(entries[1]["bucket"], "wheels");
+    assert!(entries[1]["modified_at"].is_string());
+
+    Ok(())
+}
+
+/// `cache info` should succeed for a package that isn't in the cache.
+#[test]
+fn cache_info_package_missing() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.cache_info().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No cache entries found for iniconfig
+    ");
+
+    uv_snapshot!(context.filters(), context.cache_info().arg("iniconfig").arg("--output-format").arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"package":"iniconfig","entries":[]}
+
+    ----- stderr -----
+    "#);
+}
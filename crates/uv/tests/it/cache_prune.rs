@@ -382,3 +382,32 @@ fn prune_stale_revision() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --ci` should retain unzipped wheels that are hardlinked into an environment.
+#[test]
+#[cfg(unix)]
+fn prune_ci_hardlinked() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement with hardlinks, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("hardlink")
+        .assert()
+        .success();
+
+    // The pre-built wheel entries are removed, but the hardlinked archive is retained.
+    let output = context.prune().arg("--ci").arg("--verbose").output()?;
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("Removing unzipped wheel entry"));
+    assert!(stderr.contains("Retaining hardlinked cache archive"));
+    assert!(!stderr.contains("Removing dangling cache archive"));
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Parse the output of `uv cache size --shared` into `(logical, physical, shared)` bytes.
+fn parse_shared(stdout: &str) -> (u64, u64, u64) {
+    let mut sizes = stdout.lines().map(|line| {
+        line.rsplit_once(": ")
+            .and_then(|(_, bytes)| bytes.parse::<u64>().ok())
+            .unwrap()
+    });
+    (
+        sizes.next().unwrap(),
+        sizes.next().unwrap(),
+        sizes.next().unwrap(),
+    )
+}
+
+/// `cache size` should report zero bytes for an empty cache.
+#[test]
+fn cache_size_empty() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.cache_size().arg("--shared"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Logical size: 0
+    Physical size: 0
+    Shared: 0
+
+    ----- stderr -----
+    ");
+}
+
+/// `cache size` should report the size of the cache after an install.
+#[test]
+fn cache_size_populated() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("copy")
+        .assert()
+        .success();
+
+    let output = context.cache_size().output()?;
+    assert!(output.status.success());
+    let bytes = String::from_utf8(output.stdout)?.trim().parse::<u64>()?;
+    assert!(bytes > 0);
+
+    // Without hardlinks, nothing is shared.
+    let output = context.cache_size().arg("--shared").output()?;
+    assert!(output.status.success());
+    let (logical, physical, shared) = parse_shared(&String::from_utf8(output.stdout)?);
+    assert_eq!(physical, bytes);
+    assert_eq!(logical, physical);
+    assert_eq!(shared, 0);
+
+    Ok(())
+}
+
+/// `cache size --shared` should account for files hardlinked into an environment.
+#[test]
+#[cfg(unix)]
+fn cache_size_shared_hardlink() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("hardlink")
+        .assert()
+        .success();
+
+    let output = context.cache_size().arg("--shared").output()?;
+    assert!(output.status.success());
+    let (logical, physical, shared) = parse_shared(&String::from_utf8(output.stdout)?);
+    assert!(logical > physical);
+    assert_eq!(shared, logical - physical);
+
+    Ok(())
+}
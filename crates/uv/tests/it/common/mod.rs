@@ -1063,6 +1063,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python check` command with options shared across scenarios.
+    pub fn python_check(&self) -> Command {
+        let mut command = Self::new_command();
+        self.add_shared_options(&mut command, true);
+        command.arg("python").arg("check");
+        command
+    }
+
     /// Create a `uv python uninstall` command with options shared across scenarios.
     pub fn python_uninstall(&self) -> Command {
         let mut command = Self::new_command();
@@ -1178,6 +1186,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv why` command with options shared across scenarios.
+    pub fn why(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("why");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache clean` command.
     pub fn clean(&self) -> Command {
         let mut command = Self::new_command();
@@ -1186,6 +1202,38 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache dir` command.
+    pub fn cache_dir(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("dir");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache info` command.
+    pub fn cache_info(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("info");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv workspace list` command.
+    pub fn workspace_list(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("workspace").arg("list");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
+    /// Create a `uv cache size` command.
+    pub fn cache_size(&self) -> Command {
+        let mut command = Self::new_command();
+        command.arg("cache").arg("size");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache prune` command.
     pub fn prune(&self) -> Command {
         let mut command = Self::new_command();
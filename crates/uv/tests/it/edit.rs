@@ -1213,6 +1213,7 @@ fn add_remove_dev() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
+    Removed anyio and 2 no-longer-needed packages: idna, sniffio
     Uninstalled 3 packages in [TIME]
      - anyio==3.7.0
      - idna==3.6
@@ -1423,6 +1424,7 @@ fn add_remove_optional() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
+    Removed anyio and 2 no-longer-needed packages: idna, sniffio
     Audited in [TIME]
     ");
 
@@ -2735,6 +2737,324 @@ fn add_path_no_workspace() -> Result<()> {
     Ok(())
 }
 
+/// Add a path dependency with extras, which should be written to the dependency entry rather than
+/// the source.
+#[test]
+fn add_path_with_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    let child = workspace.child("packages").child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.optional-dependencies]
+        cli = ["iniconfig"]
+        test = ["sniffio"]
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+    "#})?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    uv_snapshot!(context.filters(), context.add().arg("./packages/child[cli,test]").current_dir(workspace.path()).arg("--no-workspace"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/workspace/packages/child)
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    ");
+
+    let pyproject_toml = fs_err::read_to_string(workspace.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "child[cli,test]",
+        ]
+
+        [tool.uv.sources]
+        child = { path = "packages/child" }
+        "#
+        );
+    });
+
+    let lock = fs_err::read_to_string(workspace.join("uv.lock"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "child"
+        version = "0.1.0"
+        source = { directory = "packages/child" }
+
+        [package.optional-dependencies]
+        cli = [
+            { name = "iniconfig" },
+        ]
+        test = [
+            { name = "sniffio" },
+        ]
+
+        [package.metadata]
+        requires-dist = [
+            { name = "iniconfig", marker = "extra == 'cli'" },
+            { name = "sniffio", marker = "extra == 'test'" },
+        ]
+        provides-extras = ["cli", "test"]
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "parent"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "child", extra = ["cli", "test"] },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "child", extras = ["cli", "test"], directory = "packages/child" }]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372, upload-time = "2024-02-25T23:20:04.057Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235, upload-time = "2024-02-25T23:20:01.196Z" },
+        ]
+        "#
+        );
+    });
+
+    // Removing the dependency should remove both the requirement and the source.
+    uv_snapshot!(context.filters(), context.remove().arg("child").current_dir(workspace.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Uninstalled 3 packages in [TIME]
+     - child==0.1.0 (from file://[TEMP_DIR]/workspace/packages/child)
+     - iniconfig==2.0.0
+     - sniffio==1.3.1
+    ");
+
+    let pyproject_toml = fs_err::read_to_string(workspace.join("pyproject.toml"))?;
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        );
+    });
+
+    Ok(())
+}
+
+/// Add a Git dependency with extras, which should be written to the dependency entry rather than
+/// the source.
+#[test]
+#[cfg(feature = "git")]
+fn add_git_with_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("uv-public-pypackage[cli,test] @ git+https://github.com/astral-test/uv-public-pypackage").arg("--tag=0.0.1").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "uv-public-pypackage[cli,test]",
+        ]
+
+        [tool.uv.sources]
+        uv-public-pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", tag = "0.0.1" }
+        "#
+        );
+    });
+
+    // Removing the dependency should remove both the requirement and the source.
+    uv_snapshot!(context.filters(), context.remove().arg("uv-public-pypackage").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        );
+    });
+
+    Ok(())
+}
+
+/// Add a direct URL dependency with extras, which should be written to the dependency entry rather
+/// than the source.
+#[test]
+fn add_url_with_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig[cli,test] @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig[cli,test]",
+        ]
+
+        [tool.uv.sources]
+        iniconfig = { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl" }
+        "#
+        );
+    });
+
+    // Removing the dependency should remove both the requirement and the source.
+    uv_snapshot!(context.filters(), context.remove().arg("iniconfig").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#
+        );
+    });
+
+    Ok(())
+}
+
 /// Add a path dependency in an adjacent directory, which should not be added to the workspace.
 #[test]
 fn add_path_adjacent_directory() -> Result<()> {
@@ -3283,6 +3603,7 @@ fn add_update_marker() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
+    Removed requests and 5 no-longer-needed packages: certifi, chardet, charset-normalizer, idna, urllib3
     Uninstalled 5 packages in [TIME]
      - certifi==2024.2.2
      - charset-normalizer==3.3.2
@@ -3852,6 +4173,7 @@ fn remove_registry() -> Result<()> {
 
     ----- stderr -----
     Resolved 1 package in [TIME]
+    Removed anyio and 2 no-longer-needed packages: idna, sniffio
     Uninstalled 3 packages in [TIME]
      - anyio==3.7.0
      - idna==3.6
@@ -4407,6 +4729,67 @@ fn add_lower_bound_raw() -> Result<()> {
     Ok(())
 }
 
+/// Write the requirement verbatim when using `--raw`.
+#[test]
+fn add_raw_verbatim() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // The requirement should be written as provided, rather than normalized to
+    // `anyio>=3.0 ; python_full_version >= '3.8'`.
+    uv_snapshot!(context.filters(), context.add().arg("anyio >= 3.0 ; python_version >= '3.8'").arg("--raw"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + sniffio==1.3.1
+    ");
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio >= 3.0 ; python_version >= '3.8'",
+        ]
+        "#
+        );
+    });
+
+    // The lockfile is up-to-date with the verbatim requirement.
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 /// Set a lower bound when adding unconstrained dev dependencies.
 #[test]
 fn add_lower_bound_dev() -> Result<()> {
@@ -5872,6 +6255,95 @@ fn remove_group() -> Result<()> {
     Ok(())
 }
 
+/// Remove a requirement from a dependency group, reporting any packages that are no longer needed.
+#[test]
+fn remove_group_orphans() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = ["pytest==8.1.1"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), universal_windows_filters=true, context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.1.1
+    ");
+
+    // The lockfile should drop the packages that were only required by `pytest`, but the
+    // environment should be left untouched.
+    uv_snapshot!(context.filters(), context.remove().arg("pytest").arg("--group").arg("dev").arg("--no-sync"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Removed pytest and 4 no-longer-needed packages: colorama, iniconfig, packaging, pluggy
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+
+        [package.metadata]
+
+        [package.metadata.requires-dev]
+        dev = []
+        "#
+        );
+    });
+
+    // Syncing should uninstall the orphaned packages.
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Uninstalled 4 packages in [TIME]
+     - iniconfig==2.0.0
+     - packaging==24.0
+     - pluggy==1.4.0
+     - pytest==8.1.1
+    ");
+
+    Ok(())
+}
+
 /// Add to a PEP 732 script.
 #[test]
 fn add_script() -> Result<()> {
@@ -6965,6 +7437,7 @@ fn add_remove_script_lock() -> Result<()> {
 
     ----- stderr -----
     Resolved 9 packages in [TIME]
+    Removed anyio and 1 no-longer-needed package: sniffio
     "###);
 
     let script_content = context.read("script.py");
@@ -7531,6 +8004,84 @@ fn fail_to_add_revert_project() -> Result<()> {
     Ok(())
 }
 
+/// Revert changes to the `pyproject.toml` when `add` is interrupted while a build backend is
+/// running in its own process group (i.e., with a build timeout).
+#[test]
+#[cfg(unix)]
+fn interrupt_add_revert_project() -> Result<()> {
+    use nix::sys::signal::{Signal, kill};
+    use nix::unistd::Pid;
+
+    let context = TestContext::new("3.12");
+
+    let pyproject = indoc! {r#"
+        [project]
+        name = "parent"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv]
+        build-timeout = "600s"
+    "#};
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(pyproject)?;
+
+    // Add a dependency on a package with dynamic metadata, whose build backend hangs.
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "child"
+        dynamic = ["version"]
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = []
+        build-backend = "backend"
+        backend-path = ["."]
+    "#})?;
+    child.child("backend.py").write_str(indoc! {r#"
+        import pathlib
+        import time
+
+        def get_requires_for_build_wheel(config_settings=None):
+            pathlib.Path(__file__).with_name("started").touch()
+            time.sleep(600)
+            return []
+    "#})?;
+
+    let mut process = context
+        .add()
+        .arg("./child")
+        .arg("--no-workspace")
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+
+    // Wait for the build backend to start.
+    let started = child.child("started");
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(120);
+    while !started.exists() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "build backend did not start"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+
+    kill(Pid::from_raw(i32::try_from(process.id())?), Signal::SIGINT)?;
+    let status = process.wait()?;
+    assert_eq!(status.code(), Some(130));
+
+    // The `pyproject.toml` should be restored.
+    let pyproject_toml = fs_err::read_to_string(context.temp_dir.join("pyproject.toml"))?;
+    assert_eq!(pyproject_toml, pyproject);
+
+    Ok(())
+}
+
 /// Revert changes to the `pyproject.toml` and `uv.lock` when the `add` operation fails.
 ///
 /// In this case, the project has an existing lockfile.
@@ -461,6 +461,86 @@ fn requirements_txt_dependency_marker() -> Result<()> {
     Ok(())
 }
 
+/// Export the requirements for a single environment with `--no-markers`.
+#[test]
+fn requirements_txt_no_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio ; sys_platform == 'darwin'", "iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // On macOS, `anyio` and its dependencies are included, without markers.
+    uv_snapshot!(context.filters(), context.export().arg("--no-markers").arg("--python-platform").arg("aarch64-apple-darwin").arg("--python-version").arg("3.12"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-markers --python-platform aarch64-apple-darwin --python-version 3.12
+    -e .
+    anyio==4.3.0 \
+        --hash=sha256:048e05d0f6caeed70d731f3db756d35dcc1f35747c8c403364a8332c630441b8 \
+        --hash=sha256:f75253795a87df48568485fd18cdd2a3fa5c4f7c5be8e5e36637733fce06fed6
+        # via project
+    idna==3.6 \
+        --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+        --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        # via anyio
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+        # via project
+    sniffio==1.3.1 \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+        --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+        # via anyio
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    // On Linux, `anyio` and its dependencies are omitted.
+    uv_snapshot!(context.filters(), context.export().arg("--no-markers").arg("--python-platform").arg("x86_64-unknown-linux-gnu").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-markers --python-platform x86_64-unknown-linux-gnu --frozen
+    -e .
+    iniconfig==2.0.0 \
+        --hash=sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3 \
+        --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+        # via project
+
+    ----- stderr -----
+    ");
+
+    // `--no-markers` is only supported for `requirements.txt`.
+    uv_snapshot!(context.filters(), context.export().arg("--no-markers").arg("--format").arg("pylock.toml").arg("--frozen"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--no-markers` is only supported when exporting to `requirements.txt`
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn requirements_txt_dependency_multiple_markers() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -1914,6 +1994,69 @@ fn requirements_txt_no_hashes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn requirements_txt_no_annotate() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Omit the `# via` comments, but retain the hashes.
+    uv_snapshot!(context.filters(), context.export().arg("--no-annotate"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv export --cache-dir [CACHE_DIR] --no-annotate
+    -e .
+    anyio==3.7.0 \
+        --hash=sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce \
+        --hash=sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0
+    idna==3.6 \
+        --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+        --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    sniffio==1.3.1 \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+        --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Omit both the hashes and the `# via` comments, for a flat list of pinned requirements.
+    uv_snapshot!(context.filters(), context.export()
+        .arg("--no-annotate")
+        .arg("--no-hashes")
+        .arg("--no-header")
+        .arg("--no-emit-project"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio==3.7.0
+    idna==3.6
+    sniffio==1.3.1
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn requirements_txt_output_file() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -4467,3 +4610,335 @@ fn no_editable_env_var() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn cyclonedx_json_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [dependency-groups]
+        dev = ["iniconfig"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("cyclonedx-json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "metadata": {
+        "component": {
+          "type": "application",
+          "bom-ref": "project@0.1.0",
+          "name": "project",
+          "version": "0.1.0",
+          "properties": [
+            {
+              "name": "uv:path",
+              "value": "."
+            },
+            {
+              "name": "uv:editable",
+              "value": "true"
+            }
+          ]
+        }
+      },
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/anyio@3.7.0",
+          "name": "anyio",
+          "version": "3.7.0",
+          "purl": "pkg:pypi/anyio@3.7.0",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/idna@3.6",
+          "name": "idna",
+          "version": "3.6",
+          "purl": "pkg:pypi/idna@3.6",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/iniconfig@2.0.0",
+          "name": "iniconfig",
+          "version": "2.0.0",
+          "purl": "pkg:pypi/iniconfig@2.0.0",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374"
+                }
+              ]
+            }
+          ],
+          "properties": [
+            {
+              "name": "uv:dependency-group",
+              "value": "dev"
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/sniffio@1.3.1",
+          "name": "sniffio",
+          "version": "1.3.1",
+          "purl": "pkg:pypi/sniffio@1.3.1",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2"
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "pkg:pypi/anyio@3.7.0",
+          "dependsOn": [
+            "pkg:pypi/idna@3.6",
+            "pkg:pypi/sniffio@1.3.1"
+          ]
+        },
+        {
+          "ref": "pkg:pypi/idna@3.6",
+          "dependsOn": []
+        },
+        {
+          "ref": "pkg:pypi/iniconfig@2.0.0",
+          "dependsOn": []
+        },
+        {
+          "ref": "pkg:pypi/sniffio@1.3.1",
+          "dependsOn": []
+        },
+        {
+          "ref": "project@0.1.0",
+          "dependsOn": [
+            "pkg:pypi/anyio@3.7.0",
+            "pkg:pypi/iniconfig@2.0.0"
+          ]
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "#);
+
+    // Omitting the development dependencies should omit them from the SBOM.
+    uv_snapshot!(context.filters(), context.export().arg("--format").arg("cyclonedx-json").arg("--no-dev").arg("--no-emit-project"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.5",
+      "version": 1,
+      "components": [
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/anyio@3.7.0",
+          "name": "anyio",
+          "version": "3.7.0",
+          "purl": "pkg:pypi/anyio@3.7.0",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/idna@3.6",
+          "name": "idna",
+          "version": "3.6",
+          "purl": "pkg:pypi/idna@3.6",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f"
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "type": "library",
+          "bom-ref": "pkg:pypi/sniffio@1.3.1",
+          "name": "sniffio",
+          "version": "1.3.1",
+          "purl": "pkg:pypi/sniffio@1.3.1",
+          "externalReferences": [
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc"
+                }
+              ]
+            },
+            {
+              "type": "distribution",
+              "url": "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl",
+              "hashes": [
+                {
+                  "alg": "SHA-256",
+                  "content": "2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2"
+                }
+              ]
+            }
+          ]
+        }
+      ],
+      "dependencies": [
+        {
+          "ref": "pkg:pypi/anyio@3.7.0",
+          "dependsOn": [
+            "pkg:pypi/idna@3.6",
+            "pkg:pypi/sniffio@1.3.1"
+          ]
+        },
+        {
+          "ref": "pkg:pypi/idna@3.6",
+          "dependsOn": []
+        },
+        {
+          "ref": "pkg:pypi/sniffio@1.3.1",
+          "dependsOn": []
+        }
+      ]
+    }
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "#);
+
+    Ok(())
+}
@@ -25,6 +25,7 @@ fn help() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      why                        Show why a package is required by the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
@@ -61,6 +62,10 @@ fn help() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error [env: UV_HTTP_RETRIES=]
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests [env: UV_HTTP_RETRY_BACKOFF=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -71,6 +76,12 @@ fn help() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository
+              [env: UV_NO_CONFIG_BOUNDARY=]
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file [env:
+              UV_NO_USER_CONSTRAINTS=]
       -h, --help
               Display the concise help for this command
       -V, --version
@@ -105,6 +116,7 @@ fn help_flag() {
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
+      why      Show why a package is required by the project
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
@@ -140,6 +152,10 @@ fn help_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error [env: UV_HTTP_RETRIES=]
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests [env: UV_HTTP_RETRY_BACKOFF=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -150,6 +166,12 @@ fn help_flag() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository
+              [env: UV_NO_CONFIG_BOUNDARY=]
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file [env:
+              UV_NO_USER_CONSTRAINTS=]
       -h, --help
               Display the concise help for this command
       -V, --version
@@ -183,6 +205,7 @@ fn help_short_flag() {
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
+      why      Show why a package is required by the project
       tool     Run and install commands provided by Python packages
       python   Manage Python versions and installations
       pip      Manage Python packages with a pip-compatible interface
@@ -218,6 +241,10 @@ fn help_short_flag() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error [env: UV_HTTP_RETRIES=]
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests [env: UV_HTTP_RETRY_BACKOFF=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -228,6 +255,12 @@ fn help_short_flag() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository
+              [env: UV_NO_CONFIG_BOUNDARY=]
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file [env:
+              UV_NO_USER_CONSTRAINTS=]
       -h, --help
               Display the concise help for this command
       -V, --version
@@ -297,6 +330,7 @@ fn help_subcommand() {
       pin           Pin to a specific Python version
       dir           Show the uv Python installation directory
       uninstall     Uninstall Python versions
+      check         Check that managed Python installations are functional
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
@@ -394,6 +428,28 @@ fn help_subcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error.
+              
+              Connection failures, HTTP 5xx server errors, and HTTP 429 rate limits are retried with
+              exponential backoff, waiting at least as long as requested by a `Retry-After` header (up
+              to 60 seconds). Errors that won't resolve on retry, such as HTTP 404 or authentication
+              failures, are reported immediately.
+              
+              Defaults to 3.
+              
+              [env: UV_HTTP_RETRIES=]
+
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests.
+              
+              The delay before the n-th retry grows as `base^n`, up to a maximum interval. Increase this
+              value to back off more aggressively from an overloaded index.
+              
+              Defaults to 2.
+              
+              [env: UV_HTTP_RETRY_BACKOFF=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -440,6 +496,25 @@ fn help_subcommand() {
               
               [env: UV_NO_CONFIG=]
 
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository.
+              
+              By default, the search for `pyproject.toml` and `uv.toml` files stops at the workspace
+              root or at the root of the enclosing Git repository (i.e., the first parent directory that
+              contains `.git`), whichever comes first, such that configuration files outside the
+              workspace or repository are not applied.
+              
+              [env: UV_NO_CONFIG_BOUNDARY=]
+
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file.
+              
+              By default, `constraint-dependencies` and `override-dependencies` in the user-level
+              `uv.toml` are applied in addition to those declared by the project, with the project's
+              declarations taking precedence for any given package.
+              
+              [env: UV_NO_USER_CONSTRAINTS=]
+
       -h, --help
               Display the concise help for this command
 
@@ -661,6 +736,28 @@ fn help_subsubcommand() {
               
               [env: UV_INSECURE_HOST=]
 
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error.
+              
+              Connection failures, HTTP 5xx server errors, and HTTP 429 rate limits are retried with
+              exponential backoff, waiting at least as long as requested by a `Retry-After` header (up
+              to 60 seconds). Errors that won't resolve on retry, such as HTTP 404 or authentication
+              failures, are reported immediately.
+              
+              Defaults to 3.
+              
+              [env: UV_HTTP_RETRIES=]
+
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests.
+              
+              The delay before the n-th retry grows as `base^n`, up to a maximum interval. Increase this
+              value to back off more aggressively from an overloaded index.
+              
+              Defaults to 2.
+              
+              [env: UV_HTTP_RETRY_BACKOFF=]
+
           --no-progress
               Hide all progress outputs.
               
@@ -707,6 +804,25 @@ fn help_subsubcommand() {
               
               [env: UV_NO_CONFIG=]
 
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository.
+              
+              By default, the search for `pyproject.toml` and `uv.toml` files stops at the workspace
+              root or at the root of the enclosing Git repository (i.e., the first parent directory that
+              contains `.git`), whichever comes first, such that configuration files outside the
+              workspace or repository are not applied.
+              
+              [env: UV_NO_CONFIG_BOUNDARY=]
+
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file.
+              
+              By default, `constraint-dependencies` and `override-dependencies` in the user-level
+              `uv.toml` are applied in addition to those declared by the project, with the project's
+              declarations taking precedence for any given package.
+              
+              [env: UV_NO_USER_CONSTRAINTS=]
+
       -h, --help
               Display the concise help for this command
 
@@ -735,6 +851,7 @@ fn help_flag_subcommand() {
       pin           Pin to a specific Python version
       dir           Show the uv Python installation directory
       uninstall     Uninstall Python versions
+      check         Check that managed Python installations are functional
       update-shell  Ensure that the Python executable directory is on the `PATH`
 
     Cache options:
@@ -762,6 +879,10 @@ fn help_flag_subcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error [env: UV_HTTP_RETRIES=]
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests [env: UV_HTTP_RETRY_BACKOFF=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -772,6 +893,12 @@ fn help_flag_subcommand() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository
+              [env: UV_NO_CONFIG_BOUNDARY=]
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file [env:
+              UV_NO_USER_CONSTRAINTS=]
       -h, --help
               Display the concise help for this command
 
@@ -843,6 +970,10 @@ fn help_flag_subsubcommand() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error [env: UV_HTTP_RETRIES=]
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests [env: UV_HTTP_RETRY_BACKOFF=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -853,6 +984,12 @@ fn help_flag_subsubcommand() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository
+              [env: UV_NO_CONFIG_BOUNDARY=]
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file [env:
+              UV_NO_USER_CONSTRAINTS=]
       -h, --help
               Display the concise help for this command
 
@@ -963,6 +1100,7 @@ fn help_with_global_option() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      why                        Show why a package is required by the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
@@ -999,6 +1137,10 @@ fn help_with_global_option() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error [env: UV_HTTP_RETRIES=]
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests [env: UV_HTTP_RETRY_BACKOFF=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1009,6 +1151,12 @@ fn help_with_global_option() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository
+              [env: UV_NO_CONFIG_BOUNDARY=]
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file [env:
+              UV_NO_USER_CONSTRAINTS=]
       -h, --help
               Display the concise help for this command
       -V, --version
@@ -1084,6 +1232,7 @@ fn help_with_no_pager() {
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
+      why                        Show why a package is required by the project
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
@@ -1120,6 +1269,10 @@ fn help_with_no_pager() {
               Disable network access [env: UV_OFFLINE=]
           --allow-insecure-host <ALLOW_INSECURE_HOST>
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
+          --retries <RETRIES>
+              The number of times to retry a network request that failed with a transient error [env: UV_HTTP_RETRIES=]
+          --retry-backoff <RETRY_BACKOFF>
+              The base of the exponential backoff between retries of failed network requests [env: UV_HTTP_RETRY_BACKOFF=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
           --directory <DIRECTORY>
@@ -1130,6 +1283,12 @@ fn help_with_no_pager() {
               The path to a `uv.toml` file to use for configuration [env: UV_CONFIG_FILE=]
           --no-config
               Avoid discovering configuration files (`pyproject.toml`, `uv.toml`) [env: UV_NO_CONFIG=]
+          --no-config-boundary
+              Continue discovering configuration files above the root of the workspace or repository
+              [env: UV_NO_CONFIG_BOUNDARY=]
+          --no-user-constraints
+              Ignore constraints and overrides declared in the user-level configuration file [env:
+              UV_NO_USER_CONSTRAINTS=]
       -h, --help
               Display the concise help for this command
       -V, --version
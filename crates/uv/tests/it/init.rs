@@ -364,6 +364,72 @@ fn init_application_package() -> Result<()> {
     Ok(())
 }
 
+/// Run `uv init --package` in a directory whose name requires normalization, and ensure that the
+/// project name, module name, and entrypoint are derived from the normalized name.
+#[test]
+fn init_application_package_normalized_directory_name() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let child = context.temp_dir.child("Foo.Bar");
+    child.create_dir_all()?;
+
+    let pyproject_toml = child.join("pyproject.toml");
+    let init_py = child.join("src").join("foo_bar").join("__init__.py");
+
+    uv_snapshot!(context.filters(), context.init().current_dir(&child).arg("--package"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `foo-bar`
+    "###);
+
+    let pyproject = fs_err::read_to_string(&pyproject_toml)?;
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r#"
+        [project]
+        name = "foo-bar"
+        version = "0.1.0"
+        description = "Add your description here"
+        readme = "README.md"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [project.scripts]
+        foo-bar = "foo_bar:main"
+
+        [build-system]
+        requires = ["uv_build>=[CURRENT_VERSION],<[NEXT_BREAKING]"]
+        build-backend = "uv_build"
+        "#
+        );
+    });
+
+    assert!(init_py.exists());
+
+    uv_snapshot!(context.filters(), context.run().current_dir(&child).arg("foo-bar"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello from foo-bar!
+
+    ----- stderr -----
+    warning: `VIRTUAL_ENV=[VENV]/` does not match the project environment path `.venv` and will be ignored; use `--active` to target the active environment instead
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo-bar==0.1.0 (from file://[TEMP_DIR]/Foo.Bar)
+    "###);
+
+    Ok(())
+}
+
 /// Run `uv init --lib` to create an library project
 #[test]
 fn init_library() -> Result<()> {
@@ -811,7 +877,7 @@ fn init_script_file_conflicts() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: `name_conflict.py` is already a PEP 723 script; use `uv run` to execute it
+    error: `name_conflict.py` is already a PEP 723 script; use `uv run` to execute it, or `--force` to replace its metadata
     "###);
 
     let contents = "print(\"Hello, world!\")";
@@ -842,6 +908,149 @@ fn init_script_file_conflicts() -> Result<()> {
     Ok(())
 }
 
+// Init script should populate dependencies from `--with` and scaffold the requested template.
+#[test]
+fn init_script_with_template() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.init()
+        .arg("--script")
+        .arg("cli.py")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--with")
+        .arg("typing-extensions>=4")
+        .arg("--template")
+        .arg("cli"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized script at `cli.py`
+    ");
+
+    assert_snapshot!(context.read("cli.py"), @r#"
+    # /// script
+    # requires-python = ">=3.12"
+    # dependencies = [
+    #     "iniconfig",
+    #     "typing-extensions>=4",
+    # ]
+    # ///
+
+    import argparse
+
+
+    def main() -> None:
+        parser = argparse.ArgumentParser(prog="cli.py")
+        parser.add_argument("--name", default="world", help="who to greet")
+        args = parser.parse_args()
+        print(f"Hello, {args.name}!")
+
+
+    if __name__ == "__main__":
+        main()
+    "#);
+
+    // Adding a dependency should not reformat the generated metadata.
+    uv_snapshot!(context.filters(), context.add().arg("--script").arg("cli.py").arg("anyio==3.7.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Updated `cli.py`
+    ");
+
+    assert_snapshot!(context.read("cli.py"), @r#"
+    # /// script
+    # requires-python = ">=3.12"
+    # dependencies = [
+    #     "anyio==3.7.0",
+    #     "iniconfig",
+    #     "typing-extensions>=4",
+    # ]
+    # ///
+
+    import argparse
+
+
+    def main() -> None:
+        parser = argparse.ArgumentParser(prog="cli.py")
+        parser.add_argument("--name", default="world", help="who to greet")
+        args = parser.parse_args()
+        print(f"Hello, {args.name}!")
+
+
+    if __name__ == "__main__":
+        main()
+    "#);
+
+    // Invalid requirements are rejected.
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("invalid.py").arg("--with").arg("iniconfig=="), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'iniconfig==' for '--with <WITH>': Expected a version, but found end of input
+    iniconfig==
+             ^
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
+// Init script should replace the metadata of an existing script with `--force`.
+#[test]
+fn init_script_force() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("main.py");
+    script.write_str(indoc! {r#"
+        # /// script
+        # requires-python = ">=3.8"
+        # dependencies = ["requests"]
+        # ///
+
+        print("Hello, world!")
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("main.py").arg("--with").arg("iniconfig"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `main.py` is already a PEP 723 script; use `uv run` to execute it, or `--force` to replace its metadata
+    ");
+
+    uv_snapshot!(context.filters(), context.init().arg("--script").arg("main.py").arg("--with").arg("iniconfig").arg("--force"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized script at `main.py`
+    ");
+
+    assert_snapshot!(context.read("main.py"), @r#"
+    # /// script
+    # requires-python = ">=3.12"
+    # dependencies = [
+    #     "iniconfig",
+    # ]
+    # ///
+
+    print("Hello, world!")
+    "#);
+
+    Ok(())
+}
+
 // Init script should not trash an existing shebang.
 #[test]
 fn init_script_shebang() -> Result<()> {
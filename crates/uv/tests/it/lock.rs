@@ -509,6 +509,113 @@ fn lock_sdist_git() -> Result<()> {
     Ok(())
 }
 
+/// Lock a Git requirement using a `tag-pattern` in `tool.uv.sources`.
+#[test]
+#[cfg(feature = "git")]
+fn lock_sdist_git_tag_pattern() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["uv-public-pypackage"]
+
+        [tool.uv.sources]
+        uv-public-pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", tag-pattern = "0.0.*" }
+        "#,
+    )?;
+
+    // The highest matching tag (`0.0.2`) should be selected.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "uv-public-pypackage" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "uv-public-pypackage", git = "https://github.com/astral-test/uv-public-pypackage?tag-pattern=0.0.*" }]
+
+        [[package]]
+        name = "uv-public-pypackage"
+        version = "0.1.0"
+        source = { git = "https://github.com/astral-test/uv-public-pypackage?tag-pattern=0.0.*#b270df1a2fb5d012294e9aaf05e7e0bab1e6a389" }
+        "#
+        );
+    });
+
+    // Pin the lockfile to the commit for `0.0.1`.
+    let lock = lock.replace(
+        "b270df1a2fb5d012294e9aaf05e7e0bab1e6a389",
+        "0dacfd662c64cb4ceb16e6cf65a157a8b715b979",
+    );
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    // Without `--upgrade`, the locked commit should be reused.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979)
+    "###);
+
+    // With `--upgrade-package`, the tags should be re-resolved.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("uv-public-pypackage"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("?tag-pattern=0.0.*#b270df1a2fb5d012294e9aaf05e7e0bab1e6a389"));
+
+    Ok(())
+}
+
 /// Lock a Git requirement using PEP 508.
 #[test]
 #[cfg(feature = "git")]
@@ -1827,6 +1934,86 @@ fn lock_project_with_constraints() -> Result<()> {
     Ok(())
 }
 
+/// Lock a project with `constraint-dependencies` declared in the user-level `uv.toml`, which are
+/// layered beneath those declared by the project.
+#[test]
+fn lock_project_with_user_constraints() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let uv_toml = context.user_config_dir.child("uv").child("uv.toml");
+    uv_toml.write_str(
+        r#"
+        constraint-dependencies = ["idna<3.4", "sniffio<1.3"]
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [tool.uv]
+        constraint-dependencies = ["sniffio>=1.3"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // The user-level constraints are recorded separately from those of the project, and the
+    // project's `sniffio` constraint takes precedence over the user-level one.
+    let lock = context.read("uv.lock");
+    assert!(lock.contains(r#"constraints = [{ name = "sniffio", specifier = ">=1.3" }]"#));
+    assert!(lock.contains(r#"user-constraints = [{ name = "idna", specifier = "<3.4" }]"#));
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Ignoring the user-level constraints should invalidate the lockfile.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--no-user-constraints"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    "###);
+
+    // Install the base dependencies from the lockfile.
+    uv_snapshot!(context.filters(), context.sync().arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.3
+     + sniffio==1.3.1
+    "###);
+
+    Ok(())
+}
+
 /// Lock a project with `uv.tool.constraint-dependencies` that reference `tool.uv.sources`.
 #[test]
 fn lock_project_with_constraint_sources() -> Result<()> {
@@ -6873,10 +7060,10 @@ fn lock_resolution_mode() -> Result<()> {
     Ok(())
 }
 
-/// Lock a requirement from PyPI, filtering out wheels that target an ABI that is non-overlapping
-/// with the `Requires-Python` constraint.
+/// Override the resolution mode for direct dependencies and for a dependency group, and ensure
+/// that the overrides are recorded in the lockfile.
 #[test]
-fn lock_requires_python_no_wheels() -> Result<()> {
+fn lock_resolution_overrides() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -6886,80 +7073,25 @@ fn lock_requires_python_no_wheels() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["dearpygui==1.9.1"]
-        "#,
-    )?;
-
-    uv_snapshot!(context.filters(), context.lock(), @r###"
-    success: false
-    exit_code: 1
-    ----- stdout -----
-
-    ----- stderr -----
-      × No solution found when resolving dependencies:
-      ╰─▶ Because dearpygui==1.9.1 has no wheels with a matching Python version tag (e.g., `cp312`) and your project depends on dearpygui==1.9.1, we can conclude that your project's requirements are unsatisfiable.
-
-          hint: Wheels are available for `dearpygui` (v1.9.1) with the following Python ABI tags: `cp37m`, `cp38`, `cp39`, `cp310`, `cp311`
-    "###);
-
-    Ok(())
-}
-
-/// In this case, a package is included twice at the same version, but pointing to different direct
-/// URLs.
-#[test]
-fn lock_same_version_multiple_urls() -> Result<()> {
-    let context = TestContext::new("3.12");
+        dependencies = ["anyio>=3"]
 
-    let v1 = context.temp_dir.child("v1");
-    fs_err::create_dir_all(&v1)?;
-    let pyproject_toml = v1.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "dependency"
-        version = "0.0.1"
-        requires-python = ">=3.12"
-        dependencies = ["anyio==3.7.0"]
-        "#,
-    )?;
+        [dependency-groups]
+        dev = ["iniconfig>=1"]
 
-    let v2 = context.temp_dir.child("v2");
-    fs_err::create_dir_all(&v2)?;
-    let pyproject_toml = v2.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
-        [project]
-        name = "dependency"
-        version = "0.0.1"
-        requires-python = ">=3.12"
-        dependencies = ["anyio==3.0.0"]
+        [tool.uv]
+        resolution-overrides = { ":direct:" = "lowest", ":group:dev:" = "highest" }
         "#,
     )?;
 
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(&formatdoc! {
-        r#"
-        [project]
-        name = "project"
-        version = "0.1.0"
-        requires-python = ">=3.12"
-        dependencies = [
-          "dependency @ {} ; sys_platform == 'darwin'",
-          "dependency @ {} ; sys_platform != 'darwin'",
-        ]
-        "#,
-        Url::from_file_path(context.temp_dir.join("v1")).unwrap(),
-        Url::from_file_path(context.temp_dir.join("v2")).unwrap(),
-    })?;
-
+    // `anyio` should be resolved to its lowest version, while `iniconfig` (in the `dev` group) and
+    // any transitive dependencies should be resolved to their highest versions.
     uv_snapshot!(context.filters(), context.lock(), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 7 packages in [TIME]
+    Resolved 5 packages in [TIME]
     "###);
 
     let lock = context.read("uv.lock");
@@ -6972,24 +7104,18 @@ fn lock_same_version_multiple_urls() -> Result<()> {
         version = 1
         revision = 3
         requires-python = ">=3.12"
-        resolution-markers = [
-            "sys_platform == 'darwin'",
-            "sys_platform != 'darwin'",
-        ]
 
         [options]
+        resolution-overrides = { iniconfig = "highest", ":direct:" = "lowest" }
         exclude-newer = "2024-03-25T00:00:00Z"
 
         [[package]]
         name = "anyio"
         version = "3.0.0"
         source = { registry = "https://pypi.org/simple" }
-        resolution-markers = [
-            "sys_platform != 'darwin'",
-        ]
         dependencies = [
-            { name = "idna", marker = "sys_platform != 'darwin'" },
-            { name = "sniffio", marker = "sys_platform != 'darwin'" },
+            { name = "idna" },
+            { name = "sniffio" },
         ]
         sdist = { url = "https://files.pythonhosted.org/packages/99/0d/65165f99e5f4f3b4c43a5ed9db0fb7aa655f5a58f290727a30528a87eb45/anyio-3.0.0.tar.gz", hash = "sha256:b553598332c050af19f7d41f73a7790142f5bc3d5eb8bd82f5e515ec22019bd9", size = 116952, upload-time = "2021-04-20T14:02:14.75Z" }
         wheels = [
@@ -6997,23 +7123,258 @@ fn lock_same_version_multiple_urls() -> Result<()> {
         ]
 
         [[package]]
-        name = "anyio"
-        version = "3.7.0"
+        name = "idna"
+        version = "3.6"
         source = { registry = "https://pypi.org/simple" }
-        resolution-markers = [
-            "sys_platform == 'darwin'",
-        ]
-        dependencies = [
-            { name = "idna", marker = "sys_platform == 'darwin'" },
-            { name = "sniffio", marker = "sys_platform == 'darwin'" },
-        ]
-        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737, upload-time = "2023-05-27T11:12:46.688Z" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873, upload-time = "2023-05-27T11:12:44.474Z" },
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
         ]
 
         [[package]]
-        name = "dependency"
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [package.dev-dependencies]
+        dev = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "anyio", specifier = ">=3" }]
+
+        [package.metadata.requires-dev]
+        dev = [{ name = "iniconfig", specifier = ">=1" }]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372, upload-time = "2024-02-25T23:20:04.057Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235, upload-time = "2024-02-25T23:20:01.196Z" },
+        ]
+        "#
+        );
+    });
+
+    // Re-run with `--check`.
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "###);
+
+    // Changing the overrides should invalidate the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3"]
+
+        [dependency-groups]
+        dev = ["iniconfig>=1"]
+
+        [tool.uv]
+        resolution-overrides = { ":direct:" = "lowest" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--check"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Ignoring existing lockfile due to change in resolution overrides
+    Resolved 5 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    "###);
+
+    // Unknown pseudo-targets are rejected.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3"]
+
+        [tool.uv]
+        resolution-overrides = { ":transitive:" = "lowest" }
+        "#,
+    )?;
+
+    context
+        .lock()
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Unknown resolution target `:transitive:`; expected a package name, `:direct:`, or `:group:<name>:`",
+        ));
+
+    Ok(())
+}
+
+/// Lock a requirement from PyPI, filtering out wheels that target an ABI that is non-overlapping
+/// with the `Requires-Python` constraint.
+#[test]
+fn lock_requires_python_no_wheels() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["dearpygui==1.9.1"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because dearpygui==1.9.1 has no wheels with a matching Python version tag (e.g., `cp312`) and your project depends on dearpygui==1.9.1, we can conclude that your project's requirements are unsatisfiable.
+
+          hint: Wheels are available for `dearpygui` (v1.9.1) with the following Python ABI tags: `cp37m`, `cp38`, `cp39`, `cp310`, `cp311`
+    "###);
+
+    Ok(())
+}
+
+/// In this case, a package is included twice at the same version, but pointing to different direct
+/// URLs.
+#[test]
+fn lock_same_version_multiple_urls() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let v1 = context.temp_dir.child("v1");
+    fs_err::create_dir_all(&v1)?;
+    let pyproject_toml = v1.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "dependency"
+        version = "0.0.1"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#,
+    )?;
+
+    let v2 = context.temp_dir.child("v2");
+    fs_err::create_dir_all(&v2)?;
+    let pyproject_toml = v2.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "dependency"
+        version = "0.0.1"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.0.0"]
+        "#,
+    )?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(&formatdoc! {
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+          "dependency @ {} ; sys_platform == 'darwin'",
+          "dependency @ {} ; sys_platform != 'darwin'",
+        ]
+        "#,
+        Url::from_file_path(context.temp_dir.join("v1")).unwrap(),
+        Url::from_file_path(context.temp_dir.join("v2")).unwrap(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+        resolution-markers = [
+            "sys_platform == 'darwin'",
+            "sys_platform != 'darwin'",
+        ]
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "anyio"
+        version = "3.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "sys_platform != 'darwin'",
+        ]
+        dependencies = [
+            { name = "idna", marker = "sys_platform != 'darwin'" },
+            { name = "sniffio", marker = "sys_platform != 'darwin'" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/99/0d/65165f99e5f4f3b4c43a5ed9db0fb7aa655f5a58f290727a30528a87eb45/anyio-3.0.0.tar.gz", hash = "sha256:b553598332c050af19f7d41f73a7790142f5bc3d5eb8bd82f5e515ec22019bd9", size = 116952, upload-time = "2021-04-20T14:02:14.75Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/3b/49/ebee263b69fe243bd1fd0a88bc6bb0f7732bf1794ba3273cb446351f9482/anyio-3.0.0-py3-none-any.whl", hash = "sha256:e71c3d9d72291d12056c0265d07c6bbedf92332f78573e278aeb116f24f30395", size = 72182, upload-time = "2021-04-20T14:02:13.663Z" },
+        ]
+
+        [[package]]
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple" }
+        resolution-markers = [
+            "sys_platform == 'darwin'",
+        ]
+        dependencies = [
+            { name = "idna", marker = "sys_platform == 'darwin'" },
+            { name = "sniffio", marker = "sys_platform == 'darwin'" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737, upload-time = "2023-05-27T11:12:46.688Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873, upload-time = "2023-05-27T11:12:44.474Z" },
+        ]
+
+        [[package]]
+        name = "dependency"
         version = "0.0.1"
         source = { directory = "v1" }
         resolution-markers = [
@@ -9126,12 +9487,12 @@ fn lock_migrate() -> Result<()> {
     Ok(())
 }
 
-/// Upgrade a specific package with `--upgrade-package`.
+/// Revalidate the cached index responses and metadata for a specific package with
+/// `--refresh-package`.
 #[test]
-fn lock_upgrade_package() -> Result<()> {
+fn lock_refresh_package() -> Result<()> {
     let context = TestContext::new("3.12");
 
-    // Constrain `anyio` and `idna.`
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
     pyproject_toml.write_str(
         r#"
@@ -9139,37 +9500,250 @@ fn lock_upgrade_package() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["anyio<=2", "idna<=3"]
+        dependencies = ["iniconfig"]
         "#,
     )?;
 
-    // Lock the root package.
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
-    "###);
+    Resolved 2 packages in [TIME]
+    ");
 
-    // Re-run with `--locked`.
-    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    let lock = context.read("uv.lock");
+
+    // Refreshing a single package should re-resolve to the same lockfile.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--refresh-package")
+        .arg("iniconfig")
+        .arg("--upgrade-package")
+        .arg("iniconfig"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
-    "###);
+    Resolved 2 packages in [TIME]
+    ");
 
-    let lock = context.read("uv.lock");
+    assert_eq!(lock, context.read("uv.lock"));
 
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
-            lock, @r#"
+    Ok(())
+}
+
+/// Record per-package platform tag overrides (`compatible-tags`) in the lockfile, and
+/// re-resolve when they change.
+#[test]
+fn lock_compatible_tags() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        compatible-tags = { iniconfig = ["linux_x86_64"] }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        compatible-tags = { iniconfig = ["linux_x86_64"] }
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig" }]
+        "#
+        );
+    });
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Removing the override should invalidate the lockfile.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolving despite existing lockfile due to change in `compatible-tags`
+    Resolved 2 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}
+
+/// Restrict upgrades with `tool.uv.upgrade-policy`.
+#[test]
+fn lock_upgrade_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<=2", "idna<=3"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    // Relax the bounds, but restrict `anyio` to its locked minor series, and pin `idna`.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio", "idna"]
+
+        [tool.uv]
+        upgrade-policy = { anyio = "minor", idna = "pin" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v2.0.0 -> v2.0.2
+    ");
+
+    // Naming the package explicitly bypasses its policy, even alongside `--upgrade`; `idna` remains
+    // pinned.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade").arg("--upgrade-package").arg("anyio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Updated anyio v2.0.2 -> v4.3.0
+    ");
+
+    Ok(())
+}
+
+/// Upgrade a specific package with `--upgrade-package`.
+#[test]
+fn lock_upgrade_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Constrain `anyio` and `idna.`
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<=2", "idna<=3"]
+        "#,
+    )?;
+
+    // Lock the root package.
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Re-run with `--locked`.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
         version = 1
         revision = 3
         requires-python = ">=3.12"
@@ -9409,74 +9983,163 @@ fn lock_upgrade_package() -> Result<()> {
     Ok(())
 }
 
-/// Check that we discard the fork marker from the lockfile when using `--upgrade`.
-#[test]
-fn lock_upgrade_drop_fork_markers() -> Result<()> {
-    let context = TestContext::new("3.12");
-
-    let requirements = r#"[project]
-    name = "forking"
-    version = "0.1.0"
-    requires-python = ">=3.12"
-    dependencies = ["fork-upgrade-foo==1"]
-
-    [build-system]
-        requires = ["flit_core>=3.8,<4"]
-        build-backend = "flit_core.buildapi"
-    "#;
-
-    let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(requirements)?;
-    context
-        .lock()
-        .arg("--index-url")
-        .arg(packse_index_url())
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .assert()
-        .success();
-    let lock = context.read("uv.lock");
-    assert!(lock.contains("resolution-markers"));
-
-    // Remove the bound and lock with `--upgrade`.
-    pyproject_toml.write_str(&requirements.replace("fork-upgrade-foo==1", "fork-upgrade-foo"))?;
-    context
-        .lock()
-        .arg("--index-url")
-        .arg(packse_index_url())
-        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
-        .arg("--upgrade")
-        .assert()
-        .success();
-    let lock = context.read("uv.lock");
-    assert!(!lock.contains("resolution-markers"));
-    Ok(())
-}
-
-/// Warn when there are missing bounds on transitive dependencies with `--resolution lowest`.
+/// Upgrade a specific package to a given version with `--upgrade-package`.
 #[test]
-fn lock_warn_missing_transitive_lower_bounds() -> Result<()> {
+fn lock_upgrade_package_specifier() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
-    pyproject_toml.write_str(
-        r#"
+    pyproject_toml.write_str(indoc! {r#"
         [project]
-        name = "foo"
+        name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["pytest>8"]
-        "#,
-    )?;
+        dependencies = ["iniconfig"]
+    "#})?;
 
-    uv_snapshot!(context.filters(), context.lock().arg("--resolution").arg("lowest"), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
-    warning: The transitive dependency `packaging` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
-    warning: The transitive dependency `iniconfig` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Move to the newest version permitted by the bound.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("iniconfig<2"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Updated iniconfig v2.0.0 -> v1.1.1
+    ");
+
+    // Move to an exact version.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("iniconfig==1.0.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Updated iniconfig v1.1.1 -> v1.0.0
+    ");
+
+    // The bound is not persisted to the `pyproject.toml`.
+    assert_snapshot!(context.read("pyproject.toml"), @r#"
+    [project]
+    name = "project"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["iniconfig"]
+    "#);
+
+    // Extras are rejected.
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("iniconfig[test]"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'iniconfig[test]' for '--upgrade-package <UPGRADE_PACKAGE>': Extras are not supported in `--upgrade-package` (use `iniconfig` instead)
+
+    For more information, try '--help'.
+    ");
+
+    // A bound that conflicts with the project's requirements is attributed to the command line.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--upgrade-package").arg("iniconfig<2"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The requested upgrade `iniconfig<2` (from `--upgrade-package`) is incompatible with `iniconfig>=2` (required by `project`)
+    ");
+
+    Ok(())
+}
+
+/// Check that we discard the fork marker from the lockfile when using `--upgrade`.
+#[test]
+fn lock_upgrade_drop_fork_markers() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements = r#"[project]
+    name = "forking"
+    version = "0.1.0"
+    requires-python = ">=3.12"
+    dependencies = ["fork-upgrade-foo==1"]
+
+    [build-system]
+        requires = ["flit_core>=3.8,<4"]
+        build-backend = "flit_core.buildapi"
+    "#;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(requirements)?;
+    context
+        .lock()
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .assert()
+        .success();
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("resolution-markers"));
+
+    // Remove the bound and lock with `--upgrade`.
+    pyproject_toml.write_str(&requirements.replace("fork-upgrade-foo==1", "fork-upgrade-foo"))?;
+    context
+        .lock()
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER)
+        .arg("--upgrade")
+        .assert()
+        .success();
+    let lock = context.read("uv.lock");
+    assert!(!lock.contains("resolution-markers"));
+    Ok(())
+}
+
+/// Warn when there are missing bounds on transitive dependencies with `--resolution lowest`.
+#[test]
+fn lock_warn_missing_transitive_lower_bounds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "foo"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["pytest>8"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--resolution").arg("lowest"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    warning: The transitive dependency `packaging` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
+    warning: The transitive dependency `iniconfig` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
     warning: The transitive dependency `colorama` is unpinned. Consider setting a lower bound with a constraint when using `--resolution lowest` to avoid using outdated versions.
     "###);
 
@@ -9988,6 +10651,7 @@ fn lock_find_links_http_wheel() -> Result<()> {
 
         [options]
         exclude-newer = "2024-03-25T00:00:00Z"
+        no-build-package = ["packaging"]
 
         [[package]]
         name = "packaging"
@@ -10079,6 +10743,7 @@ fn lock_find_links_http_sdist() -> Result<()> {
 
         [options]
         exclude-newer = "2024-03-25T00:00:00Z"
+        no-binary-package = ["packaging"]
 
         [[package]]
         name = "packaging"
@@ -10128,6 +10793,76 @@ fn lock_find_links_http_sdist() -> Result<()> {
     Ok(())
 }
 
+/// A package can't be listed in both `no-binary-package` and `only-binary-package`.
+#[test]
+fn lock_no_binary_package_conflict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["packaging==23.2"]
+
+        [tool.uv]
+        no-binary-package = ["packaging"]
+        only-binary-package = ["packaging"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `pyproject.toml`. The package `packaging` cannot be listed in both `no-binary-package` and `no-build-package` (or `only-binary-package`).
+    ");
+
+    Ok(())
+}
+
+/// `only-binary-package` is an alias for `no-build-package`; setting both should be rejected.
+#[test]
+fn lock_only_binary_package_alias_conflict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["packaging==23.2"]
+        "#
+    })?;
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! { r#"
+        no-build-package = ["packaging"]
+        only-binary-package = ["packaging"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r#"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `uv.toml`
+      Caused by: TOML parse error at line 1, column 1
+      |
+    1 | no-build-package = ["packaging"]
+      | ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    `no-build-package` and `only-binary-package` are aliases for the same setting; provide only one of them
+    "#);
+
+    Ok(())
+}
+
 /// Use an explicit `--find-links` index.
 #[test]
 fn lock_find_links_explicit_index() -> Result<()> {
@@ -15975,9 +16710,10 @@ fn lock_add_empty_dependency_group() -> Result<()> {
     Ok(())
 }
 
-/// Use a trailing slash on the declared index.
+/// Removing a dependency group should prune its packages from the lockfile, while retaining
+/// platform-specific packages that are still referenced by another group.
 #[test]
-fn lock_trailing_slash_index_url() -> Result<()> {
+fn lock_remove_dependency_group() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -15987,21 +16723,50 @@ fn lock_trailing_slash_index_url() -> Result<()> {
         name = "project"
         version = "0.1.0"
         requires-python = ">=3.12"
-        dependencies = ["anyio==3.7.0"]
+        dependencies = ["iniconfig"]
 
-        [tool.uv]
-        index-url = "https://pypi.org/simple/"
+        [dependency-groups]
+        async = ["anyio"]
+        windows = ["colorama ; sys_platform == 'win32'"]
+        lint = ["colorama ; sys_platform == 'win32'", "typing-extensions"]
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r###"
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    ");
+
+    // Remove the `async` and `windows` groups.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [dependency-groups]
+        lint = ["colorama ; sys_platform == 'win32'", "typing-extensions"]
+        "#,
+    )?;
+
+    // The packages that are only reachable from the removed groups should be pruned and reported.
+    uv_snapshot!(context.filters(), context.lock(), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Resolved 4 packages in [TIME]
-    "###);
+    Removed anyio v4.3.0
+    Removed idna v3.6
+    Removed sniffio v1.3.1
+    ");
 
     let lock = context.read("uv.lock");
 
@@ -16018,25 +16783,21 @@ fn lock_trailing_slash_index_url() -> Result<()> {
         exclude-newer = "2024-03-25T00:00:00Z"
 
         [[package]]
-        name = "anyio"
-        version = "3.7.0"
-        source = { registry = "https://pypi.org/simple/" }
-        dependencies = [
-            { name = "idna" },
-            { name = "sniffio" },
-        ]
-        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737, upload-time = "2023-05-27T11:12:46.688Z" }
+        name = "colorama"
+        version = "0.4.6"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d8/53/6f443c9a4a8358a93a6792e2acffb9d9d5cb0a5cfd8802644b7b1c9a02e4/colorama-0.4.6.tar.gz", hash = "sha256:08695f5cb7ed6e0531a20572697297273c47b8cae5a63ffc6d6ed5c201be6e44", size = 27697, upload-time = "2022-10-25T02:36:22.414Z" }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873, upload-time = "2023-05-27T11:12:44.474Z" },
+            { url = "https://files.pythonhosted.org/packages/d1/d6/3965ed04c63042e047cb6a3e6ed1a63a35087b6a609aa3a15ed8ac56c221/colorama-0.4.6-py2.py3-none-any.whl", hash = "sha256:4f1d9991f5acc0ca119f9d443620b77f9d6b33703e51011c16baf57afb285fc6", size = 25335, upload-time = "2022-10-25T02:36:20.889Z" },
         ]
 
         [[package]]
-        name = "idna"
-        version = "3.6"
-        source = { registry = "https://pypi.org/simple/" }
-        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
         wheels = [
-            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
         ]
 
         [[package]]
@@ -16044,22 +16805,134 @@ fn lock_trailing_slash_index_url() -> Result<()> {
         version = "0.1.0"
         source = { virtual = "." }
         dependencies = [
-            { name = "anyio" },
+            { name = "iniconfig" },
+        ]
+
+        [package.dev-dependencies]
+        lint = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "typing-extensions" },
         ]
 
         [package.metadata]
-        requires-dist = [{ name = "anyio", specifier = "==3.7.0" }]
+        requires-dist = [{ name = "iniconfig" }]
 
-        [[package]]
-        name = "sniffio"
-        version = "1.3.1"
-        source = { registry = "https://pypi.org/simple/" }
-        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372, upload-time = "2024-02-25T23:20:04.057Z" }
-        wheels = [
-            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235, upload-time = "2024-02-25T23:20:01.196Z" },
+        [package.metadata.requires-dev]
+        lint = [
+            { name = "colorama", marker = "sys_platform == 'win32'" },
+            { name = "typing-extensions" },
         ]
-        "#
-        );
+
+        [[package]]
+        name = "typing-extensions"
+        version = "4.10.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/16/3a/0d26ce356c7465a19c9ea8814b960f8a36c3b0d07c323176620b7b483e44/typing_extensions-4.10.0.tar.gz", hash = "sha256:b0abd7c89e8fb96f98db18d86106ff1d90ab692004eb746cf6eda2682f91b3cb", size = 77558, upload-time = "2024-02-25T22:12:49.693Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/f9/de/dc04a3ea60b22624b51c703a84bbe0184abcd1d0b9bc8074b5d6b7ab90bb/typing_extensions-4.10.0-py3-none-any.whl", hash = "sha256:69b1a937c3a517342112fb4c6df7e72fc39a38e7891a5730ed4985b5214b5475", size = 33926, upload-time = "2024-02-25T22:12:47.72Z" },
+        ]
+        "#
+        );
+    });
+
+    // The pruned lockfile is up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Use a trailing slash on the declared index.
+#[test]
+fn lock_trailing_slash_index_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [tool.uv]
+        index-url = "https://pypi.org/simple/"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "anyio"
+        version = "3.7.0"
+        source = { registry = "https://pypi.org/simple/" }
+        dependencies = [
+            { name = "idna" },
+            { name = "sniffio" },
+        ]
+        sdist = { url = "https://files.pythonhosted.org/packages/c6/b3/fefbf7e78ab3b805dec67d698dc18dd505af7a18a8dd08868c9b4fa736b5/anyio-3.7.0.tar.gz", hash = "sha256:275d9973793619a5374e1c89a4f4ad3f4b0a5510a2b5b939444bee8f4c4d37ce", size = 142737, upload-time = "2023-05-27T11:12:46.688Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/68/fe/7ce1926952c8a403b35029e194555558514b365ad77d75125f521a2bec62/anyio-3.7.0-py3-none-any.whl", hash = "sha256:eddca883c4175f14df8aedce21054bfca3adb70ffe76a9f607aef9d7fa2ea7f0", size = 80873, upload-time = "2023-05-27T11:12:44.474Z" },
+        ]
+
+        [[package]]
+        name = "idna"
+        version = "3.6"
+        source = { registry = "https://pypi.org/simple/" }
+        sdist = { url = "https://files.pythonhosted.org/packages/bf/3f/ea4b9117521a1e9c50344b909be7886dd00a519552724809bb1f486986c2/idna-3.6.tar.gz", hash = "sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca", size = 175426, upload-time = "2023-11-25T15:40:54.902Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/c2/e7/a82b05cf63a603df6e68d59ae6a68bf5064484a0718ea5033660af4b54a9/idna-3.6-py3-none-any.whl", hash = "sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f", size = 61567, upload-time = "2023-11-25T15:40:52.604Z" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "anyio" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "anyio", specifier = "==3.7.0" }]
+
+        [[package]]
+        name = "sniffio"
+        version = "1.3.1"
+        source = { registry = "https://pypi.org/simple/" }
+        sdist = { url = "https://files.pythonhosted.org/packages/a2/87/a6771e1546d97e7e041b6ae58d80074f81b7d5121207425c964ddf5cfdbd/sniffio-1.3.1.tar.gz", hash = "sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc", size = 20372, upload-time = "2024-02-25T23:20:04.057Z" }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/e9/44/75a9c9421471a6c4805dbf2356f7c181a29c1879239abab1ea2cc8f38b40/sniffio-1.3.1-py3-none-any.whl", hash = "sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2", size = 10235, upload-time = "2024-02-25T23:20:01.196Z" },
+        ]
+        "#
+        );
     });
 
     // Re-run with `--locked`.
@@ -16254,6 +17127,44 @@ fn lock_explicit_index() -> Result<()> {
     Ok(())
 }
 
+/// A package pinned to an explicit index should not fall back to other indexes (like PyPI) if it
+/// isn't available on the explicit index.
+#[test]
+fn lock_explicit_index_no_fallback() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv.sources]
+        iniconfig = { index = "pytorch" }
+
+        [[tool.uv.index]]
+        name = "pytorch"
+        url = "https://astral-sh.github.io/pytorch-mirror/whl/cu121"
+        explicit = true
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the package registry and your project depends on iniconfig, we can conclude that your project's requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn lock_explicit_default_index() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -19173,6 +20084,112 @@ fn lock_unsupported_version() -> Result<()> {
     Ok(())
 }
 
+/// Lockfiles that contain multiple spellings of the same package should be rejected, and
+/// repaired with `uv lock --repair`.
+#[test]
+fn lock_repair_duplicate_names() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    let lockfile = r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "IniConfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646 }
+        wheels = [
+            { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892 },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "IniConfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0" }]
+"#;
+    context.temp_dir.child("uv.lock").write_str(lockfile)?;
+
+    // The lockfile should be rejected, naming both entries.
+    uv_snapshot!(context.filters(), context.lock().arg("--frozen"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse `uv.lock`, which contains multiple entries for `iniconfig` (as `iniconfig` and `IniConfig`); run `uv lock --repair` to merge them
+      Caused by: Found duplicate package `iniconfig==2.0.0 @ registry+https://pypi.org/simple`
+    "###);
+
+    // `--repair` should merge the entries.
+    uv_snapshot!(context.filters(), context.lock().arg("--repair"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Repaired `uv.lock` by merging entries with equivalent names
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // The repaired lockfile should be up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    // If the entries differ, the lockfile can't be repaired.
+    context
+        .temp_dir
+        .child("uv.lock")
+        .write_str(&lockfile.replacen("size = 5892", "size = 5893", 1))?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--repair"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to repair `uv.lock`: the entries for `iniconfig` (as `iniconfig` and `IniConfig`) differ; remove `uv.lock` and run `uv lock` to regenerate it
+    "###);
+
+    Ok(())
+}
+
 /// See: <https://github.com/astral-sh/uv/issues/7618>
 #[test]
 fn lock_change_requires_python() -> Result<()> {
@@ -28137,8 +29154,9 @@ fn windows_amd64_required() -> Result<()> {
     Ok(())
 }
 
+/// Resolve against multiple Python versions at once via `--python-version`.
 #[test]
-fn lock_empty_extra() -> Result<()> {
+fn lock_python_versions() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let pyproject_toml = context.temp_dir.child("pyproject.toml");
@@ -28147,12 +29165,12 @@ fn lock_empty_extra() -> Result<()> {
         [project]
         name = "project"
         version = "0.1.0"
-        requires-python = ">=3.12"
+        requires-python = ">=3.9"
         dependencies = ["iniconfig"]
         "#,
     )?;
 
-    uv_snapshot!(context.filters(), context.lock(), @r"
+    uv_snapshot!(context.filters(), context.lock().arg("--python-version").arg("3.9").arg("--python-version").arg("3.13"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -28161,25 +29179,86 @@ fn lock_empty_extra() -> Result<()> {
     Resolved 2 packages in [TIME]
     ");
 
+    // The requested versions aren't persisted to the lockfile.
     let lock = context.read("uv.lock");
+    assert!(!lock.contains("required-markers"));
 
-    insta::with_settings!({
-        filters => context.filters(),
-    }, {
-        assert_snapshot!(
-            lock, @r#"
-        version = 1
-        revision = 3
-        requires-python = ">=3.12"
+    // Re-locking without the requested versions should be a no-op.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-        [options]
-        exclude-newer = "2024-03-25T00:00:00Z"
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
 
-        [[package]]
-        name = "iniconfig"
-        version = "2.0.0"
-        source = { registry = "https://pypi.org/simple" }
-        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
+    // As should re-locking with the requested versions.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked").arg("--python-version").arg("3.9").arg("--python-version").arg("3.13"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    // Versions outside of `requires-python` are rejected.
+    uv_snapshot!(context.filters(), context.lock().arg("--python-version").arg("3.8"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The requested Python version (3.8) is incompatible with the Python requirement: `>=3.9`
+    ");
+
+    Ok(())
+}
+
+#[test]
+fn lock_empty_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r#"
+        version = 1
+        revision = 3
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "https://pypi.org/simple" }
+        sdist = { url = "https://files.pythonhosted.org/packages/d7/4b/cbd8e699e64a6f16ca3a8220661b5f83792b3017d0f79807cb8708d33913/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3", size = 4646, upload-time = "2023-01-07T11:08:11.254Z" }
         wheels = [
             { url = "https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374", size = 5892, upload-time = "2023-01-07T11:08:09.864Z" },
         ]
@@ -30258,3 +31337,392 @@ fn lock_required_intersection() -> Result<()> {
 
     Ok(())
 }
+
+/// Record a resolution bundle, then replay it without network access.
+#[test]
+fn lock_record_replay_bundle() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--record-bundle").arg("bundle").arg("--preview-features").arg("resolution-bundle"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Recorded resolution bundle to `bundle`
+    ");
+
+    let lock = context.read("uv.lock");
+
+    // The bundle should include the responses and the project manifests.
+    context
+        .temp_dir
+        .child("bundle/index.json")
+        .assert(predicates::path::is_file());
+    context
+        .temp_dir
+        .child("bundle/environment.json")
+        .assert(predicates::path::is_file());
+    context
+        .temp_dir
+        .child("bundle/workspace/pyproject.toml")
+        .assert(predicates::path::is_file());
+
+    // Replaying the bundle should reproduce the lockfile, without network access.
+    fs_err::remove_file(context.temp_dir.join("uv.lock"))?;
+    uv_snapshot!(context.filters(), context.lock().arg("--replay-bundle").arg("bundle").arg("--preview-features").arg("resolution-bundle"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    ");
+
+    assert_eq!(context.read("uv.lock"), lock);
+
+    // Any request that isn't in the bundle should fail.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "anyio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--replay-bundle").arg("bundle").arg("--preview-features").arg("resolution-bundle"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to fetch: `https://pypi.org/simple/anyio/`
+      Caused by: Network connectivity is disabled, but the requested data wasn't found in the resolution bundle for: `GET https://pypi.org/simple/anyio/`
+    ");
+
+    Ok(())
+}
+
+/// Replaying a resolution bundle should never fetch a Git source that isn't in the bundle.
+#[test]
+#[cfg(feature = "git")]
+fn lock_replay_bundle_git() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--record-bundle").arg("bundle").arg("--preview-features").arg("resolution-bundle"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Recorded resolution bundle to `bundle`
+    ");
+
+    // Git sources are fetched outside of the bundle, so the replay should fail rather than
+    // accessing the network.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig", "uv-public-pypackage"]
+
+        [tool.uv.sources]
+        uv-public-pypackage = { git = "https://github.com/astral-test/uv-public-pypackage", tag = "0.0.1" }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--replay-bundle").arg("bundle").arg("--preview-features").arg("resolution-bundle"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × Failed to download and build `uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0.0.1`
+      ├─▶ Git operation failed
+      ├─▶ failed to clone into: [CACHE_DIR]/git-v0/db/8dab139913c4b566
+      ╰─▶ Remote Git fetches are not allowed because network connectivity is disabled (i.e., with `--offline`)
+    ");
+
+    Ok(())
+}
+
+/// Lock with `prerelease = "forbid"`, which fails if a pre-release is required, unless the package
+/// is listed in `allow-prereleases`.
+#[test]
+fn lock_prerelease_forbid() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv]
+        prerelease = "forbid"
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.0.0rc1"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    // The pre-release is forbidden, so the error should explain which requirement forced it.
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because there is no version of anyio==4.0.0rc1 and child depends on anyio==4.0.0rc1, we can conclude that child's requirements are unsatisfiable.
+          And because your workspace requires child, we can conclude that your workspace's requirements are unsatisfiable.
+
+          hint: `anyio` was requested with a pre-release marker (e.g., anyio==4.0.0rc1), but pre-releases are forbidden (to allow pre-releases of `anyio`, add it to `tool.uv.allow-prereleases`)
+    ");
+
+    // Allowing pre-releases for `anyio` should punch a hole in the policy.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv]
+        prerelease = "forbid"
+        allow-prereleases = ["AnyIO"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    let lock = context.read("uv.lock");
+    assert!(lock.contains("prerelease-mode = \"forbid\""));
+    assert!(lock.contains("allow-prereleases = [\"anyio\"]"));
+    assert!(lock.contains("version = \"4.0.0rc1\""));
+
+    Ok(())
+}
+
+/// Report the changes to the lockfile with `--output-format json`, and fail on major updates to
+/// direct dependencies with `--fail-on major`.
+#[test]
+fn lock_upgrade_report() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<4"]
+
+        [dependency-groups]
+        dev = ["iniconfig<2"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    ");
+
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio"]
+
+        [dependency-groups]
+        dev = ["iniconfig"]
+        "#,
+    )?;
+
+    // The update to `anyio` crosses a major version boundary, as does the update to `iniconfig`.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--dry-run")
+        .arg("--output-format")
+        .arg("json")
+        .arg("--fail-on")
+        .arg("major")
+        .arg("--preview-features")
+        .arg("json-output"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "schema": {
+        "version": "preview"
+      },
+      "changes": [
+        {
+          "name": "anyio",
+          "action": "update",
+          "previous_versions": [
+            "3.7.1"
+          ],
+          "versions": [
+            "4.3.0"
+          ],
+          "bump": "major",
+          "direct": true,
+          "required_by": [
+            {
+              "package": "project"
+            }
+          ]
+        },
+        {
+          "name": "iniconfig",
+          "action": "update",
+          "previous_versions": [
+            "1.1.1"
+          ],
+          "versions": [
+            "2.0.0"
+          ],
+          "bump": "major",
+          "direct": true,
+          "required_by": [
+            {
+              "package": "project",
+              "group": "dev"
+            }
+          ]
+        }
+      ],
+      "dry_run": true
+    }
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Update anyio v3.7.1 -> v4.3.0
+    Update iniconfig v1.1.1 -> v2.0.0
+    Direct dependencies were updated across a major version boundary:
+     - anyio v3.7.1 -> v4.3.0 (major)
+     - iniconfig v1.1.1 -> v2.0.0 (major)
+    "#);
+
+    // A minor update doesn't cross a major version boundary.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<3.7"]
+
+        [dependency-groups]
+        dev = ["iniconfig<2"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--fail-on").arg("major"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Updated anyio v3.7.1 -> v3.6.2
+    ");
+
+    // But it does cross a minor version boundary.
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio<3.6"]
+
+        [dependency-groups]
+        dev = ["iniconfig<2"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--fail-on").arg("minor"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Updated anyio v3.6.2 -> v3.5.0
+    Direct dependencies were updated across a minor version boundary:
+     - anyio v3.6.2 -> v3.5.0 (minor)
+    ");
+
+    Ok(())
+}
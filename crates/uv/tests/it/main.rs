@@ -14,9 +14,18 @@ mod build_backend;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_clean;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_dir;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_info;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_size;
+
 #[cfg(all(feature = "python", feature = "pypi", feature = "test-ecosystem"))]
 mod ecosystem;
 
@@ -128,6 +137,9 @@ mod venv;
 
 mod version;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod why;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod workflow;
 
@@ -63,6 +63,34 @@ async fn simple_http_500() {
     ");
 }
 
+/// The `--retries` flag takes precedence over `UV_HTTP_RETRIES`.
+#[tokio::test]
+async fn simple_http_500_retries_flag() {
+    let context = TestContext::new("3.12");
+
+    let (_server_drop_guard, mock_server_uri) = http_error_server().await;
+
+    let filters = vec![(mock_server_uri.as_str(), "[SERVER]")];
+    uv_snapshot!(filters, context
+        .pip_install()
+        .arg("tqdm")
+        .arg("--index-url")
+        .arg(&mock_server_uri)
+        .arg("--retries")
+        .arg("5")
+        .env(EnvVars::UV_HTTP_RETRIES, "1")
+        .env(EnvVars::UV_TEST_NO_HTTP_RETRY_DELAY, "true"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Request failed after 5 retries
+      Caused by: Failed to fetch: `[SERVER]/tqdm/`
+      Caused by: HTTP status server error (500 Internal Server Error) for url ([SERVER]/tqdm/)
+    ");
+}
+
 /// Check the simple index error message when the server returns a retryable IO error.
 #[tokio::test]
 async fn simple_io_err() {
@@ -75,6 +75,76 @@ fn compile_requirements_in_annotation_line() -> Result<()> {
     Ok(())
 }
 
+/// Write the resolver's decisions to a `--resolution-log`, including when resolution fails.
+#[test]
+fn compile_resolution_log() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--resolution-log")
+        .arg("resolution.jsonl")
+        .assert()
+        .success();
+
+    let events = context
+        .read("resolution.jsonl")
+        .lines()
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The selected versions are logged.
+    let selected = events
+        .iter()
+        .filter(|event| event["event"] == "selected")
+        .map(|event| {
+            format!(
+                "{}=={}",
+                event["package"].as_str().unwrap(),
+                event["version"].as_str().unwrap()
+            )
+        })
+        .collect::<Vec<_>>();
+    insta::assert_snapshot!(selected.join("\n"), @r"
+    anyio==3.7.0
+    idna==3.6
+    sniffio==1.3.1
+    ");
+
+    // Each candidate is logged along with the chain of requirements that led to it.
+    let idna = events
+        .iter()
+        .find(|event| event["event"] == "candidate" && event["package"] == "idna")
+        .expect("a candidate event for `idna`");
+    assert_eq!(idna["version"], "3.6");
+    assert_eq!(idna["chain"][0]["package"], "anyio");
+    assert_eq!(idna["chain"][0]["version"], "3.7.0");
+
+    // If resolution fails, the log is still written.
+    requirements_in.write_str("anyio==3.7.0\nidna<2")?;
+
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--resolution-log")
+        .arg("resolution.jsonl")
+        .assert()
+        .failure();
+
+    let events = context
+        .read("resolution.jsonl")
+        .lines()
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert!(events.iter().any(|event| event["event"] == "no-solution"));
+    assert!(!events.iter().any(|event| event["event"] == "selected"));
+
+    Ok(())
+}
+
 /// Resolve a specific version of `anyio` from a `requirements.in` file on stdin
 /// when passed a path of `-`.
 #[test]
@@ -534,7 +604,7 @@ portalocker==2.5.1
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --constraint constraints.txt --annotation-style line --python-version 3.10 --universal
-    a2wsgi==1.10.4            # via connexion
+    a2wsgi==1.10.4            # via connexion[flask]
     adal==1.2.7               # via azure-kusto-data, msrestazure
     aiohttp==3.9.3            # via apache-airflow-providers-http
     aiosignal==1.3.1          # via aiohttp
@@ -545,11 +615,11 @@ portalocker==2.5.1
     apache-airflow-providers-ftp==3.3.1  # via apache-airflow
     apache-airflow-providers-http==4.3.0  # via apache-airflow
     apache-airflow-providers-imap==3.1.1  # via apache-airflow
-    apache-airflow-providers-microsoft-azure==4.2.0  # via apache-airflow, -c constraints.txt
+    apache-airflow-providers-microsoft-azure==4.2.0  # via apache-airflow[microsoft-azure], -c constraints.txt
     apache-airflow-providers-sqlite==3.3.2  # via apache-airflow
     apispec==3.3.2            # via flask-appbuilder
     argcomplete==3.2.3        # via apache-airflow
-    asgiref==3.8.1            # via apache-airflow-providers-http, connexion, flask
+    asgiref==3.8.1            # via apache-airflow-providers-http, connexion, flask[async]
     async-timeout==4.0.3 ; python_full_version < '3.11'  # via aiohttp
     attrs==23.2.0             # via aiohttp, cattrs, jsonschema, referencing
     azure-batch==14.1.0       # via apache-airflow-providers-microsoft-azure
@@ -584,14 +654,14 @@ portalocker==2.5.1
     connexion==3.0.6          # via apache-airflow
     cron-descriptor==1.4.3    # via apache-airflow
     croniter==2.0.3           # via apache-airflow
-    cryptography==42.0.5      # via adal, apache-airflow, azure-identity, azure-storage-blob, azure-storage-common, msal, pyjwt
+    cryptography==42.0.5      # via adal, apache-airflow, azure-identity, azure-storage-blob, azure-storage-common, msal, pyjwt[crypto]
     deprecated==1.2.14        # via apache-airflow
     dill==0.3.8               # via apache-airflow
     dnspython==2.6.1          # via email-validator
     docutils==0.20.1          # via python-daemon
     email-validator==1.3.1    # via flask-appbuilder
     exceptiongroup==1.2.0 ; python_full_version < '3.11'  # via anyio, cattrs
-    flask==2.2.5              # via apache-airflow, connexion, flask-appbuilder, flask-babel, flask-caching, flask-jwt-extended, flask-login, flask-session, flask-sqlalchemy, flask-wtf
+    flask==2.2.5              # via apache-airflow, connexion[flask], flask-appbuilder, flask-babel, flask-caching, flask-jwt-extended, flask-login, flask-session, flask-sqlalchemy, flask-wtf
     flask-appbuilder==4.1.3   # via apache-airflow
     flask-babel==2.0.0        # via flask-appbuilder
     flask-caching==2.1.0      # via apache-airflow
@@ -667,7 +737,7 @@ portalocker==2.5.1
     sqlalchemy-utils==0.41.2  # via flask-appbuilder
     sqlparse==0.4.4           # via apache-airflow-providers-common-sql
     starlette==0.37.2         # via connexion
-    swagger-ui-bundle==1.1.0  # via connexion
+    swagger-ui-bundle==1.1.0  # via connexion[swagger-ui]
     tabulate==0.9.0           # via apache-airflow
     tenacity==8.2.3           # via apache-airflow
     termcolor==2.4.0          # via apache-airflow
@@ -3893,6 +3963,54 @@ fn compile_source_distribution_path_dependency() -> Result<()> {
 }
 
 /// Resolve a local path dependency to a non-existent file.
+/// Resolve an extra that is spelled invalidly in the wheel metadata, both in `Provides-Extra` and
+/// in the `extra` marker.
+#[test]
+fn compile_wheel_invalid_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context.temp_dir.child("asdf-1.0-py3-none-any.whl");
+    let mut writer = zip::ZipWriter::new(File::create(wheel.path())?);
+    writer.start_file(
+        "asdf-1.0.dist-info/METADATA",
+        zip::write::SimpleFileOptions::default(),
+    )?;
+    std::io::Write::write_all(
+        &mut writer,
+        indoc! {r"
+            Metadata-Version: 2.3
+            Name: asdf
+            Version: 1.0
+            Provides-Extra: Dev Tools
+            Requires-Dist: iniconfig; extra == 'Dev Tools'
+        "}
+        .as_bytes(),
+    )?;
+    writer.finish()?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("asdf[devtools] @ file:asdf-1.0-py3-none-any.whl")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    asdf @ file:asdf-1.0-py3-none-any.whl
+        # via -r requirements.in
+    iniconfig==2.0.0
+        # via asdf
+
+    ----- stderr -----
+    warning: The metadata for `asdf` includes an invalid extra (`Dev Tools`), which will be treated as `devtools`
+    Resolved 2 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn compile_wheel_path_dependency_missing() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -4744,7 +4862,7 @@ fn compile_editable() -> Result<()> {
     -e file://../../scripts/packages/black_editable
         # via -r [TEMP_DIR]/requirements.in
     aiohttp==3.9.3
-        # via black
+        # via black[d]
     aiosignal==1.3.1
         # via aiohttp
     anyio==4.3.0
@@ -4768,7 +4886,7 @@ fn compile_editable() -> Result<()> {
     sniffio==1.3.1
         # via anyio
     uvloop==0.19.0
-        # via black
+        # via black[uvloop]
     yarl==1.9.4
         # via aiohttp
 
@@ -4802,7 +4920,7 @@ fn deduplicate_editable() -> Result<()> {
     -e file://../../scripts/packages/black_editable
         # via -r [TEMP_DIR]/requirements.in
     aiohttp==3.9.3
-        # via black
+        # via black[d]
     aiosignal==1.3.1
         # via aiohttp
     attrs==23.2.0
@@ -4818,7 +4936,7 @@ fn deduplicate_editable() -> Result<()> {
         #   aiohttp
         #   yarl
     uvloop==0.19.0
-        # via black
+        # via black[uvloop]
     yarl==1.9.4
         # via aiohttp
 
@@ -4899,7 +5017,7 @@ fn recursive_extras_direct_url() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] [TEMP_DIR]/requirements.in
     aiohttp==3.9.3
-        # via black
+        # via black[d]
     aiosignal==1.3.1
         # via aiohttp
     attrs==23.2.0
@@ -4917,7 +5035,7 @@ fn recursive_extras_direct_url() -> Result<()> {
         #   aiohttp
         #   yarl
     uvloop==0.19.0
-        # via black
+        # via black[uvloop]
     yarl==1.9.4
         # via aiohttp
 
@@ -5101,6 +5219,47 @@ fn generate_hashes_registry() -> Result<()> {
     Ok(())
 }
 
+/// Carry forward hashes from pinned input requirements, discarding any that don't match the
+/// index.
+#[test]
+fn generate_hashes_from_input() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        anyio==4.0.0 \
+            --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a \
+            --hash=sha256:0000000000000000000000000000000000000000000000000000000000000000
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--generate-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --generate-hashes
+    anyio==4.0.0 \
+        --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+        # via -r requirements.in
+    idna==3.6 \
+        --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+        --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        # via anyio
+    sniffio==1.3.1 \
+        --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+        --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+        # via anyio
+
+    ----- stderr -----
+    warning: Discarding hash `sha256:0000000000000000000000000000000000000000000000000000000000000000` for `anyio==4.0.0`, which doesn't match any distribution on the index
+    Resolved 3 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Include hashes from the URL in the generated output.
 #[test]
 fn generate_hashes_source_distribution_url() -> Result<()> {
@@ -5557,7 +5716,7 @@ fn avoid_irrelevant_extras() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
     anyio==4.3.0
-        # via extras
+        # via extras[dev]
     extras==0.0.1
         # via -r requirements.in
     idna==3.6
@@ -5612,7 +5771,9 @@ coverage = ["example[test]", "extras>=0.0.1,<=0.0.2"]
     -e .
         # via -r requirements.in
     extras==0.0.1
-        # via example
+        # via
+        #   example[coverage]
+        #   example[test]
     iniconfig==2.0.0
         # via extras
 
@@ -6617,6 +6778,82 @@ fn emit_find_links_relative() -> Result<()> {
     Ok(())
 }
 
+/// Emit the build requirements for any source distributions in the resolution.
+#[test]
+fn emit_build_requirements() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("app").child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "example"
+        version = "0.0.0"
+        dependencies = ["iniconfig"]
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["flit_core>=3.8,<4"]
+        build-backend = "flit_core.buildapi"
+    "#})?;
+    context
+        .temp_dir
+        .child("app")
+        .child("example")
+        .child("__init__.py")
+        .touch()?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("./app")?;
+
+    // By default, the build requirements are appended as a commented section.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--emit-build-requirements"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-build-requirements
+    ./app
+        # via -r requirements.in
+    iniconfig==2.0.0
+        # via example
+
+    # The following build requirements were resolved for source distributions:
+    # flit-core==3.9.0 (via example)
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    // The build requirements can also be written to a separate file.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-header")
+        .arg("--emit-build-requirements-output")
+        .arg("build-constraints.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ./app
+        # via -r requirements.in
+    iniconfig==2.0.0
+        # via example
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    insta::assert_snapshot!(context.read("build-constraints.txt"), @r"
+    flit-core==3.9.0
+        # via example
+    ");
+
+    Ok(())
+}
+
 /// Emit the `--no-binary` and `--only-binary` options.
 #[test]
 fn emit_build_options() -> Result<()> {
@@ -6660,6 +6897,41 @@ fn emit_build_options() -> Result<()> {
     Ok(())
 }
 
+/// Emit the `--config-settings` and `--config-settings-package` options, including those from the
+/// input file.
+#[test]
+fn emit_build_options_config_settings() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        --config-settings-package iniconfig:key=value
+        iniconfig
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-build-options")
+            .arg("--config-settings")
+            .arg("editable_mode=compat"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-build-options --config-settings editable_mode=compat
+    --config-settings editable_mode=compat
+    --config-settings-package iniconfig:key=value
+
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Respect the `--no-index` flag in a `requirements.txt` file.
 #[test]
 fn no_index_requirements_txt() -> Result<()> {
@@ -7587,7 +7859,7 @@ fn no_strip_extra() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1
         # via flask
 
@@ -7648,7 +7920,7 @@ fn no_strip_extras() -> Result<()> {
         # via trio
     packaging==24.0
         # via
-        #   anyio
+        #   anyio[doc]
         #   sphinx
     pygments==2.17.2
         # via sphinx
@@ -7664,14 +7936,14 @@ fn no_strip_extras() -> Result<()> {
         # via trio
     sphinx==7.2.6
         # via
-        #   anyio
+        #   anyio[doc]
         #   sphinx-autodoc-typehints
         #   sphinx-rtd-theme
         #   sphinxcontrib-jquery
     sphinx-autodoc-typehints==2.0.0
-        # via anyio
+        # via anyio[doc]
     sphinx-rtd-theme==2.0.0
-        # via anyio
+        # via anyio[doc]
     sphinxcontrib-applehelp==1.0.8
         # via sphinx
     sphinxcontrib-devhelp==1.0.6
@@ -7687,7 +7959,7 @@ fn no_strip_extras() -> Result<()> {
     sphinxcontrib-serializinghtml==1.1.10
         # via sphinx
     trio==0.25.0
-        # via anyio
+        # via anyio[trio]
     urllib3==2.2.1
         # via requests
 
@@ -7737,7 +8009,7 @@ fn no_strip_markers() -> Result<()> {
 fn no_strip_markers_multiple_markers() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         trio ; python_version > '3.11'
         trio ; sys_platform == 'win32'
     "})?;
@@ -7829,7 +8101,7 @@ fn no_strip_markers_transitive_marker() -> Result<()> {
 fn universal() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         trio ; python_version > '3.11'
         trio ; sys_platform == 'win32'
     "})?;
@@ -7869,12 +8141,79 @@ fn universal() -> Result<()> {
     Ok(())
 }
 
+/// Derive platform-specific requirements files from a universal resolution.
+#[test]
+fn universal_split_by_platform() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str(indoc! {r"
+        idna ; sys_platform == 'linux'
+        colorama ; sys_platform == 'win32'
+    "})?;
+
+    uv_snapshot!(context.filters(), windows_filters=false, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--universal")
+            .arg("--split-by-platform=linux,windows")
+            .arg("--no-header")
+            .arg("--output-file")
+            .arg("requirements.txt"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    colorama==0.4.6 ; sys_platform == 'win32'
+        # via -r requirements.in
+    idna==3.6 ; sys_platform == 'linux'
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    insta::assert_snapshot!(context.read("requirements-linux.txt"), @r"
+    idna==3.6
+        # via -r requirements.in
+    ");
+
+    insta::assert_snapshot!(context.read("requirements-windows.txt"), @r"
+    colorama==0.4.6
+        # via -r requirements.in
+    ");
+
+    Ok(())
+}
+
+/// `--split-by-platform` requires `--universal`.
+#[test]
+fn split_by_platform_requires_universal() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("idna")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--split-by-platform")
+            .arg("--output-file")
+            .arg("requirements.txt"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `--split-by-platform` option requires `--universal`
+    "
+    );
+
+    Ok(())
+}
+
 /// Perform a universal resolution with conflicting versions and markers.
 #[test]
 fn universal_conflicting() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         trio==0.25.0 ; sys_platform == 'darwin'
         trio==0.10.0 ; sys_platform == 'win32'
     "})?;
@@ -7923,7 +8262,7 @@ fn universal_conflicting() -> Result<()> {
 fn universal_cycles() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         testtools==2.3.0
         fixtures==3.0.0
     "})?;
@@ -7981,7 +8320,7 @@ fn universal_cycles() -> Result<()> {
 fn universal_constraint() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         anyio ; sys_platform == 'win32'
     "})?;
 
@@ -8022,7 +8361,7 @@ fn universal_constraint() -> Result<()> {
 fn universal_constraint_marker() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         anyio ; sys_platform == 'win32'
     "})?;
 
@@ -8068,7 +8407,7 @@ fn universal_constraint_marker() -> Result<()> {
 fn universal_multi_version() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         iniconfig
         iniconfig==2.0.0 ; python_version > '3.12'
         iniconfig==1.0.0 ; python_version == '3.12'
@@ -8106,7 +8445,7 @@ fn universal_multi_version() -> Result<()> {
 fn universal_platform_fork() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         --index-url https://astral-sh.github.io/pytorch-mirror/whl/cpu
 
         torch==2.5.1
@@ -8168,7 +8507,7 @@ fn universal_platform_fork() -> Result<()> {
 fn universal_disjoint_locals() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         --find-links https://astral-sh.github.io/pytorch-mirror/whl/torch_stable.html
 
         torch==2.0.0+cu118 ; platform_machine == 'x86_64'
@@ -8226,7 +8565,7 @@ fn universal_disjoint_locals() -> Result<()> {
 fn universal_transitive_disjoint_locals() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2025-01-30T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         --find-links https://astral-sh.github.io/pytorch-mirror/whl/torch_stable.html
 
         torch==2.0.0+cu118 ; platform_machine == 'x86_64'
@@ -8865,7 +9204,7 @@ fn universal_nested_disjoint_local_requirement() -> Result<()> {
 fn existing_prerelease_preference() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2024-07-17T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         cffi
     "})?;
 
@@ -8902,7 +9241,7 @@ fn existing_prerelease_preference() -> Result<()> {
 fn universal_disjoint_prereleases() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2024-07-17T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         cffi >= 1.16.0rc1 ; os_name != 'linux'
         cffi >= 1.16.0rc1, <1.16.0rc2 ; os_name == 'linux'
     "})?;
@@ -8933,7 +9272,7 @@ fn universal_disjoint_prereleases() -> Result<()> {
 fn universal_disjoint_prereleases_preference() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2024-07-17T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         cffi ; os_name != 'linux'
         cffi > 1.16.0 ; os_name == 'linux'
     "})?;
@@ -8974,7 +9313,7 @@ fn universal_disjoint_prereleases_preference() -> Result<()> {
 fn universal_disjoint_prereleases_preference_marker() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2024-07-17T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         cffi ; os_name != 'linux'
         cffi >= 1.16.0rc1 ; os_name == 'linux'
     "})?;
@@ -9015,7 +9354,7 @@ fn universal_disjoint_prereleases_preference_marker() -> Result<()> {
 fn universal_disjoint_prereleases_allow() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2024-07-17T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         cffi >= 1.15.0, < 1.17.0 ; os_name == 'linux'
         cffi >= 1.15.0, <= 1.16.0rc2 ; os_name != 'linux'
     "})?;
@@ -9051,7 +9390,7 @@ fn universal_disjoint_prereleases_allow() -> Result<()> {
 fn universal_transitive_disjoint_prerelease_requirement() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2024-07-17T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         cffi ; os_name == 'linux'
         cffi >= 1.17.0rc1 ; os_name != 'linux'
         cryptography
@@ -9091,7 +9430,7 @@ fn universal_transitive_disjoint_prerelease_requirement() -> Result<()> {
 fn universal_prerelease_mode() -> Result<()> {
     let context = TestContext::new("3.12").with_exclude_newer("2024-07-17T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         cffi ; os_name == 'linux'
         cffi >= 1.17.0rc1 ; os_name != 'linux'
     "})?;
@@ -9227,7 +9566,7 @@ fn universal_disjoint_prerelease_requirement() -> Result<()> {
 fn universal_requires_python() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         numpy >=1.26 ; python_version >= '3.9'
         numpy <1.26 ; python_version < '3.9'
     "})?;
@@ -9261,7 +9600,7 @@ fn universal_requires_python() -> Result<()> {
 fn universal_requires_python_incomplete() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         uv; python_version >= '3.8'
     "})?;
 
@@ -9297,7 +9636,7 @@ fn universal_requires_python_incomplete() -> Result<()> {
 fn universal_no_repeated_unconditional_distributions_1() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         pylint
         sphinx
     "})?;
@@ -9415,7 +9754,7 @@ fn universal_no_repeated_unconditional_distributions_1() -> Result<()> {
 fn universal_no_repeated_unconditional_distributions_2() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         pylint
         dill==0.3.1.1
     "})?;
@@ -9468,7 +9807,7 @@ fn universal_no_repeated_unconditional_distributions_2() -> Result<()> {
 fn universal_prefer_upper_bounds() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         pylint < 3 ; sys_platform == 'darwin'
         pylint > 2 ; sys_platform != 'darwin'
     "})?;
@@ -9524,7 +9863,7 @@ fn universal_prefer_upper_bounds() -> Result<()> {
 fn universal_unnecessary_python() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         iniconfig ; python_version >= '3.7'
     "})?;
 
@@ -9565,7 +9904,7 @@ fn universal_unnecessary_python() -> Result<()> {
 fn universal_marker_propagation() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         --find-links https://astral-sh.github.io/pytorch-mirror/whl/torch_stable.html
 
         torch==2.0.0 ; platform_machine == 'x86_64'
@@ -9658,7 +9997,7 @@ fn universal_marker_propagation() -> Result<()> {
 fn universal_disjoint_extra() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         flask[async]; sys_platform == 'linux'
         flask[dotenv]; sys_platform == 'darwin'
     "})?;
@@ -9672,7 +10011,7 @@ fn universal_disjoint_extra() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal
     asgiref==3.8.1 ; sys_platform == 'linux'
-        # via flask
+        # via flask[async]
     blinker==1.7.0 ; sys_platform == 'darwin' or sys_platform == 'linux'
         # via flask
     click==8.1.7 ; sys_platform == 'darwin' or sys_platform == 'linux'
@@ -9688,7 +10027,7 @@ fn universal_disjoint_extra() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1 ; sys_platform == 'darwin' or sys_platform == 'linux'
         # via flask
 
@@ -9704,7 +10043,7 @@ fn universal_disjoint_extra() -> Result<()> {
 fn universal_disjoint_extra_no_strip() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         flask[async]; sys_platform == 'linux'
         flask[dotenv]; sys_platform == 'darwin'
     "})?;
@@ -9719,7 +10058,7 @@ fn universal_disjoint_extra_no_strip() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --no-strip-extras
     asgiref==3.8.1 ; sys_platform == 'linux'
-        # via flask
+        # via flask[async]
     blinker==1.7.0 ; sys_platform == 'darwin' or sys_platform == 'linux'
         # via flask
     click==8.1.7 ; sys_platform == 'darwin' or sys_platform == 'linux'
@@ -9739,7 +10078,7 @@ fn universal_disjoint_extra_no_strip() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1 ; sys_platform == 'darwin' or sys_platform == 'linux'
         # via flask
 
@@ -9755,7 +10094,7 @@ fn universal_disjoint_extra_no_strip() -> Result<()> {
 fn universal_overlap_extra_base() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         flask
         flask[dotenv]; sys_platform == 'darwin'
     "})?;
@@ -9785,7 +10124,7 @@ fn universal_overlap_extra_base() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1
         # via flask
 
@@ -9801,7 +10140,7 @@ fn universal_overlap_extra_base() -> Result<()> {
 fn universal_overlap_extra_base_no_strip() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         flask
         flask[dotenv]; sys_platform == 'darwin'
     "})?;
@@ -9834,7 +10173,7 @@ fn universal_overlap_extra_base_no_strip() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1
         # via flask
 
@@ -9850,7 +10189,7 @@ fn universal_overlap_extra_base_no_strip() -> Result<()> {
 fn universal_overlap_extras() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         flask[async]; sys_platform == 'linux' or sys_platform == 'darwin'
         flask[dotenv]; sys_platform == 'darwin'
     "})?;
@@ -9864,7 +10203,7 @@ fn universal_overlap_extras() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal
     asgiref==3.8.1 ; sys_platform == 'darwin' or sys_platform == 'linux'
-        # via flask
+        # via flask[async]
     blinker==1.7.0 ; sys_platform == 'darwin' or sys_platform == 'linux'
         # via flask
     click==8.1.7 ; sys_platform == 'darwin' or sys_platform == 'linux'
@@ -9880,7 +10219,7 @@ fn universal_overlap_extras() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1 ; sys_platform == 'darwin' or sys_platform == 'linux'
         # via flask
 
@@ -9896,7 +10235,7 @@ fn universal_overlap_extras() -> Result<()> {
 fn universal_overlap_extras_no_strip() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         flask[async]; sys_platform == 'linux' or sys_platform == 'darwin'
         flask[dotenv]; sys_platform == 'darwin'
     "})?;
@@ -9911,7 +10250,7 @@ fn universal_overlap_extras_no_strip() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --no-strip-extras
     asgiref==3.8.1 ; sys_platform == 'darwin' or sys_platform == 'linux'
-        # via flask
+        # via flask[async]
     blinker==1.7.0 ; sys_platform == 'darwin' or sys_platform == 'linux'
         # via flask
     click==8.1.7 ; sys_platform == 'darwin' or sys_platform == 'linux'
@@ -9929,7 +10268,7 @@ fn universal_overlap_extras_no_strip() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1 ; sys_platform == 'darwin' or sys_platform == 'linux'
         # via flask
 
@@ -9945,7 +10284,7 @@ fn universal_overlap_extras_no_strip() -> Result<()> {
 fn universal_identical_extras() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         flask[async]; sys_platform == 'darwin'
         flask[dotenv]; sys_platform == 'darwin'
     "})?;
@@ -9959,7 +10298,7 @@ fn universal_identical_extras() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal
     asgiref==3.8.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[async]
     blinker==1.7.0 ; sys_platform == 'darwin'
         # via flask
     click==8.1.7 ; sys_platform == 'darwin'
@@ -9975,7 +10314,7 @@ fn universal_identical_extras() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1 ; sys_platform == 'darwin'
         # via flask
 
@@ -9991,7 +10330,7 @@ fn universal_identical_extras() -> Result<()> {
 fn universal_identical_extras_no_strip() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         flask[async]; sys_platform == 'darwin'
         flask[dotenv]; sys_platform == 'darwin'
     "})?;
@@ -10006,7 +10345,7 @@ fn universal_identical_extras_no_strip() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --universal --no-strip-extras
     asgiref==3.8.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[async]
     blinker==1.7.0 ; sys_platform == 'darwin'
         # via flask
     click==8.1.7 ; sys_platform == 'darwin'
@@ -10022,7 +10361,7 @@ fn universal_identical_extras_no_strip() -> Result<()> {
         #   jinja2
         #   werkzeug
     python-dotenv==1.0.1 ; sys_platform == 'darwin'
-        # via flask
+        # via flask[dotenv]
     werkzeug==3.0.1 ; sys_platform == 'darwin'
         # via flask
 
@@ -10483,7 +10822,7 @@ fn prerelease_constraint() -> Result<()> {
     Ok(())
 }
 
-/// Resolve from a `pyproject.toml` file with a mutually recursive extra.
+/// Reject a `pyproject.toml` file with a mutually recursive extra.
 #[test]
 fn compile_pyproject_toml_mutually_recursive_extra() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -10512,22 +10851,12 @@ dev = [
             .arg("pyproject.toml")
             .arg("--extra")
             .arg("dev"), @r###"
-    success: true
-    exit_code: 0
+    success: false
+    exit_code: 2
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --extra dev
-    anyio==4.3.0
-        # via project (pyproject.toml)
-    idna==3.6
-        # via anyio
-    iniconfig==2.0.0
-        # via project (pyproject.toml)
-    sniffio==1.3.1
-        # via anyio
 
     ----- stderr -----
-    Resolved 4 packages in [TIME]
+    error: Detected a cycle in `project.optional-dependencies`: `dev` -> `test` -> `dev`
     "###
     );
 
@@ -11027,7 +11356,7 @@ dev = [
     -e .
         # via -r requirements.in
     anyio @ https://files.pythonhosted.org/packages/bf/cd/d6d9bb1dadf73e7af02d18225cbd2c93f8552e13130484f1c8dcfece292b/anyio-4.2.0-py3-none-any.whl
-        # via example
+        # via example[dev]
     idna==3.6
         # via anyio
     sniffio==1.3.1
@@ -11041,6 +11370,77 @@ dev = [
     Ok(())
 }
 
+/// Dependencies introduced through an extra should be annotated with the extra (e.g.,
+/// `# via example[dev]`), alongside any base dependency edges.
+#[test]
+fn annotate_extra_dependents() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = ["anyio==4.3.0"]
+requires-python = '>=3.8'
+
+[project.optional-dependencies]
+dev = ["anyio", "iniconfig==2.0.0"]
+"#,
+    )?;
+
+    // Write to a requirements file.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("-e .[dev]")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    -e .
+        # via -r requirements.in
+    anyio==4.3.0
+        # via
+        #   example
+        #   example[dev]
+    idna==3.6
+        # via anyio
+    iniconfig==2.0.0
+        # via example[dev]
+    sniffio==1.3.1
+        # via anyio
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--annotation-style")
+        .arg("line"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --annotation-style line
+    -e .                      # via -r requirements.in
+    anyio==4.3.0              # via example, example[dev]
+    idna==3.6                 # via anyio
+    iniconfig==2.0.0          # via example[dev]
+    sniffio==1.3.1            # via anyio
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Under `--resolution=lowest-direct`, ignore optional dependencies.
 ///
 /// In the below example, ensure that `setuptools` does not resolve to the lowest-available version.
@@ -13558,7 +13958,7 @@ fn no_binary_only_binary() -> Result<()> {
       ╰─▶ Because only source-distribution>=0.0.1 is available and source-distribution==0.0.1 has no usable wheels, we can conclude that source-distribution<=0.0.1 cannot be used.
           And because you require source-distribution<=0.0.1, we can conclude that your requirements are unsatisfiable.
 
-          hint: Wheels are required for `source-distribution` because building from source is disabled for all packages (i.e., with `--no-build`)
+          hint: Building from source is disabled for all packages (i.e., with `--no-build`), but the following packages only provide source distributions: `source-distribution` (v0.0.1, v0.0.2, v0.0.3). Use `--allow-binary-fallback <package>` to allow specific packages to be built from source.
     "###
     );
 
@@ -13585,6 +13985,38 @@ fn no_binary_only_binary() -> Result<()> {
     Ok(())
 }
 
+/// Allow `--allow-binary-fallback` to permit select source distributions with `--only-binary :all:`.
+#[test]
+fn allow_binary_fallback_only_binary() -> Result<()> {
+    let context = TestContext::new("3.12").with_exclude_newer("2025-01-29T00:00:00Z");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("source-distribution<=0.0.1\niniconfig")?;
+
+    uv_snapshot!(context
+        .pip_compile()
+        .arg("requirements.in")
+        .arg("--only-binary")
+        .arg(":all:")
+        .arg("--allow-binary-fallback")
+        .arg("source-distribution"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --only-binary :all: --allow-binary-fallback source-distribution
+    iniconfig==2.0.0
+        # via -r requirements.in
+    source-distribution==0.0.1
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// `gunicorn` only depends on `eventlet` via an extra, so the resolution should succeed despite
 /// the nonsensical extra.
 #[test]
@@ -13964,7 +14396,7 @@ fn invalid_extra() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
     iniconfig==2.0.0
-        # via project
+        # via project[encryption]
     .
         # via -r requirements.in
 
@@ -14404,7 +14836,7 @@ matplotlib
     six==1.16.0
         # via python-dateutil
     tomli==2.0.1 ; python_full_version <= '3.11'
-        # via coverage
+        # via coverage[toml]
     zipp==3.18.1 ; python_full_version < '3.10'
         # via importlib-resources
 
@@ -14620,7 +15052,7 @@ fn negation_not_imply_prerelease() -> Result<()> {
 fn lowest_direct_fork_min_python() -> Result<()> {
     let context = TestContext::new("3.10");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         pycountry >= 22.1.10
         setuptools >= 50.0.0 ; python_version >= '3.12'
     "})?;
@@ -14658,7 +15090,7 @@ fn lowest_direct_fork_min_python() -> Result<()> {
 fn lowest_fork_min_python() -> Result<()> {
     let context = TestContext::new("3.10");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         anyio >= 3.0.0
         idna >= 3.0.0 ; python_version >= '3.12'
     "})?;
@@ -14698,7 +15130,7 @@ fn lowest_fork_min_python() -> Result<()> {
 fn lowest_direct_fork_max_python() -> Result<()> {
     let context = TestContext::new("3.10");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         pycountry >= 22.1.10
         setuptools >= 50.0.0 ; python_version < '3.12'
     "})?;
@@ -14732,7 +15164,7 @@ fn lowest_direct_fork_max_python() -> Result<()> {
 fn lowest_fork_max_python() -> Result<()> {
     let context = TestContext::new("3.10");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         pycountry >= 22.1.10
         setuptools >= 50.0.0 ; python_version < '3.12'
     "})?;
@@ -14921,7 +15353,7 @@ fn invalid_platform() -> Result<()> {
 fn universal_disjoint_deprecated_markers() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         anyio ; sys_platform == 'win32' and sys.platform == 'win32'
         typing-extensions ; platform.python_implementation == 'CPython' and python_implementation != 'CPython'
     "})?;
@@ -14953,7 +15385,7 @@ fn universal_disjoint_deprecated_markers() -> Result<()> {
 fn universal_disjoint_override_urls() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         anyio
     "})?;
 
@@ -14998,7 +15430,7 @@ fn universal_disjoint_override_urls() -> Result<()> {
 fn universal_conflicting_override_urls() -> Result<()> {
     let context = TestContext::new("3.12");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         anyio
     "})?;
 
@@ -15033,7 +15465,7 @@ fn compile_lowest_extra_unpinned_warning() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         all-extras-required-a[extra_b,extra_c]
         all-extras-required-b==1
         all-extras-required-c==1
@@ -15056,11 +15488,11 @@ fn compile_lowest_extra_unpinned_warning() -> Result<()> {
     all-extras-required-b==1.0.0
         # via
         #   -r requirements.in
-        #   all-extras-required-a
+        #   all-extras-required-a[extra-b]
     all-extras-required-c==1.0.0
         # via
         #   -r requirements.in
-        #   all-extras-required-a
+        #   all-extras-required-a[extra-c]
 
     ----- stderr -----
     warning: The direct dependency `all-extras-required-a` is unpinned. Consider setting a lower bound when using `--resolution lowest` or `--resolution lowest-direct` to avoid using outdated versions.
@@ -15071,13 +15503,68 @@ fn compile_lowest_extra_unpinned_warning() -> Result<()> {
     Ok(())
 }
 
+/// With `--prefer-local-versions`, a version with a local segment should be preferred over the
+/// equivalent public version, even under `--resolution lowest`.
+#[test]
+fn compile_prefer_local_versions() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("local-not-used-with-sdist-a==1.2.3")?;
+
+    // By default, the public version sorts lower than the local version.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("--resolution")
+        .arg("lowest")
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .arg(requirements_in.path())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --resolution lowest [TEMP_DIR]/requirements.in
+    local-not-used-with-sdist-a==1.2.3
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    // With `--prefer-local-versions`, the local version should be selected instead.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("--resolution")
+        .arg("lowest")
+        .arg("--prefer-local-versions")
+        .arg("--index-url")
+        .arg(packse_index_url())
+        .arg(requirements_in.path())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] --resolution lowest --prefer-local-versions [TEMP_DIR]/requirements.in
+    local-not-used-with-sdist-a==1.2.3+foo
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 #[cfg(feature = "python-eol")]
 #[test]
 fn disjoint_requires_python() -> Result<()> {
     let context = TestContext::new("3.8").with_exclude_newer("2025-01-29T00:00:00Z");
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         iniconfig ; platform_python_implementation == 'CPython' and python_version >= '3.10'
         coverage
     "})?;
@@ -15146,7 +15633,7 @@ fn dynamic_version_source_dist() -> Result<()> {
     }
 
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         foo
     "})?;
 
@@ -15177,7 +15664,7 @@ fn dynamic_version_source_dist() -> Result<()> {
 fn max_python_requirement() -> Result<()> {
     let context = TestContext::new("3.8").with_exclude_newer("2024-12-18T00:00:00Z");
     let requirements_in = context.temp_dir.child("requirements.in");
-    requirements_in.write_str(indoc::indoc! {r"
+    requirements_in.write_str(indoc! {r"
         nox >=2024.04.15
         nox[uv] >=2024.03.02; python_version >= '3.8'
     "})?;
@@ -15235,7 +15722,7 @@ fn max_python_requirement() -> Result<()> {
         #   nox
         #   platformdirs
     uv==0.5.10 ; python_full_version >= '3.8'
-        # via nox
+        # via nox[uv]
     virtualenv==20.26.6 ; python_full_version < '3.8'
         # via nox
     virtualenv==20.28.0 ; python_full_version >= '3.8'
@@ -15290,7 +15777,7 @@ fn max_python_requirement() -> Result<()> {
         #   nox
         #   platformdirs
     uv==0.5.10 ; python_full_version >= '3.8'
-        # via nox
+        # via nox[uv]
     virtualenv==20.26.6
         # via nox
     zipp==3.15.0 ; python_full_version < '3.8'
@@ -15389,7 +15876,7 @@ fn dependency_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group bar
     iniconfig==2.0.0
-        # via myproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -15408,7 +15895,7 @@ fn dependency_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --group bar
     iniconfig==2.0.0
-        # via myproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
     typing-extensions==4.10.0
         # via myproject (pyproject.toml)
 
@@ -15427,7 +15914,7 @@ fn dependency_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] [TEMP_DIR]/pyproject.toml --group bar
     iniconfig==2.0.0
-        # via myproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
     typing-extensions==4.10.0
         # via myproject (pyproject.toml)
 
@@ -15445,7 +15932,7 @@ fn dependency_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group pyproject.toml:bar
     iniconfig==2.0.0
-        # via myproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -15462,7 +15949,7 @@ fn dependency_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --group pyproject.toml:bar
     iniconfig==2.0.0
-        # via myproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
     typing-extensions==4.10.0
         # via myproject (pyproject.toml)
 
@@ -15480,7 +15967,7 @@ fn dependency_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group foo
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -15497,9 +15984,9 @@ fn dependency_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group foo --group bar
     iniconfig==2.0.0
-        # via myproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -15517,9 +16004,9 @@ fn dependency_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --group foo --group bar
     iniconfig==2.0.0
-        # via myproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
     typing-extensions==4.10.0
         # via myproject (pyproject.toml)
 
@@ -15530,6 +16017,67 @@ fn dependency_group() -> Result<()> {
     Ok(())
 }
 
+/// Requirements introduced through a dependency group are annotated with the group, in both
+/// annotation styles.
+#[test]
+fn dependency_group_annotation() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "myproject"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==4.3.0"]
+        [dependency-groups]
+        dev = ["sniffio"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--group").arg("dev")
+        .arg("--annotation-style").arg("line"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --group dev --annotation-style line
+    anyio==4.3.0              # via myproject (pyproject.toml)
+    idna==3.6                 # via anyio
+    sniffio==1.3.1            # via anyio, group:dev (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--group").arg("dev")
+        .arg("--annotation-style").arg("split"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --group dev --annotation-style split
+    anyio==4.3.0
+        # via myproject (pyproject.toml)
+    idna==3.6
+        # via anyio
+    sniffio==1.3.1
+        # via
+        #   group:dev (pyproject.toml)
+        #   anyio
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn many_pyproject_group() -> Result<()> {
     // uv pip compile --group tests, with multiple pyproject.tomls at once
@@ -15580,7 +16128,7 @@ fn many_pyproject_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group pyproject.toml:foo
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -15595,7 +16143,7 @@ fn many_pyproject_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group subdir/pyproject.toml:foo
     iniconfig==2.0.0
-        # via mysubproject (subdir/pyproject.toml:foo)
+        # via group:foo (subdir/pyproject.toml)
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -15613,9 +16161,9 @@ fn many_pyproject_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group pyproject.toml:foo --group subdir/pyproject.toml:foo
     iniconfig==2.0.0
-        # via mysubproject (subdir/pyproject.toml:foo)
+        # via group:foo (subdir/pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -15676,9 +16224,9 @@ fn suspicious_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group foo --group subdir/pyproject.toml:foo
     iniconfig==2.0.0
-        # via mysubproject (subdir/pyproject.toml:foo)
+        # via group:foo (subdir/pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -15697,9 +16245,9 @@ fn suspicious_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group foo --group subdir/pyproject.toml:bar
     sniffio==1.3.1
-        # via mysubproject (subdir/pyproject.toml:bar)
+        # via group:bar (subdir/pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -15719,7 +16267,7 @@ fn suspicious_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] subdir/pyproject.toml --group foo
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
     typing-extensions==4.10.0
         # via mysubproject (subdir/pyproject.toml)
 
@@ -15739,7 +16287,7 @@ fn suspicious_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --group subdir/pyproject.toml:foo
     iniconfig==2.0.0
-        # via mysubproject (subdir/pyproject.toml:foo)
+        # via group:foo (subdir/pyproject.toml)
     typing-extensions==4.10.0
         # via myproject (pyproject.toml)
 
@@ -15926,17 +16474,17 @@ fn project_and_group_workspace_inherit() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --group packages/mysubproject/pyproject.toml:foo
     -e file://[TEMP_DIR]/packages/pytest
-        # via mysubproject (packages/mysubproject/pyproject.toml:foo)
+        # via group:foo (packages/mysubproject/pyproject.toml)
     -e file://[TEMP_DIR]/packages/sniffio
         # via
-        #   mysubproject (packages/mysubproject/pyproject.toml:foo)
+        #   group:foo (packages/mysubproject/pyproject.toml)
         #   anyio
     anyio==4.3.0
-        # via mysubproject (packages/mysubproject/pyproject.toml:foo)
+        # via group:foo (packages/mysubproject/pyproject.toml)
     idna==3.6
         # via anyio
     iniconfig==2.0.0
-        # via mysubproject (packages/mysubproject/pyproject.toml:foo)
+        # via group:foo (packages/mysubproject/pyproject.toml)
 
     ----- stderr -----
     Resolved 5 packages in [TIME]
@@ -15995,7 +16543,7 @@ fn project_and_group_workspace() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --project subdir --group foo
     iniconfig==2.0.0
-        # via mysubproject (subdir/pyproject.toml:foo)
+        # via group:foo (subdir/pyproject.toml)
 
     ----- stderr -----
     Resolved 1 package in [TIME]
@@ -16015,9 +16563,9 @@ fn project_and_group_workspace() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --project subdir --group subdir/pyproject.toml:bar --group foo
     iniconfig==2.0.0
-        # via mysubproject (subdir/pyproject.toml:foo)
+        # via group:foo (subdir/pyproject.toml)
     sniffio==1.3.1
-        # via mysubproject (subdir/pyproject.toml:bar)
+        # via group:bar (subdir/pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -16037,9 +16585,9 @@ fn project_and_group_workspace() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --project subdir --group bar --group pyproject.toml:foo
     sniffio==1.3.1
-        # via mysubproject (subdir/pyproject.toml:bar)
+        # via group:bar (subdir/pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -16059,9 +16607,9 @@ fn project_and_group_workspace() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --project subdir --group subdir/pyproject.toml:bar --group pyproject.toml:foo
     sniffio==1.3.1
-        # via mysubproject (subdir/pyproject.toml:bar)
+        # via group:bar (subdir/pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (pyproject.toml:foo)
+        # via group:foo (pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -16123,9 +16671,9 @@ fn directory_and_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --directory subdir --group bar --group ../pyproject.toml:foo
     sniffio==1.3.1
-        # via mysubproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (../pyproject.toml:foo)
+        # via group:foo (../pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -16145,9 +16693,9 @@ fn directory_and_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --directory subdir --group pyproject.toml:bar --group ../pyproject.toml:foo
     sniffio==1.3.1
-        # via mysubproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject (../pyproject.toml:foo)
+        # via group:foo (../pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -16168,9 +16716,9 @@ fn directory_and_group() -> Result<()> {
     # This file was autogenerated by uv via the following command:
     #    uv pip compile --cache-dir [CACHE_DIR] --directory subdir --project ../ --group pyproject.toml:bar --group foo
     sniffio==1.3.1
-        # via mysubproject (pyproject.toml:bar)
+        # via group:bar (pyproject.toml)
     sortedcontainers==2.4.0
-        # via myproject ([TEMP_DIR]/pyproject.toml:foo)
+        # via group:foo ([TEMP_DIR]/pyproject.toml)
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -16257,7 +16805,7 @@ fn markers_on_extra_packages() -> Result<()> {
     psycopg==3.1.18
         # via -r requirements.in
     psycopg-binary==3.1.18 ; implementation_name != 'pypy' and platform_python_implementation != 'PyPy'
-        # via psycopg
+        # via psycopg[binary]
     typing-extensions==4.10.0
         # via psycopg
     tzdata==2024.1 ; sys_platform == 'win32'
@@ -17671,7 +18219,7 @@ fn pubgrub_panic_double_self_dependency_extra() -> Result<()> {
     .
         # via -r requirements.in
     iniconfig==2.0.0
-        # via foo
+        # via foo[test]
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
@@ -17756,3 +18304,172 @@ fn omit_python_patch_universal() -> Result<()> {
 
     Ok(())
 }
+
+/// Configuration discovery stops at the root of the enclosing Git repository, unless
+/// `--no-config-boundary` is provided.
+#[test]
+fn compile_config_discovery_repository_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Write an invalid `uv.toml` above the repository root.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str("managed = true")?;
+
+    let repository = context.temp_dir.child("repository");
+    repository.child(".git").create_dir_all()?;
+    let requirements_in = repository.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .current_dir(&repository), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    // With `--no-config-boundary`, the `uv.toml` above the repository root is discovered.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-config-boundary")
+        .current_dir(&repository), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `[TEMP_DIR]/uv.toml`. The `managed` field is not allowed in a `uv.toml` file. `managed` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.
+    "
+    );
+
+    Ok(())
+}
+
+/// Configuration discovery stops at the workspace root, even outside of a Git repository, unless
+/// `--no-config-boundary` is provided.
+#[test]
+fn compile_config_discovery_workspace_root() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Write an invalid `uv.toml` above the workspace root.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str("managed = true")?;
+
+    // Create a project without a `[tool.uv]` table, which is not a Git checkout.
+    let workspace = context.temp_dir.child("workspace");
+    workspace.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+    let requirements_in = workspace.child("requirements.in");
+    requirements_in.write_str("iniconfig")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .current_dir(&workspace), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    iniconfig==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    // With `--no-config-boundary`, the `uv.toml` above the workspace root is discovered.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-config-boundary")
+        .current_dir(&workspace), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `[TEMP_DIR]/uv.toml`. The `managed` field is not allowed in a `uv.toml` file. `managed` is only applicable in the context of a project, and should be placed in a `pyproject.toml` file instead.
+    "
+    );
+
+    Ok(())
+}
+
+/// Compile a `pyproject.toml` from outside its directory; relative paths in its requirements,
+/// sources, and dependency groups should be resolved against the directory containing it.
+#[test]
+fn compile_pyproject_toml_outside_project_dir() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let projects = context.temp_dir.child("projects");
+    for name in ["lib-a", "lib-b", "lib-c"] {
+        projects
+            .child(name)
+            .child("pyproject.toml")
+            .write_str(&indoc::formatdoc! {r#"
+                [project]
+                name = "{name}"
+                version = "0.1.0"
+                requires-python = ">=3.12"
+                dependencies = []
+            "#})?;
+    }
+
+    projects
+        .child("service-a")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+            [project]
+            name = "service-a"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["lib-a @ ../lib-a"]
+
+            [project.optional-dependencies]
+            docs = ["lib-b"]
+
+            [dependency-groups]
+            test = ["lib-c @ ../lib-c"]
+            dev = [{ include-group = "test" }, "iniconfig"]
+
+            [tool.uv.sources]
+            lib-b = { path = "../lib-b" }
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("projects/service-a/pyproject.toml")
+        .arg("--extra").arg("docs")
+        .arg("--group").arg("projects/service-a/pyproject.toml:dev"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] projects/service-a/pyproject.toml --extra docs --group projects/service-a/pyproject.toml:dev
+    iniconfig==2.0.0
+        # via group:dev (projects/service-a/pyproject.toml)
+    ../lib-a
+        # via service-a (projects/service-a/pyproject.toml)
+    ../lib-b
+        # via service-a (projects/service-a/pyproject.toml)
+    ../lib-c
+        # via group:dev (projects/service-a/pyproject.toml)
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::io::Cursor;
 use std::process::Command;
 
@@ -19,7 +20,7 @@ use wiremock::{
 use crate::common::{self, decode_token};
 use crate::common::{
     DEFAULT_PYTHON_VERSION, TestContext, build_vendor_links_url, download_to_disk, get_bin,
-    uv_snapshot, venv_bin_path,
+    site_packages_path, uv_snapshot, venv_bin_path,
 };
 use uv_fs::Simplified;
 use uv_static::EnvVars;
@@ -1985,6 +1986,54 @@ fn install_git_public_https() {
     context.assert_installed("uv_public_pypackage", "0.1.0");
 }
 
+/// Git dependencies pinned to a reference should be fetched without their history.
+#[test]
+#[cfg(feature = "git")]
+fn install_git_public_https_shallow() -> Result<()> {
+    let context = TestContext::new(DEFAULT_PYTHON_VERSION);
+
+    uv_snapshot!(
+        context
+        .pip_install()
+        .arg("uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0.0.1"),
+        @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + uv-public-pypackage==0.1.0 (from git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979)
+    ");
+
+    // The database should only contain the tagged commit. The resolved commit is the same as
+    // with a full clone.
+    let db = fs_err::read_dir(context.cache_dir.join("git-v0").join("db"))?
+        .next()
+        .unwrap()?
+        .path();
+    let output = Command::new("git")
+        .arg("rev-parse")
+        .arg("--is-shallow-repository")
+        .current_dir(&db)
+        .output()?;
+    assert_eq!(String::from_utf8(output.stdout)?.trim(), "true");
+
+    let output = Command::new("git")
+        .arg("rev-list")
+        .arg("--count")
+        .arg("--all")
+        .current_dir(&db)
+        .output()?;
+    assert_eq!(String::from_utf8(output.stdout)?.trim(), "1");
+
+    context.assert_installed("uv_public_pypackage", "0.1.0");
+
+    Ok(())
+}
+
 /// Install a package from a public GitHub repository, omitting the `git+` prefix
 #[test]
 #[cfg(feature = "git")]
@@ -3393,6 +3442,74 @@ fn install_upgrade() {
     );
 }
 
+/// Upgrade a package with `--upgrade-strategy only-if-needed`, retaining the installed versions of
+/// its dependencies.
+#[test]
+fn install_upgrade_strategy() {
+    let context = TestContext::new("3.12");
+
+    // Install an old version of anyio and its dependencies.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio==3.6.2")
+        .arg("idna==3.4")
+        .arg("sniffio==1.3.0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==3.6.2
+     + idna==3.4
+     + sniffio==1.3.0
+    "
+    );
+
+    // Upgrade anyio, but leave its dependencies at their installed versions.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--upgrade")
+        .arg("--upgrade-strategy")
+        .arg("only-if-needed"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==3.6.2
+     + anyio==4.3.0
+    "
+    );
+
+    // Upgrade anyio eagerly, which upgrades its dependencies too.
+    uv_snapshot!(context.pip_install()
+        .arg("anyio")
+        .arg("--upgrade")
+        .arg("--upgrade-strategy")
+        .arg("eager"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     - idna==3.4
+     + idna==3.6
+     - sniffio==1.3.0
+     + sniffio==1.3.1
+    "
+    );
+}
+
 /// Install a package from a `requirements.txt` file, with a `constraints.txt` file.
 #[test]
 fn install_constraints_txt() -> Result<()> {
@@ -4057,6 +4174,45 @@ fn config_settings_path() -> Result<()> {
     Ok(())
 }
 
+/// Respect `--config-settings` entries in a `requirements.txt` file.
+#[test]
+fn config_settings_requirements_txt() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "--config-settings editable_mode=compat\n-e {}",
+        context
+            .workspace_root
+            .join("scripts/packages/setuptools_editable")
+            .display()
+    ))?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###
+    );
+
+    // When installed with `editable_mode=compat`, the `finder.py` file should _not_ be present.
+    let finder = context
+        .site_packages()
+        .join("__editable___setuptools_editable_0_1_0_finder.py");
+    assert!(!finder.exists());
+
+    Ok(())
+}
+
 /// Reinstall a duplicate package in a virtual environment.
 #[test]
 fn reinstall_duplicate() -> Result<()> {
@@ -6028,6 +6184,8 @@ fn already_installed_dependent_editable() {
       × No solution found when resolving dependencies:
       ╰─▶ Because first-local was not found in the provided package locations and second-local==0.1.0 depends on first-local, we can conclude that second-local==0.1.0 cannot be used.
           And because only second-local==0.1.0 is available and you require second-local, we can conclude that your requirements are unsatisfiable.
+
+          hint: `first-local` was not found in the provided package locations (https://raw.githubusercontent.com/astral-sh/packse/PACKSE_VERSION/vendor/links.html), and index lookups were disabled (i.e., with `--no-index`)
     "###
     );
 
@@ -6147,6 +6305,8 @@ fn already_installed_local_path_dependent() {
       × No solution found when resolving dependencies:
       ╰─▶ Because first-local was not found in the provided package locations and second-local==0.1.0 depends on first-local, we can conclude that second-local==0.1.0 cannot be used.
           And because only second-local==0.1.0 is available and you require second-local, we can conclude that your requirements are unsatisfiable.
+
+          hint: `first-local` was not found in the provided package locations (https://raw.githubusercontent.com/astral-sh/packse/PACKSE_VERSION/vendor/links.html), and index lookups were disabled (i.e., with `--no-index`)
     "###
     );
 
@@ -6601,6 +6761,31 @@ fn find_links() {
     );
 }
 
+/// Install using `--find-links` with a local directory and `--no-index`, where a requirement is
+/// missing from the local directory.
+#[test]
+fn find_links_no_index_missing() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm")
+        .arg("iniconfig")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("scripts/links/")), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because iniconfig was not found in the provided package locations and you require iniconfig, we can conclude that your requirements are unsatisfiable.
+
+          hint: `iniconfig` was not found in the provided package locations (file://[WORKSPACE]/scripts/links/), and index lookups were disabled (i.e., with `--no-index`)
+    "
+    );
+}
+
 /// Sync using `--find-links` with a local directory, with wheels disabled.
 #[test]
 fn find_links_no_binary() {
@@ -6775,6 +6960,144 @@ fn require_hashes_missing_dependency() -> Result<()> {
     Ok(())
 }
 
+/// Report every requirement that lacks a hash, along with its computed hashes, under
+/// `--derive-hashes`.
+#[test]
+fn derive_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Write to a requirements file, omitting the hashes for `idna` and `sniffio`.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0 \
+            --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+        idna==3.6
+        sniffio==1.3.1
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--derive-hashes"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    error: In `--derive-hashes` mode, all requirements must have a hash, but none were provided for the following distributions. Add the computed hashes to your requirements:
+        idna==3.6 \
+            --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+            --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        sniffio==1.3.1 \
+            --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+            --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+    "
+    );
+
+    // Add the reported hashes.
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0 \
+            --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a
+        idna==3.6 \
+            --hash=sha256:9ecdbbd083b06798ae1e86adcbfe8ab1479cf864e4ee30fe4e46a003d12491ca \
+            --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+        sniffio==1.3.1 \
+            --hash=sha256:2f6da418d1f1e0fddd844478f41680e794e6051915791a034ff65e5f100525a2 \
+            --hash=sha256:f4324edc670a0f49750a81b895f35c3adb843cca46f0530f79fc1babb23789dc
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--derive-hashes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + anyio==4.0.0
+     + idna==3.6
+     + sniffio==1.3.1
+    "
+    );
+
+    // Local directories can't be hashed, so they should be reported rather than skipped.
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        file://{workspace_root}/scripts/packages/anyio_local
+        ",
+        workspace_root = context.workspace_root.simplified_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--derive-hashes"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: In `--derive-hashes` mode, all requirements must have a hash, but none were provided for the following distributions. Add the computed hashes to your requirements:
+        anyio @ file://[WORKSPACE]/scripts/packages/anyio_local (unhashable: no hashes could be computed)
+    "
+    );
+
+    Ok(())
+}
+
+/// Reject requirements that can only be verified with an insecure hash algorithm.
+#[test]
+fn require_hashes_insecure_algorithm() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Write to a requirements file.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("idna==3.6 --hash=md5:a8d4e9af2bfcfe30b3a2d3d1b7a6c8b4")?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Hash-checking requires a digest using a secure algorithm (e.g., `sha256`), but only insecure digests (md5) were provided for: idna==3.6
+    "
+    );
+
+    // Alongside a secure digest, the `md5` digest is accepted.
+    requirements_txt.write_str(indoc::indoc! {r"
+        idna==3.6 \
+            --hash=md5:a8d4e9af2bfcfe30b3a2d3d1b7a6c8b4 \
+            --hash=sha256:c05567e9c24a6b9faaa835c4821bad0590fbb9d5779e7caa6e1cc4978e7eb24f
+    "})?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "
+    );
+
+    Ok(())
+}
+
 /// We disallow `--require-hashes` for editables' dependencies.
 #[test]
 fn require_hashes_editable() -> Result<()> {
@@ -6801,6 +7124,23 @@ fn require_hashes_editable() -> Result<()> {
     "###
     );
 
+    // With `--allow-unhashable-sources`, the editable itself is exempt, but its dependencies are
+    // not.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .arg("--allow-unhashable-sources"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping hash verification for `file://[WORKSPACE]/scripts/packages/black_editable[d]`: hash-checking is not supported for local directories
+    error: In `--require-hashes` mode, all requirements must be pinned upfront with `==`, but found: `aiohttp`
+    "###
+    );
+
     Ok(())
 }
 
@@ -10223,6 +10563,64 @@ fn project_and_group() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn only_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "myproject"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+        [dependency-groups]
+        foo = ["sortedcontainers"]
+        bar = ["iniconfig", {include-group = "foo"}]
+        "#,
+    )?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("sniffio")?;
+
+    // The project's dependencies and the requirements are excluded.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r").arg("pyproject.toml")
+        .arg("-r").arg("requirements.txt")
+        .arg("--only-group").arg("bar"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + sortedcontainers==2.4.0
+    ");
+
+    // Requirements and groups are resolved together with `--group`.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r").arg("pyproject.toml")
+        .arg("-r").arg("requirements.txt")
+        .arg("--group").arg("foo"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + sniffio==1.3.1
+     + typing-extensions==4.10.0
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn directory_and_group() -> Result<()> {
     // Checking that --directory is handled properly with --group
@@ -11868,6 +12266,49 @@ fn strip_shebang_arguments() -> Result<()> {
     Ok(())
 }
 
+/// Install a package with scripts into a `--prefix` directory.
+#[test]
+#[cfg(unix)]
+fn install_prefix_scripts() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let prefix = context.temp_dir.child("prefix");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm==4.66.2")
+        .arg("--prefix")
+        .arg(prefix.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==4.66.2
+    ");
+
+    // The package should be installed using the interpreter's scheme, relative to the prefix.
+    assert!(
+        site_packages_path(prefix.path(), "python3.12")
+            .join("tqdm")
+            .is_dir()
+    );
+
+    // The scripts should reference the interpreter provided via `--python`, rather than an
+    // interpreter within the prefix.
+    let script_content = fs::read_to_string(venv_bin_path(prefix.path()).join("tqdm"))?;
+    let shebang = script_content.lines().next().unwrap_or_default();
+
+    insta::with_settings!({filters => context.filters()
+    }, {
+        insta::assert_snapshot!(shebang, @"#![VENV]/bin/python3");
+    });
+
+    Ok(())
+}
+
 #[test]
 fn install_python_preference() {
     let context =
@@ -12412,3 +12853,148 @@ fn pip_install_build_dependencies_respect_locked_versions() -> Result<()> {
 
     Ok(())
 }
+
+/// Emit progress events as newline-delimited JSON with `--progress json`.
+#[test]
+fn install_progress_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let output = context
+        .pip_install()
+        .arg("anyio==3.7.0")
+        .arg("--progress")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+
+    let events = String::from_utf8(output.stderr)?
+        .lines()
+        .filter(|line| line.starts_with('{'))
+        .map(serde_json::from_str::<serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Every phase reports its completion, along with a duration.
+    let completed = events
+        .iter()
+        .filter(|event| event["event"] == "complete")
+        .inspect(|event| assert!(event["duration_ms"].is_u64()))
+        .map(|event| event["phase"].as_str().unwrap())
+        .collect::<BTreeSet<_>>();
+    insta::assert_snapshot!(completed.into_iter().collect::<Vec<_>>().join("\n"), @r"
+    download
+    install
+    prepare
+    resolve
+    ");
+
+    // Each installed wheel is reported as progress on the install phase.
+    let mut installed = events
+        .iter()
+        .filter(|event| event["event"] == "progress" && event["phase"] == "install")
+        .map(|event| event["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    installed.sort_unstable();
+    insta::assert_snapshot!(installed.join("\n"), @r"
+    anyio==3.7.0
+    idna==3.6
+    sniffio==1.3.1
+    ");
+
+    Ok(())
+}
+
+/// Install into a `--target` directory, ignoring the packages in the host environment.
+#[test]
+fn install_target() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    // Install an older `iniconfig` into the virtual environment.
+    context
+        .pip_install()
+        .arg("iniconfig==1.1.1")
+        .assert()
+        .success();
+
+    // The `--target` directory should be populated regardless.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==1.1.1")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    ");
+
+    // The package and its `.dist-info` should be at the top-level of the directory.
+    let target = context.temp_dir.child("target");
+    assert!(target.child("iniconfig").is_dir());
+    assert!(target.child("iniconfig-1.1.1.dist-info").is_dir());
+
+    // Re-installing should be a no-op.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Audited 1 package in [TIME]
+    ");
+
+    // With `--upgrade`, the existing package should be replaced.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--upgrade")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    ");
+    assert!(!target.child("iniconfig-1.1.1.dist-info").exists());
+    assert!(target.child("iniconfig-2.0.0.dist-info").is_dir());
+
+    // The directory should be importable by augmenting the `PYTHONPATH`.
+    context
+        .python_command()
+        .arg("-c")
+        .arg("import importlib.metadata; assert importlib.metadata.version('iniconfig') == '2.0.0'")
+        .env(EnvVars::PYTHONPATH, target.path())
+        .current_dir(&context.temp_dir)
+        .assert()
+        .success();
+
+    // The virtual environment should be unaffected.
+    uv_snapshot!(context.filters(), context.pip_freeze(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==1.1.1
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
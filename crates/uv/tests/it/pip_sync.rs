@@ -254,6 +254,69 @@ fn install_symlink() -> Result<()> {
     Ok(())
 }
 
+/// Uninstall a package that was installed using symlink semantics, without removing the files
+/// from the cache.
+#[test]
+#[cfg(unix)] // Windows does not allow symlinks by default
+fn uninstall_symlink() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("symlink")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    // The package files should be symlinked into the cache, but the `.dist-info` directory should
+    // be copied, since it's modified during installation.
+    let site_packages = context.site_packages();
+    let module = site_packages.join("markupsafe").join("__init__.py");
+    assert!(fs::symlink_metadata(&module)?.file_type().is_symlink());
+    let target = fs::canonicalize(&module)?;
+
+    let dist_info = site_packages.join("MarkupSafe-2.1.3.dist-info");
+    for file in ["RECORD", "METADATA", "INSTALLER"] {
+        assert!(
+            !fs::symlink_metadata(dist_info.join(file))?
+                .file_type()
+                .is_symlink()
+        );
+    }
+
+    uv_snapshot!(context.pip_uninstall()
+        .arg("markupsafe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "###
+    );
+
+    // The symlinks should be removed, but the files in the cache should be retained.
+    assert!(!site_packages.join("markupsafe").exists());
+    assert!(!dist_info.exists());
+    assert!(target.is_file());
+
+    Ok(())
+}
+
 /// Reject attempts to use symlink semantics with `--no-cache`.
 #[test]
 fn install_symlink_no_cache() -> Result<()> {
@@ -348,6 +411,63 @@ fn noop() -> Result<()> {
     Ok(())
 }
 
+/// Sync a requirement with an extra whose dependencies aren't listed in the requirements file. The
+/// installed dependencies of the extra should be retained, rather than removed as extraneous.
+#[test]
+fn sync_extra_dependencies() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("flask[dotenv]==3.0.2")
+        .arg("python-dotenv==1.0.0")
+        .assert()
+        .success();
+
+    // `python-dotenv` is only required by the `dotenv` extra, and isn't listed.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        blinker==1.7.0
+        click==8.1.7
+        flask[dotenv]==3.0.2
+        itsdangerous==2.1.2
+        jinja2==3.1.3
+        markupsafe==2.1.5
+        werkzeug==3.0.1
+    "})?;
+
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    Audited 8 packages in [TIME]
+    "###
+    );
+
+    // Syncing again should be a no-op.
+    uv_snapshot!(context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    Audited 8 packages in [TIME]
+    "###
+    );
+
+    context.assert_command("import dotenv").success();
+
+    Ok(())
+}
+
 /// Attempt to sync an empty set of requirements.
 #[test]
 fn pip_sync_empty() -> Result<()> {
@@ -1073,6 +1193,84 @@ fn warn_on_yanked_dry_run() -> Result<()> {
     Ok(())
 }
 
+/// Report the planned changes of a dry run as JSON.
+#[test]
+fn dry_run_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0\nidna==3.6\nsniffio==1.3.1")?;
+
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Downgrade `anyio`, keep `idna`, remove `sniffio`, and add `iniconfig`.
+    requirements_txt.write_str("anyio==3.7.0\nidna==3.6\niniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--output-format")
+        .arg("json"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "anyio",
+        "action": "downgrade",
+        "from": "4.0.0",
+        "to": "3.7.0"
+      },
+      {
+        "name": "idna",
+        "action": "satisfied",
+        "from": "3.6",
+        "to": "3.6"
+      },
+      {
+        "name": "iniconfig",
+        "action": "install",
+        "to": "2.0.0"
+      },
+      {
+        "name": "sniffio",
+        "action": "uninstall",
+        "from": "1.3.1"
+      }
+    ]
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Would download 2 packages
+    Would uninstall 2 packages
+    Would install 2 packages
+     - anyio==4.0.0
+     + anyio==3.7.0
+     + iniconfig==2.0.0
+     - sniffio==1.3.1
+    "#
+    );
+
+    // The environment is unchanged.
+    context.assert_command("import sniffio").success();
+
+    // `--output-format` requires `--dry-run`.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .arg("--output-format")
+        .arg("json")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("--dry-run"));
+
+    Ok(())
+}
+
 /// Resolve a local wheel.
 #[test]
 fn install_local_wheel() -> Result<()> {
@@ -1428,7 +1626,7 @@ fn install_url_source_dist_cached() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Removed [N] files ([SIZE])
+    Removed [N] files for source-distribution ([SIZE])
     "###
     );
 
@@ -1523,7 +1721,7 @@ fn install_git_source_dist_cached() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    No cache entries found
+    No cache entries found for werkzeug
     "###
     );
 
@@ -1611,7 +1809,7 @@ fn install_registry_source_dist_cached() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Removed [N] files ([SIZE])
+    Removed [N] files for source-distribution ([SIZE])
     "###
     );
 
@@ -1711,7 +1909,7 @@ fn install_path_source_dist_cached() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Removed [N] files ([SIZE])
+    Removed [N] files for source-distribution ([SIZE])
     "###
     );
 
@@ -1805,7 +2003,7 @@ fn install_path_built_dist_cached() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Removed [N] files ([SIZE])
+    Removed [N] files for tomli ([SIZE])
     "###
     );
 
@@ -1897,7 +2095,7 @@ fn install_url_built_dist_cached() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    Removed [N] files ([SIZE])
+    Removed [N] files for tqdm ([SIZE])
     "###
     );
 
@@ -4317,6 +4515,39 @@ fn require_hashes_editable() -> Result<()> {
     Ok(())
 }
 
+/// With `--allow-unhashable-sources`, local directories (like editables) are exempt from
+/// `--require-hashes`, since they can't be hashed.
+#[test]
+fn require_hashes_editable_allow_unhashable_sources() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        -e file://{workspace_root}/scripts/packages/black_editable[d]
+        ",
+        workspace_root = context.workspace_root.simplified_display(),
+    })?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg(requirements_txt.path())
+        .arg("--require-hashes")
+        .arg("--allow-unhashable-sources"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping hash verification for `file://[WORKSPACE]/scripts/packages/black_editable[d]`: hash-checking is not supported for local directories
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + black==0.1.0 (from file://[WORKSPACE]/scripts/packages/black_editable)
+    "###
+    );
+
+    Ok(())
+}
+
 /// If a dependency is repeated, the hash should be required for both instances.
 #[test]
 fn require_hashes_repeated_dependency() -> Result<()> {
@@ -5399,6 +5630,65 @@ fn target_no_build_isolation() -> Result<()> {
     Ok(())
 }
 
+/// Sync to a `--target` directory with a package that requires building from source, along with
+/// `--no-build-isolation-package`.
+#[test]
+fn target_no_build_isolation_package() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_python_names()
+        .with_filtered_virtualenv_bin()
+        .with_filtered_exe_suffix();
+
+    // Install `flit_core` into the current environment.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flit_core")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + flit-core==3.9.0
+    ");
+
+    // Build `wheel` without isolation, using the `flit_core` from the current environment.
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("wheel")?;
+
+    uv_snapshot!(context.filters(), context.pip_sync()
+        .arg("requirements.in")
+        .arg("--no-build-isolation-package")
+        .arg("wheel")
+        .arg("--no-binary")
+        .arg("wheel")
+        .arg("--target")
+        .arg("target"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: .venv/[BIN]/[PYTHON]
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + wheel==0.43.0
+    ");
+
+    // Ensure that the build requirements are not present in the target directory.
+    assert!(!context.temp_dir.child("target").child("flit_core").is_dir());
+
+    // Ensure that the package is present in the target directory.
+    assert!(context.temp_dir.child("target").child("wheel").is_dir());
+
+    Ok(())
+}
+
 /// Sync to a `--target` directory without a virtual environment.
 #[test]
 fn target_system() -> Result<()> {
@@ -5,6 +5,8 @@ use indoc::indoc;
 use std::env;
 use std::env::current_dir;
 use uv_static::EnvVars;
+use wiremock::matchers::{basic_auth, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[test]
 fn username_password_no_longer_supported() {
@@ -387,3 +389,107 @@ fn invalid_index() {
     "###
     );
 }
+
+/// Start a simple index at `/simple/` that lists `ok-1.0.0-py3-none-any.whl`, but requires
+/// authentication.
+async fn check_url_server() -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/simple/ok/"))
+        .and(basic_auth("user", "pass"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(
+                indoc! {r#"
+                    <!DOCTYPE html>
+                    <html>
+                      <body>
+                        <a href="/files/ok-1.0.0-py3-none-any.whl#sha256=79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f">ok-1.0.0-py3-none-any.whl</a>
+                      </body>
+                    </html>
+                "#},
+                "text/html",
+            ),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/simple/ok/"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+    server
+}
+
+/// The check URL is queried with the credentials from the `.netrc` file.
+#[tokio::test]
+async fn check_url_netrc() {
+    let context = TestContext::new("3.12");
+    let server = check_url_server().await;
+    let uri = server.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(uri.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    let netrc = context.temp_dir.child(".netrc");
+    netrc
+        .write_str("machine 127.0.0.1 login user password pass")
+        .unwrap();
+
+    uv_snapshot!(filters, context.publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--check-url")
+        .arg(format!("{uri}/simple/"))
+        .arg("--publish-url")
+        .arg(format!("{uri}/upload"))
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .env(EnvVars::NETRC, netrc.path()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to [SERVER]/upload
+    File ok-1.0.0-py3-none-any.whl already exists, skipping
+    "
+    );
+}
+
+/// An authentication failure on the check URL is an error, rather than a missing file.
+#[tokio::test]
+async fn check_url_unauthorized() {
+    let context = TestContext::new("3.12");
+    let server = check_url_server().await;
+    let uri = server.uri();
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(uri.as_str(), "[SERVER]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.publish()
+        .arg("-u")
+        .arg("dummy")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--check-url")
+        .arg(format!("{uri}/simple/"))
+        .arg("--publish-url")
+        .arg(format!("{uri}/upload"))
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Publishing 1 file to [SERVER]/upload
+    error: Failed to query check URL `[SERVER]/simple/` due to an authentication failure (401 Unauthorized)
+
+    hint: Provide credentials for the check URL via a `.netrc` file, the keyring (`--keyring-provider subprocess`), or, for a named index, the `UV_INDEX_<NAME>_USERNAME` and `UV_INDEX_<NAME>_PASSWORD` environment variables
+    "
+    );
+}
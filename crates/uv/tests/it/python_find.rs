@@ -799,6 +799,75 @@ fn python_find_managed() {
     ");
 }
 
+#[test]
+fn python_find_managed_only_system_only() {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"])
+        .with_filtered_python_sources()
+        .with_versions_as_managed(&["3.11"]);
+
+    // Create a virtual environment, which is ignored by both filters.
+    context
+        .venv()
+        .arg("--python")
+        .arg("3.12")
+        .assert()
+        .success();
+
+    // Only the managed interpreter is considered.
+    uv_snapshot!(context.filters(), context.python_find().arg("--managed-only"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.11]
+
+    ----- stderr -----
+    ");
+
+    // The managed interpreter does not satisfy the request.
+    uv_snapshot!(context.filters(), context.python_find().arg("--managed-only").arg("3.12"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.12 in managed installations
+    ");
+
+    // Only the unmanaged interpreter is considered.
+    uv_snapshot!(context.filters(), context.python_find().arg("--system-only"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [PYTHON-3.12]
+
+    ----- stderr -----
+    ");
+
+    // The unmanaged interpreter does not satisfy the request.
+    uv_snapshot!(context.filters(), context.python_find().arg("--system-only").arg("3.11"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found for Python 3.11 in [PYTHON SOURCES]
+    ");
+
+    // The filters are mutually exclusive.
+    uv_snapshot!(context.filters(), context.python_find().arg("--managed-only").arg("--system-only"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--managed-only' cannot be used with '--system-only'
+
+    Usage: uv python find --cache-dir [CACHE_DIR] [REQUEST]
+
+    For more information, try '--help'.
+    ");
+}
+
 /// See: <https://github.com/astral-sh/uv/issues/11825>
 ///
 /// This test will not succeed on macOS if using a Homebrew provided interpreter. The interpreter
@@ -193,6 +193,91 @@ fn python_reinstall() {
     ");
 }
 
+/// Break a managed installation by removing its `_ssl` extension module, then check and repair it.
+#[cfg(unix)]
+#[test]
+fn python_reinstall_broken() -> anyhow::Result<()> {
+    use uv_python::managed::platform_key_from_env;
+
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs()
+        .with_python_download_cache();
+
+    // Install a couple versions
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12").arg("3.13"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 2 versions in [TIME]
+     + cpython-3.12.11-[PLATFORM] (python3.12)
+     + cpython-3.13.6-[PLATFORM] (python3.13)
+    ");
+
+    // Remove the `_ssl` module from Python 3.13.
+    let lib_dynload = context
+        .temp_dir
+        .child("managed")
+        .child(format!("cpython-3.13.6-{}", platform_key_from_env()?))
+        .child("lib")
+        .child("python3.13")
+        .child("lib-dynload");
+    for entry in fs_err::read_dir(&lib_dynload)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().starts_with("_ssl.") {
+            fs_err::remove_file(entry.path())?;
+        }
+    }
+
+    uv_snapshot!(context.filters(), context.python_check(), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    cpython-3.12.11-[PLATFORM] ok
+    cpython-3.13.6-[PLATFORM] broken (ModuleNotFoundError: No module named '_ssl')
+
+    ----- stderr -----
+    Found 1 broken installation; run `uv python install --reinstall-broken` to repair
+    ");
+
+    // Only the broken installation should be reinstalled.
+    uv_snapshot!(context.filters(), context.python_install().arg("--reinstall-broken"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Found broken installation `cpython-3.13.6-[PLATFORM]`: ModuleNotFoundError: No module named '_ssl'
+    Installed Python 3.13.6 in [TIME]
+     ~ cpython-3.13.6-[PLATFORM] (python3.13)
+    ");
+
+    uv_snapshot!(context.filters(), context.python_check(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.11-[PLATFORM] ok
+    cpython-3.13.6-[PLATFORM] ok
+
+    ----- stderr -----
+    ");
+
+    // Nothing left to repair.
+    uv_snapshot!(context.filters(), context.python_install().arg("--reinstall-broken"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All requested Python installations are healthy
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn python_reinstall_patch() {
     let context: TestContext = TestContext::new_with_versions(&[])
@@ -1,3 +1,4 @@
+use anyhow::Result;
 use uv_platform::{Arch, Os};
 use uv_static::EnvVars;
 
@@ -208,6 +209,35 @@ fn python_list_venv() {
     ");
 }
 
+/// `--output-format json` should report whether each interpreter is installed and managed.
+#[test]
+fn python_list_json() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let output = context
+        .python_list()
+        .arg("--only-installed")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success());
+    let data: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let entries = data.as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["version_parts"]["minor"], 12);
+    assert_eq!(entries[1]["version_parts"]["minor"], 11);
+    assert!(entries.iter().all(|entry| {
+        entry["installed"] == true
+            && entry["managed"] == false
+            && entry["implementation"] == "cpython"
+            && entry["path"].is_string()
+            && entry["url"].is_null()
+            && entry["arch"].is_string()
+    }));
+
+    Ok(())
+}
+
 #[cfg(unix)]
 #[test]
 fn python_list_unsupported_version() {
@@ -178,6 +178,26 @@ fn python_pin() {
         "###);
     }
 
+    // Request an implementation and version that is not installed
+    // (skip on Windows because the snapshot is different and the behavior is not platform dependent)
+    #[cfg(unix)]
+    {
+        uv_snapshot!(context.filters(), context.python_pin().arg("pypy@3.10"), @r"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        Updated `.python-version` from `pypy` -> `pypy@3.10`
+
+        ----- stderr -----
+        warning: No interpreter found for PyPy 3.10 in managed installations or search path
+        ");
+
+        let python_version = context.read(PYTHON_VERSION_FILENAME);
+        assert_snapshot!(python_version, @r"
+        pypy@3.10
+        ");
+    }
+
     // Request a version that is not installed
     // (skip on Windows because the snapshot is different and the behavior is not platform dependent)
     #[cfg(unix)]
@@ -186,7 +206,7 @@ fn python_pin() {
         success: true
         exit_code: 0
         ----- stdout -----
-        Updated `.python-version` from `pypy` -> `3.7`
+        Updated `.python-version` from `pypy@3.10` -> `3.7`
 
         ----- stderr -----
         warning: No interpreter found for Python 3.7 in managed installations or search path
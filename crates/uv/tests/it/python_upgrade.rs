@@ -701,3 +701,54 @@ fn python_upgrade_force_install() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn python_upgrade_dry_run() {
+    let context: TestContext = TestContext::new_with_versions(&[])
+        .with_filtered_python_keys()
+        .with_filtered_exe_suffix()
+        .with_managed_python_dirs();
+
+    // Install an earlier patch version
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.10.17"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.17 in [TIME]
+     + cpython-3.10.17-[PLATFORM] (python3.10)
+    ");
+
+    // Report the upgrade, along with the minor version directory that would be updated
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("3.10").arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Would upgrade cpython-3.10.17-[PLATFORM] to cpython-3.10.18-[PLATFORM]
+    Would update managed/cpython-3.10-[PLATFORM] to use cpython-3.10.18-[PLATFORM]
+    ");
+
+    // Nothing should have been installed
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("3.10"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.10.18 in [TIME]
+     + cpython-3.10.18-[PLATFORM] (python3.10)
+    ");
+
+    // Once upgraded, there's nothing left to do
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--preview").arg("3.10").arg("--dry-run"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All requested versions already on latest supported patch release
+    ");
+}
@@ -194,6 +194,95 @@ fn run_args() -> Result<()> {
     Ok(())
 }
 
+/// Display the environment that would be used to run a command.
+#[test]
+fn run_show_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        types = ["sniffio"]
+
+        [dependency-groups]
+        dev = []
+        "#
+    })?;
+
+    // The command should not be executed.
+    uv_snapshot!(context.filters(), context.run().arg("--show-environment").arg("false"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Interpreter: [VENV]/[BIN]/[PYTHON]
+    Environment: project
+    Source: project `foo` at `[TEMP_DIR]/`
+    Extras: none
+    Groups: dev
+    Lockfile: updated
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    uv_snapshot!(context.filters(), context.run().arg("--show-environment").arg("--extra").arg("types").arg("--no-dev").arg("--frozen"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Interpreter: [VENV]/[BIN]/[PYTHON]
+    Environment: project
+    Source: project `foo` at `[TEMP_DIR]/`
+    Extras: types
+    Groups: none
+    Lockfile: not checked (`--frozen`)
+
+    ----- stderr -----
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + sniffio==1.3.1
+    ");
+
+    uv_snapshot!(context.filters(), context.run().arg("--show-environment").arg("--extra").arg("types"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Interpreter: [VENV]/[BIN]/[PYTHON]
+    Environment: project
+    Source: project `foo` at `[TEMP_DIR]/`
+    Extras: types
+    Groups: dev
+    Lockfile: up-to-date
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited 2 packages in [TIME]
+    ");
+
+    uv_snapshot!(context.filters(), context.run().arg("--show-environment").arg("--no-project"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Interpreter: [VENV]/[BIN]/[PYTHON]
+    Environment: interpreter
+    Extras: none
+    Groups: none
+    Lockfile: none
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
 /// Run without specifying any arguments.
 ///
 /// This should list the available scripts.
@@ -1957,6 +2046,82 @@ fn run_with_editable() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn run_with_group() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = ["sniffio==1.3.0"]
+
+        [dependency-groups]
+        analysis = ["iniconfig==2.0.0"]
+        lint = ["typing-extensions"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r"
+        import iniconfig
+
+        print(iniconfig.__version__)
+       "
+    })?;
+
+    // The group's dependencies should be layered atop the project environment.
+    uv_snapshot!(context.filters(), context.run().arg("--with-group").arg("analysis").arg("main.py"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    2.0.0
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + foo==1.0.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.0
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Requesting an unknown group should list the declared groups.
+    uv_snapshot!(context.filters(), context.run().arg("--with-group").arg("missing").arg("main.py"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The dependency group `missing` was not found in `pyproject.toml` (declared groups: `analysis`, `lint`)
+    ");
+
+    // Groups can't be layered without a project.
+    uv_snapshot!(context.filters(), context.run().arg("--no-project").arg("--with-group").arg("analysis").arg("main.py"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--no-project' cannot be used with '--with-group <WITH_GROUP>'
+
+    Usage: uv run --cache-dir [CACHE_DIR] --no-project --exclude-newer <EXCLUDE_NEWER> [COMMAND]
+
+    For more information, try '--help'.
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_group() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2435,6 +2600,139 @@ fn run_no_sync() -> Result<()> {
     Ok(())
 }
 
+/// Running with `--no-sync` should warn if the environment was synced against a different
+/// `pyproject.toml`.
+#[test]
+fn run_no_sync_stale() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#
+    })?;
+
+    context.sync().assert().success();
+
+    // The environment is up-to-date.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("-c").arg("import anyio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    // Add a dependency, without syncing.
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("-c").arg("import anyio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The project environment is out of date with `pyproject.toml` or `uv.lock`; run `uv sync` to update it, or omit `--no-sync`
+    ");
+
+    // Without `--no-sync`, the environment is updated.
+    uv_snapshot!(context.filters(), context.run().arg("--").arg("python").arg("-c").arg("import iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // And the warning is no longer shown.
+    uv_snapshot!(context.filters(), context.run().arg("--no-sync").arg("--").arg("python").arg("-c").arg("import iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Running with `--frozen` should error if the environment was synced against a different
+/// `pyproject.toml`.
+#[test]
+fn run_frozen_stale() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "#
+    })?;
+
+    context.sync().assert().success();
+
+    // The environment is up-to-date.
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("--").arg("python").arg("-c").arg("import anyio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 3 packages in [TIME]
+    ");
+
+    // Add a dependency, without syncing.
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig"]
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("--").arg("python").arg("-c").arg("import anyio"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The project environment is out of date with `pyproject.toml` or `uv.lock`; run `uv sync` to update it, or omit `--frozen`
+    ");
+
+    // Once the environment is synced, `--frozen` succeeds again.
+    context.sync().assert().success();
+
+    uv_snapshot!(context.filters(), context.run().arg("--frozen").arg("--").arg("python").arg("-c").arg("import iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 4 packages in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_empty_requirements_txt() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -3299,6 +3597,60 @@ fn run_module() {
     "#);
 }
 
+/// Run a module in the project environment, forwarding its arguments and exit code.
+#[test]
+fn run_module_project() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#
+    })?;
+
+    // The module doesn't install a script, so it can only be run via `-m`.
+    let main = context.temp_dir.child("check.py");
+    main.write_str(indoc! { r#"
+        import sys
+
+        import iniconfig
+
+        print(iniconfig.__name__)
+        sys.exit(int(sys.argv[1]))
+        "#
+    })?;
+
+    uv_snapshot!(context.filters(), context.run().arg("-m").arg("check").arg("3"), @r"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    uv_snapshot!(context.filters(), context.run().arg("--module").arg("check").arg("0"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn run_module_stdin() {
     let context = TestContext::new("3.12");
@@ -4472,18 +4824,98 @@ fn run_with_malformed_env() -> Result<()> {
     })?;
 
     context.temp_dir.child(".env").write_str(indoc! { "
+        REBEL_1=leia_organa
         THE_^EMPIRE_VARIABLE=darth_vader
        "
     })?;
 
     uv_snapshot!(context.filters(), context.run().arg("--env-file").arg(".env").arg("test.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse environment file `.env` at line 2: Expected `=` after `THE_`
+    "###);
+
+    Ok(())
+}
+
+#[test]
+fn run_with_env_file_interpolation() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("test.py").write_str(indoc! { "
+        import os
+        print(os.environ.get('HOST'))
+        print(os.environ.get('URL'))
+        print(os.environ.get('PRICE'))
+        print(os.environ.get('LITERAL'))
+       "
+    })?;
+
+    context.temp_dir.child(".env").write_str(indoc! { "
+        HOST=localhost
+        PORT=8000
+        URL=http://${HOST}:${PORT}
+        PRICE=$$5
+       "
+    })?;
+
+    // Values in later files override earlier ones, and may reference them.
+    context.temp_dir.child(".env.local").write_str(indoc! { "
+        HOST=deathstar.empire
+        URL=https://${HOST}:${PORT}/${REBEL_BASE}
+        LITERAL='${HOST}'
+       "
+    })?;
+
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--env-file").arg(".env")
+        .arg("--env-file").arg(".env.local")
+        .arg("test.py")
+        .env("REBEL_BASE", "yavin"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
+    deathstar.empire
+    https://deathstar.empire:8000/yavin
+    $5
+    ${HOST}
+
+    ----- stderr -----
+    "###);
+
+    // Variables in the ambient environment take precedence over the files.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("test.py")
+        .env(EnvVars::UV_ENV_FILE, ".env .env.local")
+        .env("HOST", "hoth"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hoth
+    https://hoth:8000/
+    $5
+    ${HOST}
+
+    ----- stderr -----
+    "###);
+
+    // `--no-env-file` disables `UV_ENV_FILE`.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--no-env-file")
+        .arg("test.py")
+        .env(EnvVars::UV_ENV_FILE, ".env .env.local"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    None
+    None
+    None
     None
 
     ----- stderr -----
-    warning: Failed to parse environment file `.env` at position 4: THE_^EMPIRE_VARIABLE=darth_vader
     "###);
 
     Ok(())
@@ -57,3 +57,25 @@ fn test_self_update_offline_error() {
     error: Self-update is not possible because network connectivity is disabled (i.e., with `--offline`)
     ");
 }
+
+#[test]
+fn test_self_update_downgrade_error() {
+    let context = TestContext::new("3.12");
+
+    let version = regex::escape(env!("CARGO_PKG_VERSION"));
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(version.as_str(), "[VERSION]")])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.self_update().arg("0.1.0"),
+    @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Refusing to downgrade uv from v[VERSION] to v0.1.0; pass `--allow-downgrade` to downgrade
+    ");
+}
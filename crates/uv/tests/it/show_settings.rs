@@ -64,6 +64,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -79,7 +81,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -95,12 +99,15 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -142,6 +149,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -193,6 +201,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -200,6 +215,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -238,6 +255,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -264,6 +282,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -279,7 +299,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -295,12 +317,15 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -342,6 +367,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -393,6 +419,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -400,6 +433,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -438,6 +473,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -465,6 +501,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -480,7 +518,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -496,12 +536,15 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -543,6 +586,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -594,6 +638,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -601,6 +652,8 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -639,6 +692,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -698,6 +752,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -713,7 +769,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -729,12 +787,15 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -776,6 +837,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -827,6 +889,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -834,6 +903,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -872,6 +943,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -900,6 +972,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -915,7 +989,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -931,12 +1007,15 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -995,6 +1074,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1002,6 +1088,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1040,6 +1128,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -1078,6 +1167,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1093,7 +1184,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -1109,12 +1202,15 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1156,6 +1252,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -1207,6 +1304,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1214,6 +1318,8 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1254,6 +1360,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -1305,6 +1412,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1320,7 +1429,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -1336,12 +1447,15 @@ fn resolve_index_url() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1383,6 +1497,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -1416,6 +1531,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -1467,6 +1583,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1474,6 +1597,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1512,6 +1637,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -1540,6 +1666,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1555,7 +1683,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -1571,12 +1701,15 @@ fn resolve_index_url() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1620,6 +1753,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -1653,6 +1787,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -1686,6 +1821,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -1737,6 +1873,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1744,6 +1887,8 @@ fn resolve_index_url() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -1782,6 +1927,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -1833,6 +1979,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -1848,7 +1996,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -1864,12 +2014,15 @@ fn resolve_find_links() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1912,6 +2065,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         format: Flat,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -1962,6 +2116,13 @@ fn resolve_find_links() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -1969,6 +2130,8 @@ fn resolve_find_links() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2007,6 +2170,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -2057,6 +2221,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2072,7 +2238,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -2088,12 +2256,15 @@ fn resolve_top_level() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2152,6 +2323,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2159,6 +2337,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2197,6 +2377,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -2240,6 +2421,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2255,7 +2438,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -2271,12 +2456,15 @@ fn resolve_top_level() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2318,6 +2506,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -2351,6 +2540,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -2402,6 +2592,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2409,6 +2606,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2447,6 +2646,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -2473,6 +2673,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2488,7 +2690,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -2504,12 +2708,15 @@ fn resolve_top_level() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2551,6 +2758,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -2584,6 +2792,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -2635,6 +2844,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2642,6 +2858,8 @@ fn resolve_top_level() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2680,6 +2898,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -2729,6 +2948,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2744,7 +2965,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -2760,12 +2983,15 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2824,6 +3050,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -2831,6 +3064,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -2869,6 +3104,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -2902,6 +3138,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -2917,7 +3155,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -2933,12 +3173,15 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2997,6 +3240,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3004,6 +3254,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3042,6 +3294,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -3075,6 +3328,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3090,7 +3345,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -3106,12 +3363,15 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3170,6 +3430,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3177,6 +3444,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3215,6 +3484,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -3250,6 +3520,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3265,7 +3537,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -3281,12 +3555,15 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3345,6 +3622,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3352,6 +3636,8 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3390,6 +3676,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -3444,6 +3731,8 @@ fn resolve_tool() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3459,7 +3748,9 @@ fn resolve_tool() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -3497,8 +3788,12 @@ fn resolve_tool() -> anyhow::Result<()> {
             resolution: Some(
                 LowestDirect,
             ),
+            resolution_overrides: None,
+            allow_prereleases: None,
             prerelease: None,
             fork_strategy: None,
+            prefer_local_versions: None,
+            hash_algorithm: None,
             dependency_metadata: None,
             config_settings: None,
             config_settings_package: None,
@@ -3506,6 +3801,11 @@ fn resolve_tool() -> anyhow::Result<()> {
             no_build_isolation_package: None,
             extra_build_dependencies: None,
             extra_build_variables: None,
+            build_timeout: None,
+            build_timeout_package: None,
+            build_max_output_size: None,
+            build_max_output_size_package: None,
+            no_build_timeout: None,
             exclude_newer: None,
             exclude_newer_package: None,
             link_mode: Some(
@@ -3527,6 +3827,13 @@ fn resolve_tool() -> anyhow::Result<()> {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_binary_fallback: [],
+                    limits: BuildLimits {
+                        timeout: None,
+                        timeout_package: {},
+                        max_output_size: None,
+                        max_output_size_package: {},
+                    },
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -3544,6 +3851,7 @@ fn resolve_tool() -> anyhow::Result<()> {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                hash_algorithm: None,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -3560,10 +3868,19 @@ fn resolve_tool() -> anyhow::Result<()> {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                prefer_local_versions: false,
                 prerelease: IfNecessaryOrExplicit,
                 resolution: LowestDirect,
+                resolution_overrides: ResolutionOverrides(
+                    {},
+                ),
+                allow_prereleases: {},
                 sources: Enabled,
                 upgrade: None,
+                user_constraints: [],
+                user_overrides: [],
+                configured_no_binary_package: {},
+                configured_no_build_package: {},
             },
             compile_bytecode: false,
             reinstall: None,
@@ -3632,6 +3949,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3647,7 +3966,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -3663,12 +3984,15 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3727,6 +4051,13 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3734,6 +4065,8 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -3772,6 +4105,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -3839,6 +4173,8 @@ fn resolve_both() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -3854,7 +4190,9 @@ fn resolve_both() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -3870,12 +4208,15 @@ fn resolve_both() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3917,6 +4258,7 @@ fn resolve_both() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -3968,6 +4310,13 @@ fn resolve_both() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -3975,6 +4324,8 @@ fn resolve_both() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4013,6 +4364,7 @@ fn resolve_both() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -4084,6 +4436,8 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4099,7 +4453,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -4115,12 +4471,15 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4162,6 +4521,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -4213,6 +4573,13 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4220,6 +4587,8 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4258,6 +4627,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -4408,6 +4778,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4423,7 +4795,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -4439,12 +4813,15 @@ fn resolve_config_file() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4486,6 +4863,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -4537,6 +4915,13 @@ fn resolve_config_file() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4544,6 +4929,8 @@ fn resolve_config_file() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4582,6 +4969,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -4619,7 +5007,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `required-version`, `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `resolution-overrides`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `config-settings-package`, `no-build-isolation`, `no-build-isolation-package`, `extra-build-dependencies`, `extra-build-variables`, `exclude-newer`, `exclude-newer-package`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `only-binary-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `python-downloads-json-url`, `publish-url`, `trusted-publishing`, `check-url`, `add-bounds`, `tool-aliases`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `build-constraint-dependencies`, `environments`, `required-environments`, `conflicts`, `compatible-tags`, `upgrade-policy`, `test-pythons`, `compile-bytecode-exclude`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dependency-groups`, `dev-dependencies`, `build-backend`
     "
     );
 
@@ -4708,6 +5096,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4723,7 +5113,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -4739,12 +5131,15 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4803,6 +5198,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4810,6 +5212,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: LowestDirect,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -4848,6 +5252,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -4884,6 +5289,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -4899,7 +5306,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -4915,12 +5324,15 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4979,6 +5391,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -4986,6 +5405,8 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5024,6 +5445,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -5079,6 +5501,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                     port: None,
                 },
             ],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5094,7 +5518,9 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -5110,12 +5536,15 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5174,6 +5603,13 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5181,6 +5617,8 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5219,6 +5657,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -5266,6 +5705,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5281,7 +5722,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -5297,12 +5740,15 @@ fn index_priority() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5346,6 +5792,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -5379,6 +5826,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -5430,6 +5878,13 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5437,6 +5892,8 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5475,6 +5932,7 @@ fn index_priority() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -5501,6 +5959,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5516,7 +5976,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -5532,12 +5994,15 @@ fn index_priority() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5581,6 +6046,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -5614,6 +6080,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -5665,6 +6132,13 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5672,6 +6146,8 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5710,6 +6186,7 @@ fn index_priority() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -5742,6 +6219,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5757,7 +6236,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -5773,12 +6254,15 @@ fn index_priority() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5822,6 +6306,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -5855,6 +6340,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -5906,6 +6392,13 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -5913,6 +6406,8 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -5951,6 +6446,7 @@ fn index_priority() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -5978,6 +6474,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -5993,7 +6491,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -6009,12 +6509,15 @@ fn index_priority() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6058,6 +6561,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -6091,6 +6595,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -6142,6 +6647,13 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6149,6 +6661,8 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6187,6 +6701,7 @@ fn index_priority() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -6221,6 +6736,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6236,7 +6753,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -6252,12 +6771,15 @@ fn index_priority() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6301,6 +6823,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -6334,6 +6857,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -6385,6 +6909,13 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6392,6 +6923,8 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6430,6 +6963,7 @@ fn index_priority() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -6457,6 +6991,8 @@ fn index_priority() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6472,7 +7008,9 @@ fn index_priority() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -6488,12 +7026,15 @@ fn index_priority() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         environments: SupportedEnvironments(
             [],
         ),
+        emit_build_requirements: false,
+        emit_build_requirements_output: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6537,6 +7078,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -6570,6 +7112,7 @@ fn index_priority() -> anyhow::Result<()> {
                         format: Simple,
                         publish_url: None,
                         authenticate: Auto,
+                        auth_scope: [],
                         ignore_error_codes: None,
                         cache_control: None,
                     },
@@ -6621,6 +7164,13 @@ fn index_priority() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6628,6 +7178,8 @@ fn index_priority() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6666,6 +7218,7 @@ fn index_priority() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -6706,6 +7259,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6721,7 +7276,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -6738,11 +7295,13 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        upgrade_strategy: Eager,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6801,6 +7360,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6808,6 +7374,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -6846,6 +7414,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -6872,6 +7441,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -6887,7 +7458,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -6904,11 +7477,13 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        upgrade_strategy: Eager,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6967,6 +7542,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -6974,6 +7556,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7010,6 +7594,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: None,
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -7036,6 +7621,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7051,7 +7638,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -7068,11 +7657,13 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        upgrade_strategy: Eager,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7131,6 +7722,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -7138,6 +7736,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7176,6 +7776,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             hash_checking: Some(
                 Require,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -7202,6 +7803,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7217,7 +7820,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -7234,11 +7839,13 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        upgrade_strategy: Eager,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7297,6 +7904,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -7304,6 +7918,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7340,6 +7956,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: None,
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -7366,6 +7983,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7381,7 +8000,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -7398,11 +8019,13 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        upgrade_strategy: Eager,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7461,6 +8084,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -7468,6 +8098,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7504,6 +8136,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             compile_bytecode: false,
             sources: Enabled,
             hash_checking: None,
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -7531,6 +8164,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7546,7 +8181,9 @@ fn verify_hashes() -> anyhow::Result<()> {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -7563,11 +8200,13 @@ fn verify_hashes() -> anyhow::Result<()> {
         constraints: [],
         overrides: [],
         build_constraints: [],
+        only_group: [],
         dry_run: Disabled,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         build_constraints_from_workspace: [],
         modifications: Sufficient,
+        upgrade_strategy: Eager,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -7626,6 +8265,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             build_options: BuildOptions {
                 no_binary: None,
                 no_build: None,
+                allow_binary_fallback: [],
+                limits: BuildLimits {
+                    timeout: None,
+                    timeout_package: {},
+                    max_output_size: None,
+                    max_output_size_package: {},
+                },
             },
             allow_empty_requirements: false,
             strict: false,
@@ -7633,6 +8279,8 @@ fn verify_hashes() -> anyhow::Result<()> {
             resolution: Highest,
             prerelease: IfNecessaryOrExplicit,
             fork_strategy: RequiresPython,
+            hash_algorithm: None,
+            prefer_local_versions: false,
             dependency_metadata: DependencyMetadata(
                 {},
             ),
@@ -7671,6 +8319,7 @@ fn verify_hashes() -> anyhow::Result<()> {
             hash_checking: Some(
                 Verify,
             ),
+            allow_unhashable_sources: false,
             upgrade: None,
             reinstall: None,
         },
@@ -7711,6 +8360,8 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7720,13 +8371,15 @@ fn preview_features() {
         show_settings: true,
         preview: Preview {
             flags: PreviewFeatures(
-                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | EXTRA_BUILD_DEPENDENCIES,
+                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | EXTRA_BUILD_DEPENDENCIES | WORKSPACE_LIST | RESOLUTION_BUNDLE,
             ),
         },
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -7764,6 +8417,13 @@ fn preview_features() {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_binary_fallback: [],
+                    limits: BuildLimits {
+                        timeout: None,
+                        timeout_package: {},
+                        max_output_size: None,
+                        max_output_size_package: {},
+                    },
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -7781,6 +8441,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                hash_algorithm: None,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -7797,10 +8458,19 @@ fn preview_features() {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                prefer_local_versions: false,
                 prerelease: IfNecessaryOrExplicit,
                 resolution: Highest,
+                resolution_overrides: ResolutionOverrides(
+                    {},
+                ),
+                allow_prereleases: {},
                 sources: Enabled,
                 upgrade: None,
+                user_constraints: [],
+                user_overrides: [],
+                configured_no_binary_package: {},
+                configured_no_build_package: {},
             },
             compile_bytecode: false,
             reinstall: None,
@@ -7824,6 +8494,8 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7839,7 +8511,9 @@ fn preview_features() {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -7877,6 +8551,13 @@ fn preview_features() {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_binary_fallback: [],
+                    limits: BuildLimits {
+                        timeout: None,
+                        timeout_package: {},
+                        max_output_size: None,
+                        max_output_size_package: {},
+                    },
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -7894,6 +8575,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                hash_algorithm: None,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -7910,10 +8592,19 @@ fn preview_features() {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                prefer_local_versions: false,
                 prerelease: IfNecessaryOrExplicit,
                 resolution: Highest,
+                resolution_overrides: ResolutionOverrides(
+                    {},
+                ),
+                allow_prereleases: {},
                 sources: Enabled,
                 upgrade: None,
+                user_constraints: [],
+                user_overrides: [],
+                configured_no_binary_package: {},
+                configured_no_build_package: {},
             },
             compile_bytecode: false,
             reinstall: None,
@@ -7937,6 +8628,8 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -7946,13 +8639,15 @@ fn preview_features() {
         show_settings: true,
         preview: Preview {
             flags: PreviewFeatures(
-                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | EXTRA_BUILD_DEPENDENCIES,
+                PYTHON_INSTALL_DEFAULT | PYTHON_UPGRADE | JSON_OUTPUT | PYLOCK | ADD_BOUNDS | EXTRA_BUILD_DEPENDENCIES | WORKSPACE_LIST | RESOLUTION_BUNDLE,
             ),
         },
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -7990,6 +8685,13 @@ fn preview_features() {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_binary_fallback: [],
+                    limits: BuildLimits {
+                        timeout: None,
+                        timeout_package: {},
+                        max_output_size: None,
+                        max_output_size_package: {},
+                    },
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -8007,6 +8709,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                hash_algorithm: None,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8023,10 +8726,19 @@ fn preview_features() {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                prefer_local_versions: false,
                 prerelease: IfNecessaryOrExplicit,
                 resolution: Highest,
+                resolution_overrides: ResolutionOverrides(
+                    {},
+                ),
+                allow_prereleases: {},
                 sources: Enabled,
                 upgrade: None,
+                user_constraints: [],
+                user_overrides: [],
+                configured_no_binary_package: {},
+                configured_no_build_package: {},
             },
             compile_bytecode: false,
             reinstall: None,
@@ -8050,6 +8762,8 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8065,7 +8779,9 @@ fn preview_features() {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -8103,6 +8819,13 @@ fn preview_features() {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_binary_fallback: [],
+                    limits: BuildLimits {
+                        timeout: None,
+                        timeout_package: {},
+                        max_output_size: None,
+                        max_output_size_package: {},
+                    },
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -8120,6 +8843,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                hash_algorithm: None,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8136,10 +8860,19 @@ fn preview_features() {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                prefer_local_versions: false,
                 prerelease: IfNecessaryOrExplicit,
                 resolution: Highest,
+                resolution_overrides: ResolutionOverrides(
+                    {},
+                ),
+                allow_prereleases: {},
                 sources: Enabled,
                 upgrade: None,
+                user_constraints: [],
+                user_overrides: [],
+                configured_no_binary_package: {},
+                configured_no_build_package: {},
             },
             compile_bytecode: false,
             reinstall: None,
@@ -8163,6 +8896,8 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8178,7 +8913,9 @@ fn preview_features() {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -8216,6 +8953,13 @@ fn preview_features() {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_binary_fallback: [],
+                    limits: BuildLimits {
+                        timeout: None,
+                        timeout_package: {},
+                        max_output_size: None,
+                        max_output_size_package: {},
+                    },
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -8233,6 +8977,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                hash_algorithm: None,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8249,10 +8994,19 @@ fn preview_features() {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                prefer_local_versions: false,
                 prerelease: IfNecessaryOrExplicit,
                 resolution: Highest,
+                resolution_overrides: ResolutionOverrides(
+                    {},
+                ),
+                allow_prereleases: {},
                 sources: Enabled,
                 upgrade: None,
+                user_constraints: [],
+                user_overrides: [],
+                configured_no_binary_package: {},
+                configured_no_build_package: {},
             },
             compile_bytecode: false,
             reinstall: None,
@@ -8278,6 +9032,8 @@ fn preview_features() {
             connectivity: Online,
             native_tls: false,
             allow_insecure_host: [],
+            retries: 3,
+            retry_backoff: 2,
         },
         concurrency: Concurrency {
             downloads: 50,
@@ -8293,7 +9049,9 @@ fn preview_features() {
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        progress: Auto,
         installer_metadata: true,
+        resolution_log: None,
     }
     CacheSettings {
         no_cache: false,
@@ -8331,6 +9089,13 @@ fn preview_features() {
                 build_options: BuildOptions {
                     no_binary: None,
                     no_build: None,
+                    allow_binary_fallback: [],
+                    limits: BuildLimits {
+                        timeout: None,
+                        timeout_package: {},
+                        max_output_size: None,
+                        max_output_size_package: {},
+                    },
                 },
                 config_setting: ConfigSettings(
                     {},
@@ -8348,6 +9113,7 @@ fn preview_features() {
                     ),
                 },
                 fork_strategy: RequiresPython,
+                hash_algorithm: None,
                 index_locations: IndexLocations {
                     indexes: [],
                     flat_index: [],
@@ -8364,10 +9130,19 @@ fn preview_features() {
                 extra_build_variables: ExtraBuildVariables(
                     {},
                 ),
+                prefer_local_versions: false,
                 prerelease: IfNecessaryOrExplicit,
                 resolution: Highest,
+                resolution_overrides: ResolutionOverrides(
+                    {},
+                ),
+                allow_prereleases: {},
                 sources: Enabled,
                 upgrade: None,
+                user_constraints: [],
+                user_overrides: [],
+                configured_no_binary_package: {},
+                configured_no_build_package: {},
             },
             compile_bytecode: false,
             reinstall: None,
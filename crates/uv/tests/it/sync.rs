@@ -93,6 +93,10 @@ fn locked() -> Result<()> {
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
+    Remove anyio v3.7.0
+    Remove idna v3.6
+    Add iniconfig v2.0.0
+    Remove sniffio v1.3.1
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
     "###);
 
@@ -429,6 +433,7 @@ fn sync_json() -> Result<()> {
 
     ----- stderr -----
     Resolved 2 packages in [TIME]
+    Update iniconfig v2.0.0 -> v1.1.1
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
     "###);
 
@@ -3104,6 +3109,70 @@ fn sync_dev_group() -> Result<()> {
     Ok(())
 }
 
+/// Dependency groups with a marker that doesn't match the current environment should be locked,
+/// but omitted when syncing.
+#[test]
+fn sync_group_marker() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["typing-extensions"]
+
+        [dependency-groups]
+        gpu = ["anyio==3.7.0"]
+        test = ["iniconfig"]
+
+        [tool.uv.dependency-groups]
+        gpu = { marker = "sys_platform == 'emscripten'" }
+        "#,
+    )?;
+
+    // The group should be included in the lockfile.
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    ");
+
+    // Requesting the group should be a no-op.
+    uv_snapshot!(context.filters(), context.sync().arg("--group").arg("gpu"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Skipping group `gpu`, since its marker (`sys_platform == 'emscripten'`) does not match the current environment
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + typing-extensions==4.10.0
+    ");
+
+    // As should enabling all groups.
+    uv_snapshot!(context.filters(), context.sync().arg("--all-groups"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    Ok(())
+}
+
 #[test]
 fn sync_non_existent_group() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -8017,6 +8086,176 @@ fn sync_python_version() -> Result<()> {
     Ok(())
 }
 
+/// Sync an environment for each Python version in `tool.uv.test-pythons`.
+#[test]
+fn sync_all_pythons() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.10", "3.11", "3.12"]);
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        test-pythons = ["3.11", "3.12"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--all-pythons"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.11.[X] interpreter at: [PYTHON-3.11]
+    Creating virtual environment at: .venv-3.11
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv-3.12
+    Resolved 2 packages in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Synced 2 environments:
+     - Python 3.11 at: .venv-3.11
+     - Python 3.12 at: .venv-3.12
+    ");
+
+    // The default project environment should be untouched.
+    context
+        .temp_dir
+        .child(".venv")
+        .assert(predicate::path::missing());
+
+    // A version that's incompatible with the project should fail before syncing any environments.
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        test-pythons = ["3.12", "3.10"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--all-pythons"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Python 3.10 in `tool.uv.test-pythons` is incompatible with the project's Python requirement: `>=3.11`
+    ");
+
+    // A version that's compatible with the project, but not with the lockfile, should also fail
+    // before syncing any environments.
+    fs_err::remove_dir_all(context.temp_dir.child(".venv-3.11"))?;
+    fs_err::remove_dir_all(context.temp_dir.child(".venv-3.12"))?;
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        environments = ["python_version >= '3.12'"]
+        test-pythons = ["3.12", "3.11"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--all-pythons"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The lockfile does not support Python 3.11 in `tool.uv.test-pythons`
+      Caused by: The current Python platform is not compatible with the lockfile's supported environments: `python_full_version >= '3.12'`
+    ");
+
+    context
+        .temp_dir
+        .child(".venv-3.12")
+        .assert(predicate::path::missing());
+
+    // Without any versions, `--all-pythons` should fail.
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.11"
+        dependencies = ["iniconfig"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--all-pythons"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `uv sync --all-pythons` requires at least one Python version in `tool.uv.test-pythons`
+    ");
+
+    Ok(())
+}
+
+/// Packages listed in `tool.uv.compile-bytecode-exclude` should be skipped during bytecode
+/// compilation.
+#[test]
+fn sync_compile_bytecode_exclude() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0", "markupsafe==2.1.3"]
+
+        [tool.uv]
+        compile-bytecode-exclude = ["MarkupSafe"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--compile-bytecode"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+    Bytecode compiled 4 files, skipped 1 package in [TIME]
+     + iniconfig==2.0.0
+     + markupsafe==2.1.3
+    ");
+
+    // The excluded package should be left uncompiled.
+    assert!(
+        !context
+            .site_packages()
+            .join("markupsafe")
+            .join("__pycache__")
+            .exists()
+    );
+    assert!(
+        context
+            .site_packages()
+            .join("iniconfig")
+            .join("__pycache__")
+            .join("__init__.cpython-312.pyc")
+            .exists()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn sync_explicit() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -8151,6 +8390,61 @@ fn sync_all() -> Result<()> {
     Ok(())
 }
 
+/// Sync all members in a workspace, where the members have conflicting requirements.
+#[test]
+fn sync_all_conflict() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==1.1.1"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+        "#,
+    )?;
+
+    // Add a workspace member that is not a dependency of the root.
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+    child
+        .child("src")
+        .child("child")
+        .child("__init__.py")
+        .touch()?;
+
+    // The members are resolved together, so the conflict should name both.
+    uv_snapshot!(context.filters(), context.sync().arg("--all-packages"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because child depends on iniconfig==2.0.0 and project depends on iniconfig==1.1.1, we can conclude that child and project are incompatible.
+          And because your workspace requires child and project, we can conclude that your workspace's requirements are unsatisfiable.
+    ");
+
+    Ok(())
+}
+
 /// Sync all members in a workspace with extras attached.
 #[test]
 fn sync_all_extras() -> Result<()> {
@@ -9864,6 +10158,10 @@ fn sync_dry_run_and_locked() -> Result<()> {
     Would download 1 package
     Would install 1 package
      + iniconfig==2.0.0
+    Remove anyio v3.7.0
+    Remove idna v3.6
+    Add iniconfig v2.0.0
+    Remove sniffio v1.3.1
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
     ");
 
@@ -10179,6 +10477,7 @@ fn sync_locked_script() -> Result<()> {
     ----- stderr -----
     Using script environment at: [CACHE_DIR]/environments-v2/script-[HASH]
     Resolved 4 packages in [TIME]
+    Add iniconfig v2.0.0
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
     ");
 
@@ -10282,6 +10581,8 @@ fn sync_locked_script() -> Result<()> {
     Updating script environment at: [CACHE_DIR]/environments-v2/script-[HASH]
     warning: Resolving despite existing lockfile due to fork markers being disjoint with `requires-python`: `python_full_version >= '3.11'` vs `python_full_version >= '3.8' and python_full_version < '3.11'`
     Resolved 6 packages in [TIME]
+    Add exceptiongroup v1.2.0
+    Add typing-extensions v4.10.0
     The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
     ");
 
@@ -12904,3 +13205,169 @@ fn sync_extra_build_variables() -> Result<()> {
 
     Ok(())
 }
+
+/// Packages installed by another tool should be reported, rejected, or adopted.
+#[test]
+fn sync_foreign_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    ");
+
+    // Mark the package as installed by `pip`.
+    let dist_info = context.site_packages().join("iniconfig-2.0.0.dist-info");
+    fs_err::write(dist_info.join("INSTALLER"), "pip\n")?;
+
+    // By default, the package should be reported.
+    uv_snapshot!(context.filters(), context.sync(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: The environment contains packages that were not installed by uv: `iniconfig==2.0.0` (installed by `pip`); use `--adopt-foreign` to manage them with uv, or `--check-foreign` to fail instead
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    // With `--check-foreign`, the sync should fail.
+    uv_snapshot!(context.filters(), context.sync().arg("--check-foreign"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The environment contains packages that were not installed by uv: `iniconfig==2.0.0` (installed by `pip`); use `--adopt-foreign` to manage them with uv
+    ");
+
+    // With `--adopt-foreign`, the package should be marked as installed by uv.
+    uv_snapshot!(context.filters(), context.sync().arg("--adopt-foreign"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Adopted 1 package: `iniconfig==2.0.0` (installed by `pip`)
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    assert_eq!(fs_err::read_to_string(dist_info.join("INSTALLER"))?, "uv");
+    let record = fs_err::read_to_string(dist_info.join("RECORD"))?;
+    assert_eq!(
+        record
+            .lines()
+            .filter(|line| line.starts_with("iniconfig-2.0.0.dist-info/INSTALLER,"))
+            .count(),
+        1
+    );
+
+    // Subsequent syncs should no longer report the package.
+    uv_snapshot!(context.filters(), context.sync().arg("--check-foreign"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    ");
+
+    Ok(())
+}
+
+/// Sync into a relocatable project environment with `--relocatable`.
+#[test]
+#[cfg(unix)]
+fn sync_relocatable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.scripts]
+        project = "project:main"
+
+        [build-system]
+        requires = ["hatchling"]
+        build-backend = "hatchling.build"
+        "#,
+    )?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("def main():\n    print('Hello')\n")?;
+
+    // The existing environment isn't relocatable, so it should be recreated. The project itself is
+    // installed as editable, which can't be relocated.
+    uv_snapshot!(context.filters(), context.sync().arg("--relocatable"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    warning: The environment at `.venv` is relocatable, but editable installs reference their source by absolute path and cannot be relocated: `project`. To install them as non-editable, pass `--no-editable`.
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    context
+        .venv
+        .child("pyvenv.cfg")
+        .assert(predicate::str::contains("relocatable = true"));
+
+    // The entrypoint should locate the interpreter relative to itself.
+    let entrypoint = fs_err::read_to_string(context.venv.child("bin").child("project"))?;
+    assert!(entrypoint.starts_with("#!/bin/sh\n"));
+    assert!(entrypoint.contains(r#""$(dirname -- "$(realpath -- "$0")")"/'python'"#));
+    assert!(!entrypoint.contains(&*context.venv.to_string_lossy()));
+
+    // With `--no-editable`, there's nothing to warn about, and the environment is reused.
+    uv_snapshot!(context.filters(), context.sync().arg("--relocatable").arg("--no-editable"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    Ok(())
+}
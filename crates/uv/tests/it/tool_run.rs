@@ -156,6 +156,135 @@ fn tool_run_at_version() {
     ");
 }
 
+#[test]
+fn tool_run_at_version_specifiers() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("pytest@>=8.0,<8.1")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.2
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.2
+    ");
+
+    // The cached environment should be reused without resolving.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("pytest@>=8.0,<8.1")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.2
+
+    ----- stderr -----
+    ");
+
+    // A different request for the same tool should use a separate environment.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    ");
+}
+
+/// Changing the resolver settings should invalidate an environment cached for a version request.
+#[test]
+fn tool_run_at_version_specifiers_prerelease() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("pytest@>=8.0,<8.1")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.2
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.2
+    ");
+
+    // Allowing pre-releases should resolve a separate environment, rather than reusing the
+    // environment cached for the same request.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--prerelease")
+        .arg("allow")
+        .arg("pytest@>=8.0,<8.1")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.2
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.2
+    ");
+
+    // Repeating the pre-release request should reuse its environment without resolving.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--prerelease")
+        .arg("allow")
+        .arg("pytest@>=8.0,<8.1")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.2
+
+    ----- stderr -----
+    ");
+}
+
 #[test]
 fn tool_run_from_version() {
     let context = TestContext::new("3.12");
@@ -185,6 +314,76 @@ fn tool_run_from_version() {
     "###);
 }
 
+/// Resolve the package that provides an executable via `tool-aliases`.
+#[test]
+fn tool_run_alias() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // `py.test` is provided by the `pytest` package.
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        tool-aliases = { "py.test" = "pytest" }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("py.test@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    ");
+
+    // Aliases must map to valid package names.
+    uv_toml.write_str(indoc! {r#"
+        tool-aliases = { "py.test" = "not a package" }
+    "#})?;
+
+    context
+        .tool_run()
+        .arg("py.test")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Not a valid package or extra name",
+        ));
+
+    // Alias keys must be executable names.
+    uv_toml.write_str(indoc! {r#"
+        tool-aliases = { "py.test@8.0.0" = "pytest" }
+    "#})?;
+
+    context
+        .tool_run()
+        .arg("py.test")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Executable name `py.test@8.0.0` must not contain a version (`@`)",
+        ));
+
+    Ok(())
+}
+
 #[test]
 fn tool_run_constraints() {
     let context = TestContext::new("3.12");
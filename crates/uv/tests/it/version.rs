@@ -1736,6 +1736,8 @@ fn self_version_json() -> Result<()> {
             ),
             (r#"commit_hash": ".*""#, r#"commit_hash": "[LONGHASH]""#),
             (r#"commit_date": ".*""#, r#"commit_date": "[DATE]""#),
+            (r#"build_date": ".*""#, r#"build_date": "[DATE]""#),
+            (r#""features": \[[^\]]*\]"#, r#""features": [FEATURES]"#),
             (r#"last_tag": (".*"|null)"#, r#"last_tag": "[TAG]""#),
             (
                 r#"commits_since_last_tag": .*"#,
@@ -1759,7 +1761,9 @@ fn self_version_json() -> Result<()> {
           "commit_date": "[DATE]",
           "last_tag": "[TAG]",
           "commits_since_last_tag": [COUNT]
-        }
+        },
+        "build_date": "[DATE]",
+        "features": [FEATURES]
       }
 
       ----- stderr -----
@@ -1773,7 +1777,9 @@ fn self_version_json() -> Result<()> {
       {
         "package_name": "uv",
         "version": "[VERSION]",
-        "commit_info": null
+        "commit_info": null,
+        "build_date": "[DATE]",
+        "features": [FEATURES]
       }
 
       ----- stderr -----
@@ -0,0 +1,209 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use crate::common::{TestContext, uv_snapshot};
+
+/// Explain a package that's required through the project's dependencies, extras, and groups.
+#[test]
+fn why_lock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [project.optional-dependencies]
+        async = ["anyio==3.7.0"]
+
+        [dependency-groups]
+        dev = ["sniffio>=1"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // `sniffio` is required by both the `async` extra (through `anyio`) and the `dev` group.
+    uv_snapshot!(context.filters(), context.why().arg("sniffio"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project (extra: async)
+    └── anyio==3.7.0
+        └── sniffio
+
+    project (group: dev)
+    └── sniffio>=1
+
+    ----- stderr -----
+    ");
+
+    // `idna` is only required through the `async` extra.
+    uv_snapshot!(context.filters(), context.why().arg("idna"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project (extra: async)
+    └── anyio==3.7.0
+        └── idna
+
+    ----- stderr -----
+    note: `idna` is only required through optional dependencies: `project[async]`
+    ");
+
+    // Package names are normalized.
+    uv_snapshot!(context.filters(), context.why().arg("IniConfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project
+    └── iniconfig
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.why().arg("project"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `project` is not required by any other package
+    ");
+
+    uv_snapshot!(context.filters(), context.why().arg("flask"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `flask` is not required by the project
+    ");
+
+    Ok(())
+}
+
+/// Explain a package that's required by another workspace member.
+#[test]
+fn why_workspace_member() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["child"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.sources]
+        child = { workspace = true }
+        "#,
+    )?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(
+        r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=2 ; sys_platform == 'linux'"]
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+        "#,
+    )?;
+    child.child("src/child/__init__.py").touch()?;
+
+    context.lock().assert().success();
+
+    // Both the path through `project` and the path from `child` itself are shown.
+    uv_snapshot!(context.filters(), context.why().arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    child
+    └── iniconfig>=2 ; sys_platform == 'linux'
+
+    project
+    └── child
+        └── iniconfig>=2 ; sys_platform == 'linux'
+
+    ----- stderr -----
+    ");
+
+    Ok(())
+}
+
+/// Without a lockfile, `uv why` requires `--environment`.
+#[test]
+fn why_missing_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.why().arg("iniconfig"), @r"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    ");
+
+    Ok(())
+}
+
+/// Explain a package using the metadata of the installed packages.
+#[test]
+fn why_environment() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .pip_install()
+        .arg("anyio==3.7.0")
+        .arg("iniconfig")
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.why().arg("--environment").arg("idna"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio
+    └── idna>=2.8
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.why().arg("--environment").arg("iniconfig"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `iniconfig` is not required by any other package
+    ");
+
+    Ok(())
+}
@@ -1930,3 +1930,74 @@ fn transitive_dep_in_git_workspace_with_root() -> Result<()> {
 
     Ok(())
 }
+
+/// Negated `members` and `exclude` patterns are evaluated in order, with the last match winning;
+/// `uv workspace list` shows the entry that matched each directory.
+#[test]
+fn workspace_list_negated_patterns() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let deps = indoc! {r#"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["services/*", "!services/scratch"]
+        exclude = ["services/**/legacy*", "!services/legacy-auth"]
+    "#};
+    make_project(context.temp_dir.path(), "albatross", deps)?;
+    for name in ["api", "legacy-auth", "legacy-billing", "scratch"] {
+        make_project(
+            &context.temp_dir.join("services").join(name),
+            name,
+            "dependencies = []",
+        )?;
+    }
+
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--preview-features").arg("workspace-list"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    albatross (.) workspace root
+    api (services/api) included by `services/*`
+    legacy-auth (services/legacy-auth) included by `services/*`
+    services/legacy-billing excluded by `exclude` entry `services/**/legacy*`
+    services/scratch excluded by `members` entry `!services/scratch`
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.lock(), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 3 packages in [TIME]
+    ");
+
+    // Dropping the negated `exclude` entry removes `legacy-auth` from the workspace, which should
+    // invalidate the lockfile.
+    let deps = indoc! {r#"
+        dependencies = []
+
+        [tool.uv.workspace]
+        members = ["services/*", "!services/scratch"]
+        exclude = ["services/**/legacy*"]
+    "#};
+    fs_err::remove_dir_all(context.temp_dir.join("albatross"))?;
+    make_project(context.temp_dir.path(), "albatross", deps)?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Resolved 2 packages in [TIME]
+    The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    ");
+
+    Ok(())
+}